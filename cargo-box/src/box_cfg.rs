@@ -0,0 +1,220 @@
+//! `.box` file in workspace root with signing settings of generated projects.
+//!
+//! ```sh
+//! BOX_ORG_ID=org.cidre
+//! DEVELOPMENT_TEAM=FD6ZML48V9
+//! DEVICE_ID=00008110-001234567890801E
+//! ```
+//!
+//! Env vars take precedence over the file.
+
+use std::{env, fmt, io, path::Path};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BoxCfg {
+    /// Bundle id prefix, `org.cidre` gives `org.cidre.{product}`.
+    pub(crate) org_id: String,
+    pub(crate) dev_team: String,
+    /// Device to run on, only runner needs it.
+    pub(crate) device_id: Option<String>,
+}
+
+#[derive(Debug)]
+pub(crate) enum Error {
+    Io(io::Error),
+    /// Line `dotenv` can't parse.
+    Syntax(String),
+    Missing(&'static str),
+    Invalid {
+        key: &'static str,
+        val: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "can't read .box: {e}"),
+            Self::Syntax(line) => write!(f, ".box: can't parse `{line}`, expected `KEY=value`"),
+            Self::Missing(key) => {
+                write!(f, "{key} env is required. You can add it .box file")?;
+                match *key {
+                    DEVELOPMENT_TEAM => {
+                        f.write_str("\nuse `cargo box teams` to list available team ids")
+                    }
+                    DEVICE_ID => f.write_str("\nuse `cargo box devices` to list connected devices"),
+                    _ => Ok(()),
+                }
+            }
+            Self::Invalid { key, val } => {
+                let expected = match *key {
+                    BOX_ORG_ID => "reverse DNS like `org.cidre`",
+                    DEVELOPMENT_TEAM => "10 uppercase letters or digits",
+                    _ => "non empty value",
+                };
+                write!(f, "{key}={val:?} is invalid, expected {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+const BOX_ORG_ID: &str = "BOX_ORG_ID";
+const DEVELOPMENT_TEAM: &str = "DEVELOPMENT_TEAM";
+const DEVICE_ID: &str = "DEVICE_ID";
+
+impl BoxCfg {
+    /// Reads `.box` in `root` if there is one, env vars override it.
+    pub(crate) fn load(root: &Path) -> Result<Self, Error> {
+        Self::load_with_env(root, |key| env::var(key).ok())
+    }
+
+    pub(crate) fn load_with_env(
+        root: &Path,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, Error> {
+        let mut vars = Vec::new();
+        // unlike `dotenv::from_path` iter doesn't touch process env
+        #[allow(deprecated)]
+        match dotenv::from_path_iter(root.join(".box")) {
+            Ok(iter) => {
+                for item in iter {
+                    match item {
+                        Ok(var) => vars.push(var),
+                        Err(dotenv::Error::LineParse(line, _)) => return Err(Error::Syntax(line)),
+                        Err(dotenv::Error::Io(e)) => return Err(Error::Io(e)),
+                        Err(e) => return Err(Error::Io(io::Error::other(e.to_string()))),
+                    }
+                }
+            }
+            Err(dotenv::Error::Io(e)) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(dotenv::Error::Io(e)) => return Err(Error::Io(e)),
+            Err(e) => return Err(Error::Io(io::Error::other(e.to_string()))),
+        }
+        let get = |key: &'static str| -> Result<Option<String>, Error> {
+            let val = env(key).or_else(|| {
+                vars.iter()
+                    .rev()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, v)| v.clone())
+            });
+            match val {
+                Some(v) if v.trim().is_empty() => Err(Error::Invalid { key, val: v }),
+                v => Ok(v),
+            }
+        };
+
+        let org_id = get(BOX_ORG_ID)?.ok_or(Error::Missing(BOX_ORG_ID))?;
+        if !is_org_id(&org_id) {
+            return Err(Error::Invalid {
+                key: BOX_ORG_ID,
+                val: org_id,
+            });
+        }
+        let dev_team = get(DEVELOPMENT_TEAM)?.ok_or(Error::Missing(DEVELOPMENT_TEAM))?;
+        if !is_team_id(&dev_team) {
+            return Err(Error::Invalid {
+                key: DEVELOPMENT_TEAM,
+                val: dev_team,
+            });
+        }
+        let device_id = get(DEVICE_ID)?;
+        Ok(Self {
+            org_id,
+            dev_team,
+            device_id,
+        })
+    }
+
+    pub(crate) fn device_id(&self) -> Result<&str, Error> {
+        self.device_id.as_deref().ok_or(Error::Missing(DEVICE_ID))
+    }
+}
+
+/// Bundle ids allow letters, digits, `-` and `.`
+fn is_org_id(val: &str) -> bool {
+    val.split('.')
+        .all(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+}
+
+fn is_team_id(val: &str) -> bool {
+    val.len() == 10
+        && val
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn fixture(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name)
+    }
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    #[test]
+    fn load() {
+        let cfg = BoxCfg::load_with_env(&fixture("ws"), no_env).unwrap();
+        assert_eq!(cfg.org_id, "org.cidre");
+        assert_eq!(cfg.dev_team, "FD6ZML48V9");
+        assert_eq!(cfg.device_id, None);
+        assert!(matches!(cfg.device_id(), Err(Error::Missing("DEVICE_ID"))));
+
+        let cfg = BoxCfg::load_with_env(&fixture("ws"), |key| {
+            (key == "DEVICE_ID" || key == "BOX_ORG_ID").then(|| "com.example-1".to_string())
+        })
+        .unwrap();
+        assert_eq!(cfg.org_id, "com.example-1");
+        assert_eq!(cfg.device_id().unwrap(), "com.example-1");
+    }
+
+    #[test]
+    fn errors() {
+        // no .box at all
+        let err = BoxCfg::load_with_env(&fixture("single"), no_env).unwrap_err();
+        assert!(matches!(err, Error::Missing("BOX_ORG_ID")));
+
+        let err = BoxCfg::load_with_env(&fixture("single"), |key| {
+            (key == "BOX_ORG_ID").then(|| "org.cidre".to_string())
+        })
+        .unwrap_err();
+        assert!(matches!(err, Error::Missing("DEVELOPMENT_TEAM")));
+        assert!(err.to_string().contains("cargo box teams"));
+
+        let err = BoxCfg::load_with_env(&fixture("single"), |key| match key {
+            "BOX_ORG_ID" => Some("org..cidre".to_string()),
+            _ => None,
+        })
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Invalid {
+                key: "BOX_ORG_ID",
+                ..
+            }
+        ));
+
+        let err = BoxCfg::load_with_env(&fixture("single"), |key| match key {
+            "BOX_ORG_ID" => Some("org.cidre".to_string()),
+            "DEVELOPMENT_TEAM" => Some("fd6zml48v9".to_string()),
+            _ => None,
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "DEVELOPMENT_TEAM=\"fd6zml48v9\" is invalid, expected 10 uppercase letters or digits"
+        );
+
+        let err = BoxCfg::load_with_env(&fixture("broken"), no_env).unwrap_err();
+        assert!(matches!(err, Error::Syntax(line) if line == "BOX_ORG_ID org.cidre"));
+    }
+}
@@ -0,0 +1,206 @@
+//! `cargo box devices`, devices known to `devicectl`.
+
+use std::io::Write;
+
+use serde::Serialize;
+
+use crate::Format;
+
+/// Source of `devicectl list devices` json output.
+pub(crate) trait DeviceCtl {
+    fn list_devices(&self) -> String;
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Device {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) platform: String,
+    /// Unknown for unavailable devices.
+    pub(crate) os_version: Option<String>,
+    /// `wired` or `localNetwork`, none if device is not connected.
+    pub(crate) connection: Option<String>,
+    pub(crate) pairing_state: String,
+}
+
+pub(crate) fn parse(buf: &str) -> serde_json::Result<Vec<Device>> {
+    let list = serde_json::from_str::<json::DeviceList>(buf)?;
+    Ok(list
+        .result
+        .devices
+        .into_iter()
+        .map(|d| Device {
+            id: d.id.to_string(),
+            name: d.props.name.into_owned(),
+            platform: d.hardware.platform.to_string(),
+            os_version: d.props.os_version.map(str::to_string),
+            connection: d.connection.transport_type.map(str::to_string),
+            pairing_state: d.connection.pairing_state.to_string(),
+        })
+        .collect())
+}
+
+pub(crate) fn write(
+    devices: &[Device],
+    format: Format,
+    out: &mut impl Write,
+) -> serde_json::Result<()> {
+    match format {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, devices)?;
+            writeln!(out).map_err(serde_json::Error::io)
+        }
+        Format::Text => write_text(devices, out).map_err(serde_json::Error::io),
+    }
+}
+
+fn write_text(devices: &[Device], out: &mut impl Write) -> std::io::Result<()> {
+    if devices.is_empty() {
+        return writeln!(out, "no devices are found");
+    }
+    for d in devices {
+        write!(out, "{}: {} ({}", d.id, d.name, d.platform)?;
+        if let Some(os_version) = &d.os_version {
+            write!(out, " {os_version}")?;
+        }
+        if let Some(connection) = &d.connection {
+            write!(out, ", {connection}")?;
+        }
+        writeln!(out, ", {})", d.pairing_state)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn list(
+    ctl: &impl DeviceCtl,
+    format: Format,
+    out: &mut impl Write,
+) -> serde_json::Result<()> {
+    let devices = parse(&ctl.list_devices())?;
+    write(&devices, format, out)
+}
+
+mod json {
+    use serde::Deserialize;
+    use std::borrow::Cow;
+
+    #[derive(Deserialize, Debug)]
+    pub(crate) struct DeviceList<'a> {
+        #[serde(borrow)]
+        pub(crate) result: ListResult<'a>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub(crate) struct ListResult<'a> {
+        #[serde(borrow)]
+        pub(crate) devices: Vec<Device<'a>>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub(crate) struct Device<'a> {
+        #[serde(rename = "identifier")]
+        pub(crate) id: &'a str,
+
+        #[serde(borrow)]
+        #[serde(rename = "deviceProperties")]
+        pub(crate) props: DeviceProps<'a>,
+
+        #[serde(borrow)]
+        #[serde(rename = "connectionProperties")]
+        pub(crate) connection: ConnectionProps<'a>,
+
+        #[serde(borrow)]
+        #[serde(rename = "hardwareProperties")]
+        pub(crate) hardware: HwProps<'a>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub(crate) struct DeviceProps<'a> {
+        pub(crate) name: Cow<'a, str>,
+        #[serde(rename = "osVersionNumber")]
+        pub(crate) os_version: Option<&'a str>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub(crate) struct HwProps<'a> {
+        pub(crate) platform: &'a str,
+    }
+
+    #[derive(Deserialize, Debug)]
+    #[serde(rename_all = "camelCase")]
+    pub(crate) struct ConnectionProps<'a> {
+        pub(crate) pairing_state: &'a str,
+        pub(crate) transport_type: Option<&'a str>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Recorded `devicectl` output.
+    struct Recorded(&'static str);
+
+    impl DeviceCtl for Recorded {
+        fn list_devices(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    const LIST: Recorded = Recorded(include_str!(
+        "../tests/fixtures/devicectl/list_devices.json"
+    ));
+
+    fn output(ctl: &impl DeviceCtl, format: Format) -> String {
+        let mut out = Vec::new();
+        list(ctl, format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn parsing() {
+        let devices = parse(LIST.0).unwrap();
+        assert_eq!(devices.len(), 2);
+        assert_eq!(
+            devices[0],
+            Device {
+                id: "8C2E5D41-3B0F-4A55-9E61-1F7B2C9D0A12".to_string(),
+                name: "Yury’s iPhone".to_string(),
+                platform: "iOS".to_string(),
+                os_version: Some("18.1".to_string()),
+                connection: Some("wired".to_string()),
+                pairing_state: "paired".to_string(),
+            }
+        );
+        assert_eq!(devices[1].os_version, None);
+        assert_eq!(devices[1].connection, None);
+
+        assert!(parse(r#"{"result":{}}"#).is_err());
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(
+            output(&LIST, Format::Text),
+            "8C2E5D41-3B0F-4A55-9E61-1F7B2C9D0A12: Yury’s iPhone (iOS 18.1, wired, paired)\n\
+             0F3A9B77-6C1D-4E28-B5A0-7D94E3C1B2F6: iPad (iOS, unpaired)\n"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&output(&LIST, Format::Json)).unwrap();
+        assert_eq!(
+            json[1],
+            serde_json::json!({
+                "id": "0F3A9B77-6C1D-4E28-B5A0-7D94E3C1B2F6",
+                "name": "iPad",
+                "platform": "iOS",
+                "os_version": null,
+                "connection": null,
+                "pairing_state": "unpaired",
+            })
+        );
+
+        let empty = Recorded(r#"{"result":{"devices":[]}}"#);
+        assert_eq!(output(&empty, Format::Text), "no devices are found\n");
+        assert_eq!(output(&empty, Format::Json), "[]\n");
+    }
+}
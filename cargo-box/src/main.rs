@@ -1,5 +1,12 @@
 use clap::{Parser, Subcommand};
-use std::env;
+use std::{env, io, process};
+
+mod box_cfg;
+mod devices;
+mod proj;
+// keychain is macOS only, formatting is tested everywhere
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+mod teams;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -16,16 +23,30 @@ enum Cmd {
 
     /// List dev teams on this mac
     #[command()]
-    Teams,
+    Teams(ListArgs),
 
     /// List connected devices on this mac
     #[command()]
-    Devices,
+    Devices(ListArgs),
 
     /// Create configured xcode project for binary, test or example
     /// in target/boxes for runner.
     #[command()]
-    Proj(xcode::ProjArgs),
+    Proj(proj::ProjArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct ListArgs {
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Format {
+    #[default]
+    Text,
+    /// Array of records for scripts
+    Json,
 }
 
 fn main() {
@@ -44,22 +65,43 @@ fn main() {
     }
 
     match Cli::parse_from(args).cmd {
-        Cmd::Teams => teams::list(),
-        Cmd::Devices => device_ctl::list_devices(),
-        Cmd::Proj(args) => xcode::proj(args),
+        Cmd::Teams(args) => list_teams(args.format),
+        Cmd::Devices(args) => {
+            if let Err(e) = devices::list(&device_ctl::XcRun, args.format, &mut io::stdout()) {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+        Cmd::Proj(args) => {
+            if let Err(e) = proj::proj(&args) {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
         _ => panic!("unknown command"),
     }
 }
 
+#[cfg(target_os = "macos")]
+fn list_teams(format: Format) {
+    if let Err(e) = teams::list(&teams::Keychain, format, &mut io::stdout()) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn list_teams(_format: Format) {
+    eprintln!("teams are read from keychain, which is available only on macOS");
+    process::exit(1);
+}
+
 mod runner {
-    use std::{
-        env,
-        path::{Path, PathBuf},
-    };
+    use std::{env, path::Path, process};
 
     use clap::Parser;
 
-    use crate::{device_ctl, xcode};
+    use crate::{device_ctl, proj, xcode};
 
     #[derive(Parser, Debug)]
     pub(crate) struct Args {
@@ -202,11 +244,7 @@ mod runner {
             }
         }
 
-        let mut proj_args = xcode::ProjArgs {
-            bin: None,
-            example: None,
-            dep: None,
-        };
+        let mut proj_args = proj::ProjArgs::default();
 
         if is_example {
             proj_args.example = Some(name.clone());
@@ -216,22 +254,25 @@ mod runner {
             proj_args.dep = Some(name.clone());
         }
 
-        xcode::proj(proj_args);
-
-        let mut project = PathBuf::from("./target/boxes");
-        if is_example {
-            project.push("examples");
-        }
-        if is_dep {
-            project.push("deps");
-        }
-
-        let mut target = project.clone();
-        target.push(format!("build-{name}"));
+        let proj = match proj::proj(&proj_args) {
+            Ok(proj) => proj,
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        };
+        let device_id = match proj.cfg.device_id() {
+            Ok(id) => id,
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        };
 
-        project.push(&name);
-        project.push(&name);
+        let mut target = proj.dir.clone();
+        target.set_file_name(format!("build-{name}"));
 
+        let mut project = proj.dir.join(&name);
         // TODO: if is_binary, try replace with BOX_BIN_PATH instead of copy
         std::fs::copy(binary, &project).unwrap();
         project.pop();
@@ -248,153 +289,16 @@ mod runner {
         target.push("Build");
         target.push("Products");
 
-        target.push(&format!("{config}-{sdk}"));
-        target.push(&format!("{name}.app"));
+        target.push(format!("{config}-{sdk}"));
+        target.push(format!("{name}.app"));
 
-        let box_org_id = std::env::var("BOX_ORG_ID").unwrap();
-        let device_id = std::env::var("DEVICE_ID").unwrap();
-
-        device_ctl::install_app(&device_id, target.to_str().unwrap());
-        device_ctl::run_app(&device_id, &format!("{box_org_id}.{name}"), &args.args[3..]);
-    }
-}
-
-mod teams {
-    use cidre::{arc, cf, sec};
-
-    pub(crate) fn list() {
-        let query = cf::DictionaryOf::with_keys_values(
-            &[
-                sec::class_key(),
-                sec::match_keys::limit(),
-                sec::match_keys::policy(),
-            ],
-            &[
-                sec::class::certificate().as_type_ref(),
-                sec::match_limit::all(),
-                &sec::Policy::with_props(sec::Policy::apple_code_signing(), None).unwrap(),
-            ],
-        );
-
-        let certs = sec::item_matching(&query).unwrap();
-
-        assert_eq!(certs.get_type_id(), cf::Array::type_id());
-        let certs: arc::R<cf::ArrayOf<sec::Cert>> = unsafe { std::mem::transmute(certs) };
-
-        let mut filter_set = std::collections::HashSet::new();
-        let subject_key = sec::cert_oids::x509_v1_subject_name();
-        let org_name_label = sec::cert_oids::organization_name();
-        let unit_name_label = sec::cert_oids::organizational_unit_name();
-        let prop_value_key = sec::prop_keys::value();
-        let prop_label_key = sec::prop_keys::label();
-        let keys = cf::ArrayOf::from_slice(&[subject_key]);
-        for cert in certs.iter() {
-            let Ok(vals) = cert.values(&keys) else {
-                continue;
-            };
-            let Some(value) = vals.get(subject_key) else {
-                continue;
-            };
-            let Some(section) = value.get(prop_value_key) else {
-                continue;
-            };
-            assert_eq!(section.get_type_id(), cf::Array::type_id());
-
-            let section: &cf::ArrayOf<cf::DictionaryOf<cf::String, cf::Type>> =
-                unsafe { std::mem::transmute(section) };
-
-            let mut team_id = None;
-            let mut team_name = None;
-            for dict in section.iter() {
-                let Some(label) = dict.get(prop_label_key) else {
-                    continue;
-                };
-                let Some(value) = dict.get(prop_value_key) else {
-                    continue;
-                };
-
-                // todo: build safer api
-                if value.get_type_id() != cf::String::type_id() {
-                    continue;
-                }
-                let value: &cf::String = unsafe { std::mem::transmute(value) };
-
-                if label.equal(org_name_label) {
-                    team_name = Some(value);
-                } else if label.equal(unit_name_label) {
-                    team_id = Some(value);
-                }
-            }
-
-            if let (Some(id), Some(name)) = (team_id, team_name) {
-                if filter_set.contains(id) {
-                    continue;
-                };
-                println!("{id}: {name}");
-                filter_set.insert(id);
-            }
-        }
-        if filter_set.is_empty() {
-            println!("no teams are found");
-        }
-    }
-}
-
-mod cargo {
-    use cargo_toml::{Manifest, Workspace};
-    use std::{env, path::PathBuf};
-
-    pub(crate) fn manifests() -> Option<(PathBuf, Vec<Manifest>, Option<Workspace>)> {
-        let mut path = root()?.join("Cargo.toml");
-        if let Ok(mut res) = Manifest::from_path(&path) {
-            path.pop();
-            res.complete_from_path(&path).unwrap();
-            if let Some(ws) = res.workspace {
-                let mut vec = Vec::with_capacity(ws.members.len());
-                for member in ws.members.iter() {
-                    path = path.join(&format!("{member}/Cargo.toml"));
-                    let mut man = Manifest::from_path(&path).unwrap();
-                    path.pop();
-                    man.complete_from_path(&path).unwrap();
-                    path.pop();
-                    vec.push(man);
-                }
-                Some((path, vec, Some(ws)))
-            } else {
-                Some((path, vec![res], None))
-            }
-        } else {
-            None
-        }
-    }
-
-    fn root() -> Option<PathBuf> {
-        let cwd = env::current_dir().unwrap();
-        let mut cwd_clone = cwd.clone();
-        cwd_clone.push("target");
-        if cwd_clone.exists() {
-            // we are good
-            return Some(cwd);
-        }
-        cwd_clone.pop(); // target
-        cwd_clone.pop(); // ..
-        cwd_clone.push("target");
-        if cwd_clone.exists() {
-            // we are good
-            cwd_clone.pop();
-            return Some(cwd_clone);
-        }
-        None
+        let box_org_id = &proj.cfg.org_id;
+        device_ctl::install_app(device_id, target.to_str().unwrap());
+        device_ctl::run_app(device_id, &format!("{box_org_id}.{name}"), &args.args[3..]);
     }
 }
 
 mod xcode {
-    use std::fs;
-
-    use cargo_toml::{Manifest, Product};
-
-    use crate::cargo;
-
     pub(crate) fn build(project: &str, platform: &str, conf: &str, target: &str) {
         std::process::Command::new("xcodebuild")
             .args(["-project", project])
@@ -406,177 +310,13 @@ mod xcode {
             .status()
             .unwrap();
     }
-
-    #[derive(clap::Args, Debug)]
-    pub(crate) struct ProjArgs {
-        #[arg(long)]
-        pub(crate) bin: Option<String>,
-        #[arg(long)]
-        pub(crate) example: Option<String>,
-        #[arg(long)]
-        pub(crate) dep: Option<String>,
-    }
-
-    fn find_product<'a>(
-        mans: &'a [Manifest],
-        args: &ProjArgs,
-    ) -> Option<(&'a Manifest, &'a Product)> {
-        let (is_bin, name, nothing, not_found, available) = if args.example.is_some() {
-            (
-                false,
-                args.example.as_ref(),
-                "No examples.",
-                "No examples found with name",
-                "Available examples:",
-            )
-        } else {
-            (
-                true,
-                args.bin.as_ref(),
-                "No binaries.",
-                "No binary found with name",
-                "Available binaries:",
-            )
-        };
-
-        let products = |man: &'a Manifest| -> &'a [Product] {
-            if is_bin {
-                &man.bin
-            } else {
-                &man.example
-            }
-        };
-
-        if let Some(product_name) = name.as_ref() {
-            let mut count = 0;
-            for man in mans {
-                for product in products(man) {
-                    if product.name.as_ref() == name {
-                        return Some((man, product));
-                    }
-                    count += 1;
-                }
-            }
-            if count == 0 {
-                println!("{nothing}");
-            } else {
-                println!("{not_found} `{product_name}`");
-                println!("{available}");
-                for man in mans {
-                    for product in products(man) {
-                        if let Some(name) = product.name.as_ref() {
-                            println!("\t{name}");
-                        }
-                    }
-                }
-            }
-
-            return None;
-        }
-
-        for man in mans {
-            for product in man.bin.iter() {
-                if product.name.is_some() {
-                    return Some((man, product));
-                }
-            }
-        }
-
-        println!("No binaries");
-
-        None
-    }
-
-    pub(crate) fn proj(args: ProjArgs) {
-        let (mut path, mans, _ws) = cargo::manifests().unwrap();
-        let product = if args.dep.is_some() {
-            &Product {
-                path: None,
-                name: args.dep.clone(),
-                test: false,
-                doctest: false,
-                bench: false,
-                doc: false,
-                plugin: false,
-                proc_macro: false,
-                harness: true,
-                edition: None,
-                crate_type: vec![],
-                required_features: vec![],
-            }
-        } else {
-            let Some((_man, product)) = find_product(&mans, &args) else {
-                return;
-            };
-            product
-        };
-
-        path.push(".box");
-        _ = dotenv::from_filename(".box");
-        path.pop();
-
-        let Ok(box_org_id) = std::env::var("BOX_ORG_ID") else {
-            println!("BOX_ORG_ID env is required. You can add it .box file");
-            return;
-        };
-        let Ok(dev_team_id) = std::env::var("DEVELOPMENT_TEAM") else {
-            println!("DEVELOPMENT_TEAM env is required. You can add it .box file");
-            println!("use cidre-box teams command to list available team ids");
-            return;
-        };
-
-        path.push("target/boxes");
-        if args.example.is_some() {
-            path.push("examples")
-        }
-        if args.dep.is_some() {
-            path.push("deps")
-        }
-        let product_name = product.name.as_ref().unwrap();
-        path.push(product_name);
-
-        fs::create_dir_all(&path).unwrap();
-        path.push("box.entitlements");
-        fs::write(&path, include_str!("../box/box.entitlements")).unwrap();
-        path.pop();
-        path.push("cfg.xcconfig");
-        fs::write(&path, include_str!("../box/cfg.xcconfig")).unwrap();
-        path.pop();
-        path.push("box.xcconfig");
-        fs::write(
-            &path,
-            format!(
-                r#"
-PRODUCT_NAME = {product_name}
-BOX_ID = {product_name}
-DEVELOPMENT_TEAM = {dev_team_id}
-BOX_ORG_ID = {box_org_id}
-"#
-            ),
-        )
-        .unwrap();
-
-        path.pop();
-
-        path.push("box.xcodeproj");
-        fs::create_dir_all(&path).unwrap();
-        path.push("project.pbxproj");
-        fs::write(&path, include_str!("../box/box.xcodeproj/project.pbxproj")).unwrap();
-        path.pop();
-        path.push("xcshareddata/xcschemes");
-        fs::create_dir_all(&path).unwrap();
-        path.push("box.xcscheme");
-        fs::write(
-            &path,
-            include_str!("../box/box.xcodeproj/xcshareddata/xcschemes/box.xcscheme"),
-        )
-        .unwrap();
-    }
 }
 
 mod device_ctl {
     use std::{env, fs, process};
 
+    use crate::devices::DeviceCtl;
+
     fn run_cmd(args: &[&str]) -> String {
         let json_output_path = env::temp_dir().join(format!("devicectl-{}.json", process::id()));
         let mut child = process::Command::new("xcrun")
@@ -607,7 +347,7 @@ mod device_ctl {
             id,
         ];
         for s in args {
-            args_vec.push(&s);
+            args_vec.push(s);
         }
         let buf = run_cmd(&args_vec);
         let run = serde_json::from_str::<json::AppRun>(&buf).unwrap();
@@ -619,15 +359,17 @@ mod device_ctl {
         std::process::exit(code);
     }
 
-    pub(crate) fn list_devices() {
-        let buf = run_cmd(&["list", "devices"]);
-        let list = serde_json::from_str::<json::DeviceList>(&buf).unwrap();
-        println!("{:#?}", list.result.devices);
+    /// `xcrun devicectl`
+    pub(crate) struct XcRun;
+
+    impl DeviceCtl for XcRun {
+        fn list_devices(&self) -> String {
+            run_cmd(&["list", "devices"])
+        }
     }
 
     mod json {
         use serde::Deserialize;
-        use std::borrow::Cow;
 
         #[derive(Deserialize, Debug)]
         pub(crate) struct AppRun {
@@ -647,58 +389,5 @@ mod device_ctl {
             #[serde(rename = "exitCode")]
             pub(crate) code: Option<i32>,
         }
-
-        #[derive(Deserialize, Debug)]
-        pub(crate) struct DeviceList<'a> {
-            #[serde(borrow)]
-            pub(crate) result: ListResult<'a>,
-        }
-
-        #[derive(Deserialize, Debug)]
-        pub(crate) struct ListResult<'a> {
-            #[serde(borrow)]
-            pub(crate) devices: Vec<Device<'a>>,
-        }
-
-        // #[serde(rename_all = "camelCase")]
-        #[derive(Deserialize, Debug)]
-        pub(crate) struct Device<'a> {
-            #[serde(rename = "identifier")]
-            pub(crate) id: &'a str,
-
-            #[serde(borrow)]
-            #[serde(rename = "deviceProperties")]
-            pub(crate) props: DeviceProps<'a>,
-
-            // pub(crate) visibility_class: &'a str,
-            #[serde(borrow)]
-            #[serde(rename = "connectionProperties")]
-            pub(crate) connection: ConnectionProps<'a>,
-
-            #[serde(borrow)]
-            #[serde(rename = "hardwareProperties")]
-            pub(crate) hardware: HwProps<'a>,
-        }
-
-        #[derive(Deserialize, Debug)]
-        pub(crate) struct DeviceProps<'a> {
-            pub(crate) name: Cow<'a, str>,
-            #[serde(rename = "developerModeStatus")]
-            pub(crate) dev_mode_status: Option<&'a str>,
-        }
-
-        #[derive(Deserialize, Debug)]
-        #[serde(rename_all = "camelCase")]
-        pub(crate) struct HwProps<'a> {
-            pub(crate) device_type: &'a str,
-            pub(crate) platform: &'a str,
-            pub(crate) udid: &'a str,
-        }
-
-        #[derive(Deserialize, Debug)]
-        #[serde(rename_all = "camelCase")]
-        pub(crate) struct ConnectionProps<'a> {
-            pub(crate) pairing_state: &'a str,
-        }
     }
 }
@@ -0,0 +1,445 @@
+//! Xcode project generation for a bin, example or test of cargo workspace.
+//!
+//! Needs no Apple tools, project is rendered from templates in `box` dir
+//! into `target/boxes/[examples|deps/]{name}`.
+
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use cargo_toml::{Manifest, Product};
+
+use crate::box_cfg::{self, BoxCfg};
+
+#[derive(clap::Args, Debug, Default)]
+pub(crate) struct ProjArgs {
+    #[arg(long)]
+    pub(crate) bin: Option<String>,
+    #[arg(long)]
+    pub(crate) example: Option<String>,
+    #[arg(long)]
+    pub(crate) dep: Option<String>,
+    /// Print chosen product and project tree without writing it
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Bin,
+    Example,
+    /// Test or bench binary from `deps` dir.
+    Test,
+}
+
+impl Kind {
+    /// Subdir of `target/boxes`.
+    fn dir(self) -> Option<&'static str> {
+        match self {
+            Self::Bin => None,
+            Self::Example => Some("examples"),
+            Self::Test => Some("deps"),
+        }
+    }
+
+    fn plural(self) -> &'static str {
+        match self {
+            Self::Bin => "binaries",
+            Self::Example => "examples",
+            Self::Test => "tests",
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Bin => "binary",
+            Self::Example => "example",
+            Self::Test => "test",
+        })
+    }
+}
+
+/// Product the project is generated for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Selected {
+    pub(crate) kind: Kind,
+    pub(crate) name: String,
+    /// Package of the product, unknown for test binaries of dependencies.
+    pub(crate) package: Option<String>,
+}
+
+impl fmt::Display for Selected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} `{}`", self.kind, self.name)?;
+        if let Some(package) = &self.package {
+            write!(f, " of package `{package}`")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum Error {
+    NoWorkspace(PathBuf),
+    Manifest(PathBuf, cargo_toml::Error),
+    NoProducts(Kind),
+    NotFound {
+        kind: Kind,
+        name: String,
+        available: Vec<String>,
+    },
+    Box(box_cfg::Error),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoWorkspace(cwd) => write!(
+                f,
+                "can't find cargo workspace with `target` dir from {}",
+                cwd.display()
+            ),
+            Self::Manifest(path, e) => write!(f, "{}: {e}", path.display()),
+            Self::NoProducts(kind) => write!(f, "No {}.", kind.plural()),
+            Self::NotFound {
+                kind,
+                name,
+                available,
+            } => {
+                write!(f, "No {kind} found with name `{name}`")?;
+                if !available.is_empty() {
+                    write!(f, "\nAvailable {}:", kind.plural())?;
+                    for name in available {
+                        write!(f, "\n\t{name}")?;
+                    }
+                }
+                Ok(())
+            }
+            Self::Box(e) => e.fmt(f),
+            Self::Io(path, e) => write!(f, "{}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<box_cfg::Error> for Error {
+    fn from(value: box_cfg::Error) -> Self {
+        Self::Box(value)
+    }
+}
+
+/// Workspace root, current dir or its parent with `target` dir.
+pub(crate) fn root(cwd: &Path) -> Option<PathBuf> {
+    if cwd.join("target").exists() {
+        return Some(cwd.to_path_buf());
+    }
+    let parent = cwd.parent()?;
+    parent.join("target").exists().then(|| parent.to_path_buf())
+}
+
+/// Manifests of workspace members or of the single package in `root`.
+pub(crate) fn manifests(root: &Path) -> Result<Vec<Manifest>, Error> {
+    let man = manifest(root)?;
+    let Some(ws) = &man.workspace else {
+        return Ok(vec![man]);
+    };
+    let mut res = Vec::with_capacity(ws.members.len() + 1);
+    for member in ws.members.iter() {
+        res.push(manifest(&root.join(member))?);
+    }
+    if man.package.is_some() {
+        res.push(man);
+    }
+    Ok(res)
+}
+
+fn manifest(dir: &Path) -> Result<Manifest, Error> {
+    let path = dir.join("Cargo.toml");
+    let mut man = Manifest::from_path(&path).map_err(|e| Error::Manifest(path.clone(), e))?;
+    man.complete_from_path(&path)
+        .map_err(|e| Error::Manifest(path, e))?;
+    Ok(man)
+}
+
+fn package(man: &Manifest) -> Option<String> {
+    man.package.as_ref().map(|p| p.name.clone())
+}
+
+/// Product by `--bin`, `--example` or `--dep` name, first binary by default.
+pub(crate) fn select(mans: &[Manifest], args: &ProjArgs) -> Result<Selected, Error> {
+    if let Some(name) = &args.dep {
+        // unit tests are named after lib or bin, benches are not in manifests
+        let package = mans
+            .iter()
+            .find(|man| {
+                let lib = man.lib.iter().filter_map(|p| p.name.as_ref());
+                let products = man.test.iter().chain(&man.bin).chain(&man.bench);
+                lib.chain(products.filter_map(|p| p.name.as_ref()))
+                    .any(|n| n.replace('-', "_") == *name)
+            })
+            .and_then(package);
+        return Ok(Selected {
+            kind: Kind::Test,
+            name: name.clone(),
+            package,
+        });
+    }
+
+    let (kind, name) = match (&args.example, &args.bin) {
+        (Some(name), _) => (Kind::Example, Some(name)),
+        (None, name) => (Kind::Bin, name.as_ref()),
+    };
+    let products = |man: &Manifest| -> Vec<Product> {
+        match kind {
+            Kind::Example => man.example.clone(),
+            _ => man.bin.clone(),
+        }
+    };
+    let mut available = vec![];
+    for man in mans {
+        for product in products(man) {
+            let Some(product_name) = product.name else {
+                continue;
+            };
+            if name.is_none_or(|n| *n == product_name) {
+                return Ok(Selected {
+                    kind,
+                    name: product_name,
+                    package: package(man),
+                });
+            }
+            available.push(product_name);
+        }
+    }
+    match name {
+        Some(name) if !available.is_empty() => Err(Error::NotFound {
+            kind,
+            name: name.clone(),
+            available,
+        }),
+        _ => Err(Error::NoProducts(kind)),
+    }
+}
+
+/// Dir of generated project.
+pub(crate) fn box_dir(root: &Path, selected: &Selected) -> PathBuf {
+    let mut path = root.join("target/boxes");
+    if let Some(dir) = selected.kind.dir() {
+        path.push(dir);
+    }
+    path.push(&selected.name);
+    path
+}
+
+/// Project files with paths relative to box dir.
+pub(crate) fn render(selected: &Selected, cfg: &BoxCfg) -> Vec<(&'static str, String)> {
+    let name = &selected.name;
+    let BoxCfg {
+        org_id, dev_team, ..
+    } = cfg;
+    vec![
+        (
+            "box.entitlements",
+            include_str!("../box/box.entitlements").to_string(),
+        ),
+        (
+            "cfg.xcconfig",
+            include_str!("../box/cfg.xcconfig").to_string(),
+        ),
+        (
+            "box.xcconfig",
+            format!(
+                r#"
+PRODUCT_NAME = {name}
+BOX_ID = {name}
+DEVELOPMENT_TEAM = {dev_team}
+BOX_ORG_ID = {org_id}
+"#
+            ),
+        ),
+        (
+            "box.xcodeproj/project.pbxproj",
+            include_str!("../box/box.xcodeproj/project.pbxproj").to_string(),
+        ),
+        (
+            "box.xcodeproj/xcshareddata/xcschemes/box.xcscheme",
+            include_str!("../box/box.xcodeproj/xcshareddata/xcschemes/box.xcscheme").to_string(),
+        ),
+    ]
+}
+
+fn write(dir: &Path, files: &[(&str, String)]) -> Result<(), Error> {
+    for (file, content) in files {
+        let path = dir.join(file);
+        let parent = path.parent().unwrap_or(dir);
+        fs::create_dir_all(parent).map_err(|e| Error::Io(parent.to_path_buf(), e))?;
+        fs::write(&path, content).map_err(|e| Error::Io(path, e))?;
+    }
+    Ok(())
+}
+
+/// Generated project.
+pub(crate) struct Proj {
+    pub(crate) dir: PathBuf,
+    pub(crate) cfg: BoxCfg,
+}
+
+/// Generates project in workspace of current dir.
+pub(crate) fn proj(args: &ProjArgs) -> Result<Proj, Error> {
+    let cwd = env::current_dir().map_err(|e| Error::Io(PathBuf::from("."), e))?;
+    let root = root(&cwd).ok_or(Error::NoWorkspace(cwd))?;
+    let cfg = BoxCfg::load(&root)?;
+    proj_at(&root, args, cfg)
+}
+
+pub(crate) fn proj_at(root: &Path, args: &ProjArgs, cfg: BoxCfg) -> Result<Proj, Error> {
+    let mans = manifests(root)?;
+    let selected = select(&mans, args)?;
+    let dir = box_dir(root, &selected);
+    let files = render(&selected, &cfg);
+    if args.dry_run {
+        println!("{selected}");
+        let rel = dir.strip_prefix(root).unwrap_or(&dir);
+        for (file, _) in &files {
+            println!("{}", rel.join(file).display());
+        }
+    } else {
+        write(&dir, &files)?;
+    }
+    Ok(Proj { dir, cfg })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name)
+    }
+
+    fn cfg() -> BoxCfg {
+        BoxCfg {
+            org_id: "org.cidre".to_string(),
+            dev_team: "FD6ZML48V9".to_string(),
+            device_id: None,
+        }
+    }
+
+    fn select_in(ws: &str, args: ProjArgs) -> Result<Selected, Error> {
+        select(&manifests(&fixture(ws)).unwrap(), &args)
+    }
+
+    #[test]
+    fn selection() {
+        let sel = select_in("ws", ProjArgs::default()).unwrap();
+        assert_eq!(sel.to_string(), "binary `app` of package `app`");
+
+        let args = ProjArgs {
+            bin: Some("tool".to_string()),
+            ..Default::default()
+        };
+        let sel = select_in("single", args).unwrap();
+        assert_eq!(sel.to_string(), "binary `tool` of package `single`");
+
+        let args = ProjArgs {
+            example: Some("demo".to_string()),
+            ..Default::default()
+        };
+        let sel = select_in("ws", args).unwrap();
+        assert_eq!(sel.kind, Kind::Example);
+        assert_eq!(sel.package.as_deref(), Some("app"));
+
+        let args = ProjArgs {
+            dep: Some("smoke".to_string()),
+            ..Default::default()
+        };
+        let sel = select_in("ws", args).unwrap();
+        assert_eq!(sel.to_string(), "test `smoke` of package `box-lib`");
+
+        // unit tests of lib
+        let args = ProjArgs {
+            dep: Some("box_lib".to_string()),
+            ..Default::default()
+        };
+        let sel = select_in("ws", args).unwrap();
+        assert_eq!(sel.package.as_deref(), Some("box-lib"));
+    }
+
+    #[test]
+    fn selection_errors() {
+        let args = ProjArgs {
+            bin: Some("nope".to_string()),
+            ..Default::default()
+        };
+        let err = select_in("ws", args).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No binary found with name `nope`\nAvailable binaries:\n\tapp"
+        );
+
+        let args = ProjArgs {
+            example: Some("demo".to_string()),
+            ..Default::default()
+        };
+        let err = select_in("single", args).unwrap_err();
+        assert!(matches!(err, Error::NoProducts(Kind::Example)));
+
+        let err = manifests(&fixture("missing")).unwrap_err();
+        assert!(matches!(err, Error::Manifest(..)));
+    }
+
+    #[test]
+    fn rendering() {
+        let args = ProjArgs {
+            example: Some("demo".to_string()),
+            ..Default::default()
+        };
+        let sel = select_in("ws", args).unwrap();
+        let root = fixture("ws");
+        assert_eq!(
+            box_dir(&root, &sel),
+            root.join("target/boxes/examples/demo")
+        );
+
+        let files = render(&sel, &cfg());
+        let names: Vec<_> = files.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            [
+                "box.entitlements",
+                "cfg.xcconfig",
+                "box.xcconfig",
+                "box.xcodeproj/project.pbxproj",
+                "box.xcodeproj/xcshareddata/xcschemes/box.xcscheme",
+            ]
+        );
+        let xcconfig = &files[2].1;
+        assert!(xcconfig.contains("PRODUCT_NAME = demo\n"));
+        assert!(xcconfig.contains("DEVELOPMENT_TEAM = FD6ZML48V9\n"));
+        assert!(xcconfig.contains("BOX_ORG_ID = org.cidre\n"));
+    }
+
+    #[test]
+    fn write_tree() {
+        let root = env::temp_dir().join(format!("cargo-box-proj-{}", std::process::id()));
+        let args = ProjArgs {
+            bin: Some("tool".to_string()),
+            ..Default::default()
+        };
+        let sel = select_in("single", args).unwrap();
+        let dir = box_dir(&root, &sel);
+        write(&dir, &render(&sel, &cfg())).unwrap();
+        let scheme = dir.join("box.xcodeproj/xcshareddata/xcschemes/box.xcscheme");
+        assert!(scheme.exists());
+        let xcconfig = fs::read_to_string(dir.join("box.xcconfig")).unwrap();
+        assert!(xcconfig.contains("BOX_ID = tool\n"));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,292 @@
+//! `cargo box teams`, development teams of code signing certificates in keychain.
+
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Format;
+
+/// Seconds between 1970 and 2001, `kCFAbsoluteTimeIntervalSince1970`.
+const ABS_TIME_INTERVAL_SINCE_1970: f64 = 978307200.0;
+
+/// Subject fields of code signing certificate.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct CertInfo {
+    /// Organizational unit.
+    pub(crate) team_id: String,
+    /// Organization.
+    pub(crate) team_name: String,
+    /// Absolute time, seconds since 2001.
+    pub(crate) not_after: Option<f64>,
+}
+
+/// Source of code signing certificates.
+pub(crate) trait Identities {
+    fn certs(&self) -> Vec<CertInfo>;
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Team {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    /// Latest expiration among team certificates, RFC 3339 in UTC.
+    pub(crate) expires: Option<String>,
+}
+
+/// Teams in order of first certificate.
+pub(crate) fn teams(certs: Vec<CertInfo>) -> Vec<Team> {
+    let mut res: Vec<(CertInfo, Option<f64>)> = Vec::new();
+    for cert in certs {
+        if let Some((_, not_after)) = res.iter_mut().find(|(c, _)| c.team_id == cert.team_id) {
+            *not_after = match (*not_after, cert.not_after) {
+                (Some(a), Some(b)) => Some(a.max(b)),
+                (a, b) => a.or(b),
+            };
+            continue;
+        }
+        let not_after = cert.not_after;
+        res.push((cert, not_after));
+    }
+    res.into_iter()
+        .map(|(cert, not_after)| Team {
+            id: cert.team_id,
+            name: cert.team_name,
+            expires: not_after.map(rfc3339),
+        })
+        .collect()
+}
+
+/// Formats absolute time as `2025-03-01T12:30:00Z`.
+fn rfc3339(abs_time: f64) -> String {
+    let secs = (abs_time + ABS_TIME_INTERVAL_SINCE_1970).floor() as i64;
+    let (days, secs) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // days to civil date, http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+pub(crate) fn write(
+    teams: &[Team],
+    format: Format,
+    out: &mut impl Write,
+) -> serde_json::Result<()> {
+    match format {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, teams)?;
+            writeln!(out).map_err(serde_json::Error::io)
+        }
+        Format::Text => write_text(teams, out).map_err(serde_json::Error::io),
+    }
+}
+
+fn write_text(teams: &[Team], out: &mut impl Write) -> std::io::Result<()> {
+    if teams.is_empty() {
+        return writeln!(out, "no teams are found");
+    }
+    for team in teams {
+        write!(out, "{}: {}", team.id, team.name)?;
+        if let Some(expires) = &team.expires {
+            write!(out, " (expires {})", &expires[..10])?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn list(
+    src: &impl Identities,
+    format: Format,
+    out: &mut impl Write,
+) -> serde_json::Result<()> {
+    write(&teams(src.certs()), format, out)
+}
+
+/// Apple code signing certificates from keychain.
+#[cfg(target_os = "macos")]
+pub(crate) struct Keychain;
+
+#[cfg(target_os = "macos")]
+impl Identities for Keychain {
+    fn certs(&self) -> Vec<CertInfo> {
+        use cidre::{arc, cf, sec};
+
+        let query = cf::DictionaryOf::with_keys_values(
+            &[
+                sec::class_key(),
+                sec::match_keys::limit(),
+                sec::match_keys::policy(),
+            ],
+            &[
+                sec::class::certificate().as_type_ref(),
+                sec::match_limit::all(),
+                &sec::Policy::with_props(sec::Policy::apple_code_signing(), None).unwrap(),
+            ],
+        );
+
+        // no certs is an error
+        let Ok(certs) = sec::item_matching(&query) else {
+            return vec![];
+        };
+
+        assert_eq!(certs.get_type_id(), cf::Array::type_id());
+        let certs: arc::R<cf::ArrayOf<sec::Cert>> = unsafe { std::mem::transmute(certs) };
+
+        let subject_key = sec::cert_oids::x509_v1_subject_name();
+        let not_after_key = sec::cert_oids::x509_v1_validity_not_after();
+        let org_name_label = sec::cert_oids::organization_name();
+        let unit_name_label = sec::cert_oids::organizational_unit_name();
+        let prop_value_key = sec::prop_keys::value();
+        let prop_label_key = sec::prop_keys::label();
+        let keys = cf::ArrayOf::from_slice(&[subject_key, not_after_key]);
+        let mut res = vec![];
+        for cert in certs.iter() {
+            let Ok(vals) = cert.values(&keys) else {
+                continue;
+            };
+            let Some(value) = vals.get(subject_key) else {
+                continue;
+            };
+            let Some(section) = value.get(prop_value_key) else {
+                continue;
+            };
+            assert_eq!(section.get_type_id(), cf::Array::type_id());
+
+            let section: &cf::ArrayOf<cf::DictionaryOf<cf::String, cf::Type>> =
+                unsafe { std::mem::transmute(section) };
+
+            let mut team_id = None;
+            let mut team_name = None;
+            for dict in section.iter() {
+                let Some(label) = dict.get(prop_label_key) else {
+                    continue;
+                };
+                let Some(value) = dict.get(prop_value_key) else {
+                    continue;
+                };
+
+                // todo: build safer api
+                if value.get_type_id() != cf::String::type_id() {
+                    continue;
+                }
+                let value: &cf::String = unsafe { std::mem::transmute(value) };
+
+                if label.equal(org_name_label) {
+                    team_name = Some(value.to_string());
+                } else if label.equal(unit_name_label) {
+                    team_id = Some(value.to_string());
+                }
+            }
+
+            let not_after = vals
+                .get(not_after_key)
+                .and_then(|v| v.get(prop_value_key))
+                .and_then(|v| {
+                    if v.get_type_id() == cf::Number::type_id() {
+                        let v: &cf::Number = unsafe { std::mem::transmute(v) };
+                        v.to_f64()
+                    } else if v.get_type_id() == cf::Date::type_id() {
+                        let v: &cf::Date = unsafe { std::mem::transmute(v) };
+                        Some(v.abs_time())
+                    } else {
+                        None
+                    }
+                });
+
+            if let (Some(team_id), Some(team_name)) = (team_id, team_name) {
+                res.push(CertInfo {
+                    team_id,
+                    team_name,
+                    not_after,
+                });
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Recorded keychain certificates.
+    struct Recorded(&'static str);
+
+    impl Identities for Recorded {
+        fn certs(&self) -> Vec<CertInfo> {
+            serde_json::from_str(self.0).unwrap()
+        }
+    }
+
+    const CERTS: Recorded = Recorded(include_str!("../tests/fixtures/keychain/certs.json"));
+
+    fn output(src: &impl Identities, format: Format) -> String {
+        let mut out = Vec::new();
+        list(src, format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(rfc3339(0.0), "2001-01-01T00:00:00Z");
+        assert_eq!(rfc3339(-1.0), "2000-12-31T23:59:59Z");
+        assert_eq!(rfc3339(36_115_200.0 + 3_723.5), "2002-02-23T01:02:03Z");
+        // leap day
+        assert_eq!(rfc3339(762_393_600.0), "2025-02-28T00:00:00Z");
+        assert_eq!(rfc3339(730_857_600.0), "2024-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn dedup() {
+        let teams = teams(CERTS.certs());
+        assert_eq!(
+            teams,
+            [
+                Team {
+                    id: "FD6ZML48V9".to_string(),
+                    name: "Yury Korolev".to_string(),
+                    expires: Some("2025-11-20T09:12:44Z".to_string()),
+                },
+                Team {
+                    id: "A1B2C3D4E5".to_string(),
+                    name: "Cidre Inc.".to_string(),
+                    expires: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(
+            output(&CERTS, Format::Text),
+            "FD6ZML48V9: Yury Korolev (expires 2025-11-20)\nA1B2C3D4E5: Cidre Inc.\n"
+        );
+        let json: serde_json::Value = serde_json::from_str(&output(&CERTS, Format::Json)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"id": "FD6ZML48V9", "name": "Yury Korolev", "expires": "2025-11-20T09:12:44Z"},
+                {"id": "A1B2C3D4E5", "name": "Cidre Inc.", "expires": null},
+            ])
+        );
+
+        let empty = Recorded("[]");
+        assert_eq!(output(&empty, Format::Text), "no teams are found\n");
+        assert_eq!(output(&empty, Format::Json), "[]\n");
+    }
+}
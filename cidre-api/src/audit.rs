@@ -0,0 +1,160 @@
+//! Lists APIs that stop being safe or present for a deployment target set.
+
+use std::{fmt::Write, str::FromStr};
+
+use crate::manifest::{Entry, Fallback, Kind, Platform, Version};
+
+/// Deployment target as spelled by cargo features, e.g. `macos_13_0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Target {
+    pub platform: Platform,
+    pub version: Version,
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((platform, version)) = s.split_once('_') else {
+            return Err(format!("expected target like `macos_13_0`, found `{s}`"));
+        };
+        Ok(Self {
+            platform: platform.parse()?,
+            version: version.parse()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Finding<'a> {
+    pub entry: &'a Entry,
+    /// `None` if the item doesn't exist on the platform at all.
+    pub introduced: Option<Version>,
+    pub fallback: Fallback,
+}
+
+/// Entries introduced after `target`. With `all`, entries that are not
+/// available on the target platform are reported as missing as well.
+pub fn audit(entries: &[Entry], target: Target, all: bool) -> Vec<Finding<'_>> {
+    entries
+        .iter()
+        .filter_map(|entry| match entry.introduced(target.platform) {
+            Some(v) if v > target.version => Some(Finding {
+                entry,
+                introduced: Some(v),
+                fallback: entry.fallback,
+            }),
+            Some(_) => None,
+            None if all => Some(Finding {
+                entry,
+                introduced: None,
+                fallback: Fallback::Missing,
+            }),
+            None => None,
+        })
+        .collect()
+}
+
+pub fn report(entries: &[Entry], targets: &[Target], all: bool) -> String {
+    let mut out = String::new();
+    for target in targets {
+        let findings = audit(entries, *target, all);
+        let count = |f| findings.iter().filter(|x| x.fallback == f).count();
+        writeln!(
+            out,
+            "{} {}: {} unsafe, {} optional, {} panics, {} missing",
+            target.platform,
+            target.version,
+            count(Fallback::Unsafe),
+            count(Fallback::Optional),
+            count(Fallback::Panics),
+            count(Fallback::Missing),
+        )
+        .unwrap();
+        for f in &findings {
+            let e = f.entry;
+            let symbol = match (&e.symbol, e.kind) {
+                (Some(sel), Kind::Selector) => format!(" -[{sel}]"),
+                (Some(sym), _) => format!(" {sym}"),
+                (None, _) => String::new(),
+            };
+            let introduced = match f.introduced {
+                Some(v) => format!("{} {v}", target.platform),
+                None => format!("not on {}", target.platform),
+            };
+            writeln!(
+                out,
+                "  {:<8} {}{symbol} ({introduced}) {}:{}",
+                f.fallback.as_str(),
+                e.item,
+                e.file,
+                e.line
+            )
+            .unwrap();
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(
+        item: &str,
+        kind: Kind,
+        fallback: Fallback,
+        available: &[(Platform, Version)],
+    ) -> Entry {
+        Entry {
+            item: item.to_string(),
+            kind,
+            symbol: Some(item.to_string()),
+            file: "a.rs".to_string(),
+            line: 1,
+            weak: fallback == Fallback::Optional,
+            fallback,
+            available: available.to_vec(),
+        }
+    }
+
+    #[test]
+    fn targets() {
+        let t: Target = "maccatalyst_16_1".parse().unwrap();
+        assert_eq!(t.platform, Platform::MacCatalyst);
+        assert_eq!(t.version, Version::new(16, 1));
+        assert!("macos".parse::<Target>().is_err());
+        assert!("linux_1_0".parse::<Target>().is_err());
+    }
+
+    #[test]
+    fn findings() {
+        let mac = |major| (Platform::MacOs, Version::new(major, 0));
+        let ios = |major| (Platform::Ios, Version::new(major, 0));
+        let entries = [
+            entry("a", Kind::Selector, Fallback::Unsafe, &[mac(14), ios(17)]),
+            entry("b", Kind::Static, Fallback::Optional, &[mac(13)]),
+            entry("c", Kind::ExternFn, Fallback::Missing, &[mac(15)]),
+        ];
+        let target = "macos_13_0".parse().unwrap();
+        let items = |all| -> Vec<_> {
+            audit(&entries, target, all)
+                .iter()
+                .map(|f| f.entry.item.as_str())
+                .collect()
+        };
+        assert_eq!(items(false), ["a", "c"]);
+
+        let target = "ios_16_0".parse().unwrap();
+        let findings = audit(&entries, target, true);
+        assert_eq!(findings.len(), 3);
+        assert_eq!(findings[1].introduced, None);
+        assert_eq!(findings[1].fallback, Fallback::Missing);
+
+        let report = report(&entries, &[target], false);
+        assert_eq!(
+            report,
+            "ios 16.0: 1 unsafe, 0 optional, 0 panics, 0 missing\n  unsafe   a -[a] (ios 17.0) a.rs:1\n"
+        );
+    }
+}
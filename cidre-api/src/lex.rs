@@ -0,0 +1,359 @@
+//! Minimal Rust lexer. It knows just enough about the language to skip comments
+//! and literals and to build nested token groups, so item walking never
+//! trips on braces inside strings or doc comments.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delim {
+    Paren,
+    Bracket,
+    Brace,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tt {
+    Ident(String),
+    Punct(char),
+    Lit(String),
+    Group(Delim, Vec<Token>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub tt: Tt,
+    pub line: usize,
+}
+
+impl Token {
+    pub fn ident(&self) -> Option<&str> {
+        match &self.tt {
+            Tt::Ident(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn is_punct(&self, c: char) -> bool {
+        self.tt == Tt::Punct(c)
+    }
+
+    pub fn group(&self, delim: Delim) -> Option<&[Token]> {
+        match &self.tt {
+            Tt::Group(d, tokens) if *d == delim => Some(tokens),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub line: usize,
+    pub msg: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.msg)
+    }
+}
+
+impl std::error::Error for Error {}
+
+struct Lexer {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+}
+
+impl Lexer {
+    fn peek(&self, n: usize) -> Option<char> {
+        self.chars.get(self.pos + n).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.get(self.pos).copied()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn err<T>(&self, line: usize, msg: impl Into<String>) -> Result<T, Error> {
+        Err(Error {
+            line,
+            msg: msg.into(),
+        })
+    }
+
+    fn skip_block_comment(&mut self) -> Result<(), Error> {
+        let line = self.line;
+        self.pos += 2;
+        let mut depth = 1;
+        while depth > 0 {
+            match (self.peek(0), self.peek(1)) {
+                (Some('/'), Some('*')) => {
+                    self.pos += 2;
+                    depth += 1;
+                }
+                (Some('*'), Some('/')) => {
+                    self.pos += 2;
+                    depth -= 1;
+                }
+                (Some(_), _) => {
+                    self.bump();
+                }
+                (None, _) => return self.err(line, "unterminated block comment"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a quoted literal starting at the opening quote, honoring escapes.
+    fn quoted(&mut self, quote: char, out: &mut String) -> Result<(), Error> {
+        let line = self.line;
+        out.push(self.bump().unwrap());
+        loop {
+            match self.bump() {
+                Some('\\') => {
+                    out.push('\\');
+                    match self.bump() {
+                        Some(c) => out.push(c),
+                        None => break,
+                    }
+                }
+                Some(c) if c == quote => {
+                    out.push(c);
+                    return Ok(());
+                }
+                Some(c) => out.push(c),
+                None => break,
+            }
+        }
+        self.err(line, "unterminated literal")
+    }
+
+    /// Reads `r#"..."#` starting at the first `#` or `"`.
+    fn raw_str(&mut self, out: &mut String) -> Result<(), Error> {
+        let line = self.line;
+        let mut hashes = 0;
+        while self.peek(0) == Some('#') {
+            out.push('#');
+            self.pos += 1;
+            hashes += 1;
+        }
+        if self.peek(0) != Some('"') {
+            return self.err(line, "expected `\"` in raw string literal");
+        }
+        out.push(self.bump().unwrap());
+        loop {
+            match self.bump() {
+                Some('"') if (0..hashes).all(|i| self.peek(i) == Some('#')) => {
+                    out.push('"');
+                    for _ in 0..hashes {
+                        out.push('#');
+                    }
+                    self.pos += hashes;
+                    return Ok(());
+                }
+                Some(c) => out.push(c),
+                None => return self.err(line, "unterminated raw string literal"),
+            }
+        }
+    }
+
+    fn ident(&mut self) -> String {
+        let mut s = String::new();
+        while let Some(c) = self.peek(0) {
+            if c == '_' || c.is_alphanumeric() {
+                s.push(c);
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        s
+    }
+
+    fn number(&mut self) -> String {
+        let mut s = String::new();
+        while let Some(c) = self.peek(0) {
+            let fraction = c == '.' && self.peek(1).is_some_and(|c| c.is_ascii_digit());
+            if c == '_' || c.is_ascii_alphanumeric() || fraction {
+                s.push(c);
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        s
+    }
+
+    fn tokens(&mut self) -> Result<Vec<Token>, Error> {
+        // open delimiter with its line and the tokens collected so far
+        let mut stack: Vec<(Delim, usize, Vec<Token>)> = vec![];
+        let mut cur = vec![];
+
+        while let Some(c) = self.peek(0) {
+            let line = self.line;
+            let tt = match c {
+                c if c.is_whitespace() => {
+                    self.bump();
+                    continue;
+                }
+                '/' if self.peek(1) == Some('/') => {
+                    while self.peek(0).is_some_and(|c| c != '\n') {
+                        self.pos += 1;
+                    }
+                    continue;
+                }
+                '/' if self.peek(1) == Some('*') => {
+                    self.skip_block_comment()?;
+                    continue;
+                }
+                '"' => {
+                    let mut s = String::new();
+                    self.quoted('"', &mut s)?;
+                    Tt::Lit(s)
+                }
+                '\'' => {
+                    // 'a' and '\n' are chars, anything else starts a lifetime
+                    if self.peek(1) == Some('\\') || self.peek(2) == Some('\'') {
+                        let mut s = String::new();
+                        self.quoted('\'', &mut s)?;
+                        Tt::Lit(s)
+                    } else {
+                        self.pos += 1;
+                        Tt::Punct('\'')
+                    }
+                }
+                '(' | '[' | '{' => {
+                    self.pos += 1;
+                    let delim = match c {
+                        '(' => Delim::Paren,
+                        '[' => Delim::Bracket,
+                        _ => Delim::Brace,
+                    };
+                    stack.push((delim, line, std::mem::take(&mut cur)));
+                    continue;
+                }
+                ')' | ']' | '}' => {
+                    self.pos += 1;
+                    let Some((delim, open_line, parent)) = stack.pop() else {
+                        return self.err(line, format!("unexpected `{c}`"));
+                    };
+                    let expected = match delim {
+                        Delim::Paren => ')',
+                        Delim::Bracket => ']',
+                        Delim::Brace => '}',
+                    };
+                    if c != expected {
+                        return self.err(line, format!("expected `{expected}`, found `{c}`"));
+                    }
+                    let inner = std::mem::replace(&mut cur, parent);
+                    cur.push(Token {
+                        tt: Tt::Group(delim, inner),
+                        line: open_line,
+                    });
+                    continue;
+                }
+                c if c.is_ascii_digit() => Tt::Lit(self.number()),
+                c if c == '_' || c.is_alphabetic() => {
+                    let s = self.ident();
+                    let next = self.peek(0);
+                    match (s.as_str(), next) {
+                        ("r", Some('#')) if self.peek(1).is_some_and(|c| c != '"' && c != '#') => {
+                            self.pos += 1;
+                            Tt::Ident(self.ident())
+                        }
+                        ("r" | "br" | "cr", Some('#' | '"')) => {
+                            let mut lit = s;
+                            self.raw_str(&mut lit)?;
+                            Tt::Lit(lit)
+                        }
+                        ("b" | "c", Some('"')) | ("b", Some('\'')) => {
+                            let mut lit = s;
+                            self.quoted(next.unwrap(), &mut lit)?;
+                            Tt::Lit(lit)
+                        }
+                        _ => Tt::Ident(s),
+                    }
+                }
+                c => {
+                    self.pos += 1;
+                    Tt::Punct(c)
+                }
+            };
+            cur.push(Token { tt, line });
+        }
+
+        if let Some((_, line, _)) = stack.pop() {
+            return self.err(line, "unclosed delimiter");
+        }
+        Ok(cur)
+    }
+}
+
+/// Splits source into token trees. Comments (including doc comments) are dropped.
+pub fn parse(src: &str) -> Result<Vec<Token>, Error> {
+    Lexer {
+        chars: src.chars().collect(),
+        pos: 0,
+        line: 1,
+    }
+    .tokens()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn idents(tokens: &[Token]) -> Vec<String> {
+        let mut res = vec![];
+        for t in tokens {
+            match &t.tt {
+                Tt::Ident(s) => res.push(s.clone()),
+                Tt::Group(_, inner) => res.extend(idents(inner)),
+                _ => {}
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn basics() {
+        let src = r####"
+            /// doc { with brace
+            fn a<'a>(x: &'a str) -> char { 'x' } /* nested /* } */ */
+            const S: &str = r#"raw " } "#;
+            const B: &[u8] = b"}\"";
+            const C: char = '\'';
+            let v = 14.0;
+            let t = x.0;
+        "####;
+        let tokens = parse(src).unwrap();
+        assert_eq!(
+            idents(&tokens),
+            [
+                "fn", "a", "a", "x", "a", "str", "char", "const", "S", "str", "const", "B", "u8",
+                "const", "C", "char", "let", "v", "let", "t", "x"
+            ]
+        );
+        let f = tokens.iter().find(|t| t.ident() == Some("fn")).unwrap();
+        assert_eq!(f.line, 3);
+        let v = tokens.iter().find(|t| t.tt == Tt::Lit("14.0".into()));
+        assert_eq!(v.unwrap().line, 7);
+        assert!(
+            tokens
+                .iter()
+                .any(|t| t.tt == Tt::Lit("r#\"raw \" } \"#".into()))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse("fn a() {").unwrap_err().line, 1);
+        assert_eq!(parse("\n(]").unwrap_err().msg, "expected `)`, found `]`");
+        assert!(parse("\"abc").is_err());
+    }
+}
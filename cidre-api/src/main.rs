@@ -0,0 +1,124 @@
+//! API availability manifest for cidre.
+//!
+//! Collects every item annotated with `#[api::available(..)]` or
+//! `#[objc::available(..)]` together with its selector or symbol, per platform
+//! introduction version and whether it is weak-linked. The audit lists APIs that
+//! become `unsafe`, optional or missing for a deployment target set, so feature
+//! bumps can be reviewed before they land.
+//!
+//! ```sh
+//! cargo run -p cidre-api -- manifest -o api.json
+//! cargo run -p cidre-api -- audit macos_13_0 ios_16_0 --manifest api.json
+//! ```
+
+mod audit;
+mod lex;
+mod manifest;
+mod scan;
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+usage:
+  cidre-api manifest [--src <dir>] [-o <file>]
+  cidre-api audit <target>... [--src <dir> | --manifest <file>] [--all]
+
+targets are spelled like cargo features: macos_13_0, ios_16_0, maccatalyst_16_0, ...
+--src defaults to cidre/src next to this crate
+--all also reports items that don't exist on the target platform";
+
+#[derive(Default)]
+struct Args {
+    cmd: String,
+    src: Option<PathBuf>,
+    manifest: Option<PathBuf>,
+    out: Option<PathBuf>,
+    targets: Vec<audit::Target>,
+    all: bool,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut res = Args {
+        cmd: args.next().ok_or("missing command")?,
+        ..Default::default()
+    };
+    if !matches!(res.cmd.as_str(), "manifest" | "audit") {
+        return Err(format!("unknown command `{}`", res.cmd));
+    }
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .map(PathBuf::from)
+                .ok_or_else(|| format!("`{name}` expects a value"))
+        };
+        match arg.as_str() {
+            "--src" => res.src = Some(value("--src")?),
+            "--manifest" if res.cmd == "audit" => res.manifest = Some(value("--manifest")?),
+            "-o" | "--out" if res.cmd == "manifest" => res.out = Some(value("--out")?),
+            "--all" if res.cmd == "audit" => res.all = true,
+            "-h" | "--help" => return Err(String::new()),
+            a if a.starts_with('-') => return Err(format!("unexpected option `{a}`")),
+            a if res.cmd == "audit" => res.targets.push(a.parse()?),
+            a => return Err(format!("unexpected argument `{a}`")),
+        }
+    }
+    if res.cmd == "audit" && res.targets.is_empty() {
+        return Err("expected at least one target".to_string());
+    }
+    if res.src.is_some() && res.manifest.is_some() {
+        return Err("`--src` and `--manifest` are exclusive".to_string());
+    }
+    Ok(res)
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let entries = match &args.manifest {
+        Some(path) => {
+            let json =
+                std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+            manifest::from_json(&json).map_err(|e| format!("{}: {e}", path.display()))?
+        }
+        None => {
+            let default_src = Path::new(env!("CARGO_MANIFEST_DIR")).join("../cidre/src");
+            let src = args.src.unwrap_or(default_src);
+            scan::scan_dir(&src).map_err(|e| format!("{}: {e}", src.display()))?
+        }
+    };
+
+    let out = if args.cmd == "manifest" {
+        manifest::to_json(&entries)
+    } else {
+        audit::report(&entries, &args.targets, args.all)
+    };
+
+    match args.out {
+        Some(path) => std::fs::write(&path, out).map_err(|e| format!("{}: {e}", path.display())),
+        None => {
+            print!("{out}");
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            if !e.is_empty() {
+                eprintln!("error: {e}\n");
+            }
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,548 @@
+//! Manifest model and its JSON form.
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "entries": [
+//!     {
+//!       "item": "av::CaptureDevice::is_center_stage_enabled",
+//!       "kind": "selector",
+//!       "selector": "isCenterStageEnabled",
+//!       "file": "av/capture/device.rs",
+//!       "line": 321,
+//!       "weak": false,
+//!       "fallback": "unsafe",
+//!       "available": { "macos": "12.3", "ios": "14.5" }
+//!     }
+//!   ]
+//! }
+//! ```
+
+use std::{fmt, str::FromStr};
+
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Platform {
+    MacOs,
+    Ios,
+    MacCatalyst,
+    TvOs,
+    WatchOs,
+    VisionOs,
+}
+
+impl Platform {
+    pub const ALL: [Self; 6] = [
+        Self::MacOs,
+        Self::Ios,
+        Self::MacCatalyst,
+        Self::TvOs,
+        Self::WatchOs,
+        Self::VisionOs,
+    ];
+
+    /// Name as used by `#[api::available(..)]` and cargo features.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::MacOs => "macos",
+            Self::Ios => "ios",
+            Self::MacCatalyst => "maccatalyst",
+            Self::TvOs => "tvos",
+            Self::WatchOs => "watchos",
+            Self::VisionOs => "visionos",
+        }
+    }
+}
+
+impl FromStr for Platform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.as_str() == s)
+            .ok_or_else(|| format!("unknown platform `{s}`"))
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl Version {
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl FromStr for Version {
+    type Err = String;
+
+    /// Accepts `14`, `14.0` and `14_0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid version `{s}`");
+        let (major, minor) = match s.split_once(['.', '_']) {
+            Some((major, minor)) => (major, minor),
+            None => (s, "0"),
+        };
+        Ok(Self::new(
+            major.parse().map_err(|_| err())?,
+            minor.parse().map_err(|_| err())?,
+        ))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    /// `#[objc::msg_send(..)]` method.
+    Selector,
+    /// Rust fn with a body.
+    Fn,
+    /// Function declared in an `extern` block.
+    ExternFn,
+    /// Static declared in an `extern` block.
+    Static,
+    /// Objective-C class declared with `define_cls!` or `define_obj_type!`.
+    Class,
+}
+
+impl Kind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Selector => "selector",
+            Self::Fn => "fn",
+            Self::ExternFn => "extern_fn",
+            Self::Static => "static",
+            Self::Class => "class",
+        }
+    }
+}
+
+impl FromStr for Kind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "selector" => Self::Selector,
+            "fn" => Self::Fn,
+            "extern_fn" => Self::ExternFn,
+            "static" => Self::Static,
+            "class" => Self::Class,
+            _ => return Err(format!("unknown kind `{s}`")),
+        })
+    }
+}
+
+/// What an item turns into when the deployment target is below its introduction version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Fallback {
+    /// Still callable but `unsafe`; the caller has to check availability.
+    Unsafe,
+    /// Resolved at runtime and exposed as `Option`.
+    Optional,
+    /// Stays safe but unwraps the weak symbol, so it panics when the symbol is missing.
+    Panics,
+    /// Compiled out.
+    Missing,
+}
+
+impl Fallback {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Unsafe => "unsafe",
+            Self::Optional => "optional",
+            Self::Panics => "panics",
+            Self::Missing => "missing",
+        }
+    }
+}
+
+impl FromStr for Fallback {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "unsafe" => Self::Unsafe,
+            "optional" => Self::Optional,
+            "panics" => Self::Panics,
+            "missing" => Self::Missing,
+            _ => return Err(format!("unknown fallback `{s}`")),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Rust path relative to the crate root, e.g. `av::CaptureDevice::new`.
+    pub item: String,
+    pub kind: Kind,
+    /// Selector for [`Kind::Selector`], symbol or class name for the rest.
+    pub symbol: Option<String>,
+    /// Path relative to the scanned source root.
+    pub file: String,
+    pub line: usize,
+    pub weak: bool,
+    pub fallback: Fallback,
+    /// Introduction version per platform. Platforms not listed don't have the item.
+    pub available: Vec<(Platform, Version)>,
+}
+
+impl Entry {
+    pub fn introduced(&self, platform: Platform) -> Option<Version> {
+        self.available
+            .iter()
+            .find(|(p, _)| *p == platform)
+            .map(|(_, v)| *v)
+    }
+}
+
+fn write_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+pub fn to_json(entries: &[Entry]) -> String {
+    let mut out = format!("{{\n  \"version\": {FORMAT_VERSION},\n  \"entries\": [");
+    for (i, e) in entries.iter().enumerate() {
+        out.push_str(if i == 0 { "\n" } else { ",\n" });
+        out.push_str("    {\"item\": ");
+        write_str(&mut out, &e.item);
+        out.push_str(", \"kind\": ");
+        write_str(&mut out, e.kind.as_str());
+        if let Some(symbol) = &e.symbol {
+            out.push_str(match e.kind {
+                Kind::Selector => ", \"selector\": ",
+                _ => ", \"symbol\": ",
+            });
+            write_str(&mut out, symbol);
+        }
+        out.push_str(", \"file\": ");
+        write_str(&mut out, &e.file);
+        out.push_str(&format!(
+            ", \"line\": {}, \"weak\": {}, \"fallback\": \"{}\", \"available\": {{",
+            e.line,
+            e.weak,
+            e.fallback.as_str()
+        ));
+        for (j, (p, v)) in e.available.iter().enumerate() {
+            if j > 0 {
+                out.push_str(", ");
+            }
+            out.push_str(&format!("\"{p}\": \"{v}\""));
+        }
+        out.push_str("}}");
+    }
+    if !entries.is_empty() {
+        out.push_str("\n  ");
+    }
+    out.push_str("]\n}\n");
+    out
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Null,
+    Bool(bool),
+    Num(f64),
+    Str(String),
+    Arr(Vec<Value>),
+    Obj(Vec<(String, Value)>),
+}
+
+impl Value {
+    fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Obj(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+struct Reader<'a> {
+    src: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn err<T>(&self, msg: &str) -> Result<T, String> {
+        Err(format!("{msg} at byte {}", self.pos))
+    }
+
+    fn ws(&mut self) {
+        while self
+            .src
+            .get(self.pos)
+            .is_some_and(|c| c.is_ascii_whitespace())
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        self.ws();
+        if self.src.get(self.pos) == Some(&c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), String> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            self.err(&format!("expected `{}`", c as char))
+        }
+    }
+
+    fn keyword(&mut self, kw: &str, val: Value) -> Result<Value, String> {
+        if self.src[self.pos..].starts_with(kw.as_bytes()) {
+            self.pos += kw.len();
+            Ok(val)
+        } else {
+            self.err("unexpected token")
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut bytes = vec![];
+        loop {
+            let Some(&c) = self.src.get(self.pos) else {
+                return self.err("unterminated string");
+            };
+            self.pos += 1;
+            match c {
+                b'"' => break,
+                b'\\' => {
+                    let Some(&e) = self.src.get(self.pos) else {
+                        return self.err("unterminated string");
+                    };
+                    self.pos += 1;
+                    let c = match e {
+                        b'n' => '\n',
+                        b't' => '\t',
+                        b'r' => '\r',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'u' => {
+                            let hex = self.src.get(self.pos..self.pos + 4);
+                            let code = hex
+                                .and_then(|h| std::str::from_utf8(h).ok())
+                                .and_then(|h| u32::from_str_radix(h, 16).ok());
+                            self.pos += 4;
+                            match code.and_then(char::from_u32) {
+                                Some(c) => c,
+                                None => return self.err("invalid unicode escape"),
+                            }
+                        }
+                        e => e as char,
+                    };
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                }
+                c => bytes.push(c),
+            }
+        }
+        String::from_utf8(bytes).or_else(|_| self.err("invalid utf-8"))
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.ws();
+        match self.src.get(self.pos) {
+            Some(b'{') => {
+                self.pos += 1;
+                let mut fields = vec![];
+                if !self.eat(b'}') {
+                    loop {
+                        self.ws();
+                        let key = self.string()?;
+                        self.expect(b':')?;
+                        fields.push((key, self.value()?));
+                        if self.eat(b'}') {
+                            break;
+                        }
+                        self.expect(b',')?;
+                    }
+                }
+                Ok(Value::Obj(fields))
+            }
+            Some(b'[') => {
+                self.pos += 1;
+                let mut items = vec![];
+                if !self.eat(b']') {
+                    loop {
+                        items.push(self.value()?);
+                        if self.eat(b']') {
+                            break;
+                        }
+                        self.expect(b',')?;
+                    }
+                }
+                Ok(Value::Arr(items))
+            }
+            Some(b'"') => Ok(Value::Str(self.string()?)),
+            Some(b't') => self.keyword("true", Value::Bool(true)),
+            Some(b'f') => self.keyword("false", Value::Bool(false)),
+            Some(b'n') => self.keyword("null", Value::Null),
+            Some(c) if *c == b'-' || c.is_ascii_digit() => {
+                let start = self.pos;
+                while self
+                    .src
+                    .get(self.pos)
+                    .is_some_and(|c| b"+-.eE".contains(c) || c.is_ascii_digit())
+                {
+                    self.pos += 1;
+                }
+                let s = std::str::from_utf8(&self.src[start..self.pos]).unwrap();
+                match s.parse() {
+                    Ok(n) => Ok(Value::Num(n)),
+                    Err(_) => self.err("invalid number"),
+                }
+            }
+            _ => self.err("unexpected token"),
+        }
+    }
+}
+
+fn entry(v: &Value) -> Result<Entry, String> {
+    let str_field = |key: &str| {
+        v.get(key)
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("entry is missing `{key}`"))
+    };
+    let item = str_field("item")?.to_string();
+    let kind: Kind = str_field("kind")?.parse()?;
+    let symbol = v
+        .get("selector")
+        .or_else(|| v.get("symbol"))
+        .and_then(Value::as_str)
+        .map(String::from);
+    let line = match v.get("line") {
+        Some(Value::Num(n)) => *n as usize,
+        _ => 0,
+    };
+    let weak = matches!(v.get("weak"), Some(Value::Bool(true)));
+    let fallback = str_field("fallback")?.parse()?;
+    let mut available = vec![];
+    if let Some(Value::Obj(fields)) = v.get("available") {
+        for (k, ver) in fields {
+            let ver = ver
+                .as_str()
+                .ok_or_else(|| format!("`{item}`: version for `{k}` must be a string"))?;
+            available.push((k.parse()?, ver.parse()?));
+        }
+    }
+    Ok(Entry {
+        item,
+        kind,
+        symbol,
+        file: str_field("file")?.to_string(),
+        line,
+        weak,
+        fallback,
+        available,
+    })
+}
+
+pub fn from_json(src: &str) -> Result<Vec<Entry>, String> {
+    let mut reader = Reader {
+        src: src.as_bytes(),
+        pos: 0,
+    };
+    let root = reader.value()?;
+    reader.ws();
+    if reader.pos != reader.src.len() {
+        return reader.err("trailing characters");
+    }
+    match root.get("version") {
+        Some(Value::Num(n)) if *n == FORMAT_VERSION as f64 => {}
+        _ => return Err(format!("expected manifest version {FORMAT_VERSION}")),
+    }
+    match root.get("entries") {
+        Some(Value::Arr(items)) => items.iter().map(entry).collect(),
+        _ => Err("manifest is missing `entries`".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version() {
+        assert_eq!("14.0".parse(), Ok(Version::new(14, 0)));
+        assert_eq!("13_3".parse(), Ok(Version::new(13, 3)));
+        assert_eq!("2".parse(), Ok(Version::new(2, 0)));
+        assert!("x.1".parse::<Version>().is_err());
+        assert!(Version::new(10, 15) < Version::new(11, 0));
+        assert_eq!(Version::new(10, 15).to_string(), "10.15");
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let entries = vec![
+            Entry {
+                item: "ns::Obj::desc".into(),
+                kind: Kind::Selector,
+                symbol: Some("description".into()),
+                file: "ns/objc_runtime.rs".into(),
+                line: 12,
+                weak: false,
+                fallback: Fallback::Unsafe,
+                available: vec![
+                    (Platform::MacOs, Version::new(14, 0)),
+                    (Platform::Ios, Version::new(17, 0)),
+                ],
+            },
+            Entry {
+                item: "cm::tag_\"quoted\"".into(),
+                kind: Kind::Static,
+                symbol: Some("kCMTagInvalid".into()),
+                file: "cm/tag.rs".into(),
+                line: 3,
+                weak: true,
+                fallback: Fallback::Optional,
+                available: vec![(Platform::VisionOs, Version::new(1, 0))],
+            },
+        ];
+        let json = to_json(&entries);
+        assert_eq!(from_json(&json).unwrap(), entries);
+        assert_eq!(from_json(&to_json(&[])).unwrap(), []);
+        assert!(from_json("{\"version\": 2, \"entries\": []}").is_err());
+        assert!(from_json("{\"version\": 1, \"entries\": [}").is_err());
+    }
+}
@@ -0,0 +1,589 @@
+//! Walks cidre sources and collects items annotated with `#[api::available(..)]`
+//! or `#[objc::available(..)]`.
+//!
+//! Fallbacks mirror what the macros generate when the deployment target is below
+//! the introduction version:
+//!
+//! - `#[objc::msg_send]` methods and fns with a body become `unsafe`;
+//! - fns whose body starts with a `{}` scope return `Option`;
+//! - `define_cls!` and `define_obj_type!` classes become weak (`Option`);
+//! - extern fns and statics in an `#[api::weak]` block are resolved with `dlsym`,
+//!   all other extern items are compiled out.
+
+use std::{fs, io, path::Path};
+
+use crate::{
+    lex::{self, Delim, Token, Tt},
+    manifest::{Entry, Fallback, Kind, Platform, Version},
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Lex(String, lex::Error),
+    Attr(String, usize, String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => e.fmt(f),
+            Self::Lex(file, e) => write!(f, "{file}: {e}"),
+            Self::Attr(file, line, msg) => write!(f, "{file}: line {line}: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Attributes collected in front of the next item.
+#[derive(Default)]
+struct Attrs {
+    available: Option<Vec<(Platform, Version)>>,
+    weak: bool,
+    sel: Option<String>,
+    line: usize,
+}
+
+#[derive(Default, Clone)]
+struct Ctx {
+    /// Self type of the enclosing `impl` or `trait`.
+    ty: Option<String>,
+    /// Inside `extern` block and whether it is `#[api::weak]`.
+    extern_block: Option<bool>,
+}
+
+struct Scanner<'a> {
+    file: &'a str,
+    module: String,
+    entries: Vec<Entry>,
+}
+
+fn path_of(tokens: &[Token]) -> Vec<&str> {
+    tokens
+        .iter()
+        .filter(|t| !t.is_punct(':'))
+        .map_while(Token::ident)
+        .collect()
+}
+
+/// Self type of `impl<..> Trait<..> for Type<..> where ..` without generics.
+fn impl_ty(header: &[Token]) -> Option<String> {
+    let mut i = 0;
+    let mut ty = None;
+    let mut depth = 0;
+    while i < header.len() {
+        let t = &header[i];
+        match &t.tt {
+            Tt::Punct('<') => depth += 1,
+            Tt::Punct('>') if i > 0 && header[i - 1].is_punct('-') => {}
+            Tt::Punct('>') => depth -= 1,
+            Tt::Ident(s) if depth == 0 && s == "where" => break,
+            Tt::Ident(s) if depth == 0 && s == "for" => ty = None,
+            Tt::Ident(s)
+                if depth == 0
+                    && ty.is_none()
+                    && !matches!(s.as_str(), "unsafe" | "dyn" | "impl" | "const") =>
+            {
+                let path = path_of(&header[i..]);
+                i += path.len() * 3 - 2;
+                // init methods live in `impl arc::A<Type>`
+                if path.last() == Some(&"A") && header.get(i).is_some_and(|t| t.is_punct('<')) {
+                    return impl_ty(&header[i + 1..]);
+                }
+                ty = path.last().map(|s| s.to_string());
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    ty
+}
+
+/// How `api::available` rewrites a fn with a body, and the weak symbol it forwards to.
+fn fn_fallback(sig: &[Token], body: &[Token]) -> (Fallback, Option<String>) {
+    // `{ {} ... }` marks the result as optional
+    if body.first().and_then(|t| t.group(Delim::Brace)).is_some() {
+        return (Fallback::Optional, None);
+    }
+    // `fn x() -> &'static T { unsafe { VAR } }` returns `VAR.get_var()`
+    let no_args = sig
+        .iter()
+        .rev()
+        .find_map(|t| t.group(Delim::Paren))
+        .is_some_and(|args| args.is_empty());
+    let ret = sig.iter().rposition(|t| t.is_punct('\''));
+    let ret_static = ret.is_some_and(|r| {
+        let ret = &sig[r..];
+        ret.iter().any(|t| t.ident() == Some("static"))
+            && !ret.iter().any(|t| t.group(Delim::Paren).is_some())
+    });
+    let unsafe_block = match body {
+        [kw, block, ..] if kw.ident() == Some("unsafe") => block.group(Delim::Brace),
+        _ => None,
+    };
+    if no_args && ret_static {
+        let var = match unsafe_block {
+            Some([var, rest @ ..])
+                if !matches!(rest.first().map(|t| &t.tt), Some(Tt::Group(..))) =>
+            {
+                var.ident()
+            }
+            Some(_) => None,
+            None => body.first().and_then(Token::ident),
+        };
+        if let Some(var) = var {
+            return (Fallback::Optional, Some(var.to_string()));
+        }
+    }
+    // `unsafe { f(args) }` forwards to `F.get_fn().unwrap()(args)`
+    if let Some([f, args]) = unsafe_block {
+        if let (Some(f), Some(_)) = (f.ident(), args.group(Delim::Paren)) {
+            return (Fallback::Panics, Some(f.to_string()));
+        }
+    }
+    if body.first().is_some_and(|t| t.ident() == Some("Self"))
+        && body.get(3).and_then(Token::ident) == Some("alloc")
+    {
+        return (Fallback::Optional, None);
+    }
+    (Fallback::Unsafe, None)
+}
+
+impl Scanner<'_> {
+    fn err<T>(&self, line: usize, msg: impl Into<String>) -> Result<T, Error> {
+        Err(Error::Attr(self.file.to_string(), line, msg.into()))
+    }
+
+    fn item_name(&self, ty: Option<&str>, name: &str) -> String {
+        let mut item = self.module.clone();
+        for part in ty.into_iter().chain(Some(name)) {
+            if !item.is_empty() {
+                item.push_str("::");
+            }
+            item.push_str(part);
+        }
+        item
+    }
+
+    fn versions(&self, tokens: &[Token], line: usize) -> Result<Vec<(Platform, Version)>, Error> {
+        let mut res = vec![];
+        for part in tokens.split(|t| t.is_punct(',')) {
+            match part {
+                [] => {}
+                [p, eq, v] if eq.is_punct('=') => {
+                    let (Some(p), Tt::Lit(v) | Tt::Ident(v)) = (p.ident(), &v.tt) else {
+                        return self.err(line, "expected `platform = version`");
+                    };
+                    let p = p.parse().or_else(|e| self.err(line, e))?;
+                    let v = v.parse().or_else(|e| self.err(line, e))?;
+                    res.push((p, v));
+                }
+                _ => return self.err(line, "expected `platform = version`"),
+            }
+        }
+        if res.is_empty() {
+            return self.err(line, "expected platform versions");
+        }
+        res.sort();
+        Ok(res)
+    }
+
+    fn attr(&self, tokens: &[Token], line: usize, attrs: &mut Attrs) -> Result<(), Error> {
+        let path = path_of(tokens);
+        if path.is_empty() {
+            return Ok(());
+        }
+        let args = tokens
+            .get(path.len() * 3 - 2)
+            .and_then(|t| t.group(Delim::Paren));
+        match (path.as_slice(), args) {
+            (["api" | "objc", "available"], Some(args)) => {
+                attrs.available = Some(self.versions(args, line)?);
+                if attrs.line == 0 {
+                    attrs.line = line;
+                }
+            }
+            (["api", "weak"], _) => attrs.weak = true,
+            (["objc", "msg_send" | "msg_send_debug"], Some(args)) => {
+                let sel: String = args
+                    .iter()
+                    .map(|t| match &t.tt {
+                        Tt::Ident(s) => s.as_str(),
+                        _ => ":",
+                    })
+                    .collect();
+                attrs.sel = Some(sel);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn push(
+        &mut self,
+        attrs: &Attrs,
+        item: String,
+        kind: Kind,
+        symbol: Option<String>,
+        weak: bool,
+        fallback: Fallback,
+    ) {
+        let Some(available) = &attrs.available else {
+            return;
+        };
+        self.entries.push(Entry {
+            item,
+            kind,
+            symbol,
+            file: self.file.to_string(),
+            line: attrs.line,
+            weak,
+            fallback,
+            available: available.clone(),
+        });
+    }
+
+    /// `define_obj_type!(#[..] pub Name(Base), CLS, #[api::available(..)])`
+    fn obj_type(&mut self, args: &[Token], ctx: &Ctx) -> Result<(), Error> {
+        let parts: Vec<&[Token]> = args.split(|t| t.is_punct(',')).collect();
+        let [decl, cls, attr] = parts.as_slice() else {
+            return Ok(());
+        };
+        let ([pound, attr], [cls]) = (attr, cls) else {
+            return Ok(());
+        };
+        let (true, Some(attr), Some(cls)) =
+            (pound.is_punct('#'), attr.group(Delim::Bracket), cls.ident())
+        else {
+            return Ok(());
+        };
+        let mut attrs = Attrs::default();
+        self.attr(attr, pound.line, &mut attrs)?;
+        let name = decl
+            .iter()
+            .filter(|t| !matches!(t.tt, Tt::Group(..) | Tt::Punct(_)))
+            .filter_map(Token::ident)
+            .find(|s| *s != "pub");
+        if let Some(name) = name {
+            let item = self.item_name(ctx.ty.as_deref(), name);
+            let symbol = Some(cls.to_string());
+            self.push(&attrs, item, Kind::Class, symbol, true, Fallback::Optional);
+        }
+        Ok(())
+    }
+
+    fn walk(&mut self, tokens: &[Token], ctx: &Ctx) -> Result<(), Error> {
+        let mut attrs = Attrs::default();
+        let mut i = 0;
+        while i < tokens.len() {
+            let t = &tokens[i];
+            let next = tokens.get(i + 1);
+            match &t.tt {
+                Tt::Punct('#') => {
+                    // inner attribute `#![..]`
+                    let j = if next.is_some_and(|t| t.is_punct('!')) {
+                        i + 2
+                    } else {
+                        i + 1
+                    };
+                    if let Some(attr) = tokens.get(j).and_then(|t| t.group(Delim::Bracket)) {
+                        if j == i + 1 {
+                            self.attr(attr, t.line, &mut attrs)?;
+                        }
+                        i = j + 1;
+                        continue;
+                    }
+                }
+                Tt::Punct(';') => attrs = Attrs::default(),
+                Tt::Group(Delim::Brace, _) => attrs = Attrs::default(),
+                Tt::Ident(kw) => match kw.as_str() {
+                    "fn" => {
+                        let name = next.and_then(Token::ident).unwrap_or_default().to_string();
+                        let mut j = i + 1;
+                        while j < tokens.len()
+                            && !tokens[j].is_punct(';')
+                            && tokens[j].group(Delim::Brace).is_none()
+                        {
+                            j += 1;
+                        }
+                        let body = tokens.get(j).and_then(|t| t.group(Delim::Brace));
+                        let item = self.item_name(ctx.ty.as_deref(), &name);
+                        if let Some(sel) = attrs.sel.take() {
+                            self.push(
+                                &attrs,
+                                item,
+                                Kind::Selector,
+                                Some(sel),
+                                false,
+                                Fallback::Unsafe,
+                            );
+                        } else if let Some(body) = body {
+                            let (fallback, symbol) = fn_fallback(&tokens[i + 1..j], body);
+                            let weak = symbol.is_some();
+                            self.push(&attrs, item, Kind::Fn, symbol, weak, fallback);
+                        } else if let Some(weak) = ctx.extern_block {
+                            let fallback = if weak {
+                                Fallback::Optional
+                            } else {
+                                Fallback::Missing
+                            };
+                            self.push(&attrs, item, Kind::ExternFn, Some(name), weak, fallback);
+                        }
+                        attrs = Attrs::default();
+                        i = j + 1;
+                        continue;
+                    }
+                    "static" if ctx.extern_block.is_some() => {
+                        let weak = ctx.extern_block == Some(true);
+                        let name = tokens[i + 1..]
+                            .iter()
+                            .filter_map(Token::ident)
+                            .find(|s| *s != "mut")
+                            .unwrap_or_default()
+                            .to_string();
+                        let item = self.item_name(ctx.ty.as_deref(), &name);
+                        let fallback = if weak {
+                            Fallback::Optional
+                        } else {
+                            Fallback::Missing
+                        };
+                        self.push(&attrs, item, Kind::Static, Some(name), weak, fallback);
+                        attrs = Attrs::default();
+                    }
+                    "impl" | "trait" | "mod" | "extern" => {
+                        let Some(j) = tokens[i + 1..]
+                            .iter()
+                            .position(|t| t.is_punct(';') || t.group(Delim::Brace).is_some())
+                            .map(|j| j + i + 1)
+                        else {
+                            break;
+                        };
+                        let header = &tokens[i + 1..j];
+                        // `extern "C" fn` and `extern crate` are not blocks
+                        if kw == "extern" && header.iter().any(|t| t.ident().is_some()) {
+                            i += 1;
+                            continue;
+                        }
+                        if let Some(inner) = tokens[j].group(Delim::Brace) {
+                            let mut inner_ctx = ctx.clone();
+                            match kw.as_str() {
+                                "impl" => inner_ctx.ty = impl_ty(header),
+                                "trait" => {
+                                    inner_ctx.ty =
+                                        header.first().and_then(Token::ident).map(String::from)
+                                }
+                                "mod" => inner_ctx = Ctx::default(),
+                                _ => inner_ctx.extern_block = Some(attrs.weak),
+                            }
+                            self.walk(inner, &inner_ctx)?;
+                        }
+                        attrs = Attrs::default();
+                        i = j + 1;
+                        continue;
+                    }
+                    "define_cls" | "define_cls_init" if next.is_some_and(|t| t.is_punct('!')) => {
+                        let cls = tokens.get(i + 2).and_then(|t| t.group(Delim::Paren));
+                        let cls = cls.and_then(|g| g.first()).and_then(Token::ident);
+                        if let (Some(ty), Some(cls)) = (&ctx.ty, cls) {
+                            let item = self.item_name(None, ty);
+                            self.push(
+                                &attrs,
+                                item,
+                                Kind::Class,
+                                Some(cls.to_string()),
+                                true,
+                                Fallback::Optional,
+                            );
+                        }
+                        attrs = Attrs::default();
+                    }
+                    "define_obj_type" if next.is_some_and(|t| t.is_punct('!')) => {
+                        if let Some(args) = tokens.get(i + 2).and_then(|t| t.group(Delim::Paren)) {
+                            self.obj_type(args, ctx)?;
+                        }
+                        attrs = Attrs::default();
+                        i += 3;
+                        continue;
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+            i += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Module used as item prefix: the top level module the file belongs to.
+/// cidre reexports framework types there, e.g. `av/capture/device.rs` -> `av`.
+fn module_of(file: &str) -> String {
+    let first = file.split('/').next().unwrap_or_default();
+    match first.strip_suffix(".rs") {
+        Some("lib" | "mod" | "main") => String::new(),
+        Some(stem) => stem.to_string(),
+        None => first.to_string(),
+    }
+}
+
+/// Scans a single source file. `file` is the path reported in entries.
+pub fn scan_src(file: &str, src: &str) -> Result<Vec<Entry>, Error> {
+    let tokens = lex::parse(src).map_err(|e| Error::Lex(file.to_string(), e))?;
+    let mut scanner = Scanner {
+        file,
+        module: module_of(file),
+        entries: vec![],
+    };
+    scanner.walk(&tokens, &Ctx::default())?;
+    Ok(scanner.entries)
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<std::path::PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Scans every `.rs` file under `root`. Entries are ordered by file and line.
+pub fn scan_dir(root: &Path) -> Result<Vec<Entry>, Error> {
+    let mut files = vec![];
+    collect_files(root, &mut files)?;
+    files.sort();
+    let mut entries = vec![];
+    for path in files {
+        let src = fs::read_to_string(&path)?;
+        let rel = path.strip_prefix(root).unwrap_or(&path);
+        let rel = rel.to_string_lossy().replace('\\', "/");
+        entries.extend(scan_src(&rel, &src)?);
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SRC: &str = r#"
+define_obj_type!(
+    #[doc(alias = "SCRecordingOutput")]
+    pub RecordingOutput(ns::Id),
+    SC_RECORDING_OUTPUT,
+    #[api::available(macos = 15.0)]
+);
+
+impl RecordingOutput {
+    #[objc::msg_send(recordedDuration)]
+    #[api::available(macos = 15.0, ios = 18.0)]
+    pub fn recorded_duration(&self) -> cm::Time;
+
+    #[objc::msg_send(plain)]
+    pub fn plain(&self);
+
+    #[api::available(macos = 15.0)]
+    pub fn with_delegate<D: Delegate>(d: &D) -> arc::R<Self> {
+        Self::with_delegate_in(d, ns::Zone::default())
+    }
+
+    #[api::available(macos = 15.0)]
+    pub fn scoped() -> arc::R<Self> {
+        {}
+        Self::new()
+    }
+}
+
+impl<T: Obj> arc::A<Manager<T>> {
+    #[objc::available(macos = 14.0)]
+    crate::define_cls!(SC_MANAGER);
+}
+
+#[api::weak]
+unsafe extern "C" {
+    #[api::available(macos = 14.0, visionos = 1.0)]
+    static kCMTagInvalid: &'static Tag;
+
+    #[api::available(macos = 14.0)]
+    fn CMTagGetValue(tag: Tag) -> i64;
+}
+
+unsafe extern "C-unwind" {
+    #[api::available(ios = 17.0)]
+    fn CMMissing();
+}
+
+impl Tag {
+    #[api::available(macos = 14.0)]
+    pub fn did_change() -> &'static ns::NotificationName {
+        unsafe { CMTagDidChange }
+    }
+
+    #[api::available(macos = 14.0)]
+    pub fn value(&self) -> i64 {
+        unsafe { CMTagGetValue(self) }
+    }
+}
+"#;
+
+    #[test]
+    fn entries() {
+        let entries = scan_src("sc/recording_output.rs", SRC).unwrap();
+        let summary: Vec<_> = entries
+            .iter()
+            .map(|e| {
+                let versions: Vec<_> = e
+                    .available
+                    .iter()
+                    .map(|(p, v)| format!("{p} {v}"))
+                    .collect();
+                format!(
+                    "{} {} {} {} {} {} [{}]",
+                    e.line,
+                    e.item,
+                    e.kind.as_str(),
+                    e.symbol.as_deref().unwrap_or("-"),
+                    e.weak,
+                    e.fallback.as_str(),
+                    versions.join(", ")
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                "6 sc::RecordingOutput class SC_RECORDING_OUTPUT true optional [macos 15.0]",
+                "11 sc::RecordingOutput::recorded_duration selector recordedDuration false unsafe [macos 15.0, ios 18.0]",
+                "17 sc::RecordingOutput::with_delegate fn - false unsafe [macos 15.0]",
+                "22 sc::RecordingOutput::scoped fn - false optional [macos 15.0]",
+                "30 sc::Manager class SC_MANAGER true optional [macos 14.0]",
+                "36 sc::kCMTagInvalid static kCMTagInvalid true optional [macos 14.0, visionos 1.0]",
+                "39 sc::CMTagGetValue extern_fn CMTagGetValue true optional [macos 14.0]",
+                "44 sc::CMMissing extern_fn CMMissing false missing [ios 17.0]",
+                "49 sc::Tag::did_change fn CMTagDidChange true optional [macos 14.0]",
+                "54 sc::Tag::value fn CMTagGetValue true panics [macos 14.0]",
+            ]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = scan_src("a.rs", "\n#[api::available(macos = x)]\nfn a() {}").unwrap_err();
+        assert_eq!(err.to_string(), "a.rs: line 2: invalid version `x`");
+        let err = scan_src("a.rs", "#[api::available(linux = 1.0)]\nfn a() {}").unwrap_err();
+        assert_eq!(err.to_string(), "a.rs: line 1: unknown platform `linux`");
+        assert_eq!(module_of("objc.rs"), "objc");
+        assert_eq!(module_of("lib.rs"), "");
+    }
+}
@@ -0,0 +1,120 @@
+use proc_macro2::{Span, TokenStream};
+use syn::{Attribute, Error, Expr, ExprLit, Lit, Meta, Result};
+
+use crate::available::Versions;
+
+/// `objc::` and `api::` attributes other macros look for.
+pub(crate) enum Attr {
+    Optional,
+    /// Getter or setter of protocol property.
+    Property,
+    MsgSend(String),
+    ApiAvailable(Versions),
+    /// `/// # Availability` doc line, next doc line lists versions.
+    DocAvailable,
+}
+
+impl Attr {
+    pub(crate) fn parse(attr: &Attribute) -> Result<Option<Self>> {
+        if let Meta::NameValue(nv) = &attr.meta {
+            let doc =
+                nv.path.is_ident("doc") && doc_str(attr).as_deref() == Some(" # Availability");
+            return Ok(doc.then_some(Self::DocAvailable));
+        }
+        let Some((ns, name)) = ns_name(attr) else {
+            return Ok(None);
+        };
+        if ns != "objc" && ns != "api" {
+            return Ok(None);
+        }
+        match name.to_string().as_str() {
+            "optional" => Ok(Some(Self::Optional)),
+            "property" => Ok(Some(Self::Property)),
+            "msg_send" => match &attr.meta {
+                Meta::List(list) => Ok(Some(Self::MsgSend(sel_string(&list.tokens)))),
+                _ => Err(Error::new(
+                    name.span(),
+                    "expected selector, e.g. `#[objc::msg_send(objectAtIndex:)]`",
+                )),
+            },
+            "available" => match &attr.meta {
+                Meta::List(list) => Ok(Some(Self::ApiAvailable(list.parse_args()?))),
+                _ => Err(Error::new(
+                    name.span(),
+                    "expected platform versions, e.g. `#[api::available(macos = 14.0)]`",
+                )),
+            },
+            _ => Ok(None),
+        }
+    }
+}
+
+/// `ns` and `name` of `#[ns::name]` attribute.
+pub(crate) fn ns_name(attr: &Attribute) -> Option<(&syn::Ident, &syn::Ident)> {
+    let path = attr.path();
+    if path.leading_colon.is_some() || path.segments.len() != 2 {
+        return None;
+    }
+    Some((&path.segments[0].ident, &path.segments[1].ident))
+}
+
+/// Value of `#[doc = "..."]` attribute.
+pub(crate) fn doc_str(attr: &Attribute) -> Option<String> {
+    match &attr.meta {
+        Meta::NameValue(nv) if nv.path.is_ident("doc") => match &nv.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(s), ..
+            }) => Some(s.value()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub(crate) fn is_cfg(attr: &Attribute) -> bool {
+    attr.path().is_ident("cfg")
+}
+
+fn sel_string(tokens: &TokenStream) -> String {
+    tokens.to_string().replace([' ', '\n'], "")
+}
+
+/// Selector of `#[objc::msg_send(objectAtIndex:)]` like attributes and number of its arguments.
+pub(crate) fn selector(tokens: TokenStream, example: &str) -> Result<(String, usize)> {
+    let Some(first) = tokens.clone().into_iter().next() else {
+        return Err(Error::new(
+            Span::call_site(),
+            format!("expected selector, e.g. `{example}`"),
+        ));
+    };
+    let sel = sel_string(&tokens);
+    let count = sel_args_count(&sel, first.span())?;
+    Ok((sel, count))
+}
+
+/// Number of arguments of the selector, one per `:`.
+pub(crate) fn sel_args_count(sel: &str, span: Span) -> Result<usize> {
+    if let Some(c) = sel
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '_' || *c == ':'))
+    {
+        return Err(Error::new(
+            span,
+            format!("unexpected `{c}` in selector `{sel}`"),
+        ));
+    }
+    if sel.starts_with(|c: char| c.is_ascii_digit() || c == ':') {
+        return Err(Error::new(
+            span,
+            format!("selector `{sel}` must start with a name"),
+        ));
+    }
+    let count = sel.matches(':').count();
+    if count > 0 && !sel.ends_with(':') {
+        return Err(Error::new(
+            span,
+            format!("selector `{sel}` takes arguments and must end with `:`"),
+        ));
+    }
+    Ok(count)
+}
@@ -0,0 +1,626 @@
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    spanned::Spanned,
+    Attribute, Error, ForeignItem, ItemForeignMod, Macro, Result, ReturnType, Token, Type,
+};
+
+use crate::sig::{c_str, has_fn, ret_ty, FnBody, FnItem};
+
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Version(pub u32, pub u32);
+
+impl Version {
+    fn from_str(str: &str) -> Option<Self> {
+        let (major, minor) = str.split_once('.').or_else(|| str.split_once('_'))?;
+        Some(Self(major.parse().ok()?, minor.parse().ok()?))
+    }
+}
+
+#[derive(Default, Debug)]
+pub(crate) struct Versions {
+    pub macos: Option<Version>,
+    pub ios: Option<Version>,
+    pub tvos: Option<Version>,
+    pub watchos: Option<Version>,
+    pub visionos: Option<Version>,
+    pub maccatalyst: Option<Version>,
+}
+
+impl Versions {
+    const PLATFORMS: &'static str = "macos, ios, tvos, watchos, visionos or maccatalyst";
+
+    fn platform_mut(&mut self, platform: &str) -> Option<&mut Option<Version>> {
+        match platform {
+            "macos" => Some(&mut self.macos),
+            "ios" => Some(&mut self.ios),
+            "tvos" => Some(&mut self.tvos),
+            "watchos" => Some(&mut self.watchos),
+            "visionos" => Some(&mut self.visionos),
+            "maccatalyst" => Some(&mut self.maccatalyst),
+            _ => None,
+        }
+    }
+
+    /// Platforms with versions in declaration order.
+    fn iter(&self) -> impl Iterator<Item = (&'static str, Version)> {
+        [
+            ("macos", self.macos),
+            ("ios", self.ios),
+            ("tvos", self.tvos),
+            ("watchos", self.watchos),
+            ("visionos", self.visionos),
+            ("maccatalyst", self.maccatalyst),
+        ]
+        .into_iter()
+        .filter_map(|(platform, v)| Some((platform, v?)))
+    }
+
+    pub(crate) fn any(&self) -> bool {
+        self.iter().next().is_some()
+    }
+
+    fn features(&self) -> Vec<String> {
+        self.iter()
+            .map(|(platform, v)| format!("{platform}_{}_{}", v.0, v.1))
+            .collect()
+    }
+
+    /// `#[cfg]` enabled when deployment target feature is on, empty without versions.
+    pub(crate) fn available_cfg(&self) -> TokenStream {
+        self.cfg(|feature| quote!(feature = #feature))
+    }
+
+    /// `#[cfg]` enabled when deployment target is below API introduction version.
+    pub(crate) fn unavailable_cfg(&self) -> TokenStream {
+        self.cfg(|feature| quote!(not(feature = #feature)))
+    }
+
+    fn cfg(&self, feature: impl Fn(String) -> TokenStream) -> TokenStream {
+        let preds = Vec::from_iter(self.iter().map(|(platform, v)| {
+            let feature = feature(format!("{platform}_{}_{}", v.0, v.1));
+            if platform == "maccatalyst" {
+                quote!(all(target_os = "ios", target_abi = "macabi", #feature))
+            } else {
+                quote!(all(target_os = #platform, #feature))
+            }
+        }));
+        match preds.as_slice() {
+            [] => TokenStream::new(),
+            [pred] => quote!(#[cfg(#pred)]),
+            preds => quote!(#[cfg(any(#(#preds),*))]),
+        }
+    }
+
+    fn available_doc(&self) -> TokenStream {
+        self.doc("")
+    }
+
+    fn unavailable_doc(&self) -> TokenStream {
+        self.doc("Not ")
+    }
+
+    fn doc(&self, prefix: &str) -> TokenStream {
+        let features = self.features();
+        if features.is_empty() {
+            return TokenStream::new();
+        }
+        let versions = format!(" {prefix}{}", features.join(", "));
+        quote! {
+            #[doc = " # Availability"]
+            #[doc = #versions]
+        }
+    }
+
+    /// Versions from `/// macos_14_0, ios_17_0` doc line.
+    pub(crate) fn from_doc_str(str: &str) -> Self {
+        let mut res = Self::default();
+        for str in str.split_whitespace() {
+            for str in str.split_terminator(',') {
+                let Some((platform, version)) = str.split_once('_') else {
+                    continue;
+                };
+                if let Some(v) = res.platform_mut(platform) {
+                    *v = Version::from_str(version);
+                }
+            }
+        }
+        res
+    }
+}
+
+impl Parse for Versions {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut versions = Self::default();
+        while !input.is_empty() {
+            let target_os = match input.parse::<TokenTree>()? {
+                TokenTree::Ident(i) => i,
+                t => {
+                    return Err(Error::new(
+                        t.span(),
+                        format!("expected platform, found `{t}`"),
+                    ));
+                }
+            };
+            match input.parse::<TokenTree>() {
+                Ok(TokenTree::Punct(p)) if p.as_char() == '=' => {}
+                tt => {
+                    let span = tt.map_or_else(|_| target_os.span(), |tt| tt.span());
+                    return Err(Error::new(
+                        span,
+                        format!("expected `=` after `{target_os}`"),
+                    ));
+                }
+            }
+            let val = match input.parse::<TokenTree>() {
+                Ok(TokenTree::Literal(val)) => val,
+                tt => {
+                    let span = tt.map_or_else(|_| target_os.span(), |tt| tt.span());
+                    return Err(Error::new(
+                        span,
+                        format!("expected version, e.g. `{target_os} = 14.0`"),
+                    ));
+                }
+            };
+            let Some(v) = Version::from_str(&val.to_string()) else {
+                return Err(Error::new(
+                    val.span(),
+                    format!("invalid version `{val}`, expected `major.minor`"),
+                ));
+            };
+            let Some(platform) = versions.platform_mut(&target_os.to_string()) else {
+                return Err(Error::new(
+                    target_os.span(),
+                    format!(
+                        "unsupported platform `{target_os}`, expected {}",
+                        Self::PLATFORMS
+                    ),
+                ));
+            };
+            if platform.replace(v).is_some() {
+                return Err(Error::new(
+                    target_os.span(),
+                    format!("duplicate platform `{target_os}`"),
+                ));
+            }
+            if input.is_empty() {
+                break;
+            }
+            match input.parse::<TokenTree>()? {
+                TokenTree::Punct(p) if p.as_char() == ',' => {}
+                tt => {
+                    return Err(Error::new(tt.span(), format!("expected `,`, found `{tt}`")));
+                }
+            }
+        }
+        Ok(versions)
+    }
+}
+
+/// Item annotated with `#[api::available]`.
+enum Item {
+    Fn(Box<FnItem>),
+    /// `define_cls!(..)` or `define_cls_init!(..)`, unavailable variant uses weak class.
+    DefineCls {
+        attrs: Vec<Attribute>,
+        mac: Macro,
+        semi: Option<Token![;]>,
+    },
+    /// Declaration without body: extern fn or static, kept only when available.
+    Decl(Vec<Attribute>, TokenStream),
+}
+
+impl Parse for Item {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if has_fn(&input.fork().parse()?) {
+            return input.parse().map(Self::Fn);
+        }
+        let attrs = input.call(Attribute::parse_outer)?;
+        if let Ok(mac) = input.fork().parse::<Macro>() {
+            if weak_cls(&mac).is_some() {
+                return Ok(Self::DefineCls {
+                    attrs,
+                    mac: input.parse()?,
+                    semi: input.parse()?,
+                });
+            }
+        }
+        let rest: TokenStream = input.parse()?;
+        match rest.clone().into_iter().last() {
+            Some(TokenTree::Punct(p)) if p.as_char() == ';' => Ok(Self::Decl(attrs, rest)),
+            _ => Err(Error::new(
+                Span::call_site(),
+                "#[api::available] expects function, declaration or `define_cls!`",
+            )),
+        }
+    }
+}
+
+pub(crate) fn gen_api_available(versions: TokenStream, body: TokenStream) -> Result<TokenStream> {
+    let versions: Versions = syn::parse2(versions)?;
+    if !versions.any() {
+        return Err(Error::new(
+            Span::call_site(),
+            "expected platform versions, e.g. `#[api::available(macos = 14.0, ios = 17.0)]`",
+        ));
+    }
+    let available = versions.available_cfg();
+    let available_doc = versions.available_doc();
+    let unavailable = versions.unavailable_cfg();
+    let unavailable_doc = versions.unavailable_doc();
+
+    match syn::parse2(body)? {
+        Item::Decl(attrs, rest) => Ok(quote! {
+            #available
+            #(#attrs)*
+            #available_doc
+            #rest
+        }),
+        Item::DefineCls { attrs, mac, semi } => {
+            let mut weak = mac.clone();
+            let last = weak.path.segments.last_mut().unwrap();
+            last.ident = format_ident!("{}", weak_cls(&mac).unwrap(), span = last.ident.span());
+            Ok(quote! {
+                #available
+                #(#attrs)*
+                #available_doc
+                #mac #semi
+                #unavailable
+                #(#attrs)*
+                #unavailable_doc
+                #weak #semi
+            })
+        }
+        Item::Fn(item) => {
+            let FnBody::Block(_) = item.body else {
+                let FnItem {
+                    attrs, vis, sig, ..
+                } = &*item;
+                return Ok(quote! {
+                    #available
+                    #(#attrs)*
+                    #available_doc
+                    #vis #sig;
+                });
+            };
+            let try_fn = WeakTryFn::new(&item).map(|f| f.expand(&versions));
+            let weak = unavailable_fn(&item);
+            let FnItem {
+                attrs,
+                vis,
+                sig,
+                body,
+            } = &*item;
+            let FnItem {
+                vis: weak_vis,
+                sig: weak_sig,
+                body: weak_body,
+                ..
+            } = &weak;
+            let (FnBody::Block(body), FnBody::Block(weak_body)) = (body, weak_body) else {
+                unreachable!()
+            };
+            Ok(quote! {
+                #available
+                #(#attrs)*
+                #available_doc
+                #vis #sig #body
+
+                #unavailable
+                #(#attrs)*
+                #unavailable_doc
+                #weak_vis #weak_sig #weak_body
+
+                #try_fn
+            })
+        }
+    }
+}
+
+/// Weak counterpart of `define_cls!` or `define_cls_init!`.
+fn weak_cls(mac: &Macro) -> Option<&'static str> {
+    match mac.path.segments.last()?.ident.to_string().as_str() {
+        "define_cls" => Some("define_weak_cls"),
+        "define_cls_init" => Some("define_weak_cls_init"),
+        _ => None,
+    }
+}
+
+/// Rewrites fn for deployment targets below introduction version:
+///
+/// - `fn xxx() -> &'static T { unsafe { VAR } }` becomes `unsafe fn` returning `Option` of weak var;
+/// - `fn xxx(..) -> R { unsafe { f(..) } }` calls weak fn, panics if it is missing;
+/// - `fn xxx(..) -> R { Self::alloc()... }` and `fn xxx(..) -> R { { .. } }` return `Option<R>`;
+/// - everything else becomes `unsafe`.
+fn unavailable_fn(item: &FnItem) -> FnItem {
+    let FnBody::Block(body) = &item.body else {
+        unreachable!()
+    };
+    let mut sig = item.sig.clone();
+    let mut body = body.clone();
+    if sig.inputs.is_empty() {
+        if let Some((output, var_body)) = try_replace_return(&sig.output, &body) {
+            sig.output = output;
+            body = var_body;
+        }
+    }
+    match try_replace_fn(&body) {
+        Some((new_body, optional)) => {
+            body = new_body;
+            if optional {
+                if let ReturnType::Type(_, ty) = &mut sig.output {
+                    **ty = syn::parse_quote!(Option<#ty>);
+                }
+            }
+        }
+        None => {
+            if sig.unsafety.is_none() {
+                sig.unsafety = Some(Default::default());
+            }
+        }
+    }
+    FnItem {
+        attrs: item.attrs.clone(),
+        vis: item.vis.clone(),
+        sig,
+        body: FnBody::Block(body),
+    }
+}
+
+fn brace(stream: TokenStream) -> Group {
+    Group::new(Delimiter::Brace, stream)
+}
+
+/// Replaces body forwarding to weak fn, `Some` if it returns `Option` now.
+fn try_replace_fn(body: &Group) -> Option<(Group, bool)> {
+    let tokens = Vec::from_iter(body.stream());
+    match tokens.first()? {
+        TokenTree::Group(scope) if scope.delimiter() == Delimiter::Brace => {
+            Some((body.clone(), true))
+        }
+        TokenTree::Ident(ident) if ident == "unsafe" => {
+            let Some(TokenTree::Group(block)) = tokens.get(1) else {
+                return None;
+            };
+            if block.delimiter() != Delimiter::Brace {
+                return None;
+            }
+            let block = Vec::from_iter(block.stream());
+            let [TokenTree::Ident(f), TokenTree::Group(args)] = block.as_slice() else {
+                return None;
+            };
+            if args.delimiter() != Delimiter::Parenthesis {
+                return None;
+            }
+            let var = format_ident!("{}", upper_case(&f.to_string()));
+            let body = brace(quote!(unsafe { #var.get_fn().unwrap()#args }));
+            Some((body, false))
+        }
+        TokenTree::Ident(ident) if ident == "Self" => {
+            // `Self::alloc()` becomes `Some(Self::alloc()?...)`
+            let [_, TokenTree::Punct(c0), TokenTree::Punct(c1), TokenTree::Ident(alloc), TokenTree::Group(args), rest @ ..] =
+                tokens.as_slice()
+            else {
+                return None;
+            };
+            if c0.as_char() != ':'
+                || c1.as_char() != ':'
+                || alloc != "alloc"
+                || args.delimiter() != Delimiter::Parenthesis
+            {
+                return None;
+            }
+            let rest = TokenStream::from_iter(rest.iter().cloned());
+            let body = brace(quote!(Some(Self::alloc()?#rest)));
+            Some((body, true))
+        }
+        _ => None,
+    }
+}
+
+/// `fn xxx() -> &'static T { unsafe { VAR } }` becomes
+/// `fn xxx() -> Option<&'static T> { unsafe { VAR.get_var() } }`.
+fn try_replace_return(output: &ReturnType, body: &Group) -> Option<(ReturnType, Group)> {
+    let ReturnType::Type(arrow, ty) = output else {
+        return None;
+    };
+    let Type::Reference(r) = &**ty else {
+        return None;
+    };
+    if r.lifetime.as_ref()?.ident != "static" {
+        return None;
+    }
+    let tokens = Vec::from_iter(body.stream());
+    let var = match tokens.first()? {
+        TokenTree::Ident(kw) if kw == "unsafe" => {
+            let Some(TokenTree::Group(block)) = tokens.get(1) else {
+                return None;
+            };
+            if block.delimiter() != Delimiter::Brace {
+                return None;
+            }
+            let mut block = block.stream().into_iter();
+            let Some(TokenTree::Ident(var)) = block.next() else {
+                return None;
+            };
+            if let Some(TokenTree::Group(_)) = block.next() {
+                return None;
+            }
+            var
+        }
+        TokenTree::Ident(var) => var.clone(),
+        _ => return None,
+    };
+    let var = format_ident!("{}", upper_case(&var.to_string()));
+    let output = ReturnType::Type(*arrow, Box::new(syn::parse_quote!(Option<#ty>)));
+    Some((output, brace(quote!(unsafe { #var.get_var() }))))
+}
+
+/// Safe `try_xxx` for fns forwarding to weak symbol: `fn xxx(..) -> R { unsafe { f(..) } }`.
+/// Above deployment target `f` is resolved with `api::DlSym`, which caches the lookup.
+pub(crate) struct WeakTryFn<'a> {
+    item: &'a FnItem,
+    symbol: syn::Ident,
+    /// Call arguments group with parens.
+    call_args: Group,
+}
+
+impl<'a> WeakTryFn<'a> {
+    pub(crate) fn new(item: &'a FnItem) -> Option<Self> {
+        let FnBody::Block(body) = &item.body else {
+            return None;
+        };
+        let body = Vec::from_iter(body.stream());
+        let [TokenTree::Ident(kw), TokenTree::Group(block)] = body.as_slice() else {
+            return None;
+        };
+        if kw != "unsafe" || block.delimiter() != Delimiter::Brace {
+            return None;
+        }
+        let block = Vec::from_iter(block.stream());
+        let [TokenTree::Ident(symbol), TokenTree::Group(call_args)] = block.as_slice() else {
+            return None;
+        };
+        if call_args.delimiter() != Delimiter::Parenthesis {
+            return None;
+        }
+        Some(Self {
+            item,
+            symbol: symbol.clone(),
+            call_args: call_args.clone(),
+        })
+    }
+
+    pub(crate) fn expand(&self, versions: &Versions) -> TokenStream {
+        let Self {
+            item,
+            symbol,
+            call_args,
+        } = self;
+        let fn_name = item.name();
+        let try_name = format_ident!("try_{fn_name}");
+        let attrs = &item.attrs;
+        let quals = item.quals(item.is_unsafe());
+        let head = item.head();
+        let ret_ty = ret_ty(&item.sig.output);
+        let where_clause = &item.sig.generics.where_clause;
+        let available = versions.available_cfg();
+        let unavailable = versions.unavailable_cfg();
+        let var = format_ident!("{}", upper_case(&symbol.to_string()));
+        let inline = if attrs.iter().any(|a| a.path().is_ident("inline")) {
+            None
+        } else {
+            Some(quote!(#[inline]))
+        };
+        let some_doc =
+            format!(" Safe variant of `{fn_name}`, always `Some` for current deployment target.");
+        let weak_doc = format!(" Calls `{fn_name}` if `{symbol}` is present at runtime.");
+        quote! {
+            #available
+            #[doc = #some_doc]
+            #inline
+            #(#attrs)*
+            #quals fn #try_name #head -> Option<#ret_ty> #where_clause {
+                Some(unsafe { #symbol #call_args })
+            }
+
+            #unavailable
+            #[doc = #weak_doc]
+            #inline
+            #(#attrs)*
+            #quals fn #try_name #head -> Option<#ret_ty> #where_clause {
+                unsafe { #var.get_fn().map(|f| f #call_args) }
+            }
+        }
+    }
+}
+
+pub(crate) fn gen_api_weak(body: TokenStream) -> Result<TokenStream> {
+    let block: ItemForeignMod = syn::parse2(body.clone())
+        .map_err(|e| Error::new(e.span(), "#[api::weak] expects extern block"))?;
+    let mut vars = TokenStream::new();
+    for item in &block.items {
+        let (attrs, name, ty) = match item {
+            ForeignItem::Fn(f) => {
+                let inputs = &f.sig.inputs;
+                let variadic = &f.sig.variadic;
+                let output = &f.sig.output;
+                (
+                    &f.attrs,
+                    &f.sig.ident,
+                    quote!(extern "C" fn(#inputs #variadic) #output),
+                )
+            }
+            ForeignItem::Static(s) => (&s.attrs, &s.ident, s.ty.to_token_stream()),
+            _ => continue,
+        };
+        let Some(versions) = weak_versions(attrs)? else {
+            continue;
+        };
+        let ty = match item {
+            ForeignItem::Static(s) => match &*s.ty {
+                Type::Reference(r) if r.lifetime.as_ref().is_some_and(|l| l.ident == "static") => {
+                    r.elem.to_token_stream()
+                }
+                ty => {
+                    return Err(Error::new(
+                        ty.span(),
+                        "weak static should be declared as `static NAME: &'static Type;`",
+                    ));
+                }
+            },
+            _ => ty,
+        };
+        let upper_name = format_ident!("{}", upper_case(&name.to_string()));
+        let availability = versions.unavailable_cfg();
+        let symbol = c_str(&name.to_string());
+        vars.extend(quote! {
+            #availability
+            static #upper_name: api::DlSym<#ty> = api::DlSym::new(#symbol);
+        });
+    }
+    let mut res = body;
+    res.extend(vars);
+    Ok(res)
+}
+
+/// Versions of `#[api::available(..)]`, `#[objc::available(..)]` or `#[available(..)]`.
+fn weak_versions(attrs: &[Attribute]) -> Result<Option<Versions>> {
+    for attr in attrs {
+        let path = attr.path();
+        let available = match path.segments.len() {
+            1 => path.is_ident("available"),
+            2 => {
+                let ns = &path.segments[0].ident;
+                (ns == "api" || ns == "objc") && path.segments[1].ident == "available"
+            }
+            _ => false,
+        };
+        if !available {
+            continue;
+        }
+        if let syn::Meta::List(list) = &attr.meta {
+            return Versions::parse.parse2(list.tokens.clone()).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+// Super simple, but stable upper_case impl
+pub(crate) fn upper_case(str: &str) -> String {
+    let len = str.len();
+    let mut res = Vec::<u8>::with_capacity(len + 10);
+    let bytes = str.as_bytes();
+    let mut was_lowercase = false;
+    for ch in bytes {
+        let is_upper = ch.is_ascii_uppercase();
+        if was_lowercase && is_upper {
+            res.push(b'_');
+        }
+        res.push(ch.to_ascii_uppercase());
+        was_lowercase = !is_upper;
+    }
+
+    String::from_utf8(res).unwrap()
+}
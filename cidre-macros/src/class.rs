@@ -0,0 +1,657 @@
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{ParseStream, Parser},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Error, Ident, ImplItem, ItemImpl, Meta, Result, ReturnType, Token, Type,
+};
+
+use crate::{
+    attr::{is_cfg, ns_name, sel_args_count},
+    sig::{c_str, has_fn, ret_ty, FnBody, FnItem, Params},
+};
+
+pub(crate) fn gen_class(args: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let mut def = ClassDef::default();
+    def.parse_args(args)?;
+
+    const EXPECTED: &str = "#[objc::class] expects inherent impl block";
+    let mut imp: ItemImpl =
+        syn::parse2(item).map_err(|_| Error::new(Span::call_site(), EXPECTED))?;
+    if let Some(lt) = &imp.generics.lt_token {
+        return Err(Error::new(
+            lt.span,
+            "#[objc::class] doesn't support generic classes",
+        ));
+    }
+    if let Some((_, _, for_token)) = &imp.trait_ {
+        return Err(Error::new(
+            for_token.span,
+            "#[objc::class] expects inherent impl block, use #[objc::add_methods] for protocols",
+        ));
+    }
+    def.cfgs = imp.attrs.iter().filter(|a| is_cfg(a)).cloned().collect();
+    def.ty = (*imp.self_ty).clone();
+
+    let items = std::mem::take(&mut imp.items);
+    for item in items {
+        if let Some(item) = def.parse_item(item)? {
+            imp.items.push(item);
+        }
+    }
+    imp.items.push(ImplItem::Verbatim(def.members()));
+
+    let mut res = imp.into_token_stream();
+    res.extend(def.alloc_impl());
+    Ok(res)
+}
+
+/// Expands `#[objc::class]` of `imp` with its methods replaced by `items`,
+/// the way snapshots of the string based implementation were recorded.
+#[cfg(test)]
+pub(crate) fn gen_class_items(
+    args: TokenStream,
+    imp: ItemImpl,
+    items: TokenStream,
+) -> Result<TokenStream> {
+    let mut def = ClassDef::default();
+    def.parse_args(args)?;
+    def.ty = (*imp.self_ty).clone();
+    for item in imp.items {
+        def.parse_item(item)?;
+    }
+    let ty = &def.ty;
+    let members = def.members();
+    let mut res = quote!(impl #ty { #items #members });
+    res.extend(def.alloc_impl());
+    Ok(res)
+}
+
+/// Parsed `#[objc::class]` impl block.
+struct ClassDef {
+    /// Rust type of the impl block.
+    ty: Type,
+    /// Objective-C class name, Rust type name if `None`.
+    name: Option<Ident>,
+    /// Superclass Rust type, `NSObject` if `None`.
+    sup: Option<TokenStream>,
+    /// `XxxImpl` traits of protocols the class conforms to.
+    protocols: Vec<Type>,
+    ivars: Option<TokenStream>,
+    /// `#[cfg]` attributes of the impl block.
+    cfgs: Vec<Attribute>,
+    methods: Vec<ClassMethod>,
+    super_sends: Vec<SuperSend>,
+    /// Name of `#[objc::dealloc]` hook.
+    dealloc: Option<Ident>,
+}
+
+impl Default for ClassDef {
+    fn default() -> Self {
+        Self {
+            ty: Type::Verbatim(TokenStream::new()),
+            name: None,
+            sup: None,
+            protocols: vec![],
+            ivars: None,
+            cfgs: vec![],
+            methods: vec![],
+            super_sends: vec![],
+            dealloc: None,
+        }
+    }
+}
+
+/// Method implemented in Rust and registered on the class.
+struct ClassMethod {
+    sel: String,
+    fn_name: Ident,
+    /// Registered on metaclass, has no `self` receiver.
+    class: bool,
+    /// Names and types of arguments without receiver.
+    args: Vec<(Ident, Type)>,
+    output: ReturnType,
+    /// `#[cfg]` attributes of the method.
+    cfgs: Vec<Attribute>,
+}
+
+/// `#[objc::msg_send_super]` declaration.
+struct SuperSend {
+    sel: String,
+    item: FnItem,
+    /// Types of arguments without receiver.
+    types: Vec<Type>,
+    /// Names of arguments without receiver.
+    vars: Vec<Ident>,
+}
+
+enum ClassAttr {
+    Method(String, Span),
+    ClassMethod(String, Span),
+    MsgSendSuper(String, Span),
+    Dealloc,
+}
+
+impl ClassAttr {
+    fn parse(attr: &Attribute) -> Result<Option<Self>> {
+        let Some((ns, name)) = ns_name(attr) else {
+            return Ok(None);
+        };
+        if ns != "objc" {
+            return Ok(None);
+        }
+        let name = name.to_string();
+        if name == "dealloc" {
+            return Ok(Some(Self::Dealloc));
+        }
+        if name != "method" && name != "class_method" && name != "msg_send_super" {
+            return Ok(None);
+        }
+        let tokens = match &attr.meta {
+            Meta::List(list) => list.tokens.clone(),
+            _ => TokenStream::new(),
+        };
+        let Some(first) = tokens.clone().into_iter().next() else {
+            return Err(Error::new(
+                attr.bracket_token.span.join(),
+                format!("expected selector, e.g. `#[objc::{name}(initWithFrame:)]`"),
+            ));
+        };
+        let span = first.span();
+        let sel = tokens.to_string().replace([' ', '\n'], "");
+        Ok(Some(match name.as_str() {
+            "method" => Self::Method(sel, span),
+            "class_method" => Self::ClassMethod(sel, span),
+            _ => Self::MsgSendSuper(sel, span),
+        }))
+    }
+}
+
+impl ClassDef {
+    fn parse_args(&mut self, args: TokenStream) -> Result<()> {
+        const EXPECTED: &str = "expected `super`, `protocols`, `ivars` or `name`";
+        let mut seen = Vec::new();
+        for part in split_args(args) {
+            let mut iter = part.into_iter();
+            let key = match iter.next() {
+                Some(TokenTree::Ident(key)) => key,
+                Some(tt) => return Err(Error::new(tt.span(), EXPECTED)),
+                None => continue,
+            };
+            let key_str = key.to_string();
+            if seen.contains(&key_str) {
+                return Err(Error::new(key.span(), format!("duplicate `{key}`")));
+            }
+            match iter.next() {
+                Some(TokenTree::Punct(p)) if p.as_char() == '=' => {}
+                _ => {
+                    return Err(Error::new(
+                        key.span(),
+                        format!("expected `=` after `{key}`"),
+                    ))
+                }
+            }
+            let value = Vec::from_iter(iter);
+            if value.is_empty() {
+                return Err(Error::new(key.span(), format!("expected value of `{key}`")));
+            }
+            match key_str.as_str() {
+                "super" => self.sup = Some(TokenStream::from_iter(value)),
+                "ivars" => self.ivars = Some(TokenStream::from_iter(value)),
+                "name" => match value.as_slice() {
+                    [TokenTree::Ident(name)] => self.name = Some(name.clone()),
+                    _ => {
+                        return Err(Error::new(
+                            value[0].span(),
+                            "expected class name, e.g. `name = CidreView`",
+                        ));
+                    }
+                },
+                "protocols" => {
+                    let protocols = match value.as_slice() {
+                        [TokenTree::Group(g)] if g.delimiter() == Delimiter::Bracket => {
+                            Punctuated::<Type, Token![,]>::parse_terminated
+                                .parse2(g.stream())
+                                .ok()
+                        }
+                        _ => None,
+                    };
+                    let Some(protocols) = protocols else {
+                        return Err(Error::new(
+                            value[0].span(),
+                            "expected protocol impl traits, e.g. `protocols = [ns::AppDelegateImpl]`",
+                        ));
+                    };
+                    self.protocols.extend(protocols);
+                }
+                _ => return Err(Error::new(key.span(), EXPECTED)),
+            }
+            seen.push(key_str);
+        }
+        Ok(())
+    }
+
+    /// Collects class method, returns item without class attribute or `None` for super sends.
+    fn parse_item(&mut self, item: ImplItem) -> Result<Option<ImplItem>> {
+        let (attrs, rest) = (|input: ParseStream| {
+            Ok((
+                input.call(Attribute::parse_outer)?,
+                input.parse::<TokenStream>()?,
+            ))
+        })
+        .parse2(item.to_token_stream())?;
+
+        let mut class_attr = None;
+        let mut other_attrs = Vec::with_capacity(attrs.len());
+        for attr in attrs {
+            match ClassAttr::parse(&attr)? {
+                Some(a) => {
+                    if class_attr.replace(a).is_some() {
+                        return Err(Error::new(
+                            attr.bracket_token.span.join(),
+                            "only one #[objc::class] method attribute is allowed",
+                        ));
+                    }
+                }
+                None => other_attrs.push(attr),
+            }
+        }
+        let Some(class_attr) = class_attr else {
+            return Ok(Some(item));
+        };
+        if !has_fn(&rest) {
+            let span = other_attrs.first().map_or_else(
+                || {
+                    rest.clone()
+                        .into_iter()
+                        .next()
+                        .map_or_else(Span::call_site, |tt| tt.span())
+                },
+                |a| a.pound_token.span,
+            );
+            return Err(Error::new(span, "expected function"));
+        }
+        let mut item: FnItem = syn::parse2(rest)?;
+        item.attrs = other_attrs;
+        Ok(self
+            .parse_method(class_attr, item)?
+            .map(|item| ImplItem::Verbatim(item.into_token_stream())))
+    }
+
+    fn parse_method(&mut self, attr: ClassAttr, item: FnItem) -> Result<Option<FnItem>> {
+        crate::sig::check_ret(&item.sig.output)?;
+        let params = Params::new(&item.sig)?;
+        let receiver = params.receiver;
+        let args: Vec<_> = params
+            .args
+            .iter()
+            .map(|(n, t)| ((*n).clone(), (*t).clone()))
+            .collect();
+        let name = item.name();
+        let args_span = item.args_span();
+        let check_arity = |sel: &str, sel_span: Span| {
+            let sel_args_count = sel_args_count(sel, sel_span)?;
+            if sel_args_count != args.len() {
+                return Err(Error::new(
+                    args_span,
+                    format!(
+                        "selector `{sel}` takes {sel_args_count} argument(s), but function `{name}` has {}",
+                        args.len()
+                    ),
+                ));
+            }
+            Ok(())
+        };
+
+        let sel = match attr {
+            ClassAttr::MsgSendSuper(sel, span) => {
+                if let FnBody::Block(body) = &item.body {
+                    return Err(Error::new(
+                        body.span(),
+                        "#[objc::msg_send_super] function must not have a body, expected `;`",
+                    ));
+                }
+                if receiver.is_none() {
+                    return Err(Error::new(
+                        args_span,
+                        "#[objc::msg_send_super] expects `&self` or `&mut self`",
+                    ));
+                }
+                check_arity(&sel, span)?;
+                let (vars, types) = args.into_iter().unzip();
+                self.super_sends.push(SuperSend {
+                    sel,
+                    item,
+                    types,
+                    vars,
+                });
+                return Ok(None);
+            }
+            _ if matches!(item.body, FnBody::Semi(_)) => {
+                return Err(Error::new(name.span(), "expected function body"));
+            }
+            _ if item.sig.generics.lt_token.is_some() => {
+                return Err(Error::new(
+                    item.sig.generics.lt_token.span(),
+                    "objc methods can't be generic",
+                ));
+            }
+            ClassAttr::Dealloc => {
+                if receiver.is_none() || !args.is_empty() {
+                    return Err(Error::new(
+                        args_span,
+                        "#[objc::dealloc] expects `fn name(&mut self)`",
+                    ));
+                }
+                if self.dealloc.replace(name.clone()).is_some() {
+                    return Err(Error::new(name.span(), "duplicate #[objc::dealloc]"));
+                }
+                return Ok(Some(item));
+            }
+            ClassAttr::Method(sel, span) => {
+                if receiver.is_none_or(|r| r.reference.is_none()) {
+                    return Err(Error::new(
+                        args_span,
+                        "#[objc::method] expects `&self` or `&mut self`, use #[objc::class_method] for class methods",
+                    ));
+                }
+                check_arity(&sel, span)?;
+                sel
+            }
+            ClassAttr::ClassMethod(sel, span) => {
+                if receiver.is_some() {
+                    return Err(Error::new(
+                        args_span,
+                        "#[objc::class_method] must not have `self`, use #[objc::method] for instance methods",
+                    ));
+                }
+                check_arity(&sel, span)?;
+                sel
+            }
+        };
+
+        self.methods.push(ClassMethod {
+            sel,
+            fn_name: name.clone(),
+            class: receiver.is_none(),
+            args,
+            output: item.sig.output.clone(),
+            cfgs: item.attrs.iter().filter(|a| is_cfg(a)).cloned().collect(),
+        });
+        Ok(Some(item))
+    }
+
+    /// Registration, class and ivars accessors and super sends.
+    fn members(&self) -> TokenStream {
+        let ty = &self.ty;
+        let name = match &self.name {
+            Some(name) => name.to_string(),
+            None => ty.to_token_stream().to_string(),
+        };
+        let already_registered = format!("objc class {name} is already registered");
+        let name = c_str(&name);
+        let super_cls = match &self.sup {
+            Some(sup) => quote! {
+                unsafe { std::mem::transmute::<&'static objc::Class<#sup>, &'static objc::Class<objc::Id>>(<#sup>::cls()) }
+            },
+            None => quote!(unsafe { objc::NS_OBJECT }),
+        };
+
+        let mut register = TokenStream::new();
+        if let Some(ivars) = &self.ivars {
+            register.extend(quote! {
+                assert!(unsafe { objc::Ivars::<#ivars>::add_to(cls) });
+            });
+        }
+        for m in &self.methods {
+            register.extend(m.register(ty));
+        }
+        if self.ivars.is_some() || self.dealloc.is_some() {
+            register.extend(self.register_dealloc());
+        }
+        for p in &self.protocols {
+            register.extend(quote! {
+                <Self as #p>::cls_add_methods(cls);
+                <Self as #p>::cls_add_protocol(cls);
+            });
+        }
+
+        let mut res = quote! {
+            fn register_cls() -> &'static objc::Class<Self> {
+                let cls = unsafe {
+                    objc::objc_allocateClassPair(Self::super_cls(), #name.as_ptr() as _, 0)
+                };
+                let cls = cls.expect(#already_registered);
+                #register
+                unsafe { objc::objc_registerClassPair(cls) };
+                unsafe { std::mem::transmute(cls) }
+            }
+
+            #[allow(dead_code)]
+            pub fn cls() -> &'static objc::Class<Self> {
+                static CLS: std::sync::OnceLock<usize> = std::sync::OnceLock::new();
+                let cls = *CLS.get_or_init(|| Self::register_cls() as *const objc::Class<Self> as usize);
+                unsafe { &*(cls as *const objc::Class<Self>) }
+            }
+
+            #[allow(dead_code)]
+            #[inline]
+            pub fn cls_ptr() -> *const std::ffi::c_void {
+                Self::cls() as *const objc::Class<Self> as _
+            }
+
+            #[allow(dead_code)]
+            #[inline]
+            pub fn super_cls() -> &'static objc::Class<objc::Id> {
+                #super_cls
+            }
+        };
+
+        res.extend(match &self.ivars {
+            Some(ivars) => quote! {
+                #[allow(dead_code)]
+                #[inline]
+                pub fn ivars(&self) -> &#ivars {
+                    unsafe { self.rs_ivars() }.get()
+                }
+
+                #[allow(dead_code)]
+                #[inline]
+                pub fn ivars_mut(&mut self) -> &mut #ivars {
+                    unsafe { self.rs_ivars() }.get_mut()
+                }
+
+                #[allow(clippy::mut_from_ref)]
+                unsafe fn rs_ivars(&self) -> &mut objc::Ivars<#ivars> {
+                    static OFFSET: std::sync::atomic::AtomicIsize = std::sync::atomic::AtomicIsize::new(0);
+                    unsafe { objc::Ivars::of(self, Self::cls(), &OFFSET) }
+                }
+
+                /// Allocates instance with `ivars`, finish it with `init` family message.
+                #[allow(dead_code)]
+                pub fn alloc_with(ivars: #ivars) -> arc::A<Self> {
+                    let res = Self::cls().alloc();
+                    unsafe {
+                        let obj: &Self = std::mem::transmute_copy(&res);
+                        obj.rs_ivars().set(ivars);
+                    }
+                    res
+                }
+
+                #[allow(dead_code)]
+                pub fn with(ivars: #ivars) -> arc::R<Self> {
+                    Self::alloc_with(ivars).init()
+                }
+            },
+            None => quote! {
+                #[allow(dead_code)]
+                #[inline]
+                pub fn alloc() -> arc::A<Self> {
+                    Self::cls().alloc()
+                }
+
+                #[allow(dead_code)]
+                pub fn new() -> arc::R<Self> {
+                    Self::alloc().init()
+                }
+            },
+        });
+
+        for s in &self.super_sends {
+            res.extend(s.expand());
+        }
+        res
+    }
+
+    fn register_dealloc(&self) -> TokenStream {
+        let ty = &self.ty;
+        let hook = self.dealloc.as_ref().map(|hook| quote!(this.#hook();));
+        let drop = self
+            .ivars
+            .as_ref()
+            .map(|_| quote!(unsafe { this.rs_ivars() }.take();));
+        quote! {
+            {
+                extern "C" fn imp(this: &mut #ty, _cmd: Option<&objc::Sel>) {
+                    #hook
+                    #drop
+                    unsafe {
+                        let sup = objc::Super {
+                            receiver: this as *const #ty as _,
+                            super_cls: #ty::super_cls(),
+                        };
+                        let sig: extern "C" fn(&objc::Super, &objc::Sel) =
+                            std::mem::transmute(objc::objc_msgSendSuper as *const std::ffi::c_void);
+                        sig(&sup, objc::sel_reg_name(c"dealloc".as_ptr()));
+                    }
+                }
+                unsafe {
+                    let sel = objc::sel_reg_name(c"dealloc".as_ptr());
+                    let imp: extern "C" fn() = std::mem::transmute(imp as *const u8);
+                    objc::class_addMethod(cls, sel, imp, std::ptr::null());
+                }
+            }
+        }
+    }
+
+    /// `init` for allocated instances.
+    fn alloc_impl(&self) -> TokenStream {
+        let cfgs = &self.cfgs;
+        let ty = &self.ty;
+        quote! {
+            #(#cfgs)*
+            impl arc::A<#ty> {
+                #[objc::msg_send(init)]
+                pub fn init(self) -> arc::R<#ty>;
+            }
+        }
+    }
+}
+
+impl ClassMethod {
+    fn register(&self, ty: &Type) -> TokenStream {
+        let ClassMethod {
+            sel,
+            fn_name,
+            class,
+            args,
+            output,
+            cfgs,
+        } = self;
+        let typed = args.iter().map(|(n, t)| quote!(#n: #t));
+        let vars = args.iter().map(|(n, _)| n);
+        let (receiver, call, cls) = if *class {
+            (
+                quote!(_cls: *const std::ffi::c_void),
+                quote!(#ty::#fn_name(#(#vars),*)),
+                quote!(objc::object_getClass(cls as *const objc::Class<objc::Id> as _).unwrap()),
+            )
+        } else {
+            (
+                quote!(this: &mut #ty),
+                quote!(this.#fn_name(#(#vars),*)),
+                quote!(cls),
+            )
+        };
+        let sel = c_str(sel);
+        quote! {
+            #(#cfgs)*
+            {
+                extern "C" fn imp(#receiver, _cmd: Option<&objc::Sel> #(, #typed)*) #output {
+                    #call
+                }
+                unsafe {
+                    let sel = objc::sel_reg_name(#sel.as_ptr());
+                    let imp: extern "C" fn() = std::mem::transmute(imp as *const u8);
+                    objc::class_addMethod(#cls, sel, imp, std::ptr::null());
+                }
+            }
+        }
+    }
+}
+
+impl SuperSend {
+    fn expand(&self) -> TokenStream {
+        let SuperSend {
+            sel,
+            item,
+            types,
+            vars,
+        } = self;
+        let attrs = &item.attrs;
+        let quals = item.quals(item.is_unsafe());
+        let name = item.name();
+        let head = item.head();
+        let output = &item.sig.output;
+        let where_clause = &item.sig.generics.where_clause;
+        let ret_ty = ret_ty(output);
+        let sel = c_str(sel);
+        quote! {
+            #(#attrs)*
+            #quals fn #name #head #output #where_clause {
+                unsafe {
+                    let sup = objc::Super {
+                        receiver: self as *const Self as _,
+                        super_cls: Self::super_cls(),
+                    };
+                    let sel = objc::sel_reg_name(#sel.as_ptr());
+                    #[cfg(target_arch = "x86_64")]
+                    let fn_ptr = if std::mem::size_of::<#ret_ty>() > 16 {
+                        objc::objc_msgSendSuper_stret as *const std::ffi::c_void
+                    } else {
+                        objc::objc_msgSendSuper as *const std::ffi::c_void
+                    };
+                    #[cfg(not(target_arch = "x86_64"))]
+                    let fn_ptr = objc::objc_msgSendSuper as *const std::ffi::c_void;
+                    let sig: extern "C" fn(&objc::Super, &objc::Sel #(, #types)*) #output = std::mem::transmute(fn_ptr);
+                    sig(&sup, sel #(, #vars)*)
+                }
+            }
+        }
+    }
+}
+
+/// Splits `key = value` arguments by top level commas.
+fn split_args(stream: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut args: Vec<Vec<TokenTree>> = vec![Vec::new()];
+    let mut nesting = 0;
+    for tt in stream {
+        if let TokenTree::Punct(p) = &tt {
+            match p.as_char() {
+                '<' => nesting += 1,
+                '>' => nesting -= 1,
+                ',' if nesting == 0 => {
+                    args.push(Vec::new());
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        args.last_mut().unwrap().push(tt);
+    }
+    if args.last().is_some_and(Vec::is_empty) {
+        args.pop();
+    }
+    args
+}
@@ -1,1615 +1,583 @@
-/// This is all dirty hacks. We need to reimplement it with syn and quote
-///
-use std::{borrow::Cow, str::FromStr};
-
-use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
-
-enum Attr {
-    Optional,
-    MsgSend(String),
-    ApiAvailable(Versions),
-    DocAvailable,
-}
-
-impl Attr {
-    fn from_stream(stream: TokenStream) -> Option<Attr> {
-        let mut iter = stream.into_iter();
-        let Some(TokenTree::Ident(ident)) = iter.next() else {
-            return None;
-        };
-
-        let str = ident.to_string();
-        if str == "doc" {
-            let Some(TokenTree::Punct(p)) = iter.next() else {
-                return None;
-            };
-            assert_eq!(p, '=');
-            let Some(TokenTree::Literal(s)) = iter.next() else {
-                return None;
-            };
-            if s.to_string() == "\" # Availability\"" {
-                return Some(Attr::DocAvailable);
-            }
-        } else if str != "objc" && str != "api" {
-            return None;
-        }
-
-        let Some(TokenTree::Punct(p)) = iter.next() else {
-            return None;
-        };
-
-        assert_eq!(p, ':');
-
-        let Some(TokenTree::Punct(p)) = iter.next() else {
-            return None;
-        };
-
-        assert_eq!(p, ':');
-
-        if let Some(tt) = iter.next() {
-            match tt {
-                TokenTree::Group(v) => panic!("didn't expect group {v}"),
-                TokenTree::Ident(v) => {
-                    let v = v.to_string();
-                    return match v.as_str() {
-                        "optional" => Some(Attr::Optional),
-                        "msg_send" => {
-                            let Some(TokenTree::Group(a)) = iter.next() else {
-                                return None;
-                            };
-                            let sel = a.stream().to_string().replace([' ', '\n'], "");
-                            Some(Attr::MsgSend(sel))
-                        }
-                        "available" => {
-                            let Some(TokenTree::Group(a)) = iter.next() else {
-                                return None;
-                            };
-                            Some(Attr::ApiAvailable(Versions::from_stream(a.stream())))
-                        }
-                        _ => None,
-                    };
-                }
-                TokenTree::Punct(v) => panic!("didn't expect punct {v}"),
-                TokenTree::Literal(v) => panic!("didn't expect literal {v}"),
-            }
-        }
-
-        panic!("Unexpected attribute")
-    }
+//! Attribute macros behind `objc::msg_send`, `objc::protocol`, `objc::class`, `api::available` and friends.
+//!
+//! Inputs are parsed with `syn` and expansions are built with `quote`,
+//! so spans, generics, `where` clauses and attributes survive.
+
+use proc_macro::TokenStream;
+
+mod attr;
+mod available;
+mod class;
+mod msg_send;
+mod protocol;
+mod sig;
+
+fn expand(res: syn::Result<proc_macro2::TokenStream>) -> TokenStream {
+    res.unwrap_or_else(syn::Error::into_compile_error).into()
 }
 
 /// Should generate static fn sel_xxx function that gets selector.
 /// So user can check selector with is_reponds_to_sel
 #[proc_macro_attribute]
 pub fn optional(_sel: TokenStream, func: TokenStream) -> TokenStream {
-    let mut iter = func.clone().into_iter();
-    let Some(TokenTree::Punct(p)) = iter.next() else {
-        panic!("expect #[objc::msg_send(...)]")
-    };
-
-    if p != '#' {
-        panic!("expect #[objc::msg_send(...)]")
-    }
-    let Some(TokenTree::Group(g)) = iter.next() else {
-        panic!("expect #[objc::msg_send(...)]")
-    };
-
-    let Some(Attr::MsgSend(extern_name)) = Attr::from_stream(g.stream()) else {
-        panic!("expect #[objc::msg_send(...)]")
-    };
-
-    let mut fn_name = None;
-
-    while let Some(tt) = iter.next() {
-        match tt {
-            TokenTree::Ident(i) if i.to_string().eq("fn") => {
-                let Some(TokenTree::Ident(name)) = iter.next() else {
-                    panic!("expect function name");
-                };
-                fn_name = Some(name.to_string());
-            }
-            _ => {} // panic?
-        }
-    }
-
-    let Some(fn_name) = fn_name else {
-        panic!("function name not found");
-    };
-
-    let getter: TokenStream = format!(
-        "
-    /// `@selector({extern_name})` but dynamic
-    /// use this function to check if object responds to selector
-    fn sel_{fn_name}() -> &'static objc::Sel {{
-        unsafe {{ objc::sel_reg_name(c\"{extern_name}\".as_ptr()) }}
-    }}
-        "
-    )
-    .parse()
-    .unwrap();
+    expand(protocol::gen_optional(func.into()))
+}
 
-    let mut func = func;
-    func.extend(getter);
-    func
+/// Marks getter or setter of protocol property, e.g.
+///
+/// ```ignore
+/// #[objc::property]
+/// #[objc::msg_send(pan)]
+/// fn pan(&self) -> f32;
+///
+/// #[objc::property]
+/// #[objc::msg_send(setPan:)]
+/// fn set_pan(&mut self, val: f32);
+/// ```
+#[proc_macro_attribute]
+pub fn property(args: TokenStream, func: TokenStream) -> TokenStream {
+    expand(protocol::gen_property(args.into(), func.into()))
 }
 
+/// Declares caller side trait of Objective-C protocol and `XxxImpl` trait for implementing it.
+///
+/// ```ignore
+/// #[objc::protocol(NSSecureCoding)]
+/// pub trait SecureCoding: objc::Obj {
+///     // class method, default body is used by `SecureCodingImpl`
+///     #[objc::msg_send(supportsSecureCoding)]
+///     fn supports_secure_coding() -> bool {
+///         true
+///     }
+/// }
+/// ```
+///
+/// Optional methods without body are registered only by `#[objc::add_methods]`.
+/// Functions without `#[objc::msg_send]` are plain Rust helpers of the caller side trait.
+/// Protocols with class methods get `fn cls_ptr()` to implement on the caller side.
 #[proc_macro_attribute]
 pub fn protocol(args: TokenStream, ts: TokenStream) -> TokenStream {
-    let mut original_trait = ts.clone();
-    let error_msg = "objc::protocol expects protocol name as first argument";
-    let mut args = args.into_iter();
-    let Some(TokenTree::Ident(ident)) = args.next() else {
-        panic!("{}", error_msg);
-    };
-    assert!(args.next().is_none(), "{}", error_msg);
-    let protocol_name = ident.to_string();
-    let mut trait_name = String::new();
-
-    let mut pre_tokens = Vec::<TokenTree>::with_capacity(10);
-    let mut ts = ts.into_iter();
-    let mut group_stream = None;
-    while let Some(tt) = ts.next() {
-        match tt {
-            TokenTree::Group(ref g) if g.delimiter() == Delimiter::Brace => {
-                while let Some(tt) = pre_tokens.pop() {
-                    let val = tt.to_string();
-                    if val == "trait" {
-                        pre_tokens.push(tt);
-                        break;
-                    }
-                    trait_name = val;
-                }
-                group_stream = Some(g.stream());
-                break;
-            }
-            _ => pre_tokens.push(tt),
-        }
-    }
-    if trait_name != protocol_name {
-        let alias = format!("#[doc(alias = \"{protocol_name}\")]");
-        let stream = TokenStream::from_str(&alias).unwrap();
-        let mut alias_tokens = Vec::from_iter(stream.into_iter());
-        alias_tokens.append(&mut pre_tokens);
-        pre_tokens = alias_tokens;
-    }
-    let mut is_optional = false;
-    let mut skip = false;
-    let mut sel = String::new();
-    let mut fn_name; // = "".to_string();
-    let mut generics = Vec::new();
-    //let mut fn_args = Vec::new();
-    let mut fn_args_str; // = "".to_string();
-    let mut result = Vec::new();
-    let mut fn_body = Cow::Borrowed("");
-
-    let mut impl_trait_functions = vec![];
-    let mut has_optionals = false;
-    let mut fn_names = vec![];
-
-    let mut iter = group_stream.expect("should be group").into_iter();
-    while let Some(token) = iter.next() {
-        match token {
-            TokenTree::Group(g) => println!("group {g}"),
-            TokenTree::Ident(i) => {
-                let str = i.to_string();
-                if str == "fn" {
-                    let Some(TokenTree::Ident(name)) = iter.next() else {
-                        panic!("expect fn name");
-                    };
-                    fn_name = name.to_string();
-                    let args = loop {
-                        let Some(tt) = iter.next() else {
-                            panic!("need more tokens");
-                        };
-                        match tt {
-                            TokenTree::Group(args) => break args,
-                            _ => generics.push(tt),
-                        }
-                    };
-                    fn_args_str = args.to_string();
-                    for tt in iter.by_ref() {
-                        match tt {
-                            TokenTree::Punct(ref p) if p.as_char() == ';' => {
-                                result.push(tt);
-                                break;
-                            }
-                            TokenTree::Group(ref g) if g.delimiter() == Delimiter::Brace => {
-                                fn_body = Cow::Owned(g.to_string());
-                                break;
-                            }
-                            _ => result.push(tt),
-                        }
-                    }
-
-                    let mut ext = "";
-
-                    let register_sel = if sel.is_empty() {
-                        Cow::Borrowed("None")
-                    } else {
-                        ext = "extern \"C\" ";
-                        fn_args_str = fn_args_str.replacen("(& self", "(&self", 1);
-                        fn_args_str =
-                            fn_args_str.replacen("(&self", "(&self, _cmd: Option<&objc::Sel>", 1);
-
-                        fn_args_str = fn_args_str.replacen("(& mut self", "(&mut self", 1);
-                        fn_args_str = fn_args_str.replacen(
-                            "(&mut self",
-                            "(&mut self, _cmd: Option<&objc::Sel>",
-                            1,
-                        );
-                        Cow::Owned(format!(
-                            "unsafe {{ objc::sel_reg_name(c\"{sel}\".as_ptr()) }}"
-                        ))
-                    };
-
-                    if is_optional && !sel.is_empty() && fn_body.is_empty() {
-                        result.pop(); // remove ';'
-                        fn_body = Cow::Borrowed("{ unimplemented!() }");
-                    }
-
-                    if !is_optional && sel.is_empty() {
-                        skip = true;
-                    }
-
-                    let ret = if result.is_empty() {
-                        Cow::Borrowed("")
-                    } else {
-                        Cow::Owned(TokenStream::from_iter(result.clone().into_iter()).to_string())
-                    };
-
-                    let gen = if generics.is_empty() {
-                        Cow::Borrowed("")
-                    } else {
-                        Cow::Owned(TokenStream::from_iter(generics.clone().into_iter()).to_string())
-                    };
-
-                    let impl_fn = if skip {
-                        format!(
-                            "
-    {ext}fn {fn_name}{gen}{fn_args_str}{ret} {fn_body}
-
-                                    "
-                        )
-                    } else {
-                        fn_names.push(fn_name.clone());
-                        format!(
-                            "
-    {ext}fn impl_{fn_name}{gen}{fn_args_str}{ret} {fn_body}
-
-                                    "
-                        )
-                    };
-
-                    impl_trait_functions.push(impl_fn);
-
-                    if !is_optional && !skip {
-                        let impl_sel = format!(
-                            "
-    fn sel_{fn_name}() -> &'static objc::Sel {{ {register_sel} }}
-        "
-                        );
-                        impl_trait_functions.push(impl_sel);
-                    }
-
-                    is_optional = false;
-                    sel.clear();
-                    fn_name.clear();
-                    fn_body = Cow::Borrowed("");
-                    fn_args_str.clear();
-                    generics.clear();
-                    skip = false;
-                    //fn_args.clear();
-                    result.clear();
-                }
-            }
-            TokenTree::Punct(p) => match p.as_char() {
-                '#' => {
-                    let TokenTree::Group(g) = iter.next().unwrap() else {
-                        panic!("not a group");
-                    };
-                    match Attr::from_stream(g.stream()) {
-                        Some(Attr::Optional) => {
-                            has_optionals = true;
-                            is_optional = true;
-                        }
-                        Some(Attr::MsgSend(s)) => sel = s,
-                        Some(_) => continue,
-                        None => continue,
-                    }
-                }
-                _ => panic!("other char '{p}'"),
-            },
-            TokenTree::Literal(l) => println!("lit {l}"),
-        }
-    }
-    let pre = TokenStream::from_iter(pre_tokens).to_string();
-    let obj_trait_name = format!("{trait_name}Impl");
-    let fns = impl_trait_functions.join("\n");
-
-    let add_methods = if has_optionals {
-        Cow::Borrowed("fn cls_add_methods<O: objc::Obj>(cls: &objc::Class<O>);")
-    } else {
-        Cow::Owned(add_methods_fn(&fn_names))
-    };
-
-    let add_protocol = format!(
-        "
-    fn cls_add_protocol<O: objc::Obj>(cls: &objc::Class<O>) {{
-        unsafe {{
-            let cls: &objc::Class<objc::Id> = std::mem::transmute(cls);
-            if let Some(proto) =  objc::objc_getProtocol(c\"{protocol_name}\".as_ptr())  {{
-                cls.add_protocol(proto);
-            }}
-        }}
-    }}
-        "
-    );
-
-    let code = format!(
-        "
-
-{pre} {obj_trait_name}: {trait_name} {{
-    {fns}
-    {add_methods}
-    {add_protocol}
-}}
-        "
-    );
-
-    let ts: TokenStream = code.parse().unwrap();
-
-    original_trait.extend(ts);
-    original_trait
+    expand(protocol::gen_protocol(args.into(), ts.into()))
 }
 
-fn add_methods_fn(fns: &[String]) -> String {
-    let mut res = "
-    fn cls_add_methods<O: objc::Obj>(cls: &objc::Class<O>) {
-        let cls: &objc::Class<objc::Id> = unsafe { std::mem::transmute(cls) };
-        "
-    .to_string();
-    for f in fns {
-        let add = format!(
-            "
-        let sel = Self::sel_{f}();
-        unsafe {{
-            let imp: extern \"C\" fn() = std::mem::transmute(Self::impl_{f} as *const u8);
-            objc::class_addMethod(cls, sel, imp, std::ptr::null());
-        }}
-            "
-        );
-        res.push_str(&add);
-    }
-    res.push_str("\n}");
-    res
-}
 #[proc_macro_attribute]
 pub fn add_methods(_args: TokenStream, tr_impl: TokenStream) -> TokenStream {
-    let mut tokens = vec![];
-
-    let iter = tr_impl.into_iter();
-    let mut fns = vec![];
-
-    for tt in iter {
-        match tt {
-            TokenTree::Group(g) => {
-                let mut body = g.stream().into_iter();
-                while let Some(tt) = body.next() {
-                    match tt {
-                        TokenTree::Ident(i) if i.to_string().eq("fn") => {
-                            let Some(TokenTree::Ident(f)) = body.next() else {
-                                panic!("expected function name");
-                            };
-                            fns.push(f.to_string().replacen("impl_", "", 1));
-                        }
-                        _ => continue,
-                    }
-                }
-                let imp: TokenStream = add_methods_fn(&fns).parse().unwrap();
-                let mut stream = g.stream();
-                stream.extend(imp);
-                let g = Group::new(g.delimiter(), stream);
-                tokens.push(TokenTree::Group(g));
-            }
-            _ => tokens.push(tt),
-        }
-    }
-
-    // println!("fns {fns:?}");
-
-    TokenStream::from_iter(tokens)
+    expand(protocol::gen_add_methods(tr_impl.into()))
 }
+
 #[proc_macro_attribute]
 pub fn msg_send_debug(sel: TokenStream, func: TokenStream) -> TokenStream {
     let x86_64 = false;
-    gen_msg_send(sel, func, x86_64, true)
+    let res = msg_send::gen_msg_send(sel.into(), func.into(), x86_64);
+    if let Ok(flow) = &res {
+        println!("{flow}");
+    }
+    expand(res)
 }
 
 #[proc_macro_attribute]
 pub fn msg_send(sel: TokenStream, func: TokenStream) -> TokenStream {
     let x86_64 = false;
-    gen_msg_send(sel, func, x86_64, false)
+    expand(msg_send::gen_msg_send(sel.into(), func.into(), x86_64))
 }
 
 #[proc_macro_attribute]
 pub fn msg_send_x86_64(sel: TokenStream, func: TokenStream) -> TokenStream {
     let x86_64 = true;
-    gen_msg_send(sel, func, x86_64, false)
+    expand(msg_send::gen_msg_send(sel.into(), func.into(), x86_64))
 }
 
-fn gen_msg_send(sel: TokenStream, func: TokenStream, x86_64: bool, debug: bool) -> TokenStream {
-    let sel = sel.to_string().replace([' ', '\n'], "");
-    let sel_args_count = sel.matches(':').count();
-
-    let mut iter = func.into_iter();
-    let mut meta: Vec<TokenTree> = Vec::new();
-    let mut unsafe_already = false;
-    let mut optional_already = false;
-    let mut versions = Versions::default();
-
-    while let Some(tt) = iter.next() {
-        match tt {
-            TokenTree::Group(ref g) => {
-                if g.delimiter() == Delimiter::Bracket {
-                    match Attr::from_stream(g.stream()) {
-                        Some(Attr::Optional) => optional_already = true,
-                        Some(Attr::ApiAvailable(v)) => {
-                            versions = v;
-                            meta.pop();
-                            continue;
-                        }
-                        Some(Attr::DocAvailable) => {
-                            iter.next(); // Punct('#')
-                            let Some(TokenTree::Group(g)) = iter.next() else {
-                                panic!("Expect doc with versions");
-                            };
-                            let mut doc_iter = g.stream().into_iter();
-                            doc_iter.next(); // Ident("doc")
-                            doc_iter.next(); // Punct('=')
-                            let Some(TokenTree::Literal(s)) = doc_iter.next() else {
-                                panic!("Expect doc with versions");
-                            };
-                            let str = s.to_string();
-                            versions = Versions::from_doc_str(&str[1..str.len() - 1]);
-                            meta.push(tt.clone());
-                            meta.push(TokenTree::Punct(Punct::new(
-                                '#',
-                                proc_macro::Spacing::Joint,
-                            )));
-                            meta.push(TokenTree::Group(g));
-                            continue;
-                        }
-                        Some(Attr::MsgSend(_)) => panic!("only one msg_send is allowed"),
-                        None => {}
-                    }
-                }
-            }
-            TokenTree::Ident(ref i) => {
-                let i = i.to_string();
-                match i.as_str() {
-                    "fn" => break,
-                    "unsafe" => unsafe_already = true,
-                    _ => {}
-                }
-            }
-            TokenTree::Punct(_) => {}
-            TokenTree::Literal(_) => {}
-        }
-        meta.push(tt);
-    }
+/// Defines Objective-C class from inherent impl block of `define_obj_type!(pub Foo(ns::View))` type.
+///
+/// ```ignore
+/// #[objc::class(super = ns::View, ivars = ViewState, protocols = [ns::AppDelegateImpl])]
+/// impl MyView {
+///     #[objc::method(drawRect:)]
+///     fn draw_rect(&mut self, rect: cg::Rect) {
+///         self.super_draw_rect(rect);
+///     }
+///
+///     #[objc::msg_send_super(drawRect:)]
+///     fn super_draw_rect(&mut self, rect: cg::Rect);
+///
+///     #[objc::class_method(layerClass)]
+///     fn layer_cls() -> &'static objc::Class<ca::Layer> { .. }
+///
+///     #[objc::dealloc]
+///     fn cleanup(&mut self) { .. }
+/// }
+/// ```
+///
+/// `name = CidreMyView` overrides objc class name which is the Rust type name by default.
+#[proc_macro_attribute]
+pub fn class(args: TokenStream, item: TokenStream) -> TokenStream {
+    expand(class::gen_class(args.into(), item.into()))
+}
 
-    let Some(TokenTree::Ident(fn_name)) = iter.next() else {
-        panic!("expected function name");
-    };
+#[proc_macro_attribute]
+pub fn api_weak(_ts: TokenStream, body: TokenStream) -> TokenStream {
+    expand(available::gen_api_weak(body.into()))
+}
 
-    let fn_name = fn_name.to_string();
-    let mut generics = Vec::new();
-    let doc_alias = if fn_name != sel {
-        format!("#[doc(alias = \"{sel}\")]")
-    } else {
-        String::new()
-    };
+#[proc_macro_attribute]
+pub fn api_available(versions: TokenStream, body: TokenStream) -> TokenStream {
+    expand(available::gen_api_available(versions.into(), body.into()))
+}
 
-    let args = loop {
-        let Some(tt) = iter.next() else {
-            panic!("need more tokens");
+#[cfg(test)]
+mod tests {
+    use proc_macro2::{Delimiter, TokenStream, TokenTree};
+    use quote::{quote, ToTokens};
+    use syn::parse::{ParseStream, Parser};
+
+    /// Runs the macro the way rustc would.
+    fn run(name: &str, args: TokenStream, item: TokenStream, x86_64: bool) -> TokenStream {
+        let res = match name {
+            "msg_send" => crate::msg_send::gen_msg_send(args, item, x86_64),
+            "optional" => crate::protocol::gen_optional(item),
+            "property" => crate::protocol::gen_property(args, item),
+            "protocol" => crate::protocol::gen_protocol(args, item),
+            "add_methods" => crate::protocol::gen_add_methods(item),
+            "class" => crate::class::gen_class(args, item),
+            "available" => crate::available::gen_api_available(args, item),
+            "weak" => crate::available::gen_api_weak(item),
+            _ => unreachable!(),
         };
-        match tt {
-            TokenTree::Group(args) => break args,
-            _ => generics.push(tt),
-        }
-    };
-
-    let gen = TokenStream::from_iter(generics).to_string();
-
-    let mut ret = TokenStream::from_iter(iter).to_string();
-    assert_eq!(ret.pop().expect(";"), ';');
-    let ret_full = ret.to_string();
-    if let Some((a, _)) = ret.split_once("where") {
-        ret = a.to_string();
-    }
-    let option = ret_full.contains("-> Option <");
-    if debug {
-        println!("{option}: {ret_full}");
-    }
-    let gen_rar_version =
-        !sel.starts_with("new") && ret.contains("arc :: R <") && !sel.starts_with("initWith");
-
-    if debug {
-        println!("option: {option}, gen_rar_version {gen_rar_version} ret: {ret}");
-    }
-
-    let fn_args = args.to_string();
-
-    let (class, vars) = fn_args_from_stream(args.stream());
-    let fn_args_count = vars.len();
-
-    assert_eq!(
-        sel_args_count, fn_args_count,
-        "selector and function args don't match {vars:?}"
-    );
-
-    let (mut fn_args, mut call_args) = if x86_64 {
-        let fn_args = fn_args.replacen('(', "(id:", 1).replacen(
-            "self",
-            "Self, imp: *const std::ffi::c_void",
-            1,
-        );
-        (
-            fn_args,
-            format!("sig(self, x86_64_sel, {})", vars.join(", ")),
-        )
-    } else if fn_args_count == 0 {
-        let fn_args = fn_args
-            .replacen("( &", "(id: &", 1)
-            .replacen("self", "Self", 1);
-        (fn_args, "sig(self)".to_string())
-    } else {
-        let fn_args = fn_args
-            .replacen('(', "(id:", 1)
-            .replace("self", "Self, imp: *const std::ffi::c_void");
-        (
-            fn_args,
-            format!("sig(self, std::ptr::null(), {})", vars.join(", ")),
-        )
-    };
-
-    if class {
-        if x86_64 {
-            fn_args = fn_args.replacen(
-                "(id:",
-                "(cls: *const std::ffi::c_void, imp: *const std::ffi::c_void, ",
-                1,
-            );
-            call_args = call_args.replacen("sig(self", "sig(Self::cls_ptr()", 1);
-        } else if fn_args_count == 0 {
-            fn_args = fn_args.replacen('(', "(cls: *const std::ffi::c_void", 1);
-            call_args = call_args.replacen("sig(self", "sig(Self::cls_ptr()", 1);
-        } else {
-            fn_args = fn_args.replacen(
-                "(id:",
-                "(cls: *const std::ffi::c_void, imp: *const std::ffi::c_void, ",
-                1,
-            );
-            call_args = call_args.replacen("sig(self", "sig(Self::cls_ptr()", 1);
-        }
+        res.unwrap_or_else(syn::Error::into_compile_error)
     }
 
-    let available = versions.available_cfg();
-    let unavailable = versions.unavailable_cfg();
-
-    let mut flow = String::new();
-    let pre = TokenStream::from_iter(meta).to_string();
-    let self_ = if class { "Self::" } else { "self." };
-    let vars = vars.join(", ");
-    let mut impl_fn_name = fn_name.clone();
-    let impl_ret_full = if gen_rar_version {
-        ret_full.replacen("arc :: R <", "arc :: Rar <", 1)
-    } else {
-        ret_full.clone()
-    };
-    let impl_ret = if gen_rar_version {
-        ret.replacen("arc :: R <", "arc :: Rar <", 1)
-    } else {
-        ret
-    };
-
-    if gen_rar_version {
-        impl_fn_name.push_str("_ar");
-    }
-    if x86_64 {
-        flow.push_str(&format!(
-            "
-    {available}
-    {doc_alias}
-    #[inline]
-    {pre} fn {impl_fn_name}{gen}{args}{impl_ret_full} {{
-        extern \"C\" {{
-            #[link_name = \"objc_msgSend\"]
-            fn msg_send();
-        }}
-        extern \"C-unwind\" {{
-            fn sel_registerName(name: *const i8) -> *const std::ffi::c_void;
-        }}
-
-        unsafe {{
-            let x86_64_sel = sel_registerName(c\"{sel}\".as_ptr());
-            let fn_ptr = msg_send as *const std::ffi::c_void;
-            let sig: extern \"C\" fn{fn_args} {impl_ret} = std::mem::transmute(fn_ptr);
-
-            {call_args}
-        }}
-    }}
-            "
-        ));
-        if versions.any() {
-            let unsafe_str = if unsafe_already { "" } else { "unsafe" };
-            let optional = if optional_already {
-                String::new()
-            } else {
-                format!(
-                    "
-    /// `@selector({sel})` but dynamic
-    /// use this function to check if object responds to selector
-    #[inline]
-    pub fn sel_{fn_name}() -> &'static objc::Sel {{
-        unsafe {{ objc::sel_reg_name(c\"{sel}\".as_ptr()) }}
-    }}
-        "
-                )
-            };
-
-            flow.push_str(&format!(
-                "
-    {optional}
-
-    {unavailable}
-    {doc_alias}
-    #[inline]
-    {pre} {unsafe_str} fn {impl_fn_name}{gen}{args}{impl_ret_full} {{
-        extern \"C\" {{
-            #[link_name = \"objc_msgSend\"]
-            fn msg_send();
-
-        }}
-        extern \"C-unwind\" {{
-            fn sel_registerName(name: *const i8) -> *const std::ffi::c_void;
-        }}
-
-        unsafe {{
-            let x86_64_sel = sel_registerName(c\"{sel}\".as_ptr());
-            let fn_ptr = msg_send as *const std::ffi::c_void;
-            let sig: extern \"C\" fn{fn_args} {impl_ret} = std::mem::transmute(fn_ptr);
-
-            {call_args}
-        }}
-    }}
-                "
-            ));
-        }
-    } else {
-        flow.push_str(&format!(
-            "
-    {available}
-    {doc_alias}
-    #[inline]
-    {pre} fn {impl_fn_name}{gen}{args}{impl_ret_full} {{
-        extern \"C\" {{
-            #[link_name = \"objc_msgSend${sel}\"]
-            fn msg_send();
-        }}
-
-        unsafe {{
-            let fn_ptr = msg_send as *const std::ffi::c_void;
-            let sig: extern \"C\" fn{fn_args} {impl_ret} = std::mem::transmute(fn_ptr);
-
-            {call_args}
-        }}
-    }}
-            "
-        ));
-        if versions.any() {
-            let unsafe_str = if unsafe_already { "" } else { "unsafe" };
-            let optional = if optional_already {
-                String::new()
-            } else {
-                format!(
-                    "
-    /// `@selector({sel})` but dynamic
-    /// use this function to check if object responds to selector
-    #[inline]
-    pub fn sel_{fn_name}() -> &'static objc::Sel {{
-        unsafe {{ objc::sel_reg_name(c\"{sel}\".as_ptr()) }}
-    }}
-        "
-                )
-            };
-
-            flow.push_str(&format!(
-                "
-    {optional}
-
-    {unavailable}
-    {doc_alias}
-    #[inline]
-    {pre} {unsafe_str} fn {impl_fn_name}{gen}{args}{impl_ret_full} {{
-        extern \"C\" {{
-            #[link_name = \"objc_msgSend${sel}\"]
-            fn msg_send();
-        }}
-
-        let fn_ptr = msg_send as *const std::ffi::c_void;
-        let sig: extern \"C\" fn{fn_args} {impl_ret} = std::mem::transmute(fn_ptr);
-
-        {call_args}
-    }}
-                "
-            ));
-        }
-    };
-
-    if gen_rar_version {
-        if debug {
-            println!("get rar version");
+    /// Name of `#[objc::xxx]` or `#[api::xxx]` macro attribute.
+    fn macro_name(attr: &syn::Attribute) -> Option<&'static str> {
+        let (ns, name) = crate::attr::ns_name(attr)?;
+        if ns != "objc" && ns != "api" {
+            return None;
         }
-        if option {
-            flow.push_str(&format!(
-                "
-
-    {available}
-    {doc_alias}
-    #[inline]
-    {pre} fn {fn_name}{gen}{args}{ret_full} {{
-        arc::rar_retain_option({self_}{fn_name}_ar({vars}) )
-    }}
-                "
-            ));
-            if versions.any() {
-                let unsafe_str = if unsafe_already { "" } else { "unsafe" };
-                flow.push_str(&format!(
-                    "
-
-    {unavailable}
-    {doc_alias}
-    #[inline]
-    /// Check availability with selector1 `Self::sel_{fn_name}()`
-    {pre} {unsafe_str} fn {fn_name}{gen}{args}{ret_full} {{
-        arc::rar_retain_option({self_}{fn_name}_ar({vars}) )
-    }}
-                      ",
-                ));
+        let names = [
+            "msg_send",
+            "optional",
+            "property",
+            "protocol",
+            "add_methods",
+            "class",
+            "available",
+            "weak",
+        ];
+        names.into_iter().find(|n| name == n)
+    }
+
+    #[derive(Clone, Copy)]
+    enum Ctx {
+        File,
+        Impl,
+        Trait,
+        Foreign,
+    }
+
+    impl Ctx {
+        fn items(self, ts: TokenStream) -> Vec<TokenStream> {
+            fn tokens<T: ToTokens>(items: &[T]) -> Vec<TokenStream> {
+                items.iter().map(ToTokens::to_token_stream).collect()
             }
-        } else {
-            // not option
-            flow.push_str(&format!(
-                "
-
-    {available}
-    {doc_alias}
-    #[inline]
-    {pre} fn {fn_name}{gen}{args}{ret_full} {{
-        arc::rar_retain({self_}{fn_name}_ar({vars}))
-    }}
-                ",
-            ));
-            if versions.any() {
-                let unsafe_str = if unsafe_already { "" } else { "unsafe" };
-                flow.push_str(&format!(
-                    "
-
-    {unavailable}
-    {doc_alias}
-    /// Check availability with selector `Self::sel_{fn_name}()`
-    #[inline]
-    {pre} {unsafe_str} fn {fn_name}{gen}{args}{ret_full} {{
-        arc::rar_retain({self_}{fn_name}_ar({vars}))
-    }}
-                "
-                ));
+            match self {
+                Self::File => tokens(&syn::parse2::<syn::File>(ts).unwrap().items),
+                Self::Impl => tokens(
+                    &syn::parse2::<syn::ItemImpl>(quote!(impl X { #ts }))
+                        .unwrap()
+                        .items,
+                ),
+                Self::Trait => tokens(
+                    &syn::parse2::<syn::ItemTrait>(quote!(trait X { #ts }))
+                        .unwrap()
+                        .items,
+                ),
+                Self::Foreign => tokens(
+                    &syn::parse2::<syn::ItemForeignMod>(quote!(extern "C" { #ts }))
+                        .unwrap()
+                        .items,
+                ),
             }
         }
     }
-    if debug {
-        println!("{flow}");
-    }
 
-    flow.parse().unwrap()
-}
-
-fn fn_args_from_stream(stream: TokenStream) -> (bool, Vec<String>) {
-    if stream.is_empty() {
-        return (true, Vec::new());
-    }
-    let mut res = Vec::new();
-    let mut pos = 0;
-    let mut self_arg = false;
-    let mut skip_ident = false;
-    let mut lifetime = false;
-    let mut nesting = 0;
-    for s in stream.into_iter() {
-        match s {
-            TokenTree::Group(_) => {}
-            TokenTree::Ident(ref i) => {
-                if lifetime {
-                    lifetime = false;
-                    continue;
+    /// Expands macro attributes of `items` until none is left.
+    fn expand(ctx: Ctx, items: TokenStream, x86_64: bool) -> TokenStream {
+        let mut res = TokenStream::new();
+        for item in ctx.items(items) {
+            let (mut attrs, rest) = (|input: ParseStream| {
+                Ok((
+                    input.call(syn::Attribute::parse_outer)?,
+                    input.parse::<TokenStream>()?,
+                ))
+            })
+            .parse2(item.clone())
+            .unwrap();
+            if let Some(pos) = attrs.iter().position(|a| macro_name(a).is_some()) {
+                let attr = attrs.remove(pos);
+                let name = macro_name(&attr).unwrap();
+                let args = match attr.meta {
+                    syn::Meta::List(list) => list.tokens,
+                    _ => TokenStream::new(),
+                };
+                let output = run(name, args, quote!(#(#attrs)* #rest), x86_64);
+                res.extend(expand(ctx, output, x86_64));
+                continue;
+            }
+            res.extend(match syn::parse2(item.clone()) {
+                Ok(syn::Item::Impl(mut i)) => {
+                    let ts = expand(
+                        Ctx::Impl,
+                        i.items.iter().map(ToTokens::to_token_stream).collect(),
+                        x86_64,
+                    );
+                    i.items = syn::parse2::<syn::ItemImpl>(quote!(impl X { #ts }))
+                        .unwrap()
+                        .items;
+                    i.into_token_stream()
                 }
-                if !skip_ident {
-                    let str = i.to_string();
-                    if str == "mut" {
-                        continue;
-                    }
-                    if pos == 0 && str == "self" {
-                        self_arg = true;
-                        continue;
-                    }
-                    res.push(str);
-                    skip_ident = true;
+                Ok(syn::Item::Trait(mut t)) => {
+                    let ts = expand(
+                        Ctx::Trait,
+                        t.items.iter().map(ToTokens::to_token_stream).collect(),
+                        x86_64,
+                    );
+                    t.items = syn::parse2::<syn::ItemTrait>(quote!(trait X { #ts }))
+                        .unwrap()
+                        .items;
+                    t.into_token_stream()
                 }
-            }
-            // #[objc::msg_send_debug(objectForKey:)]
-            // pub fn get<'a>(&'a self, key: &K) -> Option<&'a V>;
-            TokenTree::Punct(p) => match p.as_char() {
-                '<' => nesting += 1,
-                '>' => nesting -= 1,
-                '\'' => lifetime = true,
-                // '&' => skip_ident = true,
-                ',' if nesting == 0 => {
-                    pos += 1;
-                    skip_ident = false;
+                Ok(syn::Item::ForeignMod(mut f)) => {
+                    let ts = expand(
+                        Ctx::Foreign,
+                        f.items.iter().map(ToTokens::to_token_stream).collect(),
+                        x86_64,
+                    );
+                    f.items = syn::parse2::<syn::ItemForeignMod>(quote!(extern "C" { #ts }))
+                        .unwrap()
+                        .items;
+                    f.into_token_stream()
                 }
-                ':' => skip_ident = true,
-                _ => {}
-            },
-            TokenTree::Literal(ref _l) => {}
+                _ => item,
+            });
         }
+        res
     }
-    (!self_arg, res)
-}
-
-#[proc_macro_attribute]
-pub fn api_weak(_ts: TokenStream, body: TokenStream) -> TokenStream {
-    let mut original_body = body.clone();
-    let mut iter = body.into_iter();
-    let mut versions = None;
-    let mut tokens: Vec<TokenTree> = Vec::new();
-    let mut vars: Vec<(Versions, String, String)> = Vec::new(); // Version, Name, Type
-    while let Some(t) = iter.next() {
-        match t {
-            // extern "C" {
-            TokenTree::Group(ref p) if p.delimiter() == Delimiter::Brace => {
-                let mut group = p.stream().into_iter();
-                while let Some(t) = group.next() {
-                    match t {
-                        TokenTree::Punct(ref p) if p.as_char() == ':' => {
-                            if let Some(version) = versions.take() {
-                                let var_name = tokens.last().unwrap().to_string();
-                                let _t = group.next().unwrap(); // &
-                                let _t = group.next().unwrap(); // '
-                                if let TokenTree::Ident(ident) = group.next().unwrap() {
-                                    assert_eq!(ident.to_string(), "static");
-                                    let mut ty = Vec::new();
-                                    while let Some(t) = group.next() {
-                                        match t {
-                                            TokenTree::Punct(ref p) if p.as_char() == ';' => break,
-                                            t => ty.push(t),
-                                        }
-                                    }
-                                    let ty = if ty.len() == 1 {
-                                        ty[0].to_string()
-                                    } else {
-                                        TokenStream::from_iter(ty).to_string()
-                                    };
-                                    vars.push((version, var_name, ty));
-                                }
-                            }
-                        }
-                        TokenTree::Punct(ref p) if p.as_char() == ';' => {
-                            tokens.clear();
-                            versions = None;
-                        }
-                        TokenTree::Group(ref p) if p.delimiter() == Delimiter::Parenthesis => {
-                            if let Some(version) = versions.take() {
-                                let mut ty: Vec<TokenTree> =
-                                    TokenStream::from_str("extern \"C\" fn ")
-                                        .unwrap()
-                                        .into_iter()
-                                        .collect();
-                                let name = tokens.pop().unwrap();
-                                let var_name = name.to_string();
-                                // ty.push(name);
-                                ty.push(t.clone());
-                                while let Some(t) = group.next() {
-                                    match t {
-                                        TokenTree::Punct(ref p) if p.as_char() == ';' => break,
-                                        t => ty.push(t),
-                                    }
-                                }
-                                let ty = if ty.len() == 1 {
-                                    ty[0].to_string()
-                                } else {
-                                    TokenStream::from_iter(ty).to_string()
-                                };
-
-                                // println!("ty: {ty:?}");
-
-                                vars.push((version, var_name, ty));
-                            }
-                        }
-                        TokenTree::Group(ref p) if p.delimiter() == Delimiter::Bracket => {
-                            let mut attr = p.stream().into_iter();
-                            while let Some(ref ident) = attr.next() {
-                                match ident {
-                                    // TokenTree::Group(_) => todo!(),
-                                    TokenTree::Ident(i) => {
-                                        let st = i.to_string();
-                                        match st.as_str() {
-                                            // api::available
-                                            "api" => {
-                                                attr.next();
-                                                attr.next();
-                                                continue;
-                                            }
-                                            // objc::available
-                                            "objc" => {
-                                                attr.next();
-                                                attr.next();
-                                                continue;
-                                            }
-                                            // direct available
-                                            "available" => {
-                                                if let Some(TokenTree::Group(g)) = attr.next() {
-                                                    versions =
-                                                        Some(Versions::from_stream(g.stream()));
-                                                    // println!("features {features:?}");
-                                                } else {
-                                                    break;
-                                                }
-                                                break;
-                                            }
-                                            _ => break,
-                                        }
-                                    }
-                                    _ => break, // TokenTree::Punct(_) => todo!(),
-                                                // TokenTree::Literal(_) => todo!(),
-                                }
-                            }
 
-                            // println!("found {p:?}")
-                        }
-                        _ => {}
+    /// Expands `tests/expand/{name}.rs` and compares it with `tests/expand/{name}.expanded`,
+    /// `CIDRE_MACROS_BLESS=1 cargo test` overwrites snapshots.
+    fn assert_expansion(name: &str, x86_64: bool) -> String {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/expand");
+        let src = std::fs::read_to_string(format!("{dir}/{name}.rs")).unwrap();
+        let items = syn::parse_file(&src).unwrap().into_token_stream();
+        let file = syn::parse2(expand(Ctx::File, items, x86_64)).unwrap();
+        let expanded = prettyplease::unparse(&file);
+
+        let suffix = if x86_64 { ".x86_64" } else { "" };
+        let path = format!("{dir}/{name}{suffix}.expanded");
+        if std::env::var_os("CIDRE_MACROS_BLESS").is_some() {
+            std::fs::write(&path, &expanded).unwrap();
+            return expanded;
+        }
+        let expected = std::fs::read_to_string(&path)
+            .unwrap_or_else(|_| panic!("missing {path}, run with CIDRE_MACROS_BLESS=1"));
+        assert_eq!(expected, expanded, "{name}{suffix}");
+        expanded
+    }
+
+    #[test]
+    fn msg_send_expansion() {
+        assert_expansion("msg_send", false);
+        assert_expansion("msg_send", true);
+    }
+
+    /// Evaluates `cfg` predicate like `any(all(target_os = "macos", not(feature = "macos_14_0")))`.
+    fn cfg_enabled(pred: &str, target_os: &str, features: &[&str]) -> bool {
+        fn eval<'a>(s: &'a str, os: &str, features: &[&str]) -> (bool, &'a str) {
+            let s = s.trim_start();
+            if let Some((op, rest)) = s.split_once('(') {
+                if matches!(op, "any" | "all" | "not") {
+                    let mut rest = rest;
+                    let mut values = vec![];
+                    while !rest.trim_start().starts_with(')') {
+                        let (v, r) = eval(rest, os, features);
+                        values.push(v);
+                        rest = r.trim_start().trim_start_matches(',');
                     }
-                    tokens.push(t);
-                    // println!("t: {t:?}")
+                    let rest = &rest.trim_start()[1..];
+                    let v = match op {
+                        "any" => values.iter().any(|v| *v),
+                        "all" => values.iter().all(|v| *v),
+                        _ => !values[0],
+                    };
+                    return (v, rest);
                 }
             }
-            _x => {
-                // println!("x: {x:?}")
+            let end = s.find([',', ')']).unwrap_or(s.len());
+            let (key, value) = s[..end].split_once('=').unwrap();
+            let value = value.trim().trim_matches('"');
+            let v = match key.trim() {
+                "target_os" => value == os,
+                "feature" => features.contains(&value),
+                _ => false,
+            };
+            (v, &s[end..])
+        }
+        eval(pred, target_os, features).0
+    }
+
+    /// Signatures of fns that survive `#[cfg]` for `target_os` and `features`.
+    fn enabled_fns(expanded: &str, target_os: &str, features: &[&str]) -> Vec<String> {
+        let mut attr = String::new();
+        let mut cfgs = vec![];
+        let mut res = vec![];
+        for line in expanded.lines().map(str::trim) {
+            if !attr.is_empty() || line.starts_with("#[") {
+                attr.push_str(line);
+                if attr.ends_with(']') {
+                    if let Some(pred) = attr.strip_prefix("#[cfg(") {
+                        cfgs.push(pred.trim_end_matches(")]").to_string());
+                    }
+                    attr.clear();
+                }
+            } else if line.starts_with("///") {
+                continue;
+            } else if line.contains("fn ") && line.ends_with('{') {
+                if cfgs.iter().all(|c| cfg_enabled(c, target_os, features)) {
+                    res.push(line.trim_end_matches('{').trim().to_string());
+                }
+                cfgs.clear();
+            } else {
+                cfgs.clear();
             }
         }
+        res
     }
-    // println!("{vars:?}");
-    let vars = vars
-        .iter()
-        .map(|(version, name, ty)| {
-            let upper_name = upper_case(name);
-            let availability = version.unavailable_cfg();
-            format!(
-            "{availability}\nstatic {upper_name}: api::DlSym<{ty}> = api::DlSym::new(c\"{name}\");"
-        )
-        })
-        .collect::<Vec<_>>()
-        .join("\n");
-    let stream = TokenStream::from_str(&vars).unwrap();
-    original_body.extend(stream);
-    original_body
-}
-
-#[derive(Default, Debug, Copy, Clone)]
-struct Version(u32, u32);
-
-impl Version {
-    fn from_str(str: &str) -> Option<Self> {
-        if let Some((major, minor)) = str.split_once('.') {
-            Some(Self(str::parse(major).unwrap(), str::parse(minor).unwrap()))
-        } else if let Some((major, minor)) = str.split_once('_') {
-            Some(Self(str::parse(major).unwrap(), str::parse(minor).unwrap()))
-        } else {
-            None
-        }
-    }
-}
-
-#[derive(Default, Debug)]
-struct Versions {
-    macos: Option<Version>,
-    ios: Option<Version>,
-    tvos: Option<Version>,
-    watchos: Option<Version>,
-    visionos: Option<Version>,
-    maccatalyst: Option<Version>,
-}
-
-impl Versions {
-    fn any(&self) -> bool {
-        self.macos.is_some()
-            || self.ios.is_some()
-            || self.tvos.is_some()
-            || self.watchos.is_some()
-            || self.visionos.is_some()
-            || self.maccatalyst.is_some()
-    }
-
-    fn available_cfg_ts(&self) -> TokenStream {
-        TokenStream::from_str(&self.available_cfg()).unwrap()
-    }
-
-    fn available_cfg(&self) -> String {
-        let mut vec = Vec::with_capacity(6);
-        if let Some(v) = self.macos {
-            vec.push(format!(
-                "all(target_os=\"macos\", feature=\"macos_{}_{}\")",
-                v.0, v.1
-            ));
-        }
-        if let Some(v) = self.ios {
-            vec.push(format!(
-                "all(target_os=\"ios\", feature=\"ios_{}_{}\")",
-                v.0, v.1
-            ));
-        }
-        if let Some(v) = self.tvos {
-            vec.push(format!(
-                "all(target_os=\"tvos\", feature=\"tvos_{}_{}\")",
-                v.0, v.1,
-            ));
-        }
-        if let Some(v) = self.watchos {
-            vec.push(format!(
-                "all(target_os=\"watchos\", feature=\"watchos_{}_{}\")",
-                v.0, v.1,
-            ));
-        }
-        if let Some(v) = self.visionos {
-            vec.push(format!(
-                "all(target_os=\"visionos\", feature=\"visionos_{}_{}\")",
-                v.0, v.1
-            ));
-        }
-        if let Some(v) = self.maccatalyst {
-            vec.push(format!(
-                "all(target_os=\"ios\", target_abi=\"macabi\", feature=\"maccatalyst_{}_{}\")",
-                v.0, v.1
-            ));
-        }
-
-        match vec.len() {
-            0 => String::new(),
-            1 => format!("#[cfg({})]\n", vec[0]),
-            _ => format!("#[cfg(any({}))]\n", vec.join(", ")),
-        }
-    }
-    fn unavailable_cfg_ts(&self) -> TokenStream {
-        TokenStream::from_str(&self.unavailable_cfg()).unwrap()
-    }
-
-    fn unavailable_cfg(&self) -> String {
-        let mut vec = Vec::with_capacity(6);
-        if let Some(v) = self.macos {
-            vec.push(format!(
-                "all(target_os=\"macos\", not(feature=\"macos_{}_{}\"))",
-                v.0, v.1
-            ));
-        }
-        if let Some(v) = self.ios {
-            vec.push(format!(
-                "all(target_os=\"ios\", not(feature=\"ios_{}_{}\"))",
-                v.0, v.1
-            ));
-        }
-        if let Some(v) = self.tvos {
-            vec.push(format!(
-                "all(target_os=\"tvos\", not(feature=\"tvos_{}_{}\"))",
-                v.0, v.1
-            ));
-        }
-        if let Some(v) = self.watchos {
-            vec.push(format!(
-                "all(target_os=\"watchos\", not(feature=\"watchos_{}_{}\"))",
-                v.0, v.1
-            ));
-        }
-        if let Some(v) = self.visionos {
-            vec.push(format!(
-                "all(target_os=\"visionos\", not(feature=\"visionos_{}_{}\"))",
-                v.0, v.1
-            ));
-        }
-        if let Some(v) = self.maccatalyst {
-            vec.push(format!(
-                "all(target_os=\"ios\", target_abi=\"macabi\", not(feature=\"maccatalyst_{}_{}\"))",
-                v.0, v.1
-            ));
-        }
-
-        match vec.len() {
-            0 => String::new(),
-            1 => format!("#[cfg({})]\n", vec[0]),
-            _ => format!("#[cfg(any({}))]\n", vec.join(", ")),
-        }
-    }
-    fn available_doc_ts(&self) -> TokenStream {
-        TokenStream::from_str(&self.available_doc()).unwrap()
-    }
-
-    fn available_doc(&self) -> String {
-        let mut vec = Vec::with_capacity(6);
-        if let Some(v) = self.macos {
-            vec.push(format!("macos_{}_{}", v.0, v.1));
-        }
-        if let Some(v) = self.ios {
-            vec.push(format!("ios_{}_{}", v.0, v.1));
-        }
-        if let Some(v) = self.tvos {
-            vec.push(format!("tvos_{}_{}", v.0, v.1,));
-        }
-        if let Some(v) = self.watchos {
-            vec.push(format!("watchos_{}_{}", v.0, v.1));
-        }
-        if let Some(v) = self.visionos {
-            vec.push(format!("visionos_{}_{}", v.0, v.1));
-        }
-        if let Some(v) = self.maccatalyst {
-            vec.push(format!("maccatalyst_{}_{}", v.0, v.1));
-        }
-
-        match vec.len() {
-            0 => String::new(),
-            1 => format!("/// # Availability\n/// {0}", vec[0]),
-            _ => format!("/// # Availability\n/// {0}", vec.join(", ")),
-        }
-    }
-    fn unavailable_doc_ts(&self) -> TokenStream {
-        TokenStream::from_str(&self.unavailable_doc()).unwrap()
-    }
-
-    fn unavailable_doc(&self) -> String {
-        let mut vec = Vec::with_capacity(6);
-        if let Some(v) = self.macos {
-            vec.push(format!("macos_{}_{}", v.0, v.1));
-        }
-        if let Some(v) = self.ios {
-            vec.push(format!("ios_{}_{}", v.0, v.1));
-        }
-        if let Some(v) = self.tvos {
-            vec.push(format!("tvos_{}_{}", v.0, v.1,));
-        }
-        if let Some(v) = self.watchos {
-            vec.push(format!("watchos_{}_{}", v.0, v.1));
-        }
-        if let Some(v) = self.visionos {
-            vec.push(format!("visionos_{}_{}", v.0, v.1));
-        }
-        if let Some(v) = self.maccatalyst {
-            vec.push(format!("maccatalyst_{}_{}", v.0, v.1));
-        }
-
-        match vec.len() {
-            0 => String::new(),
-            1 => format!("/// # Availability\n/// Not {0}", vec[0]),
-            _ => format!("/// # Availability\n/// Not {0}", vec.join(", ")),
-        }
-    }
-
-    fn from_stream(versions: TokenStream) -> Self {
-        let mut iter = versions.into_iter();
-        let mut versions = Self::default();
-        while let Some(t) = iter.next() {
-            let target_os = match t {
-                TokenTree::Ident(ident) => ident.to_string(),
-                _ => panic!("Unexpected token {t:?}"),
-            };
-            let Some(TokenTree::Punct(ident)) = iter.next() else {
-                panic!("Expecting = ");
-            };
 
-            assert_eq!(ident, '=', "expecting =");
-
-            let Some(TokenTree::Literal(val)) = iter.next() else {
-                panic!("expecting version");
+    #[test]
+    fn msg_send_availability() {
+        for x86_64 in [false, true] {
+            let expanded = assert_expansion("msg_send_versions", x86_64);
+            let frame_fns = |os, features| {
+                let mut fns = enabled_fns(&expanded, os, features);
+                fns.retain(|f| f.contains("frame(&self)") || f.contains("sel_frame"));
+                fns
             };
+            assert_eq!(
+                frame_fns("macos", &["macos_14_0"]),
+                [
+                    "pub fn frame(&self) -> cg::Rect",
+                    "pub fn sel_frame() -> &'static objc::Sel",
+                    "pub fn try_frame(&self) -> Option<cg::Rect>",
+                ]
+            );
+            assert_eq!(
+                frame_fns("macos", &["macos_13_0"]),
+                [
+                    "pub fn sel_frame() -> &'static objc::Sel",
+                    "pub unsafe fn frame(&self) -> cg::Rect",
+                    "pub fn try_frame(&self) -> Option<cg::Rect>",
+                ]
+            );
+            // not available on tvos at all
+            assert_eq!(
+                frame_fns("tvos", &["tvos_18_0"]),
+                ["pub fn sel_frame() -> &'static objc::Sel"]
+            );
 
-            let v = Version::from_str(&val.to_string());
-            match target_os.as_str() {
-            "macos" => versions.macos = v,
-            "ios" => versions.ios = v,
-            "tvos" => versions.tvos = v,
-            "watchos" => versions.watchos = v,
-            "visionos" => versions.visionos = v,
-            "maccatalyst" => versions.maccatalyst = v,
-            t => panic!("Unsupported platform. Platform should be macos, ios, watchos, visionos or maccatalyst. Found {t:?}"),
-        };
-
-            if let Some(TokenTree::Punct(p)) = iter.next() {
-                assert_eq!(p, ',', "expect ,");
+            let with_rect = |features| {
+                let fns = enabled_fns(&expanded, "macos", features);
+                fns.into_iter()
+                    .filter(|f| f.contains("with_rect") && f.contains("unsafe fn"))
+                    .count()
             };
+            assert_eq!(with_rect(&["macos_14_0"]), 2);
+            assert_eq!(with_rect(&["macos_15_0"]), 0);
         }
-
-        versions
     }
 
-    fn from_doc_str(str: &str) -> Self {
-        let mut res = Self::default();
-        for str in str.split_whitespace() {
-            for str in str.split_terminator(',') {
-                if str.starts_with("macos_") {
-                    res.macos = Version::from_str(&str[6..]);
-                } else if str.starts_with("ios_") {
-                    res.ios = Version::from_str(&str[4..]);
-                } else if str.starts_with("tvos_") {
-                    res.tvos = Version::from_str(&str[5..]);
-                } else if str.starts_with("watchos_") {
-                    res.watchos = Version::from_str(&str[8..]);
-                } else if str.starts_with("visionos_") {
-                    res.visionos = Version::from_str(&str[8..]);
-                } else if str.starts_with("maccatalyst_") {
-                    res.maccatalyst = Version::from_str(&str[12..]);
-                }
-            }
-        }
-        res
+    #[test]
+    fn weak_try_fn_expansion() {
+        let expanded = assert_expansion("api_available", false);
+        let try_fns = |features| {
+            let mut fns = enabled_fns(&expanded, "macos", features);
+            fns.retain(|f| f.contains("try_"));
+            fns
+        };
+        let try_with = "pub fn try_with_cf_dictionary(dict: &cf::Dictionary) -> Option<Self>";
+        assert_eq!(try_fns(&["macos_13_0"]), [try_with]);
+        assert_eq!(try_fns(&["macos_14_0"]), [try_with]);
+        assert!(expanded.contains("CMTAG_MAKE_FROM_DICTIONARY.get_fn().map(|f| f(dict))"));
+        assert!(expanded.contains("CMTAG_COPY_DESCRIPTION.get_fn().map(|f| f(allocator, *self))"));
     }
-}
 
-#[proc_macro_attribute]
-pub fn api_available(versions: TokenStream, body: TokenStream) -> TokenStream {
-    let versions = Versions::from_stream(versions);
-    let available = versions.available_cfg_ts();
-    let available_doc = versions.available_doc_ts();
-    let unavailable = versions.unavailable_cfg_ts();
-    let unavailable_doc = versions.unavailable_doc_ts();
-    if available.is_empty() {
-        return body;
+    #[test]
+    fn api_weak_expansion() {
+        assert_expansion("api_weak", false);
     }
 
-    let mut no_args = false;
-    let mut no_body = false;
-
-    let mut available = Some(available);
-    let mut available_doc = Some(available_doc);
-    let mut unavailable = Some(unavailable);
-    let mut unavailable_doc = Some(unavailable_doc);
-    let mut res = Vec::new();
-    let mut maybe_res: Vec<TokenTree> = Vec::new();
-    let mut fn_index = 0usize;
-    let mut unsafe_already = false;
-
-    let mut body_iter = body.into_iter();
-
-    while let Some(t) = body_iter.next() {
-        if available.is_some() {
-            res.extend(available.take().unwrap());
-        }
-        if unavailable.is_some() {
-            maybe_res.extend(unavailable.take().unwrap());
-        }
-        if available_doc.is_some() {
-            match t {
-                TokenTree::Ident(ref _i) => {
-                    let doc = available_doc.take().unwrap();
-                    res.extend(doc);
-                }
-                _ => {}
-            }
-        }
-        if unavailable_doc.is_some() {
-            match t {
-                TokenTree::Ident(ref _i) => {
-                    let doc = unavailable_doc.take().unwrap();
-                    maybe_res.extend(doc);
-                }
-                _ => {}
-            }
-        }
-        match t {
-            TokenTree::Punct(ref p) if p.as_char() == ';' => {
-                no_body = true;
-            }
-            TokenTree::Ident(ref ident) => match ident.to_string().as_str() {
-                "fn" => {
-                    fn_index = maybe_res.len();
-                }
-                "unsafe" => {
-                    unsafe_already = true;
-                }
-                "define_cls" => {
-                    let token = TokenTree::Ident(Ident::new("define_weak_cls", t.span()));
-                    maybe_res.push(token);
-                    res.push(t.clone());
-                    while let Some(t) = body_iter.next() {
-                        maybe_res.push(t.clone());
-                        res.push(t);
-                    }
-                    break;
-                }
-                "define_cls_init" => {
-                    let token = TokenTree::Ident(Ident::new("define_weak_cls_init", t.span()));
-                    maybe_res.push(token);
-                    res.push(t.clone());
-                    while let Some(t) = body_iter.next() {
-                        maybe_res.push(t.clone());
-                        res.push(t);
-                    }
-                    break;
-                }
-                _ => {}
-            },
-            _ => {}
-        }
-
-        maybe_res.push(t.clone());
-
-        if let TokenTree::Group(ref g) = t {
-            // function without args ()
-            if g.delimiter() == Delimiter::Parenthesis {
-                no_args = g.stream().is_empty();
-                // we are in function.
-            }
-            // function body {}
-            if g.delimiter() == Delimiter::Brace {
-                if no_args && try_replace_return(&mut maybe_res) {}
-                let mut make_result_optional = false;
-                if try_replace_fn(&mut maybe_res, &mut make_result_optional) {
-                    if make_result_optional {
-                        let mut i = maybe_res.len() - 2;
+    #[test]
+    fn protocol_expansion() {
+        assert_expansion("protocol", false);
+        assert_expansion("add_methods", false);
+    }
 
-                        while i > 0 {
-                            if let TokenTree::Punct(ref p) = maybe_res[i] {
-                                if p.as_char() == '-' && p.spacing() == Spacing::Joint {
-                                    i += 2;
-                                    break;
-                                }
-                            }
-                            i -= 1;
-                        }
+    #[test]
+    fn class_expansion() {
+        assert_expansion("objc_class", false);
+    }
 
-                        maybe_res.insert(i, TokenTree::Punct(Punct::new('<', Spacing::Alone)));
-                        maybe_res
-                            .insert(i, TokenTree::Ident(Ident::new("Option", Span::call_site())));
-                        maybe_res.insert(
-                            maybe_res.len() - 2,
-                            TokenTree::Punct(Punct::new('>', Spacing::Alone)),
-                        );
+    /// Tokens separated by spaces, punct spacing and string literal form are ignored.
+    fn token_str(ts: TokenStream) -> String {
+        fn push(ts: TokenStream, res: &mut Vec<String>) {
+            for tt in ts {
+                match tt {
+                    TokenTree::Group(g) => {
+                        let (open, close) = match g.delimiter() {
+                            Delimiter::Parenthesis => ("(", ")"),
+                            Delimiter::Brace => ("{", "}"),
+                            Delimiter::Bracket => ("[", "]"),
+                            Delimiter::None => ("", ""),
+                        };
+                        res.push(open.to_string());
+                        push(g.stream(), res);
+                        res.push(close.to_string());
                     }
-                } else {
-                    if !unsafe_already {
-                        maybe_res.insert(
-                            fn_index,
-                            TokenTree::Ident(Ident::new("unsafe", Span::call_site())),
-                        );
+                    TokenTree::Punct(p) => res.push(p.as_char().to_string()),
+                    TokenTree::Ident(i) => res.push(i.to_string()),
+                    TokenTree::Literal(l) => {
+                        res.push(match syn::parse_str::<syn::LitStr>(&l.to_string()) {
+                            Ok(s) => format!("{:?}", s.value()),
+                            Err(_) => l.to_string(),
+                        })
                     }
                 }
             }
         }
-
-        res.push(t);
-    }
-    if !no_body {
-        res.extend(maybe_res);
+        let mut res = vec![];
+        push(ts, &mut res);
+        res.join(" ")
     }
 
-    TokenStream::from_iter(res)
-}
-
-fn try_replace_fn(tokens: &mut Vec<TokenTree>, make_result_optional: &mut bool) -> bool {
-    let Some(TokenTree::Group(ref g)) = tokens.last() else {
-        return false;
-    };
-    if g.delimiter() != Delimiter::Brace {
-        return false;
+    /// Compares expansion with `tests/expand/{name}.expanded` recorded by the string based
+    /// implementation, token by token as spacing of its output is different.
+    fn assert_baseline(name: &str, expanded: TokenStream) {
+        let path = format!(
+            "{}/tests/expand/{name}.expanded",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let expected = std::fs::read_to_string(&path).unwrap().parse().unwrap();
+        assert_eq!(token_str(expected), token_str(expanded), "{name}");
     }
-    // check fn body. it should be function call.
-    let mut body_stream = g.stream().into_iter();
-
-    match body_stream.next().unwrap() {
-        TokenTree::Group(scope) => {
-            if scope.delimiter() == Delimiter::Brace {
-                *make_result_optional = true;
-                return true;
-            }
-        }
-        TokenTree::Ident(ident) => match ident.to_string().as_str() {
-            "unsafe" => {
-                let Some(TokenTree::Group(g)) = body_stream.next() else {
-                    return false;
-                };
-                if g.delimiter() != Delimiter::Brace {
-                    return false;
-                }
-                let mut block = g.stream().into_iter();
-                let Some(TokenTree::Ident(ident)) = block.next() else {
-                    return false;
-                };
-                let Some(TokenTree::Group(args)) = block.next() else {
-                    return false;
-                };
-                if args.delimiter() != Delimiter::Parenthesis {
-                    return false;
-                }
-                if block.next().is_none() {
-                    tokens.pop();
-                    let var = upper_case(&ident.to_string());
-                    let stream = TokenStream::from_str(&format!(
-                        "{{ unsafe {{ {var}.get_fn().unwrap(){} }} }}",
-                        args.to_string()
-                    ))
-                    .unwrap();
-                    tokens.extend(stream);
-
-                    return true;
-                }
-            }
-            "Self" => {
-                // `Self::alloc()`
-
-                let mut new_body = Vec::new();
-                new_body.push(TokenTree::Ident(ident));
-                let Some(TokenTree::Punct(p)) = body_stream.next() else {
-                    return false;
-                };
-
-                if p.as_char() != ':' {
-                    return false;
-                }
-                new_body.push(TokenTree::Punct(p));
-                let Some(TokenTree::Punct(p)) = body_stream.next() else {
-                    return false;
-                };
-
-                if p.as_char() != ':' {
-                    return false;
-                }
-                new_body.push(TokenTree::Punct(p));
-                let Some(TokenTree::Ident(ident)) = body_stream.next() else {
-                    return false;
-                };
-
-                if ident.to_string() != "alloc" {
-                    return false;
-                };
-                new_body.push(TokenTree::Ident(ident));
 
-                let Some(TokenTree::Group(g)) = body_stream.next() else {
-                    return false;
-                };
-
-                if g.delimiter() != Delimiter::Parenthesis {
-                    return false;
-                }
-                new_body.push(TokenTree::Group(g));
+    #[test]
+    fn baseline_msg_send_expansion() {
+        let msg_send = |sel, item, x86_64| run("msg_send", sel, item, x86_64);
+        let count = quote!(
+            pub fn foo(&self) -> usize;
+        );
+        assert_baseline(
+            "msg_send_count",
+            msg_send(quote!(count), count.clone(), false),
+        );
+        assert_baseline(
+            "msg_send_count_x86_64",
+            msg_send(quote!(count), count, true),
+        );
 
-                // Some(Self::alloc()?.)
-                new_body.push(TokenTree::Punct(Punct::new('?', Spacing::Alone)));
-                while let Some(t) = body_stream.next() {
-                    new_body.push(t)
-                }
-                let s = TokenStream::from_iter(new_body.drain(..));
-                new_body.push(TokenTree::Ident(Ident::new("Some", Span::call_site())));
-                let g = TokenTree::Group(Group::new(Delimiter::Parenthesis, s));
-                new_body.push(g);
-                let s = TokenStream::from_iter(new_body.drain(..));
-                let g = TokenTree::Group(Group::new(Delimiter::Brace, s));
+        let obj_at = quote!(
+            pub fn foo(&self, index: usize) -> &T;
+        );
+        let sel = quote!(objectAtIndex:);
+        assert_baseline(
+            "msg_send_args",
+            msg_send(sel.clone(), obj_at.clone(), false),
+        );
+        assert_baseline("msg_send_args_x86_64", msg_send(sel, obj_at, true));
 
-                tokens.pop();
-                tokens.push(g);
+        let new = quote!(
+            pub fn foo() -> arc::R<Self>;
+        );
+        assert_baseline("msg_send_class", msg_send(quote!(new), new.clone(), false));
+        assert_baseline("msg_send_class_x86_64", msg_send(quote!(new), new, true));
 
-                *make_result_optional = true;
-                return true;
-                // println!("found");
-            }
-            _ => return false,
-        },
+        let string = quote!(
+            pub fn foo(format: &ns::String) -> Option<arc::R<Self>>;
+        );
+        let sel = quote!(stringWithFormat:);
+        assert_baseline("msg_send_rar", msg_send(sel, string, false));
 
-        _ => return false,
-    };
-    return false;
-}
+        let frame = quote! {
+            #[objc::msg_send(frame)]
+            #[objc::available(macos = 14.0, ios = 17.0)]
+            pub fn foo(&self) -> cg::Rect;
+        };
+        assert_baseline(
+            "msg_send_available",
+            expand(Ctx::Impl, frame.clone(), false),
+        );
+        assert_baseline("msg_send_available_x86_64", expand(Ctx::Impl, frame, true));
 
-fn try_replace_return(tokens: &mut Vec<TokenTree>) -> bool {
-    let mut idx = tokens.len() - 1;
-    let mut has_static = false;
-    // going reverse till `&'static` or ()
-    while idx > 0 {
-        match &tokens[idx] {
-            TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis => return false,
-            TokenTree::Ident(i) if i.to_string() == "static" => has_static = true,
-            TokenTree::Punct(p) if p.as_char() == '\'' => break,
-            // TokenTree::Literal(_) => todo!(),
-            _ => {}
-        }
-        idx -= 1;
+        let new = quote! {
+            #[objc::msg_send(valueWithRect:)]
+            #[api::available(macos = 15.0)]
+            pub fn foo(frame: cg::Rect) -> arc::R<Self>;
+        };
+        assert_baseline("msg_send_available_try", expand(Ctx::Impl, new, false));
     }
 
-    if !has_static {
-        return false;
+    #[test]
+    fn baseline_weak_try_fn_expansion() {
+        let value: crate::sig::FnItem = syn::parse2(quote!(
+            pub fn value(&self) -> i64 {
+                unsafe { CMTagGetValue(*self) }
+            }
+        ))
+        .unwrap();
+        let versions = syn::parse2(quote!(macos = 14.0)).unwrap();
+        let try_fn = crate::available::WeakTryFn::new(&value).unwrap();
+        assert_baseline("weak_try_fn", try_fn.expand(&versions));
     }
 
-    let mut body_stream = {
-        let Some(TokenTree::Group(ref g)) = tokens.last() else {
-            return false;
-        };
+    #[test]
+    fn baseline_class_expansion() {
+        let view = syn::parse2(quote! {
+            impl MyView {
+                #[objc::method(drawRect:)]
+                fn draw_rect(&mut self, rect: cg::Rect) {}
 
-        // check fn body. it should return single var, no function call.
-        g.stream().into_iter()
-    };
+                #[cfg(feature = "ca")]
+                #[objc::class_method(layerClass)]
+                fn layer_cls() -> &'static objc::Class<ca::Layer> {}
 
-    // check for
-    // unsafe { VAR }
-    // or
-    // unsafe { fn_call(args) }
+                #[objc::dealloc]
+                fn cleanup(&mut self) {}
 
-    let var = match body_stream.next().unwrap() {
-        TokenTree::Ident(ident) => match ident.to_string().as_str() {
-            "unsafe" => {
-                let TokenTree::Group(g) = body_stream.next().unwrap() else {
-                    return false;
-                };
-                if g.delimiter() != Delimiter::Brace {
-                    return false;
-                }
-                let mut block = g.stream().into_iter();
-                let TokenTree::Ident(ident) = block.next().unwrap() else {
-                    return false;
-                };
-                if let Some(TokenTree::Group(_g)) = block.next() {
-                    return false;
-                };
-                ident.to_string()
+                #[objc::msg_send_super(drawRect:)]
+                fn super_draw_rect(&mut self, rect: cg::Rect);
             }
-            x => x.to_string(),
-        },
-
-        _ => return false,
-    };
-
-    idx -= 1; // &
-    tokens.pop(); // {}
-    let stream = TokenStream::from_iter(tokens.drain(idx..));
-    let ty = stream.to_string();
-    let stream = TokenStream::from_str(&format!("Option<{ty}>")).unwrap();
-    tokens.extend(stream);
-    let var = upper_case(&var.to_string());
-    let stream = TokenStream::from_str(&format!("{{ unsafe {{ {var}.get_var() }} }}")).unwrap();
-    tokens.extend(stream);
-    true
-}
+        })
+        .unwrap();
+        let args = quote! {
+            super = ns::View, ivars = State, protocols = [ns::AppDelegateImpl], name = CidreMyView
+        };
+        let items = quote!(
+            fn draw_rect(&mut self, rect: cg::Rect) {
+                self.super_draw_rect(rect)
+            }
+        );
+        let expanded = crate::class::gen_class_items(args, view, items).unwrap();
+        assert_baseline("class", expanded);
 
-// Super simple, but stable upper_case impl
-fn upper_case(str: &str) -> String {
-    let len = str.len();
-    let mut res = Vec::<u8>::with_capacity(len + 10);
-    let bytes = str.as_bytes();
-    let mut was_lowercase = false;
-    for ch in bytes {
-        let is_upper = ch.is_ascii_uppercase();
-        if was_lowercase && is_upper {
-            res.push(b'_');
-        }
-        res.push(ch.to_ascii_uppercase());
-        was_lowercase = !is_upper;
+        let plain = syn::parse2(quote!(impl Plain {})).unwrap();
+        let expanded = crate::class::gen_class_items(TokenStream::new(), plain, TokenStream::new());
+        assert_baseline("class_plain", expanded.unwrap());
     }
 
-    String::from_utf8(res).unwrap()
+    #[test]
+    fn generics_expansion() {
+        let expanded = assert_expansion("generics", false);
+        assert!(expanded.contains("pub fn get<'a>(&'a self, key: &K) -> Option<&'a V>"));
+        // `#[cfg]` of protocol method guards its registration too
+        let cfgs = expanded.matches("#[cfg(feature = \"cm\")]").count();
+        assert_eq!(cfgs, 6);
+    }
 }
-
-// fn is_upper_case(str: &str) -> bool {
-//     let bytes = str.as_bytes();
-//     for ch in bytes {
-//         if ch != &b'_' {
-//             if !ch.is_ascii_uppercase() {
-//                 return false;
-//             }
-//         }
-//     }
-//     true
-// }
@@ -0,0 +1,417 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    visit_mut::{self, VisitMut},
+    Error, PathArguments, Result, ReturnType, Type,
+};
+
+use crate::{
+    attr::{self, doc_str, Attr},
+    available::Versions,
+    sig::{self, c_str, FnBody, FnItem, Params},
+};
+
+pub(crate) fn gen_msg_send(
+    sel: TokenStream,
+    func: TokenStream,
+    x86_64: bool,
+) -> Result<TokenStream> {
+    let (sel, sel_args_count) = attr::selector(sel, "#[objc::msg_send(objectAtIndex:)]")?;
+    let item = FnItem::parse_for("#[objc::msg_send]", func)?;
+    MsgSend::new(sel, sel_args_count, item)?.expand(x86_64)
+}
+
+/// Parsed `#[objc::msg_send]` function.
+struct MsgSend {
+    sel: String,
+    /// Function without `available` attributes.
+    item: FnItem,
+    versions: Versions,
+    optional_already: bool,
+}
+
+impl MsgSend {
+    fn new(sel: String, sel_args_count: usize, mut item: FnItem) -> Result<Self> {
+        let mut versions = Versions::default();
+        let mut optional_already = false;
+        let mut attrs = Vec::with_capacity(item.attrs.len());
+        let mut iter = std::mem::take(&mut item.attrs).into_iter();
+        while let Some(a) = iter.next() {
+            match Attr::parse(&a)? {
+                Some(Attr::Optional) => optional_already = true,
+                Some(Attr::ApiAvailable(v)) => {
+                    versions = v;
+                    continue;
+                }
+                Some(Attr::DocAvailable) => {
+                    const EXPECTED: &str =
+                        "expected `/// macos_x_y, ios_x_y` after `/// # Availability`";
+                    let span = a.bracket_token.span.join();
+                    let Some(next) = iter.next() else {
+                        return Err(Error::new(span, EXPECTED));
+                    };
+                    let Some(doc) = doc_str(&next) else {
+                        return Err(Error::new(span, EXPECTED));
+                    };
+                    versions = Versions::from_doc_str(&doc);
+                    attrs.push(a);
+                    attrs.push(next);
+                    continue;
+                }
+                Some(Attr::MsgSend(_)) => {
+                    return Err(Error::new(
+                        a.bracket_token.span.join(),
+                        "only one msg_send is allowed",
+                    ));
+                }
+                Some(Attr::Property) | None => {}
+            }
+            attrs.push(a);
+        }
+        item.attrs = attrs;
+
+        if let FnBody::Block(body) = &item.body {
+            return Err(Error::new(
+                body.span(),
+                "#[objc::msg_send] function must not have a body, expected `;`",
+            ));
+        }
+        sig::check_ret(&item.sig.output)?;
+        let params = Params::new(&item.sig)?;
+        let fn_args_count = params.args.len();
+        if sel_args_count != fn_args_count {
+            return Err(Error::new(
+                item.args_span(),
+                format!(
+                    "selector `{sel}` takes {sel_args_count} argument(s), but function `{}` has {fn_args_count}",
+                    item.name()
+                ),
+            ));
+        }
+
+        Ok(Self {
+            sel,
+            item,
+            versions,
+            optional_already,
+        })
+    }
+
+    /// Function declaration with `name` and `output`, `unsafe` if `unsafety`.
+    fn decl(&self, name: &Ident, output: &ReturnType, unsafety: bool) -> TokenStream {
+        let item = &self.item;
+        let attrs = &item.attrs;
+        let quals = item.quals(unsafety || item.is_unsafe());
+        let head = item.head();
+        let where_clause = &item.sig.generics.where_clause;
+        quote!(#(#attrs)* #quals fn #name #head #output #where_clause)
+    }
+
+    fn expand(&self, x86_64: bool) -> Result<TokenStream> {
+        let MsgSend {
+            sel,
+            item,
+            versions,
+            optional_already,
+        } = self;
+        let params = Params::new(&item.sig)?;
+        let fn_name = item.name();
+        let output = &item.sig.output;
+        let class = params.receiver.is_none();
+        let vars = Vec::from_iter(params.names());
+        let doc_alias = (fn_name != sel).then(|| quote!(#[doc(alias = #sel)]));
+        let option = is_option(output);
+        let rar_output = if sel.starts_with("new") || sel.starts_with("initWith") {
+            None
+        } else {
+            rar(output)
+        };
+        let (impl_fn_name, impl_ret) = match &rar_output {
+            Some(ret) => (format_ident!("{fn_name}_ar"), ret),
+            None => (fn_name.clone(), output),
+        };
+
+        let c_void = quote!(*const std::ffi::c_void);
+        let typed = Vec::from_iter(params.typed());
+        let (fn_args, call_args) = match params.receiver {
+            Some(r) if x86_64 => {
+                let ty = &r.ty;
+                (
+                    quote!(id: #ty, imp: #c_void #(, #typed)*),
+                    quote!(sig(self, x86_64_sel, #(#vars),*)),
+                )
+            }
+            None if x86_64 => (
+                quote!(cls: #c_void, imp: #c_void, #(#typed),*),
+                quote!(sig(Self::cls_ptr(), x86_64_sel, #(#vars),*)),
+            ),
+            Some(r) if vars.is_empty() => {
+                let ty = &r.ty;
+                (quote!(#ty), quote!(sig(self)))
+            }
+            Some(r) => {
+                let ty = &r.ty;
+                (
+                    quote!(id: #ty, imp: #c_void #(, #typed)*),
+                    quote!(sig(self, std::ptr::null(), #(#vars),*)),
+                )
+            }
+            None if vars.is_empty() => (quote!(cls: #c_void), quote!(sig(Self::cls_ptr()))),
+            None => (
+                quote!(cls: #c_void, imp: #c_void #(, #typed)*),
+                quote!(sig(Self::cls_ptr(), std::ptr::null(), #(#vars),*)),
+            ),
+        };
+
+        let sel_c = c_str(sel);
+        let sig_ty = quote!(extern "C" fn(#fn_args) #impl_ret);
+        let (body, unavailable_body) = if x86_64 {
+            // structs larger than 16 bytes are returned in memory on x86_64
+            // and must be sent with `objc_msgSend_stret`
+            let ret_ty = sig::ret_ty(impl_ret);
+            let body = quote! {
+                extern "C" {
+                    #[link_name = "objc_msgSend"]
+                    fn msg_send();
+                    #[link_name = "objc_msgSend_stret"]
+                    fn msg_send_stret();
+                }
+                extern "C-unwind" {
+                    fn sel_registerName(name: *const i8) -> *const std::ffi::c_void;
+                }
+
+                unsafe {
+                    let x86_64_sel = sel_registerName(#sel_c.as_ptr());
+                    let fn_ptr = if std::mem::size_of::<#ret_ty>() > 16 {
+                        msg_send_stret as *const std::ffi::c_void
+                    } else {
+                        msg_send as *const std::ffi::c_void
+                    };
+                    let sig: #sig_ty = std::mem::transmute(fn_ptr);
+
+                    #call_args
+                }
+            };
+            (body.clone(), body)
+        } else {
+            let link_name = format!("objc_msgSend${sel}");
+            let extern_block = quote! {
+                extern "C" {
+                    #[link_name = #link_name]
+                    fn msg_send();
+                }
+            };
+            let call = quote! {
+                let fn_ptr = msg_send as *const std::ffi::c_void;
+                let sig: #sig_ty = std::mem::transmute(fn_ptr);
+
+                #call_args
+            };
+            (
+                quote!(#extern_block unsafe { #call }),
+                quote!(#extern_block #call),
+            )
+        };
+
+        let available = versions.available_cfg();
+        let unavailable = versions.unavailable_cfg();
+        let any = versions.any();
+
+        let decl = self.decl(&impl_fn_name, impl_ret, false);
+        let mut flow = quote! {
+            #available
+            #doc_alias
+            #[inline]
+            #decl {
+                #body
+            }
+        };
+        if any {
+            if !optional_already {
+                flow.extend(sel_fn(fn_name, sel, true));
+            }
+            let decl = self.decl(&impl_fn_name, impl_ret, true);
+            flow.extend(quote! {
+                #unavailable
+                #doc_alias
+                #[inline]
+                #decl {
+                    #unavailable_body
+                }
+            });
+        }
+
+        if rar_output.is_some() {
+            let ar_name = format_ident!("{fn_name}_ar");
+            let ar_call = if class {
+                quote!(Self::#ar_name(#(#vars),*))
+            } else {
+                quote!(self.#ar_name(#(#vars),*))
+            };
+            let (retain, check_doc) = if option {
+                (quote!(rar_retain_option), "selector1")
+            } else {
+                (quote!(rar_retain), "selector")
+            };
+            let decl = self.decl(fn_name, output, false);
+            flow.extend(quote! {
+                #available
+                #doc_alias
+                #[inline]
+                #decl {
+                    arc::#retain(#ar_call)
+                }
+            });
+            if any {
+                let decl = self.decl(fn_name, output, true);
+                let doc = format!(" Check availability with {check_doc} `Self::sel_{fn_name}()`");
+                // doc goes after `#[inline]` for optional results
+                let (doc_before, doc_after) = if option {
+                    (None, Some(quote!(#[doc = #doc])))
+                } else {
+                    (Some(quote!(#[doc = #doc])), None)
+                };
+                flow.extend(quote! {
+                    #unavailable
+                    #doc_alias
+                    #doc_before
+                    #[inline]
+                    #doc_after
+                    #decl {
+                        arc::#retain(#ar_call)
+                    }
+                });
+            }
+        }
+        if any && !optional_already {
+            flow.extend(self.try_fn(&params, &available, &unavailable));
+        }
+        Ok(flow)
+    }
+
+    /// Safe `try_xxx` wrapper. Above deployment target it checks once that
+    /// the receiver responds to selector and caches the answer.
+    fn try_fn(
+        &self,
+        params: &Params,
+        available: &TokenStream,
+        unavailable: &TokenStream,
+    ) -> TokenStream {
+        let MsgSend { sel, item, .. } = self;
+        let fn_name = item.name();
+        let try_name = format_ident!("try_{fn_name}");
+        let ret_ty = sig::ret_ty(&item.sig.output);
+        let output: ReturnType = syn::parse_quote!(-> Option<#ret_ty>);
+        let decl = self.decl(&try_name, &output, false);
+        let vars = params.names();
+        let (call, receiver) = if params.receiver.is_none() {
+            (quote!(Self::#fn_name(#(#vars),*)), quote!(Self::cls_ptr()))
+        } else {
+            (
+                quote!(self.#fn_name(#(#vars),*)),
+                quote!((self as *const Self).cast()),
+            )
+        };
+        let some_call = if item.is_unsafe() {
+            quote!(unsafe { Some(#call) })
+        } else {
+            quote!(Some(#call))
+        };
+        let some_doc = format!(
+            " Safe variant of [`Self::{fn_name}`], always `Some` for current deployment target."
+        );
+        let check_doc = format!(" Calls [`Self::{fn_name}`] if receiver responds to `{sel}`.");
+        let sel_c = c_str(sel);
+        quote! {
+            #available
+            #[doc = #some_doc]
+            #[inline]
+            #decl {
+                #some_call
+            }
+
+            #unavailable
+            #[doc = #check_doc]
+            #[doc = " The check is done once and cached."]
+            #[inline]
+            #decl {
+                static SEL: objc::SelCheck = objc::SelCheck::new(#sel_c);
+                unsafe {
+                    if SEL.responds(#receiver) {
+                        Some(#call)
+                    } else {
+                        None
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `sel_xxx` getter, so caller can check if object responds to selector.
+pub(crate) fn sel_fn(fn_name: &Ident, sel: &str, public: bool) -> TokenStream {
+    let name = format_ident!("sel_{fn_name}");
+    let doc = format!(" `@selector({sel})` but dynamic");
+    let sel = c_str(sel);
+    let (vis, inline) = if public {
+        (Some(quote!(pub)), Some(quote!(#[inline])))
+    } else {
+        (None, None)
+    };
+    quote! {
+        #[doc = #doc]
+        #[doc = " use this function to check if object responds to selector"]
+        #inline
+        #vis fn #name() -> &'static objc::Sel {
+            unsafe { objc::sel_reg_name(#sel.as_ptr()) }
+        }
+    }
+}
+
+/// `Option<..>` result.
+fn is_option(output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
+    };
+    let Type::Path(path) = &**ty else {
+        return false;
+    };
+    path.qself.is_none()
+        && path.path.leading_colon.is_none()
+        && path.path.segments.first().is_some_and(|s| {
+            s.ident == "Option" && matches!(s.arguments, PathArguments::AngleBracketed(_))
+        })
+}
+
+/// Return type with the first `arc::R<T>` replaced with `arc::Rar<T>`, so
+/// `xxx_ar` variant can be retained with `objc_retainAutoreleasedReturnValue`.
+fn rar(output: &ReturnType) -> Option<ReturnType> {
+    struct Rar(bool);
+
+    impl VisitMut for Rar {
+        fn visit_path_mut(&mut self, path: &mut syn::Path) {
+            if !self.0 {
+                let mut segments = path.segments.iter_mut().peekable();
+                while let Some(seg) = segments.next() {
+                    let Some(next) = segments.peek_mut() else {
+                        break;
+                    };
+                    if seg.ident == "arc"
+                        && next.ident == "R"
+                        && matches!(next.arguments, PathArguments::AngleBracketed(_))
+                    {
+                        next.ident = Ident::new("Rar", next.ident.span());
+                        self.0 = true;
+                        break;
+                    }
+                }
+            }
+            visit_mut::visit_path_mut(self, path);
+        }
+    }
+
+    let mut output = output.clone();
+    let mut rar = Rar(false);
+    rar.visit_return_type_mut(&mut output);
+    rar.0.then_some(output)
+}
@@ -0,0 +1,368 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{ParseStream, Parser},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Error, FnArg, Ident, ImplItem, ItemImpl, ItemTrait, Pat, Result, ReturnType,
+    Signature, Token, TraitItem,
+};
+
+use crate::{
+    attr::{is_cfg, ns_name, Attr},
+    msg_send::sel_fn,
+    sig::{c_str, FnItem},
+};
+
+pub(crate) fn gen_optional(func: TokenStream) -> Result<TokenStream> {
+    const EXPECTED: &str = "#[objc::optional] expects #[objc::msg_send(...)] right after it";
+    let first_span = func
+        .clone()
+        .into_iter()
+        .next()
+        .map_or_else(Span::call_site, |tt| tt.span());
+    let attrs = (|input: ParseStream| {
+        let attrs = input.call(Attribute::parse_outer)?;
+        input.parse::<TokenStream>()?;
+        Ok(attrs)
+    })
+    .parse2(func.clone())?;
+    if attrs.is_empty() {
+        return Err(Error::new(first_span, EXPECTED));
+    }
+    // `#[cfg]`, docs and `#[objc::property]` may go in between
+    let mut attr = None;
+    for a in attrs.iter().filter(|a| ns_name(a).is_some()) {
+        attr = Attr::parse(a)?;
+        if !matches!(attr, Some(Attr::Property)) {
+            break;
+        }
+    }
+    let Some(Attr::MsgSend(sel)) = attr else {
+        return Err(Error::new(Span::call_site(), EXPECTED));
+    };
+    let item = FnItem::parse_for("#[objc::optional]", func.clone())?;
+
+    let mut func = func;
+    func.extend(sel_fn(item.name(), &sel, false));
+    Ok(func)
+}
+
+/// Shape of getter and setter is checked by `#[objc::protocol]` which sees both.
+pub(crate) fn gen_property(args: TokenStream, func: TokenStream) -> Result<TokenStream> {
+    if let Some(tt) = args.into_iter().next() {
+        return Err(Error::new(
+            tt.span(),
+            "#[objc::property] takes no arguments",
+        ));
+    }
+    Ok(func)
+}
+
+/// Method of `XxxImpl` trait, registered on the class when it is defined with `cls_add_methods`.
+struct ProtocolMethod {
+    name: Ident,
+    /// `#[cfg]` attributes of the method.
+    cfgs: Vec<Attribute>,
+    /// `+` method, registered on the metaclass.
+    class: bool,
+}
+
+/// `#[objc::property]` getter or setter.
+struct Accessor {
+    sel: String,
+    span: Span,
+    setter: bool,
+}
+
+pub(crate) fn gen_protocol(args: TokenStream, ts: TokenStream) -> Result<TokenStream> {
+    let error_msg = "#[objc::protocol] expects protocol name, e.g. `#[objc::protocol(NSCopying)]`";
+    let mut args = args.into_iter();
+    let protocol_name = match args.next() {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        Some(tt) => return Err(Error::new(tt.span(), error_msg)),
+        None => return Err(Error::new(Span::call_site(), error_msg)),
+    };
+    if let Some(tt) = args.next() {
+        return Err(Error::new(
+            tt.span(),
+            format!("unexpected `{tt}` after protocol name"),
+        ));
+    }
+    let mut tr: ItemTrait = syn::parse2(ts)
+        .map_err(|_| Error::new(Span::call_site(), "#[objc::protocol] expects trait"))?;
+    let trait_name = tr.ident.clone();
+
+    let mut impl_trait_functions = TokenStream::new();
+    let mut methods = vec![];
+    let mut accessors = vec![];
+    let mut has_cls_ptr = false;
+    let mut has_class_methods = false;
+
+    for item in &mut tr.items {
+        let TraitItem::Fn(f) = item else {
+            return Err(Error::new(
+                item.span(),
+                "#[objc::protocol] expects only functions",
+            ));
+        };
+        let mut is_optional = false;
+        let mut is_property = false;
+        let mut sel = None;
+        let mut cfgs = vec![];
+        for attr in &f.attrs {
+            match Attr::parse(attr)? {
+                Some(Attr::Optional) => is_optional = true,
+                Some(Attr::Property) => is_property = true,
+                Some(Attr::MsgSend(s)) => sel = Some(s),
+                Some(_) => {}
+                None if is_cfg(attr) => cfgs.push(attr.clone()),
+                None => {}
+            }
+        }
+        let fn_name = f.sig.ident.clone();
+        has_cls_ptr |= fn_name == "cls_ptr";
+
+        let Some(sel) = &sel else {
+            if is_property {
+                return Err(Error::new(
+                    fn_name.span(),
+                    "#[objc::property] expects #[objc::msg_send(...)]",
+                ));
+            }
+            // plain Rust method, stays in protocol trait only
+            continue;
+        };
+        if let Some(param) = f.sig.generics.type_params().next() {
+            return Err(Error::new(param.span(), "objc methods can't be generic"));
+        }
+        if is_property {
+            accessors.push(accessor(&f.sig, sel)?);
+        }
+
+        let mut inputs = f.sig.inputs.clone();
+        // objc passes selector after receiver
+        let class = match inputs.first() {
+            Some(FnArg::Receiver(r)) => {
+                if r.reference.is_some() {
+                    inputs.insert(1, syn::parse_quote!(_cmd: Option<&objc::Sel>));
+                }
+                false
+            }
+            _ => {
+                inputs.insert(0, syn::parse_quote!(_cls: *const std::ffi::c_void));
+                inputs.insert(1, syn::parse_quote!(_cmd: Option<&objc::Sel>));
+                true
+            }
+        };
+        has_class_methods |= class;
+
+        // default body is objc side implementation, caller side is msg_send
+        let (body, registered) = match f.default.take() {
+            Some(block) => {
+                f.semi_token = Some(Default::default());
+                (block.to_token_stream(), true)
+            }
+            // unimplemented optionals are registered by #[objc::add_methods] only
+            None if is_optional => {
+                unused_args(&mut inputs);
+                (quote!({ unimplemented!() }), false)
+            }
+            None => (quote!(;), true),
+        };
+        if registered {
+            methods.push(ProtocolMethod {
+                name: fn_name.clone(),
+                cfgs: cfgs.clone(),
+                class,
+            });
+        }
+
+        let generics = &f.sig.generics;
+        let where_clause = &generics.where_clause;
+        let output = &f.sig.output;
+        let impl_fn = format_ident!("impl_{fn_name}");
+        impl_trait_functions.extend(quote! {
+            #(#cfgs)*
+            extern "C" fn #impl_fn #generics(#inputs) #output #where_clause #body
+        });
+        if !is_optional {
+            let sel_reg = c_str(sel);
+            let sel_fn = format_ident!("sel_{fn_name}");
+            impl_trait_functions.extend(quote! {
+                #(#cfgs)*
+                fn #sel_fn() -> &'static objc::Sel {
+                    unsafe { objc::sel_reg_name(#sel_reg.as_ptr()) }
+                }
+            });
+        }
+    }
+    check_properties(&accessors)?;
+
+    if has_class_methods && !has_cls_ptr {
+        tr.items.push(syn::parse_quote! {
+            /// Class of implementing type, receiver of protocol class methods.
+            fn cls_ptr() -> *const std::ffi::c_void;
+        });
+    }
+
+    let attrs = &tr.attrs;
+    let vis = &tr.vis;
+    let unsafety = &tr.unsafety;
+    let doc_alias = (trait_name != protocol_name).then(|| quote!(#[doc(alias = #protocol_name)]));
+    let obj_trait_name = format_ident!("{trait_name}Impl");
+
+    let add_methods = add_methods_fn(&methods);
+    let protocol_name = c_str(&protocol_name);
+
+    let mut res = tr.to_token_stream();
+    res.extend(quote! {
+        #doc_alias
+        #(#attrs)*
+        #vis #unsafety trait #obj_trait_name: #trait_name {
+            #impl_trait_functions
+            #add_methods
+
+            fn cls_add_protocol<O: objc::Obj>(cls: &objc::Class<O>) {
+                unsafe {
+                    let cls: &objc::Class<objc::Id> = std::mem::transmute(cls);
+                    if let Some(proto) = objc::objc_getProtocol(#protocol_name.as_ptr()) {
+                        cls.add_protocol(proto);
+                    }
+                }
+            }
+        }
+    });
+    Ok(res)
+}
+
+/// Prefixes argument names with `_`, body of unimplemented optional doesn't use them.
+fn unused_args(inputs: &mut Punctuated<FnArg, Token![,]>) {
+    for arg in inputs {
+        let FnArg::Typed(arg) = arg else {
+            continue;
+        };
+        match &mut *arg.pat {
+            Pat::Ident(pat) if pat.ident.to_string().starts_with('_') => {}
+            Pat::Ident(pat) => {
+                pat.ident = format_ident!("_{}", pat.ident, span = pat.ident.span());
+                pat.mutability = None;
+            }
+            pat => *pat = syn::parse_quote!(_),
+        }
+    }
+}
+
+/// Checks shape of `#[objc::property]` getter `fn name(&self) -> T` or setter `fn set_name(&mut self, value: T)`.
+fn accessor(sig: &Signature, sel: &str) -> Result<Accessor> {
+    let span = sig.ident.span();
+    let receiver = matches!(sig.inputs.first(), Some(FnArg::Receiver(r)) if r.reference.is_some());
+    let args = sig.inputs.len().saturating_sub(1);
+    let unit = matches!(sig.output, ReturnType::Default);
+    let setter = sel.starts_with("set") && sel.ends_with(':');
+    let ok = if setter {
+        args == 1 && unit && sel.matches(':').count() == 1
+    } else {
+        args == 0 && !unit && !sel.contains(':')
+    };
+    if !receiver || !ok {
+        return Err(Error::new(
+            span,
+            "#[objc::property] expects getter `fn name(&self) -> T` or setter `fn set_name(&mut self, value: T)`",
+        ));
+    }
+    Ok(Accessor {
+        sel: sel.to_string(),
+        span,
+        setter,
+    })
+}
+
+/// Each property setter `setName:` needs `name` or `isName` getter.
+fn check_properties(accessors: &[Accessor]) -> Result<()> {
+    let getters: Vec<String> = accessors
+        .iter()
+        .filter(|a| !a.setter)
+        .map(|a| {
+            let name = match a.sel.strip_prefix("is") {
+                Some(rest) if rest.starts_with(|c: char| c.is_ascii_uppercase()) => rest,
+                _ => &a.sel,
+            };
+            let mut chars = name.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    for a in accessors.iter().filter(|a| a.setter) {
+        let name = &a.sel["set".len()..a.sel.len() - 1];
+        if !getters.iter().any(|g| g == name) {
+            return Err(Error::new(
+                a.span,
+                format!("no #[objc::property] getter for setter `{}`", a.sel),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn add_methods_fn(methods: &[ProtocolMethod]) -> TokenStream {
+    let adds = methods.iter().map(|ProtocolMethod { name, cfgs, class }| {
+        let sel_fn = format_ident!("sel_{name}");
+        let impl_fn = format_ident!("impl_{name}");
+        let cls = if *class {
+            quote!(objc::object_getClass(cls as *const objc::Class<objc::Id> as _).unwrap())
+        } else {
+            quote!(cls)
+        };
+        let add = quote! {
+            let sel = Self::#sel_fn();
+            unsafe {
+                let imp: extern "C" fn() = std::mem::transmute(Self::#impl_fn as *const u8);
+                objc::class_addMethod(#cls, sel, imp, std::ptr::null());
+            }
+        };
+        if cfgs.is_empty() {
+            add
+        } else {
+            quote!(#(#cfgs)* { #add })
+        }
+    });
+    if methods.is_empty() {
+        return quote! {
+            fn cls_add_methods<O: objc::Obj>(_cls: &objc::Class<O>) {}
+        };
+    }
+    quote! {
+        fn cls_add_methods<O: objc::Obj>(cls: &objc::Class<O>) {
+            let cls: &objc::Class<objc::Id> = unsafe { std::mem::transmute(cls) };
+            #(#adds)*
+        }
+    }
+}
+
+pub(crate) fn gen_add_methods(tr_impl: TokenStream) -> Result<TokenStream> {
+    let mut imp: ItemImpl = syn::parse2(tr_impl)
+        .map_err(|_| Error::new(Span::call_site(), "#[objc::add_methods] expects impl block"))?;
+    let mut methods = vec![];
+    for item in &imp.items {
+        let ImplItem::Fn(f) = item else {
+            continue;
+        };
+        let fn_name = &f.sig.ident;
+        let Some(name) = fn_name.to_string().strip_prefix("impl_").map(String::from) else {
+            return Err(Error::new(
+                fn_name.span(),
+                format!("#[objc::add_methods] expects `impl_` prefixed methods, found `{fn_name}`"),
+            ));
+        };
+        methods.push(ProtocolMethod {
+            name: Ident::new(&name, fn_name.span()),
+            cfgs: f.attrs.iter().filter(|a| is_cfg(a)).cloned().collect(),
+            class: !matches!(f.sig.inputs.first(), Some(FnArg::Receiver(_))),
+        });
+    }
+    imp.items.push(ImplItem::Verbatim(add_methods_fn(&methods)));
+    Ok(imp.into_token_stream())
+}
@@ -0,0 +1,238 @@
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    Attribute, Error, FnArg, GenericArgument, Ident, Pat, PathArguments, Receiver, Result,
+    ReturnType, Signature, Token, Type, Visibility,
+};
+
+/// Function with or without body.
+///
+/// `fn count(&self) -> usize;` is not valid in impl blocks, so `syn::ImplItemFn` can't be used.
+pub(crate) struct FnItem {
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub sig: Signature,
+    pub body: FnBody,
+}
+
+pub(crate) enum FnBody {
+    Semi(Token![;]),
+    Block(Group),
+}
+
+impl FnItem {
+    /// Parses function, `what` names the macro in the error if `tokens` is not a function.
+    pub(crate) fn parse_for(what: &str, tokens: TokenStream) -> Result<Self> {
+        if !has_fn(&tokens) {
+            return Err(Error::new(
+                Span::call_site(),
+                format!("{what} expects function"),
+            ));
+        }
+        syn::parse2(tokens)
+    }
+
+    pub(crate) fn name(&self) -> &Ident {
+        &self.sig.ident
+    }
+
+    /// Everything before `fn` but attributes: visibility and qualifiers.
+    pub(crate) fn quals(&self, unsafety: bool) -> TokenStream {
+        let Signature {
+            constness,
+            asyncness,
+            abi,
+            ..
+        } = &self.sig;
+        let vis = &self.vis;
+        let unsafety = unsafety.then(<Token![unsafe]>::default);
+        quote!(#vis #constness #asyncness #unsafety #abi)
+    }
+
+    pub(crate) fn is_unsafe(&self) -> bool {
+        self.sig.unsafety.is_some()
+    }
+
+    /// Generics and arguments group.
+    pub(crate) fn head(&self) -> TokenStream {
+        let generics = &self.sig.generics;
+        let inputs = &self.sig.inputs;
+        let variadic = &self.sig.variadic;
+        quote!(#generics(#inputs #variadic))
+    }
+
+    /// Span of arguments group.
+    pub(crate) fn args_span(&self) -> Span {
+        self.sig.paren_token.span.join()
+    }
+}
+
+impl Parse for FnItem {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let sig = input.parse()?;
+        let body = if input.peek(Token![;]) {
+            FnBody::Semi(input.parse()?)
+        } else {
+            match input.parse::<TokenTree>() {
+                Ok(TokenTree::Group(g)) if g.delimiter() == Delimiter::Brace => FnBody::Block(g),
+                Ok(tt) => return Err(Error::new(tt.span(), "expected `;` or function body")),
+                Err(e) => return Err(Error::new(e.span(), "expected `;`")),
+            }
+        };
+        Ok(Self {
+            attrs,
+            vis,
+            sig,
+            body,
+        })
+    }
+}
+
+impl ToTokens for FnItem {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.attrs.iter().map(ToTokens::to_token_stream));
+        self.vis.to_tokens(tokens);
+        self.sig.to_tokens(tokens);
+        match &self.body {
+            FnBody::Semi(semi) => semi.to_tokens(tokens),
+            FnBody::Block(block) => block.to_tokens(tokens),
+        }
+    }
+}
+
+/// `fn` keyword at top level.
+pub(crate) fn has_fn(tokens: &TokenStream) -> bool {
+    tokens
+        .clone()
+        .into_iter()
+        .any(|tt| matches!(tt, TokenTree::Ident(i) if i == "fn"))
+}
+
+/// Receiver and names with types of other arguments.
+pub(crate) struct Params<'a> {
+    pub receiver: Option<&'a Receiver>,
+    pub args: Vec<(&'a Ident, &'a Type)>,
+}
+
+impl<'a> Params<'a> {
+    pub(crate) fn new(sig: &'a Signature) -> Result<Self> {
+        let mut receiver = None;
+        let mut args = Vec::with_capacity(sig.inputs.len());
+        for input in &sig.inputs {
+            let arg = match input {
+                FnArg::Receiver(r) => {
+                    receiver = Some(r);
+                    continue;
+                }
+                FnArg::Typed(arg) => arg,
+            };
+            match &*arg.pat {
+                Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => {
+                    args.push((&pat.ident, &*arg.ty));
+                }
+                Pat::Wild(pat) => {
+                    return Err(Error::new(
+                        pat.span(),
+                        "#[objc::msg_send] arguments must be named",
+                    ));
+                }
+                pat => {
+                    let pat = pat.to_token_stream();
+                    return Err(Error::new(
+                        pat.span(),
+                        format!("expected `name: Type` argument, found `{pat}`"),
+                    ));
+                }
+            }
+        }
+        Ok(Self { receiver, args })
+    }
+
+    pub(crate) fn names(&self) -> impl Iterator<Item = &'a Ident> + '_ {
+        self.args.iter().map(|(name, _)| *name)
+    }
+
+    /// `name: Type` pairs without patterns, so they fit into `extern "C" fn(..)` type.
+    pub(crate) fn typed(&self) -> impl Iterator<Item = TokenStream> + '_ {
+        self.args.iter().map(|(name, ty)| quote!(#name: #ty))
+    }
+}
+
+/// Return type without `->`, `()` if it is omitted.
+pub(crate) fn ret_ty(output: &ReturnType) -> TokenStream {
+    match output {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_, ty) => ty.to_token_stream(),
+    }
+}
+
+const TUPLE: &str =
+    "tuples have no C layout and can't be returned through `objc_msgSend`, use `#[repr(C)]` struct";
+
+/// Rejects return types without C layout, they can't be returned through `objc_msgSend`.
+///
+/// Large structs are fine: aarch64 returns them via `x8` register and
+/// `msg_send_x86_64` switches to `objc_msgSend_stret` for them.
+pub(crate) fn check_ret(output: &ReturnType) -> Result<()> {
+    match output {
+        ReturnType::Default => Ok(()),
+        ReturnType::Type(_, ty) => check_ret_ty(ty, false),
+    }
+}
+
+fn check_ret_ty(ty: &Type, indirect: bool) -> Result<()> {
+    let (span, msg) = match ty {
+        Type::Tuple(t) if t.elems.is_empty() => return Ok(()),
+        Type::Tuple(t) => (t.paren_token.span.join(), TUPLE.to_string()),
+        Type::Paren(t) => (t.paren_token.span.join(), TUPLE.to_string()),
+        Type::ImplTrait(t) => (
+            t.impl_token.span,
+            "`impl` types can't be returned through `objc_msgSend`".to_string(),
+        ),
+        Type::TraitObject(t) if t.dyn_token.is_some() => (
+            t.dyn_token.span(),
+            "`dyn` types can't be returned through `objc_msgSend`".to_string(),
+        ),
+        Type::Slice(t) => (
+            t.bracket_token.span.join(),
+            "slices can't be returned through `objc_msgSend`".to_string(),
+        ),
+        Type::Array(_) if indirect => return Ok(()),
+        Type::Array(t) => (
+            t.bracket_token.span.join(),
+            "arrays can't be returned by value through `objc_msgSend`, use `#[repr(C)]` struct"
+                .to_string(),
+        ),
+        Type::Reference(t) => return check_ret_ty(&t.elem, true),
+        Type::Ptr(t) => return check_ret_ty(&t.elem, true),
+        Type::Group(t) => return check_ret_ty(&t.elem, indirect),
+        Type::BareFn(t) => return check_ret(&t.output),
+        Type::Path(t) if t.qself.is_none() && t.path.is_ident("str") => (
+            t.span(),
+            "`str` can't be returned through `objc_msgSend`".to_string(),
+        ),
+        Type::Path(t) => {
+            for seg in &t.path.segments {
+                if let PathArguments::AngleBracketed(args) = &seg.arguments {
+                    for arg in &args.args {
+                        if let GenericArgument::Type(ty) = arg {
+                            check_ret_ty(ty, false)?;
+                        }
+                    }
+                }
+            }
+            return Ok(());
+        }
+        _ => return Ok(()),
+    };
+    Err(Error::new(span, msg))
+}
+
+/// `c"..."` literal.
+pub(crate) fn c_str(s: &str) -> proc_macro2::Literal {
+    proc_macro2::Literal::c_string(&std::ffi::CString::new(s).unwrap())
+}
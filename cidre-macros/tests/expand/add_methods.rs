@@ -0,0 +1,30 @@
+define_obj_type!(Bla + FooImpl, D, BLA_USIZE);
+
+impl Foo for Bla {}
+
+#[objc::add_methods]
+impl FooImpl for Bla {
+    extern "C" fn impl_count(&self, _cmd: Option<&objc::Sel>) -> usize {
+        0
+    }
+}
+
+#[objc::add_methods]
+impl OutputImpl for FrameCounter {}
+
+#[objc::add_methods]
+impl SecureCodingImpl for Archive {
+    extern "C" fn impl_supports_secure_coding(
+        _cls: *const std::ffi::c_void,
+        _cmd: Option<&objc::Sel>,
+    ) -> bool {
+        true
+    }
+
+    extern "C" fn impl_cls_for_keyed_unarchiver(
+        _cls: *const std::ffi::c_void,
+        _cmd: Option<&objc::Sel>,
+    ) -> &'static objc::Class<objc::Id> {
+        Self::cls()
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(feature = "macos_14_0")]
+#[api::available(macos = 14.0, ios = 17.0, tvos = 17.0, visionos = 1.0)]
+crate::define_cls!(AV_AUDIO_APPLICATION);
+
+impl Tag {
+    #[api::available(macos = 14.0, ios = 17.0, tvos = 17.0, watchos = 10.0, visionos = 1.0)]
+    pub fn with_cf_dictionary(dict: &cf::Dictionary) -> Self {
+        unsafe { CMTagMakeFromDictionary(dict) }
+    }
+
+    #[doc(alias = "CMTagCopyDescription")]
+    #[api::available(macos = 14.0, ios = 17.0, tvos = 17.0, watchos = 10.0, visionos = 1.0)]
+    #[inline]
+    pub fn desc_in(&self, allocator: Option<&cf::Allocator>) -> Option<arc::R<cf::String>> {
+        unsafe { CMTagCopyDescription(allocator, *self) }
+    }
+
+    #[doc(alias = "CMTagCopyDescription")]
+    #[api::available(macos = 14.0, ios = 17.0, tvos = 17.0, watchos = 10.0, visionos = 1.0)]
+    #[inline]
+    pub fn desc(&self) -> arc::R<cf::String> {
+        unsafe { std::mem::transmute(self.desc_in(None)) }
+    }
+}
+
+impl FrameInfo {
+    #[api::available(macos = 12.3)]
+    pub fn status() -> &'static Self {
+        unsafe { SCStreamFrameInfoStatus }
+    }
+}
+
+impl CalcImageAestheticsScoresRequest {
+    #[api::available(macos = 15.0, ios = 18.0, tvos = 18.0, visionos = 2.0)]
+    pub fn with_ch(ch: &mut vn::RequestCh<Self>) -> arc::R<Self> {
+        Self::alloc().init_with_ch(Some(ch))
+    }
+
+    #[api::available(macos = 15.0, ios = 18.0, tvos = 18.0, visionos = 2.0)]
+    pub fn with(f: impl FnMut(&mut Self, Option<&ns::Error>) + 'static) -> arc::R<Self> {
+        // this scope is marker for api::available to make result optional and skip body
+        {
+            let mut block = vn::RequestCh::<Self>::new2(f);
+            Self::with_ch(&mut block)
+        }
+    }
+}
+
+impl CaptureSession {
+    #[objc::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
+    pub fn add_control<'ear>(&mut self, val: &av::CaptureControl) -> ns::ExResult<'ear> {
+        ns::try_catch(|| unsafe { self.add_control_throws(val) })
+    }
+}
+
+impl Application {
+    #[objc::available(ios = 18.2, maccatalyst = 18.2, visionos = 2.2)]
+    pub async fn request_mic_injection_permission() -> MicInjectionPermission {
+        let (future, mut block) = blocks::comp1();
+        unsafe { Self::request_mic_injection_permission_ch_block(&mut block) };
+        future.await
+    }
+}
+
+#[link(name = "CoreMedia", kind = "framework")]
+unsafe extern "C-unwind" {
+    #[api::available(macos = 14.0, ios = 17.0, tvos = 17.0, watchos = 10.0, visionos = 1.0)]
+    fn CMTaggedBufferGroupGetTypeID() -> cf::Type;
+
+    #[api::available(macos = 12.3)]
+    static SCStreamFrameInfoStatus: &'static FrameInfo;
+}
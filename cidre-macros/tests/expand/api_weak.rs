@@ -0,0 +1,19 @@
+#[link(name = "CoreMedia", kind = "framework")]
+#[api::weak]
+unsafe extern "C-unwind" {
+    #[api::available(macos = 14.0, ios = 17.0, tvos = 17.0, watchos = 10.0, visionos = 1.0)]
+    fn CMTagCopyDescription(
+        allocator: Option<&cf::Allocator>,
+        tag: Tag,
+    ) -> Option<arc::R<cf::String>>;
+
+    #[api::available(macos = 14.0, ios = 17.0, tvos = 17.0, watchos = 10.0, visionos = 1.0)]
+    fn CMTagMakeFromDictionary(dict: &cf::Dictionary) -> Tag;
+}
+
+#[link(name = "ScreenCaptureKit", kind = "framework")]
+#[api::weak]
+unsafe extern "C" {
+    #[api::available(macos = 12.3)]
+    static SCStreamFrameInfoStatus: &'static FrameInfo;
+}
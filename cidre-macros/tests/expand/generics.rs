@@ -0,0 +1,45 @@
+impl<K: objc::Obj, V: objc::Obj> Dictionary<K, V> {
+    #[objc::msg_send(objectForKey:)]
+    pub fn get<'a>(&'a self, key: &K) -> Option<&'a V>;
+
+    #[objc::msg_send(enumerateKeysAndObjectsUsingBlock:)]
+    pub fn for_each<F>(&self, block: &mut blocks::NoEscBlock<F>)
+    where
+        F: FnMut(&K, &V, &mut bool);
+}
+
+#[objc::protocol(CidreDelegate)]
+pub trait Delegate: objc::Obj {
+    #[cfg(feature = "cm")]
+    #[objc::msg_send(didOutputSampleBuffer:)]
+    fn did_output_sample_buf(&mut self, sample_buf: &cm::SampleBuf);
+
+    #[cfg(target_os = "ios")]
+    #[objc::optional]
+    #[objc::msg_send(sceneDidBecomeActive:)]
+    fn scene_did_become_active(&mut self, scene: &ui::Scene);
+
+    #[objc::msg_send(nameForIndex:)]
+    fn name_for_index<'a>(&'a self, index: usize) -> &'a ns::String;
+
+    fn names<I>(&self, indices: I) -> Vec<arc::R<ns::String>>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        indices
+            .into_iter()
+            .map(|i| self.name_for_index(i).retained())
+            .collect()
+    }
+}
+
+#[objc::add_methods]
+impl DelegateImpl for Handler {
+    #[cfg(feature = "cm")]
+    extern "C" fn impl_did_output_sample_buf(
+        &mut self,
+        _cmd: Option<&objc::Sel>,
+        sample_buf: &cm::SampleBuf,
+    ) {
+    }
+}
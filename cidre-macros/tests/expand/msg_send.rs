@@ -0,0 +1,58 @@
+impl<T: objc::Obj> arc::A<Array<T>> {
+    #[objc::msg_send(init)]
+    pub fn init(self) -> arc::R<Array<T>>;
+
+    #[objc::msg_send(initWithObjects:count:)]
+    pub unsafe fn init_with_objs(self, ptr: *const &T, count: usize) -> arc::R<Array<T>>;
+}
+
+impl<T: objc::Obj> Array<T> {
+    #[objc::msg_send(array)]
+    pub fn new() -> arc::R<Self>;
+
+    #[objc::msg_send(containsObject:)]
+    pub fn contains(&self, object: &T) -> bool;
+
+    #[objc::msg_send(count)]
+    pub fn len(&self) -> usize;
+
+    #[objc::msg_send(firstObject)]
+    pub fn first(&self) -> Option<&T>;
+
+    #[objc::msg_send(copy)]
+    pub fn copy(&self) -> arc::R<Self>;
+
+    #[objc::msg_send(objectAtIndex:)]
+    pub unsafe fn get_throws(&self, index: usize) -> arc::R<T>;
+}
+
+impl<T: objc::Obj> ArrayMut<T> {
+    #[objc::msg_send(addObject:)]
+    pub fn push(&mut self, obj: &T);
+
+    #[objc::msg_send(insertObject:atIndex:)]
+    pub unsafe fn insert_obj_throws(&mut self, obj: &T, at_index: usize);
+}
+
+impl String {
+    #[objc::msg_send(stringWithContentsOfURL:encoding:error:)]
+    pub unsafe fn with_contents_of_url_err<'ear>(
+        url: &ns::Url,
+        encoding: ns::StringEncoding,
+        err: *mut Option<&'ear ns::Error>,
+    ) -> Option<arc::R<Self>>;
+
+    #[objc::msg_send(frame)]
+    pub fn frame(&self) -> cg::Rect;
+}
+
+impl VideoDataOutput {
+    #[cfg(feature = "cm")]
+    #[cfg(feature = "dispatch")]
+    #[objc::msg_send(setSampleBufferDelegate:queue:)]
+    pub fn set_sample_buf_delegate<D: VideoDataOutputSampleBufDelegate>(
+        &mut self,
+        delegate: Option<&D>,
+        queue: Option<&dispatch::Queue>,
+    );
+}
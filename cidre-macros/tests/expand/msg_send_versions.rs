@@ -0,0 +1,26 @@
+impl View {
+    #[objc::msg_send(frame)]
+    #[objc::available(macos = 14.0, ios = 17.0)]
+    pub fn frame(&self) -> cg::Rect;
+}
+
+impl Value {
+    #[objc::msg_send(valueWithRect:)]
+    #[api::available(macos = 15.0)]
+    pub fn with_rect(frame: cg::Rect) -> arc::R<Self>;
+}
+
+impl CaptureSession {
+    #[objc::msg_send(addInputWithNoConnections:)]
+    #[objc::available(ios = 8.0, maccatalyst = 14.0, tvos = 17.0, visionos = 2.1)]
+    pub fn add_input_without_connections(&mut self, input: &av::CaptureInput);
+
+    /// Indicates whether session controls are supported on this platform.
+    #[objc::msg_send(supportsControls)]
+    #[objc::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
+    pub fn supports_controls(&self) -> bool;
+
+    #[objc::msg_send(controlsDelegate)]
+    #[objc::available(macos = 15.0, ios = 18.0, maccatalyst = 18.0, tvos = 18.0)]
+    pub fn controls_delegate(&self) -> Option<arc::R<AnyControlsDelegate>>;
+}
@@ -0,0 +1,32 @@
+#[objc::class(super = ns::View, ivars = State, protocols = [ns::AppDelegateImpl], name = CidreMyView)]
+impl MyView {
+    #[objc::method(drawRect:)]
+    fn draw_rect(&mut self, rect: cg::Rect) {
+        self.super_draw_rect(rect)
+    }
+
+    #[objc::msg_send_super(drawRect:)]
+    fn super_draw_rect(&mut self, rect: cg::Rect);
+
+    #[cfg(feature = "ca")]
+    #[objc::class_method(layerClass)]
+    fn layer_cls() -> &'static objc::Class<ca::Layer> {
+        ca::Layer::cls()
+    }
+
+    #[objc::msg_send_super(frame)]
+    pub fn super_frame(&self) -> cg::Rect;
+
+    #[objc::dealloc]
+    fn cleanup(&mut self) {}
+}
+
+#[cfg(test)]
+#[objc::class]
+impl Plain {}
+
+#[objc::class(protocols = [ns::AppDelegateImpl, ns::ApplicationDelegateImpl])]
+impl Delegate {
+    #[objc::method(applicationDidFinishLaunching:)]
+    fn did_finish(&self, n: &ns::Notification) -> bool { true }
+}
@@ -0,0 +1,91 @@
+#[objc::protocol(Foo)]
+trait Foo: objc::Obj {
+    #[objc::msg_send(count)]
+    fn count(&self) -> usize;
+
+    #[objc::optional]
+    #[objc::msg_send(count2)]
+    fn count2(&self) -> usize;
+}
+
+#[objc::protocol(NSApplicationDelegate)]
+pub trait Delegate {}
+
+#[objc::protocol(SCRecordingOutputDelegate)]
+pub trait RecordingDelegate: objc::Obj {
+    #[objc::msg_send(recordingOutputDidStartRecording:)]
+    fn recording_output_did_start_recording(&mut self, recording_output: &RecordingOutput);
+
+    #[objc::msg_send(recordingOutput:didFailWithError:)]
+    fn recording_output_did_fail_with_err(
+        &mut self,
+        recording_output: &RecordingOutput,
+        error: &ns::Error,
+    );
+}
+
+#[objc::protocol(AVCaptureVideoDataOutputSampleBufferDelegate)]
+pub trait VideoDataOutputSampleBufDelegate: objc::Obj {
+    #[objc::optional]
+    #[objc::msg_send(captureOutput:didOutputSampleBuffer:fromConnection:)]
+    fn capture_output_did_output_sample_buf_from_connection(
+        &mut self,
+        output: &av::CaptureOutput,
+        sample_buf: &cm::SampleBuf,
+        connection: &av::CaptureConnection,
+    );
+
+    #[objc::optional]
+    #[objc::msg_send(captureOutput:didDropSampleBuffer:fromConnection:)]
+    fn capture_output_did_drop_sample_buf_from_connection(
+        &mut self,
+        output: &av::CaptureOutput,
+        sample_buffer: &cm::SampleBuf,
+        connection: &av::CaptureConnection,
+    );
+}
+
+#[objc::protocol(AVStereoMixing)]
+pub trait StereoMixing {
+    #[objc::property]
+    #[objc::msg_send(pan)]
+    fn pan(&self) -> f32;
+
+    #[objc::property]
+    #[objc::msg_send(setPan:)]
+    fn set_pan(&mut self, val: f32);
+
+    #[objc::optional]
+    #[objc::property]
+    #[objc::msg_send(isMuted)]
+    fn is_muted(&self) -> bool {
+        false
+    }
+
+    #[objc::optional]
+    #[objc::property]
+    #[objc::msg_send(setMuted:)]
+    fn set_muted(&mut self, val: bool);
+}
+
+#[objc::protocol(NSSecureCoding)]
+pub trait SecureCoding: objc::Obj {
+    #[objc::msg_send(supportsSecureCoding)]
+    fn supports_secure_coding() -> bool {
+        true
+    }
+
+    #[objc::optional]
+    #[objc::msg_send(classForKeyedUnarchiver)]
+    fn cls_for_keyed_unarchiver() -> &'static objc::Class<objc::Id>;
+}
+
+#[objc::protocol(CidreKVObserverRegistration)]
+pub trait KvObserverRegistration {
+    #[objc::msg_send(removeObserver:forKeyPath:)]
+    unsafe fn remove_observer_throws(&mut self, observer: &ns::Id, key_path: &ns::String);
+
+    fn remove_observer(&mut self, observer: &ns::Id, for_key_path: &ns::String) -> ns::ExResult {
+        ns::try_catch(|| unsafe { self.remove_observer_throws(observer, for_key_path) })
+    }
+}
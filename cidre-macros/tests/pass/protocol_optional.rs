@@ -0,0 +1,67 @@
+#![deny(warnings)]
+
+#[allow(non_snake_case)]
+mod objc {
+    pub use cidre_macros::{add_methods, msg_send, optional, protocol};
+    use std::{ffi::c_void, marker::PhantomData};
+
+    pub trait Obj {}
+
+    pub struct Id;
+    pub struct Sel;
+    pub struct Protocol;
+    pub struct Class<T>(PhantomData<T>);
+
+    impl<T> Class<T> {
+        pub unsafe fn add_protocol(&self, _protocol: &Protocol) -> bool {
+            true
+        }
+    }
+
+    pub unsafe fn sel_reg_name(_str: *const i8) -> &'static Sel {
+        &Sel
+    }
+
+    pub unsafe fn class_addMethod(
+        _cls: &Class<Id>,
+        _name: &Sel,
+        _imp: extern "C" fn(),
+        _types: *const i8,
+    ) -> bool {
+        true
+    }
+
+    pub unsafe fn objc_getProtocol(_name: *const i8) -> Option<&'static Protocol> {
+        None
+    }
+
+    pub unsafe fn object_getClass(_obj: *const c_void) -> Option<&'static Class<Id>> {
+        None
+    }
+}
+
+pub struct Output;
+pub struct SampleBuf;
+
+#[objc::protocol(AVCaptureVideoDataOutputSampleBufferDelegate)]
+pub trait SampleBufDelegate: objc::Obj {
+    #[objc::optional]
+    #[objc::msg_send(captureOutput:didOutputSampleBuffer:)]
+    fn capture_output_did_output_sample_buf(&mut self, output: &Output, sample_buf: &SampleBuf);
+
+    #[objc::optional]
+    #[objc::msg_send(captureOutput:didDropSampleBuffer:)]
+    fn capture_output_did_drop_sample_buf(&mut self, _output: &Output, buf: &SampleBuf);
+}
+
+#[objc::protocol(NSApplicationDelegate)]
+pub trait AppDelegate {}
+
+pub struct App;
+
+impl AppDelegate for App {}
+
+#[objc::add_methods]
+impl AppDelegateImpl for App {}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use cidre_macros as objc;
+
+trait DelegateImpl {
+    extern "C" fn impl_did_finish(&self);
+}
+
+struct Delegate;
+
+#[objc::add_methods]
+impl DelegateImpl for Delegate {
+    extern "C" fn did_finish(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use cidre_macros as api;
+
+#[api::api_available(macos = 14)]
+pub fn new_api() {}
+
+fn main() {}
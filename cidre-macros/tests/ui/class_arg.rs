@@ -0,0 +1,8 @@
+use cidre_macros as objc;
+
+struct MyView;
+
+#[objc::class(superclass = MyView)]
+impl MyView {}
+
+fn main() {}
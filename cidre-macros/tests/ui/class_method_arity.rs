@@ -0,0 +1,11 @@
+use cidre_macros as objc;
+
+struct MyView;
+
+#[objc::class]
+impl MyView {
+    #[objc::method(drawRect:)]
+    fn draw_rect(&mut self) {}
+}
+
+fn main() {}
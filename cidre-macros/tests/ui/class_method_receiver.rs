@@ -0,0 +1,13 @@
+use cidre_macros as objc;
+
+struct MyView;
+
+#[objc::class]
+impl MyView {
+    #[objc::method(layerClass)]
+    fn layer_class() -> usize {
+        0
+    }
+}
+
+fn main() {}
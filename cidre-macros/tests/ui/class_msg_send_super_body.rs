@@ -0,0 +1,13 @@
+use cidre_macros as objc;
+
+struct MyView;
+
+#[objc::class]
+impl MyView {
+    #[objc::msg_send_super(frame)]
+    fn super_frame(&self) -> usize {
+        0
+    }
+}
+
+fn main() {}
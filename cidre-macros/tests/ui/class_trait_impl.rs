@@ -0,0 +1,10 @@
+use cidre_macros as objc;
+
+struct MyView;
+
+trait Foo {}
+
+#[objc::class]
+impl Foo for MyView {}
+
+fn main() {}
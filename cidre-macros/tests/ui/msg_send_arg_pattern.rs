@@ -0,0 +1,10 @@
+use cidre_macros as objc;
+
+struct Foo;
+
+impl Foo {
+    #[objc::msg_send(objectAtIndex:)]
+    fn obj_at(&self, _: usize) -> usize;
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use cidre_macros as objc;
+
+struct Device;
+
+impl Device {
+    #[objc::api_available(android = 14.0)]
+    #[objc::msg_send(name)]
+    fn name(&self) -> usize;
+}
+
+fn main() {}
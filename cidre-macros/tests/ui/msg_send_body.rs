@@ -0,0 +1,12 @@
+use cidre_macros as objc;
+
+struct Array;
+
+impl Array {
+    #[objc::msg_send(count)]
+    fn count(&self) -> usize {
+        0
+    }
+}
+
+fn main() {}
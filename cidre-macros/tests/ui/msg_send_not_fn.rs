@@ -0,0 +1,10 @@
+use cidre_macros as objc;
+
+struct Array;
+
+impl Array {
+    #[objc::msg_send(count)]
+    const COUNT: usize = 0;
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use cidre_macros as objc;
+
+struct Foo;
+
+impl Foo {
+    #[objc::msg_send(bytes)]
+    fn bytes(&self) -> [u8; 32];
+
+    #[objc::msg_send(bytesPtr)]
+    fn bytes_ptr(&self) -> *const [u8; 32];
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use cidre_macros as objc;
+
+struct Foo;
+
+impl Foo {
+    #[objc::msg_send(bytes)]
+    fn bytes(&self) -> &[u8];
+}
+
+fn main() {}
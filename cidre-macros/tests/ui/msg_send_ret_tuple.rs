@@ -0,0 +1,10 @@
+use cidre_macros as objc;
+
+struct Foo;
+
+impl Foo {
+    #[objc::msg_send(size)]
+    fn size(&self) -> (f64, f64);
+}
+
+fn main() {}
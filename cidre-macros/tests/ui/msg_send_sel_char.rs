@@ -0,0 +1,10 @@
+use cidre_macros as objc;
+
+struct Foo;
+
+impl Foo {
+    #[objc::msg_send(objectAt-Index:)]
+    fn obj_at(&self, index: usize) -> usize;
+}
+
+fn main() {}
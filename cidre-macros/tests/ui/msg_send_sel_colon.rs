@@ -0,0 +1,10 @@
+use cidre_macros as objc;
+
+struct Foo;
+
+impl Foo {
+    #[objc::msg_send(drawPrimitives:vertexStart:usize)]
+    fn draw(&self, primitive: u8, start: usize);
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use cidre_macros as objc;
+
+struct Array;
+
+impl Array {
+    #[objc::msg_send(count)]
+    #[objc::msg_send(count)]
+    fn count(&self) -> usize;
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use cidre_macros as objc;
+
+trait Delegate {
+    #[objc::optional]
+    fn did_finish(&self);
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use cidre_macros as objc;
+
+#[objc::protocol(NSDelegate, NSObject)]
+pub trait Delegate {}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use cidre_macros as objc;
+
+#[objc::protocol(AVStereoMixing)]
+pub trait StereoMixing {
+    #[objc::property]
+    #[objc::msg_send(pan)]
+    fn pan(&self) -> f32;
+
+    #[objc::property]
+    #[objc::msg_send(setVolume:)]
+    fn set_volume(&mut self, val: f32);
+}
+
+fn main() {}
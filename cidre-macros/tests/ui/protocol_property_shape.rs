@@ -0,0 +1,10 @@
+use cidre_macros as objc;
+
+#[objc::protocol(AVStereoMixing)]
+pub trait StereoMixing {
+    #[objc::property]
+    #[objc::msg_send(pan)]
+    fn pan(&self);
+}
+
+fn main() {}
@@ -118,6 +118,16 @@ pub fn criterion_benchmark(c: &mut Criterion) {
 
     lr.fill(0.0f32);
 
+    let mut converter = audio::PcmConverter::with_formats(&src_asbd, &dst_asbd).unwrap();
+    c.bench_function("interleave with audio::PcmConverter", |b| {
+        b.iter(|| {
+            converter.convert_buf_list(N, &list_a, &mut list_b).unwrap();
+        });
+        assert_eq!(lr, res);
+    });
+
+    lr.fill(0.0f32);
+
     let comp = lr.as_mut_ptr() as *mut vdsp::Complex<f32>;
     let mut comp = unsafe { std::slice::from_raw_parts_mut(comp, N) };
     c.bench_function("interleave vDSP", |b| {
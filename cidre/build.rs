@@ -1,18 +1,88 @@
-use std::{env, fs::read_to_string, path::PathBuf, process::Command, str::FromStr};
-
-#[derive(Debug, Clone, Copy)]
+use std::{
+    env, fmt,
+    fs::read_to_string,
+    path::PathBuf,
+    process::{Command, exit},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 struct Version {
     major: u32,
     minor: u32,
 }
 
+/// Platforms of `platform_x_y` features and xcodebuild settings for them.
+const PLATFORMS: [(&str, &str); 6] = [
+    ("macos", "MACOSX_DEPLOYMENT_TARGET"),
+    ("ios", "IPHONEOS_DEPLOYMENT_TARGET"),
+    ("maccatalyst", "IPHONEOS_DEPLOYMENT_TARGET"),
+    ("tvos", "TVOS_DEPLOYMENT_TARGET"),
+    ("watchos", "WATCHOS_DEPLOYMENT_TARGET"),
+    ("visionos", "XROS_DEPLOYMENT_TARGET"),
+];
+
+/// Rust target triple, xcodebuild sdk and arch.
+const TARGETS: [(&str, &str, &str); 13] = [
+    ("aarch64-apple-darwin", "macosx", "arm64"),
+    ("x86_64-apple-darwin", "macosx", "x86_64"),
+    ("aarch64-apple-ios", "iphoneos", "arm64"),
+    ("aarch64-apple-ios-sim", "iphonesimulator", "arm64"),
+    ("x86_64-apple-ios", "iphonesimulator", "x86_64"),
+    ("aarch64-apple-ios-macabi", "maccatalyst", "arm64"),
+    ("aarch64-apple-tvos", "appletvos", "arm64"),
+    ("aarch64-apple-tvos-sim", "appletvsimulator", "arm64"),
+    ("arm64_32-apple-watchos", "watchos", "arm64_32"),
+    ("aarch64-apple-watchos", "watchos", "arm64"),
+    ("aarch64-apple-watchos-sim", "watchsimulator", "arm64"),
+    ("aarch64-apple-visionos", "xros", "arm64"),
+    ("aarch64-apple-visionos-sim", "xrsimulator", "arm64"),
+];
+
+/// Features which build for non-Apple targets with `vdsp_soft`.
+const PORTABLE_FEATURES: [&str; 3] = ["vdsp", "vdsp_soft", "simd"];
+
+/// Build of cidre for non-Apple targets.
+const VDSP_SOFT_CMD: &str = "cargo build -p cidre --no-default-features --features vdsp_soft";
+
+/// Pomace targets, each one is static lib with objc shims of a feature with the same name.
+const POMACE_TARGETS: [&str; 25] = [
+    "ut",
+    "un",
+    "sn",
+    "ns",
+    "av",
+    "cl",
+    "nl",
+    "ca",
+    "vn",
+    "mps",
+    "mpsg",
+    "mc",
+    "mtl",
+    "mtk",
+    "ci",
+    "gc",
+    "av_kit",
+    "mlc",
+    "core_motion",
+    "wk",
+    "ui",
+    "wc",
+    "sc",
+    "app",
+    "core_audio",
+];
+
+/// Deployment targets resolved from enabled `platform_x_y` features, highest one wins.
 #[derive(Debug, Clone, Default)]
 struct DeploymentTargets {
-    macos: String,
-    ios: String,
-    tvos: String,
-    watchos: String,
-    visionos: String,
+    macos: Option<Version>,
+    ios: Option<Version>,
+    maccatalyst: Option<Version>,
+    tvos: Option<Version>,
+    watchos: Option<Version>,
+    visionos: Option<Version>,
 }
 
 impl Version {
@@ -25,40 +95,185 @@ impl Version {
         None
     }
 
+    /// Parses `14_0` of `macos_14_0` feature or `14.0` of `MACOSX_DEPLOYMENT_TARGET`.
     fn with_str(str: &str) -> Option<Self> {
-        let mut res = Self { major: 0, minor: 0 };
-        let mut iter = str.split("_").into_iter();
-        let Some(s) = iter.next() else {
-            return None;
+        let mut iter = str.split(['_', '.']);
+        let major = iter.next()?.parse().ok()?;
+        let minor = match iter.next() {
+            Some(s) => s.parse().ok()?,
+            None => 0,
         };
+        Some(Self { major, minor })
+    }
+}
 
-        res.major = str::parse(s).unwrap();
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
 
-        let Some(s) = iter.next() else {
-            return None;
-        };
+impl DeploymentTargets {
+    fn get(&self, platform: &str) -> Option<Version> {
+        match platform {
+            "macos" => self.macos,
+            "ios" => self.ios,
+            "maccatalyst" => self.maccatalyst,
+            "tvos" => self.tvos,
+            "watchos" => self.watchos,
+            "visionos" => self.visionos,
+            _ => None,
+        }
+    }
+
+    fn get_mut(&mut self, platform: &str) -> Option<&mut Option<Version>> {
+        match platform {
+            "macos" => Some(&mut self.macos),
+            "ios" => Some(&mut self.ios),
+            "maccatalyst" => Some(&mut self.maccatalyst),
+            "tvos" => Some(&mut self.tvos),
+            "watchos" => Some(&mut self.watchos),
+            "visionos" => Some(&mut self.visionos),
+            _ => None,
+        }
+    }
 
-        res.minor = str::parse(s).unwrap();
+    /// Resolved `(platform, xcodebuild setting, version)` for `sdk`.
+    ///
+    /// Mac Catalyst is configured with iOS setting, so `maccatalyst_x_y` takes it over `ios_x_y`
+    /// only when building for Mac Catalyst.
+    fn settings(&self, sdk: &str) -> Vec<(&'static str, &'static str, Version)> {
+        let mut res = Vec::with_capacity(PLATFORMS.len());
+        for (platform, setting) in PLATFORMS {
+            let skip = match platform {
+                "ios" => sdk == "maccatalyst" && self.maccatalyst.is_some(),
+                "maccatalyst" => sdk != "maccatalyst",
+                _ => false,
+            };
+            if skip {
+                continue;
+            }
+            if let Some(v) = self.get(platform) {
+                res.push((platform, setting, v));
+            }
+        }
+        res
+    }
 
-        Some(res)
+    /// xcodebuild `SETTING=x.y` arguments.
+    fn xc_env_args(&self, sdk: &str) -> Vec<String> {
+        self.settings(sdk)
+            .into_iter()
+            .map(|(_, setting, v)| format!("{setting}={v}"))
+            .collect()
     }
 
-    fn to_string(&self) -> String {
-        format!("{}.{}", self.major, self.minor)
+    /// Features can't promise APIs newer than deployment target set in env.
+    fn validate_env(&self, sdk: &str) {
+        for (platform, setting) in PLATFORMS {
+            if platform != "maccatalyst" {
+                println!("cargo:rerun-if-env-changed={setting}");
+            }
+        }
+        for (platform, setting, v) in self.settings(sdk) {
+            let Ok(env_str) = env::var(setting) else {
+                continue;
+            };
+            let Some(env_v) = Version::with_str(&env_str) else {
+                error(format!("can't parse {setting}={env_str:?}, expected `x.y`"));
+            };
+            if env_v < v {
+                error(format!(
+                    "feature `{platform}_{}_{}` requires {setting} >= {v}, but it is {env_str}",
+                    v.major, v.minor
+                ));
+            }
+        }
+    }
+
+    /// `cidre_macos_deployment_target = "14.0"` cfg and `CIDRE_MACOS_DEPLOYMENT_TARGET` env
+    /// for cidre itself and `DEP_CIDRE_MACOS_DEPLOYMENT_TARGET` for build scripts of dependents.
+    fn export(&self) {
+        for (platform, _) in PLATFORMS {
+            let cfg = format!("cidre_{platform}_deployment_target");
+            println!("cargo:rustc-check-cfg=cfg({cfg}, values(any()))");
+            let Some(v) = self.get(platform) else {
+                continue;
+            };
+            println!("cargo:rustc-cfg={cfg}=\"{v}\"");
+            println!("cargo:rustc-env={}={v}", cfg.to_uppercase());
+            println!("cargo:{platform}_deployment_target={v}");
+        }
     }
 }
 
-fn add_xc_target_args_from_features(
-    target_args: &mut Vec<&'static str>,
-    features: &[&'static str],
-) {
-    for feature in features {
-        let env_var = format!("CARGO_FEATURE_{}", feature.to_uppercase());
-        if env::var_os(env_var).is_some() {
-            target_args.push("-target");
-            target_args.push(feature);
+/// Fails build script with error instead of panic backtrace.
+fn error(msg: String) -> ! {
+    eprintln!("error: {msg}");
+    exit(1)
+}
+
+fn is_feature_enabled(feature: &str) -> bool {
+    let env_var = format!("CARGO_FEATURE_{}", feature.to_uppercase());
+    env::var_os(env_var).is_some()
+}
+
+/// Enabled features which are not portable, deployment targets are just cfgs.
+fn apple_only_features() -> Vec<String> {
+    let mut res: Vec<_> = env::vars_os()
+        .filter_map(|(key, _)| {
+            let feature = key.to_str()?.strip_prefix("CARGO_FEATURE_")?.to_lowercase();
+            let deployment_target = PLATFORMS.iter().any(|(platform, _)| {
+                feature
+                    .strip_prefix(platform)
+                    .and_then(|v| v.strip_prefix('_'))
+                    .is_some_and(|v| Version::with_str(v).is_some())
+            });
+            (!deployment_target && !PORTABLE_FEATURES.contains(&feature.as_str()))
+                .then_some(feature)
+        })
+        .collect();
+    res.sort();
+    res
+}
+
+/// If pomace builds `target` for `sdk`.
+fn is_pomace_target_available(target: &str, sdk: &str) -> bool {
+    let watch = sdk == "watchos" || sdk == "watchsimulator";
+    let tv = sdk == "appletvos" || sdk == "appletvsimulator";
+    let vision = sdk == "xros" || sdk == "xrsimulator";
+    let ios = sdk == "iphoneos" || sdk == "iphonesimulator";
+    let mac = sdk == "macosx" || sdk == "maccatalyst";
+    match target {
+        "ut" | "un" | "sn" | "ns" | "av" | "cl" | "nl" => true,
+        "ca" | "vn" | "mps" | "mpsg" | "mc" | "mtl" | "mtk" | "ci" | "gc" | "av_kit" => !watch,
+        "mlc" => !watch && !vision,
+        "core_motion" => !tv,
+        "wk" => !tv && !watch,
+        "ui" => sdk != "macosx",
+        "wc" => ios,
+        "sc" | "app" => mac,
+        "core_audio" => sdk == "macosx",
+        _ => false,
+    }
+}
+
+/// xcodebuild `-target` args of enabled features and enabled features without pomace for `sdk`.
+fn xc_target_args(sdk: &str) -> (Vec<&'static str>, Vec<&'static str>) {
+    let mut args = Vec::new();
+    let mut missing = Vec::new();
+    for target in POMACE_TARGETS {
+        if !is_feature_enabled(target) {
+            continue;
+        }
+        if is_pomace_target_available(target, sdk) {
+            args.push("-target");
+            args.push(target);
+        } else {
+            missing.push(target);
         }
     }
+    (args, missing)
 }
 
 fn xc_build(
@@ -69,29 +284,14 @@ fn xc_build(
     deployment_targets: &DeploymentTargets,
 ) {
     let mut out_lib_dir = PathBuf::from(&env::var("OUT_DIR").unwrap());
-    let mut env_args = Vec::with_capacity(6);
-    if !deployment_targets.macos.is_empty() {
-        env_args.push(deployment_targets.macos.clone());
-    }
-    if !deployment_targets.ios.is_empty() {
-        env_args.push(deployment_targets.ios.clone());
-    }
-    if !deployment_targets.tvos.is_empty() {
-        env_args.push(deployment_targets.tvos.clone());
-    }
-    if !deployment_targets.watchos.is_empty() {
-        env_args.push(deployment_targets.watchos.clone());
-    }
-    if !deployment_targets.visionos.is_empty() {
-        env_args.push(deployment_targets.visionos.clone());
-    }
+    let mut env_args = deployment_targets.xc_env_args(sdk);
     env_args.push(format!("SYMROOT={}", out_lib_dir.to_str().unwrap()));
 
     let status = if sdk == "maccatalyst" {
         let c = Command::new("xcrun")
             .arg("--show-sdk-path")
             .output()
-            .unwrap();
+            .unwrap_or_else(|e| missing_xcode("xcrun", e));
         let line = String::from_utf8(c.stdout).unwrap();
         let line = line.lines().next().unwrap();
 
@@ -116,7 +316,7 @@ fn xc_build(
             .arg("build")
             .args(env_args)
             .status()
-            .unwrap()
+            .unwrap_or_else(|e| missing_xcode("xcodebuild", e))
     } else {
         Command::new("xcodebuild")
             .args(["-project", "./pomace/pomace.xcodeproj"])
@@ -128,7 +328,7 @@ fn xc_build(
             .arg("build")
             .args(env_args)
             .status()
-            .unwrap()
+            .unwrap_or_else(|e| missing_xcode("xcodebuild", e))
     };
 
     out_lib_dir.push(configuration);
@@ -142,7 +342,21 @@ fn xc_build(
 
     println!("cargo:rustc-link-search=native={s}");
 
-    assert!(status.success());
+    if !status.success() {
+        error(format!(
+            "xcodebuild failed to build pomace targets {:?} for {sdk} {arch}",
+            targets
+                .iter()
+                .filter(|t| **t != "-target")
+                .collect::<Vec<_>>()
+        ));
+    }
+}
+
+fn missing_xcode(tool: &str, e: std::io::Error) -> ! {
+    error(format!(
+        "can't run `{tool}` ({e}), install Xcode or set CIDRE_SKIP_POMACE=1 to check without pomace"
+    ))
 }
 
 fn parse_deployment_targets() -> DeploymentTargets {
@@ -150,22 +364,16 @@ fn parse_deployment_targets() -> DeploymentTargets {
     let path = PathBuf::from_str(&path).unwrap();
     let path = path.join("Cargo.toml");
     let str = read_to_string(path).unwrap();
-    let mut lines_iter = str.lines().into_iter();
-    while let Some(line) = lines_iter.next() {
-        if !line.starts_with("# deployment targets") {
-            continue;
+    let mut lines_iter = str.lines();
+    for line in lines_iter.by_ref() {
+        if line.starts_with("# deployment targets") {
+            break;
         }
-        break;
     }
 
-    let mut macos = Vec::new();
-    let mut ios = Vec::new();
-    let mut tvos = Vec::new();
-    let mut maccatalyst = Vec::new();
-    let mut watchos = Vec::new();
-    let mut visionos = Vec::new();
+    let mut tables: [Vec<(String, Version)>; PLATFORMS.len()] = Default::default();
 
-    while let Some(line) = lines_iter.next() {
+    for line in lines_iter {
         if line.starts_with("# end of deployment targets") {
             break;
         }
@@ -178,74 +386,75 @@ fn parse_deployment_targets() -> DeploymentTargets {
             continue;
         };
 
+        let Some(i) = PLATFORMS.iter().position(|(p, _)| *p == platform) else {
+            error(format!(
+                "unknown platform {platform:?} of deployment target feature `{val}`"
+            ));
+        };
+
         let Some(ver) = Version::with_str(ver_str) else {
-            continue;
+            error(format!(
+                "can't parse deployment target feature `{val}`, expected `{platform}_x_y`"
+            ));
         };
 
-        match platform {
-            "macos" => macos.push((format!("CARGO_FEATURE_MACOS_{ver_str}"), ver)),
-            "ios" => ios.push((format!("CARGO_FEATURE_IOS_{ver_str}"), ver)),
-            "tvos" => tvos.push((format!("CARGO_FEATURE_TVOS_{ver_str}"), ver)),
-            "maccatalyst" => {
-                maccatalyst.push((format!("CARGO_FEATURE_MACCATALYST_{ver_str}"), ver))
-            }
-            "watchos" => watchos.push((format!("CARGO_FEATURE_WATCHOS_{ver_str}"), ver)),
-            "visionos" => visionos.push((format!("CARGO_FEATURE_VISIONOS_{ver_str}"), ver)),
-            _ => {
-                panic!("unknown platform {platform:?}");
-            }
-        }
+        let feature = format!("CARGO_FEATURE_{}", val.to_uppercase());
+        tables[i].push((feature, ver));
     }
 
-    macos.reverse();
-    ios.reverse();
-    tvos.reverse();
-    maccatalyst.reverse();
-    watchos.reverse();
-    visionos.reverse();
-
     let mut res = DeploymentTargets::default();
 
-    if let Some(v) = Version::with_table(&macos) {
-        res.macos = format!("MACOSX_DEPLOYMENT_TARGET={}", v.to_string());
-    }
-    if let Some(v) = Version::with_table(&ios) {
-        res.ios = format!("IPHONEOS_DEPLOYMENT_TARGET={}", v.to_string());
-    }
-    if let Some(v) = Version::with_table(&tvos) {
-        res.tvos = format!("TVOS_DEPLOYMENT_TARGET={}", v.to_string());
-    }
-    if let Some(v) = Version::with_table(&watchos) {
-        res.watchos = format!("WATCHOS_DEPLOYMENT_TARGET={}", v.to_string());
-    }
-    if let Some(v) = Version::with_table(&maccatalyst) {
-        // TODO: investigate
-        res.ios = format!("IPHONEOS_DEPLOYMENT_TARGET={}", v.to_string());
-    }
-    if let Some(v) = Version::with_table(&visionos) {
-        res.visionos = format!("XROS_DEPLOYMENT_TARGET={}", v.to_string());
+    for ((platform, _), mut table) in PLATFORMS.into_iter().zip(tables) {
+        table.reverse();
+        *res.get_mut(platform).unwrap() = Version::with_table(&table);
     }
 
     res
 }
 
 fn main() {
+    println!("cargo:rerun-if-env-changed=CIDRE_SKIP_POMACE");
+    println!("cargo:rustc-check-cfg=cfg(cidre_skip_pomace)");
+
+    let target = env::var("TARGET").unwrap();
+    let Some(&(_, sdk, arch)) = TARGETS.iter().find(|(t, ..)| *t == target) else {
+        if !is_feature_enabled("vdsp_soft") {
+            let supported: Vec<_> = TARGETS.iter().map(|(t, ..)| *t).collect();
+            error(format!(
+                "unsupported target {target:?}, cidre supports {}, \
+                other targets only portable vdsp with `{VDSP_SOFT_CMD}`",
+                supported.join(", ")
+            ));
+        }
+        let apple_only = apple_only_features();
+        if !apple_only.is_empty() {
+            error(format!(
+                "features {} require Apple target, for {target:?} build only portable vdsp \
+                with `{VDSP_SOFT_CMD}`",
+                apple_only.join(", ")
+            ));
+        }
+        // portable subset, nothing to link
+        return;
+    };
+
     let versions = parse_deployment_targets();
+    versions.validate_env(sdk);
+    versions.export();
 
-    let sdk = match env::var("TARGET").unwrap().as_ref() {
-        "aarch64-apple-darwin" | "x86_64-apple-darwin" => "macosx",
-        "aarch64-apple-ios" => "iphoneos",
-        "x86_64-apple-ios" | "aarch64-apple-ios-sim" => "iphonesimulator",
-        "aarch64-apple-ios-macabi" => "maccatalyst",
-        "aarch64-apple-tvos" => "appletvos",
-        "aarch64-apple-tvos-sim" => "appletvsimulator",
-        "arm64_32-apple-watchos" => "watchos",
-        "aarch64-apple-watchos" => "watchos",
-        "aarch64-apple-watchos-sim" => "watchsimulator",
-        "aarch64-apple-visionos" => "xros",
-        "aarch64-apple-visionos-sim" => "xrsimulator",
-        x => panic!("unknown tripple: {x}"),
-    };
+    let (xc_target_args, missing) = xc_target_args(sdk);
+    if !missing.is_empty() {
+        println!(
+            "cargo:warning=pomace doesn't build {} for {sdk}, objc classes of these features will fail to link",
+            missing.join(", ")
+        );
+    }
+
+    if env::var_os("CIDRE_SKIP_POMACE").is_some_and(|v| v != "0") {
+        // no Xcode, pomace statics stay unlinked, good enough for `cargo check` and docs
+        println!("cargo:rustc-cfg=cidre_skip_pomace");
+        return;
+    }
 
     let clang_rt = match sdk {
         "macosx" => "clang_rt.osx",
@@ -254,97 +463,27 @@ fn main() {
         "appletvos" => "clang_rt.tvos",
         "appletvsimulator" => "clang_rt.tvossim",
         "watchos" => "clang_rt.watchos",
-        "watchossimulator" => "clang_rt.watchossim",
+        "watchsimulator" => "clang_rt.watchossim",
         "xros" => "clang_rt.xros",
         "xrsimulator" => "clang_rt.xrossim",
         "maccatalyst" => "clang_rt.ios", // check
-        x => panic!("unknown sdk {x}"),
+        x => unreachable!("unknown sdk {x}"),
     };
 
     println!("cargo:rustc-link-lib={}", clang_rt);
     println!("cargo:rustc-link-search={}", clang_link_search_path());
 
-    let arch = match env::var("TARGET").unwrap().as_ref() {
-        "aarch64-apple-ios-macabi"
-        | "aarch64-apple-darwin"
-        | "aarch64-apple-ios"
-        | "aarch64-apple-tvos"
-        | "aarch64-apple-tvos-sim"
-        | "aarch64-apple-ios-sim"
-        | "aarch64-apple-watchos-sim"
-        | "aarch64-apple-visionos"
-        | "aarch64-apple-visionos-sim" => "arm64",
-        "x86_64-apple-ios" | "x86_64-apple-darwin" => "x86_64",
-        "arm64_32-apple-watchos" => "arm64_32",
-        x => panic!("unknown tripple: {x}"),
-    };
-
     let configuration = match env::var("PROFILE").unwrap().as_str() {
         "release" => "Release",
         "debug" => "Debug",
         x => panic!("unknown profile: {x}"),
     };
 
-    let mut xc_target_args = Vec::new();
-
-    add_xc_target_args_from_features(
-        &mut xc_target_args,
-        &["ut", "un", "sn", "ns", "av", "cl", "nl"],
-    );
-
-    if sdk != "watchos" && sdk != "watchsimulator" {
-        add_xc_target_args_from_features(
-            &mut xc_target_args,
-            &[
-                "ca", "vn", "mps", "mpsg", "mc", "mtl", "mtk", "ci", "gc", "av_kit",
-            ],
+    if (sdk == "macosx" || sdk == "maccatalyst") && is_feature_enabled("private") {
+        println!("cargo:rustc-link-search=framework=/System/Library/PrivateFrameworks");
+        println!(
+            "cargo:rustc-link-search=framework=/Library/Apple/System/Library/PrivateFrameworks"
         );
-        if sdk != "xros" && sdk != "xrsimulator" {
-            add_xc_target_args_from_features(&mut xc_target_args, &["mlc"]);
-        }
-    }
-
-    if sdk != "appletvos" && sdk != "appletvsimulator" {
-        add_xc_target_args_from_features(&mut xc_target_args, &["core_motion"]);
-    }
-
-    if sdk != "appletvos"
-        && sdk != "appletvsimulator"
-        && sdk != "watchos"
-        && sdk != "watchsimulator"
-    {
-        add_xc_target_args_from_features(&mut xc_target_args, &["wk"]);
-    }
-
-    if [
-        "iphoneos",
-        "iphonesimulator",
-        "maccatalyst",
-        "appletvos",
-        "appletvsimulator",
-        "watchos",
-        "watchosimulator",
-        "visionos",
-        "visionsimulator",
-    ]
-    .contains(&sdk)
-    {
-        add_xc_target_args_from_features(&mut xc_target_args, &["ui"]);
-    }
-    if sdk == "iphoneos" || sdk == "iphonesimulator" {
-        add_xc_target_args_from_features(&mut xc_target_args, &["wc"]);
-    }
-    if sdk == "macosx" || sdk == "maccatalyst" {
-        add_xc_target_args_from_features(&mut xc_target_args, &["sc", "app"]);
-        if env::var_os("CARGO_FEATURE_PRIVATE").is_some() {
-            println!("cargo:rustc-link-search=framework=/System/Library/PrivateFrameworks");
-            println!(
-                "cargo:rustc-link-search=framework=/Library/Apple/System/Library/PrivateFrameworks"
-            );
-        }
-    }
-    if sdk == "macosx" {
-        add_xc_target_args_from_features(&mut xc_target_args, &["core_audio"]);
     }
 
     xc_build(&xc_target_args, sdk, arch, configuration, &versions);
@@ -356,7 +495,7 @@ fn clang_link_search_path() -> String {
     let output = Command::new("/usr/bin/clang")
         .arg("--print-search-dirs")
         .output()
-        .unwrap();
+        .unwrap_or_else(|e| missing_xcode("/usr/bin/clang", e));
     if !output.status.success() {
         panic!("Can't get search paths from clang");
     }
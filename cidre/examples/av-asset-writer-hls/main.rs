@@ -1,6 +1,6 @@
 #[cfg(target_os = "macos")]
 mod macos {
-    use std::{fs, io::Write, path::PathBuf};
+    use std::{fs, path::PathBuf};
 
     use cidre::{
         arc, av, av::AssetWriterDelegate, cm, define_obj_type, dispatch, ns, objc, objc::Obj, sc,
@@ -53,7 +53,7 @@ mod macos {
         n: u32,
         dir: PathBuf,
         base_name: String,
-        target_dur: u32,
+        playlist: cm::hls::MediaPlaylist,
     }
 
     impl SegmentWriter {
@@ -73,41 +73,28 @@ mod macos {
             format!("{}{}.m4s", self.base_name, self.n)
         }
 
+        fn write_playlist(&self) {
+            fs::write(self.playlist_path(), self.playlist.to_string()).unwrap();
+        }
+
         fn write_init(&mut self, data: &[u8]) {
             fs::write(self.init_path(), data).unwrap();
-
-            let target_dur = self.target_dur;
-            let base_name = &self.base_name;
-            fs::write(
-                self.playlist_path(),
-                format!(
-                    "#EXTM3U
-#EXT-X-VERSION:7
-#EXT-X-TARGETDURATION:{target_dur}
-#EXT-X-MEDIA-SEQUENCE:0
-#EXT-X-MAP:URI=\"{base_name}.mp4\"
-"
-                ),
-            )
-            .unwrap();
+            self.write_playlist();
         }
 
-        fn write_segment(&mut self, data: &[u8], duration: f64) {
+        fn write_segment(&mut self, data: &[u8], report: &av::AssetSegmentReport) -> f64 {
             fs::write(self.segment_path(), data).unwrap();
-            let mut file = fs::File::options()
-                .append(true)
-                .open(self.playlist_path())
-                .unwrap();
-            let segment_name = self.segment_name();
-            writeln!(file, "\n#EXTINF:{duration},\n{segment_name}").unwrap();
+            let map = cm::hls::Map::new(format!("{}.mp4", self.base_name));
+            let segment = report.hls_segment(self.segment_name()).with_map(map);
+            let duration = segment.duration;
+            self.playlist.segments.push(segment);
+            self.write_playlist();
+            duration
         }
 
         fn write_end(&mut self) {
-            let mut file = fs::File::options()
-                .append(true)
-                .open(self.playlist_path())
-                .unwrap();
-            writeln!(file, "\n#EXT-X-ENDLIST").unwrap();
+            self.playlist.end_list = true;
+            self.write_playlist();
         }
     }
 
@@ -135,15 +122,14 @@ mod macos {
                     ctx.write_init(segment_data.as_slice());
                 }
                 av::AssetSegmentType::Separable => {
-                    let duration = segment_report
-                        .unwrap()
-                        .track_reports()
-                        .get(0)
-                        .unwrap()
-                        .duration()
-                        .as_secs();
-                    ctx.write_segment(segment_data.as_slice(), duration);
-                    eprintln!("[{}]{duration}", ctx.n);
+                    let data = segment_data.as_slice();
+                    let duration = ctx.write_segment(data, segment_report.unwrap());
+                    let decode_time = cm::iso::find(data, &[cm::iso::BoxType::MOOF])
+                        .ok()
+                        .flatten()
+                        .and_then(|moof| cm::iso::Moof::parse(&moof).ok())
+                        .and_then(|moof| moof.trafs.first()?.base_media_decode_time);
+                    eprintln!("[{}]{duration} decode time: {decode_time:?}", ctx.n);
                     ctx.n += 1;
                 }
             }
@@ -181,7 +167,7 @@ mod macos {
             n: 0,
             dir: "/tmp/".into(),
             base_name: "hls".into(),
-            target_dur: TARGET_DUR,
+            playlist: cm::hls::MediaPlaylist::new(TARGET_DUR),
         });
 
         let mut input = av::AssetWriterInput::with_media_type_and_output_settings(
@@ -11,6 +11,7 @@ NS_ASSUME_NONNULL_BEGIN
 
 Class CA_DISPLAY_LINK;
 Class CA_ANIMATION;
+Class CA_SPRING_ANIMATION;
 Class CA_MEDIA_TIMING_FUNCTION;
 Class CA_LAYER;
 Class CA_METAL_LAYER;
@@ -25,6 +26,7 @@ static void ca_initializer(void)
         initialized = 1;
         
         CA_ANIMATION = [CAAnimation class];
+        CA_SPRING_ANIMATION = [CASpringAnimation class];
         CA_MEDIA_TIMING_FUNCTION = [CAMediaTimingFunction class];
         CA_DISPLAY_LINK = NSClassFromString(@"CADisplayLink");
 //        CA_DISPLAY_LINK = [CADisplayLink class];
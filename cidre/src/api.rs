@@ -23,24 +23,27 @@ impl<T> DlSym<T> {
 
     #[inline]
     pub fn get_fn(&self) -> Option<&T> {
-        unsafe {
-            match self.ptr.load(Ordering::Relaxed) {
-                1 => std::mem::transmute(&self.initialize_fn()),
-                ptr => {
-                    fence(Ordering::Acquire);
-                    std::mem::transmute(&ptr)
-                }
+        let ptr = match self.ptr.load(Ordering::Relaxed) {
+            1 => unsafe { self.initialize_fn() },
+            ptr => {
+                fence(Ordering::Acquire);
+                ptr
             }
+        };
+        if ptr == 0 {
+            return None;
         }
+        // fn pointer is stored in `self.ptr`
+        Some(unsafe { &*(&self.ptr as *const AtomicUsize).cast::<T>() })
     }
     #[inline]
     pub fn get_var(&self) -> Option<&T> {
         unsafe {
             match self.ptr.load(Ordering::Relaxed) {
-                1 => std::mem::transmute(self.initialize_var()),
+                1 => std::mem::transmute::<usize, Option<&T>>(self.initialize_var()),
                 ptr => {
                     fence(Ordering::Acquire);
-                    std::mem::transmute(ptr)
+                    std::mem::transmute::<usize, Option<&T>>(ptr)
                 }
             }
         }
@@ -55,6 +58,8 @@ impl<T> DlSym<T> {
         const RTLD_DEFAULT: isize = -2isize;
         let val = unsafe { dlsym(RTLD_DEFAULT as _, self.name.as_ptr()) };
         if val.is_null() {
+            // remember the miss, so lookup happens only once
+            self.ptr.store(0, Ordering::Release);
             return 0;
         }
 
@@ -74,6 +79,8 @@ impl<T> DlSym<T> {
         const RTLD_DEFAULT: isize = -2isize;
         let val = unsafe { dlsym(RTLD_DEFAULT as _, self.name.as_ptr()) };
         if val.is_null() {
+            // remember the miss, so lookup happens only once
+            self.ptr.store(0, Ordering::Release);
             return 0;
         }
 
@@ -326,7 +333,7 @@ pub use cidre_macros::api_available as available;
 pub use cidre_macros::api_weak as weak;
 pub use version;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "ns"))]
 mod tests {
     use crate::{api, ns};
 
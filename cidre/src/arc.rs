@@ -8,6 +8,8 @@ use std::{
 };
 
 pub trait Release {
+    /// # Safety
+    /// balances retain, object must not be used after the last release
     unsafe fn release(&mut self);
 }
 
@@ -142,7 +144,8 @@ macro_rules! return_ar {
     };
 }
 
-/// ```
+#[cfg_attr(feature = "cf", doc = "```")]
+#[cfg_attr(not(feature = "cf"), doc = "```ignore")]
 /// use cidre::cf;
 ///
 /// let n = cf::Number::from_i8(10);
@@ -377,23 +377,33 @@ pub mod err {
     use crate::os::Error;
 
     /// 0x666D743F, 1718449215
+    #[doc(alias = "kAudioConverterErr_FormatNotSupported")]
     pub const FORMAT_NOT_SUPPORTED: Error = Error::from_be_bytes(*b"fmt?");
 
     /// 'op??', integer used because of trigraph
+    #[doc(alias = "kAudioConverterErr_OperationNotSupported")]
     pub const OPERATION_NOT_SUPPORTED: Error = Error::new_unchecked(0x6F703F3F);
 
     /// 0x70726F70, 1886547824
+    #[doc(alias = "kAudioConverterErr_PropertyNotSupported")]
     pub const PROPERTY_NOT_SUPPORTED: Error = Error::from_be_bytes(*b"prop");
+    #[doc(alias = "kAudioConverterErr_InvalidInputSize")]
     pub const INVALID_INPUT_SIZE: Error = Error::from_be_bytes(*b"insz");
+    #[doc(alias = "kAudioConverterErr_InvalidOutputSize")]
     pub const INVALID_OUTPUT_SIZE: Error = Error::from_be_bytes(*b"otsz");
     /// e.g. byte size is not a multiple of the frame size
     /// 0x77686174, 2003329396
+    #[doc(alias = "kAudioConverterErr_UnspecifiedError")]
     pub const UNSPECIFIED_ERROR: Error = Error::from_be_bytes(*b"what");
 
     /// 0x2173697A, 561211770
+    #[doc(alias = "kAudioConverterErr_BadPropertySizeError")]
     pub const BAD_PROPERTY_SIZE_ERROR: Error = Error::from_be_bytes(*b"!siz");
+    #[doc(alias = "kAudioConverterErr_RequiresPacketDescriptionsError")]
     pub const REQUIRES_PACKET_DESCRIPTIONS_ERROR: Error = Error::from_be_bytes(*b"!pkd");
+    #[doc(alias = "kAudioConverterErr_InputSampleRateOutOfRange")]
     pub const INPUT_SAMPLE_RATE_OUT_OF_RANGE: Error = Error::from_be_bytes(*b"!isr");
+    #[doc(alias = "kAudioConverterErr_OutputSampleRateOutOfRange")]
     pub const OUTPUT_SAMPLE_RATE_OUT_OF_RANGE: Error = Error::from_be_bytes(*b"!osr");
 
     // ios only
@@ -404,10 +414,12 @@ pub mod err {
     /// interruption (see kAudioConverterPropertyCanResumeFromInterruption), you must
     /// wait for an EndInterruption notification from AudioSession, and call AudioSessionSetActive(true)
     /// before resuming.
+    #[doc(alias = "kAudioConverterErr_HardwareInUse")]
     pub const HARDWARE_IN_USE: Error = Error::from_be_bytes(*b"hwiu");
 
     /// Returned from AudioConverterNew if the new converter would use a hardware codec
     /// which the application does not have permission to use.
+    #[doc(alias = "kAudioConverterErr_NoHardwarePermission")]
     pub const NO_HARDWARE_PERMISSION: Error = Error::from_be_bytes(*b"perm");
 }
 
@@ -26,30 +26,55 @@ pub struct QueueTimeline(NonNull<c_void>);
 pub mod err {
     use crate::os::Error;
 
+    #[doc(alias = "kAudioQueueErr_InvalidBuffer")]
     pub const INVALID_BUFFER: Error = Error::new_unchecked(-66687);
+    #[doc(alias = "kAudioQueueErr_BufferEmpty")]
     pub const BUFFER_EMPTY: Error = Error::new_unchecked(-66686);
+    #[doc(alias = "kAudioQueueErr_DisposalPending")]
     pub const DISPOSAL_PENDING: Error = Error::new_unchecked(-66685);
+    #[doc(alias = "kAudioQueueErr_InvalidProperty")]
     pub const INVALID_PROPERTY: Error = Error::new_unchecked(-66684);
+    #[doc(alias = "kAudioQueueErr_InvalidPropertySize")]
     pub const INVALID_PROPERTY_SIZE: Error = Error::new_unchecked(-66683);
+    #[doc(alias = "kAudioQueueErr_InvalidParameter")]
     pub const INVALID_PARAMETER: Error = Error::new_unchecked(-66682);
+    #[doc(alias = "kAudioQueueErr_CannotStart")]
     pub const CANNOT_START: Error = Error::new_unchecked(-66681);
+    #[doc(alias = "kAudioQueueErr_InvalidDevice")]
     pub const INVALID_DEVICE: Error = Error::new_unchecked(-66680);
+    #[doc(alias = "kAudioQueueErr_BufferInQueue")]
     pub const BUFFER_IN_QUEUE: Error = Error::new_unchecked(-66679);
+    #[doc(alias = "kAudioQueueErr_InvalidRunState")]
     pub const INVALID_RUN_STATE: Error = Error::new_unchecked(-66678);
+    #[doc(alias = "kAudioQueueErr_InvalidQueueType")]
     pub const INVALID_QUEUE_TYPE: Error = Error::new_unchecked(-66677);
+    #[doc(alias = "kAudioQueueErr_Permissions")]
     pub const PERMISSIONS: Error = Error::new_unchecked(-66676);
+    #[doc(alias = "kAudioQueueErr_InvalidPropertyValue")]
     pub const INVALID_PROPERTY_VALUE: Error = Error::new_unchecked(-66675);
+    #[doc(alias = "kAudioQueueErr_PrimeTimedOut")]
     pub const PRIME_TIMED_OUT: Error = Error::new_unchecked(-66674);
+    #[doc(alias = "kAudioQueueErr_CodecNotFound")]
     pub const CODEC_NOT_FOUND: Error = Error::new_unchecked(-66673);
+    #[doc(alias = "kAudioQueueErr_InvalidCodecAccess")]
     pub const INVALID_CODEC_ACCESS: Error = Error::new_unchecked(-66672);
+    #[doc(alias = "kAudioQueueErr_QueueInvalidated")]
     pub const QUEUE_INVALIDATED: Error = Error::new_unchecked(-66671);
+    #[doc(alias = "kAudioQueueErr_TooManyTaps")]
     pub const TOO_MANY_TAPS: Error = Error::new_unchecked(-66670);
+    #[doc(alias = "kAudioQueueErr_InvalidTapContext")]
     pub const INVALID_TAP_CONTEXT: Error = Error::new_unchecked(-66669);
+    #[doc(alias = "kAudioQueueErr_RecordUnderrun")]
     pub const RECORD_UNDERRUN: Error = Error::new_unchecked(-66668);
+    #[doc(alias = "kAudioQueueErr_InvalidTapType")]
     pub const INVALID_TAP_TYPE: Error = Error::new_unchecked(-66667);
+    #[doc(alias = "kAudioQueueErr_BufferEnqueuedTwice")]
     pub const BUFFER_ENQUEUED_TWICE: Error = Error::new_unchecked(-66666);
+    #[doc(alias = "kAudioQueueErr_CannotStartYet")]
     pub const CANNOT_START_YET: Error = Error::new_unchecked(-66665);
+    #[doc(alias = "kAudioQueueErr_EnqueueDuringReset")]
     pub const ENQUEUE_DURING_RESET: Error = Error::new_unchecked(-66632);
+    #[doc(alias = "kAudioQueueErr_InvalidOfflineMode")]
     pub const INVALID_OFFLINE_MODE: Error = Error::new_unchecked(-66626);
 }
 
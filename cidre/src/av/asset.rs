@@ -133,7 +133,7 @@ impl UrlAsset {
     pub fn duration(&self) -> cm::Time;
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_URL_ASSET: &'static objc::Class<UrlAsset>;
 }
@@ -29,6 +29,19 @@ impl SegmentReport {
 
     #[objc::msg_send(trackReports)]
     pub fn track_reports(&self) -> arc::R<ns::Array<TrackReport>>;
+
+    /// HLS media segment with duration of the longest track of the report.
+    #[cfg(feature = "cm")]
+    pub fn hls_segment(&self, uri: impl Into<String>) -> cm::hls::Segment {
+        let duration = self
+            .track_reports()
+            .iter()
+            .map(|t| t.duration())
+            .filter(|d| d.is_valid())
+            .map(|d| d.as_secs())
+            .fold(0.0, f64::max);
+        cm::hls::Segment::new(uri, duration)
+    }
 }
 
 define_obj_type!(
@@ -250,7 +250,7 @@ pub trait Delegate: objc::Obj {
 define_obj_type!(pub AnyDelegate(ns::Id));
 impl Delegate for AnyDelegate {}
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_ASSET_WRITER: &'static objc::Class<Writer>;
 }
@@ -203,7 +203,7 @@ unsafe extern "C" {
     static AVAudioApplicationMuteStateKey: &'static ns::String;
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_AUDIO_APPLICATION: &'static objc::Class<App>;
 }
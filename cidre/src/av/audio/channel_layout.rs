@@ -34,7 +34,7 @@ impl ChannelLayout {
     pub fn channel_count(&self) -> av::AudioChannelCount;
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_AUDIO_CHANNEL_LAYOUT: &'static objc::Class<ChannelLayout>;
 }
@@ -181,7 +181,7 @@ impl Converter {
     }
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_AUDIO_CONVERTER: &'static objc::Class<Converter>;
 }
@@ -151,7 +151,7 @@ impl Format {
     pub fn magic_cookie(&self) -> Option<arc::R<ns::Data>>;
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_AUDIO_FORMAT: &'static objc::Class<Format>;
 }
@@ -2,9 +2,11 @@ use crate::{arc, av, define_obj_type, ns, objc};
 
 #[objc::protocol(AVStereoMixing)]
 pub trait StereoMixing {
+    #[objc::property]
     #[objc::msg_send(pan)]
     fn pan(&self) -> f32;
 
+    #[objc::property]
     #[objc::msg_send(setPan:)]
     fn set_pan(&mut self, value: f32);
 }
@@ -31,9 +33,11 @@ pub trait Mixing: StereoMixing {
         bus: av::AudioNodeBus,
     ) -> Option<arc::R<av::audio::MixingDst>>;
 
+    #[objc::property]
     #[objc::msg_send(volume)]
     fn volume(&self) -> f32;
 
+    #[objc::property]
     #[objc::msg_send(setVolume:)]
     fn set_volume(&mut self, value: f32);
 }
@@ -88,7 +88,7 @@ impl PlayerNode {
     pub fn set_pan(&mut self, val: f32);
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_AUDIO_PLAYER_NODE: &'static objc::Class<PlayerNode>;
 }
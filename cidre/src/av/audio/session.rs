@@ -510,7 +510,7 @@ impl Session {
 }
 
 #[cfg(any(target_os = "ios", target_os = "watchos", target_os = "tvos"))]
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_AUDIO_SESSION: &'static objc::Class<Session>;
 }
@@ -24,7 +24,7 @@ impl Effect {
     pub fn set_bypass(&mut self, value: bool);
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_AUDIO_UNIT_EFFECT: &'static objc::Class<Effect>;
 }
@@ -31,7 +31,7 @@ impl TimeEffect {
     }
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_AUDIO_UNIT_TIME_EFFECT: &'static objc::Class<TimeEffect>;
 }
@@ -837,7 +837,7 @@ impl<'a> DerefMut for ConfigLockGuard<'a> {
     }
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_CAPTURE_DEVICE: &'static objc::Class<Device>;
 }
@@ -2184,7 +2184,7 @@ pub mod notifications {
 
     /// Posted when a device becomes unavailable on the system.
     #[doc(alias = "AVCaptureDeviceWasDisconnectedNotification")]
-    #[api::available(macos = 10.7, ios = 4.0, maccatalyst = 14.0, tvos = 17)]
+    #[api::available(macos = 10.7, ios = 4.0, maccatalyst = 14.0, tvos = 17.0)]
     pub fn was_disconnected() -> &'static ns::NotificationName {
         unsafe { AVCaptureDeviceWasDisconnectedNotification }
     }
@@ -2202,7 +2202,7 @@ pub mod notifications {
     unsafe extern "C" {
         #[api::available(macos = 10.7, ios = 4.0, maccatalyst = 14.0, tvos = 17.0)]
         static AVCaptureDeviceWasConnectedNotification: &'static ns::NotificationName;
-        #[api::available(macos = 10.7, ios = 4.0, maccatalyst = 14.0, tvos = 17)]
+        #[api::available(macos = 10.7, ios = 4.0, maccatalyst = 14.0, tvos = 17.0)]
         static AVCaptureDeviceWasDisconnectedNotification: &'static ns::NotificationName;
         #[api::available(ios = 5.0, maccatalyst = 14.0, tvos = 17.0)]
         static AVCaptureDeviceSubjectAreaDidChangeNotification: &'static ns::NotificationName;
@@ -2318,7 +2318,7 @@ impl ZoomRange {
     pub fn contains_zoom_factor(&self, zoom_factor: cg::Float) -> bool;
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_CAPTURE_DEVICE_DISCOVERY_SESSION: &'static objc::Class<DiscoverySession>;
     static AV_CAPTURE_DEVICE_ROTATION_COORDINATOR: &'static objc::Class<RotationCoordinator>;
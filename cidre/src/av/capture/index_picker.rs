@@ -116,7 +116,7 @@ impl IndexPicker {
     );
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_CAPTURE_INDEX_PICKER: &'static objc::Class<IndexPicker>;
 }
@@ -139,7 +139,7 @@ define_obj_type!(
     AV_CAPTURE_METADATA_INPUT
 );
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     #[cfg(not(target_os = "watchos"))]
     static AV_CAPTURE_DEVICE_INPUT: &'static objc::Class<DeviceInput>;
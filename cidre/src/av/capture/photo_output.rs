@@ -78,7 +78,7 @@ impl PhotoSettings {
     pub fn set_auto_red_eye_reduction_enabled(&mut self, val: bool);
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_CAPTURE_PHOTO_OUTPUT: &'static objc::Class<PhotoOutput>;
     static AV_CAPTURE_PHOTO_SETTINGS: &'static objc::Class<PhotoSettings>;
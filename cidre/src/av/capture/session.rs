@@ -290,7 +290,7 @@ impl Session {
     pub fn hw_cost(&self) -> f32;
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_CAPTURE_SESSION: &'static objc::Class<Session>;
     static AV_CAPTURE_MULTI_CAM_SESSION: &'static objc::Class<MultiCamSession>;
@@ -155,7 +155,7 @@ impl Slider {
     );
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_CAPTURE_SLIDER: &'static objc::Class<Slider>;
 }
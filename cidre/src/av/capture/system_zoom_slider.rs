@@ -61,7 +61,7 @@ impl SysZoomSlider {
     }
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_CAPTURE_SYSTEM_ZOOM_SLIDER: &'static objc::Class<SysZoomSlider>;
 }
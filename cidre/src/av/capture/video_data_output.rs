@@ -119,7 +119,7 @@ impl VideoDataOutput {
     ) -> Option<arc::R<ns::Dictionary<ns::String, ns::Id>>>;
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_CAPTURE_VIDEO_DATA_OUTPUT: &'static objc::Class<VideoDataOutput>;
 }
@@ -36,7 +36,7 @@ impl av::PipControllerContentSrc {
     pub fn active_video_call_content_vc(&self) -> arc::R<av::PipViewCallController>;
 }
 
-#[link(name = "av_kit", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av_kit", kind = "static"))]
 #[api::weak]
 unsafe extern "C" {
     #[api::available(ios = 15.0, visionos = 1.0)]
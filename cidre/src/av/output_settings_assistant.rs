@@ -146,7 +146,7 @@ impl OutputSettingsAssistant {
     pub fn set_src_video_average_frame_duration(&mut self, val: cm::Time);
 }
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_OUTPUT_SETTINGS_ASSISTANT: &'static objc::Class<OutputSettingsAssistant>;
 }
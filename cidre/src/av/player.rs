@@ -96,7 +96,7 @@ define_obj_type!(
     pub QueuePlayer(Player)
 );
 
-#[link(name = "av", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "av", kind = "static"))]
 unsafe extern "C" {
     static AV_PLAYER: &'static objc::Class<Player>;
 }
@@ -45,7 +45,7 @@ impl DisplayLayer {
     pub fn sample_buf_renderer(&self) -> arc::R<av::SampleBufVideoRenderer>;
 }
 
-#[link(name = "ca", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ca", kind = "static"))]
 unsafe extern "C" {
     static AV_DISPLAY_LAYER: &'static objc::Class<DisplayLayer>;
 }
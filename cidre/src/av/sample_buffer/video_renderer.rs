@@ -142,7 +142,7 @@ impl VideoRenderer {
     }
 }
 
-#[link(name = "ca", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ca", kind = "static"))]
 unsafe extern "C" {
     static AV_SAMPLE_BUFFER_VIDEO_RENDERER: &'static objc::Class<VideoRenderer>;
 }
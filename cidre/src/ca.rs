@@ -12,7 +12,12 @@ pub use base::current_media_time;
 mod animation;
 pub use animation::Animation;
 
+mod spring_animation;
+pub use spring_animation::Spring;
+pub use spring_animation::SpringAnimation;
+
 mod media_timing_function;
+pub use media_timing_function::Curve as MediaTimingCurve;
 pub use media_timing_function::MediaTimingFn;
 pub use media_timing_function::Name as MediaTimingFnName;
 
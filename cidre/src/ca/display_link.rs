@@ -61,7 +61,7 @@ impl DisplayLink {
     pub fn set_preferred_frame_rate_range(&mut self, val: ca::FrameRateRange);
 }
 
-#[link(name = "ca", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ca", kind = "static"))]
 unsafe extern "C" {
     static CA_DISPLAY_LINK: &'static objc::Class<ns::Id>;
 }
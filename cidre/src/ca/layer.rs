@@ -262,7 +262,7 @@ impl Layer {
     pub fn set_wants_extended_dynamic_range_content(&mut self, val: bool);
 }
 
-#[link(name = "ca", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ca", kind = "static"))]
 unsafe extern "C" {
     static CA_LAYER: &'static objc::Class<Layer>;
 }
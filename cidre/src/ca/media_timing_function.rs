@@ -45,6 +45,136 @@ impl MediaTimingFn {
     pub fn with_ctrl_points(c1x: f32, c1y: f32, c2x: f32, c2y: f32) -> arc::R<Self> {
         Self::alloc().init_with_ctrl_points(c1x, c1y, c2x, c2y)
     }
+
+    #[objc::msg_send(getControlPointAtIndex:values:)]
+    pub unsafe fn get_ctrl_point_at(&self, index: usize, values: *mut f32);
+
+    /// Control point at `index` in `0..=3`, the first is (0, 0) and the last is (1, 1).
+    pub fn ctrl_point_at(&self, index: usize) -> [f32; 2] {
+        assert!(index < 4);
+        let mut res = [0.0; 2];
+        unsafe { self.get_ctrl_point_at(index, res.as_mut_ptr()) };
+        res
+    }
+
+    /// The curve to evaluate in Rust.
+    pub fn curve(&self) -> Curve {
+        let [c1x, c1y] = self.ctrl_point_at(1);
+        let [c2x, c2y] = self.ctrl_point_at(2);
+        Curve::new(c1x, c1y, c2x, c2y)
+    }
+}
+
+/// Cubic Bézier from (0, 0) to (1, 1) with `MediaTimingFn` control points.
+///
+/// ```
+/// use cidre::ca;
+///
+/// let curve = ca::MediaTimingCurve::EASE_IN_OUT;
+/// let eps = ca::MediaTimingCurve::epsilon_for_duration(0.25);
+/// assert_eq!(curve.solve(0.0, eps), 0.0);
+/// assert!((curve.solve(0.5, eps) - 0.5).abs() < eps);
+/// assert!(curve.solve(0.25, eps) < 0.25);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Curve {
+    pub c1x: f32,
+    pub c1y: f32,
+    pub c2x: f32,
+    pub c2y: f32,
+}
+
+impl Curve {
+    #[doc(alias = "kCAMediaTimingFunctionLinear")]
+    pub const LINEAR: Self = Self::new(0.0, 0.0, 1.0, 1.0);
+
+    #[doc(alias = "kCAMediaTimingFunctionEaseIn")]
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+
+    #[doc(alias = "kCAMediaTimingFunctionEaseOut")]
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+
+    #[doc(alias = "kCAMediaTimingFunctionEaseInEaseOut")]
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    #[doc(alias = "kCAMediaTimingFunctionDefault")]
+    pub const DEFAULT: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+
+    #[inline]
+    pub const fn new(c1x: f32, c1y: f32, c2x: f32, c2y: f32) -> Self {
+        Self { c1x, c1y, c2x, c2y }
+    }
+
+    /// Solve precision that is not visible for animation of `duration` seconds.
+    #[inline]
+    pub fn epsilon_for_duration(duration: f64) -> f64 {
+        1.0 / (200.0 * duration)
+    }
+
+    /// Polynomial coefficients (a, b, c) of `((a * t + b) * t + c) * t`.
+    #[inline]
+    fn coefs(p1: f32, p2: f32) -> (f64, f64, f64) {
+        let c = 3.0 * p1 as f64;
+        let b = 3.0 * (p2 as f64 - p1 as f64) - c;
+        (1.0 - c - b, b, c)
+    }
+
+    #[inline]
+    pub fn x_at(&self, t: f64) -> f64 {
+        let (a, b, c) = Self::coefs(self.c1x, self.c2x);
+        ((a * t + b) * t + c) * t
+    }
+
+    #[inline]
+    pub fn y_at(&self, t: f64) -> f64 {
+        let (a, b, c) = Self::coefs(self.c1y, self.c2y);
+        ((a * t + b) * t + c) * t
+    }
+
+    #[inline]
+    fn dx_at(&self, t: f64) -> f64 {
+        let (a, b, c) = Self::coefs(self.c1x, self.c2x);
+        (3.0 * a * t + 2.0 * b) * t + c
+    }
+
+    /// Curve parameter for `x` clamped to `0..=1`, Newton's method with bisection fallback.
+    pub fn solve_t(&self, x: f64, epsilon: f64) -> f64 {
+        let x = x.clamp(0.0, 1.0);
+        let mut t = x;
+        for _ in 0..8 {
+            let dx = self.x_at(t) - x;
+            if dx.abs() < epsilon {
+                return t;
+            }
+            let d = self.dx_at(t);
+            if d.abs() < 1e-6 {
+                break;
+            }
+            t -= dx / d;
+        }
+
+        let (mut lo, mut hi) = (0.0, 1.0);
+        t = x;
+        // float bisection converges well before the limit
+        for _ in 0..64 {
+            let v = self.x_at(t);
+            if (v - x).abs() < epsilon {
+                break;
+            }
+            if x > v {
+                lo = t;
+            } else {
+                hi = t;
+            }
+            t = (hi - lo) * 0.5 + lo;
+        }
+        t
+    }
+
+    /// Progress `y` at time fraction `x`.
+    pub fn solve(&self, x: f64, epsilon: f64) -> f64 {
+        self.y_at(self.solve_t(x, epsilon))
+    }
 }
 
 #[link(name = "QuartzCore", kind = "framework")]
@@ -56,7 +186,7 @@ unsafe extern "C" {
     static kCAMediaTimingFunctionDefault: &'static Name;
 }
 
-#[link(name = "ca", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ca", kind = "static"))]
 unsafe extern "C" {
     static CA_MEDIA_TIMING_FUNCTION: &'static objc::Class<MediaTimingFn>;
 }
@@ -71,5 +201,56 @@ mod tests {
         println!("{tfn:?}");
         let tfn = ca::MediaTimingFn::with_ctrl_points(0.0, 0.5, 0.3, 0.6);
         println!("{tfn:?}");
+        assert_eq!(tfn.curve(), ca::MediaTimingCurve::new(0.0, 0.5, 0.3, 0.6));
+        assert_eq!(tfn.ctrl_point_at(3), [1.0, 1.0]);
+    }
+
+    #[test]
+    fn named() {
+        use ca::{MediaTimingCurve as Curve, MediaTimingFnName as Name};
+
+        let named = [
+            (Name::linear(), Curve::LINEAR),
+            (Name::ease_in(), Curve::EASE_IN),
+            (Name::ease_out(), Curve::EASE_OUT),
+            (Name::ease_in_out(), Curve::EASE_IN_OUT),
+            (Name::default(), Curve::DEFAULT),
+        ];
+        for (name, curve) in named {
+            assert_eq!(ca::MediaTimingFn::with_name(name).curve(), curve);
+        }
+    }
+
+    #[test]
+    fn solve() {
+        use ca::MediaTimingCurve as Curve;
+
+        let eps = 1e-7;
+        for x in [0.0, 0.1, 0.33, 0.5, 0.9, 1.0] {
+            assert!((Curve::LINEAR.solve(x, eps) - x).abs() < 1e-6);
+            for curve in [
+                Curve::EASE_IN,
+                Curve::EASE_OUT,
+                Curve::EASE_IN_OUT,
+                Curve::DEFAULT,
+            ] {
+                let t = curve.solve_t(x, eps);
+                assert!((curve.x_at(t) - x).abs() < eps, "{curve:?} {x}");
+            }
+        }
+
+        // symmetric curves
+        let y = Curve::EASE_IN.solve(0.3, eps);
+        assert!((Curve::EASE_OUT.solve(0.7, eps) - (1.0 - y)).abs() < 1e-6);
+        assert!((Curve::EASE_IN_OUT.solve(0.5, eps) - 0.5).abs() < 1e-6);
+
+        // out of range is clamped
+        assert_eq!(Curve::DEFAULT.solve(-1.0, eps), 0.0);
+        assert_eq!(Curve::DEFAULT.solve(2.0, eps), 1.0);
+
+        // flat in the middle, bisection takes over Newton's method
+        let flat = Curve::new(1.0, 0.0, 0.0, 1.0);
+        let t = flat.solve_t(0.5001, eps);
+        assert!((flat.x_at(t) - 0.5001).abs() < eps);
     }
 }
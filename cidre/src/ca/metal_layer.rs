@@ -89,7 +89,7 @@ impl MetalLayer {
     pub fn set_wants_extended_dynamic_range_content(&mut self, val: bool);
 }
 
-#[link(name = "ca", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ca", kind = "static"))]
 unsafe extern "C" {
     static CA_METAL_LAYER: &'static objc::Class<MetalLayer>;
 }
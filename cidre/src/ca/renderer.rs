@@ -101,7 +101,7 @@ impl Renderer {
     pub fn set_dst(&mut self, val: &mtl::Texture);
 }
 
-#[link(name = "ca", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ca", kind = "static"))]
 unsafe extern "C" {
     static CA_RENDERER: &'static objc::Class<Renderer>;
 }
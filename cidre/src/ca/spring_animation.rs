@@ -0,0 +1,322 @@
+use crate::{arc, ca, cf, cg, define_obj_type, objc};
+
+define_obj_type!(
+    #[doc(alias = "CASpringAnimation")]
+    pub SpringAnimation(ca::Animation),
+    CA_SPRING_ANIMATION
+);
+
+impl SpringAnimation {
+    #[objc::msg_send(mass)]
+    pub fn mass(&self) -> cg::Float;
+
+    #[objc::msg_send(setMass:)]
+    pub fn set_mass(&mut self, val: cg::Float);
+
+    #[objc::msg_send(stiffness)]
+    pub fn stiffness(&self) -> cg::Float;
+
+    #[objc::msg_send(setStiffness:)]
+    pub fn set_stiffness(&mut self, val: cg::Float);
+
+    #[objc::msg_send(damping)]
+    pub fn damping(&self) -> cg::Float;
+
+    #[objc::msg_send(setDamping:)]
+    pub fn set_damping(&mut self, val: cg::Float);
+
+    #[objc::msg_send(initialVelocity)]
+    pub fn initial_velocity(&self) -> cg::Float;
+
+    #[objc::msg_send(setInitialVelocity:)]
+    pub fn set_initial_velocity(&mut self, val: cg::Float);
+
+    #[objc::msg_send(settlingDuration)]
+    pub fn settling_duration(&self) -> cf::TimeInterval;
+
+    /// The spring to evaluate in Rust.
+    pub fn spring(&self) -> Spring {
+        Spring::new(
+            self.mass(),
+            self.stiffness(),
+            self.damping(),
+            self.initial_velocity(),
+        )
+    }
+}
+
+/// Damped spring moving from 0 to 1 with `SpringAnimation` parameters.
+///
+/// ```
+/// use cidre::ca;
+///
+/// let spring = ca::Spring::default();
+/// assert_eq!(spring.value_at(0.0), 0.0);
+/// // underdamped, overshoots
+/// assert!(spring.value_at(0.3) > 1.0);
+/// assert!((spring.value_at(spring.settling_duration()) - 1.0).abs() <= 0.001);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Spring {
+    pub mass: cg::Float,
+    pub stiffness: cg::Float,
+    pub damping: cg::Float,
+    /// Fraction of the distance per second, positive towards 1.
+    pub initial_velocity: cg::Float,
+}
+
+impl Default for Spring {
+    /// `SpringAnimation` defaults.
+    fn default() -> Self {
+        Self::new(1.0, 100.0, 10.0, 0.0)
+    }
+}
+
+/// Displacement from 1 at which spring is at rest.
+const SETTLED: cg::Float = 0.001;
+
+/// Time step of settling duration search.
+const SETTLING_STEP: cg::Float = 0.001;
+
+impl Spring {
+    #[inline]
+    pub const fn new(
+        mass: cg::Float,
+        stiffness: cg::Float,
+        damping: cg::Float,
+        initial_velocity: cg::Float,
+    ) -> Self {
+        Self {
+            mass,
+            stiffness,
+            damping,
+            initial_velocity,
+        }
+    }
+
+    /// Undamped angular frequency.
+    #[inline]
+    pub fn natural_frequency(&self) -> cg::Float {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    /// Below 1 spring oscillates, 1 is critically damped.
+    #[inline]
+    pub fn damping_ratio(&self) -> cg::Float {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Progress at `t` seconds.
+    #[inline]
+    pub fn value_at(&self, t: cg::Float) -> cg::Float {
+        1.0 - self.motion().at(t).0
+    }
+
+    /// Progress per second at `t` seconds.
+    #[inline]
+    pub fn velocity_at(&self, t: cg::Float) -> cg::Float {
+        -self.motion().at(t).1
+    }
+
+    /// Time after which value stays within 0.1% of the distance from 1,
+    /// infinite for spring without damping.
+    pub fn settling_duration(&self) -> cf::TimeInterval {
+        let motion = self.motion();
+        let time = |k: u64| k as cg::Float * SETTLING_STEP;
+        let settled = |k: u64| motion.envelope(time(k)) < SETTLED;
+
+        // envelope starts at 1 or above, so step 0 is never settled
+        let mut hi = 1u64;
+        while !settled(hi) {
+            let Some(next) = hi.checked_mul(2) else {
+                return cf::TimeInterval::INFINITY;
+            };
+            hi = next;
+        }
+        let mut lo = hi / 2;
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if settled(mid) {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+
+        // envelope only bounds displacement, the last step out of range
+        // is at most about half of oscillation period earlier
+        let mut k = hi;
+        while k > 0 && motion.at(time(k - 1)).0.abs() < SETTLED {
+            k -= 1;
+        }
+        time(k) as cf::TimeInterval
+    }
+
+    fn motion(&self) -> Motion {
+        let w0 = self.natural_frequency();
+        let zeta = self.damping_ratio();
+        let v0 = self.initial_velocity;
+        if zeta < 1.0 {
+            let wd = w0 * (1.0 - zeta * zeta).sqrt();
+            Motion::Under {
+                decay: zeta * w0,
+                wd,
+                b: (zeta * w0 - v0) / wd,
+            }
+        } else if zeta == 1.0 {
+            Motion::Critical { w0, b: w0 - v0 }
+        } else {
+            let s = (zeta * zeta - 1.0).sqrt();
+            let (r1, r2) = (-w0 * (zeta - s), -w0 * (zeta + s));
+            let c2 = (-v0 - r1) / (r2 - r1);
+            Motion::Over {
+                r1,
+                r2,
+                c1: 1.0 - c2,
+                c2,
+            }
+        }
+    }
+}
+
+/// Solution of `m x'' + c x' + k x = 0` with `x(0) = 1`, `x'(0) = -v0`.
+enum Motion {
+    Under {
+        decay: cg::Float,
+        wd: cg::Float,
+        b: cg::Float,
+    },
+    Critical {
+        w0: cg::Float,
+        b: cg::Float,
+    },
+    Over {
+        r1: cg::Float,
+        r2: cg::Float,
+        c1: cg::Float,
+        c2: cg::Float,
+    },
+}
+
+impl Motion {
+    /// Displacement and its derivative.
+    fn at(&self, t: cg::Float) -> (cg::Float, cg::Float) {
+        match *self {
+            Self::Under { decay, wd, b } => {
+                let e = (-decay * t).exp();
+                let (sin, cos) = (wd * t).sin_cos();
+                let x = cos + b * sin;
+                (e * x, e * (-decay * x + wd * (b * cos - sin)))
+            }
+            Self::Critical { w0, b } => {
+                let e = (-w0 * t).exp();
+                let x = 1.0 + b * t;
+                (e * x, e * (b - w0 * x))
+            }
+            Self::Over { r1, r2, c1, c2 } => {
+                let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+                (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+            }
+        }
+    }
+
+    /// Bound of displacement, decreasing once it is below 1.
+    fn envelope(&self, t: cg::Float) -> cg::Float {
+        match *self {
+            Self::Under { decay, b, .. } => (1.0 + b * b).sqrt() * (-decay * t).exp(),
+            Self::Critical { w0, b } => (1.0 + b.abs() * t) * (-w0 * t).exp(),
+            Self::Over { r1, r2, c1, c2 } => c1.abs() * (r1 * t).exp() + c2.abs() * (r2 * t).exp(),
+        }
+    }
+}
+
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ca", kind = "static"))]
+unsafe extern "C" {
+    static CA_SPRING_ANIMATION: &'static objc::Class<SpringAnimation>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ca;
+
+    #[test]
+    fn basics() {
+        let mut anim = ca::SpringAnimation::new();
+        assert_eq!(anim.spring(), ca::Spring::default());
+        anim.set_damping(20.0);
+        anim.set_initial_velocity(2.0);
+        let spring = anim.spring();
+        assert_eq!(spring.damping_ratio(), 1.0);
+        assert_eq!(spring.initial_velocity, 2.0);
+
+        // estimation details are private, stay close
+        for damping in [5.0, 10.0, 20.0, 40.0] {
+            anim.set_damping(damping);
+            let ca = anim.settling_duration();
+            let rs = anim.spring().settling_duration();
+            assert!((ca - rs).abs() < 0.01 * ca, "{damping}: {ca} vs {rs}");
+        }
+    }
+
+    #[test]
+    fn spring() {
+        let eps = 1e-9;
+        let springs = [
+            ca::Spring::default(),
+            ca::Spring::new(1.0, 100.0, 20.0, 0.0),
+            ca::Spring::new(2.0, 50.0, 40.0, -1.0),
+            ca::Spring::new(0.5, 300.0, 8.0, 5.0),
+        ];
+        for s in springs {
+            assert!(s.value_at(0.0).abs() < eps);
+            assert!((s.velocity_at(0.0) - s.initial_velocity).abs() < eps);
+
+            // satisfies m x'' + c x' + k x = 0
+            let h = 1e-4;
+            for t in [0.05, 0.2, 0.7] {
+                let x = 1.0 - s.value_at(t);
+                let v = -s.velocity_at(t);
+                let a = -(s.velocity_at(t + h) - s.velocity_at(t - h)) / (2.0 * h);
+                let r = s.mass * a + s.damping * v + s.stiffness * x;
+                assert!(r.abs() < 1e-3, "{s:?} at {t}: {r}");
+                let dv = (s.value_at(t + h) - s.value_at(t - h)) / (2.0 * h);
+                assert!((dv - s.velocity_at(t)).abs() < 1e-3);
+            }
+
+            let d = s.settling_duration();
+            assert!(d > 0.0);
+            for i in 0..100 {
+                let t = d + i as f64 * 0.01;
+                assert!((s.value_at(t) - 1.0).abs() < 0.001, "{s:?} at {t}");
+            }
+            assert!((s.value_at(d - 0.001) - 1.0).abs() >= 0.001);
+        }
+
+        // whole number of search steps
+        assert_eq!(ca::Spring::default().settling_duration(), 1271.0 * 0.001);
+
+        // settles after ~1.4e6 s, found without walking there in steps
+        let light = ca::Spring::new(1.0, 100.0, 1e-5, 0.0);
+        let d = light.settling_duration();
+        assert!(d > 1e6 && d < 2e6, "{d}");
+        assert!((light.value_at(d - 0.001) - 1.0).abs() >= 0.001);
+        for i in 0..1000 {
+            let t = d + i as f64 * 0.001;
+            assert!((light.value_at(t) - 1.0).abs() < 0.001, "at {t}");
+        }
+
+        let critical = ca::Spring::new(1.0, 100.0, 20.0, 0.0);
+        assert_eq!(critical.damping_ratio(), 1.0);
+        assert!((0..200).all(|i| critical.value_at(i as f64 * 0.01) <= 1.0));
+
+        // faster with more stiffness
+        let stiff = ca::Spring::new(1.0, 400.0, 20.0, 0.0);
+        assert!(stiff.settling_duration() < ca::Spring::default().settling_duration());
+
+        assert!(
+            ca::Spring::new(1.0, 100.0, 0.0, 0.0)
+                .settling_duration()
+                .is_infinite()
+        );
+    }
+}
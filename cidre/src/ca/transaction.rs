@@ -45,7 +45,7 @@ impl Transaction {
     }
 }
 
-#[link(name = "ca", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ca", kind = "static"))]
 unsafe extern "C" {
     static CA_TRANSACTION: &'static objc::Class<Transaction>;
 }
@@ -1,4 +1,8 @@
+use std::ops::{Mul, MulAssign};
+
 use crate::cg;
+#[cfg(feature = "simd")]
+use crate::simd;
 
 /// ```
 /// use cidre::ca;
@@ -29,76 +33,275 @@ pub struct Transform3d {
 }
 
 impl Transform3d {
+    #[doc(alias = "CATransform3DIdentity")]
+    pub const IDENTITY: Self = Self::new_scale(1.0, 1.0, 1.0);
+
     /// The identity transform: [1 0 0 0; 0 1 0 0; 0 0 1 0; 0 0 0 1].
     #[inline]
-    pub fn identity() -> Self {
-        unsafe { CATransform3DIdentity }
+    pub const fn identity() -> Self {
+        Self::IDENTITY
     }
 
     /// Returns a transform that translates by '(tx, ty, tz)':
     /// self =  [1 0 0 0; 0 1 0 0; 0 0 1 0; tx ty tz 1].
+    #[doc(alias = "CATransform3DMakeTranslation")]
     #[inline]
-    pub fn new_translation(tx: f64, ty: f64, tz: f64) -> Self {
-        unsafe { CATransform3DMakeTranslation(tx, ty, tz) }
+    pub const fn new_translation(tx: f64, ty: f64, tz: f64) -> Self {
+        let mut res = Self::IDENTITY;
+        res.m41 = tx;
+        res.m42 = ty;
+        res.m43 = tz;
+        res
     }
 
     /// Returns a transform that scales by `(sx, sy, sz)':
     /// self = [sx 0 0 0; 0 sy 0 0; 0 0 sz 0; 0 0 0 1].
+    #[doc(alias = "CATransform3DMakeScale")]
     #[inline]
-    pub fn new_scale(sx: f64, sy: f64, sz: f64) -> Self {
-        unsafe { CATransform3DMakeScale(sx, sy, sz) }
+    pub const fn new_scale(sx: f64, sy: f64, sz: f64) -> Self {
+        Self {
+            m11: sx,
+            m12: 0.0,
+            m13: 0.0,
+            m14: 0.0,
+            m21: 0.0,
+            m22: sy,
+            m23: 0.0,
+            m24: 0.0,
+            m31: 0.0,
+            m32: 0.0,
+            m33: sz,
+            m34: 0.0,
+            m41: 0.0,
+            m42: 0.0,
+            m43: 0.0,
+            m44: 1.0,
+        }
     }
 
     /// Returns a transform that rotates by 'angle' radians about the vector
     /// '(x, y, z)'. If the vector has length zero the identity transform is
     /// returned.
-    #[inline]
+    #[doc(alias = "CATransform3DMakeRotation")]
     pub fn new_rotation(angle: f64, x: f64, y: f64, z: f64) -> Self {
-        unsafe { CATransform3DMakeRotation(angle, x, y, z) }
+        let len = (x * x + y * y + z * z).sqrt();
+        if len == 0.0 {
+            return Self::IDENTITY;
+        }
+        let (x, y, z) = (x / len, y / len, z / len);
+        let (s, c) = angle.sin_cos();
+        let t = 1.0 - c;
+        let mut res = Self::IDENTITY;
+        res.m11 = t * x * x + c;
+        res.m12 = t * x * y + s * z;
+        res.m13 = t * x * z - s * y;
+        res.m21 = t * x * y - s * z;
+        res.m22 = t * y * y + c;
+        res.m23 = t * y * z + s * x;
+        res.m31 = t * x * z + s * y;
+        res.m32 = t * y * z - s * x;
+        res.m33 = t * z * z + c;
+        res
     }
 
+    /// [a b 0 0; c d 0 0; 0 0 1 0; tx ty 0 1]
+    #[doc(alias = "CATransform3DMakeAffineTransform")]
     #[inline]
-    pub fn from_cg_affine_transform(m: cg::AffineTransform) -> Self {
-        unsafe { CATransform3DMakeAffineTransform(m) }
+    pub const fn from_cg_affine_transform(m: cg::AffineTransform) -> Self {
+        let mut res = Self::IDENTITY;
+        res.m11 = m.a;
+        res.m12 = m.b;
+        res.m21 = m.c;
+        res.m22 = m.d;
+        res.m41 = m.tx;
+        res.m42 = m.ty;
+        res
     }
 
     /// Returns true if 'self' is the identity transform.
+    #[doc(alias = "CATransform3DIsIdentity")]
     #[inline]
-    pub fn is_identity(&self) -> bool {
-        unsafe { CATransform3DIsIdentity(*self) }
+    pub const fn is_identity(&self) -> bool {
+        self.equal_to(&Self::IDENTITY)
     }
 
+    #[doc(alias = "CATransform3DEqualToTransform")]
+    pub const fn equal_to(&self, other: &Self) -> bool {
+        let (a, b) = (self.rows(), other.rows());
+        let mut i = 0;
+        while i < 16 {
+            if a[i / 4][i % 4] != b[i / 4][i % 4] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Translation applied before `self`: [1 0 0 0; 0 1 0 0; 0 0 1 0; tx ty tz 1] * self.
+    #[doc(alias = "CATransform3DTranslate")]
     #[inline]
-    pub fn translate(&self, tx: f64, ty: f64, tz: f64) -> Self {
-        unsafe { CATransform3DTranslate(*self, tx, ty, tz) }
+    pub const fn translate(&self, tx: f64, ty: f64, tz: f64) -> Self {
+        Self::new_translation(tx, ty, tz).concat(self)
     }
 
+    /// Scale applied before `self`: [sx 0 0 0; 0 sy 0 0; 0 0 sz 0; 0 0 0 1] * self.
+    #[doc(alias = "CATransform3DScale")]
     #[inline]
-    pub fn scale(&self, sx: f64, sy: f64, sz: f64) -> Self {
-        unsafe { CATransform3DScale(*self, sx, sy, sz) }
+    pub const fn scale(&self, sx: f64, sy: f64, sz: f64) -> Self {
+        Self::new_scale(sx, sy, sz).concat(self)
     }
 
+    /// Rotation applied before `self`.
+    #[doc(alias = "CATransform3DRotate")]
     #[inline]
     pub fn rotate(&self, angle: f64, x: f64, y: f64, z: f64) -> Self {
-        unsafe { CATransform3DRotate(*self, angle, x, y, z) }
+        Self::new_rotation(angle, x, y, z).concat(self)
+    }
+
+    /// `self * other`, `self` followed by `other`.
+    #[doc(alias = "CATransform3DConcat")]
+    pub const fn concat(&self, other: &Transform3d) -> Self {
+        let (a, b) = (self.rows(), other.rows());
+        let mut res = [[0.0; 4]; 4];
+        let mut i = 0;
+        while i < 16 {
+            let (r, c) = (i / 4, i % 4);
+            res[r][c] =
+                a[r][0] * b[0][c] + a[r][1] * b[1][c] + a[r][2] * b[2][c] + a[r][3] * b[3][c];
+            i += 1;
+        }
+        Self::with_rows(res)
+    }
+
+    /// Returns true if `self` can be exactly represented by an affine transform.
+    #[doc(alias = "CATransform3DIsAffine")]
+    #[inline]
+    pub const fn is_affine(&self) -> bool {
+        self.m13 == 0.0
+            && self.m14 == 0.0
+            && self.m23 == 0.0
+            && self.m24 == 0.0
+            && self.m31 == 0.0
+            && self.m32 == 0.0
+            && self.m33 == 1.0
+            && self.m34 == 0.0
+            && self.m43 == 0.0
+            && self.m44 == 1.0
+    }
+
+    /// The affine part of `self`, meaningful only if [`Self::is_affine`].
+    #[doc(alias = "CATransform3DGetAffineTransform")]
+    #[inline]
+    pub const fn to_affine_transform(&self) -> cg::AffineTransform {
+        cg::AffineTransform::new(self.m11, self.m12, self.m21, self.m22, self.m41, self.m42)
+    }
+
+    #[inline]
+    const fn rows(&self) -> [[f64; 4]; 4] {
+        [
+            [self.m11, self.m12, self.m13, self.m14],
+            [self.m21, self.m22, self.m23, self.m24],
+            [self.m31, self.m32, self.m33, self.m34],
+            [self.m41, self.m42, self.m43, self.m44],
+        ]
+    }
+
+    #[inline]
+    const fn with_rows(m: [[f64; 4]; 4]) -> Self {
+        let [
+            [m11, m12, m13, m14],
+            [m21, m22, m23, m24],
+            [m31, m32, m33, m34],
+            [m41, m42, m43, m44],
+        ] = m;
+        Self {
+            m11,
+            m12,
+            m13,
+            m14,
+            m21,
+            m22,
+            m23,
+            m24,
+            m31,
+            m32,
+            m33,
+            m34,
+            m41,
+            m42,
+            m43,
+            m44,
+        }
+    }
+}
+
+impl Default for Transform3d {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// `self` followed by `rhs`, same as [`Transform3d::concat`].
+impl Mul for Transform3d {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.concat(&rhs)
+    }
+}
+
+impl MulAssign for Transform3d {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.concat(&rhs)
+    }
+}
+
+/// Rows of `Transform3d` (row vectors on the left) are columns of simd
+/// matrix (column vectors on the right), memory layout is the same.
+#[cfg(feature = "simd")]
+impl From<Transform3d> for simd::f64x4x4 {
+    #[inline]
+    fn from(t: Transform3d) -> Self {
+        Self(
+            t.rows()
+                .map(|[x, y, z, w]| simd::f64x4::with_xyzw(x, y, z, w)),
+        )
     }
+}
 
+#[cfg(feature = "simd")]
+impl From<simd::f64x4x4> for Transform3d {
     #[inline]
-    pub fn concat(&self, other: &Transform3d) -> Self {
-        unsafe { CATransform3DConcat(*self, *other) }
+    fn from(m: simd::f64x4x4) -> Self {
+        Self::with_rows(m.0.map(|c| [c.x(), c.y(), c.z(), c.w()]))
     }
+}
 
+#[cfg(feature = "simd")]
+impl From<Transform3d> for simd::f32x4x4 {
     #[inline]
-    pub fn is_affine(&self) -> bool {
-        unsafe { CATransform3DIsAffine(*self) }
+    fn from(t: Transform3d) -> Self {
+        Self(
+            t.rows()
+                .map(|[x, y, z, w]| simd::f32x4::with_xyzw(x as f32, y as f32, z as f32, w as f32)),
+        )
     }
+}
 
+#[cfg(feature = "simd")]
+impl From<simd::f32x4x4> for Transform3d {
     #[inline]
-    pub fn to_affine_transform(&self) -> cg::AffineTransform {
-        unsafe { CATransform3DGetAffineTransform(*self) }
+    fn from(m: simd::f32x4x4) -> Self {
+        Self::with_rows(m.0.map(|c| [c.x(), c.y(), c.z(), c.w()].map(f64::from)))
     }
 }
 
+// QuartzCore implementations, to check that results match
+#[cfg(all(test, target_vendor = "apple"))]
 #[link(name = "QuartzCore", kind = "framework")]
 unsafe extern "C-unwind" {
     static CATransform3DIdentity: Transform3d;
@@ -114,3 +317,121 @@ unsafe extern "C-unwind" {
     fn CATransform3DIsAffine(t: Transform3d) -> bool;
     fn CATransform3DGetAffineTransform(t: Transform3d) -> cg::AffineTransform;
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::{ca::Transform3d, cg};
+
+    #[test]
+    fn basics() {
+        let t = Transform3d::new_scale(2.0, 3.0, 4.0).translate(1.0, 1.0, 1.0);
+        assert_eq!((t.m41, t.m42, t.m43), (2.0, 3.0, 4.0));
+        assert_eq!(
+            t,
+            Transform3d::new_translation(1.0, 1.0, 1.0) * Transform3d::new_scale(2.0, 3.0, 4.0)
+        );
+        assert!((t * Transform3d::IDENTITY).equal_to(&t));
+        assert!(Transform3d::new_rotation(1.0, 0.0, 0.0, 0.0).is_identity());
+
+        // about z the same as affine rotation
+        let r = Transform3d::new_rotation(FRAC_PI_2, 0.0, 0.0, 2.0);
+        assert!(r.is_affine());
+        assert_eq!(
+            r.to_affine_transform(),
+            cg::AffineTransform::new_rotation(FRAC_PI_2)
+        );
+        assert!(!Transform3d::new_rotation(FRAC_PI_2, 1.0, 0.0, 0.0).is_affine());
+
+        let a = cg::AffineTransform::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        let t = Transform3d::from_cg_affine_transform(a);
+        assert!(t.is_affine());
+        assert_eq!(t.to_affine_transform(), a);
+        let b = cg::AffineTransform::new_scale(2.0, 0.5).translate(1.0, -1.0);
+        assert_eq!(
+            (t * Transform3d::from_cg_affine_transform(b)).to_affine_transform(),
+            a * b
+        );
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd() {
+        use crate::simd;
+
+        let t = Transform3d::new_translation(1.0, 2.0, 3.0).scale(2.0, 2.0, 2.0);
+        let m = simd::f64x4x4::from(t);
+        assert_eq!(
+            m,
+            simd::f64x4x4::translate(1.0, 2.0, 3.0) * simd::f64x4x4::scale(2.0, 2.0, 2.0)
+        );
+        assert_eq!(Transform3d::from(m), t);
+        let p = m * simd::f64x4::with_xyzw(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(p, simd::f64x4::with_xyzw(3.0, 4.0, 5.0, 1.0));
+
+        let m = simd::f32x4x4::from(t);
+        assert_eq!(
+            m,
+            simd::f32x4x4::translate(1.0, 2.0, 3.0) * simd::f32x4x4::scale(2.0, 2.0, 2.0)
+        );
+        assert_eq!(Transform3d::from(m), t);
+
+        assert_eq!(
+            unsafe { std::mem::transmute::<Transform3d, simd::f64x4x4>(t) },
+            simd::f64x4x4::from(t)
+        );
+    }
+
+    #[cfg(target_vendor = "apple")]
+    #[test]
+    fn matches_ca() {
+        use super::*;
+
+        let t = Transform3d::new_rotation(0.3, 1.0, 2.0, -0.5).translate(0.1, 0.2, -0.3);
+        let a = cg::AffineTransform::new(1.5, 0.1, -0.2, 0.9, 3.3, -1.7);
+        let axes = [
+            (1.0, 0.0, 0.0),
+            (0.0, 0.0, 1.0),
+            (1.0, 2.0, 3.0),
+            (0.0, 0.0, 0.0),
+        ];
+        unsafe {
+            assert_eq!(CATransform3DIdentity, Transform3d::identity());
+            assert!(CATransform3DIsIdentity(Transform3d::identity()));
+            assert_eq!(
+                CATransform3DMakeTranslation(0.1, -0.2, 3.7),
+                Transform3d::new_translation(0.1, -0.2, 3.7)
+            );
+            assert_eq!(
+                CATransform3DMakeScale(0.1, -0.2, 3.7),
+                Transform3d::new_scale(0.1, -0.2, 3.7)
+            );
+            for (x, y, z) in axes {
+                for angle in [0.0, 0.7, FRAC_PI_2, -2.5] {
+                    assert_eq!(
+                        CATransform3DMakeRotation(angle, x, y, z),
+                        Transform3d::new_rotation(angle, x, y, z)
+                    );
+                    assert_eq!(
+                        CATransform3DRotate(t, angle, x, y, z),
+                        t.rotate(angle, x, y, z)
+                    );
+                }
+            }
+            assert_eq!(
+                CATransform3DTranslate(t, 1.1, 2.2, 3.3),
+                t.translate(1.1, 2.2, 3.3)
+            );
+            assert_eq!(CATransform3DScale(t, 1.1, 2.2, 3.3), t.scale(1.1, 2.2, 3.3));
+            let u = t.scale(0.3, 0.2, 0.1);
+            assert_eq!(CATransform3DConcat(t, u), t.concat(&u));
+            assert_eq!(
+                CATransform3DMakeAffineTransform(a),
+                Transform3d::from_cg_affine_transform(a)
+            );
+            assert_eq!(CATransform3DIsAffine(t), t.is_affine());
+            assert_eq!(CATransform3DGetAffineTransform(t), t.to_affine_transform());
+        }
+    }
+}
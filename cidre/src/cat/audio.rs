@@ -4,6 +4,14 @@ pub type UInteger = usize;
 mod base_types;
 pub use base_types::*;
 
+mod channel_layout;
+pub use channel_layout::MixMatrix as ChannelMixMatrix;
+
+mod pcm_converter;
+pub use pcm_converter::Dither;
+pub use pcm_converter::PcmConverter;
+pub use pcm_converter::PcmConverterError;
+
 mod session_types;
 pub use session_types::ErrorCode as SessionErrorCode;
 pub use session_types::SessionId;
@@ -653,12 +653,12 @@ impl StreamBasicDesc {
     }
 
     #[inline]
-    pub fn is_interleaved(&self) -> bool {
+    pub const fn is_interleaved(&self) -> bool {
         (self.format_flags.0 & FormatFlags::IS_NON_INTERLEAVED.0) == 0
     }
 
     #[inline]
-    pub fn interleaved_channels_num(&self) -> u32 {
+    pub const fn interleaved_channels_num(&self) -> u32 {
         if self.is_interleaved() {
             self.channels_per_frame
         } else {
@@ -710,6 +710,569 @@ impl StreamBasicDesc {
             reserved: 0,
         }
     }
+
+    /// Linear PCM description with `valid_bits` of sample data in `total_bits` wide
+    /// channel. Integer formats are signed. Samples are aligned high when
+    /// `valid_bits` is less than `total_bits`.
+    ///
+    /// ```
+    /// use cidre::cat::audio;
+    ///
+    /// let asbd = audio::StreamBasicDesc::lpcm(48_000.0, 2, 24, 32, false, false, false);
+    /// assert_eq!(asbd.bytes_per_frame, 8);
+    /// assert!(asbd.format_flags.contains(audio::FormatFlags::IS_ALIGNED_HIGH));
+    /// assert!(asbd.validate().is_ok());
+    /// ```
+    #[doc(alias = "FillOutASBDForLPCM")]
+    pub const fn lpcm(
+        sample_rate: f64,
+        channels_per_frame: u32,
+        valid_bits: u32,
+        total_bits: u32,
+        is_float: bool,
+        is_big_endian: bool,
+        is_non_interleaved: bool,
+    ) -> Self {
+        let mut flags = if is_float {
+            FormatFlags::IS_FLOAT.0
+        } else {
+            FormatFlags::IS_SIGNED_INTEGER.0
+        };
+        if is_big_endian {
+            flags |= FormatFlags::IS_BIG_ENDIAN.0;
+        }
+        if valid_bits == total_bits {
+            flags |= FormatFlags::IS_PACKED.0;
+        } else {
+            flags |= FormatFlags::IS_ALIGNED_HIGH.0;
+        }
+        if is_non_interleaved {
+            flags |= FormatFlags::IS_NON_INTERLEAVED.0;
+        }
+        let channels = if is_non_interleaved {
+            1
+        } else {
+            channels_per_frame
+        };
+        let bytes_per_frame = channels * (total_bits / 8);
+        Self {
+            sample_rate,
+            format: Format::LINEAR_PCM,
+            format_flags: FormatFlags(flags),
+            bytes_per_packet: bytes_per_frame,
+            frames_per_packet: 1,
+            bytes_per_frame,
+            channels_per_frame,
+            bits_per_channel: valid_bits,
+            reserved: 0,
+        }
+    }
+
+    /// Native endian packed signed 16-bit integer.
+    #[inline]
+    pub const fn common_i16(sample_rate: f64, num_channels: u32, interleaved: bool) -> Self {
+        Self::lpcm(
+            sample_rate,
+            num_channels,
+            16,
+            16,
+            false,
+            false,
+            !interleaved,
+        )
+    }
+
+    /// Native endian packed signed 32-bit integer.
+    #[inline]
+    pub const fn common_i32(sample_rate: f64, num_channels: u32, interleaved: bool) -> Self {
+        Self::lpcm(
+            sample_rate,
+            num_channels,
+            32,
+            32,
+            false,
+            false,
+            !interleaved,
+        )
+    }
+
+    /// Native endian packed 64-bit float.
+    #[inline]
+    pub const fn common_f64(sample_rate: f64, num_channels: u32, interleaved: bool) -> Self {
+        Self::lpcm(sample_rate, num_channels, 64, 64, true, false, !interleaved)
+    }
+
+    /// Native endian signed 24-bit integer packed in 3 bytes.
+    #[inline]
+    pub const fn i24_packed(sample_rate: f64, num_channels: u32, interleaved: bool) -> Self {
+        Self::lpcm(
+            sample_rate,
+            num_channels,
+            24,
+            24,
+            false,
+            false,
+            !interleaved,
+        )
+    }
+
+    /// Native endian signed 24-bit integer aligned high in 4 bytes.
+    #[inline]
+    pub const fn i24_aligned_high(sample_rate: f64, num_channels: u32, interleaved: bool) -> Self {
+        Self::lpcm(
+            sample_rate,
+            num_channels,
+            24,
+            32,
+            false,
+            false,
+            !interleaved,
+        )
+    }
+
+    /// Same description with big endian samples if `big_endian` is true, little endian otherwise.
+    #[inline]
+    pub const fn with_big_endian(mut self, big_endian: bool) -> Self {
+        if big_endian {
+            self.format_flags.0 |= FormatFlags::IS_BIG_ENDIAN.0;
+        } else {
+            self.format_flags.0 &= !FormatFlags::IS_BIG_ENDIAN.0;
+        }
+        self
+    }
+
+    /// Same linear PCM description with interleaved or non-interleaved layout.
+    pub const fn with_interleaved(mut self, interleaved: bool) -> Self {
+        if self.is_interleaved() == interleaved || self.channels_per_frame == 0 {
+            return self;
+        }
+        if interleaved {
+            self.format_flags.0 &= !FormatFlags::IS_NON_INTERLEAVED.0;
+            self.bytes_per_frame *= self.channels_per_frame;
+        } else {
+            self.format_flags.0 |= FormatFlags::IS_NON_INTERLEAVED.0;
+            self.bytes_per_frame /= self.channels_per_frame;
+        }
+        self.bytes_per_packet = self.bytes_per_frame * self.frames_per_packet;
+        self
+    }
+
+    const fn compressed(
+        format: Format,
+        format_flags: FormatFlags,
+        sample_rate: f64,
+        channels_per_frame: u32,
+        frames_per_packet: u32,
+    ) -> Self {
+        Self {
+            sample_rate,
+            format,
+            format_flags,
+            bytes_per_packet: 0,
+            frames_per_packet,
+            bytes_per_frame: 0,
+            channels_per_frame,
+            bits_per_channel: 0,
+            reserved: 0,
+        }
+    }
+
+    /// MPEG-4 AAC Low Complexity with 1024 frames per packet.
+    #[inline]
+    pub const fn aac_lc(sample_rate: f64, num_channels: u32) -> Self {
+        Self::compressed(
+            Format::MPEG4_AAC,
+            FormatFlags(0),
+            sample_rate,
+            num_channels,
+            1024,
+        )
+    }
+
+    /// MPEG-4 High Efficiency AAC with 2048 frames per packet at the output `sample_rate`.
+    #[inline]
+    pub const fn he_aac(sample_rate: f64, num_channels: u32) -> Self {
+        Self::compressed(
+            Format::MPEG4_AAC_HE,
+            FormatFlags(0),
+            sample_rate,
+            num_channels,
+            2048,
+        )
+    }
+
+    /// MPEG-4 High Efficiency AAC v2 (parametric stereo) with 2048 frames per packet.
+    #[inline]
+    pub const fn he_aac_v2(sample_rate: f64) -> Self {
+        Self::compressed(
+            Format::MPEG4_AAC_HE_V2,
+            FormatFlags(0),
+            sample_rate,
+            2,
+            2048,
+        )
+    }
+
+    /// Opus with 20 ms packets.
+    #[inline]
+    pub const fn opus(sample_rate: f64, num_channels: u32) -> Self {
+        Self::compressed(
+            Format::OPUS,
+            FormatFlags(0),
+            sample_rate,
+            num_channels,
+            (sample_rate / 50.0) as u32,
+        )
+    }
+
+    /// Lossless flags for 16, 20, 24 and 32 bit source.
+    const fn lossless_flags(source_bits: u32) -> FormatFlags {
+        match source_bits {
+            16 => FormatFlags::APPLE_LOSSLESS_16_BIT_SOURCE_DATA,
+            20 => FormatFlags::APPLE_LOSSLESS_20_BIT_SOURCE_DATA,
+            24 => FormatFlags::APPLE_LOSSLESS_24_BIT_SOURCE_DATA,
+            32 => FormatFlags::APPLE_LOSSLESS_32_BIT_SOURCE_DATA,
+            _ => FormatFlags(0),
+        }
+    }
+
+    /// Bit depth of the source material of Apple Lossless or FLAC stream.
+    pub const fn lossless_source_bits(&self) -> Option<u32> {
+        if self.format.0 != Format::APPLE_LOSSLESS.0 && self.format.0 != Format::FLAC.0 {
+            return None;
+        }
+        match self.format_flags.0 {
+            1 => Some(16),
+            2 => Some(20),
+            3 => Some(24),
+            4 => Some(32),
+            _ => None,
+        }
+    }
+
+    /// Apple Lossless with 4096 frames per packet.
+    #[inline]
+    pub const fn alac(sample_rate: f64, num_channels: u32, source_bits: u32) -> Self {
+        Self::compressed(
+            Format::APPLE_LOSSLESS,
+            Self::lossless_flags(source_bits),
+            sample_rate,
+            num_channels,
+            4096,
+        )
+    }
+
+    /// FLAC with 4096 frames per packet.
+    #[inline]
+    pub const fn flac(sample_rate: f64, num_channels: u32, source_bits: u32) -> Self {
+        Self::compressed(
+            Format::FLAC,
+            Self::lossless_flags(source_bits),
+            sample_rate,
+            num_channels,
+            4096,
+        )
+    }
+
+    #[inline]
+    pub const fn is_lpcm(&self) -> bool {
+        self.format.0 == Format::LINEAR_PCM.0
+    }
+
+    /// Number of fractional bits of fixed point linear PCM.
+    #[inline]
+    pub const fn sample_fraction_bits(&self) -> u32 {
+        (self.format_flags.0 & FormatFlags::LINEAR_PCM_SAMPLE_FRACTION_MASK.0)
+            >> FormatFlags::LINEAR_PCM_SAMPLE_FRACTION_SHIFT.0
+    }
+
+    /// Bytes of a single channel sample of linear PCM.
+    #[inline]
+    pub const fn bytes_per_sample(&self) -> u32 {
+        if self.channels_per_frame == 0 {
+            return 0;
+        }
+        self.bytes_per_frame / self.interleaved_channels_num()
+    }
+
+    /// Checks that fields and flags are consistent.
+    ///
+    /// Zero fields are treated as wildcards only for compressed formats.
+    ///
+    /// ```
+    /// use cidre::cat::audio;
+    ///
+    /// let mut asbd = audio::StreamBasicDesc::common_i16(44_100.0, 2, true);
+    /// assert!(asbd.validate().is_ok());
+    ///
+    /// asbd.bytes_per_frame = 6;
+    /// assert_eq!(
+    ///     asbd.validate(),
+    ///     Err(audio::StreamBasicDescError::BytesPerPacket { bytes_per_packet: 4, expected: 6 })
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), StreamBasicDescError> {
+        use StreamBasicDescError as E;
+
+        if !self.sample_rate.is_finite() || self.sample_rate <= 0.0 {
+            return Err(E::SampleRate);
+        }
+        if self.channels_per_frame == 0 {
+            return Err(E::NoChannels);
+        }
+
+        if !self.is_lpcm() {
+            if self.bytes_per_frame != 0 || self.bits_per_channel != 0 {
+                return Err(E::CompressedFrame);
+            }
+            return Ok(());
+        }
+
+        let flags = self.format_flags;
+        if self.frames_per_packet != 1 {
+            return Err(E::FramesPerPacket(self.frames_per_packet));
+        }
+        if self.bytes_per_packet != self.bytes_per_frame {
+            return Err(E::BytesPerPacket {
+                bytes_per_packet: self.bytes_per_packet,
+                expected: self.bytes_per_frame,
+            });
+        }
+        let channels = self.interleaved_channels_num();
+        if self.bytes_per_frame == 0 || self.bytes_per_frame % channels != 0 {
+            return Err(E::BytesPerFrame {
+                bytes_per_frame: self.bytes_per_frame,
+                channels,
+            });
+        }
+        let sample_size = self.bytes_per_frame / channels;
+        if self.bits_per_channel == 0 || self.bits_per_channel > sample_size * 8 {
+            return Err(E::BitsPerChannel {
+                bits_per_channel: self.bits_per_channel,
+                sample_size,
+            });
+        }
+        let packed = self.bits_per_channel == sample_size * 8;
+        if flags.contains(FormatFlags::IS_FLOAT) {
+            if flags.contains(FormatFlags::IS_SIGNED_INTEGER) || self.sample_fraction_bits() != 0 {
+                return Err(E::Flags(flags));
+            }
+            if !matches!(self.bits_per_channel, 16 | 32 | 64) || !packed {
+                return Err(E::FloatBits(self.bits_per_channel));
+            }
+        }
+        if flags.contains(FormatFlags::IS_PACKED) {
+            if !packed {
+                return Err(E::BitsPerChannel {
+                    bits_per_channel: self.bits_per_channel,
+                    sample_size,
+                });
+            }
+            if flags.contains(FormatFlags::IS_ALIGNED_HIGH) {
+                return Err(E::Flags(flags));
+            }
+        }
+        if self.sample_fraction_bits() >= self.bits_per_channel {
+            return Err(E::Flags(flags));
+        }
+        Ok(())
+    }
+}
+
+/// Inconsistency found by [`StreamBasicDesc::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamBasicDescError {
+    /// Sample rate is not positive and finite.
+    SampleRate,
+
+    /// `channels_per_frame` is zero.
+    NoChannels,
+
+    /// Linear PCM must have one frame per packet.
+    FramesPerPacket(u32),
+
+    /// Linear PCM `bytes_per_packet` doesn't match `bytes_per_frame`.
+    BytesPerPacket {
+        bytes_per_packet: u32,
+        expected: u32,
+    },
+
+    /// `bytes_per_frame` is zero or isn't divisible by interleaved channels.
+    BytesPerFrame { bytes_per_frame: u32, channels: u32 },
+
+    /// `bits_per_channel` is zero, doesn't fit in the sample or
+    /// doesn't fill it while format is flagged as packed.
+    BitsPerChannel {
+        bits_per_channel: u32,
+        sample_size: u32,
+    },
+
+    /// Float samples must be packed 16, 32 or 64 bits.
+    FloatBits(u32),
+
+    /// Mutually exclusive or out of range flags.
+    Flags(FormatFlags),
+
+    /// Compressed format with `bytes_per_frame` or `bits_per_channel` set.
+    CompressedFrame,
+}
+
+impl std::fmt::Display for StreamBasicDescError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SampleRate => write!(f, "invalid sample rate"),
+            Self::NoChannels => write!(f, "no channels"),
+            Self::FramesPerPacket(n) => {
+                write!(f, "linear PCM with {n} frames per packet, expected 1")
+            }
+            Self::BytesPerPacket {
+                bytes_per_packet,
+                expected,
+            } => write!(
+                f,
+                "{bytes_per_packet} bytes per packet, expected {expected}"
+            ),
+            Self::BytesPerFrame {
+                bytes_per_frame,
+                channels,
+            } => write!(
+                f,
+                "{bytes_per_frame} bytes per frame for {channels} interleaved channels"
+            ),
+            Self::BitsPerChannel {
+                bits_per_channel,
+                sample_size,
+            } => write!(
+                f,
+                "{bits_per_channel} bits per channel in {sample_size} byte sample"
+            ),
+            Self::FloatBits(n) => write!(f, "unsupported {n}-bit float"),
+            Self::Flags(flags) => write!(f, "inconsistent format flags 0x{:X}", flags.0),
+            Self::CompressedFrame => write!(f, "compressed format with frame size"),
+        }
+    }
+}
+
+impl std::error::Error for StreamBasicDescError {}
+
+/// Prints description like `CAStreamBasicDescription::Print` and `afinfo`.
+///
+/// ```
+/// use cidre::cat::audio;
+///
+/// let asbd = audio::StreamBasicDesc::common_f32(48_000.0, 2, false);
+/// assert_eq!(asbd.to_string(), " 2 ch,  48000 Hz, Float32, deinterleaved");
+///
+/// let asbd = audio::StreamBasicDesc::aac_lc(44_100.0, 2);
+/// assert_eq!(
+///     asbd.to_string(),
+///     " 2 ch,  44100 Hz, 'aac ' (0x00000000) 0 bits/channel, 0 bytes/packet, 1024 frames/packet, 0 bytes/frame"
+/// );
+/// ```
+impl std::fmt::Display for StreamBasicDesc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:2} ch, {:6.0} Hz, ",
+            self.channels_per_frame, self.sample_rate
+        )?;
+
+        let flags = self.format_flags;
+        if self.is_lpcm() && self.frames_per_packet == 1 {
+            let interleaving = if self.channels_per_frame > 1 {
+                if self.is_interleaved() {
+                    ", interleaved"
+                } else {
+                    ", deinterleaved"
+                }
+            } else {
+                ""
+            };
+            let native = (flags.0 & FormatFlags::IS_BIG_ENDIAN.0)
+                == (if cfg!(target_endian = "big") {
+                    FormatFlags::IS_BIG_ENDIAN.0
+                } else {
+                    0
+                });
+            let sample_size = self.bytes_per_sample();
+            let packed = self.bits_per_channel == sample_size * 8;
+            if native && packed && self.sample_fraction_bits() == 0 {
+                let common = match (
+                    flags.contains(FormatFlags::IS_FLOAT),
+                    flags.contains(FormatFlags::IS_SIGNED_INTEGER),
+                    self.bits_per_channel,
+                ) {
+                    (true, _, 32) => Some("Float32"),
+                    (true, _, 64) => Some("Float64"),
+                    (false, true, 16) => Some("Int16"),
+                    (false, true, 32) => Some("Int32"),
+                    _ => None,
+                };
+                if let Some(common) = common {
+                    return write!(f, "{common}{interleaving}");
+                }
+            }
+        }
+
+        let mut fcc = self.format.0.to_be_bytes();
+        if fcc.iter().all(|c| c.is_ascii_graphic() || *c == b' ') {
+            write!(f, "'{}' ", four_cc_to_str(&mut fcc))?;
+        } else {
+            write!(f, "{:#010x} ", self.format.0)?;
+        }
+        write!(f, "(0x{:08X}) ", flags.0)?;
+
+        if self.is_lpcm() {
+            let sample_size = self.bytes_per_sample();
+            let fraction = self.sample_fraction_bits();
+            if fraction > 0 {
+                write!(f, "{}.{}-bit", self.bits_per_channel - fraction, fraction)?;
+            } else {
+                write!(f, "{}-bit", self.bits_per_channel)?;
+            }
+            if sample_size > 1 {
+                if flags.contains(FormatFlags::IS_BIG_ENDIAN) {
+                    f.write_str(" big-endian")?;
+                } else {
+                    f.write_str(" little-endian")?;
+                }
+            }
+            if flags.contains(FormatFlags::IS_FLOAT) {
+                f.write_str(" float")?;
+            } else if flags.contains(FormatFlags::IS_SIGNED_INTEGER) {
+                f.write_str(" signed integer")?;
+            } else {
+                f.write_str(" unsigned integer")?;
+            }
+            if sample_size > 0 && self.bits_per_channel != sample_size * 8 {
+                let alignment = if flags.contains(FormatFlags::IS_ALIGNED_HIGH) {
+                    "high"
+                } else {
+                    "low"
+                };
+                write!(f, ", {alignment}-aligned in {sample_size} bytes")?;
+            }
+            if !self.is_interleaved() {
+                f.write_str(", deinterleaved")?;
+            }
+            Ok(())
+        } else if let Some(bits) = self.lossless_source_bits() {
+            write!(
+                f,
+                "from {bits}-bit source, {} frames/packet",
+                self.frames_per_packet
+            )
+        } else {
+            write!(
+                f,
+                "{} bits/channel, {} bytes/packet, {} frames/packet, {} bytes/frame",
+                self.bits_per_channel,
+                self.bytes_per_packet,
+                self.frames_per_packet,
+                self.bytes_per_frame
+            )
+        }
+    }
 }
 
 /// This structure describes the packet layout of a buffer of data where the size of
@@ -1082,7 +1645,7 @@ impl ChannelLabel {
 /// These constants are for use in the mChannelBitmap field of an
 /// AudioChannelLayout structure
 #[doc(alias = "AudioChannelBitmap")]
-#[derive(Debug, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[repr(transparent)]
 pub struct ChannelBitmap(pub u32);
 
@@ -1394,30 +1957,30 @@ impl ChannelLayoutTag {
     /// L R C LFE Ls Rs Lc Rc
     pub const AUDIO_UNIT_7_1_FRONT: Self = Self::MPEG_7_1_A;
 
-    pub const AAC_3_0: Self = Self::MPEG_3_0_B;
     /// C L R
-    pub const AAC_QUADRAPHONIC: Self = Self::QUADRAPHONIC;
+    pub const AAC_3_0: Self = Self::MPEG_3_0_B;
     /// L R Ls Rs
-    pub const AAC_4_0: Self = Self::MPEG_4_0_B;
+    pub const AAC_QUADRAPHONIC: Self = Self::QUADRAPHONIC;
     /// C L R Cs
-    pub const AAC_5_0: Self = Self::MPEG_5_0_D;
+    pub const AAC_4_0: Self = Self::MPEG_4_0_B;
     /// C L R Ls Rs
-    pub const AAC_5_1: Self = Self::MPEG_5_1_D;
+    pub const AAC_5_0: Self = Self::MPEG_5_0_D;
     /// C L R Ls Rs Lfe
-    pub const AAC_6_0: Self = Self((141u32 << 16) | 6);
+    pub const AAC_5_1: Self = Self::MPEG_5_1_D;
     /// C L R Ls Rs Cs
-    pub const AAC_6_1: Self = Self((142u32 << 16) | 7);
+    pub const AAC_6_0: Self = Self((141u32 << 16) | 6);
     /// C L R Ls Rs Cs Lfe
-    pub const AAC_7_0: Self = Self((143u32 << 16) | 7);
+    pub const AAC_6_1: Self = Self((142u32 << 16) | 7);
     /// C L R Ls Rs Rls Rrs
-    pub const AAC_7_1: Self = Self::MPEG_7_1_B;
+    pub const AAC_7_0: Self = Self((143u32 << 16) | 7);
     /// C Lc Rc L R Ls Rs Lfe
-    pub const AAC_7_1_B: Self = Self((183u32 << 16) | 8);
+    pub const AAC_7_1: Self = Self::MPEG_7_1_B;
     /// C L R Ls Rs Rls Rrs LFE
-    pub const AAC_7_1_C: Self = Self((184u32 << 16) | 8);
+    pub const AAC_7_1_B: Self = Self((183u32 << 16) | 8);
     /// C L R Ls Rs LFE Vhl Vhr
-    pub const AAC_OCTAGONAL: Self = Self((144u32 << 16) | 8);
+    pub const AAC_7_1_C: Self = Self((184u32 << 16) | 8);
     /// C L R Ls Rs Rls Rrs Cs
+    pub const AAC_OCTAGONAL: Self = Self((144u32 << 16) | 8);
 
     /// L R C Vhc Lsd Rsd Ls Rs Vhl Vhr Lw Rw Csd Cs LFE1 LFE2
     pub const TMH_10_2_STD: Self = Self((145u32 << 16) | 16);
@@ -1627,7 +2190,7 @@ impl ChannelLayoutTag {
 
 /// This structure describes a single channel.
 #[doc(alias = "AudioChannelDescription")]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
 #[repr(C)]
 pub struct ChannelDesc {
     /// The AudioChannelLabel that describes the channel.
@@ -1641,7 +2204,7 @@ pub struct ChannelDesc {
 }
 
 #[doc(alias = "AudioChannelLayout")]
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 #[repr(C)]
 pub struct ChannelLayout<const N: usize> {
     pub channel_layout_tag: ChannelLayoutTag,
@@ -1694,7 +2257,7 @@ impl Mpeg4Object {
 
 #[cfg(test)]
 mod tests {
-    use crate::at;
+    use crate::{at, cat::audio};
 
     #[test]
     fn basics() {
@@ -1703,4 +2266,139 @@ mod tests {
         assert!(!asbd.is_interleaved());
         assert!(asbd.is_common_f32());
     }
+
+    #[test]
+    fn lpcm() {
+        use audio::StreamBasicDesc as Asbd;
+
+        let asbd = Asbd::common_i16(44_100.0, 2, true);
+        assert_eq!(asbd.bytes_per_frame, 4);
+        assert_eq!(asbd.bytes_per_sample(), 2);
+        assert_eq!(asbd.validate(), Ok(()));
+        assert_eq!(asbd.to_string(), " 2 ch,  44100 Hz, Int16, interleaved");
+
+        let asbd = asbd.with_interleaved(false);
+        assert_eq!(asbd.bytes_per_frame, 2);
+        assert_eq!(asbd.bytes_per_packet, 2);
+        assert_eq!(asbd.validate(), Ok(()));
+        assert_eq!(
+            asbd.with_interleaved(true),
+            Asbd::common_i16(44_100.0, 2, true)
+        );
+
+        let asbd = Asbd::i24_packed(96_000.0, 6, true).with_big_endian(true);
+        assert_eq!(asbd.bytes_per_frame, 18);
+        assert_eq!(asbd.validate(), Ok(()));
+        assert_eq!(
+            asbd.to_string(),
+            " 6 ch,  96000 Hz, 'lpcm' (0x0000000E) 24-bit big-endian signed integer"
+        );
+
+        let asbd = Asbd::i24_aligned_high(48_000.0, 1, true);
+        assert_eq!(asbd.validate(), Ok(()));
+        assert!(asbd.to_string().ends_with(
+            "'lpcm' (0x00000014) 24-bit little-endian signed integer, high-aligned in 4 bytes"
+        ));
+
+        for asbd in [
+            Asbd::common_i32(48_000.0, 2, false),
+            Asbd::common_f32(48_000.0, 8, true),
+            Asbd::common_f64(48_000.0, 1, true),
+            Asbd::lpcm(8_000.0, 1, 8, 8, false, false, false),
+            Asbd::lpcm(8_000.0, 1, 16, 16, true, false, false),
+        ] {
+            assert_eq!(asbd.validate(), Ok(()), "{asbd}");
+        }
+        assert_eq!(
+            Asbd::common_f64(48_000.0, 1, true).to_string(),
+            " 1 ch,  48000 Hz, Float64"
+        );
+    }
+
+    #[test]
+    fn validate() {
+        use audio::{FormatFlags, StreamBasicDesc as Asbd, StreamBasicDescError as E};
+
+        let good = Asbd::common_f32(48_000.0, 2, true);
+
+        let mut asbd = good;
+        asbd.sample_rate = f64::NAN;
+        assert_eq!(asbd.validate(), Err(E::SampleRate));
+
+        let mut asbd = good;
+        asbd.channels_per_frame = 0;
+        assert_eq!(asbd.validate(), Err(E::NoChannels));
+
+        let mut asbd = good;
+        asbd.frames_per_packet = 2;
+        assert_eq!(asbd.validate(), Err(E::FramesPerPacket(2)));
+
+        let mut asbd = good;
+        asbd.bytes_per_frame = 7;
+        asbd.bytes_per_packet = 7;
+        assert_eq!(
+            asbd.validate(),
+            Err(E::BytesPerFrame {
+                bytes_per_frame: 7,
+                channels: 2
+            })
+        );
+
+        let mut asbd = good;
+        asbd.bits_per_channel = 40;
+        assert_eq!(
+            asbd.validate(),
+            Err(E::BitsPerChannel {
+                bits_per_channel: 40,
+                sample_size: 4
+            })
+        );
+
+        let mut asbd = good;
+        asbd.format_flags |= FormatFlags::IS_SIGNED_INTEGER;
+        assert!(matches!(asbd.validate(), Err(E::Flags(_))));
+
+        let asbd = Asbd::lpcm(48_000.0, 2, 24, 32, true, false, false);
+        assert_eq!(asbd.validate(), Err(E::FloatBits(24)));
+
+        let mut asbd = Asbd::i24_aligned_high(48_000.0, 2, true);
+        asbd.format_flags |= FormatFlags::IS_PACKED;
+        assert_eq!(
+            asbd.validate(),
+            Err(E::BitsPerChannel {
+                bits_per_channel: 24,
+                sample_size: 4
+            })
+        );
+
+        let mut asbd = Asbd::aac_lc(44_100.0, 2);
+        assert_eq!(asbd.validate(), Ok(()));
+        asbd.bits_per_channel = 16;
+        assert_eq!(asbd.validate(), Err(E::CompressedFrame));
+    }
+
+    #[test]
+    fn compressed() {
+        use audio::{Format, StreamBasicDesc as Asbd};
+
+        let asbd = Asbd::he_aac(48_000.0, 2);
+        assert_eq!(asbd.format, Format::MPEG4_AAC_HE);
+        assert_eq!(asbd.frames_per_packet, 2048);
+
+        assert_eq!(Asbd::he_aac_v2(48_000.0).channels_per_frame, 2);
+        assert_eq!(Asbd::opus(48_000.0, 2).frames_per_packet, 960);
+
+        let asbd = Asbd::alac(44_100.0, 2, 24);
+        assert_eq!(asbd.lossless_source_bits(), Some(24));
+        assert_eq!(asbd.validate(), Ok(()));
+        assert_eq!(
+            asbd.to_string(),
+            " 2 ch,  44100 Hz, 'alac' (0x00000003) from 24-bit source, 4096 frames/packet"
+        );
+
+        let asbd = Asbd::flac(96_000.0, 2, 16);
+        assert_eq!(asbd.lossless_source_bits(), Some(16));
+        assert_eq!(Asbd::flac(96_000.0, 2, 12).lossless_source_bits(), None);
+        assert_eq!(Asbd::aac_lc(96_000.0, 2).lossless_source_bits(), None);
+    }
 }
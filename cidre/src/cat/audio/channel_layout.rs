@@ -0,0 +1,793 @@
+use std::f64::consts::FRAC_1_SQRT_2 as G;
+
+use super::{
+    ChannelBitmap, ChannelDesc, ChannelFlags, ChannelLabel, ChannelLayout, ChannelLayoutTag,
+};
+
+use ChannelLabel as Label;
+use ChannelLayoutTag as T;
+
+const L: Label = Label::LEFT;
+const R: Label = Label::RIGHT;
+const C: Label = Label::CENTER;
+const LFE: Label = Label::LFE_SCREEN;
+const LFE2: Label = Label::LFE2;
+const LS: Label = Label::LEFT_SURROUND;
+const RS: Label = Label::RIGHT_SURROUND;
+const CS: Label = Label::CENTER_SURROUND;
+const RLS: Label = Label::REAR_SURROUND_LEFT;
+const RRS: Label = Label::REAR_SURROUND_RIGHT;
+const LW: Label = Label::LEFT_WIDE;
+const RW: Label = Label::RIGHT_WIDE;
+const LSD: Label = Label::LEFT_SURROUND_DIRECT;
+const RSD: Label = Label::RIGHT_SURROUND_DIRECT;
+const CSD: Label = Label::CENTER_SURROUND_DIRECT;
+const LC: Label = Label::LEFT_CENTER;
+const RC: Label = Label::RIGHT_CENTER;
+const TS: Label = Label::TOP_CENTER_SURROUND;
+const VHL: Label = Label::VERTICAL_HEIGHT_LEFT;
+const VHC: Label = Label::VERTICAL_HEIGHT_CENTER;
+const VHR: Label = Label::VERTICAL_HEIGHT_RIGHT;
+const LTM: Label = Label::LEFT_TOP_MIDDLE;
+const RTM: Label = Label::RIGHT_TOP_MIDDLE;
+const LTR: Label = Label::LEFT_TOP_REAR;
+const CTR: Label = Label::CENTER_TOP_REAR;
+const RTR: Label = Label::RIGHT_TOP_REAR;
+const LT: Label = Label::LEFT_TOTAL;
+const RT: Label = Label::RIGHT_TOTAL;
+
+/// Ordered labels of fixed layouts.
+///
+/// Layouts with the same labels are listed in order of preference
+/// for [`ChannelLayoutTag::with_labels`].
+const LAYOUTS: &[(T, &[Label])] = &[
+    (T::MONO, &[Label::MONO]),
+    (T::STEREO, &[L, R]),
+    (
+        T::STEREO_HEADPHONES,
+        &[Label::HEADPHONES_LEFT, Label::HEADPHONES_RIGHT],
+    ),
+    (T::MATRIX_STEREO, &[LT, RT]),
+    (T::MID_SIDE, &[Label::MS_MID, Label::MS_SIDE]),
+    (T::XY, &[Label::XY_X, Label::XY_Y]),
+    (T::BINAURAL, &[Label::BINAURAL_LEFT, Label::BINAURAL_RIGHT]),
+    (
+        T::AMBISONIC_B_FORMAT,
+        &[
+            Label::AMBISONIC_W,
+            Label::AMBISONIC_X,
+            Label::AMBISONIC_Y,
+            Label::AMBISONIC_Z,
+        ],
+    ),
+    (T::QUADRAPHONIC, &[L, R, LS, RS]),
+    (T::MPEG_3_0_A, &[L, R, C]),
+    (T::MPEG_3_0_B, &[C, L, R]),
+    (T::MPEG_4_0_A, &[L, R, C, CS]),
+    (T::MPEG_4_0_B, &[C, L, R, CS]),
+    (T::MPEG_5_0_A, &[L, R, C, LS, RS]),
+    (T::MPEG_5_0_B, &[L, R, LS, RS, C]),
+    (T::MPEG_5_0_C, &[L, C, R, LS, RS]),
+    (T::MPEG_5_0_D, &[C, L, R, LS, RS]),
+    (T::MPEG_5_1_A, &[L, R, C, LFE, LS, RS]),
+    (T::MPEG_5_1_B, &[L, R, LS, RS, C, LFE]),
+    (T::MPEG_5_1_C, &[L, C, R, LS, RS, LFE]),
+    (T::MPEG_5_1_D, &[C, L, R, LS, RS, LFE]),
+    (T::MPEG_6_1_A, &[L, R, C, LFE, LS, RS, CS]),
+    (T::MPEG_7_1_A, &[L, R, C, LFE, LS, RS, LC, RC]),
+    (T::MPEG_7_1_B, &[C, LC, RC, L, R, LS, RS, LFE]),
+    (T::MPEG_7_1_C, &[L, R, C, LFE, LS, RS, RLS, RRS]),
+    (T::EMAGIC_DEFAULT_7_1, &[L, R, LS, RS, C, LFE, LC, RC]),
+    (T::SMPTE_DTV, &[L, R, C, LFE, LS, RS, LT, RT]),
+    (T::ITU_2_1, &[L, R, CS]),
+    (T::ITU_2_2, &[L, R, LS, RS]),
+    (T::DVD_4, &[L, R, LFE]),
+    (T::DVD_5, &[L, R, LFE, CS]),
+    (T::DVD_6, &[L, R, LFE, LS, RS]),
+    (T::DVD_10, &[L, R, C, LFE]),
+    (T::DVD_11, &[L, R, C, LFE, CS]),
+    (T::DVD_18, &[L, R, LS, RS, LFE]),
+    (T::AUDIO_UNIT_6_0, &[L, R, LS, RS, C, CS]),
+    (T::AUDIO_UNIT_7_0, &[L, R, LS, RS, C, RLS, RRS]),
+    (T::AUDIO_UNIT_7_0_FRONT, &[L, R, LS, RS, C, LC, RC]),
+    (T::AAC_6_0, &[C, L, R, LS, RS, CS]),
+    (T::AAC_6_1, &[C, L, R, LS, RS, CS, LFE]),
+    (T::AAC_7_0, &[C, L, R, LS, RS, RLS, RRS]),
+    (T::AAC_7_1_B, &[C, L, R, LS, RS, RLS, RRS, LFE]),
+    (T::AAC_7_1_C, &[C, L, R, LS, RS, LFE, VHL, VHR]),
+    (T::AAC_OCTAGONAL, &[C, L, R, LS, RS, RLS, RRS, CS]),
+    (
+        T::TMH_10_2_STD,
+        &[
+            L, R, C, VHC, LSD, RSD, LS, RS, VHL, VHR, LW, RW, CSD, CS, LFE, LFE2,
+        ],
+    ),
+    (
+        T::TMH_10_2_FULL,
+        &[
+            L,
+            R,
+            C,
+            VHC,
+            LSD,
+            RSD,
+            LS,
+            RS,
+            VHL,
+            VHR,
+            LW,
+            RW,
+            CSD,
+            CS,
+            LFE,
+            LFE2,
+            LC,
+            RC,
+            Label::HEARING_IMPAIRED,
+            Label::NARRATION,
+            Label::HAPTIC,
+        ],
+    ),
+    (T::AC3_1_0_1, &[C, LFE]),
+    (T::AC3_3_0, &[L, C, R]),
+    (T::AC3_3_1, &[L, C, R, CS]),
+    (T::AC3_3_0_1, &[L, C, R, LFE]),
+    (T::AC3_2_1_1, &[L, R, CS, LFE]),
+    (T::AC3_3_1_1, &[L, C, R, CS, LFE]),
+    (T::EAC_6_0_A, &[L, C, R, LS, RS, CS]),
+    (T::EAC_7_0_A, &[L, C, R, LS, RS, RLS, RRS]),
+    (T::EAC3_6_1_A, &[L, C, R, LS, RS, LFE, CS]),
+    (T::EAC3_6_1_B, &[L, C, R, LS, RS, LFE, TS]),
+    (T::EAC3_6_1_C, &[L, C, R, LS, RS, LFE, VHC]),
+    (T::EAC3_7_1_A, &[L, C, R, LS, RS, LFE, RLS, RRS]),
+    (T::EAC3_7_1_B, &[L, C, R, LS, RS, LFE, LC, RC]),
+    (T::EAC3_7_1_C, &[L, C, R, LS, RS, LFE, LSD, RSD]),
+    (T::EAC3_7_1_D, &[L, C, R, LS, RS, LFE, LW, RW]),
+    (T::EAC3_7_1_E, &[L, C, R, LS, RS, LFE, VHL, VHR]),
+    (T::EAC3_7_1_F, &[L, C, R, LS, RS, LFE, CS, TS]),
+    (T::EAC3_7_1_G, &[L, C, R, LS, RS, LFE, CS, VHC]),
+    (T::EAC3_7_1_H, &[L, C, R, LS, RS, LFE, TS, VHC]),
+    (T::DTS_3_1, &[C, L, R, LFE]),
+    (T::DTS_4_1, &[C, L, R, CS, LFE]),
+    (T::DTS_6_0_A, &[LC, RC, L, R, LS, RS]),
+    (T::DTS_6_0_B, &[C, L, R, RLS, RRS, TS]),
+    (T::DTS_6_0_C, &[C, CS, L, R, RLS, RRS]),
+    (T::DTS_6_1_A, &[LC, RC, L, R, LS, RS, LFE]),
+    (T::DTS_6_1_B, &[C, L, R, RLS, RRS, TS, LFE]),
+    (T::DTS_6_1_C, &[C, CS, L, R, RLS, RRS, LFE]),
+    (T::DTS_7_0, &[LC, C, RC, L, R, LS, RS]),
+    (T::DTS_7_1, &[LC, C, RC, L, R, LS, RS, LFE]),
+    (T::DTS_8_0_A, &[LC, RC, L, R, LS, RS, RLS, RRS]),
+    (T::DTS_8_0_B, &[LC, C, RC, L, R, LS, CS, RS]),
+    (T::DTS_8_1_A, &[LC, RC, L, R, LS, RS, RLS, RRS, LFE]),
+    (T::DTS_8_1_B, &[LC, C, RC, L, R, LS, CS, RS, LFE]),
+    (T::DTS_6_1_D, &[C, L, R, LS, RS, LFE, CS]),
+    (T::WAVE_4_0_B, &[L, R, RLS, RRS]),
+    (T::WAVE_5_0_B, &[L, R, C, RLS, RRS]),
+    (T::WAVE_5_1_B, &[L, R, C, LFE, RLS, RRS]),
+    (T::WAVE_6_1, &[L, R, C, LFE, CS, LS, RS]),
+    (T::WAVE_7_1, &[L, R, C, LFE, RLS, RRS, LS, RS]),
+    (T::ATMOS_5_1_2, &[L, R, C, LFE, LS, RS, LTM, RTM]),
+    (T::ATMOS_5_1_4, &[L, R, C, LFE, LS, RS, VHL, VHR, LTR, RTR]),
+    (T::ATMOS_7_1_2, &[L, R, C, LFE, LS, RS, RLS, RRS, LTM, RTM]),
+    (
+        T::ATMOS_7_1_4,
+        &[L, R, C, LFE, LS, RS, RLS, RRS, VHL, VHR, LTR, RTR],
+    ),
+    (
+        T::ATMOS_9_1_6,
+        &[
+            L, R, C, LFE, LS, RS, RLS, RRS, LW, RW, VHL, VHR, LTM, RTM, LTR, RTR,
+        ],
+    ),
+    (T::LOGIC_4_0_C, &[L, R, CS, C]),
+    (T::LOGIC_6_0_B, &[L, R, LS, RS, CS, C]),
+    (T::LOGIC_6_1_B, &[L, R, LS, RS, CS, C, LFE]),
+    (T::LOGIC_6_1_D, &[L, C, R, LS, CS, RS, LFE]),
+    (T::LOGIC_7_1_B, &[L, R, LS, RS, RLS, RRS, C, LFE]),
+    (
+        T::LOGIC_ATMOS_7_1_4_B,
+        &[L, R, RLS, RRS, LS, RS, C, LFE, VHL, VHR, LTR, RTR],
+    ),
+    (
+        T::LOGIC_ATMOS_7_1_6,
+        &[L, R, RLS, RRS, LS, RS, C, LFE, VHL, VHR, LTM, RTM, LTR, RTR],
+    ),
+    (T::PENTAGONAL, &[L, R, LS, RS, C]),
+    (T::HEXAGONAL, &[L, R, LS, RS, C, CS]),
+    (T::OCTAGONAL, &[L, R, LS, RS, C, CS, LW, RW]),
+    (T::CUBE, &[L, R, LS, RS, VHL, VHR, LTR, RTR]),
+];
+
+impl ChannelLabel {
+    /// Bit of the label in [`ChannelBitmap`], empty if there is none.
+    pub const fn bitmap(&self) -> ChannelBitmap {
+        ChannelBitmap(match self.0 {
+            1..=18 => 1 << (self.0 - 1),
+            49 => 1 << 21,
+            51 => 1 << 23,
+            52..=54 => 1 << (self.0 - 28),
+            _ => 0,
+        })
+    }
+}
+
+impl ChannelBitmap {
+    /// Labels of set bits in bit order, which is the order of channels in WAVE files.
+    pub fn labels(&self) -> impl Iterator<Item = Label> + use<> {
+        let bits = self.0;
+        (0..32u32)
+            .filter(move |bit| bits & (1 << bit) != 0)
+            .filter_map(|bit| match bit {
+                0..=17 => Some(Label(bit + 1)),
+                21 => Some(Label::LEFT_TOP_MIDDLE),
+                23..=26 => Some(Label(bit + 28)),
+                _ => None,
+            })
+    }
+
+    /// `None` if some label can't be represented in a bitmap.
+    pub fn with_labels(labels: &[Label]) -> Option<Self> {
+        let mut bits = 0;
+        for label in labels {
+            let bit = label.bitmap().0;
+            if bit == 0 {
+                return None;
+            }
+            bits |= bit;
+        }
+        Some(Self(bits))
+    }
+
+    #[inline]
+    pub const fn channels_num(&self) -> u32 {
+        (self.0 & 0x07a3_ffff).count_ones()
+    }
+}
+
+impl ChannelLayoutTag {
+    /// Ordered channel labels of the layout.
+    ///
+    /// `None` for [`Self::USE_CHANNEL_DESCRIPTIONS`], [`Self::USE_CHANNEL_BITMAP`],
+    /// [`Self::UNKNOWN`] and reserved tags.
+    ///
+    /// ```
+    /// use cidre::cat::audio;
+    ///
+    /// let tag = audio::ChannelLayoutTag::MPEG_5_1_D;
+    /// assert_eq!(
+    ///     tag.labels().unwrap(),
+    ///     [
+    ///         audio::ChannelLabel::CENTER,
+    ///         audio::ChannelLabel::LEFT,
+    ///         audio::ChannelLabel::RIGHT,
+    ///         audio::ChannelLabel::LEFT_SURROUND,
+    ///         audio::ChannelLabel::RIGHT_SURROUND,
+    ///         audio::ChannelLabel::LFE_SCREEN,
+    ///     ]
+    /// );
+    /// ```
+    pub fn labels(&self) -> Option<Vec<Label>> {
+        if let Some(labels) = self.fixed_labels() {
+            return Some(labels.to_vec());
+        }
+        let n = self.number_of_channels();
+        let base = match Self(self.0 & 0xFFFF_0000) {
+            Self::DISCRETE_IN_ORDER => Label::DISCRETE_0,
+            Self::HOA_ACN_SN3D => Label::HOA_SN3D,
+            Self::HOA_ACN_N3D => Label::HOA_N3D,
+            _ => return None,
+        };
+        Some((0..n).map(|i| Label(base.0 | i)).collect())
+    }
+
+    fn fixed_labels(&self) -> Option<&'static [Label]> {
+        LAYOUTS
+            .iter()
+            .find(|(tag, _)| tag == self)
+            .map(|(_, labels)| *labels)
+    }
+
+    /// Tag describing exactly these labels in this order.
+    ///
+    /// ```
+    /// use cidre::cat::audio;
+    ///
+    /// let labels = audio::ChannelLayoutTag::ATMOS_7_1_4.labels().unwrap();
+    /// assert_eq!(
+    ///     audio::ChannelLayoutTag::with_labels(&labels),
+    ///     Some(audio::ChannelLayoutTag::ATMOS_7_1_4)
+    /// );
+    /// ```
+    pub fn with_labels(labels: &[Label]) -> Option<Self> {
+        if let Some((tag, _)) = LAYOUTS.iter().find(|(_, l)| *l == labels) {
+            return Some(*tag);
+        }
+        let n = u16::try_from(labels.len()).ok().filter(|n| *n > 0)?;
+        for (tag, base) in [
+            (Self::DISCRETE_IN_ORDER, Label::DISCRETE_0),
+            (Self::HOA_ACN_SN3D, Label::HOA_SN3D),
+            (Self::HOA_ACN_N3D, Label::HOA_N3D),
+        ] {
+            if labels
+                .iter()
+                .enumerate()
+                .all(|(i, l)| l.0 == base.0 | i as u32)
+            {
+                return Some(Self(tag.0 | n as u32));
+            }
+        }
+        None
+    }
+
+    /// Tag with labels in bit order of the bitmap.
+    pub fn with_bitmap(bitmap: ChannelBitmap) -> Option<Self> {
+        let labels: Vec<_> = bitmap.labels().collect();
+        Self::with_labels(&labels)
+    }
+
+    /// Matrix to mix channels of this layout into `dst` layout.
+    pub fn mix_matrix(&self, dst: Self) -> Option<MixMatrix> {
+        MixMatrix::with_tags(*self, dst)
+    }
+}
+
+impl<const N: usize> ChannelLayout<N> {
+    const EMPTY_DESC: ChannelDesc = ChannelDesc {
+        channel_label: Label::UNUSED,
+        channel_flags: ChannelFlags::ALL_OFF,
+        coordinates: [0.0; 3],
+    };
+
+    pub const fn with_tag(tag: T) -> Self {
+        Self {
+            channel_layout_tag: tag,
+            channel_bitmap: ChannelBitmap(0),
+            number_channel_descriptions: 0,
+            channel_descriptions: [Self::EMPTY_DESC; N],
+        }
+    }
+
+    pub const fn with_bitmap(bitmap: ChannelBitmap) -> Self {
+        let mut res = Self::with_tag(T::USE_CHANNEL_BITMAP);
+        res.channel_bitmap = bitmap;
+        res
+    }
+
+    pub fn with_labels(labels: [Label; N]) -> Self {
+        let mut res = Self::with_tag(T::USE_CHANNEL_DESCRIPTIONS);
+        res.number_channel_descriptions = N as u32;
+        for (desc, label) in res.channel_descriptions.iter_mut().zip(labels) {
+            desc.channel_label = label;
+        }
+        res
+    }
+
+    /// Channel descriptions in use.
+    pub fn descs(&self) -> &[ChannelDesc] {
+        let n = (self.number_channel_descriptions as usize).min(N);
+        &self.channel_descriptions[..n]
+    }
+
+    /// Ordered channel labels from tag, bitmap or descriptions.
+    pub fn labels(&self) -> Option<Vec<Label>> {
+        match self.channel_layout_tag {
+            T::USE_CHANNEL_DESCRIPTIONS => {
+                Some(self.descs().iter().map(|d| d.channel_label).collect())
+            }
+            T::USE_CHANNEL_BITMAP => Some(self.channel_bitmap.labels().collect()),
+            tag => tag.labels(),
+        }
+    }
+
+    pub fn channels_num(&self) -> u32 {
+        match self.channel_layout_tag {
+            T::USE_CHANNEL_DESCRIPTIONS => self.descs().len() as u32,
+            T::USE_CHANNEL_BITMAP => self.channel_bitmap.channels_num(),
+            tag => tag.number_of_channels(),
+        }
+    }
+
+    /// Tag describing the layout, resolving bitmap and descriptions.
+    pub fn to_tag(&self) -> Option<T> {
+        match self.channel_layout_tag {
+            T::USE_CHANNEL_DESCRIPTIONS => {
+                let labels: Vec<_> = self.descs().iter().map(|d| d.channel_label).collect();
+                T::with_labels(&labels)
+            }
+            T::USE_CHANNEL_BITMAP => T::with_bitmap(self.channel_bitmap),
+            tag => Some(tag),
+        }
+    }
+
+    /// Size of C `AudioChannelLayout` with `n` channel descriptions.
+    pub const fn size_with_descs(n: usize) -> usize {
+        std::mem::offset_of!(Self, channel_descriptions) + n * std::mem::size_of::<ChannelDesc>()
+    }
+
+    /// Variable-length C representation, only descriptions in use are included.
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = Self::size_with_descs(self.descs().len());
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const u8, len) }
+    }
+
+    /// Reads variable-length C representation.
+    ///
+    /// `None` if `bytes` are truncated or describe more than `N` channels.
+    ///
+    /// ```
+    /// use cidre::cat::audio;
+    ///
+    /// let layout = audio::ChannelLayout::<2>::with_labels([
+    ///     audio::ChannelLabel::LEFT,
+    ///     audio::ChannelLabel::RIGHT,
+    /// ]);
+    /// let bytes = layout.as_bytes();
+    /// assert_eq!(bytes.len(), 52);
+    ///
+    /// let copy = audio::ChannelLayout::<4>::with_bytes(bytes).unwrap();
+    /// assert_eq!(copy.descs(), layout.descs());
+    /// assert_eq!(copy.to_tag(), Some(audio::ChannelLayoutTag::STEREO));
+    /// assert!(audio::ChannelLayout::<1>::with_bytes(bytes).is_none());
+    /// ```
+    pub fn with_bytes(bytes: &[u8]) -> Option<Self> {
+        let header = Self::size_with_descs(0);
+        if bytes.len() < header {
+            return None;
+        }
+        let n = u32::from_ne_bytes(bytes[8..12].try_into().unwrap()) as usize;
+        if n > N || bytes.len() < Self::size_with_descs(n) {
+            return None;
+        }
+        let mut res = Self::with_tag(T::USE_CHANNEL_DESCRIPTIONS);
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                &mut res as *mut Self as *mut u8,
+                Self::size_with_descs(n),
+            );
+        }
+        Some(res)
+    }
+}
+
+/// Gains to mix channels of one layout into another.
+///
+/// Channels missing in the destination are folded down with ITU-R BS.775
+/// coefficients: center goes to left and right at -3 dB, surrounds go to
+/// a center surround or to the front at -3 dB, LFE is dropped. Heights and
+/// extra surrounds of immersive layouts fold the same way into the nearest
+/// channels. Destination channels missing in the source stay silent,
+/// mono is spread to left and right at -3 dB.
+///
+/// Coefficients aren't normalized, apply gain or limit after mixing to avoid clipping.
+///
+/// ```
+/// use cidre::cat::audio;
+///
+/// let m = audio::ChannelLayoutTag::MPEG_5_1_A
+///     .mix_matrix(audio::ChannelLayoutTag::STEREO)
+///     .unwrap();
+/// let g = std::f32::consts::FRAC_1_SQRT_2;
+/// //            L    R    C  LFE  Ls   Rs
+/// assert_eq!(m.row(0), [1.0, 0.0, g, 0.0, g, 0.0]);
+/// assert_eq!(m.row(1), [0.0, 1.0, g, 0.0, 0.0, g]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixMatrix {
+    src_channels: usize,
+    dst_channels: usize,
+    /// Destination channel rows of source channel gains.
+    coefs: Vec<f32>,
+}
+
+impl MixMatrix {
+    pub fn with_labels(src: &[Label], dst: &[Label]) -> Self {
+        let mut coefs = vec![0.0f64; src.len() * dst.len()];
+        let mut gains = Vec::new();
+        for (s, label) in src.iter().enumerate() {
+            gains.clear();
+            route(dst, *label, 1.0, 4, &mut gains);
+            for (d, gain) in gains.iter() {
+                coefs[d * src.len() + s] += gain;
+            }
+        }
+        Self {
+            src_channels: src.len(),
+            dst_channels: dst.len(),
+            coefs: coefs.into_iter().map(|c| c as f32).collect(),
+        }
+    }
+
+    pub fn with_tags(src: T, dst: T) -> Option<Self> {
+        Some(Self::with_labels(&src.labels()?, &dst.labels()?))
+    }
+
+    #[inline]
+    pub fn src_channels(&self) -> usize {
+        self.src_channels
+    }
+
+    #[inline]
+    pub fn dst_channels(&self) -> usize {
+        self.dst_channels
+    }
+
+    #[inline]
+    pub fn coef(&self, dst: usize, src: usize) -> f32 {
+        self.coefs[dst * self.src_channels + src]
+    }
+
+    /// Gains of source channels for `dst` channel.
+    #[inline]
+    pub fn row(&self, dst: usize) -> &[f32] {
+        &self.coefs[dst * self.src_channels..][..self.src_channels]
+    }
+
+    /// All rows, row-major.
+    #[inline]
+    pub fn as_slice(&self) -> &[f32] {
+        &self.coefs
+    }
+
+    /// Mixes one frame.
+    pub fn mix(&self, src: &[f32], dst: &mut [f32]) {
+        for (d, out) in dst.iter_mut().enumerate().take(self.dst_channels) {
+            *out = self.row(d).iter().zip(src).map(|(g, s)| g * s).sum();
+        }
+    }
+}
+
+fn find(dst: &[Label], label: Label) -> Option<usize> {
+    dst.iter()
+        .position(|l| *l == label)
+        .or_else(|| match label {
+            C => dst.iter().position(|l| *l == Label::MONO),
+            Label::MONO => dst.iter().position(|l| *l == C),
+            _ => None,
+        })
+}
+
+/// Alternatives to fold a missing channel into, in order of preference.
+fn folds(label: Label) -> &'static [&'static [(Label, f64)]] {
+    match label {
+        C | Label::MONO => &[&[(L, G), (R, G)]],
+        L | R => &[&[(C, G)]],
+        LC => &[&[(L, G), (C, G)]],
+        RC => &[&[(R, G), (C, G)]],
+        LW => &[&[(L, G)]],
+        RW => &[&[(R, G)]],
+        LS => &[&[(RLS, 1.0)], &[(CS, G)], &[(L, G)]],
+        RS => &[&[(RRS, 1.0)], &[(CS, G)], &[(R, G)]],
+        RLS => &[&[(LS, G)], &[(CS, G)], &[(L, G)]],
+        RRS => &[&[(RS, G)], &[(CS, G)], &[(R, G)]],
+        CS => &[
+            &[(LS, G), (RS, G)],
+            &[(RLS, G), (RRS, G)],
+            &[(L, G), (R, G)],
+        ],
+        LSD => &[&[(LS, 1.0)]],
+        RSD => &[&[(RS, 1.0)]],
+        CSD => &[&[(CS, 1.0)]],
+        VHL => &[&[(LTM, 1.0)], &[(L, G)]],
+        VHR => &[&[(RTM, 1.0)], &[(R, G)]],
+        VHC => &[&[(VHL, G), (VHR, G)], &[(C, G)]],
+        LTM => &[&[(VHL, G), (LTR, G)], &[(L, G)]],
+        RTM => &[&[(VHR, G), (RTR, G)], &[(R, G)]],
+        TS => &[&[(LTM, G), (RTM, G)], &[(C, G)]],
+        LTR | Label::TOP_BACK_LEFT => &[&[(LTR, 1.0)], &[(LTM, 1.0)], &[(LS, G)]],
+        RTR | Label::TOP_BACK_RIGHT => &[&[(RTR, 1.0)], &[(RTM, 1.0)], &[(RS, G)]],
+        CTR | Label::TOP_BACK_CENTER => &[&[(LTR, G), (RTR, G)], &[(CS, G)]],
+        LFE2 => &[&[(LFE, 1.0)]],
+        LT | Label::HEADPHONES_LEFT | Label::BINAURAL_LEFT => &[&[(L, 1.0)]],
+        RT | Label::HEADPHONES_RIGHT | Label::BINAURAL_RIGHT => &[&[(R, 1.0)]],
+        _ => &[],
+    }
+}
+
+/// Collects destination channel gains of `label` through the fewest folds.
+fn route(dst: &[Label], label: Label, gain: f64, depth: u32, out: &mut Vec<(usize, f64)>) -> bool {
+    (0..=depth).any(|d| route_within(dst, label, gain, d, out))
+}
+
+fn route_within(
+    dst: &[Label],
+    label: Label,
+    gain: f64,
+    depth: u32,
+    out: &mut Vec<(usize, f64)>,
+) -> bool {
+    if let Some(i) = find(dst, label) {
+        out.push((i, gain));
+        return true;
+    }
+    if depth == 0 {
+        return false;
+    }
+    for alt in folds(label) {
+        let mark = out.len();
+        if alt
+            .iter()
+            .all(|(l, g)| route(dst, *l, gain * g, depth - 1, out))
+        {
+            return true;
+        }
+        out.truncate(mark);
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cat::audio;
+
+    use super::LAYOUTS;
+
+    #[test]
+    fn tags() {
+        use audio::ChannelLabel as Label;
+        use audio::ChannelLayoutTag as Tag;
+
+        for (tag, labels) in LAYOUTS {
+            assert_eq!(tag.number_of_channels() as usize, labels.len(), "{tag:?}");
+            let same = Tag::with_labels(labels).unwrap();
+            assert_eq!(same.labels().unwrap(), *labels, "{tag:?}");
+        }
+        assert_eq!(Tag::AAC_5_1.labels(), Tag::MPEG_5_1_D.labels());
+        assert_eq!(
+            Tag::with_labels(&Tag::ITU_2_2.labels().unwrap()),
+            Some(Tag::QUADRAPHONIC)
+        );
+        assert_eq!(
+            Tag::with_labels(&Tag::PENTAGONAL.labels().unwrap()),
+            Some(Tag::MPEG_5_0_B)
+        );
+
+        let discrete = Tag(Tag::DISCRETE_IN_ORDER.0 | 3);
+        assert_eq!(discrete.number_of_channels(), 3);
+        let labels = discrete.labels().unwrap();
+        assert_eq!(
+            labels,
+            [Label::DISCRETE_0, Label::DISCRETE_1, Label::DISCRETE_2]
+        );
+        assert_eq!(Tag::with_labels(&labels), Some(discrete));
+
+        let hoa = Tag(Tag::HOA_ACN_SN3D.0 | 4);
+        assert_eq!(hoa.labels().unwrap()[3], Label::HOA_ACN_3);
+        assert_eq!(Tag::with_labels(&hoa.labels().unwrap()), Some(hoa));
+
+        assert_eq!(Tag::USE_CHANNEL_BITMAP.labels(), None);
+        assert_eq!(Tag(Tag::UNKNOWN.0 | 2).labels(), None);
+        assert_eq!(Tag::with_labels(&[]), None);
+        assert_eq!(Tag::with_labels(&[Label::RIGHT, Label::LEFT]), None);
+    }
+
+    #[test]
+    fn bitmaps() {
+        use audio::ChannelBitmap as Bitmap;
+        use audio::ChannelLayoutTag as Tag;
+
+        let bitmap = Bitmap(
+            Bitmap::LEFT.0
+                | Bitmap::RIGHT.0
+                | Bitmap::CENTER.0
+                | Bitmap::LFE_SCREEN.0
+                | Bitmap::LEFT_SURROUND.0
+                | Bitmap::RIGHT_SURROUND.0,
+        );
+        assert_eq!(bitmap.channels_num(), 6);
+        assert_eq!(Tag::with_bitmap(bitmap), Some(Tag::MPEG_5_1_A));
+        assert_eq!(
+            Bitmap::with_labels(&Tag::MPEG_5_1_D.labels().unwrap()),
+            Some(bitmap)
+        );
+        assert_eq!(
+            Bitmap::with_labels(&Tag::MATRIX_STEREO.labels().unwrap()),
+            None
+        );
+
+        for bit in [0u32, 11, 17, 21, 23, 24, 25, 26] {
+            let labels: Vec<_> = Bitmap(1 << bit).labels().collect();
+            assert_eq!(labels.len(), 1);
+            assert_eq!(labels[0].bitmap(), Bitmap(1 << bit));
+        }
+        assert_eq!(Bitmap(1 << 20).labels().count(), 0);
+        assert_eq!(Bitmap(u32::MAX).channels_num(), 23);
+        assert_eq!(Bitmap(u32::MAX).labels().count(), 23);
+    }
+
+    #[test]
+    fn layouts() {
+        use audio::ChannelLabel as Label;
+        use audio::ChannelLayoutTag as Tag;
+
+        let layout = audio::ChannelLayout::<0>::with_tag(Tag::ATMOS_7_1_4);
+        assert_eq!(layout.channels_num(), 12);
+        assert_eq!(layout.as_bytes().len(), 12);
+        assert_eq!(
+            audio::ChannelLayout::<0>::with_bytes(layout.as_bytes()),
+            Some(layout)
+        );
+
+        let layout = audio::ChannelLayout::<0>::with_bitmap(audio::ChannelBitmap(0b111));
+        assert_eq!(layout.channels_num(), 3);
+        assert_eq!(layout.to_tag(), Some(Tag::MPEG_3_0_A));
+
+        let labels = [Label::CENTER, Label::LEFT, Label::RIGHT];
+        let layout = audio::ChannelLayout::with_labels(labels);
+        assert_eq!(layout.labels().unwrap(), labels);
+        assert_eq!(layout.to_tag(), Some(Tag::MPEG_3_0_B));
+
+        let bytes = layout.as_bytes();
+        assert_eq!(bytes.len(), 12 + 3 * 20);
+        assert_eq!(audio::ChannelLayout::<3>::with_bytes(bytes), Some(layout));
+        assert!(audio::ChannelLayout::<3>::with_bytes(&bytes[..bytes.len() - 1]).is_none());
+        assert!(audio::ChannelLayout::<2>::with_bytes(bytes).is_none());
+    }
+
+    #[test]
+    fn mix() {
+        use audio::ChannelLayoutTag as Tag;
+        const G: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+        let m = Tag::MPEG_5_1_A.mix_matrix(Tag::MONO).unwrap();
+        assert_eq!(m.dst_channels(), 1);
+        assert_eq!(m.row(0), [G, G, 1.0, 0.0, 0.5, 0.5]);
+
+        let m = Tag::MONO.mix_matrix(Tag::STEREO).unwrap();
+        assert_eq!(m.as_slice(), [G, G]);
+
+        // 3/2 to 3/1 and 2/2
+        let m = Tag::MPEG_5_0_A.mix_matrix(Tag::MPEG_4_0_A).unwrap();
+        assert_eq!(m.row(3), [0.0, 0.0, 0.0, G, G]);
+        let m = Tag::MPEG_5_0_A.mix_matrix(Tag::QUADRAPHONIC).unwrap();
+        assert_eq!(m.row(0), [1.0, 0.0, G, 0.0, 0.0]);
+        assert_eq!(m.row(2), [0.0, 0.0, 0.0, 1.0, 0.0]);
+
+        // 3/1 to 2/0
+        let m = Tag::MPEG_4_0_A.mix_matrix(Tag::STEREO).unwrap();
+        assert_eq!(m.row(0), [1.0, 0.0, G, G]);
+
+        // reordering keeps unit gains
+        let m = Tag::MPEG_5_1_A.mix_matrix(Tag::MPEG_5_1_D).unwrap();
+        for (d, s) in [(0, 2), (1, 0), (2, 1), (3, 4), (4, 5), (5, 3)] {
+            assert_eq!(m.coef(d, s), 1.0);
+            assert_eq!(m.row(d).iter().sum::<f32>(), 1.0);
+        }
+
+        // 7.1.4 to 5.1
+        let m = Tag::ATMOS_7_1_4.mix_matrix(Tag::MPEG_5_1_A).unwrap();
+        //              L    R    C    LFE  Ls   Rs   Rls  Rrs  Vhl  Vhr  Ltr  Rtr
+        assert_eq!(
+            m.row(0),
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, G, 0.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            m.row(4),
+            [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, G, 0.0, 0.0, 0.0, G, 0.0]
+        );
+
+        // 7.1.4 to 7.1.2 folds heights into top middle
+        let m = Tag::ATMOS_7_1_4.mix_matrix(Tag::ATMOS_7_1_2).unwrap();
+        assert_eq!(
+            m.row(8),
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0]
+        );
+
+        // upmix leaves new channels silent
+        let m = Tag::STEREO.mix_matrix(Tag::MPEG_5_1_A).unwrap();
+        assert_eq!(
+            m.as_slice(),
+            [1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]
+        );
+
+        let mut out = [0.0f32; 2];
+        Tag::MPEG_5_1_A
+            .mix_matrix(Tag::STEREO)
+            .unwrap()
+            .mix(&[1.0, 0.5, 0.0, 1.0, 0.0, 0.0], &mut out);
+        assert_eq!(out, [1.0, 0.5]);
+
+        assert!(Tag::USE_CHANNEL_BITMAP.mix_matrix(Tag::STEREO).is_none());
+    }
+}
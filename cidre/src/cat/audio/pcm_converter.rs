@@ -0,0 +1,1073 @@
+use super::{
+    BufList, ChannelBitmap, ChannelLabel, ChannelLayoutTag, FormatFlags, StreamBasicDesc,
+    StreamBasicDescError,
+};
+
+/// Quantization of samples written with less precision than they were read.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Dither {
+    /// Round to nearest.
+    #[default]
+    None,
+
+    /// Triangular probability density noise of ±1 LSB added before rounding.
+    Tpdf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PcmConverterError {
+    /// Source or destination format is inconsistent.
+    Format(StreamBasicDescError),
+
+    /// Format isn't 8...32-bit integer or 32/64-bit float linear PCM.
+    UnsupportedFormat,
+
+    /// Sample rates differ, resampling requires `at::AudioConverterRef`.
+    SampleRate { src: f64, dst: f64 },
+
+    /// Channel map length doesn't match destination channels
+    /// or refers to a missing source channel.
+    ChannelMap,
+
+    /// Layout tag doesn't describe ordered channel labels.
+    ChannelLayoutTag(ChannelLayoutTag),
+
+    /// Wrong number of buffers for the format.
+    BufCount { expected: usize, actual: usize },
+
+    /// Buffer at `index` is too small for the requested number of frames.
+    BufSize {
+        index: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl std::fmt::Display for PcmConverterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Format(err) => write!(f, "invalid format: {err}"),
+            Self::UnsupportedFormat => write!(f, "unsupported linear PCM format"),
+            Self::SampleRate { src, dst } => {
+                write!(f, "sample rate conversion from {src} to {dst} Hz")
+            }
+            Self::ChannelMap => write!(f, "invalid channel map"),
+            Self::ChannelLayoutTag(tag) => write!(f, "unsupported channel layout tag {tag:?}"),
+            Self::BufCount { expected, actual } => {
+                write!(f, "{actual} buffers, expected {expected}")
+            }
+            Self::BufSize {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "buffer {index} has {actual} bytes, expected at least {expected}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PcmConverterError {}
+
+/// Software linear PCM converter.
+///
+/// Interleaves, deinterleaves, converts between 8, 16, 24 and 32-bit integer
+/// and 32/64-bit float samples of any endianness and remaps channels
+/// without allocations, so it can be used from realtime callbacks.
+///
+/// Samples go through f64 which represents every supported format exactly,
+/// integer to integer conversion of the same bit depth is lossless.
+/// Stereo f32 interleaving uses SIMD on aarch64 and x86_64.
+///
+/// ```
+/// use cidre::cat::audio;
+///
+/// let src = audio::StreamBasicDesc::common_i16(48_000.0, 2, true);
+/// let dst = audio::StreamBasicDesc::common_f32(48_000.0, 2, false);
+/// let mut converter = audio::PcmConverter::with_formats(&src, &dst).unwrap();
+///
+/// let lr: Vec<u8> = [0x4000i16, -0x4000].iter().flat_map(|s| s.to_le_bytes()).collect();
+/// let mut l = [0u8; 4];
+/// let mut r = [0u8; 4];
+/// converter.convert(1, &[&lr], &mut [&mut l, &mut r]).unwrap();
+///
+/// assert_eq!(f32::from_le_bytes(l), 0.5);
+/// assert_eq!(f32::from_le_bytes(r), -0.5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PcmConverter {
+    src: Layout,
+    dst: Layout,
+    map: Vec<Option<usize>>,
+    dither: Dither,
+    seed: u32,
+}
+
+impl PcmConverter {
+    /// Destination channels take source channels with the same index,
+    /// extra destination channels are silent.
+    pub fn with_formats(
+        src: &StreamBasicDesc,
+        dst: &StreamBasicDesc,
+    ) -> Result<Self, PcmConverterError> {
+        let src_layout = Layout::with_asbd(src)?;
+        let dst_layout = Layout::with_asbd(dst)?;
+        if src.sample_rate != dst.sample_rate {
+            return Err(PcmConverterError::SampleRate {
+                src: src.sample_rate,
+                dst: dst.sample_rate,
+            });
+        }
+        let map = (0..dst_layout.channels)
+            .map(|i| (i < src_layout.channels).then_some(i))
+            .collect();
+        Ok(Self {
+            src: src_layout,
+            dst: dst_layout,
+            map,
+            dither: Dither::None,
+            seed: 0x9e37_79b9,
+        })
+    }
+
+    pub fn with_dither(mut self, val: Dither) -> Self {
+        self.dither = val;
+        self
+    }
+
+    /// Destination channel `i` takes source channel `map[i]` or silence.
+    pub fn with_channel_map(mut self, map: &[Option<usize>]) -> Result<Self, PcmConverterError> {
+        if map.len() != self.dst.channels || map.iter().flatten().any(|&i| i >= self.src.channels) {
+            return Err(PcmConverterError::ChannelMap);
+        }
+        self.map.clear();
+        self.map.extend_from_slice(map);
+        Ok(self)
+    }
+
+    /// Destination channels take source channels with the same label,
+    /// labels missing in the source are silent.
+    pub fn with_channel_labels(
+        self,
+        src: &[ChannelLabel],
+        dst: &[ChannelLabel],
+    ) -> Result<Self, PcmConverterError> {
+        if src.len() != self.src.channels {
+            return Err(PcmConverterError::ChannelMap);
+        }
+        let map: Vec<_> = dst
+            .iter()
+            .map(|label| src.iter().position(|l| l == label))
+            .collect();
+        self.with_channel_map(&map)
+    }
+
+    /// Channels are ordered by bit, as in WAVE files.
+    pub fn with_channel_bitmaps(
+        self,
+        src: ChannelBitmap,
+        dst: ChannelBitmap,
+    ) -> Result<Self, PcmConverterError> {
+        let src: Vec<_> = src.labels().collect();
+        let dst: Vec<_> = dst.labels().collect();
+        self.with_channel_labels(&src, &dst)
+    }
+
+    pub fn with_channel_layout_tags(
+        self,
+        src: ChannelLayoutTag,
+        dst: ChannelLayoutTag,
+    ) -> Result<Self, PcmConverterError> {
+        let src = src
+            .labels()
+            .ok_or(PcmConverterError::ChannelLayoutTag(src))?;
+        let dst = dst
+            .labels()
+            .ok_or(PcmConverterError::ChannelLayoutTag(dst))?;
+        self.with_channel_labels(&src, &dst)
+    }
+
+    /// Number of buffers expected for source format.
+    pub fn src_buf_count(&self) -> usize {
+        self.src.buf_count()
+    }
+
+    /// Number of buffers expected for destination format.
+    pub fn dst_buf_count(&self) -> usize {
+        self.dst.buf_count()
+    }
+
+    /// Converts `frames` frames from `src` buffers to `dst` buffers.
+    ///
+    /// Interleaved formats use one buffer, non-interleaved use one buffer per channel.
+    pub fn convert(
+        &mut self,
+        frames: usize,
+        src: &[&[u8]],
+        dst: &mut [&mut [u8]],
+    ) -> Result<(), PcmConverterError> {
+        self.src.check(frames, src.iter().map(|b| b.len()))?;
+        self.dst.check(frames, dst.iter().map(|b| b.len()))?;
+
+        let identity = self.src.channels == self.dst.channels
+            && self.map.iter().enumerate().all(|(i, m)| *m == Some(i));
+        if identity && self.src.codec == self.dst.codec {
+            if self.src.interleaved == self.dst.interleaved
+                && self.src.bytes_per_frame == self.dst.bytes_per_frame
+            {
+                let len = frames * self.src.bytes_per_frame;
+                for (s, d) in src.iter().zip(dst.iter_mut()) {
+                    d[..len].copy_from_slice(&s[..len]);
+                }
+                return Ok(());
+            }
+            if self.src.codec == Codec::F32(NATIVE_BIG_ENDIAN)
+                && self.src.channels == 2
+                && self.src.is_packed()
+                && self.dst.is_packed()
+            {
+                if self.dst.interleaved {
+                    interleave_f32x2(frames, src[0], src[1], dst[0]);
+                } else {
+                    let [l, r] = dst else { unreachable!() };
+                    deinterleave_f32x2(frames, src[0], l, r);
+                }
+                return Ok(());
+            }
+        }
+
+        match (self.src.codec, self.dst.codec) {
+            (Codec::Int(a), Codec::Int(b)) if a.same_samples(&b) => {
+                self.copy_by_size(frames, src, dst, a.big != b.big)
+            }
+            (Codec::F32(a), Codec::F32(b)) => self.copy::<4>(frames, src, dst, a != b),
+            (Codec::F64(a), Codec::F64(b)) => self.copy::<8>(frames, src, dst, a != b),
+            (Codec::Int(c), _) => match c.size {
+                1 => self.convert_to(Int::<1>(c), frames, src, dst),
+                2 => self.convert_to(Int::<2>(c), frames, src, dst),
+                3 => self.convert_to(Int::<3>(c), frames, src, dst),
+                _ => self.convert_to(Int::<4>(c), frames, src, dst),
+            },
+            (Codec::F32(big), _) => self.convert_to(F32(big), frames, src, dst),
+            (Codec::F64(big), _) => self.convert_to(F64(big), frames, src, dst),
+        }
+        Ok(())
+    }
+
+    /// Converts `frames` frames and sets sizes of `dst` buffers.
+    pub fn convert_buf_list<const I: usize, const O: usize>(
+        &mut self,
+        frames: usize,
+        src: &BufList<I>,
+        dst: &mut BufList<O>,
+    ) -> Result<(), PcmConverterError> {
+        let src_bufs: [&[u8]; I] = std::array::from_fn(|i| {
+            let buf = &src.buffers[i];
+            if buf.data.is_null() {
+                return &[][..];
+            }
+            unsafe { std::slice::from_raw_parts(buf.data, buf.data_bytes_size as usize) }
+        });
+        let mut dst_bufs: [&mut [u8]; O] = std::array::from_fn(|i| {
+            let buf = &dst.buffers[i];
+            if buf.data.is_null() {
+                return &mut [][..];
+            }
+            unsafe { std::slice::from_raw_parts_mut(buf.data, buf.data_bytes_size as usize) }
+        });
+        self.convert(frames, &src_bufs, &mut dst_bufs)?;
+
+        let size = (frames * self.dst.bytes_per_frame) as u32;
+        let channels = if self.dst.interleaved {
+            self.dst.channels as u32
+        } else {
+            1
+        };
+        for buf in dst.buffers.iter_mut() {
+            buf.data_bytes_size = size;
+            buf.number_channels = channels;
+        }
+        Ok(())
+    }
+
+    fn copy_by_size(&self, frames: usize, src: &[&[u8]], dst: &mut [&mut [u8]], swap: bool) {
+        match self.src.size {
+            1 => self.copy::<1>(frames, src, dst, swap),
+            2 => self.copy::<2>(frames, src, dst, swap),
+            3 => self.copy::<3>(frames, src, dst, swap),
+            _ => self.copy::<4>(frames, src, dst, swap),
+        }
+    }
+
+    fn copy<const N: usize>(
+        &self,
+        frames: usize,
+        src: &[&[u8]],
+        dst: &mut [&mut [u8]],
+        swap: bool,
+    ) {
+        let (ss, ds) = (self.src.bytes_per_frame, self.dst.bytes_per_frame);
+        for (ch, m) in self.map.iter().enumerate() {
+            let (db, doff) = self.dst.loc(ch);
+            let d = &mut dst[db][doff..];
+            let Some(sc) = m else {
+                self.dst.silence(frames, d);
+                continue;
+            };
+            let (sb, soff) = self.src.loc(*sc);
+            let s = &src[sb][soff..];
+            for f in 0..frames {
+                let mut sample: [u8; N] = s[f * ss..][..N].try_into().unwrap();
+                if swap {
+                    sample.reverse();
+                }
+                d[f * ds..][..N].copy_from_slice(&sample);
+            }
+        }
+    }
+
+    fn convert_to<R: Sample>(&mut self, r: R, frames: usize, src: &[&[u8]], dst: &mut [&mut [u8]]) {
+        match self.dst.codec {
+            Codec::Int(c) => match c.size {
+                1 => self.run(r, Int::<1>(c), frames, src, dst),
+                2 => self.run(r, Int::<2>(c), frames, src, dst),
+                3 => self.run(r, Int::<3>(c), frames, src, dst),
+                _ => self.run(r, Int::<4>(c), frames, src, dst),
+            },
+            Codec::F32(big) => self.run(r, F32(big), frames, src, dst),
+            Codec::F64(big) => self.run(r, F64(big), frames, src, dst),
+        }
+    }
+
+    fn run<R: Sample, W: Sample>(
+        &mut self,
+        r: R,
+        w: W,
+        frames: usize,
+        src: &[&[u8]],
+        dst: &mut [&mut [u8]],
+    ) {
+        let dither = self.dither == Dither::Tpdf
+            && match (self.src.codec, self.dst.codec) {
+                (Codec::Int(a), Codec::Int(b)) => a.bits > b.bits,
+                (_, Codec::Int(_)) => true,
+                _ => false,
+            };
+        let (ss, ds) = (self.src.bytes_per_frame, self.dst.bytes_per_frame);
+        let mut seed = self.seed;
+        for (ch, m) in self.map.iter().enumerate() {
+            let (db, doff) = self.dst.loc(ch);
+            let d = &mut dst[db][doff..];
+            let Some(sc) = m else {
+                self.dst.silence(frames, d);
+                continue;
+            };
+            let (sb, soff) = self.src.loc(*sc);
+            let s = &src[sb][soff..];
+            if dither {
+                for f in 0..frames {
+                    let noise = uniform(&mut seed) - uniform(&mut seed);
+                    w.write(r.read(&s[f * ss..]), noise, &mut d[f * ds..]);
+                }
+            } else {
+                for f in 0..frames {
+                    w.write(r.read(&s[f * ss..]), 0.0, &mut d[f * ds..]);
+                }
+            }
+        }
+        self.seed = seed;
+    }
+}
+
+const NATIVE_BIG_ENDIAN: bool = cfg!(target_endian = "big");
+
+/// xorshift32 mapped to [0, 1).
+#[inline]
+fn uniform(seed: &mut u32) -> f64 {
+    let mut x = *seed;
+    x ^= x << 13;
+    x ^= x >> 17;
+    x ^= x << 5;
+    *seed = x;
+    (x >> 8) as f64 * (1.0 / (1u32 << 24) as f64)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct IntCodec {
+    size: usize,
+    bits: u32,
+    /// Left shift of container bits to put the sample into the top bits of u32.
+    up: u32,
+    /// Sign bit flip of unsigned samples.
+    flip: u32,
+    big: bool,
+    /// 2^(bits - 1)
+    full: f64,
+}
+
+impl IntCodec {
+    fn same_samples(&self, other: &Self) -> bool {
+        Self {
+            big: other.big,
+            ..*self
+        } == *other
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Codec {
+    Int(IntCodec),
+    F32(bool),
+    F64(bool),
+}
+
+#[derive(Debug, Clone)]
+struct Layout {
+    codec: Codec,
+    size: usize,
+    channels: usize,
+    interleaved: bool,
+    bytes_per_frame: usize,
+}
+
+impl Layout {
+    fn with_asbd(asbd: &StreamBasicDesc) -> Result<Self, PcmConverterError> {
+        asbd.validate().map_err(PcmConverterError::Format)?;
+        if !asbd.is_lpcm() || asbd.sample_fraction_bits() != 0 {
+            return Err(PcmConverterError::UnsupportedFormat);
+        }
+        let flags = asbd.format_flags;
+        let big = flags.contains(FormatFlags::IS_BIG_ENDIAN);
+        let size = asbd.bytes_per_sample() as usize;
+        let bits = asbd.bits_per_channel;
+        let codec = if flags.contains(FormatFlags::IS_FLOAT) {
+            match bits {
+                32 => Codec::F32(big),
+                64 => Codec::F64(big),
+                _ => return Err(PcmConverterError::UnsupportedFormat),
+            }
+        } else {
+            if size > 4 {
+                return Err(PcmConverterError::UnsupportedFormat);
+            }
+            let up = if flags.contains(FormatFlags::IS_ALIGNED_HIGH) {
+                32 - size as u32 * 8
+            } else {
+                32 - bits
+            };
+            let flip = if flags.contains(FormatFlags::IS_SIGNED_INTEGER) {
+                0
+            } else {
+                1 << 31
+            };
+            Codec::Int(IntCodec {
+                size,
+                bits,
+                up,
+                flip,
+                big,
+                full: (1u64 << (bits - 1)) as f64,
+            })
+        };
+        Ok(Self {
+            codec,
+            size,
+            channels: asbd.channels_per_frame as usize,
+            interleaved: asbd.is_interleaved(),
+            bytes_per_frame: asbd.bytes_per_frame as usize,
+        })
+    }
+
+    fn buf_count(&self) -> usize {
+        if self.interleaved { 1 } else { self.channels }
+    }
+
+    /// Frames have no padding between samples.
+    fn is_packed(&self) -> bool {
+        let samples = if self.interleaved { self.channels } else { 1 };
+        self.bytes_per_frame == samples * self.size
+    }
+
+    /// Buffer index and byte offset of the first sample of the channel.
+    fn loc(&self, channel: usize) -> (usize, usize) {
+        if self.interleaved {
+            (0, channel * self.size)
+        } else {
+            (channel, 0)
+        }
+    }
+
+    fn check(
+        &self,
+        frames: usize,
+        lens: impl ExactSizeIterator<Item = usize>,
+    ) -> Result<(), PcmConverterError> {
+        let expected = self.buf_count();
+        if lens.len() != expected {
+            return Err(PcmConverterError::BufCount {
+                expected,
+                actual: lens.len(),
+            });
+        }
+        let expected = frames * self.bytes_per_frame;
+        for (index, actual) in lens.enumerate() {
+            if actual < expected {
+                return Err(PcmConverterError::BufSize {
+                    index,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn silence(&self, frames: usize, buf: &mut [u8]) {
+        let mut sample = [0u8; 8];
+        if let Codec::Int(c) = self.codec {
+            let raw = c.flip >> c.up;
+            for i in 0..c.size {
+                sample[if c.big { c.size - 1 - i } else { i }] = (raw >> (8 * i)) as u8;
+            }
+        }
+        for f in 0..frames {
+            buf[f * self.bytes_per_frame..][..self.size].copy_from_slice(&sample[..self.size]);
+        }
+    }
+}
+
+trait Sample: Copy {
+    /// Reads sample at the start of `buf` as [-1, 1).
+    fn read(&self, buf: &[u8]) -> f64;
+
+    /// Writes sample at the start of `buf`, `noise` is in LSB and ignored by floats.
+    fn write(&self, val: f64, noise: f64, buf: &mut [u8]);
+}
+
+#[derive(Clone, Copy)]
+struct Int<const N: usize>(IntCodec);
+
+impl<const N: usize> Sample for Int<N> {
+    #[inline(always)]
+    fn read(&self, buf: &[u8]) -> f64 {
+        let c = &self.0;
+        let buf = &buf[..N];
+        let mut raw = 0u32;
+        for i in 0..N {
+            raw = (raw << 8) | buf[if c.big { i } else { N - 1 - i }] as u32;
+        }
+        let val = ((raw << c.up) ^ c.flip) as i32 >> (32 - c.bits);
+        val as f64 / c.full
+    }
+
+    #[inline(always)]
+    fn write(&self, val: f64, noise: f64, buf: &mut [u8]) {
+        let c = &self.0;
+        let val = (val * c.full + noise).round().clamp(-c.full, c.full - 1.0) as i32;
+        let raw = (((val << (32 - c.bits)) as u32) ^ c.flip) >> c.up;
+        let buf = &mut buf[..N];
+        for i in 0..N {
+            buf[if c.big { N - 1 - i } else { i }] = (raw >> (8 * i)) as u8;
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct F32(bool);
+
+impl Sample for F32 {
+    #[inline(always)]
+    fn read(&self, buf: &[u8]) -> f64 {
+        let bytes = buf[..4].try_into().unwrap();
+        if self.0 {
+            f32::from_be_bytes(bytes) as f64
+        } else {
+            f32::from_le_bytes(bytes) as f64
+        }
+    }
+
+    #[inline(always)]
+    fn write(&self, val: f64, _noise: f64, buf: &mut [u8]) {
+        let val = val as f32;
+        buf[..4].copy_from_slice(&if self.0 {
+            val.to_be_bytes()
+        } else {
+            val.to_le_bytes()
+        });
+    }
+}
+
+#[derive(Clone, Copy)]
+struct F64(bool);
+
+impl Sample for F64 {
+    #[inline(always)]
+    fn read(&self, buf: &[u8]) -> f64 {
+        let bytes = buf[..8].try_into().unwrap();
+        if self.0 {
+            f64::from_be_bytes(bytes)
+        } else {
+            f64::from_le_bytes(bytes)
+        }
+    }
+
+    #[inline(always)]
+    fn write(&self, val: f64, _noise: f64, buf: &mut [u8]) {
+        buf[..8].copy_from_slice(&if self.0 {
+            val.to_be_bytes()
+        } else {
+            val.to_le_bytes()
+        });
+    }
+}
+
+fn interleave_f32x2(frames: usize, l: &[u8], r: &[u8], lr: &mut [u8]) {
+    let (l, r, lr) = (&l[..frames * 4], &r[..frames * 4], &mut lr[..frames * 8]);
+    let aligned = [l.as_ptr(), r.as_ptr(), lr.as_ptr()]
+        .iter()
+        .all(|p| p.align_offset(4) == 0);
+    let done = if aligned {
+        unsafe {
+            simd::interleave(
+                l.as_ptr() as _,
+                r.as_ptr() as _,
+                lr.as_mut_ptr() as _,
+                frames,
+            )
+        }
+    } else {
+        0
+    };
+    for f in done..frames {
+        lr[f * 8..][..4].copy_from_slice(&l[f * 4..][..4]);
+        lr[f * 8 + 4..][..4].copy_from_slice(&r[f * 4..][..4]);
+    }
+}
+
+fn deinterleave_f32x2(frames: usize, lr: &[u8], l: &mut [u8], r: &mut [u8]) {
+    let (lr, l, r) = (
+        &lr[..frames * 8],
+        &mut l[..frames * 4],
+        &mut r[..frames * 4],
+    );
+    let aligned = [lr.as_ptr(), l.as_ptr(), r.as_ptr()]
+        .iter()
+        .all(|p| p.align_offset(4) == 0);
+    let done = if aligned {
+        unsafe {
+            simd::deinterleave(
+                lr.as_ptr() as _,
+                l.as_mut_ptr() as _,
+                r.as_mut_ptr() as _,
+                frames,
+            )
+        }
+    } else {
+        0
+    };
+    for f in done..frames {
+        l[f * 4..][..4].copy_from_slice(&lr[f * 8..][..4]);
+        r[f * 4..][..4].copy_from_slice(&lr[f * 8 + 4..][..4]);
+    }
+}
+
+/// Four frames per iteration, returns number of processed frames.
+mod simd {
+    #[cfg(target_arch = "aarch64")]
+    pub unsafe fn interleave(l: *const f32, r: *const f32, lr: *mut f32, frames: usize) -> usize {
+        use std::arch::aarch64::*;
+        let mut i = 0;
+        while i + 4 <= frames {
+            unsafe {
+                let v = float32x4x2_t(vld1q_f32(l.add(i)), vld1q_f32(r.add(i)));
+                vst2q_f32(lr.add(i * 2), v);
+            }
+            i += 4;
+        }
+        i
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    pub unsafe fn deinterleave(lr: *const f32, l: *mut f32, r: *mut f32, frames: usize) -> usize {
+        use std::arch::aarch64::*;
+        let mut i = 0;
+        while i + 4 <= frames {
+            unsafe {
+                let v = vld2q_f32(lr.add(i * 2));
+                vst1q_f32(l.add(i), v.0);
+                vst1q_f32(r.add(i), v.1);
+            }
+            i += 4;
+        }
+        i
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    pub unsafe fn interleave(l: *const f32, r: *const f32, lr: *mut f32, frames: usize) -> usize {
+        use std::arch::x86_64::*;
+        let mut i = 0;
+        while i + 4 <= frames {
+            unsafe {
+                let a = _mm_loadu_ps(l.add(i));
+                let b = _mm_loadu_ps(r.add(i));
+                _mm_storeu_ps(lr.add(i * 2), _mm_unpacklo_ps(a, b));
+                _mm_storeu_ps(lr.add(i * 2 + 4), _mm_unpackhi_ps(a, b));
+            }
+            i += 4;
+        }
+        i
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    pub unsafe fn deinterleave(lr: *const f32, l: *mut f32, r: *mut f32, frames: usize) -> usize {
+        use std::arch::x86_64::*;
+        let mut i = 0;
+        while i + 4 <= frames {
+            unsafe {
+                let a = _mm_loadu_ps(lr.add(i * 2));
+                let b = _mm_loadu_ps(lr.add(i * 2 + 4));
+                _mm_storeu_ps(l.add(i), _mm_shuffle_ps::<0b10_00_10_00>(a, b));
+                _mm_storeu_ps(r.add(i), _mm_shuffle_ps::<0b11_01_11_01>(a, b));
+            }
+            i += 4;
+        }
+        i
+    }
+
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "x86_64")))]
+    pub unsafe fn interleave(
+        _l: *const f32,
+        _r: *const f32,
+        _lr: *mut f32,
+        _frames: usize,
+    ) -> usize {
+        0
+    }
+
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "x86_64")))]
+    pub unsafe fn deinterleave(
+        _lr: *const f32,
+        _l: *mut f32,
+        _r: *mut f32,
+        _frames: usize,
+    ) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cat::audio;
+
+    fn le<T: Copy, const N: usize>(vals: &[T], f: fn(T) -> [u8; N]) -> Vec<u8> {
+        vals.iter().flat_map(|v| f(*v)).collect()
+    }
+
+    #[test]
+    fn interleave() {
+        let src = audio::StreamBasicDesc::common_f32(48_000.0, 2, false);
+        let dst = audio::StreamBasicDesc::common_f32(48_000.0, 2, true);
+
+        let frames = 13;
+        let l: Vec<f32> = (0..frames).map(|i| i as f32).collect();
+        let r: Vec<f32> = (0..frames).map(|i| -(i as f32)).collect();
+        let l = le(&l, f32::to_le_bytes);
+        let r = le(&r, f32::to_le_bytes);
+        let mut lr = vec![0u8; frames * 8];
+
+        let mut converter = audio::PcmConverter::with_formats(&src, &dst).unwrap();
+        converter
+            .convert(frames, &[&l, &r], &mut [&mut lr])
+            .unwrap();
+        for f in 0..frames {
+            assert_eq!(lr[f * 8..][..4], l[f * 4..][..4]);
+            assert_eq!(lr[f * 8 + 4..][..4], r[f * 4..][..4]);
+        }
+
+        let mut l2 = vec![0u8; frames * 4];
+        let mut r2 = vec![0u8; frames * 4];
+        let mut converter = audio::PcmConverter::with_formats(&dst, &src).unwrap();
+        converter
+            .convert(frames, &[&lr], &mut [&mut l2, &mut r2])
+            .unwrap();
+        assert_eq!(l, l2);
+        assert_eq!(r, r2);
+
+        let mut list_a = audio::BufList::<2>::new();
+        let mut list_b = audio::BufList::<1>::new();
+        list_a.buffers[0].data = l.as_ptr() as _;
+        list_a.buffers[0].data_bytes_size = l.len() as u32;
+        list_a.buffers[1].data = r.as_ptr() as _;
+        list_a.buffers[1].data_bytes_size = r.len() as u32;
+        let mut lr2 = vec![0u8; frames * 8];
+        list_b.buffers[0].data = lr2.as_mut_ptr();
+        list_b.buffers[0].data_bytes_size = lr2.len() as u32;
+
+        let mut converter = audio::PcmConverter::with_formats(&src, &dst).unwrap();
+        converter
+            .convert_buf_list(frames - 1, &list_a, &mut list_b)
+            .unwrap();
+        assert_eq!(list_b.buffers[0].data_bytes_size, (frames - 1) as u32 * 8);
+        assert_eq!(list_b.buffers[0].number_channels, 2);
+        assert_eq!(lr[..(frames - 1) * 8], lr2[..(frames - 1) * 8]);
+    }
+
+    #[test]
+    fn samples() {
+        use audio::StreamBasicDesc as Asbd;
+
+        let i16s = le(&[0i16, 0x4000, -0x8000, 0x7fff], i16::to_le_bytes);
+        let f32s = Asbd::common_f32(44_100.0, 1, true);
+        let mut out = vec![0u8; 16];
+        audio::PcmConverter::with_formats(&Asbd::common_i16(44_100.0, 1, true), &f32s)
+            .unwrap()
+            .convert(4, &[&i16s], &mut [&mut out])
+            .unwrap();
+        assert_eq!(
+            out,
+            le(&[0.0f32, 0.5, -1.0, 32767.0 / 32768.0], f32::to_le_bytes)
+        );
+
+        // clipping
+        let floats = le(&[1.0f32, -2.0, 0.25, f32::NAN], f32::to_le_bytes);
+        let mut out = vec![0u8; 8];
+        audio::PcmConverter::with_formats(&f32s, &Asbd::common_i16(44_100.0, 1, true))
+            .unwrap()
+            .convert(4, &[&floats], &mut [&mut out])
+            .unwrap();
+        assert_eq!(out, le(&[32767i16, -32768, 0x2000, 0], i16::to_le_bytes));
+
+        // big endian 24-bit packed to 24-bit aligned high in 32-bit and back
+        let be24 = [0x12u8, 0x34, 0x56, 0xff, 0xff, 0xfe];
+        let src = Asbd::i24_packed(48_000.0, 2, true).with_big_endian(true);
+        let dst = Asbd::i24_aligned_high(48_000.0, 2, true);
+        let mut out = vec![0u8; 8];
+        audio::PcmConverter::with_formats(&src, &dst)
+            .unwrap()
+            .convert(1, &[&be24], &mut [&mut out])
+            .unwrap();
+        assert_eq!(out, le(&[0x1234_5600i32, -0x200], i32::to_le_bytes));
+        let mut back = vec![0u8; 6];
+        audio::PcmConverter::with_formats(&dst, &src)
+            .unwrap()
+            .convert(1, &[&out], &mut [&mut back])
+            .unwrap();
+        assert_eq!(back, be24);
+
+        // f64 to i32 is exact
+        let f64s = le(&[0.5f64, -1.0, 123.0 / 2147483648.0], f64::to_le_bytes);
+        let mut out = vec![0u8; 12];
+        audio::PcmConverter::with_formats(
+            &Asbd::common_f64(48_000.0, 1, true),
+            &Asbd::common_i32(48_000.0, 1, true),
+        )
+        .unwrap()
+        .convert(3, &[&f64s], &mut [&mut out])
+        .unwrap();
+        assert_eq!(out, le(&[0x4000_0000i32, i32::MIN, 123], i32::to_le_bytes));
+
+        // unsigned 8-bit
+        let mut u8s = Asbd::lpcm(48_000.0, 1, 8, 8, false, false, false);
+        u8s.format_flags = audio::FormatFlags::IS_PACKED;
+        let mut out = vec![0u8; 3];
+        audio::PcmConverter::with_formats(&Asbd::common_i16(48_000.0, 1, true), &u8s)
+            .unwrap()
+            .convert(3, &[&i16s[..6]], &mut [&mut out])
+            .unwrap();
+        assert_eq!(out, [0x80, 0xc0, 0x00]);
+
+        // endian swap
+        let src = Asbd::common_i16(48_000.0, 1, true);
+        let dst = src.with_big_endian(true);
+        let mut out = vec![0u8; 8];
+        audio::PcmConverter::with_formats(&src, &dst)
+            .unwrap()
+            .convert(4, &[&i16s], &mut [&mut out])
+            .unwrap();
+        assert_eq!(out, le(&[0i16, 0x4000, -0x8000, 0x7fff], i16::to_be_bytes));
+    }
+
+    #[test]
+    fn dither() {
+        use audio::StreamBasicDesc as Asbd;
+
+        let frames = 1000;
+        let zeros = vec![0u8; frames * 4];
+        let mut out = vec![0u8; frames * 2];
+        let mut converter = audio::PcmConverter::with_formats(
+            &Asbd::common_f32(48_000.0, 1, true),
+            &Asbd::common_i16(48_000.0, 1, true),
+        )
+        .unwrap()
+        .with_dither(audio::Dither::Tpdf);
+        converter
+            .convert(frames, &[&zeros], &mut [&mut out])
+            .unwrap();
+
+        let samples: Vec<i16> = out
+            .chunks(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]))
+            .collect();
+        assert!(samples.iter().all(|s| (-1..=1).contains(s)));
+        assert!(samples.iter().any(|s| *s != 0));
+        let sum: i32 = samples.iter().map(|s| *s as i32).sum();
+        assert!(sum.abs() < 100);
+    }
+
+    #[test]
+    fn channels() {
+        use audio::ChannelLayoutTag as Tag;
+        use audio::StreamBasicDesc as Asbd;
+
+        // L R C LFE Ls Rs
+        let src = le(&[1i16, 2, 3, 4, 5, 6], i16::to_le_bytes);
+        let fmt6 = Asbd::common_i16(48_000.0, 6, true);
+        let fmt2 = Asbd::common_i16(48_000.0, 2, true);
+
+        let mut out = vec![0u8; 12];
+        audio::PcmConverter::with_formats(&fmt6, &fmt6)
+            .unwrap()
+            .with_channel_layout_tags(Tag::MPEG_5_1_A, Tag::MPEG_5_1_D)
+            .unwrap()
+            .convert(1, &[&src], &mut [&mut out])
+            .unwrap();
+        assert_eq!(out, le(&[3i16, 1, 2, 5, 6, 4], i16::to_le_bytes));
+
+        let mut out = vec![0u8; 4];
+        audio::PcmConverter::with_formats(&fmt6, &fmt2)
+            .unwrap()
+            .with_channel_bitmaps(
+                audio::ChannelBitmap(0b11_1111),
+                audio::ChannelBitmap(
+                    audio::ChannelBitmap::LEFT_SURROUND.0 | audio::ChannelBitmap::RIGHT_SURROUND.0,
+                ),
+            )
+            .unwrap()
+            .convert(1, &[&src], &mut [&mut out])
+            .unwrap();
+        assert_eq!(out, le(&[5i16, 6], i16::to_le_bytes));
+
+        // stereo to 5.1 leaves missing channels silent
+        let mut out = vec![0xffu8; 12];
+        audio::PcmConverter::with_formats(&fmt2, &fmt6.with_interleaved(false))
+            .unwrap()
+            .with_channel_layout_tags(Tag::STEREO, Tag::MPEG_5_1_A)
+            .unwrap()
+            .convert(1, &[&src[..4]], &mut out.chunks_mut(2).collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(out, le(&[1i16, 2, 0, 0, 0, 0], i16::to_le_bytes));
+
+        assert_eq!(
+            audio::PcmConverter::with_formats(&fmt6, &fmt2)
+                .unwrap()
+                .with_channel_map(&[Some(0), Some(6)])
+                .err(),
+            Some(audio::PcmConverterError::ChannelMap)
+        );
+    }
+
+    #[test]
+    fn downmix() {
+        use audio::StreamBasicDesc as Asbd;
+
+        let frames = 5;
+        for (from, to) in [(4, 2), (2, 1)] {
+            // channel c of frame f is f * 10 + c
+            let samples: Vec<f32> = (0..frames * from)
+                .map(|i| (i / from * 10 + i % from) as f32)
+                .collect();
+            let interleaved = le(&samples, f32::to_le_bytes);
+            let planar: Vec<_> = (0..from)
+                .map(|c| {
+                    le(
+                        &samples[c..]
+                            .iter()
+                            .step_by(from)
+                            .copied()
+                            .collect::<Vec<_>>(),
+                        f32::to_le_bytes,
+                    )
+                })
+                .collect();
+            for src_interleaved in [true, false] {
+                for dst_interleaved in [true, false] {
+                    let src = Asbd::common_f32(48_000.0, from as u32, src_interleaved);
+                    let dst = Asbd::common_f32(48_000.0, to as u32, dst_interleaved);
+                    let src_bufs: Vec<&[u8]> = if src_interleaved {
+                        vec![&interleaved]
+                    } else {
+                        planar.iter().map(|b| &b[..]).collect()
+                    };
+                    let mut out = vec![0xffu8; frames * to * 4];
+                    let mut dst_bufs: Vec<&mut [u8]> = if dst_interleaved {
+                        vec![&mut out]
+                    } else {
+                        out.chunks_mut(frames * 4).collect()
+                    };
+                    audio::PcmConverter::with_formats(&src, &dst)
+                        .unwrap()
+                        .convert(frames, &src_bufs, &mut dst_bufs)
+                        .unwrap();
+
+                    let out: Vec<f32> = out
+                        .chunks(4)
+                        .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+                        .collect();
+                    for f in 0..frames {
+                        for c in 0..to {
+                            let i = if dst_interleaved {
+                                f * to + c
+                            } else {
+                                c * frames + f
+                            };
+                            assert_eq!(
+                                out[i],
+                                (f * 10 + c) as f32,
+                                "{from} -> {to}, {src_interleaved} -> {dst_interleaved}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn errors() {
+        use audio::PcmConverterError as E;
+        use audio::StreamBasicDesc as Asbd;
+
+        let f32s = Asbd::common_f32(48_000.0, 2, true);
+        assert_eq!(
+            audio::PcmConverter::with_formats(&f32s, &Asbd::common_f32(44_100.0, 2, true)).err(),
+            Some(E::SampleRate {
+                src: 48_000.0,
+                dst: 44_100.0
+            })
+        );
+        assert_eq!(
+            audio::PcmConverter::with_formats(&Asbd::aac_lc(48_000.0, 2), &f32s).err(),
+            Some(E::UnsupportedFormat)
+        );
+
+        let mut converter = audio::PcmConverter::with_formats(&f32s, &f32s).unwrap();
+        let buf = [0u8; 16];
+        let mut out = [0u8; 8];
+        assert_eq!(
+            converter.convert(2, &[&buf], &mut [&mut out]),
+            Err(E::BufSize {
+                index: 0,
+                expected: 16,
+                actual: 8
+            })
+        );
+        assert_eq!(
+            converter.convert(1, &[&buf, &buf], &mut [&mut out]),
+            Err(E::BufCount {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+}
@@ -2,6 +2,7 @@ mod geometry;
 pub use geometry::Float;
 pub use geometry::Point;
 pub use geometry::Rect;
+pub use geometry::RectEdge;
 pub use geometry::Size;
 pub use geometry::Vector;
 
@@ -86,6 +87,7 @@ mod path;
 
 #[cfg(feature = "blocks")]
 pub use path::ApplyBlock as PathApplyBlock;
+pub use path::Data as PathData;
 pub use path::Element as PathElement;
 pub use path::ElementType as PathElementType;
 pub use path::LineCap;
@@ -93,6 +95,8 @@ pub use path::LineJoin;
 pub use path::Path;
 pub use path::PathApplierFn;
 pub use path::PathMut;
+pub use path::Segment as PathSegment;
+pub use path::SvgError as PathSvgError;
 
 #[cfg(target_os = "macos")]
 mod display_stream;
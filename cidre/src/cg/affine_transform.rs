@@ -1,3 +1,5 @@
+use std::ops::{Mul, MulAssign};
+
 use crate::cg;
 
 /// An affine transformation.
@@ -42,69 +44,180 @@ pub struct Components {
 }
 
 impl AffineTransform {
+    #[doc(alias = "CGAffineTransformIdentity")]
+    pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+    #[doc(alias = "CGAffineTransformMake")]
     #[inline]
+    pub const fn new(
+        a: cg::Float,
+        b: cg::Float,
+        c: cg::Float,
+        d: cg::Float,
+        tx: cg::Float,
+        ty: cg::Float,
+    ) -> Self {
+        Self { a, b, c, d, tx, ty }
+    }
+
+    /// scale * shear * rotation * translation
+    #[doc(alias = "CGAffineTransformMakeWithComponents")]
     pub fn with_components(components: Components) -> Self {
-        unsafe { CGAffineTransformMakeWithComponents(components) }
+        let Components {
+            scale,
+            horizontal_shear: sh,
+            rotation,
+            translation,
+        } = components;
+        let (sin, cos) = rotation.sin_cos();
+        Self::new(
+            scale.width * cos,
+            scale.width * sin,
+            scale.height * (sh * cos - sin),
+            scale.height * (sh * sin + cos),
+            translation.dx,
+            translation.dy,
+        )
     }
 
     #[inline]
-    pub fn identity() -> Self {
-        unsafe { CGAffineTransformIdentity }
+    pub const fn identity() -> Self {
+        Self::IDENTITY
     }
 
+    #[doc(alias = "CGAffineTransformMakeTranslation")]
     #[inline]
-    pub fn new_translation(tx: f64, ty: f64) -> Self {
-        unsafe { CGAffineTransformMakeTranslation(tx, ty) }
+    pub const fn new_translation(tx: cg::Float, ty: cg::Float) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, tx, ty)
     }
 
+    #[doc(alias = "CGAffineTransformMakeScale")]
     #[inline]
-    pub fn new_scale(sx: f64, sy: f64) -> Self {
-        unsafe { CGAffineTransformMakeScale(sx, sy) }
+    pub const fn new_scale(sx: cg::Float, sy: cg::Float) -> Self {
+        Self::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
     }
 
+    /// Rotation by `angle` radians, counterclockwise in y-up coordinates.
+    #[doc(alias = "CGAffineTransformMakeRotation")]
     #[inline]
-    pub fn new_rotation(angle: f64) -> Self {
-        unsafe { CGAffineTransformMakeRotation(angle) }
+    pub fn new_rotation(angle: cg::Float) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin, -sin, cos, 0.0, 0.0)
     }
 
+    #[doc(alias = "CGAffineTransformIsIdentity")]
     #[inline]
-    pub fn is_identity(&self) -> bool {
-        unsafe { CGAffineTransformIsIdentity(*self) }
+    pub const fn is_identity(&self) -> bool {
+        self.equal_to(&Self::IDENTITY)
     }
 
+    /// Translation applied before `self`.
+    #[doc(alias = "CGAffineTransformTranslate")]
     #[inline]
-    pub fn translate(&self, tx: f64, ty: f64) -> Self {
-        unsafe { CGAffineTransformTranslate(*self, tx, ty) }
+    pub const fn translate(&self, tx: cg::Float, ty: cg::Float) -> Self {
+        Self::new(
+            self.a,
+            self.b,
+            self.c,
+            self.d,
+            tx * self.a + ty * self.c + self.tx,
+            tx * self.b + ty * self.d + self.ty,
+        )
     }
 
+    /// Scale applied before `self`.
+    #[doc(alias = "CGAffineTransformScale")]
     #[inline]
-    pub fn scale(&self, sx: f64, sy: f64) -> Self {
-        unsafe { CGAffineTransformScale(*self, sx, sy) }
+    pub const fn scale(&self, sx: cg::Float, sy: cg::Float) -> Self {
+        Self::new(
+            sx * self.a,
+            sx * self.b,
+            sy * self.c,
+            sy * self.d,
+            self.tx,
+            self.ty,
+        )
     }
 
+    /// Rotation applied before `self`.
+    #[doc(alias = "CGAffineTransformRotate")]
     #[inline]
-    pub fn rotate(&self, angle: f64) -> Self {
-        unsafe { CGAffineTransformRotate(*self, angle) }
+    pub fn rotate(&self, angle: cg::Float) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(
+            cos * self.a + sin * self.c,
+            cos * self.b + sin * self.d,
+            cos * self.c - sin * self.a,
+            cos * self.d - sin * self.b,
+            self.tx,
+            self.ty,
+        )
     }
 
-    #[inline]
-    pub fn invert(&self) -> Self {
-        unsafe { CGAffineTransformInvert(*self) }
+    /// Inverted transform, `self` if it is not invertible.
+    #[doc(alias = "CGAffineTransformInvert")]
+    pub const fn invert(&self) -> Self {
+        let det = self.a * self.d - self.b * self.c;
+        if det == 0.0 {
+            return *self;
+        }
+        Self::new(
+            self.d / det,
+            -self.b / det,
+            -self.c / det,
+            self.a / det,
+            (self.c * self.ty - self.d * self.tx) / det,
+            (self.b * self.tx - self.a * self.ty) / det,
+        )
     }
 
-    #[inline]
-    pub fn concat(&self, other: &Self) -> Self {
-        unsafe { CGAffineTransformConcat(*self, *other) }
+    /// `self` followed by `other`.
+    #[doc(alias = "CGAffineTransformConcat")]
+    pub const fn concat(&self, other: &Self) -> Self {
+        Self::new(
+            self.a * other.a + self.b * other.c,
+            self.a * other.b + self.b * other.d,
+            self.c * other.a + self.d * other.c,
+            self.c * other.b + self.d * other.d,
+            self.tx * other.a + self.ty * other.c + other.tx,
+            self.tx * other.b + self.ty * other.d + other.ty,
+        )
     }
 
+    #[doc(alias = "CGAffineTransformEqualToTransform")]
     #[inline]
-    pub fn equal_to(&self, other: &Self) -> bool {
-        unsafe { CGAffineTransformEqualToTransform(*self, *other) }
+    pub const fn equal_to(&self, other: &Self) -> bool {
+        self.a == other.a
+            && self.b == other.b
+            && self.c == other.c
+            && self.d == other.d
+            && self.tx == other.tx
+            && self.ty == other.ty
     }
 
-    #[inline]
+    /// Components with positive x scale and rotation in `-pi..=pi`,
+    /// reflection goes to negative y scale.
+    #[doc(alias = "CGAffineTransformDecompose")]
     pub fn decompose(&self) -> Components {
-        unsafe { CGAffineTransformDecompose(*self) }
+        let sx = self.a.hypot(self.b);
+        let det = self.a * self.d - self.b * self.c;
+        let sy = det / sx;
+        Components {
+            scale: cg::Size::new(sx, sy),
+            horizontal_shear: (self.a * self.c + self.b * self.d) / det,
+            rotation: self.b.atan2(self.a),
+            translation: cg::Vector {
+                dx: self.tx,
+                dy: self.ty,
+            },
+        }
+    }
+}
+
+impl Default for AffineTransform {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
     }
 }
 
@@ -122,9 +235,45 @@ impl PartialEq for AffineTransform {
     }
 }
 
+/// `self` followed by `rhs`, same as [`AffineTransform::concat`].
+impl Mul for AffineTransform {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.concat(&rhs)
+    }
+}
+
+impl MulAssign for AffineTransform {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.concat(&rhs)
+    }
+}
+
+impl Mul<AffineTransform> for cg::Point {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: AffineTransform) -> Self {
+        self.apply_affine_transform(&rhs)
+    }
+}
+
+impl Mul<AffineTransform> for cg::Size {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: AffineTransform) -> Self {
+        self.apply_affine_transform(&rhs)
+    }
+}
+
 impl cg::Point {
+    #[doc(alias = "CGPointApplyAffineTransform")]
     #[inline]
-    pub fn apply_affine_transform(&self, t: &AffineTransform) -> Self {
+    pub const fn apply_affine_transform(&self, t: &AffineTransform) -> Self {
         cg::Point {
             x: t.a * self.x + t.c * self.y + t.tx,
             y: t.b * self.x + t.d * self.y + t.ty,
@@ -133,8 +282,9 @@ impl cg::Point {
 }
 
 impl cg::Size {
+    #[doc(alias = "CGSizeApplyAffineTransform")]
     #[inline]
-    pub fn apply_affine_transform(&self, t: &AffineTransform) -> Self {
+    pub const fn apply_affine_transform(&self, t: &AffineTransform) -> Self {
         cg::Size {
             width: t.a * self.width + t.c * self.height,
             height: t.b * self.width + t.d * self.height,
@@ -143,27 +293,190 @@ impl cg::Size {
 }
 
 impl cg::Rect {
-    #[inline]
-    pub fn apply_affine_transform(&self, t: &AffineTransform) -> Self {
-        unsafe { CGRectApplyAffineTransform(*self, *t) }
+    /// Bounding box of transformed corners of standardized `self`.
+    #[doc(alias = "CGRectApplyAffineTransform")]
+    pub const fn apply_affine_transform(&self, t: &AffineTransform) -> Self {
+        if self.is_null() {
+            return *self;
+        }
+        let r = self.standardize();
+        let (x0, y0) = (r.origin.x, r.origin.y);
+        let (x1, y1) = (x0 + r.size.width, y0 + r.size.height);
+        let corners = [
+            cg::Point::new(x0, y0).apply_affine_transform(t),
+            cg::Point::new(x1, y0).apply_affine_transform(t),
+            cg::Point::new(x0, y1).apply_affine_transform(t),
+            cg::Point::new(x1, y1).apply_affine_transform(t),
+        ];
+        let mut min = corners[0];
+        let mut max = corners[0];
+        let mut i = 1;
+        while i < corners.len() {
+            let p = corners[i];
+            if p.x < min.x {
+                min.x = p.x;
+            }
+            if p.x > max.x {
+                max.x = p.x;
+            }
+            if p.y < min.y {
+                min.y = p.y;
+            }
+            if p.y > max.y {
+                max.y = p.y;
+            }
+            i += 1;
+        }
+        cg::Rect::new(min.x, min.y, max.x - min.x, max.y - min.y)
     }
 }
 
+// CoreGraphics implementations, to check that results match
+#[cfg(all(test, target_vendor = "apple"))]
 unsafe extern "C-unwind" {
     static CGAffineTransformIdentity: AffineTransform;
 
     fn CGAffineTransformMakeTranslation(tx: f64, ty: f64) -> AffineTransform;
     fn CGAffineTransformMakeScale(sx: f64, sy: f64) -> AffineTransform;
     fn CGAffineTransformMakeRotation(angle: f64) -> AffineTransform;
-    fn CGAffineTransformIsIdentity(t: AffineTransform) -> bool;
     fn CGAffineTransformTranslate(t: AffineTransform, tx: f64, ty: f64) -> AffineTransform;
     fn CGAffineTransformScale(t: AffineTransform, sx: f64, sy: f64) -> AffineTransform;
     fn CGAffineTransformRotate(t: AffineTransform, angle: f64) -> AffineTransform;
     fn CGAffineTransformInvert(t: AffineTransform) -> AffineTransform;
     fn CGAffineTransformConcat(t: AffineTransform, other: AffineTransform) -> AffineTransform;
-    fn CGAffineTransformEqualToTransform(t: AffineTransform, other: AffineTransform) -> bool;
     fn CGRectApplyAffineTransform(rect: cg::Rect, t: AffineTransform) -> cg::Rect;
     fn CGAffineTransformDecompose(t: AffineTransform) -> Components;
     fn CGAffineTransformMakeWithComponents(components: Components) -> AffineTransform;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use super::*;
+
+    const T: AffineTransform = AffineTransform::new(2.0, 1.0, -1.0, 3.0, 5.0, -7.0);
+
+    fn assert_close(a: AffineTransform, b: AffineTransform) {
+        let (a, b) = (
+            [a.a, a.b, a.c, a.d, a.tx, a.ty],
+            [b.a, b.b, b.c, b.d, b.tx, b.ty],
+        );
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < 1e-12, "{a:?} != {b:?}");
+        }
+    }
 
+    #[test]
+    fn basics() {
+        let p = cg::Point::new(1.0, 2.0);
+        assert_eq!(
+            p * AffineTransform::new_translation(3.0, 4.0),
+            cg::Point::new(4.0, 6.0)
+        );
+        assert_eq!(
+            p * AffineTransform::new_scale(2.0, -1.0),
+            cg::Point::new(2.0, -2.0)
+        );
+        assert_eq!(cg::Size::new(1.0, 2.0) * T, cg::Size::new(0.0, 7.0));
+
+        // translate then scale
+        let t = AffineTransform::new_scale(2.0, 3.0).translate(1.0, 1.0);
+        assert_eq!(p * t, cg::Point::new(4.0, 9.0));
+        assert_eq!(
+            t,
+            AffineTransform::new_translation(1.0, 1.0) * AffineTransform::new_scale(2.0, 3.0)
+        );
+
+        let r = AffineTransform::new_rotation(FRAC_PI_2);
+        let q = p * r;
+        assert!((q.x + 2.0).abs() < 1e-15 && (q.y - 1.0).abs() < 1e-15);
+
+        assert_close(T * T.invert(), AffineTransform::IDENTITY);
+        assert_close(T.invert().invert(), T);
+        let singular = AffineTransform::new_scale(0.0, 1.0);
+        assert_eq!(singular.invert(), singular);
+
+        let mut m = T;
+        m *= AffineTransform::IDENTITY;
+        assert_eq!(m, T);
+        assert_eq!(AffineTransform::default(), AffineTransform::identity());
+    }
+
+    #[test]
+    fn components() {
+        let c = Components {
+            scale: cg::Size::new(2.0, -3.0),
+            horizontal_shear: 0.5,
+            rotation: PI / 3.0,
+            translation: cg::Vector { dx: 4.0, dy: 5.0 },
+        };
+        let t = AffineTransform::with_components(c);
+        let d = t.decompose();
+        assert!((d.scale.width - 2.0).abs() < 1e-12);
+        assert!((d.scale.height + 3.0).abs() < 1e-12);
+        assert!((d.horizontal_shear - 0.5).abs() < 1e-12);
+        assert!((d.rotation - PI / 3.0).abs() < 1e-12);
+        assert_eq!(d.translation, c.translation);
+        assert_close(AffineTransform::with_components(d), t);
+    }
+
+    #[test]
+    fn rect() {
+        let r = cg::Rect::new(1.0, 1.0, 2.0, -1.0);
+        let t = AffineTransform::new_scale(2.0, -1.0).translate(1.0, 0.0);
+        assert_eq!(
+            r.apply_affine_transform(&t),
+            cg::Rect::new(4.0, -1.0, 4.0, 1.0)
+        );
+        assert!(cg::Rect::null().apply_affine_transform(&T).is_null());
+    }
+
+    #[cfg(target_vendor = "apple")]
+    #[test]
+    fn matches_cg() {
+        let r = cg::Rect::new(-1.5, 2.25, 10.0, -3.5);
+        let angles = [0.0, 0.3, FRAC_PI_2, PI, -2.0, 7.5];
+        unsafe {
+            assert_eq!(CGAffineTransformIdentity, AffineTransform::identity());
+            assert_eq!(
+                CGAffineTransformMakeTranslation(0.1, -3.7),
+                AffineTransform::new_translation(0.1, -3.7)
+            );
+            assert_eq!(
+                CGAffineTransformMakeScale(1.3, 0.7),
+                AffineTransform::new_scale(1.3, 0.7)
+            );
+            assert_eq!(
+                CGAffineTransformTranslate(T, 0.1, 0.3),
+                T.translate(0.1, 0.3)
+            );
+            assert_eq!(CGAffineTransformScale(T, 0.1, 0.3), T.scale(0.1, 0.3));
+            assert_eq!(CGAffineTransformInvert(T), T.invert());
+            let t = T.rotate(0.7).scale(0.3, 1.1);
+            assert_eq!(CGAffineTransformConcat(t, T), t.concat(&T));
+            assert_eq!(
+                CGRectApplyAffineTransform(r, t),
+                r.apply_affine_transform(&t)
+            );
+            for a in angles {
+                assert_eq!(
+                    CGAffineTransformMakeRotation(a),
+                    AffineTransform::new_rotation(a)
+                );
+                assert_eq!(CGAffineTransformRotate(T, a), T.rotate(a));
+            }
+
+            let d = t.decompose();
+            let cg_d = CGAffineTransformDecompose(t);
+            assert_close(
+                AffineTransform::with_components(d),
+                AffineTransform::with_components(cg_d),
+            );
+            assert_close(
+                CGAffineTransformMakeWithComponents(d),
+                AffineTransform::with_components(d),
+            );
+        }
+    }
 }
@@ -14,8 +14,9 @@ pub struct Point {
 }
 
 impl Point {
-    pub fn zero() -> Self {
-        Default::default()
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::new(0.0, 0.0)
     }
 
     /// ```
@@ -29,7 +30,7 @@ impl Point {
     }
 
     #[inline]
-    pub fn new(x: Float, y: Float) -> Self {
+    pub const fn new(x: Float, y: Float) -> Self {
         Self { x, y }
     }
 }
@@ -42,8 +43,9 @@ pub struct Size {
 }
 
 impl Size {
-    pub fn zero() -> Self {
-        Default::default()
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::new(0.0, 0.0)
     }
 
     /// ```
@@ -57,7 +59,7 @@ impl Size {
     }
 
     #[inline]
-    pub fn new(width: Float, height: Float) -> Self {
+    pub const fn new(width: Float, height: Float) -> Self {
         Self { width, height }
     }
 }
@@ -71,8 +73,8 @@ pub struct Rect {
 
 impl Rect {
     #[inline]
-    pub fn zero() -> Self {
-        Default::default()
+    pub const fn zero() -> Self {
+        Self::new(0.0, 0.0, 0.0, 0.0)
     }
 
     /// ```
@@ -86,7 +88,7 @@ impl Rect {
     }
 
     #[inline]
-    pub fn new(x: Float, y: Float, width: Float, height: Float) -> Self {
+    pub const fn new(x: Float, y: Float, width: Float, height: Float) -> Self {
         Self {
             origin: Point { x, y },
             size: Size { width, height },
@@ -94,12 +96,247 @@ impl Rect {
     }
 
     #[inline]
-    pub fn with_size(width: Float, height: Float) -> Self {
+    pub const fn with_size(width: Float, height: Float) -> Self {
         Self {
             origin: Point::zero(),
             size: Size { width, height },
         }
     }
+
+    /// The rectangle with infinite origin, result of intersection of disjoint rectangles.
+    #[doc(alias = "CGRectNull")]
+    #[inline]
+    pub const fn null() -> Self {
+        Self::new(Float::INFINITY, Float::INFINITY, 0.0, 0.0)
+    }
+
+    #[doc(alias = "CGRectIsNull")]
+    #[inline]
+    pub const fn is_null(&self) -> bool {
+        self.origin.x == Float::INFINITY || self.origin.y == Float::INFINITY
+    }
+
+    /// Null or with zero width or height.
+    #[doc(alias = "CGRectIsEmpty")]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.is_null() || self.size.width == 0.0 || self.size.height == 0.0
+    }
+
+    /// The same rectangle with non-negative width and height.
+    #[doc(alias = "CGRectStandardize")]
+    #[inline]
+    pub const fn standardize(&self) -> Self {
+        if self.is_null() {
+            return *self;
+        }
+        let mut res = *self;
+        if res.size.width < 0.0 {
+            res.origin.x += res.size.width;
+            res.size.width = -res.size.width;
+        }
+        if res.size.height < 0.0 {
+            res.origin.y += res.size.height;
+            res.size.height = -res.size.height;
+        }
+        res
+    }
+
+    #[doc(alias = "CGRectGetMinX")]
+    #[inline]
+    pub const fn min_x(&self) -> Float {
+        self.standardize().origin.x
+    }
+
+    #[doc(alias = "CGRectGetMidX")]
+    #[inline]
+    pub const fn mid_x(&self) -> Float {
+        let r = self.standardize();
+        r.origin.x + r.size.width * 0.5
+    }
+
+    #[doc(alias = "CGRectGetMaxX")]
+    #[inline]
+    pub const fn max_x(&self) -> Float {
+        let r = self.standardize();
+        r.origin.x + r.size.width
+    }
+
+    #[doc(alias = "CGRectGetMinY")]
+    #[inline]
+    pub const fn min_y(&self) -> Float {
+        self.standardize().origin.y
+    }
+
+    #[doc(alias = "CGRectGetMidY")]
+    #[inline]
+    pub const fn mid_y(&self) -> Float {
+        let r = self.standardize();
+        r.origin.y + r.size.height * 0.5
+    }
+
+    #[doc(alias = "CGRectGetMaxY")]
+    #[inline]
+    pub const fn max_y(&self) -> Float {
+        let r = self.standardize();
+        r.origin.y + r.size.height
+    }
+
+    #[doc(alias = "CGRectGetWidth")]
+    #[inline]
+    pub const fn width(&self) -> Float {
+        self.standardize().size.width
+    }
+
+    #[doc(alias = "CGRectGetHeight")]
+    #[inline]
+    pub const fn height(&self) -> Float {
+        self.standardize().size.height
+    }
+
+    /// Smallest rectangle containing both, null rectangles are ignored.
+    #[doc(alias = "CGRectUnion")]
+    pub const fn union(&self, other: &Self) -> Self {
+        if self.is_null() {
+            return *other;
+        }
+        if other.is_null() {
+            return *self;
+        }
+        let (a, b) = (self.standardize(), other.standardize());
+        let x0 = min(a.origin.x, b.origin.x);
+        let y0 = min(a.origin.y, b.origin.y);
+        let x1 = max(a.max_x(), b.max_x());
+        let y1 = max(a.max_y(), b.max_y());
+        Self::new(x0, y0, x1 - x0, y1 - y0)
+    }
+
+    /// Common part of both rectangles, null if they don't intersect.
+    /// Rectangles sharing an edge intersect with zero width or height.
+    #[doc(alias = "CGRectIntersection")]
+    pub const fn intersection(&self, other: &Self) -> Self {
+        if self.is_null() || other.is_null() {
+            return Self::null();
+        }
+        let (a, b) = (self.standardize(), other.standardize());
+        let x0 = max(a.origin.x, b.origin.x);
+        let y0 = max(a.origin.y, b.origin.y);
+        let x1 = min(a.max_x(), b.max_x());
+        let y1 = min(a.max_y(), b.max_y());
+        if x1 < x0 || y1 < y0 {
+            return Self::null();
+        }
+        Self::new(x0, y0, x1 - x0, y1 - y0)
+    }
+
+    /// Shrinks standardized rectangle by `dx` on left and right and by `dy` on top and bottom,
+    /// negative values grow it. Null if resulting width or height is negative.
+    #[doc(alias = "CGRectInset")]
+    pub const fn inset(&self, dx: Float, dy: Float) -> Self {
+        if self.is_null() {
+            return *self;
+        }
+        let r = self.standardize();
+        let res = Self::new(
+            r.origin.x + dx,
+            r.origin.y + dy,
+            r.size.width - 2.0 * dx,
+            r.size.height - 2.0 * dy,
+        );
+        if res.size.width < 0.0 || res.size.height < 0.0 {
+            return Self::null();
+        }
+        res
+    }
+
+    /// Smallest rectangle with integral coordinates containing `self`.
+    #[doc(alias = "CGRectIntegral")]
+    pub fn integral(&self) -> Self {
+        if self.is_null() {
+            return *self;
+        }
+        let r = self.standardize();
+        let x0 = r.origin.x.floor();
+        let y0 = r.origin.y.floor();
+        let x1 = r.max_x().ceil();
+        let y1 = r.max_y().ceil();
+        Self::new(x0, y0, x1 - x0, y1 - y0)
+    }
+
+    /// Splits into slice of `amount` from `edge` and the remainder.
+    /// `amount` is clamped to the rectangle size.
+    ///
+    /// ```
+    /// use cidre::cg;
+    ///
+    /// let r = cg::Rect::new(0.0, 0.0, 100.0, 50.0);
+    /// let (slice, rest) = r.divide(30.0, cg::RectEdge::MaxX);
+    /// assert_eq!(slice, cg::Rect::new(70.0, 0.0, 30.0, 50.0));
+    /// assert_eq!(rest, cg::Rect::new(0.0, 0.0, 70.0, 50.0));
+    /// ```
+    #[doc(alias = "CGRectDivide")]
+    pub const fn divide(&self, amount: Float, edge: RectEdge) -> (Self, Self) {
+        if self.is_null() {
+            return (Self::null(), Self::null());
+        }
+        let r = self.standardize();
+        let (o, s) = (r.origin, r.size);
+        let amount = if amount < 0.0 { 0.0 } else { amount };
+        match edge {
+            RectEdge::MinX => {
+                let w = min(amount, s.width);
+                (
+                    Self::new(o.x, o.y, w, s.height),
+                    Self::new(o.x + w, o.y, s.width - w, s.height),
+                )
+            }
+            RectEdge::MaxX => {
+                let w = min(amount, s.width);
+                (
+                    Self::new(o.x + s.width - w, o.y, w, s.height),
+                    Self::new(o.x, o.y, s.width - w, s.height),
+                )
+            }
+            RectEdge::MinY => {
+                let h = min(amount, s.height);
+                (
+                    Self::new(o.x, o.y, s.width, h),
+                    Self::new(o.x, o.y + h, s.width, s.height - h),
+                )
+            }
+            RectEdge::MaxY => {
+                let h = min(amount, s.height);
+                (
+                    Self::new(o.x, o.y + s.height - h, s.width, h),
+                    Self::new(o.x, o.y, s.width, s.height - h),
+                )
+            }
+        }
+    }
+}
+
+#[doc(alias = "CGRectEdge")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum RectEdge {
+    #[doc(alias = "CGRectMinXEdge")]
+    MinX = 0,
+    #[doc(alias = "CGRectMinYEdge")]
+    MinY = 1,
+    #[doc(alias = "CGRectMaxXEdge")]
+    MaxX = 2,
+    #[doc(alias = "CGRectMaxYEdge")]
+    MaxY = 3,
+}
+
+#[inline]
+const fn min(a: Float, b: Float) -> Float {
+    if a < b { a } else { b }
+}
+
+#[inline]
+const fn max(a: Float, b: Float) -> Float {
+    if a > b { a } else { b }
 }
 
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
@@ -114,3 +351,51 @@ unsafe extern "C" {
     fn CGSizeCreateDictionaryRepresentation(size: Size) -> arc::R<cf::Dictionary>;
     fn CGRectCreateDictionaryRepresentation(rect: Rect) -> arc::R<cf::Dictionary>;
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::cg;
+
+    #[test]
+    fn rect_geometry() {
+        let a = cg::Rect::new(10.0, 10.0, -10.0, 20.0);
+        assert_eq!(a.standardize(), cg::Rect::new(0.0, 10.0, 10.0, 20.0));
+        assert_eq!((a.min_x(), a.mid_x(), a.max_x()), (0.0, 5.0, 10.0));
+        assert_eq!((a.min_y(), a.mid_y(), a.max_y()), (10.0, 20.0, 30.0));
+
+        let b = cg::Rect::new(5.0, 0.0, 10.0, 15.0);
+        assert_eq!(a.union(&b), cg::Rect::new(0.0, 0.0, 15.0, 30.0));
+        assert_eq!(a.intersection(&b), cg::Rect::new(5.0, 10.0, 5.0, 5.0));
+        assert_eq!(a.union(&cg::Rect::null()), a);
+
+        // touching edges give empty, not null
+        let c = cg::Rect::new(10.0, 10.0, 5.0, 5.0);
+        let touch = a.intersection(&c);
+        assert!(touch.is_empty() && !touch.is_null());
+        assert!(
+            a.intersection(&cg::Rect::new(11.0, 0.0, 1.0, 1.0))
+                .is_null()
+        );
+
+        assert_eq!(a.inset(2.0, 5.0), cg::Rect::new(2.0, 15.0, 6.0, 10.0));
+        assert_eq!(a.inset(-1.0, 0.0), cg::Rect::new(-1.0, 10.0, 12.0, 20.0));
+        assert!(a.inset(6.0, 0.0).is_null());
+
+        let r = cg::Rect::new(0.5, -0.5, 1.0, 1.2);
+        assert_eq!(r.integral(), cg::Rect::new(0.0, -1.0, 2.0, 2.0));
+        assert!(cg::Rect::null().integral().is_null());
+
+        let (slice, rest) = a.divide(4.0, cg::RectEdge::MinY);
+        assert_eq!(slice, cg::Rect::new(0.0, 10.0, 10.0, 4.0));
+        assert_eq!(rest, cg::Rect::new(0.0, 14.0, 10.0, 16.0));
+        let (slice, rest) = a.divide(100.0, cg::RectEdge::MinX);
+        assert_eq!(slice, a.standardize());
+        assert_eq!(rest, cg::Rect::new(10.0, 10.0, 0.0, 20.0));
+    }
+
+    #[test]
+    fn consts() {
+        const R: cg::Rect = cg::Rect::new(0.0, 0.0, 4.0, 4.0).inset(1.0, 1.0);
+        assert_eq!(R, cg::Rect::new(1.0, 1.0, 2.0, 2.0));
+    }
+}
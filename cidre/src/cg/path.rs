@@ -5,6 +5,11 @@ use crate::{arc, cf, cg, define_cf_type};
 #[cfg(feature = "blocks")]
 use crate::blocks;
 
+mod data;
+pub use data::Data;
+pub use data::Segment;
+pub use data::SvgError;
+
 #[cfg(feature = "blocks")]
 #[doc(alias = "CGPathApplyBlock")]
 pub type ApplyBlock<Attr> = blocks::Block<fn(&cg::PathElement), Attr>;
@@ -72,6 +77,17 @@ impl Element {
         };
         unsafe { std::slice::from_raw_parts_mut(self.points, len) }
     }
+
+    pub fn segment(&self) -> Segment {
+        let p = self.points();
+        match self.type_ {
+            ElementType::MoveToPoint => Segment::MoveTo(p[0]),
+            ElementType::AddLineToPoint => Segment::LineTo(p[0]),
+            ElementType::AddQuadCurveToPoint => Segment::QuadTo(p[0], p[1]),
+            ElementType::AddCurveToPoint => Segment::CurveTo(p[0], p[1], p[2]),
+            ElementType::CloseSubpath => Segment::Close,
+        }
+    }
 }
 
 define_cf_type!(
@@ -203,7 +219,7 @@ impl Path {
     pub fn bounding_box(&self) -> cg::Rect {
         unsafe { CGPathGetBoundingBox(self) }
     }
-    
+
     #[doc(alias = "CGPathGetPathBoundingBox")]
     #[inline]
     pub fn path_bounding_box(&self) -> cg::Rect {
@@ -309,6 +325,35 @@ impl Path {
     pub fn intersects_path(&self, other: &Path, even_odd_fill_rule: bool) -> bool {
         unsafe { CGPathIntersectsPath(self, other, even_odd_fill_rule) }
     }
+
+    /// Elements of the path collected with [`Path::apply`].
+    pub fn data(&self) -> Data {
+        extern "C" fn push(data: *mut Data, element: *mut Element) {
+            let (data, element) = unsafe { (&mut *data, &*element) };
+            data.push(element.segment());
+        }
+        let mut data = Data::new();
+        self.apply(&mut data, push);
+        data
+    }
+}
+
+impl Data {
+    pub fn to_path(&self) -> arc::R<PathMut> {
+        let mut path = PathMut::new();
+        for s in self.segments() {
+            match *s {
+                Segment::MoveTo(p) => path.move_to_point(None, p.x, p.y),
+                Segment::LineTo(p) => path.line_to_point(None, p.x, p.y),
+                Segment::QuadTo(c, p) => path.quad_curve_to_point(None, c.x, c.y, p.x, p.y),
+                Segment::CurveTo(c1, c2, p) => {
+                    path.curve_to_point(None, c1.x, c1.y, c2.x, c2.y, p.x, p.y)
+                }
+                Segment::Close => path.close_subpath(),
+            }
+        }
+        path
+    }
 }
 
 impl PartialEq for Path {
@@ -766,4 +811,36 @@ mod tests {
         path.close_subpath();
         path.show();
     }
+
+    #[test]
+    fn data() {
+        let close = |a: cg::Rect, b: cg::Rect| {
+            let (a, b) = (
+                [a.min_x(), a.min_y(), a.max_x(), a.max_y()],
+                [b.min_x(), b.min_y(), b.max_x(), b.max_y()],
+            );
+            a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-9)
+        };
+
+        let data =
+            cg::PathData::from_svg("M10 10h20q10 0 10 10c0 10-5 20-20 20a10 10 0 0 1-10-10z")
+                .unwrap();
+        let path = data.to_path();
+        assert_eq!(path.data(), data);
+        assert_eq!(path.bounding_box(), data.bounding_box());
+        assert!(close(path.path_bounding_box(), data.path_bounding_box()));
+
+        let mut path = cg::PathMut::new();
+        let mut data = cg::PathData::new();
+        path.move_to(0.0, 0.0);
+        data.move_to(cg::Point::zero());
+        path.arc_to(10.0, 0.0, 10.0, 10.0, 4.0);
+        data.arc_to(cg::Point::new(10.0, 0.0), cg::Point::new(10.0, 10.0), 4.0);
+        path.add_arc(None, 20.0, 20.0, 5.0, 0.0, 3.0, true);
+        data.add_arc(cg::Point::new(20.0, 20.0), 5.0, 0.0, 3.0, true);
+
+        let (a, b) = (path.current_point(), data.current_point().unwrap());
+        assert!((a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9);
+        assert!(close(path.path_bounding_box(), data.path_bounding_box()));
+    }
 }
@@ -0,0 +1,1017 @@
+use std::{fmt, str::FromStr};
+
+use crate::cg;
+
+const PI: cg::Float = std::f64::consts::PI as cg::Float;
+const TAU: cg::Float = std::f64::consts::TAU as cg::Float;
+const FRAC_PI_2: cg::Float = std::f64::consts::FRAC_PI_2 as cg::Float;
+
+/// Upper bound of lines per curve in [`Data::flatten`].
+const MAX_FLATTEN_LINES: cg::Float = 1024.0;
+
+/// Path element with its points, the same as [`cg::PathElement`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Segment {
+    MoveTo(cg::Point),
+    LineTo(cg::Point),
+    /// Control point and end point.
+    QuadTo(cg::Point, cg::Point),
+    /// Two control points and end point.
+    CurveTo(cg::Point, cg::Point, cg::Point),
+    Close,
+}
+
+impl Segment {
+    /// End point, `None` for close.
+    #[inline]
+    pub fn end(&self) -> Option<cg::Point> {
+        match *self {
+            Self::MoveTo(p) | Self::LineTo(p) | Self::QuadTo(_, p) | Self::CurveTo(_, _, p) => {
+                Some(p)
+            }
+            Self::Close => None,
+        }
+    }
+
+    pub fn apply_affine_transform(&self, t: &cg::AffineTransform) -> Self {
+        let f = |p: cg::Point| p.apply_affine_transform(t);
+        match *self {
+            Self::MoveTo(p) => Self::MoveTo(f(p)),
+            Self::LineTo(p) => Self::LineTo(f(p)),
+            Self::QuadTo(c, p) => Self::QuadTo(f(c), f(p)),
+            Self::CurveTo(c1, c2, p) => Self::CurveTo(f(c1), f(c2), f(p)),
+            Self::Close => Self::Close,
+        }
+    }
+}
+
+/// Path geometry built and inspected without CoreGraphics.
+///
+/// Follows `cg::PathMut` rules: segments other than move are ignored
+/// without current point, close sets current point to the subpath start.
+///
+/// ```
+/// use cidre::cg;
+///
+/// let data: cg::PathData = "M0 0h10v10H0z".parse().unwrap();
+/// assert_eq!(data.bounding_box(), cg::Rect::new(0.0, 0.0, 10.0, 10.0));
+/// assert_eq!(data.to_string(), "M0 0L10 0L10 10L0 10Z");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Data {
+    segments: Vec<Segment>,
+    start: Option<cg::Point>,
+    current: Option<cg::Point>,
+}
+
+impl Data {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    #[doc(alias = "CGPathGetCurrentPoint")]
+    #[inline]
+    pub fn current_point(&self) -> Option<cg::Point> {
+        self.current
+    }
+
+    pub fn push(&mut self, segment: Segment) {
+        match segment {
+            Segment::MoveTo(p) => {
+                self.start = Some(p);
+            }
+            Segment::Close => {
+                if self.current.is_none() {
+                    return;
+                }
+                self.segments.push(segment);
+                self.current = self.start;
+                return;
+            }
+            _ if self.current.is_none() => return,
+            _ => {}
+        }
+        self.segments.push(segment);
+        self.current = segment.end();
+    }
+
+    #[doc(alias = "CGPathMoveToPoint")]
+    #[inline]
+    pub fn move_to(&mut self, p: cg::Point) {
+        self.push(Segment::MoveTo(p))
+    }
+
+    #[doc(alias = "CGPathAddLineToPoint")]
+    #[inline]
+    pub fn line_to(&mut self, p: cg::Point) {
+        self.push(Segment::LineTo(p))
+    }
+
+    #[doc(alias = "CGPathAddQuadCurveToPoint")]
+    #[inline]
+    pub fn quad_to(&mut self, cp: cg::Point, p: cg::Point) {
+        self.push(Segment::QuadTo(cp, p))
+    }
+
+    #[doc(alias = "CGPathAddCurveToPoint")]
+    #[inline]
+    pub fn curve_to(&mut self, cp1: cg::Point, cp2: cg::Point, p: cg::Point) {
+        self.push(Segment::CurveTo(cp1, cp2, p))
+    }
+
+    #[doc(alias = "CGPathCloseSubpath")]
+    #[inline]
+    pub fn close(&mut self) {
+        self.push(Segment::Close)
+    }
+
+    /// Closed subpath from the rect origin, counterclockwise in y-up coordinates.
+    #[doc(alias = "CGPathAddRect")]
+    pub fn add_rect(&mut self, rect: cg::Rect) {
+        let (x0, y0, x1, y1) = (rect.min_x(), rect.min_y(), rect.max_x(), rect.max_y());
+        self.move_to(cg::Point::new(x0, y0));
+        self.line_to(cg::Point::new(x1, y0));
+        self.line_to(cg::Point::new(x1, y1));
+        self.line_to(cg::Point::new(x0, y1));
+        self.close();
+    }
+
+    /// Arc of `delta` radians from `start_angle`, with a line from current point
+    /// to the arc start if there is one. Arc is split into cubic curves of at most
+    /// a quarter circle each.
+    #[doc(alias = "CGPathAddRelativeArc")]
+    pub fn add_relative_arc(
+        &mut self,
+        center: cg::Point,
+        radius: cg::Float,
+        start_angle: cg::Float,
+        delta: cg::Float,
+    ) {
+        let m = cg::AffineTransform::new(radius, 0.0, 0.0, radius, center.x, center.y);
+        self.add_unit_arc(&m, start_angle, delta, None);
+    }
+
+    /// Arc from `start_angle` to `end_angle`. Angles grow counterclockwise in y-up
+    /// coordinates, `clockwise` arcs go with decreasing angle.
+    #[doc(alias = "CGPathAddArc")]
+    pub fn add_arc(
+        &mut self,
+        center: cg::Point,
+        radius: cg::Float,
+        start_angle: cg::Float,
+        end_angle: cg::Float,
+        clockwise: bool,
+    ) {
+        // end angle is moved by full turns to the arc direction
+        let mut delta = end_angle - start_angle;
+        if clockwise && delta > 0.0 {
+            delta -= TAU * (delta / TAU).ceil();
+        } else if !clockwise && delta < 0.0 {
+            delta -= TAU * (delta / TAU).floor();
+        }
+        self.add_relative_arc(center, radius, start_angle, delta);
+    }
+
+    /// Line to the start of the arc tangent to lines from current point to `p1`
+    /// and from `p1` to `p2`, and the arc. Just a line to `p1` if the points are collinear.
+    #[doc(alias = "CGPathAddArcToPoint")]
+    pub fn arc_to(&mut self, p1: cg::Point, p2: cg::Point, radius: cg::Float) {
+        let Some(p0) = self.current else {
+            return;
+        };
+        let (v0x, v0y) = (p0.x - p1.x, p0.y - p1.y);
+        let (v2x, v2y) = (p2.x - p1.x, p2.y - p1.y);
+        let cross = v0x * v2y - v0y * v2x;
+        if radius == 0.0 || cross == 0.0 {
+            self.line_to(p1);
+            return;
+        }
+        let (l0, l2) = (v0x.hypot(v0y), v2x.hypot(v2y));
+        let (u0x, u0y) = (v0x / l0, v0y / l0);
+        let (u2x, u2y) = (v2x / l2, v2y / l2);
+        let half = (u0x * u2x + u0y * u2y).clamp(-1.0, 1.0).acos() * 0.5;
+
+        let dist = radius / half.tan();
+        let t1 = cg::Point::new(p1.x + u0x * dist, p1.y + u0y * dist);
+        let t2 = cg::Point::new(p1.x + u2x * dist, p1.y + u2y * dist);
+        let (bx, by) = (u0x + u2x, u0y + u2y);
+        let bl = bx.hypot(by);
+        let k = radius / half.sin() / bl;
+        let center = cg::Point::new(p1.x + bx * k, p1.y + by * k);
+
+        let start = (t1.y - center.y).atan2(t1.x - center.x);
+        let end = (t2.y - center.y).atan2(t2.x - center.x);
+        let mut delta = end - start;
+        if delta > PI {
+            delta -= TAU;
+        } else if delta < -PI {
+            delta += TAU;
+        }
+        let m = cg::AffineTransform::new(radius, 0.0, 0.0, radius, center.x, center.y);
+        self.add_unit_arc(&m, start, delta, Some((t1, t2)));
+    }
+
+    /// Unit circle arc mapped by `m`, with optional exact start and end points.
+    fn add_unit_arc(
+        &mut self,
+        m: &cg::AffineTransform,
+        start: cg::Float,
+        delta: cg::Float,
+        ends: Option<(cg::Point, cg::Point)>,
+    ) {
+        let at = |a: cg::Float, k: cg::Float| {
+            let (sin, cos) = a.sin_cos();
+            (
+                cg::Point::new(cos, sin).apply_affine_transform(m),
+                cg::Point::new(cos - k * sin, sin + k * cos).apply_affine_transform(m),
+                cg::Point::new(cos + k * sin, sin - k * cos).apply_affine_transform(m),
+            )
+        };
+
+        let (p0, _, _) = at(start, 0.0);
+        let p0 = ends.map_or(p0, |(p, _)| p);
+        match self.current {
+            Some(p) if p == p0 => {}
+            Some(_) => self.line_to(p0),
+            None => self.move_to(p0),
+        }
+        if delta == 0.0 {
+            return;
+        }
+
+        let n = (delta.abs() / FRAC_PI_2).ceil().max(1.0);
+        let step = delta / n;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+        let n = n as usize;
+        for i in 0..n {
+            let a0 = start + step * i as cg::Float;
+            let (_, cp1, _) = at(a0, k);
+            let (mut p, _, cp2) = at(a0 + step, k);
+            if let Some((_, end)) = ends.filter(|_| i + 1 == n) {
+                p = end;
+            }
+            self.curve_to(cp1, cp2, p);
+        }
+    }
+
+    /// Copy with all points transformed.
+    #[doc(alias = "CGPathCreateCopyByTransformingPath")]
+    pub fn apply_affine_transform(&self, t: &cg::AffineTransform) -> Self {
+        let f = |p: cg::Point| p.apply_affine_transform(t);
+        Self {
+            segments: self
+                .segments
+                .iter()
+                .map(|s| s.apply_affine_transform(t))
+                .collect(),
+            start: self.start.map(f),
+            current: self.current.map(f),
+        }
+    }
+
+    /// Box of all points including control points, null for empty path.
+    #[doc(alias = "CGPathGetBoundingBox")]
+    pub fn bounding_box(&self) -> cg::Rect {
+        let mut b = Bounds::default();
+        for s in &self.segments {
+            match *s {
+                Segment::MoveTo(p) | Segment::LineTo(p) => b.add(p),
+                Segment::QuadTo(c, p) => {
+                    b.add(c);
+                    b.add(p);
+                }
+                Segment::CurveTo(c1, c2, p) => {
+                    b.add(c1);
+                    b.add(c2);
+                    b.add(p);
+                }
+                Segment::Close => {}
+            }
+        }
+        b.rect()
+    }
+
+    /// Smallest box of the path curves, null for empty path.
+    #[doc(alias = "CGPathGetPathBoundingBox")]
+    pub fn path_bounding_box(&self) -> cg::Rect {
+        let mut b = Bounds::default();
+        let mut start = cg::Point::zero();
+        let mut p0 = cg::Point::zero();
+        for s in &self.segments {
+            match *s {
+                Segment::MoveTo(p) => {
+                    start = p;
+                    b.add(p);
+                }
+                Segment::LineTo(p) => b.add(p),
+                Segment::QuadTo(c, p) => {
+                    for t in quad_extrema(p0.x, c.x, p.x)
+                        .into_iter()
+                        .chain(quad_extrema(p0.y, c.y, p.y))
+                        .flatten()
+                    {
+                        b.add(quad_at(p0, c, p, t));
+                    }
+                    b.add(p);
+                }
+                Segment::CurveTo(c1, c2, p) => {
+                    for t in cubic_extrema(p0.x, c1.x, c2.x, p.x)
+                        .into_iter()
+                        .chain(cubic_extrema(p0.y, c1.y, c2.y, p.y))
+                        .flatten()
+                    {
+                        b.add(cubic_at(p0, c1, c2, p, t));
+                    }
+                    b.add(p);
+                }
+                Segment::Close => {}
+            }
+            p0 = s.end().unwrap_or(start);
+        }
+        b.rect()
+    }
+
+    /// Copy with curves replaced by lines deviating from them at most by `threshold`.
+    #[doc(alias = "CGPathCreateCopyByFlattening")]
+    pub fn flatten(&self, threshold: cg::Float) -> Self {
+        let mut res = Self::new();
+        let mut p0 = cg::Point::zero();
+        for s in &self.segments {
+            match *s {
+                Segment::QuadTo(c, p) => {
+                    // Wang's formula
+                    let dd = second_diff(p0, c, p);
+                    let n = lines_count(dd / 4.0, threshold);
+                    for i in 1..n {
+                        res.line_to(quad_at(p0, c, p, i as cg::Float / n as cg::Float));
+                    }
+                    res.line_to(p);
+                }
+                Segment::CurveTo(c1, c2, p) => {
+                    let dd = second_diff(p0, c1, c2).max(second_diff(c1, c2, p));
+                    let n = lines_count(dd * 0.75, threshold);
+                    for i in 1..n {
+                        res.line_to(cubic_at(p0, c1, c2, p, i as cg::Float / n as cg::Float));
+                    }
+                    res.line_to(p);
+                }
+                s => res.push(s),
+            }
+            p0 = res.current.unwrap_or(p0);
+        }
+        res
+    }
+
+    /// SVG path data with absolute commands.
+    pub fn to_svg(&self) -> String {
+        self.to_string()
+    }
+
+    /// Parses SVG path data. Arcs are converted to cubic curves and
+    /// shorthand commands to full ones.
+    pub fn from_svg(svg: &str) -> Result<Self, SvgError> {
+        Parser::new(svg).parse()
+    }
+}
+
+impl fmt::Display for Data {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for s in &self.segments {
+            match s {
+                Segment::MoveTo(p) => write!(f, "M{} {}", p.x, p.y)?,
+                Segment::LineTo(p) => write!(f, "L{} {}", p.x, p.y)?,
+                Segment::QuadTo(c, p) => write!(f, "Q{} {} {} {}", c.x, c.y, p.x, p.y)?,
+                Segment::CurveTo(c1, c2, p) => {
+                    write!(f, "C{} {} {} {} {} {}", c1.x, c1.y, c2.x, c2.y, p.x, p.y)?
+                }
+                Segment::Close => f.write_str("Z")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Data {
+    type Err = SvgError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_svg(s)
+    }
+}
+
+impl Extend<Segment> for Data {
+    fn extend<I: IntoIterator<Item = Segment>>(&mut self, iter: I) {
+        for s in iter {
+            self.push(s);
+        }
+    }
+}
+
+impl FromIterator<Segment> for Data {
+    fn from_iter<I: IntoIterator<Item = Segment>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+/// SVG path data error with byte offset in the source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SvgError {
+    UnexpectedChar(usize, char),
+    ExpectedNumber(usize),
+    ExpectedFlag(usize),
+    /// Path data must start with moveto.
+    ExpectedMoveTo(usize),
+}
+
+impl fmt::Display for SvgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedChar(pos, ch) => write!(f, "unexpected `{ch}` at {pos}"),
+            Self::ExpectedNumber(pos) => write!(f, "expected number at {pos}"),
+            Self::ExpectedFlag(pos) => write!(f, "expected flag 0 or 1 at {pos}"),
+            Self::ExpectedMoveTo(pos) => write!(f, "expected moveto at {pos}"),
+        }
+    }
+}
+
+impl std::error::Error for SvgError {}
+
+struct Parser<'a> {
+    src: &'a [u8],
+    pos: usize,
+    data: Data,
+    /// Control point of previous curve for smooth shorthands.
+    last_cubic: Option<cg::Point>,
+    last_quad: Option<cg::Point>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self {
+            src: src.as_bytes(),
+            pos: 0,
+            data: Data::new(),
+            last_cubic: None,
+            last_quad: None,
+        }
+    }
+
+    fn parse(mut self) -> Result<Data, SvgError> {
+        self.skip_ws();
+        let mut cmd = None;
+        while self.pos < self.src.len() {
+            let c = self.src[self.pos];
+            let c = if c.is_ascii_alphabetic() {
+                self.pos += 1;
+                c
+            } else {
+                match cmd {
+                    // implicit lineto after moveto
+                    Some(b'M') => b'L',
+                    Some(b'm') => b'l',
+                    Some(c) if c != b'Z' && c != b'z' => c,
+                    _ => return Err(self.unexpected()),
+                }
+            };
+            if self.data.current.is_none() && c != b'M' && c != b'm' {
+                return Err(SvgError::ExpectedMoveTo(self.pos - 1));
+            }
+            self.command(c)?;
+            cmd = Some(c);
+            self.skip_ws();
+        }
+        Ok(self.data)
+    }
+
+    fn command(&mut self, c: u8) -> Result<(), SvgError> {
+        let cur = self.data.current.unwrap_or_default();
+        let rel = c.is_ascii_lowercase();
+        let (last_cubic, last_quad) = (self.last_cubic.take(), self.last_quad.take());
+        match c.to_ascii_uppercase() {
+            b'M' => {
+                let p = self.point(rel, cur)?;
+                self.data.move_to(p);
+            }
+            b'L' => {
+                let p = self.point(rel, cur)?;
+                self.data.line_to(p);
+            }
+            b'H' => {
+                let x = self.number()?;
+                let x = if rel { cur.x + x } else { x };
+                self.data.line_to(cg::Point::new(x, cur.y));
+            }
+            b'V' => {
+                let y = self.number()?;
+                let y = if rel { cur.y + y } else { y };
+                self.data.line_to(cg::Point::new(cur.x, y));
+            }
+            b'C' => {
+                let c1 = self.point(rel, cur)?;
+                let c2 = self.point(rel, cur)?;
+                let p = self.point(rel, cur)?;
+                self.data.curve_to(c1, c2, p);
+                self.last_cubic = Some(c2);
+            }
+            b'S' => {
+                let c1 = reflect(last_cubic, cur);
+                let c2 = self.point(rel, cur)?;
+                let p = self.point(rel, cur)?;
+                self.data.curve_to(c1, c2, p);
+                self.last_cubic = Some(c2);
+            }
+            b'Q' => {
+                let c = self.point(rel, cur)?;
+                let p = self.point(rel, cur)?;
+                self.data.quad_to(c, p);
+                self.last_quad = Some(c);
+            }
+            b'T' => {
+                let c = reflect(last_quad, cur);
+                let p = self.point(rel, cur)?;
+                self.data.quad_to(c, p);
+                self.last_quad = Some(c);
+            }
+            b'A' => {
+                let rx = self.number()?;
+                let ry = self.number()?;
+                let rotation = self.number()?;
+                let large_arc = self.flag()?;
+                let sweep = self.flag()?;
+                let p = self.point(rel, cur)?;
+                self.arc(cur, rx, ry, rotation, large_arc, sweep, p);
+            }
+            b'Z' => self.data.close(),
+            _ => {
+                self.pos -= 1;
+                return Err(self.unexpected());
+            }
+        }
+        Ok(())
+    }
+
+    /// Elliptical arc, SVG 1.1 F.6.5 endpoint to center conversion.
+    #[allow(clippy::too_many_arguments)]
+    fn arc(
+        &mut self,
+        p0: cg::Point,
+        rx: cg::Float,
+        ry: cg::Float,
+        rotation: cg::Float,
+        large_arc: bool,
+        sweep: bool,
+        p: cg::Point,
+    ) {
+        if p == p0 {
+            return;
+        }
+        let (mut rx, mut ry) = (rx.abs(), ry.abs());
+        if rx == 0.0 || ry == 0.0 {
+            self.data.line_to(p);
+            return;
+        }
+        let (sin, cos) = rotation.to_radians().sin_cos();
+        let (dx, dy) = ((p0.x - p.x) * 0.5, (p0.y - p.y) * 0.5);
+        let x1 = cos * dx + sin * dy;
+        let y1 = -sin * dx + cos * dy;
+
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1.0 {
+            let s = lambda.sqrt();
+            rx *= s;
+            ry *= s;
+        }
+        let (rx2, ry2) = (rx * rx, ry * ry);
+        let num = rx2 * ry2 - rx2 * y1 * y1 - ry2 * x1 * x1;
+        let den = rx2 * y1 * y1 + ry2 * x1 * x1;
+        let mut coef = (num / den).max(0.0).sqrt();
+        if large_arc == sweep {
+            coef = -coef;
+        }
+        let cx1 = coef * rx * y1 / ry;
+        let cy1 = -coef * ry * x1 / rx;
+        let cx = cos * cx1 - sin * cy1 + (p0.x + p.x) * 0.5;
+        let cy = sin * cx1 + cos * cy1 + (p0.y + p.y) * 0.5;
+
+        let angle = |ux: cg::Float, uy: cg::Float, vx: cg::Float, vy: cg::Float| {
+            (ux * vy - uy * vx).atan2(ux * vx + uy * vy)
+        };
+        let (ux, uy) = ((x1 - cx1) / rx, (y1 - cy1) / ry);
+        let (vx, vy) = ((-x1 - cx1) / rx, (-y1 - cy1) / ry);
+        let start = angle(1.0, 0.0, ux, uy);
+        let mut delta = angle(ux, uy, vx, vy);
+        if !sweep && delta > 0.0 {
+            delta -= TAU;
+        } else if sweep && delta < 0.0 {
+            delta += TAU;
+        }
+
+        let m = cg::AffineTransform::new(rx * cos, rx * sin, -ry * sin, ry * cos, cx, cy);
+        self.data.add_unit_arc(&m, start, delta, Some((p0, p)));
+    }
+
+    fn point(&mut self, rel: bool, cur: cg::Point) -> Result<cg::Point, SvgError> {
+        let x = self.number()?;
+        let y = self.number()?;
+        Ok(if rel {
+            cg::Point::new(cur.x + x, cur.y + y)
+        } else {
+            cg::Point::new(x, y)
+        })
+    }
+
+    fn number(&mut self) -> Result<cg::Float, SvgError> {
+        self.skip_separators();
+        let start = self.pos;
+        let digits = |p: &mut Self| {
+            let s = p.pos;
+            while p.peek().is_some_and(|c| c.is_ascii_digit()) {
+                p.pos += 1;
+            }
+            p.pos - s
+        };
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.pos += 1;
+        }
+        let mut n = digits(self);
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            n += digits(self);
+        }
+        if n == 0 {
+            self.pos = start;
+            return Err(SvgError::ExpectedNumber(start));
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let mantissa_end = self.pos;
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            if digits(self) == 0 {
+                self.pos = mantissa_end;
+            }
+        }
+        std::str::from_utf8(&self.src[start..self.pos])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            // out of range, e.g. `1e400`
+            .filter(|n: &cg::Float| n.is_finite())
+            .ok_or(SvgError::ExpectedNumber(start))
+    }
+
+    fn flag(&mut self) -> Result<bool, SvgError> {
+        self.skip_separators();
+        let res = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(SvgError::ExpectedFlag(self.pos)),
+        };
+        self.pos += 1;
+        Ok(res)
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        self.src.get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        self.skip_ws();
+        if self.peek() == Some(b',') {
+            self.pos += 1;
+            self.skip_ws();
+        }
+    }
+
+    fn unexpected(&self) -> SvgError {
+        let ch = std::str::from_utf8(&self.src[self.pos..])
+            .ok()
+            .and_then(|s| s.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+        SvgError::UnexpectedChar(self.pos, ch)
+    }
+}
+
+/// Reflection of previous control point about current point.
+#[inline]
+fn reflect(cp: Option<cg::Point>, cur: cg::Point) -> cg::Point {
+    cp.map_or(cur, |c| {
+        cg::Point::new(2.0 * cur.x - c.x, 2.0 * cur.y - c.y)
+    })
+}
+
+#[derive(Default)]
+struct Bounds(Option<(cg::Point, cg::Point)>);
+
+impl Bounds {
+    fn add(&mut self, p: cg::Point) {
+        let (min, max) = self.0.get_or_insert((p, p));
+        min.x = min.x.min(p.x);
+        min.y = min.y.min(p.y);
+        max.x = max.x.max(p.x);
+        max.y = max.y.max(p.y);
+    }
+
+    fn rect(&self) -> cg::Rect {
+        match self.0 {
+            Some((min, max)) => cg::Rect::new(min.x, min.y, max.x - min.x, max.y - min.y),
+            None => cg::Rect::null(),
+        }
+    }
+}
+
+fn quad_at(p0: cg::Point, c: cg::Point, p: cg::Point, t: cg::Float) -> cg::Point {
+    let mt = 1.0 - t;
+    let (a, b, d) = (mt * mt, 2.0 * mt * t, t * t);
+    cg::Point::new(a * p0.x + b * c.x + d * p.x, a * p0.y + b * c.y + d * p.y)
+}
+
+fn cubic_at(p0: cg::Point, c1: cg::Point, c2: cg::Point, p: cg::Point, t: cg::Float) -> cg::Point {
+    let mt = 1.0 - t;
+    let (a, b, c, d) = (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+    cg::Point::new(
+        a * p0.x + b * c1.x + c * c2.x + d * p.x,
+        a * p0.y + b * c1.y + c * c2.y + d * p.y,
+    )
+}
+
+/// Parameter of quadratic extremum inside the curve.
+fn quad_extrema(p0: cg::Float, c: cg::Float, p: cg::Float) -> [Option<cg::Float>; 1] {
+    let den = p0 - 2.0 * c + p;
+    [inside((den != 0.0).then(|| (p0 - c) / den))]
+}
+
+/// Parameters of cubic extrema inside the curve, roots of derivative.
+fn cubic_extrema(
+    p0: cg::Float,
+    c1: cg::Float,
+    c2: cg::Float,
+    p: cg::Float,
+) -> [Option<cg::Float>; 2] {
+    let (a, b, c) = (c1 - p0, c2 - c1, p - c2);
+    // (a - 2b + c)t² + 2(b - a)t + a = 0
+    let qa = a - 2.0 * b + c;
+    let qb = 2.0 * (b - a);
+    if qa.abs() < 1e-12 {
+        return [inside((qb != 0.0).then(|| -a / qb)), None];
+    }
+    let disc = qb * qb - 4.0 * qa * a;
+    if disc < 0.0 {
+        return [None, None];
+    }
+    let sq = disc.sqrt();
+    [
+        inside(Some((-qb + sq) / (2.0 * qa))),
+        inside(Some((-qb - sq) / (2.0 * qa))),
+    ]
+}
+
+#[inline]
+fn inside(t: Option<cg::Float>) -> Option<cg::Float> {
+    t.filter(|t| *t > 0.0 && *t < 1.0)
+}
+
+#[inline]
+fn second_diff(a: cg::Point, b: cg::Point, c: cg::Point) -> cg::Float {
+    (a.x - 2.0 * b.x + c.x).hypot(a.y - 2.0 * b.y + c.y)
+}
+
+#[inline]
+fn lines_count(dd: cg::Float, threshold: cg::Float) -> usize {
+    (dd / threshold).sqrt().ceil().clamp(1.0, MAX_FLATTEN_LINES) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cg::{self, PathData, PathSegment as Segment, PathSvgError as SvgError};
+
+    fn p(x: cg::Float, y: cg::Float) -> cg::Point {
+        cg::Point::new(x, y)
+    }
+
+    fn assert_close(a: cg::Point, b: cg::Point) {
+        assert!(
+            (a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    fn rect_close(a: cg::Rect, b: cg::Rect) {
+        assert_close(a.origin, b.origin);
+        assert_close(
+            cg::Point::new(a.width(), a.height()),
+            cg::Point::new(b.width(), b.height()),
+        );
+    }
+
+    #[test]
+    fn svg() {
+        let data = PathData::from_svg("M1,2 3 4m1-1.5.5.0 L+1e1 2.5E-1 H5v-1 z").unwrap();
+        assert_eq!(
+            data.segments(),
+            [
+                Segment::MoveTo(p(1.0, 2.0)),
+                Segment::LineTo(p(3.0, 4.0)),
+                Segment::MoveTo(p(4.0, 2.5)),
+                Segment::LineTo(p(4.5, 2.5)),
+                Segment::LineTo(p(10.0, 0.25)),
+                Segment::LineTo(p(5.0, 0.25)),
+                Segment::LineTo(p(5.0, -0.75)),
+                Segment::Close,
+            ]
+        );
+        assert_eq!(data.current_point(), Some(p(4.0, 2.5)));
+
+        // smooth curves reflect previous control point
+        let data: PathData = "M0 0C0 1 1 1 1 0S2-1 2 0Q3 1 4 0T6 0t2 0".parse().unwrap();
+        assert_eq!(
+            data.segments()[2],
+            Segment::CurveTo(p(1.0, -1.0), p(2.0, -1.0), p(2.0, 0.0))
+        );
+        assert_eq!(
+            data.segments()[4],
+            Segment::QuadTo(p(5.0, -1.0), p(6.0, 0.0))
+        );
+        assert_eq!(
+            data.segments()[5],
+            Segment::QuadTo(p(7.0, 1.0), p(8.0, 0.0))
+        );
+
+        assert_eq!(
+            PathData::from_svg("M0 0 1"),
+            Err(SvgError::ExpectedNumber(6))
+        );
+        assert_eq!(
+            PathData::from_svg("M0 0L1e400 0"),
+            Err(SvgError::ExpectedNumber(5))
+        );
+        assert_eq!(
+            PathData::from_svg("M-1e400 0"),
+            Err(SvgError::ExpectedNumber(1))
+        );
+        assert_eq!(PathData::from_svg("L0 0"), Err(SvgError::ExpectedMoveTo(0)));
+        assert_eq!(
+            PathData::from_svg("M0 0 X"),
+            Err(SvgError::UnexpectedChar(5, 'X'))
+        );
+        assert_eq!(
+            PathData::from_svg("M0 0 A1 1 0 2 0 1 1"),
+            Err(SvgError::ExpectedFlag(12))
+        );
+        assert_eq!(
+            SvgError::ExpectedNumber(6).to_string(),
+            "expected number at 6"
+        );
+        assert!(PathData::from_svg("").unwrap().is_empty());
+
+        let svg = "M0 0L1.5 -2Q3 4 5 6C1 2 3 4 5 6ZM7 8";
+        assert_eq!(PathData::from_svg(svg).unwrap().to_svg(), svg);
+    }
+
+    #[test]
+    fn svg_arcs() {
+        // half circle from (0, 0) to (10, 0) around (5, 0), flags packed together,
+        // positive sweep goes with increasing angle
+        let data = PathData::from_svg("M0 0a5 5 0 01 10 0").unwrap();
+        assert_eq!(data.current_point(), Some(p(10.0, 0.0)));
+        rect_close(
+            data.path_bounding_box(),
+            cg::Rect::new(0.0, -5.0, 10.0, 5.0),
+        );
+
+        let data = PathData::from_svg("M0 0A5 5 0 0 0 10 0").unwrap();
+        rect_close(data.path_bounding_box(), cg::Rect::new(0.0, 0.0, 10.0, 5.0));
+
+        // radii too small are scaled up
+        let data = PathData::from_svg("M0 0A1 1 0 0 1 10 0").unwrap();
+        rect_close(
+            data.path_bounding_box(),
+            cg::Rect::new(0.0, -5.0, 10.0, 5.0),
+        );
+
+        // half of ellipse with major axis along y
+        let data = PathData::from_svg("M0 -10A10 5 90 0 1 0 10").unwrap();
+        rect_close(
+            data.path_bounding_box(),
+            cg::Rect::new(0.0, -10.0, 5.0, 20.0),
+        );
+
+        // three quarters of circle around (0, 5)
+        let data = PathData::from_svg("M0 0A5 5 0 1 0 5 5").unwrap();
+        rect_close(
+            data.path_bounding_box(),
+            cg::Rect::new(-5.0, 0.0, 10.0, 10.0),
+        );
+
+        let data = PathData::from_svg("M0 0A0 5 0 0 1 10 0").unwrap();
+        assert_eq!(data.segments()[1], Segment::LineTo(p(10.0, 0.0)));
+    }
+
+    #[test]
+    fn arcs() {
+        let mut data = PathData::new();
+        data.add_arc(
+            p(0.0, 0.0),
+            2.0,
+            0.0,
+            std::f64::consts::PI as cg::Float,
+            false,
+        );
+        // move and two quarter curves
+        assert_eq!(data.segments().len(), 3);
+        rect_close(data.path_bounding_box(), cg::Rect::new(-2.0, 0.0, 4.0, 2.0));
+        assert_close(data.current_point().unwrap(), p(-2.0, 0.0));
+
+        // clockwise goes through negative y
+        let mut data = PathData::new();
+        data.move_to(p(5.0, 5.0));
+        data.add_arc(
+            p(0.0, 0.0),
+            2.0,
+            0.0,
+            std::f64::consts::PI as cg::Float,
+            true,
+        );
+        assert_eq!(data.segments()[1], Segment::LineTo(p(2.0, 0.0)));
+        rect_close(
+            data.path_bounding_box(),
+            cg::Rect::new(-2.0, -2.0, 7.0, 7.0),
+        );
+
+        let mut data = PathData::new();
+        data.move_to(p(0.0, 0.0));
+        data.arc_to(p(10.0, 0.0), p(10.0, 10.0), 2.0);
+        let Segment::LineTo(t1) = data.segments()[1] else {
+            panic!("{:?}", data.segments());
+        };
+        assert_close(t1, p(8.0, 0.0));
+        assert_close(data.current_point().unwrap(), p(10.0, 2.0));
+        rect_close(data.path_bounding_box(), cg::Rect::new(0.0, 0.0, 10.0, 2.0));
+
+        // collinear points give line
+        data.arc_to(p(10.0, 5.0), p(10.0, 9.0), 2.0);
+        assert_eq!(data.segments().last(), Some(&Segment::LineTo(p(10.0, 5.0))));
+
+        // nothing without current point
+        let mut data = PathData::new();
+        data.arc_to(p(10.0, 0.0), p(10.0, 10.0), 2.0);
+        data.line_to(p(1.0, 1.0));
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn geometry() {
+        let data = PathData::from_svg("M0 0C0 10 10 10 10 0Q5 -10 0 0").unwrap();
+        assert_eq!(data.bounding_box(), cg::Rect::new(0.0, -10.0, 10.0, 20.0));
+        rect_close(
+            data.path_bounding_box(),
+            cg::Rect::new(0.0, -5.0, 10.0, 12.5),
+        );
+        assert!(PathData::new().bounding_box().is_null());
+
+        let flat = data.flatten(0.1);
+        assert!(
+            flat.segments()[1..]
+                .iter()
+                .all(|s| matches!(s, Segment::LineTo(_)))
+        );
+        assert!(flat.segments().len() > 10);
+        let (f, b) = (flat.bounding_box(), data.path_bounding_box());
+        assert_eq!(f.union(&b), b);
+        assert!(b.height() - f.height() < 0.1);
+        assert_eq!(flat.current_point(), data.current_point());
+        assert!(data.flatten(100.0).segments().len() < flat.segments().len());
+
+        let t = cg::AffineTransform::new_translation(1.0, 2.0).scale(2.0, 2.0);
+        let moved = data.apply_affine_transform(&t);
+        // scaled, then translated
+        assert_eq!(moved.bounding_box(), cg::Rect::new(1.0, -18.0, 20.0, 40.0));
+        assert_eq!(moved.current_point(), Some(p(1.0, 2.0)));
+
+        let mut r = PathData::new();
+        r.add_rect(cg::Rect::new(1.0, 1.0, -1.0, 2.0));
+        assert_eq!(r.to_svg(), "M0 1L1 1L1 3L0 3Z");
+        let copy: PathData = r.segments().iter().copied().collect();
+        assert_eq!(copy, r);
+    }
+}
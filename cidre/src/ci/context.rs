@@ -54,7 +54,7 @@ impl Context {
     }
 }
 
-#[link(name = "ci", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ci", kind = "static"))]
 unsafe extern "C" {
     static CI_CONTEXT: &'static objc::Class<Context>;
 }
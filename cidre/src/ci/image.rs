@@ -466,7 +466,7 @@ impl ImageOpt {
     }
 }
 
-#[link(name = "ci", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ci", kind = "static"))]
 #[api::weak]
 unsafe extern "C" {
     static CI_IMAGE: &'static objc::Class<Image>;
@@ -96,7 +96,7 @@ impl ci::Vec {
     pub fn w(&self) -> cg::Float;
 }
 
-#[link(name = "ci", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ci", kind = "static"))]
 unsafe extern "C" {
     static CI_VECTOR: &'static objc::Class<Vec>;
 }
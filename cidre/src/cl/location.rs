@@ -92,7 +92,7 @@ impl Location {
     pub fn distance_from_location(&self, location: &cl::Location) -> cl::LocationDistance;
 }
 
-#[link(name = "cl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "cl", kind = "static"))]
 unsafe extern "C" {
     static CL_LOCATION: &'static objc::Class<Location>;
 }
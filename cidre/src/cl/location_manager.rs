@@ -115,7 +115,7 @@ pub trait Delegate {
     );
 }
 
-#[link(name = "cl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "cl", kind = "static"))]
 unsafe extern "C" {
     static CL_LOCATION_MANAGER: &'static objc::Class<LocationManager>;
 }
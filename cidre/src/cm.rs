@@ -52,6 +52,12 @@ pub use time::TimeRoundingMethod;
 pub use time::TimeScale;
 pub use time::TimeValue;
 
+pub mod nal;
+
+pub mod iso;
+
+pub mod hls;
+
 pub mod sample_buffer;
 
 #[cfg(feature = "cat")]
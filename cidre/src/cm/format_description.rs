@@ -261,6 +261,16 @@ impl FormatDesc {
         self.video_cfg(cf::str!(c"hvcC"))
     }
 
+    /// Parsed `avcC` atom.
+    pub fn avc_config(&self) -> Option<cm::nal::h264::AvcConfig> {
+        cm::nal::h264::AvcConfig::parse(&self.avcc()?).ok()
+    }
+
+    /// Parsed `hvcC` atom.
+    pub fn hvc_config(&self) -> Option<cm::nal::hevc::HvcConfig> {
+        cm::nal::hevc::HvcConfig::parse(&self.hvcc()?).ok()
+    }
+
     pub fn verbatim_sample_desc(&self) -> Option<&cf::Data> {
         unsafe {
             let key = FormatDescExtKey::verbatim_sample_desc();
@@ -275,6 +285,38 @@ impl FormatDesc {
         }
     }
 
+    /// Parsed `verbatim_iso_sample_entry`.
+    pub fn iso_sample_entry(&self) -> Option<cm::iso::SampleEntry> {
+        cm::iso::SampleEntry::with_bytes(self.verbatim_iso_sample_entry()?.as_slice()).ok()
+    }
+
+    /// RFC 6381 codec string suitable for HLS `CODECS` attribute, e.g. `avc1.640028`
+    /// or `mp4a.40.2`.
+    pub fn codecs_string(&self) -> Option<String> {
+        let sub_type = self.media_sub_type().to_be_bytes();
+        let res = match &sub_type {
+            b"avc1" | b"avc3" => {
+                let s = self.avc_config()?.codec_string();
+                format!("{}{}", std::str::from_utf8(&sub_type).ok()?, &s[4..])
+            }
+            b"hvc1" | b"hev1" => {
+                let s = self.hvc_config()?.codec_string();
+                format!("{}{}", std::str::from_utf8(&sub_type).ok()?, &s[4..])
+            }
+            b"aac " => "mp4a.40.2".into(),
+            b"aach" => "mp4a.40.5".into(),
+            b"aacp" => "mp4a.40.29".into(),
+            b".mp3" => "mp4a.40.34".into(),
+            b"ac-3" => "ac-3".into(),
+            b"ec-3" => "ec-3".into(),
+            b"alac" => "alac".into(),
+            b"flac" => "fLaC".into(),
+            b"opus" => "Opus".into(),
+            _ => return None,
+        };
+        Some(res)
+    }
+
     pub fn create_in(
         media_type: MediaType,
         media_sub_type: FourCharCode,
@@ -444,6 +486,39 @@ impl VideoFormatDesc {
         }
     }
 
+    /// Creates format description from SPS and PPS of `avcC` record.
+    pub fn with_avc_config(cfg: &cm::nal::h264::AvcConfig) -> os::Result<arc::R<VideoFormatDesc>> {
+        let sets: Vec<&[u8]> = cfg.param_sets().collect();
+        let pointers: Vec<*const u8> = sets.iter().map(|s| s.as_ptr()).collect();
+        let sizes: Vec<usize> = sets.iter().map(|s| s.len()).collect();
+        unsafe {
+            os::result_unchecked(|res| {
+                CMVideoFormatDescriptionCreateFromH264ParameterSets(
+                    None,
+                    sets.len(),
+                    pointers.as_ptr(),
+                    sizes.as_ptr(),
+                    cfg.nal_unit_header_length as i32,
+                    res,
+                )
+            })
+        }
+    }
+
+    /// Creates format description from parameter sets of `hvcC` record.
+    pub fn with_hvc_config(cfg: &cm::nal::hevc::HvcConfig) -> os::Result<arc::R<VideoFormatDesc>> {
+        let sets: Vec<&[u8]> = cfg.param_sets().collect();
+        let pointers: Vec<*const u8> = sets.iter().map(|s| s.as_ptr()).collect();
+        let sizes: Vec<usize> = sets.iter().map(|s| s.len()).collect();
+        Self::with_hevc_param_sets(
+            sets.len(),
+            &pointers,
+            &sizes,
+            cfg.nal_unit_header_length as i32,
+            None,
+        )
+    }
+
     #[doc(alias = "CMVideoFormatDescriptionCreateFromHEVCParameterSets")]
     #[inline]
     pub fn with_hevc_param_sets(
@@ -0,0 +1,488 @@
+//! Pure Rust HTTP Live Streaming playlists ([RFC 8216](https://www.rfc-editor.org/rfc/rfc8216)).
+//!
+//! Typed media playlists (segments, `EXT-X-MAP`, byte ranges, discontinuities,
+//! program date time, keys and low-latency parts) and multivariant playlists
+//! (variants and renditions). Parsers are strict: malformed attribute lists,
+//! durations exceeding target duration, undeclared rendition groups or too low
+//! `EXT-X-VERSION` are errors. `Display` writes canonical playlists.
+//!
+//! ```
+//! use cidre::cm::hls;
+//!
+//! let mut playlist = hls::MediaPlaylist::new(6);
+//! let map = hls::Map::new("init.mp4");
+//! playlist.segments.push(hls::Segment::new("0.m4s", 6.006).with_map(map.clone()));
+//! playlist.segments.push(hls::Segment::new("1.m4s", 5.994).with_map(map));
+//! playlist.end_list = true;
+//!
+//! let text = playlist.to_string();
+//! assert!(text.starts_with("#EXTM3U\n#EXT-X-VERSION:6\n"));
+//! assert_eq!(hls::MediaPlaylist::parse(&text).unwrap().segments.len(), 2);
+//! ```
+
+pub mod media;
+pub mod multivariant;
+
+pub use media::ByteRange;
+pub use media::Key;
+pub use media::Map;
+pub use media::MediaPlaylist;
+pub use media::Part;
+pub use media::PlaylistType;
+pub use media::PreloadHint;
+pub use media::PreloadHintType;
+pub use media::Segment;
+pub use media::ServerControl;
+pub use multivariant::ClosedCaptions;
+pub use multivariant::IFrameVariant;
+pub use multivariant::MultivariantPlaylist;
+pub use multivariant::Rendition;
+pub use multivariant::RenditionType;
+pub use multivariant::Resolution;
+pub use multivariant::Variant;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    /// 1-based line number, 0 if error is not related to a single line.
+    pub line: usize,
+    pub kind: ErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Playlist doesn't start with `#EXTM3U`.
+    MissingHeader,
+
+    /// Required tag is missing.
+    MissingTag(&'static str),
+
+    /// Required attribute is missing.
+    MissingAttr(&'static str),
+
+    /// Attribute list is malformed or has duplicate attributes.
+    InvalidAttrList,
+
+    /// Tag or attribute has invalid value.
+    InvalidValue(&'static str),
+
+    /// Tag appears more than once.
+    DuplicateTag(&'static str),
+
+    /// `EXTINF` or `EXT-X-STREAM-INF` is not followed by URI.
+    MissingUri,
+
+    /// URI line without preceding `EXTINF` or `EXT-X-STREAM-INF`.
+    UnexpectedUri,
+
+    /// Media playlist tag in multivariant playlist or vice versa.
+    MixedPlaylist,
+
+    /// Segment duration rounded to the nearest integer exceeds `EXT-X-TARGETDURATION`.
+    DurationExceedsTarget,
+
+    /// Playlist uses features of newer protocol version than declared.
+    Version { declared: u8, required: u8 },
+
+    /// Variant references rendition group which is not declared by `EXT-X-MEDIA`.
+    UnknownGroup(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.line != 0 {
+            write!(f, "line {}: ", self.line)?;
+        }
+        match &self.kind {
+            ErrorKind::MissingHeader => f.write_str("missing #EXTM3U header"),
+            ErrorKind::MissingTag(tag) => write!(f, "missing #{tag}"),
+            ErrorKind::MissingAttr(name) => write!(f, "missing {name} attribute"),
+            ErrorKind::InvalidAttrList => f.write_str("invalid attribute list"),
+            ErrorKind::InvalidValue(name) => write!(f, "invalid {name} value"),
+            ErrorKind::DuplicateTag(tag) => write!(f, "duplicate #{tag}"),
+            ErrorKind::MissingUri => f.write_str("missing URI"),
+            ErrorKind::UnexpectedUri => f.write_str("unexpected URI"),
+            ErrorKind::MixedPlaylist => {
+                f.write_str("media and multivariant playlist tags are mixed")
+            }
+            ErrorKind::DurationExceedsTarget => {
+                f.write_str("segment duration exceeds target duration")
+            }
+            ErrorKind::Version { declared, required } => {
+                write!(f, "version {declared} is declared, {required} is required")
+            }
+            ErrorKind::UnknownGroup(id) => write!(f, "unknown group {id:?}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<Ok = ()> = std::result::Result<Ok, Error>;
+
+type KindResult<Ok = ()> = std::result::Result<Ok, ErrorKind>;
+
+/// Tags which may appear only in multivariant playlists.
+const MULTIVARIANT_TAGS: &[&str] = &[
+    "EXT-X-MEDIA",
+    "EXT-X-STREAM-INF",
+    "EXT-X-I-FRAME-STREAM-INF",
+    "EXT-X-SESSION-DATA",
+    "EXT-X-SESSION-KEY",
+    "EXT-X-CONTENT-STEERING",
+];
+
+/// Tags which may appear only in media playlists.
+const MEDIA_TAGS: &[&str] = &[
+    "EXTINF",
+    "EXT-X-TARGETDURATION",
+    "EXT-X-MEDIA-SEQUENCE",
+    "EXT-X-DISCONTINUITY-SEQUENCE",
+    "EXT-X-PLAYLIST-TYPE",
+    "EXT-X-I-FRAMES-ONLY",
+    "EXT-X-ENDLIST",
+    "EXT-X-BYTERANGE",
+    "EXT-X-DISCONTINUITY",
+    "EXT-X-KEY",
+    "EXT-X-MAP",
+    "EXT-X-PROGRAM-DATE-TIME",
+    "EXT-X-GAP",
+    "EXT-X-PART",
+    "EXT-X-PART-INF",
+    "EXT-X-SERVER-CONTROL",
+    "EXT-X-PRELOAD-HINT",
+];
+
+/// Line of the playlist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Line<'a> {
+    /// Tag name without `#` and optional value after `:`.
+    Tag(&'a str, Option<&'a str>),
+    Uri(&'a str),
+}
+
+/// Non blank, non comment lines with their 1-based numbers.
+/// Fails if the first line is not `#EXTM3U`.
+pub(crate) fn lines(s: &str) -> Result<impl Iterator<Item = (usize, Line<'_>)>> {
+    let mut iter = s.lines().enumerate();
+    match iter.next() {
+        Some((_, "#EXTM3U")) => {}
+        _ => {
+            return Err(Error {
+                line: 1,
+                kind: ErrorKind::MissingHeader,
+            });
+        }
+    }
+    Ok(iter.filter_map(|(i, line)| {
+        let line = line.trim_end();
+        let res = if let Some(tag) = line.strip_prefix('#').filter(|t| t.starts_with("EXT")) {
+            match tag.split_once(':') {
+                Some((name, value)) => Line::Tag(name, Some(value)),
+                None => Line::Tag(tag, None),
+            }
+        } else if line.is_empty() || line.starts_with('#') {
+            return None;
+        } else {
+            Line::Uri(line)
+        };
+        Some((i + 1, res))
+    }))
+}
+
+pub(crate) fn is_media_tag(name: &str) -> bool {
+    MEDIA_TAGS.contains(&name)
+}
+
+pub(crate) fn is_multivariant_tag(name: &str) -> bool {
+    MULTIVARIANT_TAGS.contains(&name)
+}
+
+/// Value of the tag which requires one.
+pub(crate) fn value<'a>(name: &'static str, value: Option<&'a str>) -> KindResult<&'a str> {
+    value.ok_or(ErrorKind::InvalidValue(name))
+}
+
+pub(crate) fn decimal_int<T: std::str::FromStr>(name: &'static str, s: &str) -> KindResult<T> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ErrorKind::InvalidValue(name));
+    }
+    s.parse().map_err(|_| ErrorKind::InvalidValue(name))
+}
+
+pub(crate) fn decimal_float(name: &'static str, s: &str) -> KindResult<f64> {
+    let unsigned = s.strip_prefix('-').unwrap_or(s);
+    let (int, fract) = unsigned.split_once('.').unwrap_or((unsigned, "0"));
+    if int.is_empty()
+        || !int.bytes().all(|b| b.is_ascii_digit())
+        || !fract.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(ErrorKind::InvalidValue(name));
+    }
+    s.parse().map_err(|_| ErrorKind::InvalidValue(name))
+}
+
+/// `YYYY-MM-DDThh:mm:ss[.SSS](Z|+hh:mm|-hh:mm)`
+pub(crate) fn is_date_time(s: &str) -> bool {
+    fn digits(s: &[u8]) -> bool {
+        s.iter().all(u8::is_ascii_digit)
+    }
+    let b = s.as_bytes();
+    if b.len() < 20
+        || !digits(&b[0..4])
+        || b[4] != b'-'
+        || !digits(&b[5..7])
+        || b[7] != b'-'
+        || !digits(&b[8..10])
+        || b[10] != b'T'
+        || !digits(&b[11..13])
+        || b[13] != b':'
+        || !digits(&b[14..16])
+        || b[16] != b':'
+        || !digits(&b[17..19])
+    {
+        return false;
+    }
+    let mut rest = &b[19..];
+    if let Some(fract) = rest.strip_prefix(b".") {
+        let n = fract.iter().take_while(|b| b.is_ascii_digit()).count();
+        if n == 0 {
+            return false;
+        }
+        rest = &fract[n..];
+    }
+    match rest {
+        b"Z" => true,
+        [b'+' | b'-', h0, h1, b':', m0, m1] => digits(&[*h0, *h1, *m0, *m1]),
+        _ => false,
+    }
+}
+
+/// Parsed attribute list: `NAME=VALUE,NAME="quoted value"`.
+pub(crate) struct Attrs<'a> {
+    list: Vec<(&'a str, &'a str, bool)>,
+}
+
+impl<'a> Attrs<'a> {
+    pub fn parse(s: &'a str) -> KindResult<Self> {
+        let mut list: Vec<(&str, &str, bool)> = Vec::new();
+        let mut rest = s;
+        while !rest.is_empty() {
+            let (name, tail) = rest.split_once('=').ok_or(ErrorKind::InvalidAttrList)?;
+            if name.is_empty()
+                || !name
+                    .bytes()
+                    .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'-')
+                || list.iter().any(|(n, _, _)| *n == name)
+            {
+                return Err(ErrorKind::InvalidAttrList);
+            }
+            let (value, quoted, tail) = if let Some(tail) = tail.strip_prefix('"') {
+                let end = tail.find('"').ok_or(ErrorKind::InvalidAttrList)?;
+                (&tail[..end], true, &tail[end + 1..])
+            } else {
+                let end = tail.find(',').unwrap_or(tail.len());
+                let value = &tail[..end];
+                if value.is_empty() || value.contains('"') {
+                    return Err(ErrorKind::InvalidAttrList);
+                }
+                (value, false, &tail[end..])
+            };
+            list.push((name, value, quoted));
+            rest = match tail.strip_prefix(',') {
+                Some("") => return Err(ErrorKind::InvalidAttrList),
+                Some(tail) => tail,
+                None if tail.is_empty() => tail,
+                None => return Err(ErrorKind::InvalidAttrList),
+            };
+        }
+        Ok(Self { list })
+    }
+
+    fn get(&self, name: &str) -> Option<(&'a str, bool)> {
+        self.list
+            .iter()
+            .find(|(n, _, _)| *n == name)
+            .map(|(_, v, q)| (*v, *q))
+    }
+
+    pub fn quoted(&self, name: &'static str) -> KindResult<Option<&'a str>> {
+        match self.get(name) {
+            None => Ok(None),
+            Some((v, true)) => Ok(Some(v)),
+            Some((_, false)) => Err(ErrorKind::InvalidValue(name)),
+        }
+    }
+
+    pub fn req_quoted(&self, name: &'static str) -> KindResult<&'a str> {
+        self.quoted(name)?.ok_or(ErrorKind::MissingAttr(name))
+    }
+
+    pub fn enumerated(&self, name: &'static str) -> KindResult<Option<&'a str>> {
+        match self.get(name) {
+            None => Ok(None),
+            Some((v, false)) => Ok(Some(v)),
+            Some((_, true)) => Err(ErrorKind::InvalidValue(name)),
+        }
+    }
+
+    pub fn req_enumerated(&self, name: &'static str) -> KindResult<&'a str> {
+        self.enumerated(name)?.ok_or(ErrorKind::MissingAttr(name))
+    }
+
+    pub fn int<T: std::str::FromStr>(&self, name: &'static str) -> KindResult<Option<T>> {
+        self.enumerated(name)?
+            .map(|v| decimal_int(name, v))
+            .transpose()
+    }
+
+    pub fn float(&self, name: &'static str) -> KindResult<Option<f64>> {
+        self.enumerated(name)?
+            .map(|v| decimal_float(name, v))
+            .transpose()
+    }
+
+    pub fn flag(&self, name: &'static str) -> KindResult<bool> {
+        match self.enumerated(name)? {
+            None | Some("NO") => Ok(false),
+            Some("YES") => Ok(true),
+            Some(_) => Err(ErrorKind::InvalidValue(name)),
+        }
+    }
+}
+
+/// Writes `#TAG:` followed by comma separated attributes.
+pub(crate) struct AttrWriter<'a, 'b> {
+    f: &'a mut std::fmt::Formatter<'b>,
+    first: bool,
+}
+
+impl<'a, 'b> AttrWriter<'a, 'b> {
+    pub fn new(
+        f: &'a mut std::fmt::Formatter<'b>,
+        tag: &str,
+    ) -> std::result::Result<Self, std::fmt::Error> {
+        write!(f, "#{tag}:")?;
+        Ok(Self { f, first: true })
+    }
+
+    pub fn raw(&mut self, name: &str, value: impl std::fmt::Display) -> std::fmt::Result {
+        if !std::mem::take(&mut self.first) {
+            self.f.write_str(",")?;
+        }
+        write!(self.f, "{name}={value}")
+    }
+
+    pub fn quoted(&mut self, name: &str, value: impl std::fmt::Display) -> std::fmt::Result {
+        self.raw(name, format_args!("\"{value}\""))
+    }
+
+    pub fn opt_raw(
+        &mut self,
+        name: &str,
+        value: Option<impl std::fmt::Display>,
+    ) -> std::fmt::Result {
+        match value {
+            Some(v) => self.raw(name, v),
+            None => Ok(()),
+        }
+    }
+
+    pub fn opt_quoted(
+        &mut self,
+        name: &str,
+        value: Option<impl std::fmt::Display>,
+    ) -> std::fmt::Result {
+        match value {
+            Some(v) => self.quoted(name, v),
+            None => Ok(()),
+        }
+    }
+
+    pub fn flag(&mut self, name: &str, value: bool) -> std::fmt::Result {
+        if value { self.raw(name, "YES") } else { Ok(()) }
+    }
+
+    pub fn end(self) -> std::fmt::Result {
+        self.f.write_str("\n")
+    }
+}
+
+/// `EXT-X-START` tag.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Start {
+    /// Offset in seconds, negative from the end of the playlist.
+    pub time_offset: f64,
+    pub precise: bool,
+}
+
+impl Start {
+    pub(crate) fn parse(value: Option<&str>) -> KindResult<Self> {
+        let attrs = Attrs::parse(self::value("EXT-X-START", value)?)?;
+        Ok(Self {
+            time_offset: attrs
+                .float("TIME-OFFSET")?
+                .ok_or(ErrorKind::MissingAttr("TIME-OFFSET"))?,
+            precise: attrs.flag("PRECISE")?,
+        })
+    }
+
+    pub(crate) fn write(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut w = AttrWriter::new(f, "EXT-X-START")?;
+        w.raw("TIME-OFFSET", self.time_offset)?;
+        w.flag("PRECISE", self.precise)?;
+        w.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::hls;
+
+    #[test]
+    fn attrs() {
+        let attrs =
+            hls::Attrs::parse(r#"BANDWIDTH=1280000,CODECS="avc1.640028,mp4a.40.2",AUTOSELECT=YES"#)
+                .unwrap();
+        assert_eq!(attrs.int::<u64>("BANDWIDTH").unwrap(), Some(1280000));
+        assert_eq!(
+            attrs.quoted("CODECS").unwrap(),
+            Some("avc1.640028,mp4a.40.2")
+        );
+        assert!(attrs.flag("AUTOSELECT").unwrap());
+        assert!(!attrs.flag("DEFAULT").unwrap());
+        assert_eq!(
+            attrs.quoted("BANDWIDTH").err(),
+            Some(hls::ErrorKind::InvalidValue("BANDWIDTH"))
+        );
+
+        for invalid in ["A=1,", "A=1,A=2", "a=1", "A=\"1", "A=\"1\"B=2", "A=", "A"] {
+            assert!(hls::Attrs::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(hls::decimal_float("X", "6.006"), Ok(6.006));
+        assert_eq!(hls::decimal_float("X", "-1.5"), Ok(-1.5));
+        assert_eq!(hls::decimal_float("X", "10"), Ok(10.0));
+        assert!(hls::decimal_float("X", "1e3").is_err());
+        assert!(hls::decimal_float("X", ".5").is_err());
+        assert!(hls::decimal_int::<u64>("X", "+1").is_err());
+    }
+
+    #[test]
+    fn date_time() {
+        assert!(hls::is_date_time("2010-02-19T14:54:23.031+08:00"));
+        assert!(hls::is_date_time("2010-02-19T14:54:23Z"));
+        assert!(!hls::is_date_time("2010-02-19 14:54:23Z"));
+        assert!(!hls::is_date_time("2010-02-19T14:54:23."));
+        assert!(!hls::is_date_time("2010-02-19T14:54:23"));
+    }
+
+    #[test]
+    fn header() {
+        let err = hls::MediaPlaylist::parse("#EXT-X-VERSION:3\n").unwrap_err();
+        assert_eq!(err.kind, hls::ErrorKind::MissingHeader);
+        assert_eq!(err.to_string(), "line 1: missing #EXTM3U header");
+    }
+}
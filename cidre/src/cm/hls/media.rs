@@ -0,0 +1,911 @@
+use std::fmt::{self, Write};
+
+use crate::cm::hls::{self, AttrWriter, Attrs, Error, ErrorKind, KindResult, Line, Result, Start};
+
+/// `EXT-X-BYTERANGE` or `BYTERANGE` attribute: `<len>[@<offset>]`.
+///
+/// If `offset` is `None` sub-range begins at the next byte following
+/// the sub-range of the previous segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub len: u64,
+    pub offset: Option<u64>,
+}
+
+impl ByteRange {
+    pub const fn new(len: u64, offset: u64) -> Self {
+        Self {
+            len,
+            offset: Some(offset),
+        }
+    }
+
+    fn parse(name: &'static str, s: &str) -> KindResult<Self> {
+        let (len, offset) = match s.split_once('@') {
+            Some((len, offset)) => (len, Some(hls::decimal_int(name, offset)?)),
+            None => (s, None),
+        };
+        Ok(Self {
+            len: hls::decimal_int(name, len)?,
+            offset,
+        })
+    }
+}
+
+impl fmt::Display for ByteRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.len)?;
+        if let Some(offset) = self.offset {
+            write!(f, "@{offset}")?;
+        }
+        Ok(())
+    }
+}
+
+/// `EXT-X-MAP`: media initialization section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    pub uri: String,
+    /// Offset is required for map byte range.
+    pub byte_range: Option<ByteRange>,
+}
+
+impl Map {
+    pub fn new(uri: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            byte_range: None,
+        }
+    }
+
+    fn parse(value: Option<&str>) -> KindResult<Self> {
+        let attrs = Attrs::parse(hls::value("EXT-X-MAP", value)?)?;
+        let byte_range = attrs
+            .quoted("BYTERANGE")?
+            .map(|s| ByteRange::parse("BYTERANGE", s))
+            .transpose()?;
+        if byte_range.is_some_and(|r| r.offset.is_none()) {
+            return Err(ErrorKind::InvalidValue("BYTERANGE"));
+        }
+        Ok(Self {
+            uri: attrs.req_quoted("URI")?.to_string(),
+            byte_range,
+        })
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut w = AttrWriter::new(f, "EXT-X-MAP")?;
+        w.quoted("URI", &self.uri)?;
+        w.opt_quoted("BYTERANGE", self.byte_range)?;
+        w.end()
+    }
+}
+
+/// `EXT-X-KEY`: encryption of the following segments.
+///
+/// `METHOD=NONE` is represented by absence of the key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Key {
+    /// `AES-128`, `SAMPLE-AES`, `SAMPLE-AES-CTR`...
+    pub method: String,
+    pub uri: String,
+    pub iv: Option<[u8; 16]>,
+    pub key_format: Option<String>,
+    pub key_format_versions: Option<String>,
+}
+
+impl Key {
+    pub const AES_128: &str = "AES-128";
+    pub const SAMPLE_AES: &str = "SAMPLE-AES";
+
+    pub fn new(method: impl Into<String>, uri: impl Into<String>) -> Self {
+        Self {
+            method: method.into(),
+            uri: uri.into(),
+            iv: None,
+            key_format: None,
+            key_format_versions: None,
+        }
+    }
+
+    fn parse(value: Option<&str>) -> KindResult<Option<Self>> {
+        let attrs = Attrs::parse(hls::value("EXT-X-KEY", value)?)?;
+        let method = attrs.req_enumerated("METHOD")?;
+        if method == "NONE" {
+            if attrs.quoted("URI")?.is_some() {
+                return Err(ErrorKind::InvalidValue("URI"));
+            }
+            return Ok(None);
+        }
+        let iv = match attrs.enumerated("IV")? {
+            Some(s) => {
+                let hex = s
+                    .strip_prefix("0x")
+                    .or_else(|| s.strip_prefix("0X"))
+                    .filter(|h| h.len() == 32 && h.bytes().all(|b| b.is_ascii_hexdigit()))
+                    .ok_or(ErrorKind::InvalidValue("IV"))?;
+                let mut iv = [0u8; 16];
+                for (i, b) in iv.iter_mut().enumerate() {
+                    *b = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).unwrap();
+                }
+                Some(iv)
+            }
+            None => None,
+        };
+        Ok(Some(Self {
+            method: method.to_string(),
+            uri: attrs.req_quoted("URI")?.to_string(),
+            iv,
+            key_format: attrs.quoted("KEYFORMAT")?.map(str::to_string),
+            key_format_versions: attrs.quoted("KEYFORMATVERSIONS")?.map(str::to_string),
+        }))
+    }
+
+    fn write(key: Option<&Self>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut w = AttrWriter::new(f, "EXT-X-KEY")?;
+        let Some(key) = key else {
+            w.raw("METHOD", "NONE")?;
+            return w.end();
+        };
+        w.raw("METHOD", &key.method)?;
+        w.quoted("URI", &key.uri)?;
+        if let Some(iv) = key.iv {
+            let mut hex = String::with_capacity(34);
+            hex.push_str("0x");
+            for b in iv {
+                write!(hex, "{b:02X}")?;
+            }
+            w.raw("IV", hex)?;
+        }
+        w.opt_quoted("KEYFORMAT", key.key_format.as_ref())?;
+        w.opt_quoted("KEYFORMATVERSIONS", key.key_format_versions.as_ref())?;
+        w.end()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaylistType {
+    /// Segments can only be added to the end of the playlist.
+    Event,
+    /// Playlist can't change.
+    Vod,
+}
+
+/// `EXT-X-PART`: partial segment of low-latency HLS.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Part {
+    pub uri: String,
+    pub duration: f64,
+    /// Part contains independent frame.
+    pub independent: bool,
+    pub byte_range: Option<ByteRange>,
+    pub gap: bool,
+}
+
+impl Part {
+    pub fn new(uri: impl Into<String>, duration: f64) -> Self {
+        Self {
+            uri: uri.into(),
+            duration,
+            independent: false,
+            byte_range: None,
+            gap: false,
+        }
+    }
+
+    fn parse(value: Option<&str>) -> KindResult<Self> {
+        let attrs = Attrs::parse(hls::value("EXT-X-PART", value)?)?;
+        Ok(Self {
+            uri: attrs.req_quoted("URI")?.to_string(),
+            duration: attrs
+                .float("DURATION")?
+                .ok_or(ErrorKind::MissingAttr("DURATION"))?,
+            independent: attrs.flag("INDEPENDENT")?,
+            byte_range: attrs
+                .quoted("BYTERANGE")?
+                .map(|s| ByteRange::parse("BYTERANGE", s))
+                .transpose()?,
+            gap: attrs.flag("GAP")?,
+        })
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut w = AttrWriter::new(f, "EXT-X-PART")?;
+        w.raw("DURATION", self.duration)?;
+        w.quoted("URI", &self.uri)?;
+        w.flag("INDEPENDENT", self.independent)?;
+        w.opt_quoted("BYTERANGE", self.byte_range)?;
+        w.flag("GAP", self.gap)?;
+        w.end()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreloadHintType {
+    Part,
+    Map,
+}
+
+/// `EXT-X-PRELOAD-HINT`: resource the client should request in advance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreloadHint {
+    pub typ: PreloadHintType,
+    pub uri: String,
+    pub byte_range_start: Option<u64>,
+    pub byte_range_len: Option<u64>,
+}
+
+impl PreloadHint {
+    fn parse(value: Option<&str>) -> KindResult<Self> {
+        let attrs = Attrs::parse(hls::value("EXT-X-PRELOAD-HINT", value)?)?;
+        let typ = match attrs.req_enumerated("TYPE")? {
+            "PART" => PreloadHintType::Part,
+            "MAP" => PreloadHintType::Map,
+            _ => return Err(ErrorKind::InvalidValue("TYPE")),
+        };
+        Ok(Self {
+            typ,
+            uri: attrs.req_quoted("URI")?.to_string(),
+            byte_range_start: attrs.int("BYTERANGE-START")?,
+            byte_range_len: attrs.int("BYTERANGE-LENGTH")?,
+        })
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut w = AttrWriter::new(f, "EXT-X-PRELOAD-HINT")?;
+        let typ = match self.typ {
+            PreloadHintType::Part => "PART",
+            PreloadHintType::Map => "MAP",
+        };
+        w.raw("TYPE", typ)?;
+        w.quoted("URI", &self.uri)?;
+        w.opt_raw("BYTERANGE-START", self.byte_range_start)?;
+        w.opt_raw("BYTERANGE-LENGTH", self.byte_range_len)?;
+        w.end()
+    }
+}
+
+/// `EXT-X-SERVER-CONTROL`: delivery directives supported by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ServerControl {
+    pub can_skip_until: Option<f64>,
+    pub can_skip_date_ranges: bool,
+    pub hold_back: Option<f64>,
+    pub part_hold_back: Option<f64>,
+    pub can_block_reload: bool,
+}
+
+impl ServerControl {
+    fn parse(value: Option<&str>) -> KindResult<Self> {
+        let attrs = Attrs::parse(hls::value("EXT-X-SERVER-CONTROL", value)?)?;
+        Ok(Self {
+            can_skip_until: attrs.float("CAN-SKIP-UNTIL")?,
+            can_skip_date_ranges: attrs.flag("CAN-SKIP-DATERANGES")?,
+            hold_back: attrs.float("HOLD-BACK")?,
+            part_hold_back: attrs.float("PART-HOLD-BACK")?,
+            can_block_reload: attrs.flag("CAN-BLOCK-RELOAD")?,
+        })
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut w = AttrWriter::new(f, "EXT-X-SERVER-CONTROL")?;
+        w.flag("CAN-BLOCK-RELOAD", self.can_block_reload)?;
+        w.opt_raw("CAN-SKIP-UNTIL", self.can_skip_until)?;
+        w.flag("CAN-SKIP-DATERANGES", self.can_skip_date_ranges)?;
+        w.opt_raw("HOLD-BACK", self.hold_back)?;
+        w.opt_raw("PART-HOLD-BACK", self.part_hold_back)?;
+        w.end()
+    }
+}
+
+/// Media segment with the tags applied to it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Segment {
+    pub uri: String,
+    /// `EXTINF` duration in seconds.
+    pub duration: f64,
+    /// `EXTINF` title.
+    pub title: String,
+    pub byte_range: Option<ByteRange>,
+    /// `EXT-X-DISCONTINUITY` precedes the segment.
+    pub discontinuity: bool,
+    /// `EXT-X-PROGRAM-DATE-TIME` in ISO 8601 format, e.g. `2010-02-19T14:54:23.031+08:00`.
+    pub program_date_time: Option<String>,
+    /// Initialization section in effect for the segment.
+    pub map: Option<Map>,
+    /// Encryption in effect for the segment.
+    pub key: Option<Key>,
+    /// `EXT-X-GAP`: segment is missing.
+    pub gap: bool,
+    /// Low-latency parts of the segment.
+    pub parts: Vec<Part>,
+}
+
+impl Segment {
+    pub fn new(uri: impl Into<String>, duration: f64) -> Self {
+        Self {
+            uri: uri.into(),
+            duration,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_map(mut self, map: Map) -> Self {
+        self.map = Some(map);
+        self
+    }
+}
+
+/// Media playlist.
+///
+/// `version` is the declared `EXT-X-VERSION`; writer raises it to `compat_version`
+/// if features of the playlist require so. In the same way `target_duration` is raised
+/// to `compat_target_duration` if some segment is longer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaPlaylist {
+    pub version: u8,
+    /// `EXT-X-TARGETDURATION` in seconds.
+    pub target_duration: u32,
+    pub media_sequence: u64,
+    pub discontinuity_sequence: u64,
+    pub playlist_type: Option<PlaylistType>,
+    pub i_frames_only: bool,
+    pub independent_segments: bool,
+    pub start: Option<Start>,
+    /// `EXT-X-PART-INF` `PART-TARGET` in seconds.
+    pub part_target: Option<f64>,
+    pub server_control: Option<ServerControl>,
+    pub segments: Vec<Segment>,
+    /// Parts of the segment which is not complete yet.
+    pub parts: Vec<Part>,
+    pub preload_hint: Option<PreloadHint>,
+    /// `EXT-X-ENDLIST`
+    pub end_list: bool,
+}
+
+impl MediaPlaylist {
+    pub fn new(target_duration: u32) -> Self {
+        Self {
+            version: 1,
+            target_duration,
+            media_sequence: 0,
+            discontinuity_sequence: 0,
+            playlist_type: None,
+            i_frames_only: false,
+            independent_segments: false,
+            start: None,
+            part_target: None,
+            server_control: None,
+            segments: Vec::new(),
+            parts: Vec::new(),
+            preload_hint: None,
+            end_list: false,
+        }
+    }
+
+    /// Minimum `EXT-X-VERSION` required by the features used.
+    pub fn compat_version(&self) -> u8 {
+        let mut version = 1;
+        let maps = || self.segments.iter().filter_map(|s| s.map.as_ref());
+        let keys = || self.segments.iter().filter_map(|s| s.key.as_ref());
+        if keys().any(|k| k.iv.is_some()) {
+            version = 2;
+        }
+        if self.segments.iter().any(|s| s.duration.fract() != 0.0) {
+            version = 3;
+        }
+        if self.i_frames_only
+            || self.segments.iter().any(|s| s.byte_range.is_some())
+            || maps().any(|m| m.byte_range.is_some())
+        {
+            version = 4;
+        }
+        if keys().any(|k| k.key_format.is_some() || k.key_format_versions.is_some())
+            || (self.i_frames_only && maps().next().is_some())
+        {
+            version = 5;
+        }
+        if !self.i_frames_only && maps().next().is_some() {
+            version = 6;
+        }
+        version
+    }
+
+    /// Minimum `EXT-X-TARGETDURATION` for rounded durations of the segments.
+    pub fn compat_target_duration(&self) -> u32 {
+        self.segments
+            .iter()
+            .map(|s| s.duration.round() as u32)
+            .fold(self.target_duration, u32::max)
+    }
+
+    /// Total duration of the segments in seconds.
+    pub fn duration(&self) -> f64 {
+        self.segments.iter().map(|s| s.duration).sum()
+    }
+
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut res = Self::new(0);
+        let mut target_duration = None;
+        let mut version = None;
+        let mut seen = Vec::new();
+
+        let mut segment = Segment::default();
+        // line of EXTINF of pending segment
+        let mut inf_line = None;
+        let mut inf_lines = Vec::new();
+        let mut map = None;
+        let mut key = None;
+        let mut last_line = 1;
+
+        for (line, l) in hls::lines(s)? {
+            last_line = line;
+            let err = |kind| Error { line, kind };
+            match l {
+                Line::Uri(uri) => {
+                    if inf_line.is_none() {
+                        return Err(err(ErrorKind::UnexpectedUri));
+                    }
+                    if segment.byte_range.is_some_and(|r| r.offset.is_none()) {
+                        let prev = res.segments.last();
+                        if !prev.is_some_and(|p| p.uri == uri && p.byte_range.is_some()) {
+                            return Err(err(ErrorKind::InvalidValue("EXT-X-BYTERANGE")));
+                        }
+                    }
+                    segment.uri = uri.to_string();
+                    segment.map = map.clone();
+                    segment.key = key.clone();
+                    segment.parts = std::mem::take(&mut res.parts);
+                    res.segments.push(std::mem::take(&mut segment));
+                    inf_lines.push(inf_line.take().unwrap());
+                }
+                Line::Tag(name, value) => {
+                    if hls::is_multivariant_tag(name) {
+                        return Err(err(ErrorKind::MixedPlaylist));
+                    }
+                    res.parse_tag(name, value, &mut seen, &mut segment, &mut map, &mut key)
+                        .map_err(err)?;
+                    match name {
+                        "EXT-X-VERSION" => version = Some((res.version, line)),
+                        "EXT-X-TARGETDURATION" => target_duration = Some(res.target_duration),
+                        "EXTINF" => {
+                            // previous EXTINF has no URI
+                            let prev = inf_line.replace(line);
+                            if prev.is_some() {
+                                return Err(err(ErrorKind::MissingUri));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        if inf_line.is_some() {
+            return Err(Error {
+                line: last_line,
+                kind: ErrorKind::MissingUri,
+            });
+        }
+        let Some(target_duration) = target_duration else {
+            return Err(Error {
+                line: 0,
+                kind: ErrorKind::MissingTag("EXT-X-TARGETDURATION"),
+            });
+        };
+        for (s, line) in res.segments.iter().zip(inf_lines) {
+            if s.duration.round() > target_duration as f64 {
+                return Err(Error {
+                    line,
+                    kind: ErrorKind::DurationExceedsTarget,
+                });
+            }
+        }
+        let (declared, line) = version.unwrap_or((1, 0));
+        let required = res.compat_version();
+        if declared < required {
+            return Err(Error {
+                line,
+                kind: ErrorKind::Version { declared, required },
+            });
+        }
+        Ok(res)
+    }
+
+    fn parse_tag(
+        &mut self,
+        name: &str,
+        value: Option<&str>,
+        seen: &mut Vec<&'static str>,
+        segment: &mut Segment,
+        map: &mut Option<Map>,
+        key: &mut Option<Key>,
+    ) -> KindResult {
+        let mut once = |tag: &'static str| {
+            if seen.contains(&tag) {
+                return Err(ErrorKind::DuplicateTag(tag));
+            }
+            seen.push(tag);
+            Ok(tag)
+        };
+        match name {
+            "EXTM3U" => return Err(ErrorKind::DuplicateTag("EXTM3U")),
+            "EXT-X-VERSION" => {
+                let tag = once("EXT-X-VERSION")?;
+                self.version = hls::decimal_int(tag, hls::value(tag, value)?)?;
+            }
+            "EXT-X-TARGETDURATION" => {
+                let tag = once("EXT-X-TARGETDURATION")?;
+                self.target_duration = hls::decimal_int(tag, hls::value(tag, value)?)?;
+            }
+            "EXT-X-MEDIA-SEQUENCE" => {
+                let tag = once("EXT-X-MEDIA-SEQUENCE")?;
+                if !self.segments.is_empty() {
+                    return Err(ErrorKind::InvalidValue(tag));
+                }
+                self.media_sequence = hls::decimal_int(tag, hls::value(tag, value)?)?;
+            }
+            "EXT-X-DISCONTINUITY-SEQUENCE" => {
+                let tag = once("EXT-X-DISCONTINUITY-SEQUENCE")?;
+                if !self.segments.is_empty() {
+                    return Err(ErrorKind::InvalidValue(tag));
+                }
+                self.discontinuity_sequence = hls::decimal_int(tag, hls::value(tag, value)?)?;
+            }
+            "EXT-X-PLAYLIST-TYPE" => {
+                let tag = once("EXT-X-PLAYLIST-TYPE")?;
+                self.playlist_type = match hls::value(tag, value)? {
+                    "EVENT" => Some(PlaylistType::Event),
+                    "VOD" => Some(PlaylistType::Vod),
+                    _ => return Err(ErrorKind::InvalidValue(tag)),
+                };
+            }
+            "EXT-X-I-FRAMES-ONLY" => {
+                once("EXT-X-I-FRAMES-ONLY")?;
+                self.i_frames_only = true;
+            }
+            "EXT-X-INDEPENDENT-SEGMENTS" => {
+                once("EXT-X-INDEPENDENT-SEGMENTS")?;
+                self.independent_segments = true;
+            }
+            "EXT-X-START" => {
+                once("EXT-X-START")?;
+                self.start = Some(Start::parse(value)?);
+            }
+            "EXT-X-PART-INF" => {
+                let tag = once("EXT-X-PART-INF")?;
+                let attrs = Attrs::parse(hls::value(tag, value)?)?;
+                self.part_target = Some(
+                    attrs
+                        .float("PART-TARGET")?
+                        .ok_or(ErrorKind::MissingAttr("PART-TARGET"))?,
+                );
+            }
+            "EXT-X-SERVER-CONTROL" => {
+                once("EXT-X-SERVER-CONTROL")?;
+                self.server_control = Some(ServerControl::parse(value)?);
+            }
+            "EXT-X-ENDLIST" => {
+                once("EXT-X-ENDLIST")?;
+                self.end_list = true;
+            }
+            "EXT-X-PRELOAD-HINT" => {
+                self.preload_hint = Some(PreloadHint::parse(value)?);
+            }
+            "EXTINF" => {
+                let (duration, title) = hls::value("EXTINF", value)?
+                    .split_once(',')
+                    .ok_or(ErrorKind::InvalidValue("EXTINF"))?;
+                segment.duration = hls::decimal_float("EXTINF", duration)?;
+                if segment.duration < 0.0 {
+                    return Err(ErrorKind::InvalidValue("EXTINF"));
+                }
+                segment.title = title.to_string();
+            }
+            "EXT-X-BYTERANGE" => {
+                let tag = "EXT-X-BYTERANGE";
+                segment.byte_range = Some(ByteRange::parse(tag, hls::value(tag, value)?)?);
+            }
+            "EXT-X-DISCONTINUITY" => segment.discontinuity = true,
+            "EXT-X-GAP" => segment.gap = true,
+            "EXT-X-PROGRAM-DATE-TIME" => {
+                let tag = "EXT-X-PROGRAM-DATE-TIME";
+                let value = hls::value(tag, value)?;
+                if !hls::is_date_time(value) {
+                    return Err(ErrorKind::InvalidValue(tag));
+                }
+                segment.program_date_time = Some(value.to_string());
+            }
+            "EXT-X-MAP" => *map = Some(Map::parse(value)?),
+            "EXT-X-KEY" => *key = Key::parse(value)?,
+            "EXT-X-PART" => self.parts.push(Part::parse(value)?),
+            // unknown tags must be ignored
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for MediaPlaylist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("#EXTM3U\n")?;
+        writeln!(
+            f,
+            "#EXT-X-VERSION:{}",
+            self.version.max(self.compat_version())
+        )?;
+        writeln!(f, "#EXT-X-TARGETDURATION:{}", self.compat_target_duration())?;
+        if let Some(part_target) = self.part_target {
+            writeln!(f, "#EXT-X-PART-INF:PART-TARGET={part_target}")?;
+        }
+        if let Some(server_control) = &self.server_control {
+            server_control.write(f)?;
+        }
+        writeln!(f, "#EXT-X-MEDIA-SEQUENCE:{}", self.media_sequence)?;
+        if self.discontinuity_sequence != 0 {
+            writeln!(
+                f,
+                "#EXT-X-DISCONTINUITY-SEQUENCE:{}",
+                self.discontinuity_sequence
+            )?;
+        }
+        match self.playlist_type {
+            Some(PlaylistType::Event) => f.write_str("#EXT-X-PLAYLIST-TYPE:EVENT\n")?,
+            Some(PlaylistType::Vod) => f.write_str("#EXT-X-PLAYLIST-TYPE:VOD\n")?,
+            None => {}
+        }
+        if self.i_frames_only {
+            f.write_str("#EXT-X-I-FRAMES-ONLY\n")?;
+        }
+        if self.independent_segments {
+            f.write_str("#EXT-X-INDEPENDENT-SEGMENTS\n")?;
+        }
+        if let Some(start) = &self.start {
+            start.write(f)?;
+        }
+
+        let mut map = None;
+        let mut key = None;
+        for s in &self.segments {
+            if s.discontinuity {
+                f.write_str("#EXT-X-DISCONTINUITY\n")?;
+            }
+            if s.key.as_ref() != key {
+                key = s.key.as_ref();
+                Key::write(key, f)?;
+            }
+            if s.map.is_some() && s.map.as_ref() != map {
+                map = s.map.as_ref();
+                map.unwrap().write(f)?;
+            }
+            if let Some(pdt) = &s.program_date_time {
+                writeln!(f, "#EXT-X-PROGRAM-DATE-TIME:{pdt}")?;
+            }
+            for p in &s.parts {
+                p.write(f)?;
+            }
+            if s.gap {
+                f.write_str("#EXT-X-GAP\n")?;
+            }
+            writeln!(f, "#EXTINF:{},{}", s.duration, s.title)?;
+            if let Some(range) = s.byte_range {
+                writeln!(f, "#EXT-X-BYTERANGE:{range}")?;
+            }
+            writeln!(f, "{}", s.uri)?;
+        }
+        for p in &self.parts {
+            p.write(f)?;
+        }
+        if let Some(hint) = &self.preload_hint {
+            hint.write(f)?;
+        }
+        if self.end_list {
+            f.write_str("#EXT-X-ENDLIST\n")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::hls;
+
+    const VOD: &str = "#EXTM3U
+#EXT-X-VERSION:7
+#EXT-X-TARGETDURATION:6
+#EXT-X-MEDIA-SEQUENCE:0
+#EXT-X-PLAYLIST-TYPE:VOD
+#EXT-X-INDEPENDENT-SEGMENTS
+#EXT-X-MAP:URI=\"init.mp4\"
+#EXT-X-PROGRAM-DATE-TIME:2024-01-01T00:00:00.000Z
+#EXTINF:6.006,
+0.m4s
+#EXTINF:5.994,
+1.m4s
+#EXT-X-DISCONTINUITY
+#EXT-X-MAP:URI=\"init2.mp4\"
+#EXTINF:4,
+2.m4s
+#EXT-X-ENDLIST
+";
+
+    #[test]
+    fn vod() {
+        let playlist = hls::MediaPlaylist::parse(VOD).unwrap();
+        assert_eq!(playlist.version, 7);
+        assert_eq!(playlist.target_duration, 6);
+        assert_eq!(playlist.playlist_type, Some(hls::PlaylistType::Vod));
+        assert!(playlist.independent_segments);
+        assert!(playlist.end_list);
+        assert_eq!(playlist.segments.len(), 3);
+        assert_eq!(playlist.compat_version(), 6);
+        assert_eq!(playlist.duration(), 16.0);
+
+        let s = &playlist.segments[0];
+        assert_eq!(s.uri, "0.m4s");
+        assert_eq!(s.duration, 6.006);
+        assert_eq!(s.map, Some(hls::Map::new("init.mp4")));
+        assert_eq!(
+            s.program_date_time.as_deref(),
+            Some("2024-01-01T00:00:00.000Z")
+        );
+        assert_eq!(playlist.segments[1].map, Some(hls::Map::new("init.mp4")));
+        assert!(playlist.segments[2].discontinuity);
+        assert_eq!(playlist.segments[2].map, Some(hls::Map::new("init2.mp4")));
+
+        assert_eq!(playlist.to_string(), VOD);
+    }
+
+    #[test]
+    fn byte_ranges_and_keys() {
+        let mut playlist = hls::MediaPlaylist::new(10);
+        let mut key = hls::Key::new(hls::Key::AES_128, "key.bin");
+        key.iv = Some([0xab; 16]);
+        let mut map = hls::Map::new("main.mp4");
+        map.byte_range = Some(hls::ByteRange::new(720, 0));
+        for (i, len) in [1000, 2000].into_iter().enumerate() {
+            let mut s = hls::Segment::new("main.mp4", 10.0).with_map(map.clone());
+            s.byte_range = Some(hls::ByteRange {
+                len,
+                offset: if i == 0 { Some(720) } else { None },
+            });
+            s.key = Some(key.clone());
+            playlist.segments.push(s);
+        }
+        playlist
+            .segments
+            .push(hls::Segment::new("clear.mp4", 9.5).with_map(map));
+        playlist.end_list = true;
+
+        let text = playlist.to_string();
+        assert!(text.contains("#EXT-X-MAP:URI=\"main.mp4\",BYTERANGE=\"720@0\"\n"));
+        assert!(text.contains("#EXT-X-BYTERANGE:1000@720\n"));
+        assert!(text.contains("#EXT-X-BYTERANGE:2000\n"));
+        assert!(text.contains(
+            "#EXT-X-KEY:METHOD=AES-128,URI=\"key.bin\",IV=0xABABABABABABABABABABABABABABABAB\n"
+        ));
+        assert!(text.contains("#EXT-X-KEY:METHOD=NONE\n"));
+        assert_eq!(text.matches("#EXT-X-KEY").count(), 2);
+
+        let mut parsed = hls::MediaPlaylist::parse(&text).unwrap();
+        assert_eq!(parsed.version, 6);
+        parsed.version = playlist.version;
+        assert_eq!(parsed, playlist);
+    }
+
+    #[test]
+    fn target_duration_raised() {
+        let mut playlist = hls::MediaPlaylist::new(2);
+        playlist.segments.push(hls::Segment::new("0.ts", 2.0));
+        assert_eq!(playlist.compat_target_duration(), 2);
+        playlist.segments.push(hls::Segment::new("1.ts", 5.0));
+        playlist.segments.push(hls::Segment::new("2.ts", 2.4));
+        assert_eq!(playlist.compat_target_duration(), 5);
+        playlist.end_list = true;
+
+        let text = playlist.to_string();
+        assert!(text.contains("#EXT-X-TARGETDURATION:5\n"));
+        let mut parsed = hls::MediaPlaylist::parse(&text).unwrap();
+        assert_eq!(parsed.target_duration, 5);
+        parsed.target_duration = playlist.target_duration;
+        parsed.version = playlist.version;
+        assert_eq!(parsed, playlist);
+
+        // rounded to the nearest integer like parser does
+        playlist.segments.push(hls::Segment::new("3.ts", 5.4));
+        assert_eq!(playlist.compat_target_duration(), 5);
+        playlist.segments.push(hls::Segment::new("4.ts", 5.5));
+        assert_eq!(playlist.compat_target_duration(), 6);
+        assert!(hls::MediaPlaylist::parse(&playlist.to_string()).is_ok());
+    }
+
+    #[test]
+    fn low_latency() {
+        const LL: &str = "#EXTM3U
+#EXT-X-VERSION:6
+#EXT-X-TARGETDURATION:4
+#EXT-X-PART-INF:PART-TARGET=1.002
+#EXT-X-SERVER-CONTROL:CAN-BLOCK-RELOAD=YES,PART-HOLD-BACK=3.012
+#EXT-X-MEDIA-SEQUENCE:266
+#EXT-X-MAP:URI=\"init.mp4\"
+#EXT-X-PART:DURATION=2.002,URI=\"266.0.m4s\",INDEPENDENT=YES
+#EXT-X-PART:DURATION=2.002,URI=\"266.1.m4s\"
+#EXTINF:4.004,
+266.m4s
+#EXT-X-PART:DURATION=1.002,URI=\"267.0.m4s\",INDEPENDENT=YES
+#EXT-X-PRELOAD-HINT:TYPE=PART,URI=\"267.1.m4s\"
+";
+        let playlist = hls::MediaPlaylist::parse(LL).unwrap();
+        assert_eq!(playlist.media_sequence, 266);
+        assert_eq!(playlist.part_target, Some(1.002));
+        let control = playlist.server_control.unwrap();
+        assert!(control.can_block_reload);
+        assert_eq!(control.part_hold_back, Some(3.012));
+        assert_eq!(playlist.segments[0].parts.len(), 2);
+        assert!(playlist.segments[0].parts[0].independent);
+        assert_eq!(playlist.parts.len(), 1);
+        let hint = playlist.preload_hint.as_ref().unwrap();
+        assert_eq!(hint.typ, hls::PreloadHintType::Part);
+        assert_eq!(hint.uri, "267.1.m4s");
+        assert!(!playlist.end_list);
+
+        assert_eq!(playlist.to_string(), LL);
+    }
+
+    #[test]
+    fn strict() {
+        fn err(s: &str) -> hls::Error {
+            hls::MediaPlaylist::parse(s).unwrap_err()
+        }
+
+        let e = err("#EXTM3U\n#EXT-X-TARGETDURATION:6\n#EXTINF:7.2,\n0.ts\n");
+        assert_eq!(e.kind, hls::ErrorKind::DurationExceedsTarget);
+        assert_eq!(e.line, 3);
+
+        let e = err("#EXTM3U\n#EXT-X-TARGETDURATION:6\n#EXTINF:5.5,\n0.ts\n");
+        assert_eq!(
+            e.kind,
+            hls::ErrorKind::Version {
+                declared: 1,
+                required: 3
+            }
+        );
+
+        let e = err("#EXTM3U\n#EXTINF:5,\n0.ts\n");
+        assert_eq!(e.kind, hls::ErrorKind::MissingTag("EXT-X-TARGETDURATION"));
+
+        let e = err("#EXTM3U\n#EXT-X-TARGETDURATION:6\n0.ts\n");
+        assert_eq!(e.kind, hls::ErrorKind::UnexpectedUri);
+        assert_eq!(e.line, 3);
+
+        let e = err("#EXTM3U\n#EXT-X-TARGETDURATION:6\n#EXTINF:5,\n");
+        assert_eq!(e.kind, hls::ErrorKind::MissingUri);
+
+        let e = err("#EXTM3U\n#EXT-X-TARGETDURATION:6\n#EXT-X-TARGETDURATION:6\n");
+        assert_eq!(e.kind, hls::ErrorKind::DuplicateTag("EXT-X-TARGETDURATION"));
+
+        let e = err("#EXTM3U\n#EXT-X-TARGETDURATION:6\n#EXT-X-STREAM-INF:BANDWIDTH=1\n");
+        assert_eq!(e.kind, hls::ErrorKind::MixedPlaylist);
+
+        let e = err("#EXTM3U\n#EXT-X-TARGETDURATION:6\n#EXT-X-MAP:URI=init.mp4\n");
+        assert_eq!(e.kind, hls::ErrorKind::InvalidValue("URI"));
+
+        let e = err("#EXTM3U\n#EXT-X-TARGETDURATION:6\n#EXT-X-PROGRAM-DATE-TIME:today\n");
+        assert_eq!(
+            e.kind,
+            hls::ErrorKind::InvalidValue("EXT-X-PROGRAM-DATE-TIME")
+        );
+
+        let e = err(
+            "#EXTM3U\n#EXT-X-VERSION:4\n#EXT-X-TARGETDURATION:6\n#EXTINF:5,\n#EXT-X-BYTERANGE:10\n0.ts\n",
+        );
+        assert_eq!(e.kind, hls::ErrorKind::InvalidValue("EXT-X-BYTERANGE"));
+
+        // unknown tags and comments are ignored
+        let playlist = hls::MediaPlaylist::parse(
+            "#EXTM3U\n# comment\n#EXT-X-TARGETDURATION:6\n#EXT-X-UNKNOWN:1\n\n#EXTINF:5,title\n0.ts\n",
+        )
+        .unwrap();
+        assert_eq!(playlist.segments[0].title, "title");
+    }
+}
@@ -0,0 +1,609 @@
+use std::fmt;
+
+use crate::cm::{
+    self,
+    hls::{self, AttrWriter, Attrs, Error, ErrorKind, KindResult, Line, Result, Start},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenditionType {
+    Audio,
+    Video,
+    Subtitles,
+    ClosedCaptions,
+}
+
+impl RenditionType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Audio => "AUDIO",
+            Self::Video => "VIDEO",
+            Self::Subtitles => "SUBTITLES",
+            Self::ClosedCaptions => "CLOSED-CAPTIONS",
+        }
+    }
+}
+
+/// `EXT-X-MEDIA`: alternative rendition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rendition {
+    pub typ: RenditionType,
+    pub group_id: String,
+    pub name: String,
+    /// Media playlist of the rendition, must be `None` for closed captions.
+    pub uri: Option<String>,
+    pub language: Option<String>,
+    pub assoc_language: Option<String>,
+    pub default: bool,
+    pub autoselect: bool,
+    /// Only for subtitles.
+    pub forced: bool,
+    /// `CC1`..`CC4` or `SERVICE1`..`SERVICE63`, only for closed captions.
+    pub instream_id: Option<String>,
+    pub characteristics: Option<String>,
+    /// Number of audio channels, e.g. `2` or `16/JOC`.
+    pub channels: Option<String>,
+}
+
+impl Rendition {
+    pub fn new(typ: RenditionType, group_id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            typ,
+            group_id: group_id.into(),
+            name: name.into(),
+            uri: None,
+            language: None,
+            assoc_language: None,
+            default: false,
+            autoselect: false,
+            forced: false,
+            instream_id: None,
+            characteristics: None,
+            channels: None,
+        }
+    }
+
+    fn parse(value: Option<&str>) -> KindResult<Self> {
+        let attrs = Attrs::parse(hls::value("EXT-X-MEDIA", value)?)?;
+        let typ = match attrs.req_enumerated("TYPE")? {
+            "AUDIO" => RenditionType::Audio,
+            "VIDEO" => RenditionType::Video,
+            "SUBTITLES" => RenditionType::Subtitles,
+            "CLOSED-CAPTIONS" => RenditionType::ClosedCaptions,
+            _ => return Err(ErrorKind::InvalidValue("TYPE")),
+        };
+        let opt =
+            |name| -> KindResult<Option<String>> { Ok(attrs.quoted(name)?.map(str::to_string)) };
+        let res = Self {
+            typ,
+            group_id: attrs.req_quoted("GROUP-ID")?.to_string(),
+            name: attrs.req_quoted("NAME")?.to_string(),
+            uri: opt("URI")?,
+            language: opt("LANGUAGE")?,
+            assoc_language: opt("ASSOC-LANGUAGE")?,
+            default: attrs.flag("DEFAULT")?,
+            autoselect: attrs.flag("AUTOSELECT")?,
+            forced: attrs.flag("FORCED")?,
+            instream_id: opt("INSTREAM-ID")?,
+            characteristics: opt("CHARACTERISTICS")?,
+            channels: opt("CHANNELS")?,
+        };
+        let cc = typ == RenditionType::ClosedCaptions;
+        if cc && res.uri.is_some() {
+            return Err(ErrorKind::InvalidValue("URI"));
+        }
+        if cc != res.instream_id.is_some() {
+            return Err(if cc {
+                ErrorKind::MissingAttr("INSTREAM-ID")
+            } else {
+                ErrorKind::InvalidValue("INSTREAM-ID")
+            });
+        }
+        if res.forced && typ != RenditionType::Subtitles {
+            return Err(ErrorKind::InvalidValue("FORCED"));
+        }
+        if res.default && !res.autoselect && attrs.enumerated("AUTOSELECT")?.is_some() {
+            return Err(ErrorKind::InvalidValue("AUTOSELECT"));
+        }
+        Ok(res)
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut w = AttrWriter::new(f, "EXT-X-MEDIA")?;
+        w.raw("TYPE", self.typ.as_str())?;
+        w.quoted("GROUP-ID", &self.group_id)?;
+        w.quoted("NAME", &self.name)?;
+        w.opt_quoted("LANGUAGE", self.language.as_ref())?;
+        w.opt_quoted("ASSOC-LANGUAGE", self.assoc_language.as_ref())?;
+        w.flag("DEFAULT", self.default)?;
+        w.flag("AUTOSELECT", self.autoselect)?;
+        w.flag("FORCED", self.forced)?;
+        w.opt_quoted("INSTREAM-ID", self.instream_id.as_ref())?;
+        w.opt_quoted("CHARACTERISTICS", self.characteristics.as_ref())?;
+        w.opt_quoted("CHANNELS", self.channels.as_ref())?;
+        w.opt_quoted("URI", self.uri.as_ref())?;
+        w.end()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resolution {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Resolution {
+    fn parse(s: &str) -> KindResult<Self> {
+        let (w, h) = s
+            .split_once('x')
+            .ok_or(ErrorKind::InvalidValue("RESOLUTION"))?;
+        Ok(Self {
+            width: hls::decimal_int("RESOLUTION", w)?,
+            height: hls::decimal_int("RESOLUTION", h)?,
+        })
+    }
+}
+
+impl fmt::Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+/// `CLOSED-CAPTIONS` attribute of the variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClosedCaptions {
+    /// Variant has no closed captions.
+    None,
+    /// Group of `CLOSED-CAPTIONS` renditions.
+    Group(String),
+}
+
+/// `EXT-X-STREAM-INF` followed by the URI of media playlist.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    pub uri: String,
+    /// Peak bits per second.
+    pub bandwidth: u64,
+    pub average_bandwidth: Option<u64>,
+    /// RFC 6381 codec strings.
+    pub codecs: Vec<String>,
+    pub resolution: Option<Resolution>,
+    pub frame_rate: Option<f64>,
+    pub hdcp_level: Option<String>,
+    pub audio: Option<String>,
+    pub video: Option<String>,
+    pub subtitles: Option<String>,
+    pub closed_captions: Option<ClosedCaptions>,
+}
+
+impl Variant {
+    pub fn new(uri: impl Into<String>, bandwidth: u64) -> Self {
+        Self {
+            uri: uri.into(),
+            bandwidth,
+            average_bandwidth: None,
+            codecs: Vec::new(),
+            resolution: None,
+            frame_rate: None,
+            hdcp_level: None,
+            audio: None,
+            video: None,
+            subtitles: None,
+            closed_captions: None,
+        }
+    }
+
+    /// Appends codec string of the format description.
+    ///
+    /// Returns `false` if there is no known codec string for the format.
+    pub fn push_codec(&mut self, desc: &cm::FormatDesc) -> bool {
+        match desc.codecs_string() {
+            Some(codec) => {
+                self.codecs.push(codec);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn parse(value: Option<&str>) -> KindResult<Self> {
+        let attrs = Attrs::parse(hls::value("EXT-X-STREAM-INF", value)?)?;
+        let opt =
+            |name| -> KindResult<Option<String>> { Ok(attrs.quoted(name)?.map(str::to_string)) };
+        let closed_captions = match attrs.enumerated("CLOSED-CAPTIONS") {
+            Ok(Some("NONE")) => Some(ClosedCaptions::None),
+            Ok(Some(_)) => return Err(ErrorKind::InvalidValue("CLOSED-CAPTIONS")),
+            Ok(None) => None,
+            Err(_) => opt("CLOSED-CAPTIONS")?.map(ClosedCaptions::Group),
+        };
+        Ok(Self {
+            uri: String::new(),
+            bandwidth: attrs
+                .int("BANDWIDTH")?
+                .ok_or(ErrorKind::MissingAttr("BANDWIDTH"))?,
+            average_bandwidth: attrs.int("AVERAGE-BANDWIDTH")?,
+            codecs: parse_codecs(attrs.quoted("CODECS")?),
+            resolution: attrs
+                .enumerated("RESOLUTION")?
+                .map(Resolution::parse)
+                .transpose()?,
+            frame_rate: attrs.float("FRAME-RATE")?,
+            hdcp_level: attrs.enumerated("HDCP-LEVEL")?.map(str::to_string),
+            audio: opt("AUDIO")?,
+            video: opt("VIDEO")?,
+            subtitles: opt("SUBTITLES")?,
+            closed_captions,
+        })
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut w = AttrWriter::new(f, "EXT-X-STREAM-INF")?;
+        w.raw("BANDWIDTH", self.bandwidth)?;
+        w.opt_raw("AVERAGE-BANDWIDTH", self.average_bandwidth)?;
+        if !self.codecs.is_empty() {
+            w.quoted("CODECS", self.codecs.join(","))?;
+        }
+        w.opt_raw("RESOLUTION", self.resolution)?;
+        w.opt_raw("FRAME-RATE", self.frame_rate.map(|r| format!("{r:.3}")))?;
+        w.opt_raw("HDCP-LEVEL", self.hdcp_level.as_ref())?;
+        w.opt_quoted("AUDIO", self.audio.as_ref())?;
+        w.opt_quoted("VIDEO", self.video.as_ref())?;
+        w.opt_quoted("SUBTITLES", self.subtitles.as_ref())?;
+        match &self.closed_captions {
+            Some(ClosedCaptions::None) => w.raw("CLOSED-CAPTIONS", "NONE")?,
+            Some(ClosedCaptions::Group(id)) => w.quoted("CLOSED-CAPTIONS", id)?,
+            None => {}
+        }
+        w.end()?;
+        writeln!(f, "{}", self.uri)
+    }
+}
+
+/// `EXT-X-I-FRAME-STREAM-INF`: I-frame only media playlist of the variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IFrameVariant {
+    pub uri: String,
+    pub bandwidth: u64,
+    pub average_bandwidth: Option<u64>,
+    pub codecs: Vec<String>,
+    pub resolution: Option<Resolution>,
+    pub hdcp_level: Option<String>,
+    pub video: Option<String>,
+}
+
+impl IFrameVariant {
+    pub fn new(uri: impl Into<String>, bandwidth: u64) -> Self {
+        Self {
+            uri: uri.into(),
+            bandwidth,
+            average_bandwidth: None,
+            codecs: Vec::new(),
+            resolution: None,
+            hdcp_level: None,
+            video: None,
+        }
+    }
+
+    fn parse(value: Option<&str>) -> KindResult<Self> {
+        let attrs = Attrs::parse(hls::value("EXT-X-I-FRAME-STREAM-INF", value)?)?;
+        Ok(Self {
+            uri: attrs.req_quoted("URI")?.to_string(),
+            bandwidth: attrs
+                .int("BANDWIDTH")?
+                .ok_or(ErrorKind::MissingAttr("BANDWIDTH"))?,
+            average_bandwidth: attrs.int("AVERAGE-BANDWIDTH")?,
+            codecs: parse_codecs(attrs.quoted("CODECS")?),
+            resolution: attrs
+                .enumerated("RESOLUTION")?
+                .map(Resolution::parse)
+                .transpose()?,
+            hdcp_level: attrs.enumerated("HDCP-LEVEL")?.map(str::to_string),
+            video: attrs.quoted("VIDEO")?.map(str::to_string),
+        })
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut w = AttrWriter::new(f, "EXT-X-I-FRAME-STREAM-INF")?;
+        w.raw("BANDWIDTH", self.bandwidth)?;
+        w.opt_raw("AVERAGE-BANDWIDTH", self.average_bandwidth)?;
+        if !self.codecs.is_empty() {
+            w.quoted("CODECS", self.codecs.join(","))?;
+        }
+        w.opt_raw("RESOLUTION", self.resolution)?;
+        w.opt_raw("HDCP-LEVEL", self.hdcp_level.as_ref())?;
+        w.opt_quoted("VIDEO", self.video.as_ref())?;
+        w.quoted("URI", &self.uri)?;
+        w.end()
+    }
+}
+
+fn parse_codecs(s: Option<&str>) -> Vec<String> {
+    s.map(|s| s.split(',').map(|c| c.trim().to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Multivariant (master) playlist.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MultivariantPlaylist {
+    /// Declared `EXT-X-VERSION`, 0 if absent.
+    pub version: u8,
+    pub independent_segments: bool,
+    pub start: Option<Start>,
+    pub renditions: Vec<Rendition>,
+    pub variants: Vec<Variant>,
+    pub i_frame_variants: Vec<IFrameVariant>,
+}
+
+impl MultivariantPlaylist {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Minimum `EXT-X-VERSION` required by the features used.
+    pub fn compat_version(&self) -> u8 {
+        let service = self.renditions.iter().any(|r| {
+            r.instream_id
+                .as_deref()
+                .is_some_and(|id| id.starts_with("SERVICE"))
+        });
+        if service { 7 } else { 1 }
+    }
+
+    fn has_group(&self, typ: RenditionType, id: &str) -> bool {
+        self.renditions
+            .iter()
+            .any(|r| r.typ == typ && r.group_id == id)
+    }
+
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut res = Self::new();
+        let mut version = None;
+        let mut variant: Option<(Variant, usize)> = None;
+        let mut variant_lines = Vec::new();
+        let mut last_line = 1;
+
+        for (line, l) in hls::lines(s)? {
+            last_line = line;
+            let err = |kind| Error { line, kind };
+            let value = match l {
+                Line::Uri(uri) => {
+                    let Some((mut v, line)) = variant.take() else {
+                        return Err(err(ErrorKind::UnexpectedUri));
+                    };
+                    v.uri = uri.to_string();
+                    res.variants.push(v);
+                    variant_lines.push(line);
+                    continue;
+                }
+                Line::Tag(name, value) => {
+                    if hls::is_media_tag(name) {
+                        return Err(err(ErrorKind::MixedPlaylist));
+                    }
+                    if variant.is_some() {
+                        return Err(err(ErrorKind::MissingUri));
+                    }
+                    (name, value)
+                }
+            };
+            match value {
+                ("EXTM3U", _) => return Err(err(ErrorKind::DuplicateTag("EXTM3U"))),
+                ("EXT-X-VERSION", value) => {
+                    let tag = "EXT-X-VERSION";
+                    if version.is_some() {
+                        return Err(err(ErrorKind::DuplicateTag(tag)));
+                    }
+                    let v = hls::value(tag, value)
+                        .and_then(|v| hls::decimal_int(tag, v))
+                        .map_err(err)?;
+                    res.version = v;
+                    version = Some(line);
+                }
+                ("EXT-X-INDEPENDENT-SEGMENTS", _) => res.independent_segments = true,
+                ("EXT-X-START", value) => res.start = Some(Start::parse(value).map_err(err)?),
+                ("EXT-X-MEDIA", value) => {
+                    let r = Rendition::parse(value).map_err(err)?;
+                    let default = || {
+                        res.renditions
+                            .iter()
+                            .any(|o| o.typ == r.typ && o.group_id == r.group_id && o.default)
+                    };
+                    if r.default && default() {
+                        return Err(err(ErrorKind::InvalidValue("DEFAULT")));
+                    }
+                    res.renditions.push(r);
+                }
+                ("EXT-X-STREAM-INF", value) => {
+                    variant = Some((Variant::parse(value).map_err(err)?, line));
+                }
+                ("EXT-X-I-FRAME-STREAM-INF", value) => {
+                    res.i_frame_variants
+                        .push(IFrameVariant::parse(value).map_err(err)?);
+                }
+                // unknown tags must be ignored
+                _ => {}
+            }
+        }
+
+        if variant.is_some() {
+            return Err(Error {
+                line: last_line,
+                kind: ErrorKind::MissingUri,
+            });
+        }
+        for (v, line) in res.variants.iter().zip(variant_lines) {
+            let cc = match &v.closed_captions {
+                Some(ClosedCaptions::Group(id)) => Some(id),
+                _ => None,
+            };
+            let groups = [
+                (RenditionType::Audio, v.audio.as_ref()),
+                (RenditionType::Video, v.video.as_ref()),
+                (RenditionType::Subtitles, v.subtitles.as_ref()),
+                (RenditionType::ClosedCaptions, cc),
+            ];
+            for (typ, id) in groups {
+                if let Some(id) = id.filter(|id| !res.has_group(typ, id)) {
+                    return Err(Error {
+                        line,
+                        kind: ErrorKind::UnknownGroup(id.clone()),
+                    });
+                }
+            }
+        }
+        let required = res.compat_version();
+        if res.version.max(1) < required {
+            return Err(Error {
+                line: version.unwrap_or(0),
+                kind: ErrorKind::Version {
+                    declared: res.version.max(1),
+                    required,
+                },
+            });
+        }
+        Ok(res)
+    }
+}
+
+impl fmt::Display for MultivariantPlaylist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("#EXTM3U\n")?;
+        let version = self.version.max(self.compat_version());
+        if version > 1 {
+            writeln!(f, "#EXT-X-VERSION:{version}")?;
+        }
+        if self.independent_segments {
+            f.write_str("#EXT-X-INDEPENDENT-SEGMENTS\n")?;
+        }
+        if let Some(start) = &self.start {
+            start.write(f)?;
+        }
+        for r in &self.renditions {
+            r.write(f)?;
+        }
+        for v in &self.variants {
+            v.write(f)?;
+        }
+        for v in &self.i_frame_variants {
+            v.write(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::hls;
+
+    const MULTIVARIANT: &str = "#EXTM3U
+#EXT-X-VERSION:6
+#EXT-X-INDEPENDENT-SEGMENTS
+#EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID=\"aac\",NAME=\"English\",LANGUAGE=\"en\",DEFAULT=YES,AUTOSELECT=YES,CHANNELS=\"2\",URI=\"audio/en.m3u8\"
+#EXT-X-MEDIA:TYPE=CLOSED-CAPTIONS,GROUP-ID=\"cc\",NAME=\"English\",INSTREAM-ID=\"CC1\"
+#EXT-X-STREAM-INF:BANDWIDTH=6214307,AVERAGE-BANDWIDTH=5000000,CODECS=\"hvc1.1.6.L93.90,mp4a.40.2\",RESOLUTION=1920x1080,FRAME-RATE=29.970,AUDIO=\"aac\",CLOSED-CAPTIONS=\"cc\"
+1080p.m3u8
+#EXT-X-STREAM-INF:BANDWIDTH=1500000,CODECS=\"avc1.64001F,mp4a.40.2\",RESOLUTION=1280x720,FRAME-RATE=60.000,AUDIO=\"aac\",CLOSED-CAPTIONS=NONE
+720p.m3u8
+#EXT-X-I-FRAME-STREAM-INF:BANDWIDTH=300000,CODECS=\"avc1.64001F\",RESOLUTION=1280x720,URI=\"720p_iframes.m3u8\"
+";
+
+    #[test]
+    fn multivariant() {
+        let playlist = hls::MultivariantPlaylist::parse(MULTIVARIANT).unwrap();
+        assert_eq!(playlist.version, 6);
+        assert!(playlist.independent_segments);
+        assert_eq!(playlist.renditions.len(), 2);
+        let audio = &playlist.renditions[0];
+        assert_eq!(audio.typ, hls::RenditionType::Audio);
+        assert_eq!(audio.uri.as_deref(), Some("audio/en.m3u8"));
+        assert!(audio.default && audio.autoselect);
+
+        assert_eq!(playlist.variants.len(), 2);
+        let v = &playlist.variants[0];
+        assert_eq!(v.uri, "1080p.m3u8");
+        assert_eq!(v.bandwidth, 6214307);
+        assert_eq!(v.codecs, ["hvc1.1.6.L93.90", "mp4a.40.2"]);
+        assert_eq!(
+            v.resolution,
+            Some(hls::Resolution {
+                width: 1920,
+                height: 1080
+            })
+        );
+        assert_eq!(v.frame_rate, Some(29.97));
+        assert_eq!(
+            v.closed_captions,
+            Some(hls::ClosedCaptions::Group("cc".into()))
+        );
+        assert_eq!(
+            playlist.variants[1].closed_captions,
+            Some(hls::ClosedCaptions::None)
+        );
+        assert_eq!(playlist.i_frame_variants[0].uri, "720p_iframes.m3u8");
+
+        assert_eq!(playlist.to_string(), MULTIVARIANT);
+    }
+
+    #[test]
+    fn strict() {
+        fn err(s: &str) -> hls::Error {
+            hls::MultivariantPlaylist::parse(s).unwrap_err()
+        }
+
+        let e = err("#EXTM3U\n#EXT-X-STREAM-INF:BANDWIDTH=1,AUDIO=\"aac\"\na.m3u8\n");
+        assert_eq!(e.kind, hls::ErrorKind::UnknownGroup("aac".into()));
+        assert_eq!(e.line, 2);
+
+        let e = err("#EXTM3U\n#EXT-X-STREAM-INF:CODECS=\"avc1.64001F\"\na.m3u8\n");
+        assert_eq!(e.kind, hls::ErrorKind::MissingAttr("BANDWIDTH"));
+
+        let e = err("#EXTM3U\n#EXT-X-STREAM-INF:BANDWIDTH=1\n");
+        assert_eq!(e.kind, hls::ErrorKind::MissingUri);
+
+        let e = err("#EXTM3U\n#EXT-X-STREAM-INF:BANDWIDTH=1\n#EXTINF:1,\na.m3u8\n");
+        assert_eq!(e.kind, hls::ErrorKind::MixedPlaylist);
+
+        let e = err("#EXTM3U\n#EXT-X-MEDIA:TYPE=CLOSED-CAPTIONS,GROUP-ID=\"cc\",NAME=\"cc\"\n");
+        assert_eq!(e.kind, hls::ErrorKind::MissingAttr("INSTREAM-ID"));
+
+        let e = err("#EXTM3U\n#EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID=\"a\",NAME=\"a\",FORCED=YES\n");
+        assert_eq!(e.kind, hls::ErrorKind::InvalidValue("FORCED"));
+
+        let e = err(
+            "#EXTM3U\n#EXT-X-MEDIA:TYPE=CLOSED-CAPTIONS,GROUP-ID=\"cc\",NAME=\"cc\",INSTREAM-ID=\"SERVICE1\"\n",
+        );
+        assert_eq!(
+            e.kind,
+            hls::ErrorKind::Version {
+                declared: 1,
+                required: 7
+            }
+        );
+    }
+
+    #[test]
+    fn build() {
+        let mut playlist = hls::MultivariantPlaylist::new();
+        let mut audio = hls::Rendition::new(hls::RenditionType::Audio, "aud", "Main");
+        audio.default = true;
+        audio.autoselect = true;
+        audio.uri = Some("aud.m3u8".into());
+        playlist.renditions.push(audio);
+
+        let mut v = hls::Variant::new("v.m3u8", 800_000);
+        v.codecs = vec!["avc1.640028".into(), "mp4a.40.2".into()];
+        v.audio = Some("aud".into());
+        playlist.variants.push(v);
+
+        let text = playlist.to_string();
+        assert_eq!(
+            text,
+            "#EXTM3U
+#EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID=\"aud\",NAME=\"Main\",DEFAULT=YES,AUTOSELECT=YES,URI=\"aud.m3u8\"
+#EXT-X-STREAM-INF:BANDWIDTH=800000,CODECS=\"avc1.640028,mp4a.40.2\",AUDIO=\"aud\"
+v.m3u8
+"
+        );
+        assert_eq!(hls::MultivariantPlaylist::parse(&text).unwrap(), playlist);
+    }
+}
@@ -0,0 +1,584 @@
+//! Pure Rust reader and writer for ISO-BMFF (MP4) boxes.
+//!
+//! Iterates boxes of existing files and fragmented segments without copying,
+//! parses and emits typed `ftyp`/`styp`, `moov` with its tracks, `stsd` sample entries,
+//! `moof` fragments and `sidx`.
+//! Sample entries are byte compatible with `cm::FormatDesc::verbatim_iso_sample_entry`.
+
+use crate::cm::nal;
+
+pub mod fragment;
+pub mod movie;
+pub mod sample_entry;
+pub mod track;
+
+pub use fragment::Moof;
+pub use fragment::Sidx;
+pub use fragment::SidxRef;
+pub use fragment::Tfhd;
+pub use fragment::Traf;
+pub use fragment::Trun;
+pub use fragment::TrunSample;
+pub use movie::Moov;
+pub use movie::Mvhd;
+pub use sample_entry::AudioSampleEntry;
+pub use sample_entry::Esds;
+pub use sample_entry::SampleEntry;
+pub use sample_entry::Stsd;
+pub use sample_entry::VisualSampleEntry;
+pub use track::Hdlr;
+pub use track::Mdhd;
+pub use track::Tkhd;
+pub use track::Track;
+pub use track::UNITY_MATRIX;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Data ended before the box or field was complete.
+    UnexpectedEnd,
+
+    /// Box size is smaller than its header or larger than the data.
+    InvalidSize(u64),
+
+    /// Box has different type than expected.
+    UnexpectedType { expected: BoxType, actual: BoxType },
+
+    /// Required child box is missing.
+    MissingBox(BoxType),
+
+    /// Full box version is not supported.
+    UnsupportedVersion { typ: BoxType, version: u8 },
+
+    /// Value doesn't fit into the field.
+    OutOfRange(&'static str),
+
+    /// Invalid `avcC` or `hvcC` configuration.
+    Nal(nal::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("unexpected end of data"),
+            Self::InvalidSize(size) => write!(f, "invalid box size {size}"),
+            Self::UnexpectedType { expected, actual } => {
+                write!(f, "expected '{expected}' box, got '{actual}'")
+            }
+            Self::MissingBox(typ) => write!(f, "missing '{typ}' box"),
+            Self::UnsupportedVersion { typ, version } => {
+                write!(f, "unsupported '{typ}' version {version}")
+            }
+            Self::OutOfRange(name) => write!(f, "{name} is out of range"),
+            Self::Nal(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<nal::Error> for Error {
+    fn from(value: nal::Error) -> Self {
+        Self::Nal(value)
+    }
+}
+
+pub type Result<Ok = ()> = std::result::Result<Ok, Error>;
+
+/// Four character code of the box.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct BoxType(pub u32);
+
+impl BoxType {
+    pub const FTYP: Self = Self::new(b"ftyp");
+    pub const STYP: Self = Self::new(b"styp");
+    pub const MOOV: Self = Self::new(b"moov");
+    pub const MVHD: Self = Self::new(b"mvhd");
+    pub const MVEX: Self = Self::new(b"mvex");
+    pub const TREX: Self = Self::new(b"trex");
+    pub const TRAK: Self = Self::new(b"trak");
+    pub const TKHD: Self = Self::new(b"tkhd");
+    pub const MDIA: Self = Self::new(b"mdia");
+    pub const MDHD: Self = Self::new(b"mdhd");
+    pub const HDLR: Self = Self::new(b"hdlr");
+    pub const MINF: Self = Self::new(b"minf");
+    pub const VMHD: Self = Self::new(b"vmhd");
+    pub const SMHD: Self = Self::new(b"smhd");
+    pub const NMHD: Self = Self::new(b"nmhd");
+    pub const DINF: Self = Self::new(b"dinf");
+    pub const DREF: Self = Self::new(b"dref");
+    pub const URL: Self = Self::new(b"url ");
+    pub const STBL: Self = Self::new(b"stbl");
+    pub const STSD: Self = Self::new(b"stsd");
+    pub const STTS: Self = Self::new(b"stts");
+    pub const STSC: Self = Self::new(b"stsc");
+    pub const STSZ: Self = Self::new(b"stsz");
+    pub const STCO: Self = Self::new(b"stco");
+    pub const MOOF: Self = Self::new(b"moof");
+    pub const MFHD: Self = Self::new(b"mfhd");
+    pub const TRAF: Self = Self::new(b"traf");
+    pub const TFHD: Self = Self::new(b"tfhd");
+    pub const TFDT: Self = Self::new(b"tfdt");
+    pub const TRUN: Self = Self::new(b"trun");
+    pub const SIDX: Self = Self::new(b"sidx");
+    pub const MDAT: Self = Self::new(b"mdat");
+    pub const FREE: Self = Self::new(b"free");
+    pub const UUID: Self = Self::new(b"uuid");
+
+    pub const AVC1: Self = Self::new(b"avc1");
+    pub const AVC3: Self = Self::new(b"avc3");
+    pub const AVC_C: Self = Self::new(b"avcC");
+    pub const HVC1: Self = Self::new(b"hvc1");
+    pub const HEV1: Self = Self::new(b"hev1");
+    pub const HVC_C: Self = Self::new(b"hvcC");
+    pub const MP4A: Self = Self::new(b"mp4a");
+    pub const ESDS: Self = Self::new(b"esds");
+    pub const PASP: Self = Self::new(b"pasp");
+    pub const COLR: Self = Self::new(b"colr");
+    pub const BTRT: Self = Self::new(b"btrt");
+
+    #[inline]
+    pub const fn new(fcc: &[u8; 4]) -> Self {
+        Self(u32::from_be_bytes(*fcc))
+    }
+
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+}
+
+impl std::fmt::Debug for BoxType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::four_cc_fmt_debug(self.0, "iso::BoxType", f)
+    }
+}
+
+impl std::fmt::Display for BoxType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut fcc = self.to_be_bytes();
+        f.write_str(crate::four_cc_to_str(&mut fcc))
+    }
+}
+
+/// Box in existing data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoxRef<'a> {
+    typ: BoxType,
+    header_len: usize,
+    bytes: &'a [u8],
+}
+
+impl<'a> BoxRef<'a> {
+    /// Parses box header at the start of `data`. Box size 0 means box extends to the end.
+    pub fn parse(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let size = r.u32()? as u64;
+        let typ = BoxType(r.u32()?);
+        let size = match size {
+            0 => data.len() as u64,
+            1 => r.u64()?,
+            size => size,
+        };
+        if typ == BoxType::UUID {
+            r.bytes(16)?;
+        }
+        let header_len = data.len() - r.data.len();
+        if size < header_len as u64 || size > data.len() as u64 {
+            return Err(Error::InvalidSize(size));
+        }
+        Ok(Self {
+            typ,
+            header_len,
+            bytes: &data[..size as usize],
+        })
+    }
+
+    #[inline]
+    pub fn typ(&self) -> BoxType {
+        self.typ
+    }
+
+    /// Whole box including header.
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Box content after header.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        &self.bytes[self.header_len..]
+    }
+
+    /// Extended type of `uuid` box.
+    pub fn user_type(&self) -> Option<&'a [u8; 16]> {
+        if self.typ == BoxType::UUID {
+            self.bytes[self.header_len - 16..self.header_len]
+                .try_into()
+                .ok()
+        } else {
+            None
+        }
+    }
+
+    /// Version, flags and the rest of payload of full box.
+    pub fn full_payload(&self) -> Result<(u8, u32, &'a [u8])> {
+        let mut r = Reader::new(self.payload());
+        let vf = r.u32()?;
+        Ok(((vf >> 24) as u8, vf & 0x00ff_ffff, r.data))
+    }
+
+    /// Child boxes of container box.
+    #[inline]
+    pub fn children(&self) -> Boxes<'a> {
+        boxes(self.payload())
+    }
+
+    /// First child box of `typ`.
+    pub fn child(&self, typ: BoxType) -> Result<Option<Self>> {
+        self.children().find_type(typ)
+    }
+
+    pub(crate) fn expect(&self, typ: BoxType) -> Result {
+        if self.typ == typ {
+            Ok(())
+        } else {
+            Err(Error::UnexpectedType {
+                expected: typ,
+                actual: self.typ,
+            })
+        }
+    }
+
+    pub(crate) fn expect_full(&self, typ: BoxType, max_version: u8) -> Result<(u8, u32, &'a [u8])> {
+        self.expect(typ)?;
+        let (version, flags, data) = self.full_payload()?;
+        if version > max_version {
+            return Err(Error::UnsupportedVersion { typ, version });
+        }
+        Ok((version, flags, data))
+    }
+
+    /// Writes the box as is.
+    #[inline]
+    pub fn write(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.bytes);
+    }
+}
+
+/// Iterator over sibling boxes.
+///
+/// Yields error once and stops if data is malformed.
+#[derive(Debug, Clone)]
+pub struct Boxes<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Boxes<'a> {
+    /// First box of `typ`. Stops on the first malformed box.
+    pub fn find_type(self, typ: BoxType) -> Result<Option<BoxRef<'a>>> {
+        for b in self {
+            let b = b?;
+            if b.typ == typ {
+                return Ok(Some(b));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<'a> Iterator for Boxes<'a> {
+    type Item = Result<BoxRef<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        match BoxRef::parse(self.data) {
+            Ok(b) => {
+                self.data = &self.data[b.len()..];
+                Some(Ok(b))
+            }
+            Err(e) => {
+                self.data = &[];
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Iterates boxes of file, segment or container payload.
+///
+/// ```
+/// use cidre::cm::iso;
+///
+/// let data = [0, 0, 0, 8, b'f', b'r', b'e', b'e', 0, 0, 0, 9, b'm', b'd', b'a', b't', 7];
+/// let types: Vec<_> = iso::boxes(&data).map(|b| b.unwrap().typ()).collect();
+/// assert_eq!(types, [iso::BoxType::FREE, iso::BoxType::MDAT]);
+/// ```
+#[inline]
+pub fn boxes(data: &[u8]) -> Boxes<'_> {
+    Boxes { data }
+}
+
+/// Finds first box by path of types.
+///
+/// ```
+/// use cidre::cm::iso;
+///
+/// let data = [0, 0, 0, 16, b'm', b'o', b'o', b'f', 0, 0, 0, 8, b't', b'r', b'a', b'f'];
+/// let traf = iso::find(&data, &[iso::BoxType::MOOF, iso::BoxType::TRAF]).unwrap();
+/// assert_eq!(traf.unwrap().len(), 8);
+/// assert!(iso::find(&data, &[iso::BoxType::MOOV]).unwrap().is_none());
+/// ```
+pub fn find<'a>(data: &'a [u8], path: &[BoxType]) -> Result<Option<BoxRef<'a>>> {
+    let Some((&first, rest)) = path.split_first() else {
+        return Ok(None);
+    };
+    let mut cur = match boxes(data).find_type(first)? {
+        Some(b) => b,
+        None => return Ok(None),
+    };
+    for &typ in rest {
+        cur = match cur.child(typ)? {
+            Some(b) => b,
+            None => return Ok(None),
+        };
+    }
+    Ok(Some(cur))
+}
+
+/// Writes box header, content written by `f` and patches the size.
+///
+/// Switches to 64-bit size if content is larger than 4 GiB.
+pub fn write_box(
+    buf: &mut Vec<u8>,
+    typ: BoxType,
+    f: impl FnOnce(&mut Vec<u8>) -> Result,
+) -> Result {
+    let start = buf.len();
+    buf.extend_from_slice(&[0, 0, 0, 0]);
+    buf.extend_from_slice(&typ.to_be_bytes());
+    f(buf)?;
+    let size = buf.len() - start;
+    match u32::try_from(size) {
+        Ok(size) => buf[start..start + 4].copy_from_slice(&size.to_be_bytes()),
+        Err(_) => {
+            let size = size as u64 + 8;
+            buf[start..start + 4].copy_from_slice(&1u32.to_be_bytes());
+            buf.splice(start + 8..start + 8, size.to_be_bytes());
+        }
+    }
+    Ok(())
+}
+
+/// [`write_box`] with version and flags of full box.
+pub fn write_full_box(
+    buf: &mut Vec<u8>,
+    typ: BoxType,
+    version: u8,
+    flags: u32,
+    f: impl FnOnce(&mut Vec<u8>) -> Result,
+) -> Result {
+    write_box(buf, typ, |buf| {
+        buf.extend_from_slice(&((version as u32) << 24 | flags & 0x00ff_ffff).to_be_bytes());
+        f(buf)
+    })
+}
+
+/// `ftyp` or `styp` box.
+///
+/// ```
+/// use cidre::cm::iso;
+///
+/// let styp = iso::FileType {
+///     major_brand: iso::BoxType::new(b"msdh"),
+///     minor_version: 0,
+///     compatible_brands: vec![iso::BoxType::new(b"msdh"), iso::BoxType::new(b"msix")],
+/// };
+/// let mut buf = vec![];
+/// styp.write_styp(&mut buf).unwrap();
+/// let b = iso::BoxRef::parse(&buf).unwrap();
+/// assert_eq!(b.typ(), iso::BoxType::STYP);
+/// assert_eq!(iso::FileType::parse(&b).unwrap(), styp);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileType {
+    pub major_brand: BoxType,
+    pub minor_version: u32,
+    pub compatible_brands: Vec<BoxType>,
+}
+
+impl FileType {
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        if b.typ() != BoxType::STYP {
+            b.expect(BoxType::FTYP)?;
+        }
+        let mut r = Reader::new(b.payload());
+        let major_brand = BoxType(r.u32()?);
+        let minor_version = r.u32()?;
+        let mut compatible_brands = Vec::with_capacity(r.data.len() / 4);
+        while r.data.len() >= 4 {
+            compatible_brands.push(BoxType(r.u32()?));
+        }
+        Ok(Self {
+            major_brand,
+            minor_version,
+            compatible_brands,
+        })
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        self.write_as(BoxType::FTYP, buf)
+    }
+
+    pub fn write_styp(&self, buf: &mut Vec<u8>) -> Result {
+        self.write_as(BoxType::STYP, buf)
+    }
+
+    fn write_as(&self, typ: BoxType, buf: &mut Vec<u8>) -> Result {
+        write_box(buf, typ, |buf| {
+            buf.extend_from_slice(&self.major_brand.to_be_bytes());
+            buf.extend_from_slice(&self.minor_version.to_be_bytes());
+            for brand in &self.compatible_brands {
+                buf.extend_from_slice(&brand.to_be_bytes());
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Version of full box with time fields: 1 only if some value doesn't fit into 32 bits.
+pub(crate) fn time_version(values: &[u64]) -> u8 {
+    if values.iter().all(|v| u32::try_from(*v).is_ok()) {
+        0
+    } else {
+        1
+    }
+}
+
+/// `u32` for version 0 and `u64` for version 1.
+pub(crate) fn put_u32_or_u64(buf: &mut Vec<u8>, version: u8, value: u64) {
+    if version == 0 {
+        buf.extend_from_slice(&(value as u32).to_be_bytes());
+    } else {
+        buf.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+/// Big endian field reader.
+pub(crate) struct Reader<'a> {
+    pub data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    #[inline]
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub fn bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.data.len() < n {
+            return Err(Error::UnexpectedEnd);
+        }
+        let (res, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(res)
+    }
+
+    pub fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok(self.bytes(N)?.try_into().unwrap())
+    }
+
+    pub fn u8(&mut self) -> Result<u8> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    pub fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+
+    pub fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+
+    pub fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_be_bytes(self.array()?))
+    }
+
+    /// `u32` for version 0 and `u64` for version 1.
+    pub fn u32_or_u64(&mut self, version: u8) -> Result<u64> {
+        if version == 0 {
+            Ok(self.u32()? as u64)
+        } else {
+            self.u64()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::iso;
+
+    #[test]
+    fn boxes() {
+        let mut buf = vec![];
+        iso::write_box(&mut buf, iso::BoxType::MOOF, |buf| {
+            iso::write_full_box(buf, iso::BoxType::MFHD, 0, 0, |buf| {
+                buf.extend_from_slice(&7u32.to_be_bytes());
+                Ok(())
+            })?;
+            iso::write_box(buf, iso::BoxType::TRAF, |_| Ok(()))
+        })
+        .unwrap();
+        buf.extend_from_slice(&[0, 0, 0, 1, b'm', b'd', b'a', b't']);
+        buf.extend_from_slice(&20u64.to_be_bytes());
+        buf.extend_from_slice(&[1, 2, 3, 4]);
+
+        let all: Vec<_> = iso::boxes(&buf).map(Result::unwrap).collect();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].typ(), iso::BoxType::MOOF);
+        assert_eq!(all[0].len(), 32);
+        assert_eq!(all[1].typ(), iso::BoxType::MDAT);
+        assert_eq!(all[1].payload(), &[1, 2, 3, 4]);
+
+        let mfhd = all[0].child(iso::BoxType::MFHD).unwrap().unwrap();
+        assert_eq!(
+            mfhd.full_payload().unwrap(),
+            (0, 0, &7u32.to_be_bytes()[..])
+        );
+        assert!(std::ptr::eq(mfhd.as_bytes().as_ptr(), buf[8..].as_ptr()));
+
+        // box extends to the end
+        let b = iso::BoxRef::parse(&[0, 0, 0, 0, b'm', b'd', b'a', b't', 1]).unwrap();
+        assert_eq!(b.payload(), &[1]);
+
+        let uuid = [&[0, 0, 0, 25, b'u', b'u', b'i', b'd'][..], &[9; 16], &[5]].concat();
+        let b = iso::BoxRef::parse(&uuid).unwrap();
+        assert_eq!(b.user_type(), Some(&[9; 16]));
+        assert_eq!(b.payload(), &[5]);
+
+        let mut iter = iso::boxes(&[0, 0, 0, 9, b'f', b'r', b'e', b'e']);
+        assert_eq!(iter.next(), Some(Err(iso::Error::InvalidSize(9))));
+        assert_eq!(iter.next(), None);
+        assert_eq!(
+            iso::BoxRef::parse(&[0, 0, 0, 4, b'f', b'r', b'e', b'e']),
+            Err(iso::Error::InvalidSize(4))
+        );
+        assert_eq!(
+            iso::BoxRef::parse(&[0, 0, 0]),
+            Err(iso::Error::UnexpectedEnd)
+        );
+    }
+}
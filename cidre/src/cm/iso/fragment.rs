@@ -0,0 +1,754 @@
+use crate::cm::iso::{self, BoxRef, BoxType, Error, Reader, Result};
+
+/// `tfhd` box.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tfhd {
+    pub track_id: u32,
+    pub base_data_offset: Option<u64>,
+    pub sample_description_index: Option<u32>,
+    pub default_sample_duration: Option<u32>,
+    pub default_sample_size: Option<u32>,
+    pub default_sample_flags: Option<u32>,
+    pub duration_is_empty: bool,
+    /// Data offsets of `trun` boxes are relative to the start of `moof`.
+    pub default_base_is_moof: bool,
+}
+
+impl Tfhd {
+    const BASE_DATA_OFFSET: u32 = 0x01;
+    const SAMPLE_DESCRIPTION_INDEX: u32 = 0x02;
+    const DEFAULT_SAMPLE_DURATION: u32 = 0x08;
+    const DEFAULT_SAMPLE_SIZE: u32 = 0x10;
+    const DEFAULT_SAMPLE_FLAGS: u32 = 0x20;
+    const DURATION_IS_EMPTY: u32 = 0x01_0000;
+    const DEFAULT_BASE_IS_MOOF: u32 = 0x02_0000;
+
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        let (_, flags, data) = b.expect_full(BoxType::TFHD, 0)?;
+        let mut r = Reader::new(data);
+        let track_id = r.u32()?;
+        let base_data_offset = if flags & Self::BASE_DATA_OFFSET != 0 {
+            Some(r.u64()?)
+        } else {
+            None
+        };
+        let mut opt = |flag: u32| -> Result<Option<u32>> {
+            if flags & flag != 0 {
+                Ok(Some(r.u32()?))
+            } else {
+                Ok(None)
+            }
+        };
+        Ok(Self {
+            track_id,
+            base_data_offset,
+            sample_description_index: opt(Self::SAMPLE_DESCRIPTION_INDEX)?,
+            default_sample_duration: opt(Self::DEFAULT_SAMPLE_DURATION)?,
+            default_sample_size: opt(Self::DEFAULT_SAMPLE_SIZE)?,
+            default_sample_flags: opt(Self::DEFAULT_SAMPLE_FLAGS)?,
+            duration_is_empty: flags & Self::DURATION_IS_EMPTY != 0,
+            default_base_is_moof: flags & Self::DEFAULT_BASE_IS_MOOF != 0,
+        })
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        let mut flags = 0;
+        let mut set = |flag: u32, present: bool| {
+            if present {
+                flags |= flag;
+            }
+        };
+        set(Self::BASE_DATA_OFFSET, self.base_data_offset.is_some());
+        set(
+            Self::SAMPLE_DESCRIPTION_INDEX,
+            self.sample_description_index.is_some(),
+        );
+        set(
+            Self::DEFAULT_SAMPLE_DURATION,
+            self.default_sample_duration.is_some(),
+        );
+        set(
+            Self::DEFAULT_SAMPLE_SIZE,
+            self.default_sample_size.is_some(),
+        );
+        set(
+            Self::DEFAULT_SAMPLE_FLAGS,
+            self.default_sample_flags.is_some(),
+        );
+        set(Self::DURATION_IS_EMPTY, self.duration_is_empty);
+        set(Self::DEFAULT_BASE_IS_MOOF, self.default_base_is_moof);
+        iso::write_full_box(buf, BoxType::TFHD, 0, flags, |buf| {
+            buf.extend_from_slice(&self.track_id.to_be_bytes());
+            if let Some(offset) = self.base_data_offset {
+                buf.extend_from_slice(&offset.to_be_bytes());
+            }
+            let fields = [
+                self.sample_description_index,
+                self.default_sample_duration,
+                self.default_sample_size,
+                self.default_sample_flags,
+            ];
+            for v in fields.into_iter().flatten() {
+                buf.extend_from_slice(&v.to_be_bytes());
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Sample of `trun` box. Missing fields are taken from `tfhd` or `trex` defaults.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrunSample {
+    pub duration: Option<u32>,
+    pub size: Option<u32>,
+    pub flags: Option<u32>,
+    pub composition_time_offset: Option<i64>,
+}
+
+/// `trun` box.
+///
+/// All samples must have the same set of fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trun {
+    pub data_offset: Option<i32>,
+    pub first_sample_flags: Option<u32>,
+    pub samples: Vec<TrunSample>,
+}
+
+impl Trun {
+    const DATA_OFFSET: u32 = 0x01;
+    const FIRST_SAMPLE_FLAGS: u32 = 0x04;
+    const SAMPLE_DURATION: u32 = 0x100;
+    const SAMPLE_SIZE: u32 = 0x200;
+    const SAMPLE_FLAGS: u32 = 0x400;
+    const SAMPLE_COMPOSITION_TIME_OFFSET: u32 = 0x800;
+
+    /// Samples without fields take no space, so data can't bound their count.
+    const MAX_EMPTY_SAMPLES: u32 = 1 << 20;
+
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        let (version, flags, data) = b.expect_full(BoxType::TRUN, 1)?;
+        let mut r = Reader::new(data);
+        let count = r.u32()?;
+        let data_offset = if flags & Self::DATA_OFFSET != 0 {
+            Some(r.u32()? as i32)
+        } else {
+            None
+        };
+        let first_sample_flags = if flags & Self::FIRST_SAMPLE_FLAGS != 0 {
+            Some(r.u32()?)
+        } else {
+            None
+        };
+        let sample_len = [
+            Self::SAMPLE_DURATION,
+            Self::SAMPLE_SIZE,
+            Self::SAMPLE_FLAGS,
+            Self::SAMPLE_COMPOSITION_TIME_OFFSET,
+        ]
+        .iter()
+        .filter(|&&f| flags & f != 0)
+        .count()
+            * 4;
+        if sample_len == 0 && count > Self::MAX_EMPTY_SAMPLES {
+            return Err(Error::OutOfRange("sample_count"));
+        }
+        if (count as usize).saturating_mul(sample_len) > r.data.len() {
+            return Err(Error::UnexpectedEnd);
+        }
+        let mut samples =
+            Vec::with_capacity((count as usize).min(r.data.len() / sample_len.max(1)));
+        for _ in 0..count {
+            let mut opt = |flag: u32| -> Result<Option<u32>> {
+                if flags & flag != 0 {
+                    Ok(Some(r.u32()?))
+                } else {
+                    Ok(None)
+                }
+            };
+            samples.push(TrunSample {
+                duration: opt(Self::SAMPLE_DURATION)?,
+                size: opt(Self::SAMPLE_SIZE)?,
+                flags: opt(Self::SAMPLE_FLAGS)?,
+                composition_time_offset: opt(Self::SAMPLE_COMPOSITION_TIME_OFFSET)?.map(|v| {
+                    if version == 0 {
+                        v as i64
+                    } else {
+                        v as i32 as i64
+                    }
+                }),
+            });
+        }
+        Ok(Self {
+            data_offset,
+            first_sample_flags,
+            samples,
+        })
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        let first = self.samples.first().copied().unwrap_or_default();
+        let present = |s: &TrunSample| {
+            (
+                s.duration.is_some(),
+                s.size.is_some(),
+                s.flags.is_some(),
+                s.composition_time_offset.is_some(),
+            )
+        };
+        if self.samples.iter().any(|s| present(s) != present(&first)) {
+            return Err(Error::OutOfRange("trun sample fields"));
+        }
+        let mut version = 0;
+        for cto in self
+            .samples
+            .iter()
+            .filter_map(|s| s.composition_time_offset)
+        {
+            if cto < 0 {
+                version = 1;
+            }
+            if cto < i32::MIN as i64 || cto > u32::MAX as i64 {
+                return Err(Error::OutOfRange("sample_composition_time_offset"));
+            }
+        }
+        if version == 1
+            && self
+                .samples
+                .iter()
+                .filter_map(|s| s.composition_time_offset)
+                .any(|cto| cto > i32::MAX as i64)
+        {
+            return Err(Error::OutOfRange("sample_composition_time_offset"));
+        }
+
+        let mut flags = 0;
+        let fields = [
+            (Self::DATA_OFFSET, self.data_offset.is_some()),
+            (Self::FIRST_SAMPLE_FLAGS, self.first_sample_flags.is_some()),
+            (Self::SAMPLE_DURATION, first.duration.is_some()),
+            (Self::SAMPLE_SIZE, first.size.is_some()),
+            (Self::SAMPLE_FLAGS, first.flags.is_some()),
+            (
+                Self::SAMPLE_COMPOSITION_TIME_OFFSET,
+                first.composition_time_offset.is_some(),
+            ),
+        ];
+        for (flag, present) in fields {
+            if present {
+                flags |= flag;
+            }
+        }
+        iso::write_full_box(buf, BoxType::TRUN, version, flags, |buf| {
+            buf.extend_from_slice(&(self.samples.len() as u32).to_be_bytes());
+            if let Some(offset) = self.data_offset {
+                buf.extend_from_slice(&offset.to_be_bytes());
+            }
+            if let Some(flags) = self.first_sample_flags {
+                buf.extend_from_slice(&flags.to_be_bytes());
+            }
+            for s in &self.samples {
+                let cto = s.composition_time_offset.map(|v| v as u32);
+                for v in [s.duration, s.size, s.flags, cto].into_iter().flatten() {
+                    buf.extend_from_slice(&v.to_be_bytes());
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+/// `traf` box.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Traf {
+    pub tfhd: Tfhd,
+    /// `tfdt` box.
+    pub base_media_decode_time: Option<u64>,
+    pub truns: Vec<Trun>,
+    /// Other child boxes (`sdtp`, `saiz`, `senc`, ...) as is.
+    pub other: Vec<u8>,
+}
+
+impl Traf {
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        b.expect(BoxType::TRAF)?;
+        let mut tfhd = None;
+        let mut res = Self::default();
+        for child in b.children() {
+            let child = child?;
+            match child.typ() {
+                BoxType::TFHD => tfhd = Some(Tfhd::parse(&child)?),
+                BoxType::TFDT => {
+                    let (version, _, data) = child.expect_full(BoxType::TFDT, 1)?;
+                    res.base_media_decode_time = Some(Reader::new(data).u32_or_u64(version)?);
+                }
+                BoxType::TRUN => res.truns.push(Trun::parse(&child)?),
+                _ => child.write(&mut res.other),
+            }
+        }
+        res.tfhd = tfhd.ok_or(Error::MissingBox(BoxType::TFHD))?;
+        Ok(res)
+    }
+
+    /// `tfdt` is written with version 1 only if decode time doesn't fit into 32 bits.
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        iso::write_box(buf, BoxType::TRAF, |buf| {
+            self.tfhd.write(buf)?;
+            if let Some(time) = self.base_media_decode_time {
+                match u32::try_from(time) {
+                    Ok(time) => iso::write_full_box(buf, BoxType::TFDT, 0, 0, |buf| {
+                        buf.extend_from_slice(&time.to_be_bytes());
+                        Ok(())
+                    })?,
+                    Err(_) => iso::write_full_box(buf, BoxType::TFDT, 1, 0, |buf| {
+                        buf.extend_from_slice(&time.to_be_bytes());
+                        Ok(())
+                    })?,
+                }
+            }
+            for trun in &self.truns {
+                trun.write(buf)?;
+            }
+            buf.extend_from_slice(&self.other);
+            Ok(())
+        })
+    }
+}
+
+/// `moof` box.
+///
+/// ```
+/// use cidre::cm::iso;
+///
+/// let mut moof = iso::Moof {
+///     sequence_number: 1,
+///     trafs: vec![iso::Traf {
+///         tfhd: iso::Tfhd { track_id: 1, ..Default::default() },
+///         base_media_decode_time: Some(90_000),
+///         truns: vec![iso::Trun {
+///             samples: vec![iso::TrunSample { size: Some(100), ..Default::default() }; 3],
+///             ..Default::default()
+///         }],
+///         ..Default::default()
+///     }],
+///     ..Default::default()
+/// };
+/// moof.set_data_offsets().unwrap();
+///
+/// let mut buf = vec![];
+/// moof.write(&mut buf).unwrap();
+/// // sample data starts right after `mdat` header
+/// assert_eq!(moof.trafs[0].truns[0].data_offset, Some(buf.len() as i32 + 8));
+/// assert_eq!(iso::Moof::parse(&iso::BoxRef::parse(&buf).unwrap()).unwrap(), moof);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Moof {
+    /// `mfhd` box.
+    pub sequence_number: u32,
+    pub trafs: Vec<Traf>,
+    /// Other child boxes (`pssh`, ...) as is.
+    pub other: Vec<u8>,
+}
+
+impl Moof {
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        b.expect(BoxType::MOOF)?;
+        let mut res = Self::default();
+        for child in b.children() {
+            let child = child?;
+            match child.typ() {
+                BoxType::MFHD => {
+                    let (_, _, data) = child.expect_full(BoxType::MFHD, 0)?;
+                    res.sequence_number = Reader::new(data).u32()?;
+                }
+                BoxType::TRAF => res.trafs.push(Traf::parse(&child)?),
+                _ => child.write(&mut res.other),
+            }
+        }
+        Ok(res)
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        iso::write_box(buf, BoxType::MOOF, |buf| {
+            iso::write_full_box(buf, BoxType::MFHD, 0, 0, |buf| {
+                buf.extend_from_slice(&self.sequence_number.to_be_bytes());
+                Ok(())
+            })?;
+            for traf in &self.trafs {
+                traf.write(buf)?;
+            }
+            buf.extend_from_slice(&self.other);
+            Ok(())
+        })
+    }
+
+    /// Sets `trun` data offsets for sample data stored in following `mdat`
+    /// in order of `traf` and `trun` boxes.
+    ///
+    /// Data offsets become relative to `moof` (`default_base_is_moof` is set and
+    /// `base_data_offset` is cleared). Every sample must have size in `trun` or `tfhd`.
+    pub fn set_data_offsets(&mut self) -> Result {
+        for traf in &mut self.trafs {
+            traf.tfhd.base_data_offset = None;
+            traf.tfhd.default_base_is_moof = true;
+            for trun in &mut traf.truns {
+                trun.data_offset = Some(0);
+            }
+        }
+        let mut buf = vec![];
+        self.write(&mut buf)?;
+        // moof and mdat header
+        let mut offset = buf.len() as u64 + 8;
+        for traf in &mut self.trafs {
+            let default_size = traf.tfhd.default_sample_size;
+            for trun in &mut traf.truns {
+                trun.data_offset =
+                    Some(i32::try_from(offset).map_err(|_| Error::OutOfRange("data_offset"))?);
+                for s in &trun.samples {
+                    let size = s
+                        .size
+                        .or(default_size)
+                        .ok_or(Error::OutOfRange("sample_size"))?;
+                    offset += size as u64;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reference of `sidx` box.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SidxRef {
+    /// Reference to other `sidx` instead of media.
+    pub is_sidx: bool,
+    /// 31 bits.
+    pub size: u32,
+    pub subsegment_duration: u32,
+    pub starts_with_sap: bool,
+    /// 3 bits.
+    pub sap_type: u8,
+    /// 28 bits.
+    pub sap_delta_time: u32,
+}
+
+/// `sidx` box.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sidx {
+    pub reference_id: u32,
+    pub timescale: u32,
+    pub earliest_presentation_time: u64,
+    pub first_offset: u64,
+    pub refs: Vec<SidxRef>,
+}
+
+impl Sidx {
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        let (version, _, data) = b.expect_full(BoxType::SIDX, 1)?;
+        let mut r = Reader::new(data);
+        let reference_id = r.u32()?;
+        let timescale = r.u32()?;
+        let earliest_presentation_time = r.u32_or_u64(version)?;
+        let first_offset = r.u32_or_u64(version)?;
+        r.u16()?;
+        let count = r.u16()?;
+        if count as usize * 12 > r.data.len() {
+            return Err(Error::UnexpectedEnd);
+        }
+        let mut refs = Vec::with_capacity((count as usize).min(r.data.len() / 12));
+        for _ in 0..count {
+            let a = r.u32()?;
+            let subsegment_duration = r.u32()?;
+            let c = r.u32()?;
+            refs.push(SidxRef {
+                is_sidx: a >> 31 != 0,
+                size: a & 0x7fff_ffff,
+                subsegment_duration,
+                starts_with_sap: c >> 31 != 0,
+                sap_type: (c >> 28) as u8 & 0x7,
+                sap_delta_time: c & 0x0fff_ffff,
+            });
+        }
+        Ok(Self {
+            reference_id,
+            timescale,
+            earliest_presentation_time,
+            first_offset,
+            refs,
+        })
+    }
+
+    /// Version 1 is used only if times or offset don't fit into 32 bits.
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        let count =
+            u16::try_from(self.refs.len()).map_err(|_| Error::OutOfRange("reference_count"))?;
+        for r in &self.refs {
+            if r.size > 0x7fff_ffff {
+                return Err(Error::OutOfRange("referenced_size"));
+            }
+            if r.sap_type > 7 || r.sap_delta_time > 0x0fff_ffff {
+                return Err(Error::OutOfRange("SAP"));
+            }
+        }
+        let version = if self.earliest_presentation_time > u32::MAX as u64
+            || self.first_offset > u32::MAX as u64
+        {
+            1
+        } else {
+            0
+        };
+        iso::write_full_box(buf, BoxType::SIDX, version, 0, |buf| {
+            buf.extend_from_slice(&self.reference_id.to_be_bytes());
+            buf.extend_from_slice(&self.timescale.to_be_bytes());
+            if version == 0 {
+                buf.extend_from_slice(&(self.earliest_presentation_time as u32).to_be_bytes());
+                buf.extend_from_slice(&(self.first_offset as u32).to_be_bytes());
+            } else {
+                buf.extend_from_slice(&self.earliest_presentation_time.to_be_bytes());
+                buf.extend_from_slice(&self.first_offset.to_be_bytes());
+            }
+            buf.extend_from_slice(&[0, 0]);
+            buf.extend_from_slice(&count.to_be_bytes());
+            for r in &self.refs {
+                let a = (r.is_sidx as u32) << 31 | r.size;
+                let c =
+                    (r.starts_with_sap as u32) << 31 | (r.sap_type as u32) << 28 | r.sap_delta_time;
+                buf.extend_from_slice(&a.to_be_bytes());
+                buf.extend_from_slice(&r.subsegment_duration.to_be_bytes());
+                buf.extend_from_slice(&c.to_be_bytes());
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Rewrites `tfdt` decode times and `sidx` earliest presentation times of segment in place.
+///
+/// `f` receives track id (`sidx` reference id) and time. Box sizes never change,
+/// so if new time doesn't fit into version 0 box nothing is written and error is returned.
+/// Returns number of patched times.
+///
+/// ```
+/// use cidre::cm::iso;
+///
+/// let mut moof = iso::Moof::default();
+/// moof.trafs.push(iso::Traf {
+///     tfhd: iso::Tfhd { track_id: 2, ..Default::default() },
+///     base_media_decode_time: Some(1000),
+///     ..Default::default()
+/// });
+/// let mut segment = vec![];
+/// moof.write(&mut segment).unwrap();
+///
+/// let n = iso::fragment::map_times(&mut segment, |_track_id, t| t + 500).unwrap();
+/// assert_eq!(n, 1);
+/// let moof = iso::Moof::parse(&iso::BoxRef::parse(&segment).unwrap()).unwrap();
+/// assert_eq!(moof.trafs[0].base_media_decode_time, Some(1500));
+/// ```
+pub fn map_times(segment: &mut [u8], mut f: impl FnMut(u32, u64) -> u64) -> Result<usize> {
+    // (offset, version, new time)
+    let mut patches = Vec::new();
+    let base = segment.as_ptr() as usize;
+    let offset = |data: &[u8]| data.as_ptr() as usize - base;
+    for b in iso::boxes(segment) {
+        let b = b?;
+        match b.typ() {
+            BoxType::MOOF => {
+                for traf in b.children() {
+                    let traf = traf?;
+                    if traf.typ() != BoxType::TRAF {
+                        continue;
+                    }
+                    let Some(tfhd) = traf.child(BoxType::TFHD)? else {
+                        continue;
+                    };
+                    let track_id = Tfhd::parse(&tfhd)?.track_id;
+                    let Some(tfdt) = traf.child(BoxType::TFDT)? else {
+                        continue;
+                    };
+                    let (version, _, data) = tfdt.expect_full(BoxType::TFDT, 1)?;
+                    let time = Reader::new(data).u32_or_u64(version)?;
+                    patches.push((offset(data), version, f(track_id, time)));
+                }
+            }
+            BoxType::SIDX => {
+                let (version, _, data) = b.expect_full(BoxType::SIDX, 1)?;
+                let mut r = Reader::new(data);
+                let reference_id = r.u32()?;
+                r.u32()?;
+                let time = r.u32_or_u64(version)?;
+                patches.push((offset(data) + 8, version, f(reference_id, time)));
+            }
+            _ => {}
+        }
+    }
+    if patches
+        .iter()
+        .any(|&(_, version, time)| version == 0 && time > u32::MAX as u64)
+    {
+        return Err(Error::OutOfRange("time"));
+    }
+    for &(offset, version, time) in &patches {
+        if version == 0 {
+            segment[offset..offset + 4].copy_from_slice(&(time as u32).to_be_bytes());
+        } else {
+            segment[offset..offset + 8].copy_from_slice(&time.to_be_bytes());
+        }
+    }
+    Ok(patches.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::iso;
+
+    #[test]
+    fn trun() {
+        let trun = iso::Trun {
+            data_offset: Some(120),
+            first_sample_flags: Some(0x0200_0000),
+            samples: vec![
+                iso::TrunSample {
+                    duration: Some(3000),
+                    size: Some(500),
+                    flags: None,
+                    composition_time_offset: Some(-3000),
+                },
+                iso::TrunSample {
+                    duration: Some(3000),
+                    size: Some(100),
+                    flags: None,
+                    composition_time_offset: Some(6000),
+                },
+            ],
+        };
+        let mut buf = vec![];
+        trun.write(&mut buf).unwrap();
+        // header, version and flags, count, offset, first flags, 2 x 3 fields
+        assert_eq!(buf.len(), 8 + 4 + 4 + 4 + 4 + 24);
+        assert_eq!(&buf[8..12], &[1, 0, 0x0b, 0x05]);
+        let b = iso::BoxRef::parse(&buf).unwrap();
+        assert_eq!(iso::Trun::parse(&b).unwrap(), trun);
+
+        let mut bad = trun.clone();
+        bad.samples[1].size = None;
+        assert_eq!(
+            bad.write(&mut vec![]),
+            Err(iso::Error::OutOfRange("trun sample fields"))
+        );
+
+        // sample count larger than data
+        let mut short = buf.clone();
+        short[15] = 3;
+        let b = iso::BoxRef::parse(&short).unwrap();
+        assert_eq!(iso::Trun::parse(&b), Err(iso::Error::UnexpectedEnd));
+
+        // no per-sample fields, count is not bounded by data
+        let mut empty = vec![];
+        iso::Trun::default().write(&mut empty).unwrap();
+        empty[12..16].copy_from_slice(&u32::MAX.to_be_bytes());
+        let b = iso::BoxRef::parse(&empty).unwrap();
+        assert_eq!(
+            iso::Trun::parse(&b),
+            Err(iso::Error::OutOfRange("sample_count"))
+        );
+    }
+
+    #[test]
+    fn segment() {
+        let tfhd = iso::Tfhd {
+            track_id: 1,
+            base_data_offset: Some(1 << 33),
+            default_sample_duration: Some(1024),
+            default_sample_flags: Some(0x0101_0000),
+            ..Default::default()
+        };
+        let mut buf = vec![];
+        tfhd.write(&mut buf).unwrap();
+        assert_eq!(&buf[8..12], &[0, 0, 0, 0x29]);
+        assert_eq!(
+            iso::Tfhd::parse(&iso::BoxRef::parse(&buf).unwrap()).unwrap(),
+            tfhd
+        );
+
+        let sidx = iso::Sidx {
+            reference_id: 1,
+            timescale: 48_000,
+            earliest_presentation_time: u32::MAX as u64 - 10,
+            first_offset: 0,
+            refs: vec![iso::SidxRef {
+                is_sidx: false,
+                size: 1234,
+                subsegment_duration: 96_000,
+                starts_with_sap: true,
+                sap_type: 1,
+                sap_delta_time: 0,
+            }],
+        };
+        let moof = iso::Moof {
+            sequence_number: 5,
+            trafs: vec![iso::Traf {
+                tfhd: iso::Tfhd {
+                    track_id: 1,
+                    ..Default::default()
+                },
+                base_media_decode_time: Some(u32::MAX as u64 + 1),
+                truns: vec![iso::Trun {
+                    samples: vec![Default::default(); 2],
+                    ..Default::default()
+                }],
+                other: vec![0, 0, 0, 8, b's', b'd', b't', b'p'],
+            }],
+            other: vec![],
+        };
+        let mut segment = vec![];
+        iso::FileType {
+            major_brand: iso::BoxType::new(b"msdh"),
+            minor_version: 0,
+            compatible_brands: vec![],
+        }
+        .write_styp(&mut segment)
+        .unwrap();
+        sidx.write(&mut segment).unwrap();
+        moof.write(&mut segment).unwrap();
+        iso::write_box(&mut segment, iso::BoxType::MDAT, |_| Ok(())).unwrap();
+
+        let types: Vec<_> = iso::boxes(&segment).map(|b| b.unwrap().typ()).collect();
+        assert_eq!(
+            types,
+            [
+                iso::BoxType::STYP,
+                iso::BoxType::SIDX,
+                iso::BoxType::MOOF,
+                iso::BoxType::MDAT
+            ]
+        );
+        let parsed = iso::find(&segment, &[iso::BoxType::MOOF]).unwrap().unwrap();
+        assert_eq!(iso::Moof::parse(&parsed).unwrap(), moof);
+        let parsed = iso::find(&segment, &[iso::BoxType::SIDX]).unwrap().unwrap();
+        assert_eq!(iso::Sidx::parse(&parsed).unwrap(), sidx);
+
+        let mut seen = vec![];
+        let n = iso::fragment::map_times(&mut segment, |id, t| {
+            seen.push((id, t));
+            t + 5
+        })
+        .unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(seen, [(1, u32::MAX as u64 - 10), (1, u32::MAX as u64 + 1)]);
+        let parsed = iso::find(&segment, &[iso::BoxType::MOOF]).unwrap().unwrap();
+        let parsed = iso::Moof::parse(&parsed).unwrap();
+        assert_eq!(
+            parsed.trafs[0].base_media_decode_time,
+            Some(u32::MAX as u64 + 6)
+        );
+
+        // sidx v0 overflow leaves segment untouched
+        let copy = segment.clone();
+        assert_eq!(
+            iso::fragment::map_times(&mut segment, |_, t| t + 100),
+            Err(iso::Error::OutOfRange("time"))
+        );
+        assert_eq!(segment, copy);
+    }
+}
@@ -0,0 +1,253 @@
+use crate::cm::iso::{self, BoxRef, BoxType, Error, Reader, Result, Track, UNITY_MATRIX};
+
+/// `mvhd` box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mvhd {
+    pub creation_time: u64,
+    pub modification_time: u64,
+    pub timescale: u32,
+    pub duration: u64,
+    /// 16.16 fixed point, `0x0001_0000` for normal rate.
+    pub rate: i32,
+    /// 8.8 fixed point, `0x0100` for full volume.
+    pub volume: i16,
+    pub matrix: [i32; 9],
+    pub next_track_id: u32,
+}
+
+impl Mvhd {
+    pub fn new(timescale: u32, next_track_id: u32) -> Self {
+        Self {
+            creation_time: 0,
+            modification_time: 0,
+            timescale,
+            duration: 0,
+            rate: 0x0001_0000,
+            volume: 0x0100,
+            matrix: UNITY_MATRIX,
+            next_track_id,
+        }
+    }
+
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        let (version, _, data) = b.expect_full(BoxType::MVHD, 1)?;
+        let mut r = Reader::new(data);
+        let creation_time = r.u32_or_u64(version)?;
+        let modification_time = r.u32_or_u64(version)?;
+        let timescale = r.u32()?;
+        let duration = r.u32_or_u64(version)?;
+        let rate = r.u32()? as i32;
+        let volume = r.u16()? as i16;
+        r.bytes(10)?;
+        let mut matrix = [0; 9];
+        for v in &mut matrix {
+            *v = r.u32()? as i32;
+        }
+        r.bytes(24)?;
+        Ok(Self {
+            creation_time,
+            modification_time,
+            timescale,
+            duration,
+            rate,
+            volume,
+            matrix,
+            next_track_id: r.u32()?,
+        })
+    }
+
+    /// Written with version 1 only if times or duration don't fit into 32 bits.
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        let times = [self.creation_time, self.modification_time, self.duration];
+        let version = iso::time_version(&times);
+        iso::write_full_box(buf, BoxType::MVHD, version, 0, |buf| {
+            iso::put_u32_or_u64(buf, version, self.creation_time);
+            iso::put_u32_or_u64(buf, version, self.modification_time);
+            buf.extend_from_slice(&self.timescale.to_be_bytes());
+            iso::put_u32_or_u64(buf, version, self.duration);
+            buf.extend_from_slice(&self.rate.to_be_bytes());
+            buf.extend_from_slice(&self.volume.to_be_bytes());
+            buf.extend_from_slice(&[0; 10]);
+            for v in self.matrix {
+                buf.extend_from_slice(&v.to_be_bytes());
+            }
+            // pre_defined
+            buf.extend_from_slice(&[0; 24]);
+            buf.extend_from_slice(&self.next_track_id.to_be_bytes());
+            Ok(())
+        })
+    }
+}
+
+/// `moov` box.
+///
+/// ```
+/// use cidre::cm::iso;
+///
+/// let entry = iso::VisualSampleEntry::new(iso::BoxType::AVC1, 1920, 1080);
+/// let stsd = iso::Stsd { entries: vec![iso::SampleEntry::Visual(entry)] };
+/// let track = iso::Track::new(1, iso::Track::VIDEO_HANDLER, 90_000, stsd);
+/// let moov = iso::Moov::with_fragmented_tracks(1_000, vec![track]).unwrap();
+/// assert_eq!(moov.mvhd.next_track_id, 2);
+///
+/// let mut init = vec![];
+/// moov.write(&mut init).unwrap();
+/// assert_eq!(iso::Track::all(&init).unwrap(), moov.tracks);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Moov {
+    pub mvhd: Mvhd,
+    pub tracks: Vec<Track>,
+    /// Other child boxes (`mvex`, `udta`, ...) as is.
+    pub other: Vec<u8>,
+}
+
+impl Moov {
+    /// Movie of initialization segment with `mvex` and `trex` for every track.
+    pub fn with_fragmented_tracks(timescale: u32, tracks: Vec<Track>) -> Result<Self> {
+        let max_track_id = tracks.iter().map(Track::track_id).max().unwrap_or(0);
+        let next_track_id = max_track_id
+            .checked_add(1)
+            .ok_or(Error::OutOfRange("next_track_id"))?;
+        let mut other = vec![];
+        iso::write_box(&mut other, BoxType::MVEX, |buf| {
+            for track in &tracks {
+                iso::write_full_box(buf, BoxType::TREX, 0, 0, |buf| {
+                    buf.extend_from_slice(&track.track_id().to_be_bytes());
+                    // default sample description index
+                    buf.extend_from_slice(&1u32.to_be_bytes());
+                    // default sample duration, size and flags
+                    buf.extend_from_slice(&[0; 12]);
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        })?;
+        Ok(Self {
+            mvhd: Mvhd::new(timescale, next_track_id),
+            tracks,
+            other,
+        })
+    }
+
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        b.expect(BoxType::MOOV)?;
+        let mut mvhd = None;
+        let mut tracks = Vec::new();
+        let mut other = Vec::new();
+        for child in b.children() {
+            let child = child?;
+            match child.typ() {
+                BoxType::MVHD => mvhd = Some(Mvhd::parse(&child)?),
+                BoxType::TRAK => tracks.push(Track::parse(&child)?),
+                _ => child.write(&mut other),
+            }
+        }
+        Ok(Self {
+            mvhd: mvhd.ok_or(Error::MissingBox(BoxType::MVHD))?,
+            tracks,
+            other,
+        })
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        iso::write_box(buf, BoxType::MOOV, |buf| {
+            self.mvhd.write(buf)?;
+            for track in &self.tracks {
+                track.write(buf)?;
+            }
+            buf.extend_from_slice(&self.other);
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::iso;
+
+    #[test]
+    fn mvhd_round_trip() {
+        let mut payload = vec![0; 96];
+        payload[8..12].copy_from_slice(&1_000u32.to_be_bytes());
+        payload[16..20].copy_from_slice(&0x0001_0000u32.to_be_bytes());
+        payload[20..22].copy_from_slice(&0x0100u16.to_be_bytes());
+        for (i, v) in iso::UNITY_MATRIX.into_iter().enumerate() {
+            payload[32 + i * 4..36 + i * 4].copy_from_slice(&v.to_be_bytes());
+        }
+        payload[95] = 3;
+        let mut buf = vec![];
+        iso::write_full_box(&mut buf, iso::BoxType::MVHD, 0, 0, |buf| {
+            buf.extend_from_slice(&payload);
+            Ok(())
+        })
+        .unwrap();
+
+        let mvhd = iso::Mvhd::parse(&iso::BoxRef::parse(&buf).unwrap()).unwrap();
+        assert_eq!(mvhd, iso::Mvhd::new(1_000, 3));
+        let mut out = vec![];
+        mvhd.write(&mut out).unwrap();
+        assert_eq!(out, buf);
+
+        let mvhd = iso::Mvhd {
+            creation_time: u32::MAX as u64 + 1,
+            ..mvhd
+        };
+        let mut buf = vec![];
+        mvhd.write(&mut buf).unwrap();
+        let b = iso::BoxRef::parse(&buf).unwrap();
+        assert_eq!(b.full_payload().unwrap().0, 1);
+        assert_eq!(iso::Mvhd::parse(&b).unwrap(), mvhd);
+    }
+
+    #[test]
+    fn moov_round_trip() {
+        let visual = iso::VisualSampleEntry::new(iso::BoxType::HVC1, 3840, 2160);
+        let audio = iso::AudioSampleEntry::with_aac(48_000, 2, &[0x11, 0x90]).unwrap();
+        let tracks = vec![
+            iso::Track::new(
+                1,
+                iso::Track::VIDEO_HANDLER,
+                600,
+                iso::Stsd {
+                    entries: vec![iso::SampleEntry::Visual(visual)],
+                },
+            ),
+            iso::Track::new(
+                7,
+                iso::Track::SOUND_HANDLER,
+                48_000,
+                iso::Stsd {
+                    entries: vec![iso::SampleEntry::Audio(audio)],
+                },
+            ),
+        ];
+        let moov = iso::Moov::with_fragmented_tracks(600, tracks).unwrap();
+        assert_eq!(moov.mvhd.next_track_id, 8);
+
+        let mut buf = vec![];
+        moov.write(&mut buf).unwrap();
+        let b = iso::BoxRef::parse(&buf).unwrap();
+        let parsed = iso::Moov::parse(&b).unwrap();
+        assert_eq!(parsed, moov);
+        let mut out = vec![];
+        parsed.write(&mut out).unwrap();
+        assert_eq!(out, buf);
+
+        let trexs: Vec<_> = iso::find(b.payload(), &[iso::BoxType::MVEX])
+            .unwrap()
+            .unwrap()
+            .children()
+            .map(|b| b.unwrap().full_payload().unwrap().2[..4].to_vec())
+            .collect();
+        assert_eq!(trexs, [1u32.to_be_bytes(), 7u32.to_be_bytes()]);
+        assert_eq!(iso::Track::all(&buf).unwrap(), moov.tracks);
+
+        let mut track = moov.tracks[0].clone();
+        track.tkhd.track_id = u32::MAX;
+        assert_eq!(
+            iso::Moov::with_fragmented_tracks(600, vec![track]),
+            Err(iso::Error::OutOfRange("next_track_id"))
+        );
+    }
+}
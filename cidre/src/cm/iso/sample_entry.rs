@@ -0,0 +1,632 @@
+use crate::cm::{
+    iso::{self, BoxRef, BoxType, Error, Reader, Result},
+    nal::{h264, hevc},
+};
+
+/// Visual sample entry (`avc1`, `hvc1`, ...).
+///
+/// Reserved fields are written as zeros.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisualSampleEntry {
+    pub typ: BoxType,
+    pub data_reference_index: u16,
+    pub width: u16,
+    pub height: u16,
+    /// 16.16 fixed point, 72 dpi by default.
+    pub horiz_resolution: u32,
+    /// 16.16 fixed point, 72 dpi by default.
+    pub vert_resolution: u32,
+    pub frame_count: u16,
+    /// Up to 31 bytes.
+    pub compressor_name: String,
+    pub depth: u16,
+    /// Child boxes such as `avcC`, `hvcC`, `pasp`, `colr` and `btrt`.
+    pub children: Vec<u8>,
+}
+
+impl VisualSampleEntry {
+    pub fn new(typ: BoxType, width: u16, height: u16) -> Self {
+        Self {
+            typ,
+            data_reference_index: 1,
+            width,
+            height,
+            horiz_resolution: 0x0048_0000,
+            vert_resolution: 0x0048_0000,
+            frame_count: 1,
+            compressor_name: String::new(),
+            depth: 0x18,
+            children: Vec::new(),
+        }
+    }
+
+    /// `avc1` entry with `avcC` box.
+    pub fn with_avc_config(width: u16, height: u16, cfg: &h264::AvcConfig) -> Result<Self> {
+        let mut res = Self::new(BoxType::AVC1, width, height);
+        res.set_child(BoxType::AVC_C, &cfg.to_bytes()?)?;
+        Ok(res)
+    }
+
+    /// `hvc1` entry with `hvcC` box.
+    pub fn with_hvc_config(width: u16, height: u16, cfg: &hevc::HvcConfig) -> Result<Self> {
+        let mut res = Self::new(BoxType::HVC1, width, height);
+        res.set_child(BoxType::HVC_C, &cfg.to_bytes()?)?;
+        Ok(res)
+    }
+
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        let mut r = Reader::new(b.payload());
+        r.bytes(6)?;
+        let data_reference_index = r.u16()?;
+        r.bytes(16)?;
+        let width = r.u16()?;
+        let height = r.u16()?;
+        let horiz_resolution = r.u32()?;
+        let vert_resolution = r.u32()?;
+        r.u32()?;
+        let frame_count = r.u16()?;
+        let name: [u8; 32] = r.array()?;
+        let len = (name[0] as usize).min(31);
+        let compressor_name = String::from_utf8_lossy(&name[1..1 + len]).into_owned();
+        let depth = r.u16()?;
+        r.u16()?;
+        Ok(Self {
+            typ: b.typ(),
+            data_reference_index,
+            width,
+            height,
+            horiz_resolution,
+            vert_resolution,
+            frame_count,
+            compressor_name,
+            depth,
+            children: r.data.to_vec(),
+        })
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        let name = self.compressor_name.as_bytes();
+        if name.len() > 31 {
+            return Err(Error::OutOfRange("compressorname"));
+        }
+        iso::write_box(buf, self.typ, |buf| {
+            buf.extend_from_slice(&[0; 6]);
+            buf.extend_from_slice(&self.data_reference_index.to_be_bytes());
+            buf.extend_from_slice(&[0; 16]);
+            buf.extend_from_slice(&self.width.to_be_bytes());
+            buf.extend_from_slice(&self.height.to_be_bytes());
+            buf.extend_from_slice(&self.horiz_resolution.to_be_bytes());
+            buf.extend_from_slice(&self.vert_resolution.to_be_bytes());
+            buf.extend_from_slice(&[0; 4]);
+            buf.extend_from_slice(&self.frame_count.to_be_bytes());
+            buf.push(name.len() as u8);
+            buf.extend_from_slice(name);
+            buf.extend_from_slice(&[0; 32][name.len() + 1..]);
+            buf.extend_from_slice(&self.depth.to_be_bytes());
+            buf.extend_from_slice(&(-1i16).to_be_bytes());
+            buf.extend_from_slice(&self.children);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    pub fn children(&self) -> iso::Boxes<'_> {
+        iso::boxes(&self.children)
+    }
+
+    /// Payload of the first child box of `typ`.
+    pub fn child(&self, typ: BoxType) -> Result<Option<&[u8]>> {
+        Ok(self.children().find_type(typ)?.map(|b| b.payload()))
+    }
+
+    /// Replaces payload of the child box of `typ` or appends a new one.
+    pub fn set_child(&mut self, typ: BoxType, payload: &[u8]) -> Result {
+        set_child(&mut self.children, typ, payload)
+    }
+
+    /// Parsed `avcC` box.
+    pub fn avc_config(&self) -> Result<Option<h264::AvcConfig>> {
+        match self.child(BoxType::AVC_C)? {
+            Some(data) => Ok(Some(h264::AvcConfig::parse(data)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Parsed `hvcC` box.
+    pub fn hvc_config(&self) -> Result<Option<hevc::HvcConfig>> {
+        match self.child(BoxType::HVC_C)? {
+            Some(data) => Ok(Some(hevc::HvcConfig::parse(data)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Audio sample entry (`mp4a`, ...).
+///
+/// QuickTime sound description versions 1 and 2 are supported, their extra
+/// fields are kept as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioSampleEntry {
+    pub typ: BoxType,
+    pub data_reference_index: u16,
+    /// QuickTime sound description version, 0 for ISO-BMFF.
+    pub version: u16,
+    pub channel_count: u16,
+    pub sample_size: u16,
+    /// 16.16 fixed point.
+    pub sample_rate: u32,
+    /// 16 bytes for version 1 and 36 bytes for version 2.
+    pub qt_ext: Vec<u8>,
+    /// Child boxes such as `esds` and `btrt`.
+    pub children: Vec<u8>,
+}
+
+impl AudioSampleEntry {
+    pub fn new(typ: BoxType, sample_rate: u16, channel_count: u16) -> Self {
+        Self {
+            typ,
+            data_reference_index: 1,
+            version: 0,
+            channel_count,
+            sample_size: 16,
+            sample_rate: (sample_rate as u32) << 16,
+            qt_ext: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// `mp4a` entry with `esds` box.
+    ///
+    /// ```
+    /// use cidre::cm::iso;
+    ///
+    /// let entry = iso::AudioSampleEntry::with_aac(48_000, 2, &[0x11, 0x90]).unwrap();
+    /// let bytes = iso::SampleEntry::Audio(entry.clone()).to_bytes().unwrap();
+    /// assert_eq!(&bytes[4..8], b"mp4a");
+    ///
+    /// let iso::SampleEntry::Audio(parsed) = iso::SampleEntry::with_bytes(&bytes).unwrap() else {
+    ///     panic!("audio entry expected");
+    /// };
+    /// assert_eq!(parsed, entry);
+    /// assert_eq!(parsed.sample_rate_hz(), 48_000.0);
+    /// assert_eq!(parsed.esds().unwrap().unwrap().decoder_specific_info, [0x11, 0x90]);
+    /// ```
+    pub fn with_aac(sample_rate: u16, channel_count: u16, asc: &[u8]) -> Result<Self> {
+        let mut res = Self::new(BoxType::MP4A, sample_rate, channel_count);
+        let mut esds = vec![];
+        Esds::with_aac(asc).write(&mut esds)?;
+        res.children = esds;
+        Ok(res)
+    }
+
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        let mut r = Reader::new(b.payload());
+        r.bytes(6)?;
+        let data_reference_index = r.u16()?;
+        let version = r.u16()?;
+        r.bytes(6)?;
+        let channel_count = r.u16()?;
+        let sample_size = r.u16()?;
+        r.u32()?;
+        let sample_rate = r.u32()?;
+        let qt_ext = match version {
+            0 => &[][..],
+            1 => r.bytes(16)?,
+            2 => r.bytes(36)?,
+            version => {
+                return Err(Error::UnsupportedVersion {
+                    typ: b.typ(),
+                    version: version as u8,
+                });
+            }
+        };
+        Ok(Self {
+            typ: b.typ(),
+            data_reference_index,
+            version,
+            channel_count,
+            sample_size,
+            sample_rate,
+            qt_ext: qt_ext.to_vec(),
+            children: r.data.to_vec(),
+        })
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        let ext_len = match self.version {
+            0 => 0,
+            1 => 16,
+            2 => 36,
+            _ => return Err(Error::OutOfRange("version")),
+        };
+        if self.qt_ext.len() != ext_len {
+            return Err(Error::OutOfRange("qt_ext"));
+        }
+        iso::write_box(buf, self.typ, |buf| {
+            buf.extend_from_slice(&[0; 6]);
+            buf.extend_from_slice(&self.data_reference_index.to_be_bytes());
+            buf.extend_from_slice(&self.version.to_be_bytes());
+            buf.extend_from_slice(&[0; 6]);
+            buf.extend_from_slice(&self.channel_count.to_be_bytes());
+            buf.extend_from_slice(&self.sample_size.to_be_bytes());
+            // compression id is -2 for QuickTime versions 1 and 2
+            let compression_id: i16 = if self.version == 0 { 0 } else { -2 };
+            buf.extend_from_slice(&compression_id.to_be_bytes());
+            buf.extend_from_slice(&[0; 2]);
+            buf.extend_from_slice(&self.sample_rate.to_be_bytes());
+            buf.extend_from_slice(&self.qt_ext);
+            buf.extend_from_slice(&self.children);
+            Ok(())
+        })
+    }
+
+    /// Sample rate, 64-bit float from version 2 extension if present.
+    pub fn sample_rate_hz(&self) -> f64 {
+        if self.version == 2 && self.qt_ext.len() >= 12 {
+            let bytes = self.qt_ext[4..12].try_into().unwrap();
+            f64::from_bits(u64::from_be_bytes(bytes))
+        } else {
+            self.sample_rate as f64 / 65536.0
+        }
+    }
+
+    #[inline]
+    pub fn children(&self) -> iso::Boxes<'_> {
+        iso::boxes(&self.children)
+    }
+
+    /// Payload of the first child box of `typ`.
+    pub fn child(&self, typ: BoxType) -> Result<Option<&[u8]>> {
+        Ok(self.children().find_type(typ)?.map(|b| b.payload()))
+    }
+
+    /// Replaces payload of the child box of `typ` or appends a new one.
+    pub fn set_child(&mut self, typ: BoxType, payload: &[u8]) -> Result {
+        set_child(&mut self.children, typ, payload)
+    }
+
+    /// Parsed `esds` box.
+    pub fn esds(&self) -> Result<Option<Esds>> {
+        match self.children().find_type(BoxType::ESDS)? {
+            Some(b) => Ok(Some(Esds::parse(&b)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// `esds` box with MPEG-4 elementary stream descriptor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Esds {
+    pub es_id: u16,
+    /// 0x40 for MPEG-4 audio (AAC).
+    pub object_type_indication: u8,
+    /// 5 for audio stream.
+    pub stream_type: u8,
+    pub buffer_size: u32,
+    pub max_bitrate: u32,
+    pub avg_bitrate: u32,
+    /// `AudioSpecificConfig` for AAC (the magic cookie).
+    pub decoder_specific_info: Vec<u8>,
+}
+
+impl Esds {
+    const ES_DESCR_TAG: u8 = 0x03;
+    const DECODER_CONFIG_DESCR_TAG: u8 = 0x04;
+    const DECODER_SPECIFIC_INFO_TAG: u8 = 0x05;
+    const SL_CONFIG_DESCR_TAG: u8 = 0x06;
+
+    pub fn with_aac(asc: &[u8]) -> Self {
+        Self {
+            es_id: 0,
+            object_type_indication: 0x40,
+            stream_type: 5,
+            buffer_size: 0,
+            max_bitrate: 0,
+            avg_bitrate: 0,
+            decoder_specific_info: asc.to_vec(),
+        }
+    }
+
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        let (_, _, data) = b.expect_full(BoxType::ESDS, 0)?;
+        let mut r = Reader::new(data);
+        let mut es = Reader::new(Self::descr(&mut r, Self::ES_DESCR_TAG)?);
+        let es_id = es.u16()?;
+        let flags = es.u8()?;
+        if flags & 0x80 != 0 {
+            es.u16()?;
+        }
+        if flags & 0x40 != 0 {
+            let len = es.u8()?;
+            es.bytes(len as usize)?;
+        }
+        if flags & 0x20 != 0 {
+            es.u16()?;
+        }
+        let mut dc = Reader::new(Self::descr(&mut es, Self::DECODER_CONFIG_DESCR_TAG)?);
+        let object_type_indication = dc.u8()?;
+        let stream_type = dc.u8()? >> 2;
+        let [a, b, c] = dc.array()?;
+        let buffer_size = u32::from_be_bytes([0, a, b, c]);
+        let max_bitrate = dc.u32()?;
+        let avg_bitrate = dc.u32()?;
+        let decoder_specific_info = if dc.data.is_empty() {
+            Vec::new()
+        } else {
+            Self::descr(&mut dc, Self::DECODER_SPECIFIC_INFO_TAG)?.to_vec()
+        };
+        Ok(Self {
+            es_id,
+            object_type_indication,
+            stream_type,
+            buffer_size,
+            max_bitrate,
+            avg_bitrate,
+            decoder_specific_info,
+        })
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        if self.buffer_size > 0x00ff_ffff {
+            return Err(Error::OutOfRange("bufferSizeDB"));
+        }
+        let mut dc = vec![self.object_type_indication, self.stream_type << 2 | 1];
+        dc.extend_from_slice(&self.buffer_size.to_be_bytes()[1..]);
+        dc.extend_from_slice(&self.max_bitrate.to_be_bytes());
+        dc.extend_from_slice(&self.avg_bitrate.to_be_bytes());
+        if !self.decoder_specific_info.is_empty() {
+            Self::write_descr(
+                &mut dc,
+                Self::DECODER_SPECIFIC_INFO_TAG,
+                &self.decoder_specific_info,
+            )?;
+        }
+        let mut es = self.es_id.to_be_bytes().to_vec();
+        es.push(0);
+        Self::write_descr(&mut es, Self::DECODER_CONFIG_DESCR_TAG, &dc)?;
+        Self::write_descr(&mut es, Self::SL_CONFIG_DESCR_TAG, &[2])?;
+        iso::write_full_box(buf, BoxType::ESDS, 0, 0, |buf| {
+            Self::write_descr(buf, Self::ES_DESCR_TAG, &es)
+        })
+    }
+
+    fn descr<'a>(r: &mut Reader<'a>, tag: u8) -> Result<&'a [u8]> {
+        if r.u8()? != tag {
+            return Err(Error::OutOfRange("descriptor tag"));
+        }
+        let mut len = 0usize;
+        for _ in 0..4 {
+            let b = r.u8()?;
+            len = len << 7 | (b & 0x7f) as usize;
+            if b & 0x80 == 0 {
+                break;
+            }
+        }
+        r.bytes(len)
+    }
+
+    fn write_descr(buf: &mut Vec<u8>, tag: u8, data: &[u8]) -> Result {
+        let len = data.len();
+        if len >= 1 << 28 {
+            return Err(Error::OutOfRange("descriptor size"));
+        }
+        buf.push(tag);
+        let mut shift = 21;
+        while shift > 0 && len >> shift == 0 {
+            shift -= 7;
+        }
+        while shift > 0 {
+            buf.push((len >> shift) as u8 & 0x7f | 0x80);
+            shift -= 7;
+        }
+        buf.push(len as u8 & 0x7f);
+        buf.extend_from_slice(data);
+        Ok(())
+    }
+}
+
+/// Sample entry of `stsd` box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SampleEntry {
+    Visual(VisualSampleEntry),
+    Audio(AudioSampleEntry),
+    Other { typ: BoxType, payload: Vec<u8> },
+}
+
+impl SampleEntry {
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        match &b.typ().to_be_bytes() {
+            b"avc1" | b"avc3" | b"hvc1" | b"hev1" | b"dvh1" | b"dvhe" | b"mp4v" | b"av01"
+            | b"vp09" | b"encv" => Ok(Self::Visual(VisualSampleEntry::parse(b)?)),
+            b"mp4a" | b"ac-3" | b"ec-3" | b"Opus" | b"fLaC" | b"alac" | b"enca" => {
+                Ok(Self::Audio(AudioSampleEntry::parse(b)?))
+            }
+            _ => Ok(Self::Other {
+                typ: b.typ(),
+                payload: b.payload().to_vec(),
+            }),
+        }
+    }
+
+    /// Parses whole sample entry box, e.g. bytes of
+    /// `cm::FormatDesc::verbatim_iso_sample_entry`.
+    pub fn with_bytes(data: &[u8]) -> Result<Self> {
+        Self::parse(&BoxRef::parse(data)?)
+    }
+
+    pub fn typ(&self) -> BoxType {
+        match self {
+            Self::Visual(e) => e.typ,
+            Self::Audio(e) => e.typ,
+            Self::Other { typ, .. } => *typ,
+        }
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        match self {
+            Self::Visual(e) => e.write(buf),
+            Self::Audio(e) => e.write(buf),
+            Self::Other { typ, payload } => iso::write_box(buf, *typ, |buf| {
+                buf.extend_from_slice(payload);
+                Ok(())
+            }),
+        }
+    }
+
+    /// Whole sample entry box, suitable for `FormatDescExtKey::verbatim_iso_sample_entry`.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut buf = vec![];
+        self.write(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+/// `stsd` box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stsd {
+    pub entries: Vec<SampleEntry>,
+}
+
+impl Stsd {
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        let (_, _, data) = b.expect_full(BoxType::STSD, 1)?;
+        let mut r = Reader::new(data);
+        let count = r.u32()?;
+        let mut entries = Vec::with_capacity(count.min(16) as usize);
+        let mut iter = iso::boxes(r.data);
+        for _ in 0..count {
+            let b = iter.next().ok_or(Error::UnexpectedEnd)??;
+            entries.push(SampleEntry::parse(&b)?);
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        iso::write_full_box(buf, BoxType::STSD, 0, 0, |buf| {
+            buf.extend_from_slice(&(self.entries.len() as u32).to_be_bytes());
+            for entry in &self.entries {
+                entry.write(buf)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+fn set_child(children: &mut Vec<u8>, typ: BoxType, payload: &[u8]) -> Result {
+    let mut new = vec![];
+    iso::write_box(&mut new, typ, |buf| {
+        buf.extend_from_slice(payload);
+        Ok(())
+    })?;
+    let mut offset = 0;
+    for b in iso::boxes(children) {
+        let b = b?;
+        if b.typ() == typ {
+            children.splice(offset..offset + b.len(), new);
+            return Ok(());
+        }
+        offset += b.len();
+    }
+    children.extend_from_slice(&new);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::{iso, nal::h264};
+
+    #[test]
+    fn visual() {
+        let sps = [0x67, 0x42, 0xc0, 0x1e, 0xda, 0x05, 0x07, 0xe4];
+        let pps = [0x68, 0xce, 0x3c, 0x80];
+        let cfg = h264::AvcConfig::with_param_sets(&[&sps], &[&pps], 4).unwrap();
+        let mut entry = iso::VisualSampleEntry::with_avc_config(1280, 720, &cfg).unwrap();
+        entry.compressor_name = "H.264".to_string();
+        entry
+            .set_child(iso::BoxType::PASP, &[0, 0, 0, 1, 0, 0, 0, 1])
+            .unwrap();
+
+        let stsd = iso::Stsd {
+            entries: vec![iso::SampleEntry::Visual(entry.clone())],
+        };
+        let mut buf = vec![];
+        stsd.write(&mut buf).unwrap();
+        let b = iso::BoxRef::parse(&buf).unwrap();
+        assert_eq!(b.len(), buf.len());
+        // full box header and entry count
+        assert_eq!(&b.payload()[..8], &[0, 0, 0, 0, 0, 0, 0, 1]);
+        let entry_bytes = &b.payload()[8..];
+        // 8 header + 78 fields + avcC + pasp
+        assert_eq!(
+            entry_bytes.len(),
+            8 + 78 + 8 + cfg.to_bytes().unwrap().len() + 16
+        );
+        assert_eq!(&entry_bytes[8 + 24..8 + 28], &[0x05, 0x00, 0x02, 0xd0]);
+        assert_eq!(&entry_bytes[8 + 42..8 + 48], b"\x05H.264");
+
+        let parsed = iso::Stsd::parse(&b).unwrap();
+        assert_eq!(parsed, stsd);
+        let iso::SampleEntry::Visual(parsed) = &parsed.entries[0] else {
+            panic!("visual entry expected");
+        };
+        assert_eq!(parsed.avc_config().unwrap(), Some(cfg));
+        assert_eq!(parsed.hvc_config().unwrap(), None);
+        assert_eq!(
+            parsed.child(iso::BoxType::PASP).unwrap(),
+            Some(&[0, 0, 0, 1, 0, 0, 0, 1][..])
+        );
+
+        // replacing keeps the order
+        entry.set_child(iso::BoxType::AVC_C, &[1]).unwrap();
+        let types: Vec<_> = entry.children().map(|b| b.unwrap().typ()).collect();
+        assert_eq!(types, [iso::BoxType::AVC_C, iso::BoxType::PASP]);
+        assert_eq!(entry.child(iso::BoxType::AVC_C).unwrap(), Some(&[1][..]));
+        assert!(entry.avc_config().is_err());
+    }
+
+    #[test]
+    fn audio() {
+        // QuickTime sound description version 1
+        let mut entry = iso::AudioSampleEntry::new(iso::BoxType::MP4A, 44_100, 1);
+        entry.version = 1;
+        entry.qt_ext = vec![0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2];
+        let bytes = iso::SampleEntry::Audio(entry.clone()).to_bytes().unwrap();
+        assert_eq!(bytes.len(), 8 + 28 + 16);
+        assert_eq!(
+            iso::SampleEntry::with_bytes(&bytes),
+            Ok(iso::SampleEntry::Audio(entry.clone()))
+        );
+
+        entry.qt_ext.clear();
+        assert_eq!(
+            iso::SampleEntry::Audio(entry).to_bytes(),
+            Err(iso::Error::OutOfRange("qt_ext"))
+        );
+
+        // esds with 4-byte descriptor sizes as written by Apple
+        let esds = [
+            0, 0, 0, 0x33, b'e', b's', b'd', b's', 0, 0, 0, 0, 3, 0x80, 0x80, 0x80, 0x22, 0, 0, 0,
+            4, 0x80, 0x80, 0x80, 0x14, 0x40, 0x15, 0, 0x18, 0, 0, 1, 0xf4, 0, 0, 1, 0xf4, 0, 5,
+            0x80, 0x80, 0x80, 2, 0x12, 0x10, 6, 0x80, 0x80, 0x80, 1, 2,
+        ];
+        let parsed = iso::Esds::parse(&iso::BoxRef::parse(&esds).unwrap()).unwrap();
+        assert_eq!(parsed.object_type_indication, 0x40);
+        assert_eq!(parsed.stream_type, 5);
+        assert_eq!(parsed.buffer_size, 0x1800);
+        assert_eq!(parsed.avg_bitrate, 128_000);
+        assert_eq!(parsed.decoder_specific_info, [0x12, 0x10]);
+
+        let mut buf = vec![];
+        parsed.write(&mut buf).unwrap();
+        assert_eq!(buf.len(), esds.len() - 12);
+        assert_eq!(
+            iso::Esds::parse(&iso::BoxRef::parse(&buf).unwrap()),
+            Ok(parsed)
+        );
+
+        let other = [0, 0, 0, 10, b't', b'x', b'3', b'g', 1, 2];
+        let entry = iso::SampleEntry::with_bytes(&other).unwrap();
+        assert_eq!(entry.typ(), iso::BoxType::new(b"tx3g"));
+        assert_eq!(entry.to_bytes().unwrap(), other);
+    }
+}
@@ -0,0 +1,547 @@
+use crate::cm::iso::{self, BoxRef, BoxType, Error, Reader, Result, Stsd};
+
+/// Identity transformation matrix of `mvhd` and `tkhd` in 16.16 and 2.30 fixed point.
+pub const UNITY_MATRIX: [i32; 9] = [0x1_0000, 0, 0, 0, 0x1_0000, 0, 0, 0, 0x4000_0000];
+
+/// `tkhd` box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tkhd {
+    /// [`Tkhd::ENABLED`], [`Tkhd::IN_MOVIE`], ...
+    pub flags: u32,
+    pub creation_time: u64,
+    pub modification_time: u64,
+    pub track_id: u32,
+    /// In `mvhd` timescale.
+    pub duration: u64,
+    pub layer: i16,
+    pub alternate_group: i16,
+    /// 8.8 fixed point, `0x0100` for audio tracks.
+    pub volume: i16,
+    pub matrix: [i32; 9],
+    /// 16.16 fixed point.
+    pub width: u32,
+    /// 16.16 fixed point.
+    pub height: u32,
+}
+
+impl Tkhd {
+    pub const ENABLED: u32 = 0x01;
+    pub const IN_MOVIE: u32 = 0x02;
+    pub const IN_PREVIEW: u32 = 0x04;
+
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        let (version, flags, data) = b.expect_full(BoxType::TKHD, 1)?;
+        let mut r = Reader::new(data);
+        let creation_time = r.u32_or_u64(version)?;
+        let modification_time = r.u32_or_u64(version)?;
+        let track_id = r.u32()?;
+        r.u32()?;
+        let duration = r.u32_or_u64(version)?;
+        r.u64()?;
+        let layer = r.u16()? as i16;
+        let alternate_group = r.u16()? as i16;
+        let volume = r.u16()? as i16;
+        r.u16()?;
+        let mut matrix = [0; 9];
+        for v in &mut matrix {
+            *v = r.u32()? as i32;
+        }
+        Ok(Self {
+            flags,
+            creation_time,
+            modification_time,
+            track_id,
+            duration,
+            layer,
+            alternate_group,
+            volume,
+            matrix,
+            width: r.u32()?,
+            height: r.u32()?,
+        })
+    }
+
+    /// Written with version 1 only if times or duration don't fit into 32 bits.
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        let times = [self.creation_time, self.modification_time, self.duration];
+        let version = iso::time_version(&times);
+        iso::write_full_box(buf, BoxType::TKHD, version, self.flags, |buf| {
+            iso::put_u32_or_u64(buf, version, self.creation_time);
+            iso::put_u32_or_u64(buf, version, self.modification_time);
+            buf.extend_from_slice(&self.track_id.to_be_bytes());
+            buf.extend_from_slice(&[0; 4]);
+            iso::put_u32_or_u64(buf, version, self.duration);
+            buf.extend_from_slice(&[0; 8]);
+            buf.extend_from_slice(&self.layer.to_be_bytes());
+            buf.extend_from_slice(&self.alternate_group.to_be_bytes());
+            buf.extend_from_slice(&self.volume.to_be_bytes());
+            buf.extend_from_slice(&[0; 2]);
+            for v in self.matrix {
+                buf.extend_from_slice(&v.to_be_bytes());
+            }
+            buf.extend_from_slice(&self.width.to_be_bytes());
+            buf.extend_from_slice(&self.height.to_be_bytes());
+            Ok(())
+        })
+    }
+}
+
+/// `mdhd` box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mdhd {
+    pub creation_time: u64,
+    pub modification_time: u64,
+    pub timescale: u32,
+    pub duration: u64,
+    /// ISO-639-2/T language code, e.g. `und`.
+    pub language: [u8; 3],
+}
+
+impl Mdhd {
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        let (version, _, data) = b.expect_full(BoxType::MDHD, 1)?;
+        let mut r = Reader::new(data);
+        let creation_time = r.u32_or_u64(version)?;
+        let modification_time = r.u32_or_u64(version)?;
+        let timescale = r.u32()?;
+        let duration = r.u32_or_u64(version)?;
+        let lang = r.u16()?;
+        Ok(Self {
+            creation_time,
+            modification_time,
+            timescale,
+            duration,
+            language: [10, 5, 0].map(|shift| ((lang >> shift) & 0x1f) as u8 + 0x60),
+        })
+    }
+
+    /// Written with version 1 only if times or duration don't fit into 32 bits.
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        let mut lang = 0u16;
+        for c in self.language {
+            if !(0x60..0x80).contains(&c) {
+                return Err(Error::OutOfRange("mdhd language"));
+            }
+            lang = lang << 5 | (c - 0x60) as u16;
+        }
+        let times = [self.creation_time, self.modification_time, self.duration];
+        let version = iso::time_version(&times);
+        iso::write_full_box(buf, BoxType::MDHD, version, 0, |buf| {
+            iso::put_u32_or_u64(buf, version, self.creation_time);
+            iso::put_u32_or_u64(buf, version, self.modification_time);
+            buf.extend_from_slice(&self.timescale.to_be_bytes());
+            iso::put_u32_or_u64(buf, version, self.duration);
+            buf.extend_from_slice(&lang.to_be_bytes());
+            buf.extend_from_slice(&[0; 2]);
+            Ok(())
+        })
+    }
+}
+
+/// `hdlr` box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hdlr {
+    /// `vide`, `soun`, ...
+    pub handler_type: BoxType,
+    /// Written null terminated.
+    pub name: String,
+}
+
+impl Hdlr {
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        let (_, _, data) = b.expect_full(BoxType::HDLR, 0)?;
+        let mut r = Reader::new(data);
+        r.u32()?;
+        let handler_type = BoxType(r.u32()?);
+        r.bytes(12)?;
+        let name = r.data.split(|c| *c == 0).next().unwrap_or_default();
+        Ok(Self {
+            handler_type,
+            name: String::from_utf8_lossy(name).into_owned(),
+        })
+    }
+
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        if self.name.contains('\0') {
+            return Err(Error::OutOfRange("hdlr name"));
+        }
+        iso::write_full_box(buf, BoxType::HDLR, 0, 0, |buf| {
+            buf.extend_from_slice(&[0; 4]);
+            buf.extend_from_slice(&self.handler_type.to_be_bytes());
+            buf.extend_from_slice(&[0; 12]);
+            buf.extend_from_slice(self.name.as_bytes());
+            buf.push(0);
+            Ok(())
+        })
+    }
+}
+
+/// `trak` box with `tkhd`, `mdhd`, `hdlr` and `stsd`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Track {
+    pub tkhd: Tkhd,
+    pub mdhd: Mdhd,
+    pub hdlr: Hdlr,
+    pub stsd: Stsd,
+    /// Other child boxes of `trak` (`edts`, `tref`, ...) as is.
+    pub other: Vec<u8>,
+}
+
+impl Track {
+    pub const VIDEO_HANDLER: BoxType = BoxType::new(b"vide");
+    pub const SOUND_HANDLER: BoxType = BoxType::new(b"soun");
+
+    /// Enabled track of initialization segment.
+    ///
+    /// Track size is taken from the first visual sample entry.
+    pub fn new(track_id: u32, handler_type: BoxType, timescale: u32, stsd: Stsd) -> Self {
+        let (width, height) = stsd
+            .entries
+            .iter()
+            .find_map(|e| match e {
+                iso::SampleEntry::Visual(e) => Some((e.width, e.height)),
+                _ => None,
+            })
+            .unwrap_or_default();
+        Self {
+            tkhd: Tkhd {
+                flags: Tkhd::ENABLED | Tkhd::IN_MOVIE,
+                creation_time: 0,
+                modification_time: 0,
+                track_id,
+                duration: 0,
+                layer: 0,
+                alternate_group: 0,
+                volume: if handler_type == Self::SOUND_HANDLER {
+                    0x0100
+                } else {
+                    0
+                },
+                matrix: UNITY_MATRIX,
+                width: (width as u32) << 16,
+                height: (height as u32) << 16,
+            },
+            mdhd: Mdhd {
+                creation_time: 0,
+                modification_time: 0,
+                timescale,
+                duration: 0,
+                language: *b"und",
+            },
+            hdlr: Hdlr {
+                handler_type,
+                name: String::new(),
+            },
+            stsd,
+            other: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn track_id(&self) -> u32 {
+        self.tkhd.track_id
+    }
+
+    #[inline]
+    pub fn timescale(&self) -> u32 {
+        self.mdhd.timescale
+    }
+
+    #[inline]
+    pub fn handler_type(&self) -> BoxType {
+        self.hdlr.handler_type
+    }
+
+    /// Sample tables other than `stsd` are skipped.
+    pub fn parse(b: &BoxRef) -> Result<Self> {
+        b.expect(BoxType::TRAK)?;
+        let mut tkhd = None;
+        let mut mdia = None;
+        let mut other = Vec::new();
+        for child in b.children() {
+            let child = child?;
+            match child.typ() {
+                BoxType::TKHD => tkhd = Some(Tkhd::parse(&child)?),
+                BoxType::MDIA => mdia = Some(child),
+                _ => child.write(&mut other),
+            }
+        }
+        let tkhd = tkhd.ok_or(Error::MissingBox(BoxType::TKHD))?;
+        let mdia = mdia.ok_or(Error::MissingBox(BoxType::MDIA))?;
+        let mdhd = mdia
+            .child(BoxType::MDHD)?
+            .ok_or(Error::MissingBox(BoxType::MDHD))?;
+        let hdlr = mdia
+            .child(BoxType::HDLR)?
+            .ok_or(Error::MissingBox(BoxType::HDLR))?;
+        let stsd = iso::find(
+            mdia.payload(),
+            &[BoxType::MINF, BoxType::STBL, BoxType::STSD],
+        )?
+        .ok_or(Error::MissingBox(BoxType::STSD))?;
+
+        Ok(Self {
+            tkhd,
+            mdhd: Mdhd::parse(&mdhd)?,
+            hdlr: Hdlr::parse(&hdlr)?,
+            stsd: Stsd::parse(&stsd)?,
+            other,
+        })
+    }
+
+    /// Writes `minf` with media header of handler type, self-contained `dref`
+    /// and empty sample tables as in fragmented initialization segment.
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result {
+        iso::write_box(buf, BoxType::TRAK, |buf| {
+            self.tkhd.write(buf)?;
+            iso::write_box(buf, BoxType::MDIA, |buf| {
+                self.mdhd.write(buf)?;
+                self.hdlr.write(buf)?;
+                iso::write_box(buf, BoxType::MINF, |buf| {
+                    self.write_media_header(buf)?;
+                    iso::write_box(buf, BoxType::DINF, |buf| {
+                        iso::write_full_box(buf, BoxType::DREF, 0, 0, |buf| {
+                            buf.extend_from_slice(&1u32.to_be_bytes());
+                            // media data is in the same file
+                            iso::write_full_box(buf, BoxType::URL, 0, 1, |_| Ok(()))
+                        })
+                    })?;
+                    iso::write_box(buf, BoxType::STBL, |buf| {
+                        self.stsd.write(buf)?;
+                        // entry count
+                        for typ in [BoxType::STTS, BoxType::STSC] {
+                            iso::write_full_box(buf, typ, 0, 0, |buf| {
+                                buf.extend_from_slice(&[0; 4]);
+                                Ok(())
+                            })?;
+                        }
+                        // sample size and sample count
+                        iso::write_full_box(buf, BoxType::STSZ, 0, 0, |buf| {
+                            buf.extend_from_slice(&[0; 8]);
+                            Ok(())
+                        })?;
+                        iso::write_full_box(buf, BoxType::STCO, 0, 0, |buf| {
+                            buf.extend_from_slice(&[0; 4]);
+                            Ok(())
+                        })
+                    })
+                })
+            })?;
+            buf.extend_from_slice(&self.other);
+            Ok(())
+        })
+    }
+
+    fn write_media_header(&self, buf: &mut Vec<u8>) -> Result {
+        match self.hdlr.handler_type {
+            // graphics mode and opcolor
+            Self::VIDEO_HANDLER => iso::write_full_box(buf, BoxType::VMHD, 0, 1, |buf| {
+                buf.extend_from_slice(&[0; 8]);
+                Ok(())
+            }),
+            // balance and reserved
+            Self::SOUND_HANDLER => iso::write_full_box(buf, BoxType::SMHD, 0, 0, |buf| {
+                buf.extend_from_slice(&[0; 4]);
+                Ok(())
+            }),
+            _ => iso::write_full_box(buf, BoxType::NMHD, 0, 0, |_| Ok(())),
+        }
+    }
+
+    /// Tracks of `moov` box in initialization segment or file.
+    pub fn all(data: &[u8]) -> Result<Vec<Self>> {
+        let Some(moov) = iso::boxes(data).find_type(BoxType::MOOV)? else {
+            return Ok(Vec::new());
+        };
+        let mut res = Vec::new();
+        for b in moov.children() {
+            let b = b?;
+            if b.typ() == BoxType::TRAK {
+                res.push(Self::parse(&b)?);
+            }
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::iso;
+
+    fn full(buf: &mut Vec<u8>, typ: &[u8; 4], payload: &[u8]) {
+        iso::write_full_box(buf, iso::BoxType::new(typ), 0, 0, |buf| {
+            buf.extend_from_slice(payload);
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    fn audio_stsd() -> iso::Stsd {
+        let entry = iso::AudioSampleEntry::with_aac(44_100, 2, &[0x12, 0x10]).unwrap();
+        iso::Stsd {
+            entries: vec![iso::SampleEntry::Audio(entry)],
+        }
+    }
+
+    #[test]
+    fn tracks() {
+        let stsd = audio_stsd();
+
+        let mut init = vec![];
+        iso::write_box(&mut init, iso::BoxType::MOOV, |buf| {
+            full(buf, b"mvhd", &[0; 96]);
+            iso::write_box(buf, iso::BoxType::TRAK, |buf| {
+                let mut tkhd = [0; 80];
+                tkhd[11] = 2;
+                full(buf, b"tkhd", &tkhd);
+                iso::write_box(buf, iso::BoxType::MDIA, |buf| {
+                    // timescale 44100, duration 0, language 'und'
+                    full(
+                        buf,
+                        b"mdhd",
+                        &[
+                            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xac, 0x44, 0, 0, 0, 0, 0x55, 0xc4, 0, 0,
+                        ],
+                    );
+                    full(buf, b"hdlr", b"\0\0\0\0soun\0\0\0\0\0\0\0\0\0\0\0\0\0");
+                    iso::write_box(buf, iso::BoxType::MINF, |buf| {
+                        iso::write_box(buf, iso::BoxType::STBL, |buf| stsd.write(buf))
+                    })
+                })
+            })
+        })
+        .unwrap();
+
+        let tracks = iso::Track::all(&init).unwrap();
+        assert_eq!(tracks.len(), 1);
+        let track = &tracks[0];
+        assert_eq!(track.track_id(), 2);
+        assert_eq!(track.timescale(), 44_100);
+        assert_eq!(&track.mdhd.language, b"und");
+        assert_eq!(track.handler_type(), iso::Track::SOUND_HANDLER);
+        assert_eq!(track.hdlr.name, "");
+        assert_eq!(track.stsd, stsd);
+
+        assert!(iso::Track::all(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn header_boxes_round_trip() {
+        let mut tkhd = [0; 80];
+        tkhd[11] = 1;
+        tkhd[19] = 90;
+        tkhd[28..30].copy_from_slice(&1i16.to_be_bytes());
+        for (i, v) in iso::UNITY_MATRIX.into_iter().enumerate() {
+            tkhd[36 + i * 4..40 + i * 4].copy_from_slice(&v.to_be_bytes());
+        }
+        tkhd[72..74].copy_from_slice(&1920u16.to_be_bytes());
+        tkhd[76..78].copy_from_slice(&1080u16.to_be_bytes());
+        let mut buf = vec![];
+        iso::write_full_box(&mut buf, iso::BoxType::TKHD, 0, 3, |buf| {
+            buf.extend_from_slice(&tkhd);
+            Ok(())
+        })
+        .unwrap();
+        let b = iso::BoxRef::parse(&buf).unwrap();
+        let parsed = iso::Tkhd::parse(&b).unwrap();
+        assert_eq!(parsed.flags, iso::Tkhd::ENABLED | iso::Tkhd::IN_MOVIE);
+        assert_eq!(parsed.track_id, 1);
+        assert_eq!(parsed.duration, 90);
+        assert_eq!(parsed.layer, 1);
+        assert_eq!(parsed.matrix, iso::UNITY_MATRIX);
+        assert_eq!(parsed.width, 1920 << 16);
+        assert_eq!(parsed.height, 1080 << 16);
+        let mut out = vec![];
+        parsed.write(&mut out).unwrap();
+        assert_eq!(out, buf);
+
+        let mut buf = vec![];
+        full(
+            &mut buf,
+            b"mdhd",
+            &[
+                0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0xac, 0x44, 0, 0, 0, 9, 0x15, 0xc7, 0, 0,
+            ],
+        );
+        let parsed = iso::Mdhd::parse(&iso::BoxRef::parse(&buf).unwrap()).unwrap();
+        assert_eq!(parsed.creation_time, 1);
+        assert_eq!(parsed.modification_time, 2);
+        assert_eq!(parsed.duration, 9);
+        assert_eq!(&parsed.language, b"eng");
+        let mut out = vec![];
+        parsed.write(&mut out).unwrap();
+        assert_eq!(out, buf);
+
+        let mut buf = vec![];
+        full(
+            &mut buf,
+            b"hdlr",
+            b"\0\0\0\0vide\0\0\0\0\0\0\0\0\0\0\0\0Video\0",
+        );
+        let parsed = iso::Hdlr::parse(&iso::BoxRef::parse(&buf).unwrap()).unwrap();
+        assert_eq!(parsed.handler_type, iso::Track::VIDEO_HANDLER);
+        assert_eq!(parsed.name, "Video");
+        let mut out = vec![];
+        parsed.write(&mut out).unwrap();
+        assert_eq!(out, buf);
+
+        let mut mdhd = iso::Mdhd {
+            creation_time: 0,
+            modification_time: 0,
+            timescale: 1,
+            duration: u32::MAX as u64 + 1,
+            language: *b"und",
+        };
+        let mut buf = vec![];
+        mdhd.write(&mut buf).unwrap();
+        let b = iso::BoxRef::parse(&buf).unwrap();
+        assert_eq!(b.full_payload().unwrap().0, 1);
+        assert_eq!(iso::Mdhd::parse(&b).unwrap(), mdhd);
+
+        mdhd.language = *b"EN ";
+        assert_eq!(
+            mdhd.write(&mut vec![]),
+            Err(iso::Error::OutOfRange("mdhd language"))
+        );
+    }
+
+    #[test]
+    fn track_round_trip() {
+        let visual = iso::VisualSampleEntry::new(iso::BoxType::AVC1, 1280, 720);
+        let stsd = iso::Stsd {
+            entries: vec![iso::SampleEntry::Visual(visual)],
+        };
+        let mut track = iso::Track::new(1, iso::Track::VIDEO_HANDLER, 90_000, stsd);
+        track.hdlr.name = "Core Media Video".to_string();
+        track.other = vec![0, 0, 0, 8, b'e', b'd', b't', b's'];
+        assert_eq!(track.tkhd.width, 1280 << 16);
+        assert_eq!(track.tkhd.volume, 0);
+
+        let sound = iso::Track::new(2, iso::Track::SOUND_HANDLER, 44_100, audio_stsd());
+        assert_eq!(sound.tkhd.volume, 0x0100);
+        assert_eq!(sound.tkhd.width, 0);
+
+        for track in [track, sound] {
+            let mut buf = vec![];
+            track.write(&mut buf).unwrap();
+            let b = iso::BoxRef::parse(&buf).unwrap();
+            let parsed = iso::Track::parse(&b).unwrap();
+            assert_eq!(parsed, track);
+
+            let mut out = vec![];
+            parsed.write(&mut out).unwrap();
+            assert_eq!(out, buf);
+
+            let minf = iso::find(b.payload(), &[iso::BoxType::MDIA, iso::BoxType::MINF])
+                .unwrap()
+                .unwrap();
+            let types: Vec<_> = minf.children().map(|b| b.unwrap().typ()).collect();
+            let header = if track.handler_type() == iso::Track::VIDEO_HANDLER {
+                iso::BoxType::VMHD
+            } else {
+                iso::BoxType::SMHD
+            };
+            assert_eq!(types, [header, iso::BoxType::DINF, iso::BoxType::STBL]);
+        }
+    }
+}
@@ -0,0 +1,599 @@
+//! Pure Rust helpers for H.264 and HEVC elementary streams.
+//!
+//! Splits Annex-B and length-prefixed streams into NAL units, converts between them
+//! and parses parameter sets and `avcC`/`hvcC` decoder configuration records.
+
+use std::borrow::Cow;
+
+pub mod h264;
+pub mod hevc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Data ended before the syntax element was complete.
+    UnexpectedEnd,
+
+    /// Exp-Golomb code doesn't fit into 32 bits.
+    InvalidExpGolomb,
+
+    /// NAL unit header length must be 1, 2, 3 or 4 bytes.
+    InvalidHeaderLength(usize),
+
+    /// NAL unit is too large for the NAL unit header length.
+    UnitTooLarge(usize),
+
+    /// NAL unit has unexpected type.
+    UnexpectedUnitType(u8),
+
+    /// Syntax element value is out of the range allowed by spec.
+    OutOfRange(&'static str),
+
+    /// Configuration record version is not supported.
+    UnsupportedVersion(u8),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("unexpected end of data"),
+            Self::InvalidExpGolomb => f.write_str("invalid exp-golomb code"),
+            Self::InvalidHeaderLength(len) => write!(f, "invalid nal unit header length {len}"),
+            Self::UnitTooLarge(len) => write!(f, "nal unit of {len} bytes is too large"),
+            Self::UnexpectedUnitType(t) => write!(f, "unexpected nal unit type {t}"),
+            Self::OutOfRange(name) => write!(f, "{name} is out of range"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported configuration version {v}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<Ok = ()> = std::result::Result<Ok, Error>;
+
+/// Video signal type from VUI (shared between H.264 and HEVC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VideoSignal {
+    pub video_format: u8,
+    pub full_range: bool,
+    /// ISO/IEC 23091-4 values. 2 means unspecified.
+    pub colour_primaries: u8,
+    pub transfer_characteristics: u8,
+    pub matrix_coefficients: u8,
+}
+
+impl Default for VideoSignal {
+    fn default() -> Self {
+        Self {
+            video_format: 5,
+            full_range: false,
+            colour_primaries: 2,
+            transfer_characteristics: 2,
+            matrix_coefficients: 2,
+        }
+    }
+}
+
+impl VideoSignal {
+    fn read(r: &mut BitReader) -> Result<Self> {
+        let mut res = Self {
+            video_format: r.bits(3)? as u8,
+            full_range: r.bit()?,
+            ..Default::default()
+        };
+        if r.bit()? {
+            res.colour_primaries = r.u8()?;
+            res.transfer_characteristics = r.u8()?;
+            res.matrix_coefficients = r.u8()?;
+        }
+        Ok(res)
+    }
+}
+
+/// Sample aspect ratio from `aspect_ratio_idc` and optional extended SAR.
+fn read_sar(r: &mut BitReader) -> Result<(u16, u16)> {
+    const SAR: [(u16, u16); 17] = [
+        (0, 0),
+        (1, 1),
+        (12, 11),
+        (10, 11),
+        (16, 11),
+        (40, 33),
+        (24, 11),
+        (20, 11),
+        (32, 11),
+        (80, 33),
+        (18, 11),
+        (15, 11),
+        (64, 33),
+        (160, 99),
+        (4, 3),
+        (3, 2),
+        (2, 1),
+    ];
+    const EXTENDED_SAR: u8 = 255;
+    let idc = r.u8()?;
+    if idc == EXTENDED_SAR {
+        Ok((r.bits(16)? as u16, r.bits(16)? as u16))
+    } else {
+        Ok(SAR.get(idc as usize).copied().unwrap_or((0, 0)))
+    }
+}
+
+/// Removes emulation prevention bytes (`0x000003` -> `0x0000`).
+///
+/// Borrows the input if there is nothing to remove.
+pub fn rbsp(ebsp: &[u8]) -> Cow<'_, [u8]> {
+    let Some(first) = ebsp.windows(3).position(|w| w == [0, 0, 3]) else {
+        return Cow::Borrowed(ebsp);
+    };
+    let mut res = Vec::with_capacity(ebsp.len());
+    res.extend_from_slice(&ebsp[..first + 2]);
+    let mut zeros = 0;
+    for &b in &ebsp[first + 3..] {
+        if zeros >= 2 && b == 3 {
+            zeros = 0;
+            continue;
+        }
+        zeros = if b == 0 { zeros + 1 } else { 0 };
+        res.push(b);
+    }
+    Cow::Owned(res)
+}
+
+/// Inserts emulation prevention bytes so payload can't contain start codes.
+pub fn ebsp(rbsp: &[u8]) -> Vec<u8> {
+    let mut res = Vec::with_capacity(rbsp.len() + rbsp.len() / 64);
+    let mut zeros = 0;
+    for &b in rbsp {
+        if zeros >= 2 && b <= 3 {
+            res.push(3);
+            zeros = 0;
+        }
+        zeros = if b == 0 { zeros + 1 } else { 0 };
+        res.push(b);
+    }
+    res
+}
+
+/// MSB first bit reader for RBSP data.
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    #[inline]
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// Current position in bits.
+    #[inline]
+    pub const fn pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    pub const fn bits_left(&self) -> usize {
+        self.data.len() * 8 - self.pos
+    }
+
+    #[inline]
+    pub const fn is_byte_aligned(&self) -> bool {
+        self.pos % 8 == 0
+    }
+
+    #[inline]
+    pub fn bit(&mut self) -> Result<bool> {
+        let Some(byte) = self.data.get(self.pos / 8) else {
+            return Err(Error::UnexpectedEnd);
+        };
+        let res = (byte >> (7 - self.pos % 8)) & 1 == 1;
+        self.pos += 1;
+        Ok(res)
+    }
+
+    /// Reads up to 32 bits.
+    pub fn bits(&mut self, n: u32) -> Result<u32> {
+        debug_assert!(n <= 32);
+        Ok(self.bits_u64(n)? as u32)
+    }
+
+    /// Reads up to 64 bits.
+    pub fn bits_u64(&mut self, n: u32) -> Result<u64> {
+        debug_assert!(n <= 64);
+        if self.bits_left() < n as usize {
+            return Err(Error::UnexpectedEnd);
+        }
+        let mut res = 0u64;
+        for _ in 0..n {
+            res = (res << 1) | self.bit()? as u64;
+        }
+        Ok(res)
+    }
+
+    #[inline]
+    pub fn u8(&mut self) -> Result<u8> {
+        Ok(self.bits(8)? as u8)
+    }
+
+    pub fn skip(&mut self, n: usize) -> Result {
+        if self.bits_left() < n {
+            return Err(Error::UnexpectedEnd);
+        }
+        self.pos += n;
+        Ok(())
+    }
+
+    /// Unsigned Exp-Golomb code `ue(v)`.
+    pub fn ue(&mut self) -> Result<u32> {
+        let mut zeros = 0;
+        while !self.bit()? {
+            zeros += 1;
+            if zeros > 31 {
+                return Err(Error::InvalidExpGolomb);
+            }
+        }
+        if zeros == 0 {
+            return Ok(0);
+        }
+        let rest = self.bits(zeros)? as u64;
+        let res = (1u64 << zeros) - 1 + rest;
+        u32::try_from(res).map_err(|_| Error::InvalidExpGolomb)
+    }
+
+    /// Signed Exp-Golomb code `se(v)`.
+    pub fn se(&mut self) -> Result<i32> {
+        let k = self.ue()? as i64;
+        let res = if k & 1 == 1 { (k + 1) / 2 } else { -(k / 2) };
+        Ok(res as i32)
+    }
+
+    /// `ue(v)` with upper bound check.
+    pub fn ue_max(&mut self, max: u32, name: &'static str) -> Result<u32> {
+        let res = self.ue()?;
+        if res > max {
+            return Err(Error::OutOfRange(name));
+        }
+        Ok(res)
+    }
+
+    /// `se(v)` with range check.
+    pub fn se_range(&mut self, min: i32, max: i32, name: &'static str) -> Result<i32> {
+        let res = self.se()?;
+        if res < min || res > max {
+            return Err(Error::OutOfRange(name));
+        }
+        Ok(res)
+    }
+
+    /// Returns true if there is more data before `rbsp_trailing_bits`.
+    pub fn more_rbsp_data(&self) -> bool {
+        let Some(last) = self.data.iter().rposition(|&b| b != 0) else {
+            return false;
+        };
+        let stop_bit = last * 8 + 7 - self.data[last].trailing_zeros() as usize;
+        self.pos < stop_bit
+    }
+}
+
+/// Iterator over NAL units of an Annex-B byte stream.
+///
+/// Yielded units don't include start codes and trailing zero bytes.
+#[derive(Debug, Clone)]
+pub struct AnnexBUnits<'a> {
+    rest: &'a [u8],
+}
+
+fn find_start_code(data: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i + 2 < data.len() {
+        if data[i + 2] > 1 {
+            i += 3;
+        } else if data[i] == 0 && data[i + 1] == 0 && data[i + 2] == 1 {
+            return Some(i);
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+impl<'a> Iterator for AnnexBUnits<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.rest.is_empty() {
+                return None;
+            }
+            let (unit, rest) = match find_start_code(self.rest) {
+                Some(pos) => (&self.rest[..pos], &self.rest[pos + 3..]),
+                None => (self.rest, &[][..]),
+            };
+            self.rest = rest;
+            let len = unit.iter().rposition(|&b| b != 0).map_or(0, |p| p + 1);
+            if len != 0 {
+                return Some(&unit[..len]);
+            }
+        }
+    }
+}
+
+/// Splits Annex-B byte stream into NAL units.
+///
+/// ```
+/// use cidre::cm::nal;
+///
+/// let stream = [0, 0, 0, 1, 0x67, 1, 2, 0, 0, 1, 0x68, 3, 0, 0, 0, 1, 0x65, 4];
+/// let units: Vec<_> = nal::annex_b_units(&stream).collect();
+/// assert_eq!(units, [&[0x67, 1, 2][..], &[0x68, 3], &[0x65, 4]]);
+/// ```
+pub fn annex_b_units(data: &[u8]) -> AnnexBUnits<'_> {
+    let rest = match find_start_code(data) {
+        Some(pos) => &data[pos + 3..],
+        None => &[],
+    };
+    AnnexBUnits { rest }
+}
+
+/// Iterator over NAL units of length-prefixed (`avcC`/`hvcC` style) stream.
+#[derive(Debug, Clone)]
+pub struct LengthPrefixedUnits<'a> {
+    rest: &'a [u8],
+    nal_unit_header_length: usize,
+}
+
+impl<'a> Iterator for LengthPrefixedUnits<'a> {
+    type Item = Result<&'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let n = self.nal_unit_header_length;
+        if self.rest.len() < n {
+            self.rest = &[];
+            return Some(Err(Error::UnexpectedEnd));
+        }
+        let len = self.rest[..n]
+            .iter()
+            .fold(0usize, |acc, &b| (acc << 8) | b as usize);
+        let rest = &self.rest[n..];
+        if rest.len() < len {
+            self.rest = &[];
+            return Some(Err(Error::UnexpectedEnd));
+        }
+        self.rest = &rest[len..];
+        Some(Ok(&rest[..len]))
+    }
+}
+
+fn check_header_length(nal_unit_header_length: usize) -> Result {
+    if !(1..=4).contains(&nal_unit_header_length) {
+        return Err(Error::InvalidHeaderLength(nal_unit_header_length));
+    }
+    Ok(())
+}
+
+/// Splits length-prefixed stream into NAL units.
+///
+/// ```
+/// use cidre::cm::nal;
+///
+/// let stream = [0, 0, 0, 2, 0x65, 1, 0, 0, 0, 1, 0x41];
+/// let units: Vec<_> = nal::length_prefixed_units(&stream, 4)
+///     .unwrap()
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(units, [&[0x65, 1][..], &[0x41]]);
+/// ```
+pub fn length_prefixed_units(
+    data: &[u8],
+    nal_unit_header_length: usize,
+) -> Result<LengthPrefixedUnits<'_>> {
+    check_header_length(nal_unit_header_length)?;
+    Ok(LengthPrefixedUnits {
+        rest: data,
+        nal_unit_header_length,
+    })
+}
+
+/// Writes `unit` prefixed with its big endian length.
+pub fn write_length_prefixed(
+    buf: &mut Vec<u8>,
+    unit: &[u8],
+    nal_unit_header_length: usize,
+) -> Result {
+    check_header_length(nal_unit_header_length)?;
+    let n = nal_unit_header_length;
+    if n < 8 && (unit.len() as u64) >> (n * 8) != 0 {
+        return Err(Error::UnitTooLarge(unit.len()));
+    }
+    buf.extend_from_slice(&(unit.len() as u64).to_be_bytes()[8 - n..]);
+    buf.extend_from_slice(unit);
+    Ok(())
+}
+
+/// Converts Annex-B byte stream into length-prefixed stream.
+///
+/// ```
+/// use cidre::cm::nal;
+///
+/// let stream = [0, 0, 1, 0x65, 1, 0, 0, 1, 0x41];
+/// let res = nal::annex_b_to_length_prefixed(&stream, 4).unwrap();
+/// assert_eq!(res, [0, 0, 0, 2, 0x65, 1, 0, 0, 0, 1, 0x41]);
+/// assert_eq!(nal::length_prefixed_to_annex_b(&res, 4).unwrap(), [0, 0, 0, 1, 0x65, 1, 0, 0, 0, 1, 0x41]);
+/// ```
+pub fn annex_b_to_length_prefixed(data: &[u8], nal_unit_header_length: usize) -> Result<Vec<u8>> {
+    check_header_length(nal_unit_header_length)?;
+    let mut res = Vec::with_capacity(data.len() + 4);
+    for unit in annex_b_units(data) {
+        write_length_prefixed(&mut res, unit, nal_unit_header_length)?;
+    }
+    Ok(res)
+}
+
+/// Converts length-prefixed stream into Annex-B byte stream with 4 byte start codes.
+pub fn length_prefixed_to_annex_b(data: &[u8], nal_unit_header_length: usize) -> Result<Vec<u8>> {
+    let mut res = Vec::with_capacity(data.len() + 4);
+    for unit in length_prefixed_units(data, nal_unit_header_length)? {
+        res.extend_from_slice(&[0, 0, 0, 1]);
+        res.extend_from_slice(unit?);
+    }
+    Ok(res)
+}
+
+/// Big endian reader for configuration records.
+struct ByteReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn u8(&mut self) -> Result<u8> {
+        let (&b, rest) = self.data.split_first().ok_or(Error::UnexpectedEnd)?;
+        self.data = rest;
+        Ok(b)
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes([self.u8()?, self.u8()?]))
+    }
+
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.data.len() < n {
+            return Err(Error::UnexpectedEnd);
+        }
+        let (res, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(res)
+    }
+
+    fn units(&mut self, count: usize) -> Result<Vec<Vec<u8>>> {
+        let mut res = Vec::with_capacity(count);
+        for _ in 0..count {
+            let len = self.u16()? as usize;
+            res.push(self.bytes(len)?.to_vec());
+        }
+        Ok(res)
+    }
+}
+
+fn write_units(buf: &mut Vec<u8>, units: &[Vec<u8>]) -> Result {
+    for unit in units {
+        write_length_prefixed(buf, unit, 2)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::nal;
+
+    /// NAL unit with `header` and payload of `0` and `1` bits, whitespace is ignored.
+    pub(super) fn unit(header: &[u8], bits: &str) -> Vec<u8> {
+        let bits: Vec<u8> = bits
+            .bytes()
+            .filter(|b| !b.is_ascii_whitespace())
+            .map(|b| b - b'0')
+            .collect();
+        let mut rbsp = vec![0u8; bits.len() / 8 + 1];
+        for (i, b) in bits.iter().enumerate() {
+            rbsp[i / 8] |= b << (7 - i % 8);
+        }
+        // rbsp_stop_one_bit
+        rbsp[bits.len() / 8] |= 1 << (7 - bits.len() % 8);
+        let mut res = header.to_vec();
+        res.extend(nal::ebsp(&rbsp));
+        res
+    }
+
+    /// `ue(v)` bits.
+    pub(super) fn ue(val: u32) -> String {
+        let val = val as u64 + 1;
+        let zeros = 63 - val.leading_zeros() as usize;
+        format!("{}{val:b} ", "0".repeat(zeros))
+    }
+
+    /// `se(v)` bits.
+    pub(super) fn se(val: i32) -> String {
+        let k = if val > 0 {
+            2 * val as i64 - 1
+        } else {
+            -2 * val as i64
+        };
+        ue(k as u32)
+    }
+
+    #[test]
+    fn emulation_prevention() {
+        let raw = [0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 3, 0xff];
+        let escaped = nal::ebsp(&raw);
+        assert_eq!(
+            escaped,
+            [0, 0, 3, 0, 0, 3, 0, 1, 0, 0, 3, 2, 0, 0, 3, 3, 0xff]
+        );
+        assert_eq!(nal::rbsp(&escaped).as_ref(), raw);
+        assert!(matches!(
+            nal::rbsp(&[1, 2, 3]),
+            std::borrow::Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn exp_golomb() {
+        // 1 | 010 | 011 | 00100 | 00101
+        let data = [0b1010_0110, 0b0100_0010, 0b1000_0000];
+        let mut r = nal::BitReader::new(&data);
+        assert_eq!(r.ue().unwrap(), 0);
+        assert_eq!(r.ue().unwrap(), 1);
+        assert_eq!(r.se().unwrap(), -1);
+        assert_eq!(r.ue().unwrap(), 3);
+        assert_eq!(r.se().unwrap(), -2);
+        assert!(!r.more_rbsp_data());
+
+        let mut r = nal::BitReader::new(&[0, 0, 0, 0, 0]);
+        assert_eq!(r.ue(), Err(nal::Error::InvalidExpGolomb));
+
+        let data = unit(
+            &[],
+            &[ue(u32::MAX - 1), se(i32::MAX), se(-i32::MAX), se(-3)].concat(),
+        );
+        let data = nal::rbsp(&data);
+        let mut r = nal::BitReader::new(&data);
+        assert_eq!(r.ue().unwrap(), u32::MAX - 1);
+        assert_eq!(r.se().unwrap(), i32::MAX);
+        assert_eq!(
+            r.se_range(-12, 12, "offset"),
+            Err(nal::Error::OutOfRange("offset"))
+        );
+        assert_eq!(r.se_range(-3, 0, "offset").unwrap(), -3);
+    }
+
+    #[test]
+    fn streams() {
+        let stream = [0xff, 0, 0, 1, 9, 0, 0, 0, 0, 1, 0x65, 0, 0, 3, 1, 0, 0];
+        let units: Vec<_> = nal::annex_b_units(&stream).collect();
+        assert_eq!(units, [&[9][..], &[0x65, 0, 0, 3, 1]]);
+        assert_eq!(nal::annex_b_units(&[1, 2, 3]).count(), 0);
+
+        let prefixed = nal::annex_b_to_length_prefixed(&stream, 2).unwrap();
+        assert_eq!(prefixed, [0, 1, 9, 0, 5, 0x65, 0, 0, 3, 1]);
+
+        let mut iter = nal::length_prefixed_units(&[0, 5, 1], 2).unwrap();
+        assert_eq!(iter.next(), Some(Err(nal::Error::UnexpectedEnd)));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(
+            nal::annex_b_to_length_prefixed(&[0, 0, 1, 1], 5),
+            Err(nal::Error::InvalidHeaderLength(5))
+        );
+        let big = [0x65; 256];
+        let mut buf = vec![];
+        assert_eq!(
+            nal::write_length_prefixed(&mut buf, &big, 1),
+            Err(nal::Error::UnitTooLarge(256))
+        );
+    }
+}
@@ -0,0 +1,827 @@
+use crate::cm::nal::{self, BitReader, ByteReader, Error, Result, VideoSignal};
+
+/// H.264 `nal_unit_type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct UnitType(pub u8);
+
+impl UnitType {
+    pub const SLICE: Self = Self(1);
+    pub const SLICE_DPA: Self = Self(2);
+    pub const SLICE_DPB: Self = Self(3);
+    pub const SLICE_DPC: Self = Self(4);
+    pub const IDR: Self = Self(5);
+    pub const SEI: Self = Self(6);
+    pub const SPS: Self = Self(7);
+    pub const PPS: Self = Self(8);
+    pub const AUD: Self = Self(9);
+    pub const END_OF_SEQ: Self = Self(10);
+    pub const END_OF_STREAM: Self = Self(11);
+    pub const FILLER: Self = Self(12);
+    pub const SPS_EXT: Self = Self(13);
+    pub const PREFIX: Self = Self(14);
+    pub const SUBSET_SPS: Self = Self(15);
+
+    /// Returns type of the NAL unit from its header byte.
+    ///
+    /// ```
+    /// use cidre::cm::nal::h264;
+    ///
+    /// assert_eq!(h264::UnitType::with_unit(&[0x67, 0x42]), Some(h264::UnitType::SPS));
+    /// assert_eq!(h264::UnitType::with_unit(&[]), None);
+    /// ```
+    #[inline]
+    pub fn with_unit(unit: &[u8]) -> Option<Self> {
+        unit.first().map(|b| Self(b & 0x1f))
+    }
+
+    #[inline]
+    pub const fn is_vcl(&self) -> bool {
+        self.0 >= 1 && self.0 <= 5
+    }
+}
+
+/// Returns `nal_ref_idc` of the NAL unit.
+#[inline]
+pub fn ref_idc(unit: &[u8]) -> Option<u8> {
+    unit.first().map(|b| (b >> 5) & 0x3)
+}
+
+fn unit_rbsp(unit: &[u8], expected: UnitType) -> Result<std::borrow::Cow<'_, [u8]>> {
+    let Some(t) = UnitType::with_unit(unit) else {
+        return Err(Error::UnexpectedEnd);
+    };
+    if t != expected {
+        return Err(Error::UnexpectedUnitType(t.0));
+    }
+    Ok(nal::rbsp(&unit[1..]))
+}
+
+/// Profiles with chroma format, bit depth and scaling matrices in SPS.
+const fn is_high_profile(profile_idc: u8) -> bool {
+    matches!(
+        profile_idc,
+        100 | 110 | 122 | 244 | 44 | 83 | 86 | 118 | 128 | 138 | 139 | 134 | 135
+    )
+}
+
+/// `MaxFS` of the highest level, Table A-1.
+const MAX_FS: u32 = 139_264;
+
+/// `Sqrt(MaxFS * 8)`, limit of picture width and height in macroblocks, A.3.1.
+const MAX_PIC_SIZE_IN_MBS: u32 = 1_055;
+
+fn skip_scaling_list(r: &mut BitReader, size: usize) -> Result {
+    let mut last = 8i32;
+    let mut next = 8i32;
+    for _ in 0..size {
+        if next != 0 {
+            let delta = r.se_range(-128, 127, "delta_scale")?;
+            next = (last + delta + 256) % 256;
+        }
+        if next != 0 {
+            last = next;
+        }
+    }
+    Ok(())
+}
+
+fn skip_hrd_params(r: &mut BitReader) -> Result {
+    let cpb_cnt = r.ue_max(31, "cpb_cnt_minus1")? + 1;
+    r.skip(8)?; // bit_rate_scale, cpb_size_scale
+    for _ in 0..cpb_cnt {
+        r.ue()?; // bit_rate_value_minus1
+        r.ue()?; // cpb_size_value_minus1
+        r.skip(1)?; // cbr_flag
+    }
+    r.skip(20) // delays lengths and time_offset_length
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub num_units_in_tick: u32,
+    pub time_scale: u32,
+    pub fixed_frame_rate: bool,
+}
+
+impl Timing {
+    /// Frame rate for progressive content (two ticks per frame).
+    pub fn frame_rate(&self) -> Option<f64> {
+        if self.num_units_in_tick == 0 {
+            return None;
+        }
+        Some(self.time_scale as f64 / (2.0 * self.num_units_in_tick as f64))
+    }
+}
+
+/// Video usability information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Vui {
+    /// Sample aspect ratio (0, 0 if unspecified).
+    pub sar: (u16, u16),
+    pub overscan_appropriate: Option<bool>,
+    pub video_signal: Option<VideoSignal>,
+    /// `chroma_sample_loc_type_top_field` and `chroma_sample_loc_type_bottom_field`.
+    pub chroma_loc: Option<(u32, u32)>,
+    pub timing: Option<Timing>,
+    pub nal_hrd: bool,
+    pub vcl_hrd: bool,
+    pub pic_struct_present: bool,
+    pub max_num_reorder_frames: Option<u32>,
+    pub max_dec_frame_buffering: Option<u32>,
+}
+
+impl Vui {
+    fn read(r: &mut BitReader) -> Result<Self> {
+        let mut res = Self::default();
+        if r.bit()? {
+            res.sar = nal::read_sar(r)?;
+        }
+        if r.bit()? {
+            res.overscan_appropriate = Some(r.bit()?);
+        }
+        if r.bit()? {
+            res.video_signal = Some(VideoSignal::read(r)?);
+        }
+        if r.bit()? {
+            res.chroma_loc = Some((
+                r.ue_max(5, "chroma_sample_loc_type")?,
+                r.ue_max(5, "chroma_sample_loc_type")?,
+            ));
+        }
+        if r.bit()? {
+            res.timing = Some(Timing {
+                num_units_in_tick: r.bits(32)?,
+                time_scale: r.bits(32)?,
+                fixed_frame_rate: r.bit()?,
+            });
+        }
+        res.nal_hrd = r.bit()?;
+        if res.nal_hrd {
+            skip_hrd_params(r)?;
+        }
+        res.vcl_hrd = r.bit()?;
+        if res.vcl_hrd {
+            skip_hrd_params(r)?;
+        }
+        if res.nal_hrd || res.vcl_hrd {
+            r.skip(1)?; // low_delay_hrd_flag
+        }
+        res.pic_struct_present = r.bit()?;
+        if r.bit()? {
+            r.skip(1)?; // motion_vectors_over_pic_boundaries_flag
+            r.ue()?; // max_bytes_per_pic_denom
+            r.ue()?; // max_bits_per_mb_denom
+            r.ue()?; // log2_max_mv_length_horizontal
+            r.ue()?; // log2_max_mv_length_vertical
+            res.max_num_reorder_frames = Some(r.ue()?);
+            res.max_dec_frame_buffering = Some(r.ue()?);
+        }
+        Ok(res)
+    }
+}
+
+/// Frame cropping offsets in crop units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Crop {
+    pub left: u32,
+    pub right: u32,
+    pub top: u32,
+    pub bottom: u32,
+}
+
+/// Sequence parameter set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sps {
+    pub profile_idc: u8,
+    /// `constraint_set0_flag` .. `constraint_set5_flag` and reserved bits.
+    pub constraint_flags: u8,
+    pub level_idc: u8,
+    pub id: u32,
+    pub chroma_format_idc: u8,
+    pub separate_colour_plane: bool,
+    pub bit_depth_luma: u8,
+    pub bit_depth_chroma: u8,
+    pub qpprime_y_zero_transform_bypass: bool,
+    pub scaling_matrix_present: bool,
+    pub log2_max_frame_num: u8,
+    pub pic_order_cnt_type: u8,
+    pub log2_max_pic_order_cnt_lsb: Option<u8>,
+    pub max_num_ref_frames: u32,
+    pub gaps_in_frame_num_allowed: bool,
+    pub pic_width_in_mbs: u32,
+    pub pic_height_in_map_units: u32,
+    pub frame_mbs_only: bool,
+    pub mb_adaptive_frame_field: bool,
+    pub direct_8x8_inference: bool,
+    pub crop: Option<Crop>,
+    pub vui: Option<Vui>,
+}
+
+impl Sps {
+    /// Parses SPS NAL unit (with NAL header, without start code).
+    ///
+    /// ```
+    /// use cidre::cm::nal::h264;
+    ///
+    /// let sps = [
+    ///     0x67, 0x64, 0x00, 0x28, 0xac, 0xd9, 0x40, 0x78, 0x02, 0x27, 0xe5, 0xc0, 0x44,
+    ///     0x00, 0x00, 0x03, 0x00, 0x04, 0x00, 0x00, 0x03, 0x00, 0xf0, 0x3c, 0x60, 0xc6, 0x58,
+    /// ];
+    /// let sps = h264::Sps::parse(&sps).unwrap();
+    /// assert_eq!(sps.profile_idc, 100);
+    /// assert_eq!(sps.level_idc, 40);
+    /// assert_eq!(sps.width(), 1920);
+    /// assert_eq!(sps.height(), 1080);
+    /// ```
+    pub fn parse(unit: &[u8]) -> Result<Self> {
+        let rbsp = unit_rbsp(unit, UnitType::SPS)?;
+        Self::read(&mut BitReader::new(&rbsp))
+    }
+
+    fn read(r: &mut BitReader) -> Result<Self> {
+        let profile_idc = r.u8()?;
+        let constraint_flags = r.u8()?;
+        let level_idc = r.u8()?;
+        let id = r.ue_max(31, "seq_parameter_set_id")?;
+
+        let mut chroma_format_idc = 1;
+        let mut separate_colour_plane = false;
+        let mut bit_depth_luma = 8;
+        let mut bit_depth_chroma = 8;
+        let mut qpprime_y_zero_transform_bypass = false;
+        let mut scaling_matrix_present = false;
+        if is_high_profile(profile_idc) {
+            chroma_format_idc = r.ue_max(3, "chroma_format_idc")? as u8;
+            if chroma_format_idc == 3 {
+                separate_colour_plane = r.bit()?;
+            }
+            bit_depth_luma = r.ue_max(6, "bit_depth_luma_minus8")? as u8 + 8;
+            bit_depth_chroma = r.ue_max(6, "bit_depth_chroma_minus8")? as u8 + 8;
+            qpprime_y_zero_transform_bypass = r.bit()?;
+            scaling_matrix_present = r.bit()?;
+            if scaling_matrix_present {
+                let count = if chroma_format_idc != 3 { 8 } else { 12 };
+                for i in 0..count {
+                    if r.bit()? {
+                        skip_scaling_list(r, if i < 6 { 16 } else { 64 })?;
+                    }
+                }
+            }
+        }
+
+        let log2_max_frame_num = r.ue_max(12, "log2_max_frame_num_minus4")? as u8 + 4;
+        let pic_order_cnt_type = r.ue_max(2, "pic_order_cnt_type")? as u8;
+        let mut log2_max_pic_order_cnt_lsb = None;
+        if pic_order_cnt_type == 0 {
+            log2_max_pic_order_cnt_lsb =
+                Some(r.ue_max(12, "log2_max_pic_order_cnt_lsb_minus4")? as u8 + 4);
+        } else if pic_order_cnt_type == 1 {
+            r.skip(1)?; // delta_pic_order_always_zero_flag
+            r.se()?; // offset_for_non_ref_pic
+            r.se()?; // offset_for_top_to_bottom_field
+            let n = r.ue_max(255, "num_ref_frames_in_pic_order_cnt_cycle")?;
+            for _ in 0..n {
+                r.se()?; // offset_for_ref_frame
+            }
+        }
+        let max_num_ref_frames = r.ue()?;
+        let gaps_in_frame_num_allowed = r.bit()?;
+        let pic_width_in_mbs = r.ue_max(MAX_PIC_SIZE_IN_MBS - 1, "pic_width_in_mbs_minus1")? + 1;
+        let pic_height_in_map_units =
+            r.ue_max(MAX_PIC_SIZE_IN_MBS - 1, "pic_height_in_map_units_minus1")? + 1;
+        let frame_mbs_only = r.bit()?;
+        let mb_adaptive_frame_field = if frame_mbs_only { false } else { r.bit()? };
+        let direct_8x8_inference = r.bit()?;
+        let crop = if r.bit()? {
+            Some(Crop {
+                left: r.ue()?,
+                right: r.ue()?,
+                top: r.ue()?,
+                bottom: r.ue()?,
+            })
+        } else {
+            None
+        };
+        let vui = if r.bit()? { Some(Vui::read(r)?) } else { None };
+
+        let res = Self {
+            profile_idc,
+            constraint_flags,
+            level_idc,
+            id,
+            chroma_format_idc,
+            separate_colour_plane,
+            bit_depth_luma,
+            bit_depth_chroma,
+            qpprime_y_zero_transform_bypass,
+            scaling_matrix_present,
+            log2_max_frame_num,
+            pic_order_cnt_type,
+            log2_max_pic_order_cnt_lsb,
+            max_num_ref_frames,
+            gaps_in_frame_num_allowed,
+            pic_width_in_mbs,
+            pic_height_in_map_units,
+            frame_mbs_only,
+            mb_adaptive_frame_field,
+            direct_8x8_inference,
+            crop,
+            vui,
+        };
+        if let Some(crop) = res.crop {
+            // offsets must leave at least one sample
+            let (x, y) = res.crop_units();
+            let fits = |a: u32, b: u32, unit: u32, size: u32| {
+                (a as u64 + b as u64 + 1) * unit as u64 <= size as u64
+            };
+            if !fits(crop.left, crop.right, x, res.coded_width())
+                || !fits(crop.top, crop.bottom, y, res.coded_height())
+            {
+                return Err(Error::OutOfRange("frame_crop_offset"));
+            }
+        }
+        Ok(res)
+    }
+
+    /// `ChromaArrayType`
+    #[inline]
+    pub const fn chroma_array_type(&self) -> u8 {
+        if self.separate_colour_plane {
+            0
+        } else {
+            self.chroma_format_idc
+        }
+    }
+
+    /// Horizontal and vertical crop units.
+    pub const fn crop_units(&self) -> (u32, u32) {
+        let field = 2 - self.frame_mbs_only as u32;
+        match self.chroma_array_type() {
+            0 => (1, field),
+            1 => (2, 2 * field),
+            2 => (2, field),
+            _ => (1, field),
+        }
+    }
+
+    /// Coded width in luma samples.
+    #[inline]
+    pub const fn coded_width(&self) -> u32 {
+        self.pic_width_in_mbs * 16
+    }
+
+    /// Coded height in luma samples.
+    #[inline]
+    pub const fn coded_height(&self) -> u32 {
+        (2 - self.frame_mbs_only as u32) * self.pic_height_in_map_units * 16
+    }
+
+    /// Width after cropping.
+    pub const fn width(&self) -> u32 {
+        match self.crop {
+            Some(crop) => self
+                .coded_width()
+                .saturating_sub(self.crop_units().0 * (crop.left + crop.right)),
+            None => self.coded_width(),
+        }
+    }
+
+    /// Height after cropping.
+    pub const fn height(&self) -> u32 {
+        match self.crop {
+            Some(crop) => self
+                .coded_height()
+                .saturating_sub(self.crop_units().1 * (crop.top + crop.bottom)),
+            None => self.coded_height(),
+        }
+    }
+
+    #[inline]
+    pub const fn video_signal(&self) -> Option<VideoSignal> {
+        match self.vui {
+            Some(vui) => vui.video_signal,
+            None => None,
+        }
+    }
+}
+
+/// Picture parameter set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pps {
+    pub id: u32,
+    pub sps_id: u32,
+    pub entropy_coding_mode: bool,
+    pub bottom_field_pic_order_in_frame_present: bool,
+    pub num_slice_groups: u32,
+    pub num_ref_idx_l0_default_active: u32,
+    pub num_ref_idx_l1_default_active: u32,
+    pub weighted_pred: bool,
+    pub weighted_bipred_idc: u8,
+    pub pic_init_qp: i32,
+    pub pic_init_qs: i32,
+    pub chroma_qp_index_offset: i32,
+    pub deblocking_filter_control_present: bool,
+    pub constrained_intra_pred: bool,
+    pub redundant_pic_cnt_present: bool,
+    pub transform_8x8_mode: bool,
+    pub scaling_matrix_present: bool,
+    pub second_chroma_qp_index_offset: i32,
+}
+
+impl Pps {
+    /// Parses PPS NAL unit assuming 4:2:0 chroma format for scaling lists.
+    pub fn parse(unit: &[u8]) -> Result<Self> {
+        Self::parse_with_chroma_format(unit, 1)
+    }
+
+    /// Parses PPS NAL unit which refers to `sps`.
+    pub fn parse_with_sps(unit: &[u8], sps: &Sps) -> Result<Self> {
+        Self::parse_with_chroma_format(unit, sps.chroma_format_idc)
+    }
+
+    fn parse_with_chroma_format(unit: &[u8], chroma_format_idc: u8) -> Result<Self> {
+        let rbsp = unit_rbsp(unit, UnitType::PPS)?;
+        let r = &mut BitReader::new(&rbsp);
+
+        let id = r.ue_max(255, "pic_parameter_set_id")?;
+        let sps_id = r.ue_max(31, "seq_parameter_set_id")?;
+        let entropy_coding_mode = r.bit()?;
+        let bottom_field_pic_order_in_frame_present = r.bit()?;
+        let num_slice_groups = r.ue_max(7, "num_slice_groups_minus1")? + 1;
+        if num_slice_groups > 1 {
+            match r.ue_max(6, "slice_group_map_type")? {
+                0 => {
+                    for _ in 0..num_slice_groups {
+                        r.ue()?; // run_length_minus1
+                    }
+                }
+                2 => {
+                    for _ in 1..num_slice_groups {
+                        r.ue()?; // top_left
+                        r.ue()?; // bottom_right
+                    }
+                }
+                3..=5 => {
+                    r.skip(1)?; // slice_group_change_direction_flag
+                    r.ue()?; // slice_group_change_rate_minus1
+                }
+                6 => {
+                    let n = r.ue_max(MAX_FS - 1, "pic_size_in_map_units_minus1")? as usize + 1;
+                    let bits = u32::BITS - (num_slice_groups - 1).leading_zeros();
+                    r.skip(n * bits as usize)?;
+                }
+                _ => {}
+            }
+        }
+        let num_ref_idx_l0_default_active =
+            r.ue_max(31, "num_ref_idx_l0_default_active_minus1")? + 1;
+        let num_ref_idx_l1_default_active =
+            r.ue_max(31, "num_ref_idx_l1_default_active_minus1")? + 1;
+        let weighted_pred = r.bit()?;
+        let weighted_bipred_idc = r.bits(2)? as u8;
+        // QpBdOffsetY is up to 36 for 14-bit luma
+        let pic_init_qp = 26 + r.se_range(-26 - 36, 25, "pic_init_qp_minus26")?;
+        let pic_init_qs = 26 + r.se_range(-26, 25, "pic_init_qs_minus26")?;
+        let chroma_qp_index_offset = r.se_range(-12, 12, "chroma_qp_index_offset")?;
+        let deblocking_filter_control_present = r.bit()?;
+        let constrained_intra_pred = r.bit()?;
+        let redundant_pic_cnt_present = r.bit()?;
+
+        let mut transform_8x8_mode = false;
+        let mut scaling_matrix_present = false;
+        let mut second_chroma_qp_index_offset = chroma_qp_index_offset;
+        if r.more_rbsp_data() {
+            transform_8x8_mode = r.bit()?;
+            scaling_matrix_present = r.bit()?;
+            if scaling_matrix_present {
+                let lists_8x8 = if chroma_format_idc == 3 { 6 } else { 2 };
+                let count = 6 + lists_8x8 * transform_8x8_mode as usize;
+                for i in 0..count {
+                    if r.bit()? {
+                        skip_scaling_list(r, if i < 6 { 16 } else { 64 })?;
+                    }
+                }
+            }
+            second_chroma_qp_index_offset = r.se_range(-12, 12, "second_chroma_qp_index_offset")?;
+        }
+
+        Ok(Self {
+            id,
+            sps_id,
+            entropy_coding_mode,
+            bottom_field_pic_order_in_frame_present,
+            num_slice_groups,
+            num_ref_idx_l0_default_active,
+            num_ref_idx_l1_default_active,
+            weighted_pred,
+            weighted_bipred_idc,
+            pic_init_qp,
+            pic_init_qs,
+            chroma_qp_index_offset,
+            deblocking_filter_control_present,
+            constrained_intra_pred,
+            redundant_pic_cnt_present,
+            transform_8x8_mode,
+            scaling_matrix_present,
+            second_chroma_qp_index_offset,
+        })
+    }
+}
+
+/// Extra fields of `avcC` for High profiles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvcConfigExt {
+    pub chroma_format: u8,
+    pub bit_depth_luma: u8,
+    pub bit_depth_chroma: u8,
+    pub sps_ext: Vec<Vec<u8>>,
+}
+
+/// `AVCDecoderConfigurationRecord` (`avcC` atom payload).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvcConfig {
+    pub profile_indication: u8,
+    pub profile_compatibility: u8,
+    pub level_indication: u8,
+    pub nal_unit_header_length: u8,
+    pub sps: Vec<Vec<u8>>,
+    pub pps: Vec<Vec<u8>>,
+    pub ext: Option<AvcConfigExt>,
+}
+
+impl AvcConfig {
+    /// Builds configuration from parameter sets. Profile and level are taken from first SPS.
+    ///
+    /// ```
+    /// use cidre::cm::nal::h264;
+    ///
+    /// let sps = [0x67, 0x42, 0xc0, 0x1e, 0xda, 0x05, 0x07, 0xe4];
+    /// let pps = [0x68, 0xce, 0x3c, 0x80];
+    /// let cfg = h264::AvcConfig::with_param_sets(&[&sps], &[&pps], 4).unwrap();
+    /// assert_eq!(cfg.profile_indication, 66);
+    ///
+    /// let bytes = cfg.to_bytes().unwrap();
+    /// assert_eq!(&bytes[..6], &[1, 0x42, 0xc0, 0x1e, 0xff, 0xe1]);
+    /// assert_eq!(h264::AvcConfig::parse(&bytes).unwrap(), cfg);
+    /// ```
+    pub fn with_param_sets(
+        sps: &[&[u8]],
+        pps: &[&[u8]],
+        nal_unit_header_length: u8,
+    ) -> Result<Self> {
+        nal::check_header_length(nal_unit_header_length as usize)?;
+        let Some(first) = sps.first() else {
+            return Err(Error::OutOfRange("numOfSequenceParameterSets"));
+        };
+        let parsed = Sps::parse(first)?;
+        let ext = if matches!(parsed.profile_idc, 100 | 110 | 122 | 144) {
+            Some(AvcConfigExt {
+                chroma_format: parsed.chroma_format_idc,
+                bit_depth_luma: parsed.bit_depth_luma,
+                bit_depth_chroma: parsed.bit_depth_chroma,
+                sps_ext: vec![],
+            })
+        } else {
+            None
+        };
+        Ok(Self {
+            profile_indication: parsed.profile_idc,
+            profile_compatibility: parsed.constraint_flags,
+            level_indication: parsed.level_idc,
+            nal_unit_header_length,
+            sps: sps.iter().map(|s| s.to_vec()).collect(),
+            pps: pps.iter().map(|s| s.to_vec()).collect(),
+            ext,
+        })
+    }
+
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let r = &mut ByteReader { data };
+        let version = r.u8()?;
+        if version != 1 {
+            return Err(Error::UnsupportedVersion(version));
+        }
+        let profile_indication = r.u8()?;
+        let profile_compatibility = r.u8()?;
+        let level_indication = r.u8()?;
+        let nal_unit_header_length = (r.u8()? & 0x3) + 1;
+        let sps_count = r.u8()? & 0x1f;
+        let sps = r.units(sps_count as usize)?;
+        let pps_count = r.u8()?;
+        let pps = r.units(pps_count as usize)?;
+
+        // Many muxers omit the extension even for High profiles.
+        let ext = if matches!(profile_indication, 100 | 110 | 122 | 144) && r.data.len() >= 4 {
+            let chroma_format = r.u8()? & 0x3;
+            let bit_depth_luma = (r.u8()? & 0x7) + 8;
+            let bit_depth_chroma = (r.u8()? & 0x7) + 8;
+            let count = r.u8()?;
+            Some(AvcConfigExt {
+                chroma_format,
+                bit_depth_luma,
+                bit_depth_chroma,
+                sps_ext: r.units(count as usize)?,
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            profile_indication,
+            profile_compatibility,
+            level_indication,
+            nal_unit_header_length,
+            sps,
+            pps,
+            ext,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        nal::check_header_length(self.nal_unit_header_length as usize)?;
+        if self.sps.len() > 31 {
+            return Err(Error::OutOfRange("numOfSequenceParameterSets"));
+        }
+        if self.pps.len() > 255 {
+            return Err(Error::OutOfRange("numOfPictureParameterSets"));
+        }
+        let mut res = vec![
+            1,
+            self.profile_indication,
+            self.profile_compatibility,
+            self.level_indication,
+            0xfc | (self.nal_unit_header_length - 1),
+            0xe0 | self.sps.len() as u8,
+        ];
+        nal::write_units(&mut res, &self.sps)?;
+        res.push(self.pps.len() as u8);
+        nal::write_units(&mut res, &self.pps)?;
+        if let Some(ext) = &self.ext {
+            if ext.sps_ext.len() > 255 {
+                return Err(Error::OutOfRange("numOfSequenceParameterSetExt"));
+            }
+            res.push(0xfc | (ext.chroma_format & 0x3));
+            res.push(0xf8 | (ext.bit_depth_luma.wrapping_sub(8) & 0x7));
+            res.push(0xf8 | (ext.bit_depth_chroma.wrapping_sub(8) & 0x7));
+            res.push(ext.sps_ext.len() as u8);
+            nal::write_units(&mut res, &ext.sps_ext)?;
+        }
+        Ok(res)
+    }
+
+    /// RFC 6381 codec string, e.g. `avc1.64001F`.
+    pub fn codec_string(&self) -> String {
+        format!(
+            "avc1.{:02X}{:02X}{:02X}",
+            self.profile_indication, self.profile_compatibility, self.level_indication
+        )
+    }
+
+    /// Parameter sets in order expected by `cm::VideoFormatDesc::with_h264_param_sets` (SPS first).
+    pub fn param_sets(&self) -> impl Iterator<Item = &[u8]> {
+        self.sps.iter().chain(self.pps.iter()).map(|s| s.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::nal::{self, h264};
+
+    const SPS_1080P: [u8; 27] = [
+        0x67, 0x64, 0x00, 0x28, 0xac, 0xd9, 0x40, 0x78, 0x02, 0x27, 0xe5, 0xc0, 0x44, 0x00, 0x00,
+        0x03, 0x00, 0x04, 0x00, 0x00, 0x03, 0x00, 0xf0, 0x3c, 0x60, 0xc6, 0x58,
+    ];
+
+    #[test]
+    fn sps() {
+        let sps = h264::Sps::parse(&SPS_1080P).unwrap();
+        assert_eq!(sps.profile_idc, 100);
+        assert_eq!(sps.chroma_format_idc, 1);
+        assert_eq!(sps.bit_depth_luma, 8);
+        assert_eq!(sps.coded_width(), 1920);
+        assert_eq!(sps.coded_height(), 1088);
+        assert_eq!(sps.crop.unwrap().bottom, 4);
+        assert_eq!(sps.height(), 1080);
+
+        let vui = sps.vui.unwrap();
+        assert_eq!(vui.sar, (1, 1));
+        let timing = vui.timing.unwrap();
+        assert_eq!(timing.frame_rate(), Some(30.0));
+
+        assert_eq!(
+            h264::Sps::parse(&[0x68, 0xce]),
+            Err(nal::Error::UnexpectedUnitType(8))
+        );
+        assert_eq!(
+            h264::Sps::parse(&SPS_1080P[..6]),
+            Err(nal::Error::UnexpectedEnd)
+        );
+    }
+
+    /// Baseline SPS of `width` x `height` macroblocks without VUI.
+    fn sps_unit(width_minus1: u32, height_minus1: u32, crop: Option<[u32; 4]>) -> Vec<u8> {
+        use nal::tests::ue;
+
+        let mut bits = String::from("01000010 00000000 00011110");
+        bits += &[ue(0), ue(0), ue(0), ue(0), ue(1)].concat();
+        bits += "0";
+        bits += &ue(width_minus1);
+        bits += &ue(height_minus1);
+        bits += "11";
+        match crop {
+            Some(crop) => bits += &format!("1{}", crop.map(ue).concat()),
+            None => bits += "0",
+        }
+        bits += "0";
+        nal::tests::unit(&[0x67], &bits)
+    }
+
+    #[test]
+    fn malformed() {
+        let sps = h264::Sps::parse(&sps_unit(0, 0, Some([7, 0, 0, 7]))).unwrap();
+        assert_eq!((sps.width(), sps.height()), (2, 2));
+
+        assert_eq!(
+            h264::Sps::parse(&sps_unit(u32::MAX - 1, 0, None)),
+            Err(nal::Error::OutOfRange("pic_width_in_mbs_minus1"))
+        );
+        assert_eq!(
+            h264::Sps::parse(&sps_unit(0, 1 << 28, None)),
+            Err(nal::Error::OutOfRange("pic_height_in_map_units_minus1"))
+        );
+        for crop in [
+            [8, 0, 0, 0],
+            [0, 0, 4, 4],
+            [u32::MAX - 1, u32::MAX - 1, 0, 0],
+        ] {
+            assert_eq!(
+                h264::Sps::parse(&sps_unit(0, 0, Some(crop))),
+                Err(nal::Error::OutOfRange("frame_crop_offset"))
+            );
+        }
+
+        use nal::tests::{se, ue};
+        let pps = |qp: i32, qs: i32, chroma: i32| {
+            let bits = [ue(0), ue(0), "00".into(), ue(0), ue(0), ue(0), "000".into()].concat();
+            let bits = bits + &[se(qp), se(qs), se(chroma)].concat() + "000";
+            h264::Pps::parse(&nal::tests::unit(&[0x68], &bits))
+        };
+        let valid = pps(-62, 25, -12).unwrap();
+        assert_eq!((valid.pic_init_qp, valid.pic_init_qs), (-36, 51));
+        assert_eq!(
+            pps(i32::MAX, 0, 0),
+            Err(nal::Error::OutOfRange("pic_init_qp_minus26"))
+        );
+        assert_eq!(
+            pps(0, -i32::MAX, 0),
+            Err(nal::Error::OutOfRange("pic_init_qs_minus26"))
+        );
+        assert_eq!(
+            pps(0, 0, 13),
+            Err(nal::Error::OutOfRange("chroma_qp_index_offset"))
+        );
+    }
+
+    #[test]
+    fn pps() {
+        let pps = h264::Pps::parse(&[0x68, 0xeb, 0xe3, 0xcb, 0x22, 0xc0]).unwrap();
+        assert_eq!(pps.id, 0);
+        assert_eq!(pps.sps_id, 0);
+        assert!(pps.entropy_coding_mode);
+        assert!(pps.transform_8x8_mode);
+        assert_eq!(pps.num_slice_groups, 1);
+        assert_eq!(pps.weighted_bipred_idc, 2);
+        assert_eq!(pps.pic_init_qp, 26 - 3);
+    }
+
+    #[test]
+    fn avcc() {
+        let pps = [0x68, 0xeb, 0xe3, 0xcb, 0x22, 0xc0];
+        let cfg = h264::AvcConfig::with_param_sets(&[&SPS_1080P], &[&pps], 4).unwrap();
+        let ext = cfg.ext.as_ref().unwrap();
+        assert_eq!(ext.chroma_format, 1);
+        assert_eq!(ext.bit_depth_luma, 8);
+
+        assert_eq!(cfg.codec_string(), "avc1.640028");
+
+        let bytes = cfg.to_bytes().unwrap();
+        assert_eq!(bytes.len(), 6 + 2 + SPS_1080P.len() + 1 + 2 + pps.len() + 4);
+        assert_eq!(h264::AvcConfig::parse(&bytes).unwrap(), cfg);
+
+        // no High profile extension
+        let short = &bytes[..bytes.len() - 4];
+        let parsed = h264::AvcConfig::parse(short).unwrap();
+        assert!(parsed.ext.is_none());
+        assert_eq!(parsed.param_sets().count(), 2);
+
+        assert_eq!(
+            h264::AvcConfig::parse(&[0]),
+            Err(nal::Error::UnsupportedVersion(0))
+        );
+        assert_eq!(
+            h264::AvcConfig::parse(&bytes[..10]),
+            Err(nal::Error::UnexpectedEnd)
+        );
+    }
+}
@@ -0,0 +1,975 @@
+use crate::cm::nal::{self, BitReader, ByteReader, Error, Result, VideoSignal};
+
+/// HEVC `nal_unit_type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct UnitType(pub u8);
+
+impl UnitType {
+    pub const TRAIL_N: Self = Self(0);
+    pub const TRAIL_R: Self = Self(1);
+    pub const BLA_W_LP: Self = Self(16);
+    pub const BLA_W_RADL: Self = Self(17);
+    pub const BLA_N_LP: Self = Self(18);
+    pub const IDR_W_RADL: Self = Self(19);
+    pub const IDR_N_LP: Self = Self(20);
+    pub const CRA: Self = Self(21);
+    pub const VPS: Self = Self(32);
+    pub const SPS: Self = Self(33);
+    pub const PPS: Self = Self(34);
+    pub const AUD: Self = Self(35);
+    pub const EOS: Self = Self(36);
+    pub const EOB: Self = Self(37);
+    pub const FD: Self = Self(38);
+    pub const PREFIX_SEI: Self = Self(39);
+    pub const SUFFIX_SEI: Self = Self(40);
+
+    /// Returns type of the NAL unit from its two byte header.
+    ///
+    /// ```
+    /// use cidre::cm::nal::hevc;
+    ///
+    /// assert_eq!(hevc::UnitType::with_unit(&[0x40, 0x01]), Some(hevc::UnitType::VPS));
+    /// assert_eq!(hevc::UnitType::with_unit(&[0x40]), None);
+    /// ```
+    #[inline]
+    pub fn with_unit(unit: &[u8]) -> Option<Self> {
+        if unit.len() < 2 {
+            return None;
+        }
+        Some(Self((unit[0] >> 1) & 0x3f))
+    }
+
+    #[inline]
+    pub const fn is_vcl(&self) -> bool {
+        self.0 < 32
+    }
+
+    /// Intra random access point picture (BLA, IDR or CRA).
+    #[inline]
+    pub const fn is_irap(&self) -> bool {
+        self.0 >= 16 && self.0 <= 23
+    }
+}
+
+/// Returns `nuh_layer_id` and `nuh_temporal_id_plus1` of the NAL unit.
+#[inline]
+pub fn layer_and_temporal_id(unit: &[u8]) -> Option<(u8, u8)> {
+    if unit.len() < 2 {
+        return None;
+    }
+    let layer_id = ((unit[0] & 1) << 5) | (unit[1] >> 3);
+    Some((layer_id, unit[1] & 0x7))
+}
+
+fn unit_rbsp(unit: &[u8], expected: UnitType) -> Result<std::borrow::Cow<'_, [u8]>> {
+    let Some(t) = UnitType::with_unit(unit) else {
+        return Err(Error::UnexpectedEnd);
+    };
+    if t != expected {
+        return Err(Error::UnexpectedUnitType(t.0));
+    }
+    Ok(nal::rbsp(&unit[2..]))
+}
+
+/// General `profile_tier_level`. Sub-layer information is skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProfileTierLevel {
+    pub profile_space: u8,
+    pub tier: bool,
+    pub profile_idc: u8,
+    pub profile_compatibility_flags: u32,
+    /// 48 bits starting with `general_progressive_source_flag`.
+    pub constraint_indicator_flags: u64,
+    pub level_idc: u8,
+}
+
+impl ProfileTierLevel {
+    pub const MAIN: u8 = 1;
+    pub const MAIN_10: u8 = 2;
+    pub const MAIN_STILL_PICTURE: u8 = 3;
+    pub const RANGE_EXTENSIONS: u8 = 4;
+
+    fn read(r: &mut BitReader, max_sub_layers_minus1: u8) -> Result<Self> {
+        let res = Self {
+            profile_space: r.bits(2)? as u8,
+            tier: r.bit()?,
+            profile_idc: r.bits(5)? as u8,
+            profile_compatibility_flags: r.bits(32)?,
+            constraint_indicator_flags: r.bits_u64(48)?,
+            level_idc: r.u8()?,
+        };
+        let mut profile_present = [false; 8];
+        let mut level_present = [false; 8];
+        for i in 0..max_sub_layers_minus1 as usize {
+            profile_present[i] = r.bit()?;
+            level_present[i] = r.bit()?;
+        }
+        if max_sub_layers_minus1 > 0 {
+            r.skip(2 * (8 - max_sub_layers_minus1 as usize))?;
+        }
+        for i in 0..max_sub_layers_minus1 as usize {
+            if profile_present[i] {
+                r.skip(88)?;
+            }
+            if level_present[i] {
+                r.skip(8)?;
+            }
+        }
+        Ok(res)
+    }
+
+    #[inline]
+    pub const fn progressive_source(&self) -> bool {
+        self.constraint_indicator_flags & (1 << 47) != 0
+    }
+
+    #[inline]
+    pub const fn interlaced_source(&self) -> bool {
+        self.constraint_indicator_flags & (1 << 46) != 0
+    }
+}
+
+/// `sps_max_dec_pic_buffering_minus1 + 1`, `sps_max_num_reorder_pics`
+/// and `sps_max_latency_increase_plus1` for the highest sub-layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubLayerOrdering {
+    pub max_dec_pic_buffering: u32,
+    pub max_num_reorder_pics: u32,
+    pub max_latency_increase_plus1: u32,
+}
+
+impl SubLayerOrdering {
+    fn read(r: &mut BitReader, max_sub_layers_minus1: u8) -> Result<Self> {
+        let first = if r.bit()? { 0 } else { max_sub_layers_minus1 };
+        let mut res = Self::default();
+        for _ in first..=max_sub_layers_minus1 {
+            res.max_dec_pic_buffering = r.ue_max(15, "max_dec_pic_buffering_minus1")? + 1;
+            res.max_num_reorder_pics = r.ue()?;
+            res.max_latency_increase_plus1 = r.ue()?;
+        }
+        Ok(res)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub num_units_in_tick: u32,
+    pub time_scale: u32,
+    pub num_ticks_poc_diff_one: Option<u32>,
+}
+
+impl Timing {
+    fn read(r: &mut BitReader) -> Result<Self> {
+        let num_units_in_tick = r.bits(32)?;
+        let time_scale = r.bits(32)?;
+        let num_ticks_poc_diff_one = if r.bit()? { Some(r.ue()? + 1) } else { None };
+        Ok(Self {
+            num_units_in_tick,
+            time_scale,
+            num_ticks_poc_diff_one,
+        })
+    }
+
+    /// Frame rate (one tick per picture).
+    pub fn frame_rate(&self) -> Option<f64> {
+        if self.num_units_in_tick == 0 {
+            return None;
+        }
+        Some(self.time_scale as f64 / self.num_units_in_tick as f64)
+    }
+}
+
+/// Video parameter set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vps {
+    pub id: u8,
+    pub max_layers: u8,
+    pub max_sub_layers: u8,
+    pub temporal_id_nesting: bool,
+    pub ptl: ProfileTierLevel,
+    pub ordering: SubLayerOrdering,
+    pub timing: Option<Timing>,
+}
+
+impl Vps {
+    /// Parses VPS NAL unit (with NAL header, without start code).
+    pub fn parse(unit: &[u8]) -> Result<Self> {
+        let rbsp = unit_rbsp(unit, UnitType::VPS)?;
+        let r = &mut BitReader::new(&rbsp);
+        let id = r.bits(4)? as u8;
+        r.skip(2)?; // vps_base_layer_internal_flag, vps_base_layer_available_flag
+        let max_layers = r.bits(6)? as u8 + 1;
+        let max_sub_layers_minus1 = r.bits(3)? as u8;
+        if max_sub_layers_minus1 > 6 {
+            return Err(Error::OutOfRange("vps_max_sub_layers_minus1"));
+        }
+        let temporal_id_nesting = r.bit()?;
+        r.skip(16)?; // vps_reserved_0xffff_16bits
+        let ptl = ProfileTierLevel::read(r, max_sub_layers_minus1)?;
+        let ordering = SubLayerOrdering::read(r, max_sub_layers_minus1)?;
+        let max_layer_id = r.bits(6)? as usize;
+        let num_layer_sets = r.ue_max(1023, "vps_num_layer_sets_minus1")? as usize + 1;
+        r.skip((num_layer_sets - 1) * (max_layer_id + 1))?;
+        let timing = if r.bit()? {
+            Some(Timing::read(r)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            id,
+            max_layers,
+            max_sub_layers: max_sub_layers_minus1 + 1,
+            temporal_id_nesting,
+            ptl,
+            ordering,
+            timing,
+        })
+    }
+}
+
+fn skip_scaling_list_data(r: &mut BitReader) -> Result {
+    for size_id in 0..4 {
+        let step = if size_id == 3 { 3 } else { 1 };
+        for _ in (0..6).step_by(step) {
+            if !r.bit()? {
+                r.ue()?; // scaling_list_pred_matrix_id_delta
+            } else {
+                let coef_num = 64.min(1 << (4 + (size_id << 1)));
+                if size_id > 1 {
+                    r.se()?; // scaling_list_dc_coef_minus8
+                }
+                for _ in 0..coef_num {
+                    r.se()?; // scaling_list_delta_coef
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parses `st_ref_pic_set(idx)` and returns `NumDeltaPocs[idx]`.
+fn read_st_ref_pic_set(r: &mut BitReader, idx: usize, num_delta_pocs: &[u32]) -> Result<u32> {
+    if idx != 0 && r.bit()? {
+        // inter_ref_pic_set_prediction_flag, delta_idx_minus1 is only present in slice headers
+        r.skip(1)?; // delta_rps_sign
+        r.ue()?; // abs_delta_rps_minus1
+        let ref_num = num_delta_pocs[idx - 1];
+        let mut res = 0;
+        for _ in 0..=ref_num {
+            let used_by_curr_pic = r.bit()?;
+            let use_delta = if used_by_curr_pic { true } else { r.bit()? };
+            if used_by_curr_pic || use_delta {
+                res += 1;
+            }
+        }
+        Ok(res)
+    } else {
+        let negative = r.ue_max(16, "num_negative_pics")?;
+        let positive = r.ue_max(16, "num_positive_pics")?;
+        for _ in 0..negative + positive {
+            r.ue()?; // delta_poc_sX_minus1
+            r.skip(1)?; // used_by_curr_pic_sX_flag
+        }
+        Ok(negative + positive)
+    }
+}
+
+fn skip_sub_layer_hrd(r: &mut BitReader, cpb_cnt: u32, sub_pic: bool) -> Result {
+    for _ in 0..cpb_cnt {
+        r.ue()?; // bit_rate_value_minus1
+        r.ue()?; // cpb_size_value_minus1
+        if sub_pic {
+            r.ue()?; // cpb_size_du_value_minus1
+            r.ue()?; // bit_rate_du_value_minus1
+        }
+        r.skip(1)?; // cbr_flag
+    }
+    Ok(())
+}
+
+fn skip_hrd_params(r: &mut BitReader, max_sub_layers_minus1: u8) -> Result {
+    let nal = r.bit()?;
+    let vcl = r.bit()?;
+    let mut sub_pic = false;
+    if nal || vcl {
+        sub_pic = r.bit()?;
+        if sub_pic {
+            r.skip(8 + 5 + 1 + 5)?;
+        }
+        r.skip(8)?; // bit_rate_scale, cpb_size_scale
+        if sub_pic {
+            r.skip(4)?; // cpb_size_du_scale
+        }
+        r.skip(15)?;
+    }
+    for _ in 0..=max_sub_layers_minus1 {
+        let fixed_pic_rate_general = r.bit()?;
+        let fixed_pic_rate_within_cvs = if fixed_pic_rate_general {
+            true
+        } else {
+            r.bit()?
+        };
+        let mut low_delay = false;
+        if fixed_pic_rate_within_cvs {
+            r.ue()?; // elemental_duration_in_tc_minus1
+        } else {
+            low_delay = r.bit()?;
+        }
+        let cpb_cnt = if low_delay {
+            1
+        } else {
+            r.ue_max(31, "cpb_cnt_minus1")? + 1
+        };
+        if nal {
+            skip_sub_layer_hrd(r, cpb_cnt, sub_pic)?;
+        }
+        if vcl {
+            skip_sub_layer_hrd(r, cpb_cnt, sub_pic)?;
+        }
+    }
+    Ok(())
+}
+
+/// `Sqrt(MaxLumaPs * 8)` of the highest level, limit of picture width and height, A.4.1.
+const MAX_PIC_SIZE: u32 = 16_888;
+
+/// Conformance or default display window offsets in luma samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Window {
+    pub left: u32,
+    pub right: u32,
+    pub top: u32,
+    pub bottom: u32,
+}
+
+impl Window {
+    /// Reads offsets in chroma samples, window must leave at least one luma sample of `sps`.
+    fn read(r: &mut BitReader, sps: &Sps, name: &'static str) -> Result<Self> {
+        let (unit_x, unit_y) = sps.sub_size();
+        let (left, right, top, bottom) = (r.ue()?, r.ue()?, r.ue()?, r.ue()?);
+        let fits = |a: u32, b: u32, unit: u32, size: u32| {
+            (a as u64 + b as u64) * (unit as u64) < size as u64
+        };
+        if !fits(left, right, unit_x, sps.pic_width_in_luma_samples)
+            || !fits(top, bottom, unit_y, sps.pic_height_in_luma_samples)
+        {
+            return Err(Error::OutOfRange(name));
+        }
+        Ok(Self {
+            left: left * unit_x,
+            right: right * unit_x,
+            top: top * unit_y,
+            bottom: bottom * unit_y,
+        })
+    }
+}
+
+/// Video usability information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Vui {
+    /// Sample aspect ratio (0, 0 if unspecified).
+    pub sar: (u16, u16),
+    pub overscan_appropriate: Option<bool>,
+    pub video_signal: Option<VideoSignal>,
+    pub chroma_loc: Option<(u32, u32)>,
+    pub field_seq: bool,
+    pub frame_field_info_present: bool,
+    pub default_display_window: Option<Window>,
+    pub timing: Option<Timing>,
+    pub min_spatial_segmentation_idc: u32,
+}
+
+impl Vui {
+    fn read(r: &mut BitReader, sps: &Sps) -> Result<Self> {
+        let mut res = Self::default();
+        if r.bit()? {
+            res.sar = nal::read_sar(r)?;
+        }
+        if r.bit()? {
+            res.overscan_appropriate = Some(r.bit()?);
+        }
+        if r.bit()? {
+            res.video_signal = Some(VideoSignal::read(r)?);
+        }
+        if r.bit()? {
+            res.chroma_loc = Some((
+                r.ue_max(5, "chroma_sample_loc_type")?,
+                r.ue_max(5, "chroma_sample_loc_type")?,
+            ));
+        }
+        r.skip(1)?; // neutral_chroma_indication_flag
+        res.field_seq = r.bit()?;
+        res.frame_field_info_present = r.bit()?;
+        if r.bit()? {
+            res.default_display_window = Some(Window::read(r, sps, "def_disp_win_offset")?);
+        }
+        if r.bit()? {
+            res.timing = Some(Timing::read(r)?);
+            if r.bit()? {
+                skip_hrd_params(r, sps.max_sub_layers - 1)?;
+            }
+        }
+        if r.bit()? {
+            r.skip(3)?; // tiles_fixed_structure, motion_vectors_over_pic_boundaries, restricted_ref_pic_lists
+            res.min_spatial_segmentation_idc = r.ue_max(4095, "min_spatial_segmentation_idc")?;
+            r.ue()?; // max_bytes_per_pic_denom
+            r.ue()?; // max_bits_per_min_cu_denom
+            r.ue()?; // log2_max_mv_length_horizontal
+            r.ue()?; // log2_max_mv_length_vertical
+        }
+        Ok(res)
+    }
+}
+
+/// Sequence parameter set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sps {
+    pub vps_id: u8,
+    pub max_sub_layers: u8,
+    pub temporal_id_nesting: bool,
+    pub ptl: ProfileTierLevel,
+    pub id: u32,
+    pub chroma_format_idc: u8,
+    pub separate_colour_plane: bool,
+    pub pic_width_in_luma_samples: u32,
+    pub pic_height_in_luma_samples: u32,
+    /// Conformance window in luma samples.
+    pub conformance_window: Option<Window>,
+    pub bit_depth_luma: u8,
+    pub bit_depth_chroma: u8,
+    pub log2_max_pic_order_cnt_lsb: u8,
+    pub ordering: SubLayerOrdering,
+    pub log2_min_luma_coding_block_size: u8,
+    pub log2_diff_max_min_luma_coding_block_size: u8,
+    pub scaling_list_enabled: bool,
+    pub amp_enabled: bool,
+    pub sample_adaptive_offset_enabled: bool,
+    pub pcm_enabled: bool,
+    pub num_short_term_ref_pic_sets: u32,
+    pub long_term_ref_pics_present: bool,
+    pub temporal_mvp_enabled: bool,
+    pub strong_intra_smoothing_enabled: bool,
+    pub vui: Option<Vui>,
+}
+
+impl Sps {
+    /// Parses SPS NAL unit (with NAL header, without start code).
+    pub fn parse(unit: &[u8]) -> Result<Self> {
+        let rbsp = unit_rbsp(unit, UnitType::SPS)?;
+        let r = &mut BitReader::new(&rbsp);
+
+        let vps_id = r.bits(4)? as u8;
+        let max_sub_layers_minus1 = r.bits(3)? as u8;
+        if max_sub_layers_minus1 > 6 {
+            return Err(Error::OutOfRange("sps_max_sub_layers_minus1"));
+        }
+        let temporal_id_nesting = r.bit()?;
+        let ptl = ProfileTierLevel::read(r, max_sub_layers_minus1)?;
+        let id = r.ue_max(15, "sps_seq_parameter_set_id")?;
+        let chroma_format_idc = r.ue_max(3, "chroma_format_idc")? as u8;
+        let separate_colour_plane = if chroma_format_idc == 3 {
+            r.bit()?
+        } else {
+            false
+        };
+        let mut res = Self {
+            vps_id,
+            max_sub_layers: max_sub_layers_minus1 + 1,
+            temporal_id_nesting,
+            ptl,
+            id,
+            chroma_format_idc,
+            separate_colour_plane,
+            pic_width_in_luma_samples: r.ue_max(MAX_PIC_SIZE, "pic_width_in_luma_samples")?,
+            pic_height_in_luma_samples: r.ue_max(MAX_PIC_SIZE, "pic_height_in_luma_samples")?,
+            conformance_window: None,
+            bit_depth_luma: 8,
+            bit_depth_chroma: 8,
+            log2_max_pic_order_cnt_lsb: 4,
+            ordering: Default::default(),
+            log2_min_luma_coding_block_size: 3,
+            log2_diff_max_min_luma_coding_block_size: 0,
+            scaling_list_enabled: false,
+            amp_enabled: false,
+            sample_adaptive_offset_enabled: false,
+            pcm_enabled: false,
+            num_short_term_ref_pic_sets: 0,
+            long_term_ref_pics_present: false,
+            temporal_mvp_enabled: false,
+            strong_intra_smoothing_enabled: false,
+            vui: None,
+        };
+        if r.bit()? {
+            res.conformance_window = Some(Window::read(r, &res, "conf_win_offset")?);
+        }
+        res.bit_depth_luma = r.ue_max(8, "bit_depth_luma_minus8")? as u8 + 8;
+        res.bit_depth_chroma = r.ue_max(8, "bit_depth_chroma_minus8")? as u8 + 8;
+        res.log2_max_pic_order_cnt_lsb =
+            r.ue_max(12, "log2_max_pic_order_cnt_lsb_minus4")? as u8 + 4;
+        res.ordering = SubLayerOrdering::read(r, max_sub_layers_minus1)?;
+        res.log2_min_luma_coding_block_size =
+            r.ue_max(3, "log2_min_luma_coding_block_size_minus3")? as u8 + 3;
+        res.log2_diff_max_min_luma_coding_block_size =
+            r.ue_max(3, "log2_diff_max_min_luma_coding_block_size")? as u8;
+        r.ue()?; // log2_min_luma_transform_block_size_minus2
+        r.ue()?; // log2_diff_max_min_luma_transform_block_size
+        r.ue()?; // max_transform_hierarchy_depth_inter
+        r.ue()?; // max_transform_hierarchy_depth_intra
+        res.scaling_list_enabled = r.bit()?;
+        if res.scaling_list_enabled && r.bit()? {
+            skip_scaling_list_data(r)?;
+        }
+        res.amp_enabled = r.bit()?;
+        res.sample_adaptive_offset_enabled = r.bit()?;
+        res.pcm_enabled = r.bit()?;
+        if res.pcm_enabled {
+            r.skip(8)?; // pcm_sample_bit_depth_luma_minus1, pcm_sample_bit_depth_chroma_minus1
+            r.ue()?; // log2_min_pcm_luma_coding_block_size_minus3
+            r.ue()?; // log2_diff_max_min_pcm_luma_coding_block_size
+            r.skip(1)?; // pcm_loop_filter_disabled_flag
+        }
+        res.num_short_term_ref_pic_sets = r.ue_max(64, "num_short_term_ref_pic_sets")?;
+        let mut num_delta_pocs = [0u32; 64];
+        for i in 0..res.num_short_term_ref_pic_sets as usize {
+            num_delta_pocs[i] = read_st_ref_pic_set(r, i, &num_delta_pocs)?;
+        }
+        res.long_term_ref_pics_present = r.bit()?;
+        if res.long_term_ref_pics_present {
+            let n = r.ue_max(32, "num_long_term_ref_pics_sps")? as usize;
+            r.skip(n * (res.log2_max_pic_order_cnt_lsb as usize + 1))?;
+        }
+        res.temporal_mvp_enabled = r.bit()?;
+        res.strong_intra_smoothing_enabled = r.bit()?;
+        if r.bit()? {
+            res.vui = Some(Vui::read(r, &res)?);
+        }
+        Ok(res)
+    }
+
+    /// `SubWidthC` and `SubHeightC`.
+    pub const fn sub_size(&self) -> (u32, u32) {
+        if self.separate_colour_plane {
+            return (1, 1);
+        }
+        match self.chroma_format_idc {
+            1 => (2, 2),
+            2 => (2, 1),
+            _ => (1, 1),
+        }
+    }
+
+    /// Width after conformance window cropping.
+    pub const fn width(&self) -> u32 {
+        match self.conformance_window {
+            Some(w) => self
+                .pic_width_in_luma_samples
+                .saturating_sub(w.left + w.right),
+            None => self.pic_width_in_luma_samples,
+        }
+    }
+
+    /// Height after conformance window cropping.
+    pub const fn height(&self) -> u32 {
+        match self.conformance_window {
+            Some(w) => self
+                .pic_height_in_luma_samples
+                .saturating_sub(w.top + w.bottom),
+            None => self.pic_height_in_luma_samples,
+        }
+    }
+
+    #[inline]
+    pub const fn video_signal(&self) -> Option<VideoSignal> {
+        match self.vui {
+            Some(vui) => vui.video_signal,
+            None => None,
+        }
+    }
+}
+
+/// Picture parameter set. Parsing stops after `entropy_coding_sync_enabled_flag`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pps {
+    pub id: u32,
+    pub sps_id: u32,
+    pub dependent_slice_segments_enabled: bool,
+    pub output_flag_present: bool,
+    pub num_extra_slice_header_bits: u8,
+    pub sign_data_hiding_enabled: bool,
+    pub cabac_init_present: bool,
+    pub num_ref_idx_l0_default_active: u32,
+    pub num_ref_idx_l1_default_active: u32,
+    pub init_qp: i32,
+    pub constrained_intra_pred: bool,
+    pub transform_skip_enabled: bool,
+    pub diff_cu_qp_delta_depth: Option<u32>,
+    pub cb_qp_offset: i32,
+    pub cr_qp_offset: i32,
+    pub slice_chroma_qp_offsets_present: bool,
+    pub weighted_pred: bool,
+    pub weighted_bipred: bool,
+    pub transquant_bypass_enabled: bool,
+    pub tiles_enabled: bool,
+    pub entropy_coding_sync_enabled: bool,
+}
+
+impl Pps {
+    pub fn parse(unit: &[u8]) -> Result<Self> {
+        let rbsp = unit_rbsp(unit, UnitType::PPS)?;
+        let r = &mut BitReader::new(&rbsp);
+        Ok(Self {
+            id: r.ue_max(63, "pps_pic_parameter_set_id")?,
+            sps_id: r.ue_max(15, "pps_seq_parameter_set_id")?,
+            dependent_slice_segments_enabled: r.bit()?,
+            output_flag_present: r.bit()?,
+            num_extra_slice_header_bits: r.bits(3)? as u8,
+            sign_data_hiding_enabled: r.bit()?,
+            cabac_init_present: r.bit()?,
+            num_ref_idx_l0_default_active: r.ue_max(14, "num_ref_idx_l0_default_active_minus1")?
+                + 1,
+            num_ref_idx_l1_default_active: r.ue_max(14, "num_ref_idx_l1_default_active_minus1")?
+                + 1,
+            // QpBdOffsetY is up to 48 for 16-bit luma
+            init_qp: 26 + r.se_range(-26 - 48, 25, "init_qp_minus26")?,
+            constrained_intra_pred: r.bit()?,
+            transform_skip_enabled: r.bit()?,
+            diff_cu_qp_delta_depth: if r.bit()? { Some(r.ue()?) } else { None },
+            cb_qp_offset: r.se_range(-12, 12, "pps_cb_qp_offset")?,
+            cr_qp_offset: r.se_range(-12, 12, "pps_cr_qp_offset")?,
+            slice_chroma_qp_offsets_present: r.bit()?,
+            weighted_pred: r.bit()?,
+            weighted_bipred: r.bit()?,
+            transquant_bypass_enabled: r.bit()?,
+            tiles_enabled: r.bit()?,
+            entropy_coding_sync_enabled: r.bit()?,
+        })
+    }
+}
+
+/// NAL unit array of `hvcC`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HvcArray {
+    /// All NAL units of this type are in the array (required for `hvc1`).
+    pub completeness: bool,
+    pub unit_type: UnitType,
+    pub units: Vec<Vec<u8>>,
+}
+
+/// `HEVCDecoderConfigurationRecord` (`hvcC` atom payload).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HvcConfig {
+    pub ptl: ProfileTierLevel,
+    pub min_spatial_segmentation_idc: u16,
+    /// 0 unknown, 1 slices, 2 tiles, 3 wavefront.
+    pub parallelism_type: u8,
+    pub chroma_format_idc: u8,
+    pub bit_depth_luma: u8,
+    pub bit_depth_chroma: u8,
+    /// Frames per 256 seconds, 0 if unspecified.
+    pub avg_frame_rate: u16,
+    pub constant_frame_rate: u8,
+    pub num_temporal_layers: u8,
+    pub temporal_id_nested: bool,
+    pub nal_unit_header_length: u8,
+    pub arrays: Vec<HvcArray>,
+}
+
+impl HvcConfig {
+    /// Builds configuration from parameter sets. Header fields are derived from first SPS and PPS.
+    pub fn with_param_sets(
+        vps: &[&[u8]],
+        sps: &[&[u8]],
+        pps: &[&[u8]],
+        nal_unit_header_length: u8,
+    ) -> Result<Self> {
+        nal::check_header_length(nal_unit_header_length as usize)?;
+        let Some(first) = sps.first() else {
+            return Err(Error::OutOfRange("numNalus"));
+        };
+        let parsed = Sps::parse(first)?;
+        let min_spatial_segmentation_idc = parsed
+            .vui
+            .map_or(0, |vui| vui.min_spatial_segmentation_idc as u16);
+        let mut parallelism_type = 0;
+        if min_spatial_segmentation_idc != 0 {
+            if let Some(pps) = pps.first() {
+                let pps = Pps::parse(pps)?;
+                parallelism_type = match (pps.entropy_coding_sync_enabled, pps.tiles_enabled) {
+                    (true, true) => 0,
+                    (true, false) => 3,
+                    (false, true) => 2,
+                    (false, false) => 1,
+                };
+            }
+        }
+        let array = |unit_type, units: &[&[u8]]| HvcArray {
+            completeness: true,
+            unit_type,
+            units: units.iter().map(|u| u.to_vec()).collect(),
+        };
+        Ok(Self {
+            ptl: parsed.ptl,
+            min_spatial_segmentation_idc,
+            parallelism_type,
+            chroma_format_idc: parsed.chroma_format_idc,
+            bit_depth_luma: parsed.bit_depth_luma,
+            bit_depth_chroma: parsed.bit_depth_chroma,
+            avg_frame_rate: 0,
+            constant_frame_rate: 0,
+            num_temporal_layers: parsed.max_sub_layers,
+            temporal_id_nested: parsed.temporal_id_nesting,
+            nal_unit_header_length,
+            arrays: vec![
+                array(UnitType::VPS, vps),
+                array(UnitType::SPS, sps),
+                array(UnitType::PPS, pps),
+            ],
+        })
+    }
+
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let r = &mut ByteReader { data };
+        let version = r.u8()?;
+        if version != 1 {
+            return Err(Error::UnsupportedVersion(version));
+        }
+        let b = r.u8()?;
+        let profile_compatibility_flags = u32::from_be_bytes(r.bytes(4)?.try_into().unwrap());
+        let mut constraint_indicator_flags = 0u64;
+        for &b in r.bytes(6)? {
+            constraint_indicator_flags = (constraint_indicator_flags << 8) | b as u64;
+        }
+        let ptl = ProfileTierLevel {
+            profile_space: b >> 6,
+            tier: b & 0x20 != 0,
+            profile_idc: b & 0x1f,
+            profile_compatibility_flags,
+            constraint_indicator_flags,
+            level_idc: r.u8()?,
+        };
+        let min_spatial_segmentation_idc = r.u16()? & 0x0fff;
+        let parallelism_type = r.u8()? & 0x3;
+        let chroma_format_idc = r.u8()? & 0x3;
+        let bit_depth_luma = (r.u8()? & 0x7) + 8;
+        let bit_depth_chroma = (r.u8()? & 0x7) + 8;
+        let avg_frame_rate = r.u16()?;
+        let b = r.u8()?;
+        let num_arrays = r.u8()?;
+        let mut arrays = Vec::with_capacity(num_arrays as usize);
+        for _ in 0..num_arrays {
+            let t = r.u8()?;
+            let count = r.u16()?;
+            arrays.push(HvcArray {
+                completeness: t & 0x80 != 0,
+                unit_type: UnitType(t & 0x3f),
+                units: r.units(count as usize)?,
+            });
+        }
+        Ok(Self {
+            ptl,
+            min_spatial_segmentation_idc,
+            parallelism_type,
+            chroma_format_idc,
+            bit_depth_luma,
+            bit_depth_chroma,
+            avg_frame_rate,
+            constant_frame_rate: b >> 6,
+            num_temporal_layers: (b >> 3) & 0x7,
+            temporal_id_nested: b & 0x4 != 0,
+            nal_unit_header_length: (b & 0x3) + 1,
+            arrays,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        nal::check_header_length(self.nal_unit_header_length as usize)?;
+        if self.arrays.len() > 255 {
+            return Err(Error::OutOfRange("numOfArrays"));
+        }
+        let ptl = &self.ptl;
+        let mut res = Vec::with_capacity(128);
+        res.push(1);
+        res.push((ptl.profile_space << 6) | ((ptl.tier as u8) << 5) | (ptl.profile_idc & 0x1f));
+        res.extend_from_slice(&ptl.profile_compatibility_flags.to_be_bytes());
+        res.extend_from_slice(&ptl.constraint_indicator_flags.to_be_bytes()[2..]);
+        res.push(ptl.level_idc);
+        res.extend_from_slice(&(0xf000 | self.min_spatial_segmentation_idc).to_be_bytes());
+        res.push(0xfc | self.parallelism_type);
+        res.push(0xfc | self.chroma_format_idc);
+        res.push(0xf8 | self.bit_depth_luma.wrapping_sub(8) & 0x7);
+        res.push(0xf8 | self.bit_depth_chroma.wrapping_sub(8) & 0x7);
+        res.extend_from_slice(&self.avg_frame_rate.to_be_bytes());
+        res.push(
+            (self.constant_frame_rate << 6)
+                | ((self.num_temporal_layers & 0x7) << 3)
+                | ((self.temporal_id_nested as u8) << 2)
+                | (self.nal_unit_header_length - 1),
+        );
+        res.push(self.arrays.len() as u8);
+        for array in &self.arrays {
+            if array.units.len() > u16::MAX as usize {
+                return Err(Error::OutOfRange("numNalus"));
+            }
+            res.push(((array.completeness as u8) << 7) | (array.unit_type.0 & 0x3f));
+            res.extend_from_slice(&(array.units.len() as u16).to_be_bytes());
+            nal::write_units(&mut res, &array.units)?;
+        }
+        Ok(res)
+    }
+
+    /// Codec string as defined in ISO/IEC 14496-15 Annex E, e.g. `hvc1.1.6.L93.B0`.
+    pub fn codec_string(&self) -> String {
+        let ptl = &self.ptl;
+        let space = ["", "A", "B", "C"][ptl.profile_space as usize & 0x3];
+        let tier = if ptl.tier { 'H' } else { 'L' };
+        let mut res = format!(
+            "hvc1.{space}{}.{:X}.{tier}{}",
+            ptl.profile_idc,
+            ptl.profile_compatibility_flags.reverse_bits(),
+            ptl.level_idc
+        );
+        let constraints = &ptl.constraint_indicator_flags.to_be_bytes()[2..];
+        let len = constraints
+            .iter()
+            .rposition(|&b| b != 0)
+            .map_or(0, |i| i + 1);
+        for b in &constraints[..len] {
+            res.push_str(&format!(".{b:X}"));
+        }
+        res
+    }
+
+    /// Parameter sets in order expected by `cm::VideoFormatDesc::with_hevc_param_sets`
+    /// (VPS, SPS, PPS, then SEI).
+    pub fn param_sets(&self) -> impl Iterator<Item = &[u8]> {
+        let order = [
+            UnitType::VPS,
+            UnitType::SPS,
+            UnitType::PPS,
+            UnitType::PREFIX_SEI,
+            UnitType::SUFFIX_SEI,
+        ];
+        order.into_iter().flat_map(move |t| {
+            self.arrays
+                .iter()
+                .filter(move |a| a.unit_type == t)
+                .flat_map(|a| a.units.iter().map(|u| u.as_slice()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cm::nal::{self, hevc};
+
+    const VPS: [u8; 24] = [
+        0x40, 0x01, 0x0c, 0x01, 0xff, 0xff, 0x01, 0x60, 0x00, 0x00, 0x03, 0x00, 0x90, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x03, 0x00, 0x5d, 0x95, 0x98, 0x09,
+    ];
+    const SPS: [u8; 41] = [
+        0x42, 0x01, 0x01, 0x01, 0x60, 0x00, 0x00, 0x03, 0x00, 0x90, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x03, 0x00, 0x5d, 0xa0, 0x02, 0x80, 0x80, 0x2d, 0x16, 0x59, 0x59, 0xa4, 0x93, 0x2b, 0xc0,
+        0x5a, 0x70, 0x80, 0x00, 0x01, 0xf4, 0x80, 0x00, 0x3a, 0x98, 0x04,
+    ];
+    const PPS: [u8; 7] = [0x44, 0x01, 0xc1, 0x72, 0xb4, 0x62, 0x40];
+
+    #[test]
+    fn vps() {
+        let vps = hevc::Vps::parse(&VPS).unwrap();
+        assert_eq!(vps.id, 0);
+        assert_eq!(vps.max_sub_layers, 1);
+        assert_eq!(vps.ptl.profile_idc, hevc::ProfileTierLevel::MAIN);
+        assert_eq!(vps.ptl.level_idc, 93);
+        assert!(vps.ptl.progressive_source());
+    }
+
+    #[test]
+    fn malformed() {
+        use nal::tests::{se, ue};
+
+        // Main profile 4:2:0 SPS up to conformance window
+        let sps = |width: u32, height: u32, window: [u32; 4]| {
+            let mut bits = String::from("0000 000 1");
+            bits += &format!("00 0 00001 {:032b} {:048b} {:08b}", 1 << 30, 0, 93);
+            bits += &[ue(0), ue(1), ue(width), ue(height), "1".into()].concat();
+            bits += &window.map(ue).concat();
+            hevc::Sps::parse(&nal::tests::unit(&[0x42, 0x01], &bits))
+        };
+        assert_eq!(
+            sps(u32::MAX - 1, 64, [0; 4]),
+            Err(nal::Error::OutOfRange("pic_width_in_luma_samples"))
+        );
+        assert_eq!(
+            sps(64, 16_889, [0; 4]),
+            Err(nal::Error::OutOfRange("pic_height_in_luma_samples"))
+        );
+        for window in [[32, 0, 0, 0], [0, 0, 16, 16], [u32::MAX - 1, 0, 0, 0]] {
+            assert_eq!(
+                sps(64, 64, window),
+                Err(nal::Error::OutOfRange("conf_win_offset"))
+            );
+        }
+        // valid window, parsing fails later at truncated data
+        assert_eq!(sps(64, 64, [31, 0, 0, 0]), Err(nal::Error::UnexpectedEnd));
+
+        let pps = |init_qp: i32, cb: i32| {
+            let mut bits = [ue(0), ue(0), "00 000 00".into(), ue(0), ue(0)].concat();
+            bits += &[se(init_qp), "000".into(), se(cb), se(0)].concat();
+            hevc::Pps::parse(&nal::tests::unit(&[0x44, 0x01], &bits))
+        };
+        assert_eq!(
+            pps(i32::MAX, 0),
+            Err(nal::Error::OutOfRange("init_qp_minus26"))
+        );
+        assert_eq!(pps(-75, 0), Err(nal::Error::OutOfRange("init_qp_minus26")));
+        assert_eq!(pps(0, -13), Err(nal::Error::OutOfRange("pps_cb_qp_offset")));
+    }
+
+    #[test]
+    fn sps() {
+        let sps = hevc::Sps::parse(&SPS).unwrap();
+        assert_eq!(sps.ptl.profile_idc, hevc::ProfileTierLevel::MAIN);
+        assert_eq!(sps.chroma_format_idc, 1);
+        assert_eq!(sps.width(), 1280);
+        assert_eq!(sps.height(), 720);
+        assert_eq!(sps.bit_depth_luma, 8);
+
+        let timing = sps.vui.unwrap().timing.unwrap();
+        assert_eq!(
+            (timing.num_units_in_tick, timing.time_scale),
+            (1001, 30_000)
+        );
+
+        assert_eq!(
+            hevc::Sps::parse(&VPS),
+            Err(nal::Error::UnexpectedUnitType(32))
+        );
+    }
+
+    #[test]
+    fn pps() {
+        let pps = hevc::Pps::parse(&PPS).unwrap();
+        assert_eq!(pps.id, 0);
+        assert_eq!(pps.sps_id, 0);
+        assert!(!pps.tiles_enabled);
+    }
+
+    #[test]
+    fn hvcc() {
+        let cfg = hevc::HvcConfig::with_param_sets(&[&VPS], &[&SPS], &[&PPS], 4).unwrap();
+        assert_eq!(cfg.ptl.level_idc, 93);
+        assert_eq!(cfg.num_temporal_layers, 1);
+        assert_eq!(cfg.codec_string(), "hvc1.1.6.L93.90");
+
+        let bytes = cfg.to_bytes().unwrap();
+        assert_eq!(bytes.len(), 23 + 3 * 5 + VPS.len() + SPS.len() + PPS.len());
+        assert_eq!(bytes[0], 1);
+        assert_eq!(bytes[1], 0x01);
+        assert_eq!(bytes[21] & 0x3, 3);
+        assert_eq!(hevc::HvcConfig::parse(&bytes).unwrap(), cfg);
+
+        let sets: Vec<_> = cfg.param_sets().collect();
+        assert_eq!(sets, [&VPS[..], &SPS, &PPS]);
+    }
+}
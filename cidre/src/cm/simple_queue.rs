@@ -10,15 +10,19 @@ define_cf_type!(
 pub mod err {
     use crate::os::Error;
     /// An allocation failed.
+    #[doc(alias = "kCMSimpleQueueError_AllocationFailed")]
     pub const ALLOC_FAILED: Error = Error::new_unchecked(-12770);
 
     /// NULL or 0 was passed for a required parameter.
+    #[doc(alias = "kCMSimpleQueueError_RequiredParameterMissing")]
     pub const REQUIRED_PARAMETER_MISSING: Error = Error::new_unchecked(-12771);
 
     /// An out-of-range value was passed for a parameter with a restricted valid range.
+    #[doc(alias = "kCMSimpleQueueError_ParameterOutOfRange")]
     pub const PARAMETER_OUT_OF_RANGE: Error = Error::new_unchecked(-12772);
 
     /// Operation failed because queue was full.
+    #[doc(alias = "kCMSimpleQueueError_QueueIsFull")]
     pub const QUEUE_IS_FULL: Error = Error::new_unchecked(-12773);
 }
 
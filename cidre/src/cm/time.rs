@@ -38,25 +38,196 @@ pub struct Time {
     pub epoch: TimeEpoch,
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(u32)]
 pub enum TimeRoundingMethod {
+    /// Round towards zero if abs(fraction) is < 0.5, away from 0 if abs(fraction) is >= 0.5.
     RoundHalfAwayFromZero = 1,
+
+    /// Round towards zero if fraction is != 0.
     RoundTowardZero = 2,
+
+    /// Round away from zero if abs(fraction) is > 0.
     RoundAwayFromZero = 3,
+
+    /// Use `RoundTowardZero` if converting from larger to smaller scale,
+    /// but use `RoundAwayFromZero` if converting from smaller to larger scale.
+    /// Also, never round a negative number down to 0; always return
+    /// the smallest magnitude negative time in this case (-1/new_scale).
     QuickTime = 4,
+
+    /// Round towards +infinity if fraction is != 0.
     RoundTowardPositiveInfinity = 5,
+
+    /// Round towards -infinity if fraction is != 0.
     RoundTowardNegativeInfinity = 6,
 }
 
+impl TimeRoundingMethod {
+    #[doc(alias = "kCMTimeRoundingMethod_Default")]
+    pub const DEFAULT: Self = Self::RoundHalfAwayFromZero;
+}
+
 impl Default for TimeRoundingMethod {
     #[inline]
     fn default() -> Self {
-        Self::RoundAwayFromZero
+        Self::DEFAULT
     }
 }
 
+/// Divides `num` by positive `den` using `method`.
+///
+/// Returns quotient and whether any rounding took place.
+const fn div_round(num: i128, den: i128, method: TimeRoundingMethod) -> (i128, bool) {
+    let q = num / den;
+    let r = num % den;
+    if r == 0 {
+        return (q, false);
+    }
+    let away = if num < 0 { q - 1 } else { q + 1 };
+    let q = match method {
+        TimeRoundingMethod::RoundHalfAwayFromZero => {
+            if r.unsigned_abs() * 2 >= den.unsigned_abs() {
+                away
+            } else {
+                q
+            }
+        }
+        TimeRoundingMethod::RoundTowardZero | TimeRoundingMethod::QuickTime => q,
+        TimeRoundingMethod::RoundAwayFromZero => away,
+        TimeRoundingMethod::RoundTowardPositiveInfinity => {
+            if num > 0 {
+                away
+            } else {
+                q
+            }
+        }
+        TimeRoundingMethod::RoundTowardNegativeInfinity => {
+            if num < 0 {
+                away
+            } else {
+                q
+            }
+        }
+    };
+    (q, true)
+}
+
+const fn gcd(mut a: i128, mut b: i128) -> i128 {
+    a = a.abs();
+    b = b.abs();
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
 impl Time {
+    #[doc(alias = "kCMTimeMaxTimescale")]
+    pub const MAX_SCALE: TimeScale = 1_000_000_000;
+
+    /// Builds numeric time equal to `num / den` seconds (`den` must be positive) with `scale`.
+    ///
+    /// If value overflows and `shrink` is set, scale is repeatedly halved until
+    /// value fits. Otherwise (or when scale is already 1) result is +/- infinity.
+    const fn with_ratio(
+        num: i128,
+        den: i128,
+        mut scale: TimeScale,
+        method: TimeRoundingMethod,
+        shrink: bool,
+        mut rounded: bool,
+        epoch: TimeEpoch,
+    ) -> Time {
+        loop {
+            let (value, r) = div_round(num * scale as i128, den, method);
+            if value >= TimeValue::MIN as i128 && value <= TimeValue::MAX as i128 {
+                rounded |= r;
+                let flags = if rounded {
+                    TimeFlags(TimeFlags::VALID.0 | TimeFlags::HAS_BEEN_ROUNDED.0)
+                } else {
+                    TimeFlags::VALID
+                };
+                return Time {
+                    value: value as TimeValue,
+                    scale,
+                    flags,
+                    epoch,
+                };
+            }
+            if !shrink || scale <= 1 {
+                return Self::with_inf_sign(num < 0);
+            }
+            scale /= 2;
+            rounded = true;
+        }
+    }
+
+    #[inline]
+    const fn with_inf_sign(negative: bool) -> Time {
+        if negative {
+            Self::neg_infinity()
+        } else {
+            Self::infinity()
+        }
+    }
+
+    /// Returns 1 for positive infinity, -1 for negative infinity and 0 otherwise.
+    #[inline]
+    const fn inf_sign(&self) -> i32 {
+        if self.is_pos_infinity() {
+            1
+        } else if self.is_neg_infinity() {
+            -1
+        } else {
+            0
+        }
+    }
+
+    #[inline]
+    const fn is_rounded(&self) -> bool {
+        (self.flags.0 & TimeFlags::HAS_BEEN_ROUNDED.0) != 0
+    }
+
+    /// Returns `self + sign * rhs` for valid numeric operands.
+    const fn sum(self, rhs: Time, sign: i128, epoch: TimeEpoch) -> Time {
+        if self.scale <= 0 || rhs.scale <= 0 {
+            return Self::invalid();
+        }
+        let rounded = self.is_rounded() || rhs.is_rounded();
+        if self.scale == rhs.scale {
+            let num = self.value as i128 + sign * rhs.value as i128;
+            return Self::with_ratio(
+                num,
+                self.scale as i128,
+                self.scale,
+                TimeRoundingMethod::DEFAULT,
+                true,
+                rounded,
+                epoch,
+            );
+        }
+        let (l, r) = (self.scale as i128, rhs.scale as i128);
+        let lcm = l / gcd(l, r) * r;
+        let scale = if lcm > Self::MAX_SCALE as i128 {
+            Self::MAX_SCALE
+        } else {
+            lcm as TimeScale
+        };
+        let num = self.value as i128 * r + sign * rhs.value as i128 * l;
+        Self::with_ratio(
+            num,
+            l * r,
+            scale,
+            TimeRoundingMethod::DEFAULT,
+            true,
+            rounded,
+            epoch,
+        )
+    }
+
     /// Returns the absolute value of a Time.
     /// ```
     /// use cidre::cm;
@@ -68,10 +239,36 @@ impl Time {
     /// ```
     #[doc(alias = "CMTimeAbsoluteValue")]
     #[inline]
-    pub fn abs(self) -> Time {
-        unsafe { CMTimeAbsoluteValue(self) }
+    pub const fn abs(self) -> Time {
+        if self.is_invalid() {
+            return Self::invalid();
+        }
+        if self.is_neg_infinity() {
+            return Self::infinity();
+        }
+        if !self.is_numeric() || self.value >= 0 {
+            return self;
+        }
+        if self.scale <= 0 {
+            return Self::invalid();
+        }
+        Self::with_ratio(
+            -(self.value as i128),
+            self.scale as i128,
+            self.scale,
+            TimeRoundingMethod::DEFAULT,
+            true,
+            self.is_rounded(),
+            self.epoch,
+        )
     }
 
+    /// Returns the sum of two times.
+    ///
+    /// If timescales differ, result timescale is their least common multiple
+    /// (clamped to [`Time::MAX_SCALE`]). If result value overflows,
+    /// timescale is halved until it fits or result becomes infinite.
+    ///
     /// ```
     /// use cidre::cm;
     ///
@@ -82,11 +279,36 @@ impl Time {
     /// assert_eq!(t3.scale, 10);
     /// assert_eq!(t3.as_secs(), 300.0);
     /// ```
+    #[doc(alias = "CMTimeAdd")]
     #[inline]
-    pub fn add(self, rhs: Time) -> Time {
-        unsafe { CMTimeAdd(self, rhs) }
+    pub const fn add(self, rhs: Time) -> Time {
+        if self.is_invalid() || rhs.is_invalid() {
+            return Self::invalid();
+        }
+        let (l, r) = (self.inf_sign(), rhs.inf_sign());
+        if l != 0 || r != 0 {
+            if l * r < 0 {
+                return Self::invalid();
+            }
+            return Self::with_inf_sign(l + r < 0);
+        }
+        if self.is_indefinite() || rhs.is_indefinite() {
+            return Self::indefinit();
+        }
+        let epoch = if self.epoch == rhs.epoch || rhs.epoch == 0 {
+            self.epoch
+        } else if self.epoch == 0 {
+            rhs.epoch
+        } else {
+            return Self::invalid();
+        };
+        self.sum(rhs, 1, epoch)
     }
 
+    /// Returns time converted to `new_time_scale` with `rounding_method`.
+    ///
+    /// Non numeric times are returned as is. If value overflows, result is +/- infinity.
+    ///
     /// ```
     /// use cidre::cm;
     ///
@@ -94,13 +316,46 @@ impl Time {
     /// assert!(time.is_valid());
     /// assert_eq!(time.scale, 100);
     /// ```
+    #[doc(alias = "CMTimeConvertScale")]
     #[inline]
-    pub fn convert_scale(
+    pub const fn convert_scale(
         self,
         new_time_scale: TimeScale,
         rounding_method: TimeRoundingMethod,
     ) -> Time {
-        unsafe { CMTimeConvertScale(self, new_time_scale, rounding_method) }
+        if !self.is_numeric() {
+            return self;
+        }
+        if new_time_scale <= 0 || self.scale <= 0 {
+            return Self::invalid();
+        }
+        if new_time_scale == self.scale {
+            return self;
+        }
+        let method = match rounding_method {
+            TimeRoundingMethod::QuickTime if new_time_scale < self.scale => {
+                TimeRoundingMethod::RoundTowardZero
+            }
+            TimeRoundingMethod::QuickTime => TimeRoundingMethod::RoundAwayFromZero,
+            method => method,
+        };
+        let mut res = Self::with_ratio(
+            self.value as i128,
+            self.scale as i128,
+            new_time_scale,
+            method,
+            false,
+            self.is_rounded(),
+            self.epoch,
+        );
+        if matches!(rounding_method, TimeRoundingMethod::QuickTime)
+            && self.value < 0
+            && res.value == 0
+            && res.is_numeric()
+        {
+            res.value = -1;
+        }
+        res
     }
 
     #[inline]
@@ -114,21 +369,43 @@ impl Time {
     }
 
     /// Converts a Time to seconds.
+    ///
+    /// Invalid and indefinite times are NaN, infinite times are +/- infinity.
+    #[doc(alias = "CMTimeGetSeconds")]
     #[inline]
-    pub fn as_secs(self) -> f64 {
-        unsafe { CMTimeGetSeconds(self) }
+    pub const fn as_secs(self) -> f64 {
+        if self.is_numeric() {
+            self.value as f64 / self.scale as f64
+        } else if self.is_pos_infinity() {
+            f64::INFINITY
+        } else if self.is_neg_infinity() {
+            f64::NEG_INFINITY
+        } else {
+            f64::NAN
+        }
     }
 
+    #[doc(alias = "kCMTimeIndefinite")]
     #[inline]
-    pub fn indefinit() -> Time {
-        unsafe { kCMTimeIndefinite }
+    pub const fn indefinit() -> Time {
+        Time {
+            value: 0,
+            scale: 0,
+            flags: TimeFlags(TimeFlags::VALID.0 | TimeFlags::INDEFINITE.0),
+            epoch: 0,
+        }
     }
 
+    #[doc(alias = "kCMTimeInvalid")]
     #[inline]
-    pub fn invalid() -> Time {
-        unsafe { kCMTimeInvalid }
+    pub const fn invalid() -> Time {
+        Time {
+            value: 0,
+            scale: 0,
+            flags: TimeFlags(0),
+            epoch: 0,
+        }
     }
-
     #[inline]
     pub const fn is_indefinite(&self) -> bool {
         self.is_valid() && (self.flags.0 & TimeFlags::INDEFINITE.0) != 0
@@ -202,14 +479,105 @@ impl Time {
     /// ```
     #[doc(alias = "CMTimeMultiply")]
     #[inline]
-    pub fn mul_i32(self, multiplier: i32) -> Time {
-        unsafe { CMTimeMultiply(self, multiplier) }
+    pub const fn mul_i32(self, multiplier: i32) -> Time {
+        if self.is_invalid() {
+            return Self::invalid();
+        }
+        let sign = self.inf_sign();
+        if sign != 0 {
+            return Self::with_inf_sign((sign < 0) != (multiplier < 0));
+        }
+        if self.is_indefinite() {
+            return self;
+        }
+        if self.scale <= 0 {
+            return Self::invalid();
+        }
+        Self::with_ratio(
+            self.value as i128 * multiplier as i128,
+            self.scale as i128,
+            self.scale,
+            TimeRoundingMethod::DEFAULT,
+            true,
+            self.is_rounded(),
+            self.epoch,
+        )
     }
 
-    #[doc(alias = "CMTimeMultiplyByFloat64")]
+    /// Returns time multiplied by `multiplier / divisor`.
+    ///
+    /// Result is invalid if `divisor` is zero.
+    ///
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let t1 = cm::Time::new(3, 10);
+    /// let t2 = t1.mul_ratio(2, 3);
+    /// assert_eq!(t2.value, 2);
+    /// assert_eq!(t2.scale, 10);
+    /// ```
+    #[doc(alias = "CMTimeMultiplyByRatio")]
     #[inline]
+    pub const fn mul_ratio(self, multiplier: i32, divisor: i32) -> Time {
+        if self.is_invalid() || divisor == 0 {
+            return Self::invalid();
+        }
+        let sign = self.inf_sign();
+        if sign != 0 {
+            return Self::with_inf_sign((sign < 0) != ((multiplier < 0) != (divisor < 0)));
+        }
+        if self.is_indefinite() {
+            return self;
+        }
+        if self.scale <= 0 {
+            return Self::invalid();
+        }
+        let (mut num, mut den) = (multiplier as i128, divisor as i128);
+        if den < 0 {
+            num = -num;
+            den = -den;
+        }
+        Self::with_ratio(
+            self.value as i128 * num,
+            self.scale as i128 * den,
+            self.scale,
+            TimeRoundingMethod::DEFAULT,
+            true,
+            self.is_rounded(),
+            self.epoch,
+        )
+    }
+
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let t1 = cm::Time::new(10, 10);
+    /// let t2 = t1.mul_f64(0.25);
+    /// assert_eq!(t2.value, 3);
+    /// assert!(t2.flags.contains(cm::TimeFlags::HAS_BEEN_ROUNDED));
+    /// ```
+    #[doc(alias = "CMTimeMultiplyByFloat64")]
     pub fn mul_f64(self, multiplier: f64) -> Time {
-        unsafe { CMTimeMultiplyByFloat64(self, multiplier) }
+        if self.is_invalid() || multiplier.is_nan() {
+            return Self::invalid();
+        }
+        let sign = self.inf_sign();
+        if sign != 0 {
+            return Self::with_inf_sign((sign < 0) != multiplier.is_sign_negative());
+        }
+        if self.is_indefinite() {
+            return self;
+        }
+        if self.scale <= 0 {
+            return Self::invalid();
+        }
+        let secs = self.value as f64 * multiplier / self.scale as f64;
+        let mut res = Self::with_secs(secs, self.scale);
+        if res.is_numeric() && self.is_rounded() {
+            res.flags |= TimeFlags::HAS_BEEN_ROUNDED;
+        }
+        res.epoch = self.epoch;
+        res
     }
 
     /// Returns valid Time with value and timescale. Epoch is implied to be 0.
@@ -223,20 +591,30 @@ impl Time {
     /// ```
     #[doc(alias = "CMTimeMake")]
     #[inline]
-    pub fn new(value: TimeValue, timescale: i32) -> Time {
-        unsafe { CMTimeMake(value, timescale) }
+    pub const fn new(value: TimeValue, timescale: i32) -> Time {
+        Self::with_epoch(value, timescale, 0)
     }
 
     #[doc(alias = "kCMTimePositiveInfinity")]
     #[inline]
-    pub fn infinity() -> Time {
-        unsafe { kCMTimePositiveInfinity }
+    pub const fn infinity() -> Time {
+        Time {
+            value: 0,
+            scale: 0,
+            flags: TimeFlags(TimeFlags::VALID.0 | TimeFlags::POS_INFINITY.0),
+            epoch: 0,
+        }
     }
 
     #[doc(alias = "kCMTimeNegativeInfinity")]
     #[inline]
-    pub fn neg_infinity() -> Time {
-        unsafe { kCMTimeNegativeInfinity }
+    pub const fn neg_infinity() -> Time {
+        Time {
+            value: 0,
+            scale: 0,
+            flags: TimeFlags(TimeFlags::VALID.0 | TimeFlags::NEG_INFINITY.0),
+            epoch: 0,
+        }
     }
 
     #[inline]
@@ -244,6 +622,11 @@ impl Time {
         unsafe { CMTimeShow(self) }
     }
 
+    /// Returns the difference of two times.
+    ///
+    /// Timescale rules are the same as for [`Time::add`]. Times with the same epoch
+    /// produce a duration (epoch 0).
+    ///
     /// ```
     /// use cidre::cm;
     ///
@@ -254,9 +637,30 @@ impl Time {
     /// assert_eq!(t3.scale, 10);
     /// assert_eq!(t3.as_secs(), 0.0);
     /// ```
+    #[doc(alias = "CMTimeSubtract")]
     #[inline]
-    pub fn sub(self, rhs: Time) -> Time {
-        unsafe { CMTimeSubtract(self, rhs) }
+    pub const fn sub(self, rhs: Time) -> Time {
+        if self.is_invalid() || rhs.is_invalid() {
+            return Self::invalid();
+        }
+        let (l, r) = (self.inf_sign(), -rhs.inf_sign());
+        if l != 0 || r != 0 {
+            if l * r < 0 {
+                return Self::invalid();
+            }
+            return Self::with_inf_sign(l + r < 0);
+        }
+        if self.is_indefinite() || rhs.is_indefinite() {
+            return Self::indefinit();
+        }
+        let epoch = if self.epoch == rhs.epoch {
+            0
+        } else if rhs.epoch == 0 {
+            self.epoch
+        } else {
+            return Self::invalid();
+        };
+        self.sum(rhs, -1, epoch)
     }
 
     /// ```
@@ -266,13 +670,21 @@ impl Time {
     /// assert!(time.is_valid());
     /// assert_eq!(time.epoch, 5);
     /// ```
+    #[doc(alias = "CMTimeMakeWithEpoch")]
     #[inline]
-    pub fn with_epoch(value: TimeValue, timescale: i32, epoch: TimeEpoch) -> Time {
-        unsafe { CMTimeMakeWithEpoch(value, timescale, epoch) }
+    pub const fn with_epoch(value: TimeValue, timescale: i32, epoch: TimeEpoch) -> Time {
+        Time {
+            value,
+            scale: timescale,
+            flags: TimeFlags::VALID,
+            epoch,
+        }
     }
 
     /// Returns Time from a f64 number of seconds, and a preferred timescale.
     ///
+    /// If the preferred timescale causes an overflow, it is halved until value fits.
+    ///
     /// ```
     /// use cidre::cm;
     ///
@@ -281,24 +693,155 @@ impl Time {
     /// assert_eq!(time.scale, 10);
     /// assert_eq!(time.as_secs(), 100.0);
     /// ```
-    #[inline]
+    #[doc(alias = "CMTimeMakeWithSeconds")]
     pub fn with_secs(seconds: f64, preferred_timescale: TimeScale) -> Time {
-        unsafe { CMTimeMakeWithSeconds(seconds, preferred_timescale) }
+        if seconds.is_nan() || preferred_timescale <= 0 {
+            return Self::invalid();
+        }
+        if seconds.is_infinite() {
+            return Self::with_inf_sign(seconds < 0.0);
+        }
+        let mut scale = preferred_timescale;
+        loop {
+            let value = (seconds * scale as f64).round();
+            // i64::MAX as f64 rounds up to 2^63, which itself is out of range
+            if value >= TimeValue::MIN as f64 && value < TimeValue::MAX as f64 {
+                let mut res = Self::new(value as TimeValue, scale);
+                if res.as_secs() != seconds {
+                    res.flags |= TimeFlags::HAS_BEEN_ROUNDED;
+                }
+                return res;
+            }
+            if scale == 1 {
+                return Self::with_inf_sign(seconds < 0.0);
+            }
+            scale /= 2;
+        }
     }
 
+    #[doc(alias = "kCMTimeZero")]
     #[inline]
     pub const fn zero() -> Time {
-        unsafe { kCMTimeZero }
+        Self::new(0, 1)
+    }
+
+    /// Returns the greater of two times. If either time is invalid, result is invalid.
+    #[doc(alias = "CMTimeMaximum")]
+    #[inline]
+    pub const fn max(l: Time, r: Time) -> Time {
+        if l.is_invalid() || r.is_invalid() {
+            return Self::invalid();
+        }
+        if l.compare(&r).is_lt() { r } else { l }
+    }
+
+    /// Returns the lesser of two times. If either time is invalid, result is invalid.
+    #[doc(alias = "CMTimeMinimum")]
+    #[inline]
+    pub const fn min(l: Time, r: Time) -> Time {
+        if l.is_invalid() || r.is_invalid() {
+            return Self::invalid();
+        }
+        if l.compare(&r).is_gt() { r } else { l }
+    }
+
+    /// Order of non numeric times: negative infinity < numeric < indefinite
+    /// < positive infinity < invalid.
+    #[inline]
+    const fn rank(&self) -> u8 {
+        if self.is_invalid() {
+            4
+        } else if self.is_pos_infinity() {
+            3
+        } else if self.is_indefinite() {
+            2
+        } else if self.is_neg_infinity() {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Numerically compares two times.
+    ///
+    /// Invalid times are equal to each other and greater than any other time.
+    /// Numeric times in larger epochs are always greater than times in smaller epochs.
+    ///
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let t1 = cm::Time::new(1, 10);
+    /// let t2 = cm::Time::new(10, 100);
+    /// assert!(t1.compare(&t2).is_eq());
+    /// assert!(cm::Time::indefinit().compare(&cm::Time::infinity()).is_lt());
+    /// ```
+    #[doc(alias = "CMTimeCompare")]
+    pub const fn compare(&self, other: &Time) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+
+        let (l, r) = (self.rank(), other.rank());
+        if l != r {
+            return if l < r {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        if l != 1 {
+            return Ordering::Equal;
+        }
+        if self.epoch != other.epoch {
+            return if self.epoch < other.epoch {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        let l = self.value as i128 * other.scale as i128;
+        let r = other.value as i128 * self.scale as i128;
+        if l < r {
+            Ordering::Less
+        } else if l > r {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
     }
+}
+
+impl std::ops::Add for Time {
+    type Output = Time;
 
     #[inline]
-    pub fn max(l: Time, r: Time) -> Time {
-        unsafe { CMTimeMaximum(l, r) }
+    fn add(self, rhs: Self) -> Self::Output {
+        Time::add(self, rhs)
     }
+}
+
+impl std::ops::Sub for Time {
+    type Output = Time;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Time::sub(self, rhs)
+    }
+}
+
+impl std::ops::Mul<i32> for Time {
+    type Output = Time;
 
     #[inline]
-    pub fn min(l: Time, r: Time) -> Time {
-        unsafe { CMTimeMinimum(l, r) }
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.mul_i32(rhs)
+    }
+}
+
+impl std::ops::Mul<f64> for Time {
+    type Output = Time;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.mul_f64(rhs)
     }
 }
 
@@ -315,7 +858,7 @@ impl PartialEq for Time {
     /// ```
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        unsafe { CMTimeCompare(*self, *other) == 0 }
+        self.compare(other).is_eq()
     }
 }
 
@@ -334,7 +877,14 @@ impl PartialOrd for Time {
     /// ```
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        unsafe { std::mem::transmute(CMTimeCompare(*self, *other) as i8) }
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Time {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.compare(other)
     }
 }
 
@@ -363,36 +913,174 @@ mod tests {
         assert!(valid.is_valid());
         assert!(valid.is_numeric());
     }
+
+    #[test]
+    fn add_sub() {
+        let t = cm::Time::new(1, 3).add(cm::Time::new(1, 2));
+        assert_eq!(t.value, 5);
+        assert_eq!(t.scale, 6);
+        assert!(!t.flags.contains(cm::TimeFlags::HAS_BEEN_ROUNDED));
+
+        let t = cm::Time::new(1, 3).sub(cm::Time::new(1, 2));
+        assert_eq!(t.value, -1);
+        assert_eq!(t.scale, 6);
+
+        // lcm is bigger than max timescale
+        let t = cm::Time::new(1, 999_999_937).add(cm::Time::new(1, 3));
+        assert_eq!(t.scale, cm::Time::MAX_SCALE);
+        assert!(t.flags.contains(cm::TimeFlags::HAS_BEEN_ROUNDED));
+
+        // overflow halves timescale
+        let t = cm::Time::new(i64::MAX, 10).add(cm::Time::new(10, 10));
+        assert_eq!(t.scale, 5);
+        assert!(t.flags.contains(cm::TimeFlags::HAS_BEEN_ROUNDED));
+
+        let t = cm::Time::new(i64::MAX, 1).add(cm::Time::new(1, 1));
+        assert!(t.is_pos_infinity());
+        let t = cm::Time::new(i64::MIN, 1).sub(cm::Time::new(1, 1));
+        assert!(t.is_neg_infinity());
+
+        let inf = cm::Time::infinity();
+        let neg_inf = cm::Time::neg_infinity();
+        let one = cm::Time::new(1, 1);
+        assert!(inf.add(neg_inf).is_invalid());
+        assert!(inf.add(inf).is_pos_infinity());
+        assert!(inf.sub(inf).is_invalid());
+        assert!(one.sub(inf).is_neg_infinity());
+        assert!(inf.add(cm::Time::indefinit()).is_pos_infinity());
+        assert!(one.add(cm::Time::indefinit()).is_indefinite());
+        assert!(one.add(cm::Time::invalid()).is_invalid());
+
+        let a = cm::Time::with_epoch(1, 1, 2);
+        let b = cm::Time::with_epoch(1, 1, 3);
+        assert!(a.add(b).is_invalid());
+        assert_eq!(a.add(one).epoch, 2);
+        assert_eq!(a.sub(a).epoch, 0);
+        assert_eq!(a.sub(one).epoch, 2);
+        assert!(one.sub(a).is_invalid());
+    }
+
+    #[test]
+    fn convert_scale() {
+        use cm::TimeRoundingMethod as M;
+
+        let t = cm::Time::new(5, 10);
+        let r = t.convert_scale(1, M::RoundHalfAwayFromZero);
+        assert_eq!(r.value, 1);
+        assert!(r.flags.contains(cm::TimeFlags::HAS_BEEN_ROUNDED));
+        assert_eq!(t.convert_scale(1, M::RoundTowardZero).value, 0);
+        assert_eq!(t.convert_scale(1, M::RoundAwayFromZero).value, 1);
+        assert_eq!(t.convert_scale(1, M::RoundTowardPositiveInfinity).value, 1);
+        assert_eq!(t.convert_scale(1, M::RoundTowardNegativeInfinity).value, 0);
+        assert_eq!(t.convert_scale(1, M::QuickTime).value, 0);
+
+        let t = cm::Time::new(-4, 10);
+        assert_eq!(t.convert_scale(1, M::RoundHalfAwayFromZero).value, 0);
+        assert_eq!(t.convert_scale(1, M::RoundAwayFromZero).value, -1);
+        assert_eq!(t.convert_scale(1, M::RoundTowardPositiveInfinity).value, 0);
+        assert_eq!(t.convert_scale(1, M::RoundTowardNegativeInfinity).value, -1);
+        assert_eq!(t.convert_scale(1, M::QuickTime).value, -1);
+
+        let t = cm::Time::new(1, 600).convert_scale(1000, M::QuickTime);
+        assert_eq!(t.value, 2);
+
+        let t = cm::Time::new(3, 10).convert_scale(100, M::default());
+        assert_eq!(t.value, 30);
+        assert!(!t.flags.contains(cm::TimeFlags::HAS_BEEN_ROUNDED));
+
+        let t = cm::Time::new(i64::MAX, 1).convert_scale(2, M::default());
+        assert!(t.is_pos_infinity());
+
+        assert!(
+            cm::Time::infinity()
+                .convert_scale(10, M::default())
+                .is_pos_infinity()
+        );
+        assert!(
+            cm::Time::new(1, 1)
+                .convert_scale(0, M::default())
+                .is_invalid()
+        );
+    }
+
+    #[test]
+    fn mul() {
+        let t = cm::Time::new(i64::MAX / 2 + 1, 4).mul_i32(2);
+        assert_eq!(t.scale, 2);
+        assert!(t.is_numeric());
+
+        assert!(cm::Time::new(i64::MAX, 1).mul_i32(2).is_pos_infinity());
+        assert!(cm::Time::infinity().mul_i32(-1).is_neg_infinity());
+        assert!(cm::Time::indefinit().mul_i32(2).is_indefinite());
+
+        let t = cm::Time::new(3, 4).mul_ratio(1, 2);
+        assert_eq!(t.value, 2);
+        assert!(t.flags.contains(cm::TimeFlags::HAS_BEEN_ROUNDED));
+        assert!(cm::Time::new(3, 4).mul_ratio(1, 0).is_invalid());
+
+        let t = cm::Time::new(3, 4).mul_f64(-2.0);
+        assert_eq!(t.value, -6);
+        assert!(cm::Time::new(3, 4).mul_f64(f64::NAN).is_invalid());
+    }
+
+    #[test]
+    fn secs() {
+        let t = cm::Time::with_secs(1.5, 1);
+        assert_eq!(t.value, 2);
+        assert!(t.flags.contains(cm::TimeFlags::HAS_BEEN_ROUNDED));
+
+        let t = cm::Time::with_secs(1e18, 1000);
+        assert!(t.is_numeric());
+        assert!(t.scale < 1000);
+
+        assert!(cm::Time::with_secs(f64::NAN, 10).is_invalid());
+        assert!(cm::Time::with_secs(f64::NEG_INFINITY, 10).is_neg_infinity());
+        assert!(cm::Time::with_secs(1e300, 10).is_pos_infinity());
+        assert!(cm::Time::with_secs(1.0, 0).is_invalid());
+
+        assert!(cm::Time::invalid().as_secs().is_nan());
+        assert!(cm::Time::indefinit().as_secs().is_nan());
+        assert_eq!(cm::Time::neg_infinity().as_secs(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn compare() {
+        let mut times = [
+            cm::Time::invalid(),
+            cm::Time::infinity(),
+            cm::Time::with_epoch(1, 1, 1),
+            cm::Time::indefinit(),
+            cm::Time::new(1, 1),
+            cm::Time::neg_infinity(),
+            cm::Time::new(-1, 1),
+        ];
+        times.sort();
+        assert!(times[0].is_neg_infinity());
+        assert_eq!(times[1], cm::Time::new(-1, 1));
+        assert_eq!(times[2], cm::Time::new(1, 1));
+        assert_eq!(times[3].epoch, 1);
+        assert!(times[4].is_indefinite());
+        assert!(times[5].is_pos_infinity());
+        assert!(times[6].is_invalid());
+
+        assert_eq!(cm::Time::invalid(), cm::Time::invalid());
+        assert!(cm::Time::max(cm::Time::invalid(), cm::Time::zero()).is_invalid());
+        assert!(cm::Time::min(cm::Time::invalid(), cm::Time::zero()).is_invalid());
+        assert_eq!(
+            cm::Time::min(cm::Time::new(1, 2), cm::Time::new(1, 3)),
+            cm::Time::new(1, 3)
+        );
+
+        let t = cm::Time::new(i64::MIN, 1).abs();
+        assert_eq!(t.scale, 0);
+        assert!(t.is_pos_infinity());
+        assert_eq!(cm::Time::neg_infinity().abs(), cm::Time::infinity());
+    }
 }
 
 #[link(name = "CoreMedia", kind = "framework")]
 unsafe extern "C-unwind" {
-    static kCMTimeInvalid: Time;
-    static kCMTimeIndefinite: Time;
-    static kCMTimePositiveInfinity: Time;
-    static kCMTimeNegativeInfinity: Time;
-    static kCMTimeZero: Time;
-
-    fn CMTimeMake(value: TimeValue, timescale: TimeScale) -> Time;
-    fn CMTimeMakeWithEpoch(value: TimeValue, timescale: TimeScale, epoch: TimeEpoch) -> Time;
-    fn CMTimeMakeWithSeconds(seconds: f64, preferred_timescale: TimeScale) -> Time;
-    fn CMTimeGetSeconds(time: Time) -> f64;
-    fn CMTimeConvertScale(
-        time: Time,
-        new_time_scale: TimeScale,
-        rounding_method: TimeRoundingMethod,
-    ) -> Time;
-
-    fn CMTimeAdd(lhs: Time, rhs: Time) -> Time;
-    fn CMTimeSubtract(lhs: Time, rhs: Time) -> Time;
-    fn CMTimeMultiply(time: Time, multiplier: i32) -> Time;
-    fn CMTimeMultiplyByFloat64(time: Time, multiplier: f64) -> Time;
-
-    fn CMTimeCompare(time1: Time, time2: Time) -> i32;
-    fn CMTimeAbsoluteValue(time: Time) -> Time;
     fn CMTimeShow(time: Time);
-    fn CMTimeMaximum(time1: Time, time2: Time) -> Time;
-    fn CMTimeMinimum(time1: Time, time2: Time) -> Time;
 
     fn CMTimeCopyDescription(
         allocator: Option<&cf::Allocator>,
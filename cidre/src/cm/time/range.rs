@@ -9,6 +9,25 @@ pub struct Range {
 }
 
 impl Range {
+    #[doc(alias = "CMTimeRangeMake")]
+    #[inline]
+    pub const fn new(start: cm::Time, duration: cm::Time) -> Self {
+        Self { start, duration }
+    }
+
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let range = cm::TimeRange::with_start_end(cm::Time::new(1, 10), cm::Time::new(1, 2));
+    /// assert_eq!(range.duration, cm::Time::new(4, 10));
+    /// assert_eq!(range.end(), cm::Time::new(1, 2));
+    /// ```
+    #[doc(alias = "CMTimeRangeFromTimeToTime")]
+    #[inline]
+    pub const fn with_start_end(start: cm::Time, end: cm::Time) -> Self {
+        Self::new(start, end.sub(start))
+    }
+
     #[doc(alias = "CMTIMERANGE_IS_INVALID")]
     #[inline]
     pub const fn is_valid(&self) -> bool {
@@ -24,40 +43,246 @@ impl Range {
         self.is_valid() && (self.start.is_indefinite() || self.duration.is_indefinite())
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.is_valid() && self.duration == cm::Time::zero()
+    #[doc(alias = "CMTIMERANGE_IS_EMPTY")]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.is_valid() && self.duration.compare(&cm::Time::zero()).is_eq()
+    }
+
+    /// Returns start + duration, or invalid time if range is invalid.
+    #[doc(alias = "CMTimeRangeGetEnd")]
+    #[inline]
+    pub const fn end(&self) -> cm::Time {
+        if self.is_valid() {
+            self.start.add(self.duration)
+        } else {
+            cm::Time::invalid()
+        }
+    }
+
+    /// Returns the smallest range containing both ranges.
+    ///
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let a = cm::TimeRange::new(cm::Time::new(1, 1), cm::Time::new(2, 1));
+    /// let b = cm::TimeRange::new(cm::Time::new(5, 1), cm::Time::new(1, 1));
+    /// let u = a.union(&b);
+    /// assert_eq!(u.start, cm::Time::new(1, 1));
+    /// assert_eq!(u.end(), cm::Time::new(6, 1));
+    /// ```
+    #[doc(alias = "CMTimeRangeGetUnion")]
+    pub const fn union(&self, other: &Self) -> Self {
+        if !self.is_valid() || !other.is_valid() {
+            return Self::invalid();
+        }
+        Self::with_start_end(
+            cm::Time::min(self.start, other.start),
+            cm::Time::max(self.end(), other.end()),
+        )
+    }
+
+    /// Returns the overlap of both ranges. If ranges don't overlap, result is [`Range::zero`].
+    ///
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let a = cm::TimeRange::new(cm::Time::new(1, 1), cm::Time::new(2, 1));
+    /// let b = cm::TimeRange::new(cm::Time::new(2, 1), cm::Time::new(5, 1));
+    /// let i = a.intersection(&b);
+    /// assert_eq!(i.start, cm::Time::new(2, 1));
+    /// assert_eq!(i.duration, cm::Time::new(1, 1));
+    /// ```
+    #[doc(alias = "CMTimeRangeGetIntersection")]
+    pub const fn intersection(&self, other: &Self) -> Self {
+        if !self.is_valid() || !other.is_valid() {
+            return Self::invalid();
+        }
+        let start = cm::Time::max(self.start, other.start);
+        let end = cm::Time::min(self.end(), other.end());
+        if end.compare(&start).is_le() {
+            return Self::zero();
+        }
+        Self::with_start_end(start, end)
     }
 
     #[doc(alias = "CMTimeRangeContainsTime")]
     #[inline]
-    pub fn contains_time(&self, time: &cm::Time) -> bool {
-        unsafe { CMTimeRangeContainsTime(*self, *time) }
+    pub const fn contains_time(&self, time: &cm::Time) -> bool {
+        self.is_valid()
+            && time.is_valid()
+            && time.compare(&self.start).is_ge()
+            && time.compare(&self.end()).is_lt()
+    }
+
+    #[doc(alias = "CMTimeRangeContainsTimeRange")]
+    #[inline]
+    pub const fn contains_range(&self, range: &Self) -> bool {
+        self.is_valid()
+            && range.is_valid()
+            && range.start.compare(&self.start).is_ge()
+            && range.end().compare(&self.end()).is_le()
+    }
+
+    /// Returns the nearest time inside the range.
+    ///
+    /// Times before the start and after the end of the range return the start and end
+    /// respectively. If the range is empty or the time is invalid, result is invalid.
+    ///
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let range = cm::TimeRange::new(cm::Time::new(1, 1), cm::Time::new(2, 1));
+    /// assert_eq!(range.clamp(cm::Time::zero()), cm::Time::new(1, 1));
+    /// assert_eq!(range.clamp(cm::Time::new(2, 1)), cm::Time::new(2, 1));
+    /// assert_eq!(range.clamp(cm::Time::infinity()), cm::Time::new(3, 1));
+    /// ```
+    #[doc(alias = "CMTimeClampToRange")]
+    pub const fn clamp(&self, time: cm::Time) -> cm::Time {
+        if time.is_invalid() || !self.is_valid() || self.is_empty() {
+            return cm::Time::invalid();
+        }
+        if time.compare(&self.start).is_lt() {
+            return self.start;
+        }
+        let end = self.end();
+        if time.compare(&end).is_gt() {
+            return end;
+        }
+        time
+    }
+
+    /// Maps a duration from this range to `to` range:
+    /// `duration * to.duration / self.duration`.
+    #[doc(alias = "CMTimeMapDurationFromRangeToRange")]
+    pub fn map_duration(&self, duration: cm::Time, to: &Self) -> cm::Time {
+        if !duration.is_numeric() {
+            return duration;
+        }
+        let (from, to) = (self.duration, to.duration);
+        if !from.is_numeric() || !to.is_numeric() || from.value == 0 || duration.scale <= 0 {
+            return cm::Time::invalid();
+        }
+        if from == to {
+            return duration;
+        }
+
+        // ratio = to / from
+        let mut num = to.value as i128 * from.scale as i128;
+        let mut den = from.value as i128 * to.scale as i128;
+        if den < 0 {
+            num = -num;
+            den = -den;
+        }
+        let a = super::gcd(num, den);
+        if a > 1 {
+            num /= a;
+            den /= a;
+        }
+
+        // keep headroom for rescaling by timescale
+        const LIMIT: u128 = 1 << 95;
+        match (
+            (duration.value as i128).checked_mul(num),
+            (duration.scale as i128).checked_mul(den),
+        ) {
+            (Some(n), Some(d)) if n.unsigned_abs() < LIMIT && (d as u128) < LIMIT => {
+                cm::Time::with_ratio(
+                    n,
+                    d,
+                    duration.scale,
+                    cm::TimeRoundingMethod::DEFAULT,
+                    true,
+                    duration.is_rounded(),
+                    duration.epoch,
+                )
+            }
+            _ => duration.mul_f64(num as f64 / den as f64),
+        }
+    }
+
+    /// Maps a time from this range to `to` range:
+    /// `to.start + (time - self.start) * (to.duration / self.duration)`.
+    ///
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let from = cm::TimeRange::new(cm::Time::new(10, 1), cm::Time::new(10, 1));
+    /// let to = cm::TimeRange::new(cm::Time::new(0, 1), cm::Time::new(5, 1));
+    /// assert_eq!(from.map_time(cm::Time::new(14, 1), &to), cm::Time::new(2, 1));
+    /// ```
+    #[doc(alias = "CMTimeMapTimeFromRangeToRange")]
+    pub fn map_time(&self, time: cm::Time, to: &Self) -> cm::Time {
+        let offset = time.sub(self.start);
+        to.start.add(self.map_duration(offset, to))
     }
 
     #[doc(alias = "kCMTimeRangeZero")]
     #[inline]
     pub const fn zero() -> Self {
-        unsafe { kCMTimeRangeZero }
+        Self::new(cm::Time::zero(), cm::Time::zero())
     }
 
     #[doc(alias = "kCMTimeRangeInvalid")]
     #[inline]
     pub const fn invalid() -> Self {
-        unsafe { kCMTimeRangeInvalid }
+        Self::new(cm::Time::invalid(), cm::Time::invalid())
     }
 }
 
+#[doc(alias = "CMTimeMapping")]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[repr(C)]
 pub struct Mapping {
     pub source: cm::TimeRange,
     pub target: cm::TimeRange,
 }
 
-#[link(name = "CoreMedia", kind = "framework")]
-unsafe extern "C-unwind" {
-    static kCMTimeRangeZero: Range;
-    static kCMTimeRangeInvalid: Range;
+impl Mapping {
+    #[doc(alias = "CMTimeMappingMake")]
+    #[inline]
+    pub const fn new(source: cm::TimeRange, target: cm::TimeRange) -> Self {
+        Self { source, target }
+    }
+
+    /// Mapping for an empty edit: source is invalid.
+    #[doc(alias = "CMTimeMappingMakeEmpty")]
+    #[inline]
+    pub const fn empty(target: cm::TimeRange) -> Self {
+        Self::new(cm::TimeRange::invalid(), target)
+    }
+
+    #[doc(alias = "CMTIMEMAPPING_IS_VALID")]
+    #[inline]
+    pub const fn is_valid(&self) -> bool {
+        self.target.is_valid()
+    }
+
+    #[doc(alias = "CMTIMEMAPPING_IS_EMPTY")]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        !self.source.start.is_numeric() && self.target.is_valid()
+    }
 
-    fn CMTimeRangeContainsTime(range: Range, time: cm::Time) -> bool;
+    /// Maps a time from source to target range.
+    /// Empty or invalid mappings produce invalid time.
+    ///
+    /// ```
+    /// use cidre::cm;
+    ///
+    /// let mapping = cm::TimeMapping::new(
+    ///     cm::TimeRange::new(cm::Time::new(10, 1), cm::Time::new(4, 1)),
+    ///     cm::TimeRange::new(cm::Time::new(0, 1), cm::Time::new(8, 1)),
+    /// );
+    /// assert_eq!(mapping.map_time(cm::Time::new(11, 1)), cm::Time::new(2, 1));
+    /// assert!(cm::TimeMapping::empty(mapping.target).map_time(cm::Time::zero()).is_invalid());
+    /// ```
+    pub fn map_time(&self, time: cm::Time) -> cm::Time {
+        if !self.is_valid() || self.is_empty() {
+            return cm::Time::invalid();
+        }
+        self.source.map_time(time, &self.target)
+    }
 }
 
 #[cfg(test)]
@@ -74,4 +299,44 @@ mod tests {
         assert!(!range.is_valid());
         assert!(!range.is_empty());
     }
+
+    #[test]
+    fn algebra() {
+        let a = cm::TimeRange::new(cm::Time::new(0, 1), cm::Time::new(10, 1));
+        let b = cm::TimeRange::new(cm::Time::new(20, 1), cm::Time::new(10, 1));
+
+        assert!(a.intersection(&b).is_empty());
+        assert_eq!(a.union(&b).duration, cm::Time::new(30, 1));
+        assert!(!a.union(&cm::TimeRange::invalid()).is_valid());
+
+        assert!(a.contains_time(&cm::Time::zero()));
+        assert!(!a.contains_time(&cm::Time::new(10, 1)));
+        assert!(!a.contains_time(&cm::Time::invalid()));
+        assert!(a.contains_range(&cm::TimeRange::new(
+            cm::Time::new(5, 1),
+            cm::Time::new(5, 1)
+        )));
+        assert!(!a.contains_range(&b));
+
+        let indefinite = cm::TimeRange::new(cm::Time::zero(), cm::Time::indefinit());
+        assert!(indefinite.is_indefinite());
+        assert!(indefinite.contains_time(&cm::Time::new(1_000_000, 1)));
+
+        assert!(cm::TimeRange::zero().clamp(cm::Time::zero()).is_invalid());
+    }
+
+    #[test]
+    fn mapping() {
+        let from = cm::TimeRange::new(cm::Time::new(0, 3), cm::Time::new(3, 3));
+        let to = cm::TimeRange::new(cm::Time::new(1, 1), cm::Time::new(2, 1));
+        let t = from.map_time(cm::Time::new(1, 3), &to);
+        assert_eq!(t, cm::Time::new(5, 3));
+
+        let d = from.map_duration(cm::Time::new(i64::MAX, 1000), &to);
+        assert!(d.is_numeric());
+        assert_eq!(d.scale, 500);
+
+        let zero = cm::TimeRange::new(cm::Time::zero(), cm::Time::zero());
+        assert!(zero.map_time(cm::Time::zero(), &to).is_invalid());
+    }
 }
@@ -182,7 +182,7 @@ impl TapDesc {
     pub fn set_stream(&mut self, val: Option<&ns::Number>);
 }
 
-#[link(name = "core_audio", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "core_audio", kind = "static"))]
 unsafe extern "C" {
     static CA_TAP_DESCRIPTION: &'static objc::Class<TapDesc>;
 }
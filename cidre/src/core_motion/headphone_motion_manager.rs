@@ -66,7 +66,7 @@ define_obj_type!(
 
 impl Delegate for AnyDelegate {}
 
-#[link(name = "core_motion", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "core_motion", kind = "static"))]
 unsafe extern "C" {
     static CM_HEADPHONE_MOTION_MANAGER: &'static objc::Class<HeadphoneMotionManager>;
 }
@@ -195,7 +195,7 @@ impl MotionManager {
     }
 }
 
-#[link(name = "core_motion", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "core_motion", kind = "static"))]
 unsafe extern "C" {
     static CM_MOTION_MANAGER: &'static objc::Class<MotionManager>;
 }
@@ -35,6 +35,8 @@ pub use pixel_format_description::create as pixel_format_desc_create;
 #[cfg(feature = "mtl")]
 pub mod metal;
 #[cfg(feature = "mtl")]
+pub use metal::Plane as MetalPlane;
+#[cfg(feature = "mtl")]
 pub use metal::Texture as MetalTexture;
 #[cfg(feature = "mtl")]
 pub use metal::TextureCache as MetalTextureCache;
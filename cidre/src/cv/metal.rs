@@ -1,3 +1,6 @@
+pub mod pixel_format;
+pub use pixel_format::Plane;
+
 pub mod texture;
 pub use texture::keys as texture_keys;
 pub use texture::Texture;
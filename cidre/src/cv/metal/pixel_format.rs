@@ -0,0 +1,269 @@
+use crate::{cv, mtl};
+
+/// Metal view of a single plane of a `cv::PixelBuf`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Plane {
+    /// Format to use for the plane texture.
+    pub format: mtl::PixelFormat,
+
+    /// Horizontal and vertical subsampling of the plane relative to the image.
+    pub subsampling: (usize, usize),
+}
+
+impl Plane {
+    const fn new(format: mtl::PixelFormat, x: usize, y: usize) -> Self {
+        Self {
+            format,
+            subsampling: (x, y),
+        }
+    }
+
+    /// Bytes per texel block. For 422 packed formats one element holds two pixels.
+    #[inline]
+    pub const fn bytes_per_element(&self) -> usize {
+        self.format.bytes_per_block()
+    }
+
+    /// Size of the plane texture for the image of `width` x `height` pixels.
+    #[inline]
+    pub const fn size(&self, width: usize, height: usize) -> (usize, usize) {
+        (
+            width.div_ceil(self.subsampling.0),
+            height.div_ceil(self.subsampling.1),
+        )
+    }
+
+    /// Minimal bytes per row of the plane for the image `width`.
+    #[inline]
+    pub const fn bytes_per_row(&self, width: usize) -> usize {
+        self.format
+            .bytes_per_row(width.div_ceil(self.subsampling.0))
+    }
+}
+
+const BI_PLANAR_8_420: &[Plane] = &[
+    Plane::new(mtl::PixelFormat::R8UNorm, 1, 1),
+    Plane::new(mtl::PixelFormat::Rg8UNorm, 2, 2),
+];
+
+const BI_PLANAR_10_420: &[Plane] = &[
+    Plane::new(mtl::PixelFormat::R16UNorm, 1, 1),
+    Plane::new(mtl::PixelFormat::Rg16UNorm, 2, 2),
+];
+
+const BI_PLANAR_10_422: &[Plane] = &[
+    Plane::new(mtl::PixelFormat::R16UNorm, 1, 1),
+    Plane::new(mtl::PixelFormat::Rg16UNorm, 2, 1),
+];
+
+const BI_PLANAR_10_444: &[Plane] = &[
+    Plane::new(mtl::PixelFormat::R16UNorm, 1, 1),
+    Plane::new(mtl::PixelFormat::Rg16UNorm, 1, 1),
+];
+
+const PLANAR_8_420: &[Plane] = &[
+    Plane::new(mtl::PixelFormat::R8UNorm, 1, 1),
+    Plane::new(mtl::PixelFormat::R8UNorm, 2, 2),
+    Plane::new(mtl::PixelFormat::R8UNorm, 2, 2),
+];
+
+const PACKED_422_ALPHA: &[Plane] = &[
+    Plane::new(mtl::PixelFormat::Bgrg422, 1, 1),
+    Plane::new(mtl::PixelFormat::R8UNorm, 1, 1),
+];
+
+/// CoreVideo formats which can be wrapped in Metal textures.
+const TABLE: &[(cv::PixelFormat, &[Plane])] = {
+    use cv::PixelFormat as F;
+    use mtl::PixelFormat as M;
+    &[
+        (F::ONE_COMPONENT_8, &[Plane::new(M::R8UNorm, 1, 1)]),
+        (F::ONE_COMPONENT_16, &[Plane::new(M::R16UNorm, 1, 1)]),
+        (F::ONE_COMPONENT_16_HALF, &[Plane::new(M::R16Float, 1, 1)]),
+        (F::ONE_COMPONENT_32_FLOAT, &[Plane::new(M::R32Float, 1, 1)]),
+        (F::DEPTH_FLOAT_16, &[Plane::new(M::R16Float, 1, 1)]),
+        (F::DEPTH_FLOAT_32, &[Plane::new(M::R32Float, 1, 1)]),
+        (F::DISPARITY_FLOAT_16, &[Plane::new(M::R16Float, 1, 1)]),
+        (F::DISPARITY_FLOAT_32, &[Plane::new(M::R32Float, 1, 1)]),
+        (F::TWO_COMPONENT_8, &[Plane::new(M::Rg8UNorm, 1, 1)]),
+        (F::TWO_COMPONENT_16, &[Plane::new(M::Rg16UNorm, 1, 1)]),
+        (F::TWO_COMPONENT_16_HALF, &[Plane::new(M::Rg16Float, 1, 1)]),
+        (F::TWO_COMPONENT_32_FLOAT, &[Plane::new(M::Rg32Float, 1, 1)]),
+        (F::_32_BGRA, &[Plane::new(M::Bgra8UNorm, 1, 1)]),
+        (F::LOSSLESS_32_BGRA, &[Plane::new(M::Bgra8UNorm, 1, 1)]),
+        (F::LOSSY_32_BGRA, &[Plane::new(M::Bgra8UNorm, 1, 1)]),
+        (F::_32_RGBA, &[Plane::new(M::Rgba8UNorm, 1, 1)]),
+        (
+            F::ARGB_2101010_LE_PACKED,
+            &[Plane::new(M::Bgr10A2UNorm, 1, 1)],
+        ),
+        (
+            F::_30_RGB_LE_PACKED_WIDE_GAMUT,
+            &[Plane::new(M::Bgr10Xr, 1, 1)],
+        ),
+        (F::_40_ARGB_LE_WIDE_GAMUT, &[Plane::new(M::Bgra10Xr, 1, 1)]),
+        (F::_64_RGBALE, &[Plane::new(M::Rgba16UNorm, 1, 1)]),
+        (F::_64_RGBA_HALF, &[Plane::new(M::Rgba16Float, 1, 1)]),
+        (
+            F::LOSSLESS_64_RGBA_HALF,
+            &[Plane::new(M::Rgba16Float, 1, 1)],
+        ),
+        (F::_128_RGBA_FLOAT, &[Plane::new(M::Rgba32Float, 1, 1)]),
+        (F::_422_YP_CB_CR_8, &[Plane::new(M::Bgrg422, 1, 1)]),
+        (F::_422_YP_CB_CR_8_YUVS, &[Plane::new(M::Gbgr422, 1, 1)]),
+        (F::_422_YP_CB_CR_4_A_8_BI_PLANAR, PACKED_422_ALPHA),
+        (F::_420_YP_CB_CR_8_PLANAR_FULL_RANGE, PLANAR_8_420),
+        (F::_420V, BI_PLANAR_8_420),
+        (F::_420F, BI_PLANAR_8_420),
+        (F::LOSSLESS_420V, BI_PLANAR_8_420),
+        (F::LOSSLESS_420F, BI_PLANAR_8_420),
+        (F::LOSSY_420V, BI_PLANAR_8_420),
+        (F::LOSSY_420F, BI_PLANAR_8_420),
+        (F::_420_YP_CB_CR_10_BI_PLANAR_VIDEO_RANGE, BI_PLANAR_10_420),
+        (F::_420_YP_CB_CR_10_BI_PLANAR_FULL_RANGE, BI_PLANAR_10_420),
+        (
+            F::LOSSLESS_420_YP_CB_CR_10_PACKED_BI_PLANAR_VIDEO_RANGE,
+            BI_PLANAR_10_420,
+        ),
+        (
+            F::LOSSY_420_YP_CB_CR_10_PACKED_BI_PLANAR_VIDEO_RANGE,
+            BI_PLANAR_10_420,
+        ),
+        (F::_422_YP_CB_CR_10_BI_PLANAR_VIDEO_RANGE, BI_PLANAR_10_422),
+        (F::_422_YP_CB_CR_10_BI_PLANAR_FULL_RANGE, BI_PLANAR_10_422),
+        (
+            F::LOSSLESS_422_YP_CB_CR_10_PACKED_BI_PLANAR_VIDEO_RANGE,
+            BI_PLANAR_10_422,
+        ),
+        (
+            F::LOSSY_422_YP_CB_CR_10_PACKED_BI_PLANAR_VIDEO_RANGE,
+            BI_PLANAR_10_422,
+        ),
+        (F::_444_YP_CB_CR_10_BI_PLANAR_VIDEO_RANGE, BI_PLANAR_10_444),
+        (F::_444_YP_CB_CR_10_BI_PLANAR_FULL_RANGE, BI_PLANAR_10_444),
+    ]
+};
+
+impl cv::PixelFormat {
+    /// Metal formats of planes to use with `cv::MetalTextureCache`
+    /// or `None` if the format can't be wrapped in Metal textures.
+    ///
+    /// Compressed (lossless and lossy) formats use the planes of their uncompressed equivalents.
+    ///
+    /// ```
+    /// use cidre::{cv, mtl};
+    ///
+    /// let planes = cv::PixelFormat::_420V.metal_planes().unwrap();
+    /// assert_eq!(planes.len(), 2);
+    /// assert_eq!(planes[0].format, mtl::PixelFormat::R8UNorm);
+    /// assert_eq!(planes[1].format, mtl::PixelFormat::Rg8UNorm);
+    /// assert_eq!(planes[1].size(1920, 1080), (960, 540));
+    /// ```
+    pub fn metal_planes(&self) -> Option<&'static [Plane]> {
+        TABLE
+            .iter()
+            .find(|(format, _)| format == self)
+            .map(|(_, planes)| *planes)
+    }
+
+    /// Number of planes or `0` if the format can't be wrapped in Metal textures.
+    #[inline]
+    pub fn metal_plane_count(&self) -> usize {
+        self.metal_planes().map_or(0, |planes| planes.len())
+    }
+
+    #[inline]
+    pub fn metal_plane(&self, index: usize) -> Option<Plane> {
+        self.metal_planes()?.get(index).copied()
+    }
+
+    /// CoreVideo formats whose planes can be viewed as textures with `planes` formats.
+    ///
+    /// sRGB formats match their linear variants.
+    ///
+    /// ```
+    /// use cidre::{cv, mtl};
+    ///
+    /// let formats: Vec<_> =
+    ///     cv::PixelFormat::with_metal_planes(&[mtl::PixelFormat::Bgra8UNormSrgb]).collect();
+    /// assert!(formats.contains(&cv::PixelFormat::_32_BGRA));
+    /// assert!(formats.contains(&cv::PixelFormat::LOSSY_32_BGRA));
+    /// ```
+    pub fn with_metal_planes(planes: &[mtl::PixelFormat]) -> impl Iterator<Item = Self> + '_ {
+        TABLE
+            .iter()
+            .filter(move |(_, p)| {
+                p.len() == planes.len()
+                    && p.iter()
+                        .zip(planes)
+                        .all(|(a, b)| a.format == b.linear_variant())
+            })
+            .map(|(format, _)| *format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cv, mtl};
+
+    #[test]
+    fn planes() {
+        for (format, planes) in super::TABLE {
+            assert!(!planes.is_empty(), "{format:?}");
+            assert_eq!(format.metal_plane_count(), planes.len());
+            let formats: Vec<_> = planes.iter().map(|p| p.format).collect();
+            assert!(
+                cv::PixelFormat::with_metal_planes(&formats).any(|f| f == *format),
+                "{format:?}"
+            );
+            // luma or packed plane is never subsampled
+            assert_eq!(planes[0].subsampling, (1, 1), "{format:?}");
+        }
+
+        assert_eq!(cv::PixelFormat::_24_RGB.metal_planes(), None);
+        assert_eq!(cv::PixelFormat::_24_RGB.metal_plane_count(), 0);
+
+        let p = cv::PixelFormat::_422_YP_CB_CR_10_BI_PLANAR_VIDEO_RANGE
+            .metal_plane(1)
+            .unwrap();
+        assert_eq!(p.format, mtl::PixelFormat::Rg16UNorm);
+        assert_eq!(p.size(1920, 1080), (960, 1080));
+        assert_eq!(p.bytes_per_element(), 4);
+        assert_eq!(p.bytes_per_row(1921), 961 * 4);
+
+        let p = cv::PixelFormat::_2VUY.metal_plane(0).unwrap();
+        assert_eq!(p.format, mtl::PixelFormat::Bgrg422);
+        assert_eq!(p.bytes_per_row(1920), 3840);
+    }
+
+    #[test]
+    fn reverse() {
+        let formats: Vec<_> = cv::PixelFormat::with_metal_planes(&[
+            mtl::PixelFormat::R8UNorm,
+            mtl::PixelFormat::Rg8UNorm,
+        ])
+        .collect();
+        assert_eq!(
+            formats,
+            [
+                cv::PixelFormat::_420V,
+                cv::PixelFormat::_420F,
+                cv::PixelFormat::LOSSLESS_420V,
+                cv::PixelFormat::LOSSLESS_420F,
+                cv::PixelFormat::LOSSY_420V,
+                cv::PixelFormat::LOSSY_420F,
+            ]
+        );
+
+        let mut formats = cv::PixelFormat::with_metal_planes(&[mtl::PixelFormat::R32Float]);
+        assert_eq!(
+            formats.next(),
+            Some(cv::PixelFormat::ONE_COMPONENT_32_FLOAT)
+        );
+
+        assert_eq!(
+            cv::PixelFormat::with_metal_planes(&[mtl::PixelFormat::Astc4x4Ldr]).count(),
+            0
+        );
+    }
+}
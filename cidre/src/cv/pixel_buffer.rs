@@ -361,6 +361,10 @@ impl PixelFormat {
     #[doc(alias = "kCVPixelFormatType_422YpCbCr8")]
     pub const _2VUY: Self = Self::_422_YP_CB_CR_8;
 
+    /// Component Y'CbCr 8-bit 4:2:2, ordered Y'0 Cb Y'1 Cr
+    #[doc(alias = "kCVPixelFormatType_422YpCbCr8_yuvs")]
+    pub const _422_YP_CB_CR_8_YUVS: Self = Self(os::Type::from_be_bytes(*b"yuvs"));
+
     /// Component Y'CbCrA 8-bit 4:4:4:4, ordered Cb Y' Cr A
     #[doc(alias = "kCVPixelFormatType_4444YpCbCrA8")]
     pub const _4444_YP_CB_CR_A_8: Self = Self(os::Type::from_be_bytes(*b"v408"));
@@ -451,12 +455,24 @@ impl PixelFormat {
     #[doc(alias = "kCVPixelFormatType_OneComponent8")]
     pub const ONE_COMPONENT_8: Self = Self(os::Type::from_be_bytes(*b"L008"));
 
+    /// 16 bit one component, full-range (0-65535) samples
+    #[doc(alias = "kCVPixelFormatType_OneComponent16")]
+    pub const ONE_COMPONENT_16: Self = Self(os::Type::from_be_bytes(*b"L016"));
+
     #[doc(alias = "kCVPixelFormatType_OneComponent16Half")]
     pub const ONE_COMPONENT_16_HALF: Self = Self(os::Type::from_be_bytes(*b"L00h"));
 
     #[doc(alias = "kCVPixelFormatType_OneComponent32Float")]
     pub const ONE_COMPONENT_32_FLOAT: Self = Self(os::Type::from_be_bytes(*b"L00f"));
 
+    /// 16 bit two component, 8-bit samples
+    #[doc(alias = "kCVPixelFormatType_TwoComponent8")]
+    pub const TWO_COMPONENT_8: Self = Self(os::Type::from_be_bytes(*b"2C08"));
+
+    /// 32 bit two component, 16-bit little-endian full-range samples
+    #[doc(alias = "kCVPixelFormatType_TwoComponent16")]
+    pub const TWO_COMPONENT_16: Self = Self(os::Type::from_be_bytes(*b"2C16"));
+
     /// 16 bit two component IEEE half-precision float, 16-bit little-endian samples
     #[doc(alias = "kCVPixelFormatType_TwoComponent16Half")]
     pub const TWO_COMPONENT_16_HALF: Self = Self(os::Type::from_be_bytes(*b"2C0h"));
@@ -473,6 +489,30 @@ impl PixelFormat {
     #[doc(alias = "kCVPixelFormatType_128RGBAFloat")]
     pub const _128_RGBA_FLOAT: Self = Self(os::Type::from_be_bytes(*b"RGfA"));
 
+    /// little-endian RGB101010, 2 MSB are zero, wide-gamut (384-895)
+    #[doc(alias = "kCVPixelFormatType_30RGBLEPackedWideGamut")]
+    pub const _30_RGB_LE_PACKED_WIDE_GAMUT: Self = Self(os::Type::from_be_bytes(*b"w30r"));
+
+    /// little-endian ARGB10101010, each 10 bits in the MSBs of 16bits, wide-gamut (384-895, including alpha)
+    #[doc(alias = "kCVPixelFormatType_40ARGBLEWideGamut")]
+    pub const _40_ARGB_LE_WIDE_GAMUT: Self = Self(os::Type::from_be_bytes(*b"w40a"));
+
+    /// IEEE754-2008 binary16 (half float), describing the normalized shift when comparing two images. Units are 1/meters: ( pixelShift / (pixelFocalLength * baselineInMeters) )
+    #[doc(alias = "kCVPixelFormatType_DisparityFloat16")]
+    pub const DISPARITY_FLOAT_16: Self = Self(os::Type::from_be_bytes(*b"hdis"));
+
+    /// IEEE754-2008 binary32 float, describing the normalized shift when comparing two images. Units are 1/meters: ( pixelShift / (pixelFocalLength * baselineInMeters) )
+    #[doc(alias = "kCVPixelFormatType_DisparityFloat32")]
+    pub const DISPARITY_FLOAT_32: Self = Self(os::Type::from_be_bytes(*b"fdis"));
+
+    /// IEEE754-2008 binary16 (half float), describing the depth (distance to an object) in meters
+    #[doc(alias = "kCVPixelFormatType_DepthFloat16")]
+    pub const DEPTH_FLOAT_16: Self = Self(os::Type::from_be_bytes(*b"hdep"));
+
+    /// IEEE754-2008 binary32 float, describing the depth (distance to an object) in meters
+    #[doc(alias = "kCVPixelFormatType_DepthFloat32")]
+    pub const DEPTH_FLOAT_32: Self = Self(os::Type::from_be_bytes(*b"fdep"));
+
     pub fn from_cf_number(number: &cf::Number) -> Self {
         Self(number.to_i32().unwrap_or(0) as u32)
     }
@@ -27,7 +27,7 @@ impl Color {
     pub fn b(&self) -> f32;
 }
 
-#[link(name = "gc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "gc", kind = "static"))]
 unsafe extern "C" {
     static GC_COLOR: &'static objc::Class<Color>;
 }
@@ -90,7 +90,7 @@ impl Controller {
     }
 }
 
-#[link(name = "gc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "gc", kind = "static"))]
 unsafe extern "C" {
     static GC_CONTROLLER: &'static objc::Class<Controller>;
 }
@@ -75,6 +75,7 @@ pub mod io;
 pub mod blocks;
 
 /// mach
+#[cfg(target_vendor = "apple")]
 pub mod mach;
 
 /// MultipeerConnectivity
@@ -118,6 +119,7 @@ pub mod objc;
 pub mod gc;
 
 pub mod os;
+#[cfg(target_vendor = "apple")]
 pub mod sys;
 
 /// Security
@@ -308,7 +310,7 @@ macro_rules! define_opts {
     };
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "cf"))]
 mod tests {
     use crate::cf;
 
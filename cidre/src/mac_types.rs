@@ -36,7 +36,7 @@ mod tests {
 
     #[test]
     fn basics() {
-        let mut x: [u8; 4] = (*b"24BG").into();
+        let mut x = *b"24BG";
         let s = four_cc_to_str(&mut x);
         assert_eq!(s, "24BG");
 
@@ -60,7 +60,7 @@ define_obj_type!(
 
 impl Delegate for AnyDelegate {}
 
-#[link(name = "mc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mc", kind = "static"))]
 unsafe extern "C" {
     static MC_ADVERTISER_ASSISTANT: &'static objc::Class<AdvertiserAssistant>;
 }
@@ -38,7 +38,7 @@ impl ActivationDesc {
     ) -> Option<arc::R<Self>>;
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_ACTIVATION_DESCRIPTOR: &'static objc::Class<ActivationDesc>;
 }
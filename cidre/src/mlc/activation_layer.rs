@@ -96,7 +96,7 @@ impl ActivationLayer {
     pub fn clamp(min: f32, max: f32) -> arc::R<Self>;
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_ACTIVATION_LAYER: &'static objc::Class<ActivationLayer>;
 }
@@ -42,7 +42,7 @@ impl AdamOptimizer {
     ) -> arc::R<Self>;
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_ADAM_OPTIMIZER: &'static objc::Class<AdamOptimizer>;
 }
@@ -33,7 +33,7 @@ impl AdamWOptimizer {
     ) -> arc::R<Self>;
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_ADAMW_OPTIMIZER: &'static objc::Class<AdamWOptimizer>;
 }
@@ -12,7 +12,7 @@ impl ArithmeticLayer {
     pub fn with_op(op: mlc::ArithmeticOp) -> arc::R<Self>;
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_ARITHMETIC_LAYER: &'static objc::Class<ArithmeticLayer>;
 }
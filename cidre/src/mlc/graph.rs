@@ -24,7 +24,7 @@ impl Graph {
     }
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_GRAPH: &'static objc::Class<Graph>;
 }
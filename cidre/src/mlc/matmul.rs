@@ -44,7 +44,7 @@ impl Layer {
     }
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_MATMUL_DESCRIPTOR: &'static objc::Class<Desc>;
     static MLC_MATMUL_LAYER: &'static objc::Class<Layer>;
@@ -76,7 +76,7 @@ impl OptimizerDesc {
     ) -> arc::R<Self>;
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_OPTIMIZER_DESCRIPTOR: &'static objc::Class<OptimizerDesc>;
 }
@@ -58,7 +58,7 @@ impl Tensor {
     }
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_TENSOR: &'static objc::Class<Tensor>;
 }
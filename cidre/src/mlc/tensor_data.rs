@@ -31,7 +31,7 @@ impl TensorData {
     }
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_TENSOR_DATA: &'static objc::Class<TensorData>;
 }
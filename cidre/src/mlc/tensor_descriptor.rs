@@ -111,7 +111,7 @@ impl TensorDesc {
     ) -> Option<arc::R<Self>>;
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_TENSOR_DESCRIPTOR: &'static objc::Class<TensorDesc>;
 }
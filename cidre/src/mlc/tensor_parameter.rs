@@ -23,7 +23,7 @@ impl TensorParameter {
     ) -> arc::R<Self>;
 }
 
-#[link(name = "mlc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mlc", kind = "static"))]
 unsafe extern "C" {
     static MLC_TENSOR_PARAMETER: &'static objc::Class<TensorParameter>;
 }
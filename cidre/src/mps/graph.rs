@@ -132,7 +132,7 @@ impl Graph {
     pub fn set_opts(&mut self, value: Opts);
 }
 
-#[link(name = "mpsg", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mpsg", kind = "static"))]
 unsafe extern "C" {
     static MPS_GRAPH: &'static objc::Class<Graph>;
 }
@@ -296,7 +296,7 @@ impl OptionTexture {
     }
 }
 
-#[link(name = "mtk", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mtk", kind = "static"))]
 unsafe extern "C" {
     static MTK_TEXTURE_LOADER: &'static objc::Class<TextureLoader>;
 }
@@ -31,6 +31,7 @@ pub use acceleration_structure_types::PackedF32x3;
 pub use acceleration_structure_types::PackedF32x4x3;
 
 mod pixel_format;
+pub use pixel_format::ComponentType as PixelComponentType;
 pub use pixel_format::PixelFormat;
 
 mod argument;
@@ -74,7 +74,7 @@ impl Desc {
     pub fn sample_buf_attaches_mut(&mut self) -> &mut SampleBufAttachDescArray;
 }
 
-#[link(name = "mtl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mtl", kind = "static"))]
 unsafe extern "C" {
     static MTL_BLIT_PASS_DESCRIPTOR: &'static objc::Class<Desc>;
 }
@@ -65,7 +65,7 @@ impl CaptureManager {
     pub fn is_capturing(&self) -> bool;
 }
 
-#[link(name = "mtl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mtl", kind = "static"))]
 unsafe extern "C" {
     static MTL_CAPTURE_DESCRIPTOR: &'static objc::Class<CaptureDesc>;
     static MTL_CAPTURE_MANAGER: &'static objc::Class<CaptureManager>;
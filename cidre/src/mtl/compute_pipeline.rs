@@ -43,7 +43,7 @@ impl Desc {
     pub fn reset(&mut self);
 }
 
-#[link(name = "mtl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mtl", kind = "static"))]
 unsafe extern "C" {
     static MTL_COMPUTE_PIPELINE_DESCRIPTOR: &'static objc::Class<Desc>;
 }
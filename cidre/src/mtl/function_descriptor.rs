@@ -74,7 +74,7 @@ impl FnDesc {
     pub fn set_opts(&mut self, val: mtl::FnOpts);
 }
 
-#[link(name = "mtl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mtl", kind = "static"))]
 unsafe extern "C" {
     static MTL_FUNCTION_DESCRIPTOR: &'static objc::Class<FnDesc>;
 }
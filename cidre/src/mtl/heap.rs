@@ -168,7 +168,7 @@ impl Heap {
     pub fn type_(&self) -> Type;
 }
 
-#[link(name = "mtl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mtl", kind = "static"))]
 unsafe extern "C" {
     static MTL_HEAP_DESCRIPTOR: &'static objc::Class<Desc>;
 }
@@ -81,7 +81,7 @@ impl IndirectRenderCmd {
 
     /// Encodes a command to render a number of instances of primitives using vertex data
     /// in contiguous array elements, starting from the base instance.
-    #[objc::msg_send(drawPrimitives:vertexStart:vertexCount:instanceCount:baseInstance:usize)]
+    #[objc::msg_send(drawPrimitives:vertexStart:vertexCount:instanceCount:baseInstance:)]
     pub fn draw_primitives(
         &self,
         primitive_type: mtl::Primitive,
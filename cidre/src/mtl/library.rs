@@ -431,7 +431,7 @@ unsafe extern "C" {
     static MTLLibraryErrorDomain: &'static ErrorDomain;
 }
 
-#[link(name = "mtl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mtl", kind = "static"))]
 unsafe extern "C" {
     static MTL_COMPILE_OPTIONS: &'static objc::Class<CompileOpts>;
 }
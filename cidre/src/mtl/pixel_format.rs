@@ -337,6 +337,22 @@ pub enum PixelFormat {
     #[doc(alias = "MTLPixelFormatBC3_RGBA_sRGB")]
     Bc3RgbaSrgb = 135,
 
+    /// Compressed format with one normalized unsigned integer component.
+    #[doc(alias = "MTLPixelFormatBC4_RUnorm")]
+    Bc4RUNorm = 140,
+
+    /// Compressed format with one normalized signed integer component.
+    #[doc(alias = "MTLPixelFormatBC4_RSnorm")]
+    Bc4RSNorm = 141,
+
+    /// Compressed format with two normalized unsigned integer components.
+    #[doc(alias = "MTLPixelFormatBC5_RGUnorm")]
+    Bc5RgUNorm = 142,
+
+    /// Compressed format with two normalized signed integer components.
+    #[doc(alias = "MTLPixelFormatBC5_RGSnorm")]
+    Bc5RgSNorm = 143,
+
     /// Compressed format with four floating-point components.
     #[doc(alias = "MTLPixelFormatBC6H_RGBFloat")]
     Bc6HRgbFloat = 150,
@@ -354,6 +370,72 @@ pub enum PixelFormat {
     #[doc(alias = "MTLPixelFormatBC7_RGBAUnorm_sRGB")]
     Bc7RgbaUNormSrgb = 153,
 
+    /// PVRTC-compressed format with three components and 2 bits per pixel.
+    #[doc(alias = "MTLPixelFormatPVRTC_RGB_2BPP")]
+    PvrtcRgb2Bpp = 160,
+
+    #[doc(alias = "MTLPixelFormatPVRTC_RGB_2BPP_sRGB")]
+    PvrtcRgb2BppSrgb = 161,
+
+    /// PVRTC-compressed format with three components and 4 bits per pixel.
+    #[doc(alias = "MTLPixelFormatPVRTC_RGB_4BPP")]
+    PvrtcRgb4Bpp = 162,
+
+    #[doc(alias = "MTLPixelFormatPVRTC_RGB_4BPP_sRGB")]
+    PvrtcRgb4BppSrgb = 163,
+
+    /// PVRTC-compressed format with four components and 2 bits per pixel.
+    #[doc(alias = "MTLPixelFormatPVRTC_RGBA_2BPP")]
+    PvrtcRgba2Bpp = 164,
+
+    #[doc(alias = "MTLPixelFormatPVRTC_RGBA_2BPP_sRGB")]
+    PvrtcRgba2BppSrgb = 165,
+
+    /// PVRTC-compressed format with four components and 4 bits per pixel.
+    #[doc(alias = "MTLPixelFormatPVRTC_RGBA_4BPP")]
+    PvrtcRgba4Bpp = 166,
+
+    #[doc(alias = "MTLPixelFormatPVRTC_RGBA_4BPP_sRGB")]
+    PvrtcRgba4BppSrgb = 167,
+
+    /// EAC-compressed format with one normalized unsigned integer component.
+    #[doc(alias = "MTLPixelFormatEAC_R11Unorm")]
+    EacR11UNorm = 170,
+
+    /// EAC-compressed format with one normalized signed integer component.
+    #[doc(alias = "MTLPixelFormatEAC_R11Snorm")]
+    EacR11SNorm = 172,
+
+    /// EAC-compressed format with two normalized unsigned integer components.
+    #[doc(alias = "MTLPixelFormatEAC_RG11Unorm")]
+    EacRg11UNorm = 174,
+
+    /// EAC-compressed format with two normalized signed integer components.
+    #[doc(alias = "MTLPixelFormatEAC_RG11Snorm")]
+    EacRg11SNorm = 176,
+
+    /// EAC-compressed format with four normalized unsigned integer components.
+    #[doc(alias = "MTLPixelFormatEAC_RGBA8")]
+    EacRgba8 = 178,
+
+    #[doc(alias = "MTLPixelFormatEAC_RGBA8_sRGB")]
+    EacRgba8Srgb = 179,
+
+    /// ETC2-compressed format with three normalized unsigned integer components.
+    #[doc(alias = "MTLPixelFormatETC2_RGB8")]
+    Etc2Rgb8 = 180,
+
+    #[doc(alias = "MTLPixelFormatETC2_RGB8_sRGB")]
+    Etc2Rgb8Srgb = 181,
+
+    /// ETC2-compressed format with three normalized unsigned integer color components
+    /// and 1-bit alpha.
+    #[doc(alias = "MTLPixelFormatETC2_RGB8A1")]
+    Etc2Rgb8A1 = 182,
+
+    #[doc(alias = "MTLPixelFormatETC2_RGB8A1_sRGB")]
+    Etc2Rgb8A1Srgb = 183,
+
     /// ASTC-compressed format with low-dynamic-range content,
     /// conversion between sRGB and linear space, a block width of 4, and a block height of 4.
     #[doc(alias = "MTLPixelFormatASTC_4x4_sRGB")]
@@ -428,7 +510,7 @@ pub enum PixelFormat {
     Astc10x8Hdr = 233,
     Astc10x10Hdr = 234,
     Astc12x10Hdr = 235,
-    Asrc12x12Hdr = 236,
+    Astc12x12Hdr = 236,
 
     /// A pixel format where the red and green channels are subsampled horizontally.
     /// Two pixels are stored in 32 bits, with shared red and blue values, and unique green values.
@@ -498,3 +580,587 @@ pub enum PixelFormat {
     #[doc(alias = "MTLPixelFormatX24_Stencil8")]
     X24Stencil8 = 262,
 }
+
+/// Numeric interpretation of pixel format components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ComponentType {
+    /// Normalized unsigned integer, sampled as `[0.0, 1.0]`.
+    UNorm,
+    /// Normalized signed integer, sampled as `[-1.0, 1.0]`.
+    SNorm,
+    UInt,
+    SInt,
+    Float,
+}
+
+/// (block width, block height, bytes per block, components, component type)
+type Layout = (usize, usize, usize, usize, ComponentType);
+
+impl PixelFormat {
+    #[allow(non_upper_case_globals)]
+    #[deprecated(note = "use Astc12x12Hdr")]
+    pub const Asrc12x12Hdr: Self = Self::Astc12x12Hdr;
+
+    const fn layout(&self) -> Layout {
+        use ComponentType::*;
+        use PixelFormat::*;
+        match self {
+            Invalid => (1, 1, 0, 0, UNorm),
+
+            A8UNorm | R8UNorm | R8UNormSrgb => (1, 1, 1, 1, UNorm),
+            R8SNorm => (1, 1, 1, 1, SNorm),
+            R8UInt | Stencil8 => (1, 1, 1, 1, UInt),
+            R8SInt => (1, 1, 1, 1, SInt),
+
+            R16UNorm | Depth16Unorm => (1, 1, 2, 1, UNorm),
+            R16SNorm => (1, 1, 2, 1, SNorm),
+            R16UInt => (1, 1, 2, 1, UInt),
+            R16SInt => (1, 1, 2, 1, SInt),
+            R16Float => (1, 1, 2, 1, Float),
+            Rg8UNorm | Rg8UNormSrgb => (1, 1, 2, 2, UNorm),
+            Rg8SNorm => (1, 1, 2, 2, SNorm),
+            Rg8UInt => (1, 1, 2, 2, UInt),
+            Rg8SInt => (1, 1, 2, 2, SInt),
+
+            B5G6R5UNorm => (1, 1, 2, 3, UNorm),
+            A1Bgr5UNorm | Abgr4UNorm | Bgr5A1UNorm => (1, 1, 2, 4, UNorm),
+
+            R32UInt => (1, 1, 4, 1, UInt),
+            R32SInt => (1, 1, 4, 1, SInt),
+            R32Float | Depth32Float => (1, 1, 4, 1, Float),
+            Rg16UNorm => (1, 1, 4, 2, UNorm),
+            Rg16SNorm => (1, 1, 4, 2, SNorm),
+            Rg16UInt => (1, 1, 4, 2, UInt),
+            Rg16SInt => (1, 1, 4, 2, SInt),
+            Rg16Float => (1, 1, 4, 2, Float),
+            Rgba8UNorm | Rgba8UNormSrgb | Bgra8UNorm | Bgra8UNormSrgb => (1, 1, 4, 4, UNorm),
+            Rgba8SNorm => (1, 1, 4, 4, SNorm),
+            Rgba8UInt => (1, 1, 4, 4, UInt),
+            Rgba8SInt => (1, 1, 4, 4, SInt),
+
+            Rgb10A2UNorm | Bgr10A2UNorm => (1, 1, 4, 4, UNorm),
+            Rgb10A2UInt => (1, 1, 4, 4, UInt),
+            Rg11B10Float | Rgb9E5Float | Bgr10Xr | Bgr10XrSrgb => (1, 1, 4, 3, Float),
+
+            Rg32UInt => (1, 1, 8, 2, UInt),
+            Rg32SInt => (1, 1, 8, 2, SInt),
+            Rg32Float => (1, 1, 8, 2, Float),
+            Rgba16UNorm => (1, 1, 8, 4, UNorm),
+            Rgba16SNorm => (1, 1, 8, 4, SNorm),
+            Rgba16UInt => (1, 1, 8, 4, UInt),
+            Rgba16SInt => (1, 1, 8, 4, SInt),
+            Rgba16Float | Bgra10Xr | Bgra10XrSrgb => (1, 1, 8, 4, Float),
+
+            Rgba32UInt => (1, 1, 16, 4, UInt),
+            Rgba32SInt => (1, 1, 16, 4, SInt),
+            Rgba32Float => (1, 1, 16, 4, Float),
+
+            Bc1Rgba | Bc1RgbaSrgb => (4, 4, 8, 4, UNorm),
+            Bc2Rgba | Bc2RgbaSrgb | Bc3Rgba | Bc3RgbaSrgb => (4, 4, 16, 4, UNorm),
+            Bc4RUNorm => (4, 4, 8, 1, UNorm),
+            Bc4RSNorm => (4, 4, 8, 1, SNorm),
+            Bc5RgUNorm => (4, 4, 16, 2, UNorm),
+            Bc5RgSNorm => (4, 4, 16, 2, SNorm),
+            Bc6HRgbFloat | Bc6HRgbUFloat => (4, 4, 16, 3, Float),
+            Bc7RgbaUNorm | Bc7RgbaUNormSrgb => (4, 4, 16, 4, UNorm),
+
+            PvrtcRgb2Bpp | PvrtcRgb2BppSrgb => (8, 4, 8, 3, UNorm),
+            PvrtcRgb4Bpp | PvrtcRgb4BppSrgb => (4, 4, 8, 3, UNorm),
+            PvrtcRgba2Bpp | PvrtcRgba2BppSrgb => (8, 4, 8, 4, UNorm),
+            PvrtcRgba4Bpp | PvrtcRgba4BppSrgb => (4, 4, 8, 4, UNorm),
+
+            EacR11UNorm => (4, 4, 8, 1, UNorm),
+            EacR11SNorm => (4, 4, 8, 1, SNorm),
+            EacRg11UNorm => (4, 4, 16, 2, UNorm),
+            EacRg11SNorm => (4, 4, 16, 2, SNorm),
+            EacRgba8 | EacRgba8Srgb => (4, 4, 16, 4, UNorm),
+            Etc2Rgb8 | Etc2Rgb8Srgb => (4, 4, 8, 3, UNorm),
+            Etc2Rgb8A1 | Etc2Rgb8A1Srgb => (4, 4, 8, 4, UNorm),
+
+            Astc4x4Srgb | Astc4x4Ldr => (4, 4, 16, 4, UNorm),
+            Astc5x4Srgb | Astc5x4Ldr => (5, 4, 16, 4, UNorm),
+            Astc5x5Srgb | Astc5x5Ldr => (5, 5, 16, 4, UNorm),
+            Astc6x5Srgb | Astc6x5Ldr => (6, 5, 16, 4, UNorm),
+            Astc6x6Srgb | Astc6x6Ldr => (6, 6, 16, 4, UNorm),
+            Astc8x5Srgb | Astc8x5Ldr => (8, 5, 16, 4, UNorm),
+            Astc8x6Srgb | Astc8x6Ldr => (8, 6, 16, 4, UNorm),
+            Astc8x8Srgb | Astc8x8Ldr => (8, 8, 16, 4, UNorm),
+            Astc10x5Srgb | Astc10x5Ldr => (10, 5, 16, 4, UNorm),
+            Astc10x6Srgb | Astc10x6Ldr => (10, 6, 16, 4, UNorm),
+            Astc10x8Srgb | Astc10x8Ldr => (10, 8, 16, 4, UNorm),
+            Astc10x10Srgb | Astc10x10Ldr => (10, 10, 16, 4, UNorm),
+            Astc12x10Srgb | Astc12x10Ldr => (12, 10, 16, 4, UNorm),
+            Astc12x12Srgb | Astc12x12Ldr => (12, 12, 16, 4, UNorm),
+            Astc4x4Hdr => (4, 4, 16, 4, Float),
+            Astc5x4Hdr => (5, 4, 16, 4, Float),
+            Astc5x5Hdr => (5, 5, 16, 4, Float),
+            Astc6x5Hdr => (6, 5, 16, 4, Float),
+            Astc6x6Hdr => (6, 6, 16, 4, Float),
+            Astc8x5Hdr => (8, 5, 16, 4, Float),
+            Astc8x6Hdr => (8, 6, 16, 4, Float),
+            Astc8x8Hdr => (8, 8, 16, 4, Float),
+            Astc10x5Hdr => (10, 5, 16, 4, Float),
+            Astc10x6Hdr => (10, 6, 16, 4, Float),
+            Astc10x8Hdr => (10, 8, 16, 4, Float),
+            Astc10x10Hdr => (10, 10, 16, 4, Float),
+            Astc12x10Hdr => (12, 10, 16, 4, Float),
+            Astc12x12Hdr => (12, 12, 16, 4, Float),
+
+            Gbgr422 | Bgrg422 => (2, 1, 4, 3, UNorm),
+
+            // depth-stencil formats report type of depth component
+            Depth24UnormStencil8 => (1, 1, 4, 2, UNorm),
+            Depth32FloatStencil8 => (1, 1, 8, 2, Float),
+            X32Stencil8 => (1, 1, 8, 1, UInt),
+            X24Stencil8 => (1, 1, 4, 1, UInt),
+        }
+    }
+
+    /// Width and height of the compression block in pixels, `(1, 1)` for ordinary formats.
+    #[inline]
+    pub const fn block_size(&self) -> (usize, usize) {
+        let (w, h, ..) = self.layout();
+        (w, h)
+    }
+
+    /// Size of the block in bytes (size of pixel for ordinary formats).
+    ///
+    /// `Depth32FloatStencil8` and `X32Stencil8` report 8 bytes which is what
+    /// Metal allocates on most devices.
+    #[inline]
+    pub const fn bytes_per_block(&self) -> usize {
+        self.layout().2
+    }
+
+    /// Size of a pixel in bytes or `None` for block formats.
+    ///
+    /// ```
+    /// use cidre::mtl;
+    ///
+    /// assert_eq!(mtl::PixelFormat::Rgba16Float.bytes_per_pixel(), Some(8));
+    /// assert_eq!(mtl::PixelFormat::Bc1Rgba.bytes_per_pixel(), None);
+    /// ```
+    #[inline]
+    pub const fn bytes_per_pixel(&self) -> Option<usize> {
+        match self.layout() {
+            (1, 1, 0, ..) => None,
+            (1, 1, bytes, ..) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Number of components sampled from the texture.
+    #[inline]
+    pub const fn component_count(&self) -> usize {
+        self.layout().3
+    }
+
+    /// `None` for `Invalid`. Combined depth-stencil formats return type of depth component.
+    #[inline]
+    pub const fn component_type(&self) -> Option<ComponentType> {
+        match self {
+            Self::Invalid => None,
+            _ => Some(self.layout().4),
+        }
+    }
+
+    /// Block compressed formats (BC, PVRTC, EAC/ETC2, ASTC).
+    ///
+    /// 422 formats have a 2x1 block but aren't compressed.
+    #[inline]
+    pub const fn is_compressed(&self) -> bool {
+        !matches!(self.layout(), (1, 1, ..)) && !self.is_422()
+    }
+
+    /// `Gbgr422` and `Bgrg422`.
+    #[inline]
+    pub const fn is_422(&self) -> bool {
+        matches!(self, Self::Gbgr422 | Self::Bgrg422)
+    }
+
+    #[inline]
+    pub const fn is_astc(&self) -> bool {
+        let raw = *self as usize;
+        raw >= Self::Astc4x4Srgb as usize && raw <= Self::Astc12x12Hdr as usize
+    }
+
+    #[inline]
+    pub const fn is_hdr(&self) -> bool {
+        let raw = *self as usize;
+        raw >= Self::Astc4x4Hdr as usize && raw <= Self::Astc12x12Hdr as usize
+    }
+
+    /// Extended range formats for wide color displays.
+    #[inline]
+    pub const fn is_xr(&self) -> bool {
+        matches!(
+            self,
+            Self::Bgr10Xr | Self::Bgr10XrSrgb | Self::Bgra10Xr | Self::Bgra10XrSrgb
+        )
+    }
+
+    #[inline]
+    pub const fn is_srgb(&self) -> bool {
+        match self.srgb_pair() {
+            (_, Self::Invalid) => false,
+            (_, srgb) => srgb as usize == *self as usize,
+        }
+    }
+
+    #[inline]
+    pub const fn is_depth(&self) -> bool {
+        matches!(
+            self,
+            Self::Depth16Unorm
+                | Self::Depth32Float
+                | Self::Depth24UnormStencil8
+                | Self::Depth32FloatStencil8
+        )
+    }
+
+    #[inline]
+    pub const fn is_stencil(&self) -> bool {
+        matches!(
+            self,
+            Self::Stencil8
+                | Self::Depth24UnormStencil8
+                | Self::Depth32FloatStencil8
+                | Self::X24Stencil8
+                | Self::X32Stencil8
+        )
+    }
+
+    #[inline]
+    pub const fn is_depth_or_stencil(&self) -> bool {
+        self.is_depth() || self.is_stencil()
+    }
+
+    /// (linear, sRGB) pair of the format. sRGB is `Invalid` if format has no sRGB variant.
+    const fn srgb_pair(&self) -> (Self, Self) {
+        use PixelFormat::*;
+        match self {
+            R8UNorm | R8UNormSrgb => (R8UNorm, R8UNormSrgb),
+            Rg8UNorm | Rg8UNormSrgb => (Rg8UNorm, Rg8UNormSrgb),
+            Rgba8UNorm | Rgba8UNormSrgb => (Rgba8UNorm, Rgba8UNormSrgb),
+            Bgra8UNorm | Bgra8UNormSrgb => (Bgra8UNorm, Bgra8UNormSrgb),
+            Bgr10Xr | Bgr10XrSrgb => (Bgr10Xr, Bgr10XrSrgb),
+            Bgra10Xr | Bgra10XrSrgb => (Bgra10Xr, Bgra10XrSrgb),
+            Bc1Rgba | Bc1RgbaSrgb => (Bc1Rgba, Bc1RgbaSrgb),
+            Bc2Rgba | Bc2RgbaSrgb => (Bc2Rgba, Bc2RgbaSrgb),
+            Bc3Rgba | Bc3RgbaSrgb => (Bc3Rgba, Bc3RgbaSrgb),
+            Bc7RgbaUNorm | Bc7RgbaUNormSrgb => (Bc7RgbaUNorm, Bc7RgbaUNormSrgb),
+            PvrtcRgb2Bpp | PvrtcRgb2BppSrgb => (PvrtcRgb2Bpp, PvrtcRgb2BppSrgb),
+            PvrtcRgb4Bpp | PvrtcRgb4BppSrgb => (PvrtcRgb4Bpp, PvrtcRgb4BppSrgb),
+            PvrtcRgba2Bpp | PvrtcRgba2BppSrgb => (PvrtcRgba2Bpp, PvrtcRgba2BppSrgb),
+            PvrtcRgba4Bpp | PvrtcRgba4BppSrgb => (PvrtcRgba4Bpp, PvrtcRgba4BppSrgb),
+            EacRgba8 | EacRgba8Srgb => (EacRgba8, EacRgba8Srgb),
+            Etc2Rgb8 | Etc2Rgb8Srgb => (Etc2Rgb8, Etc2Rgb8Srgb),
+            Etc2Rgb8A1 | Etc2Rgb8A1Srgb => (Etc2Rgb8A1, Etc2Rgb8A1Srgb),
+            Astc4x4Srgb | Astc4x4Ldr => (Astc4x4Ldr, Astc4x4Srgb),
+            Astc5x4Srgb | Astc5x4Ldr => (Astc5x4Ldr, Astc5x4Srgb),
+            Astc5x5Srgb | Astc5x5Ldr => (Astc5x5Ldr, Astc5x5Srgb),
+            Astc6x5Srgb | Astc6x5Ldr => (Astc6x5Ldr, Astc6x5Srgb),
+            Astc6x6Srgb | Astc6x6Ldr => (Astc6x6Ldr, Astc6x6Srgb),
+            Astc8x5Srgb | Astc8x5Ldr => (Astc8x5Ldr, Astc8x5Srgb),
+            Astc8x6Srgb | Astc8x6Ldr => (Astc8x6Ldr, Astc8x6Srgb),
+            Astc8x8Srgb | Astc8x8Ldr => (Astc8x8Ldr, Astc8x8Srgb),
+            Astc10x5Srgb | Astc10x5Ldr => (Astc10x5Ldr, Astc10x5Srgb),
+            Astc10x6Srgb | Astc10x6Ldr => (Astc10x6Ldr, Astc10x6Srgb),
+            Astc10x8Srgb | Astc10x8Ldr => (Astc10x8Ldr, Astc10x8Srgb),
+            Astc10x10Srgb | Astc10x10Ldr => (Astc10x10Ldr, Astc10x10Srgb),
+            Astc12x10Srgb | Astc12x10Ldr => (Astc12x10Ldr, Astc12x10Srgb),
+            Astc12x12Srgb | Astc12x12Ldr => (Astc12x12Ldr, Astc12x12Srgb),
+            _ => (*self, Invalid),
+        }
+    }
+
+    /// Format with conversion between sRGB and linear space or `None` if there is no such format.
+    ///
+    /// ```
+    /// use cidre::mtl;
+    ///
+    /// let f = mtl::PixelFormat::Bgra8UNorm;
+    /// assert_eq!(f.srgb_variant(), Some(mtl::PixelFormat::Bgra8UNormSrgb));
+    /// assert_eq!(f.srgb_variant().unwrap().linear_variant(), f);
+    /// assert_eq!(mtl::PixelFormat::R16Float.srgb_variant(), None);
+    /// ```
+    #[inline]
+    pub const fn srgb_variant(&self) -> Option<Self> {
+        match self.srgb_pair() {
+            (_, Self::Invalid) => None,
+            (_, srgb) => Some(srgb),
+        }
+    }
+
+    /// Format without sRGB conversion. Returns `self` for formats that are already linear.
+    #[inline]
+    pub const fn linear_variant(&self) -> Self {
+        self.srgb_pair().0
+    }
+
+    /// Bytes in a row of blocks covering `width` pixels.
+    ///
+    /// ```
+    /// use cidre::mtl;
+    ///
+    /// assert_eq!(mtl::PixelFormat::Bgra8UNorm.bytes_per_row(100), 400);
+    /// assert_eq!(mtl::PixelFormat::Bc1Rgba.bytes_per_row(10), 24);
+    /// assert_eq!(mtl::PixelFormat::Astc8x8Ldr.bytes_per_row(17), 48);
+    /// ```
+    #[inline]
+    pub const fn bytes_per_row(&self, width: usize) -> usize {
+        let (bw, _, bytes, ..) = self.layout();
+        width.div_ceil(bw) * bytes
+    }
+
+    /// Bytes in a 2D image of `width` x `height` pixels.
+    #[inline]
+    pub const fn bytes_per_image(&self, width: usize, height: usize) -> usize {
+        let (_, bh, ..) = self.layout();
+        self.bytes_per_row(width) * height.div_ceil(bh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mtl::{self, PixelComponentType as T, PixelFormat as F};
+
+    const ALL: [F; 139] = [
+        F::Invalid,
+        F::A8UNorm,
+        F::R8UNorm,
+        F::R8UNormSrgb,
+        F::R8SNorm,
+        F::R8UInt,
+        F::R8SInt,
+        F::R16UNorm,
+        F::R16SNorm,
+        F::R16UInt,
+        F::R16SInt,
+        F::R16Float,
+        F::Rg8UNorm,
+        F::Rg8UNormSrgb,
+        F::Rg8SNorm,
+        F::Rg8UInt,
+        F::Rg8SInt,
+        F::B5G6R5UNorm,
+        F::A1Bgr5UNorm,
+        F::Abgr4UNorm,
+        F::Bgr5A1UNorm,
+        F::R32UInt,
+        F::R32SInt,
+        F::R32Float,
+        F::Rg16UNorm,
+        F::Rg16SNorm,
+        F::Rg16UInt,
+        F::Rg16SInt,
+        F::Rg16Float,
+        F::Rgba8UNorm,
+        F::Rgba8UNormSrgb,
+        F::Rgba8SNorm,
+        F::Rgba8UInt,
+        F::Rgba8SInt,
+        F::Bgra8UNorm,
+        F::Bgra8UNormSrgb,
+        F::Rgb10A2UNorm,
+        F::Rgb10A2UInt,
+        F::Rg11B10Float,
+        F::Rgb9E5Float,
+        F::Bgr10A2UNorm,
+        F::Bgr10Xr,
+        F::Bgr10XrSrgb,
+        F::Rg32UInt,
+        F::Rg32SInt,
+        F::Rg32Float,
+        F::Rgba16UNorm,
+        F::Rgba16SNorm,
+        F::Rgba16UInt,
+        F::Rgba16SInt,
+        F::Rgba16Float,
+        F::Bgra10Xr,
+        F::Bgra10XrSrgb,
+        F::Rgba32UInt,
+        F::Rgba32SInt,
+        F::Rgba32Float,
+        F::Bc1Rgba,
+        F::Bc1RgbaSrgb,
+        F::Bc2Rgba,
+        F::Bc2RgbaSrgb,
+        F::Bc3Rgba,
+        F::Bc3RgbaSrgb,
+        F::Bc4RUNorm,
+        F::Bc4RSNorm,
+        F::Bc5RgUNorm,
+        F::Bc5RgSNorm,
+        F::Bc6HRgbFloat,
+        F::Bc6HRgbUFloat,
+        F::Bc7RgbaUNorm,
+        F::Bc7RgbaUNormSrgb,
+        F::PvrtcRgb2Bpp,
+        F::PvrtcRgb2BppSrgb,
+        F::PvrtcRgb4Bpp,
+        F::PvrtcRgb4BppSrgb,
+        F::PvrtcRgba2Bpp,
+        F::PvrtcRgba2BppSrgb,
+        F::PvrtcRgba4Bpp,
+        F::PvrtcRgba4BppSrgb,
+        F::EacR11UNorm,
+        F::EacR11SNorm,
+        F::EacRg11UNorm,
+        F::EacRg11SNorm,
+        F::EacRgba8,
+        F::EacRgba8Srgb,
+        F::Etc2Rgb8,
+        F::Etc2Rgb8Srgb,
+        F::Etc2Rgb8A1,
+        F::Etc2Rgb8A1Srgb,
+        F::Astc4x4Srgb,
+        F::Astc5x4Srgb,
+        F::Astc5x5Srgb,
+        F::Astc6x5Srgb,
+        F::Astc6x6Srgb,
+        F::Astc8x5Srgb,
+        F::Astc8x6Srgb,
+        F::Astc8x8Srgb,
+        F::Astc10x5Srgb,
+        F::Astc10x6Srgb,
+        F::Astc10x8Srgb,
+        F::Astc10x10Srgb,
+        F::Astc12x10Srgb,
+        F::Astc12x12Srgb,
+        F::Astc4x4Ldr,
+        F::Astc5x4Ldr,
+        F::Astc5x5Ldr,
+        F::Astc6x5Ldr,
+        F::Astc6x6Ldr,
+        F::Astc8x5Ldr,
+        F::Astc8x6Ldr,
+        F::Astc8x8Ldr,
+        F::Astc10x5Ldr,
+        F::Astc10x6Ldr,
+        F::Astc10x8Ldr,
+        F::Astc10x10Ldr,
+        F::Astc12x10Ldr,
+        F::Astc12x12Ldr,
+        F::Astc4x4Hdr,
+        F::Astc5x4Hdr,
+        F::Astc5x5Hdr,
+        F::Astc6x5Hdr,
+        F::Astc6x6Hdr,
+        F::Astc8x5Hdr,
+        F::Astc8x6Hdr,
+        F::Astc8x8Hdr,
+        F::Astc10x5Hdr,
+        F::Astc10x6Hdr,
+        F::Astc10x8Hdr,
+        F::Astc10x10Hdr,
+        F::Astc12x10Hdr,
+        F::Astc12x12Hdr,
+        F::Gbgr422,
+        F::Bgrg422,
+        F::Depth16Unorm,
+        F::Depth32Float,
+        F::Stencil8,
+        F::Depth24UnormStencil8,
+        F::Depth32FloatStencil8,
+        F::X32Stencil8,
+        F::X24Stencil8,
+    ];
+
+    #[test]
+    fn exhaustive() {
+        for f in ALL {
+            let (bw, bh) = f.block_size();
+            assert!(bw >= 1 && bh >= 1, "{f:?}");
+            assert_eq!(f.bytes_per_image(bw, bh), f.bytes_per_block(), "{f:?}");
+            assert_eq!(f.bytes_per_image(0, 0), 0, "{f:?}");
+
+            if f == F::Invalid {
+                assert_eq!(f.component_type(), None);
+                continue;
+            }
+            assert!(f.bytes_per_block() > 0, "{f:?}");
+            assert!((1..=4).contains(&f.component_count()), "{f:?}");
+
+            assert_eq!(
+                f.is_compressed(),
+                f.bytes_per_pixel().is_none() && !f.is_422()
+            );
+
+            let linear = f.linear_variant();
+            assert!(!linear.is_srgb(), "{f:?}");
+            assert_eq!(linear.block_size(), f.block_size());
+            assert_eq!(linear.bytes_per_block(), f.bytes_per_block());
+            match f.srgb_variant() {
+                Some(srgb) => {
+                    assert!(srgb.is_srgb(), "{f:?}");
+                    assert_eq!(srgb.linear_variant(), linear);
+                    assert_eq!(f.is_srgb(), srgb == f);
+                }
+                None => {
+                    assert!(!f.is_srgb());
+                    assert_eq!(linear, f);
+                }
+            }
+
+            if f.is_astc() {
+                assert_eq!(f.bytes_per_block(), 16);
+                assert_eq!(f.is_hdr(), f.component_type() == Some(T::Float));
+            }
+            if f.is_depth_or_stencil() {
+                assert!(!f.is_compressed());
+            }
+        }
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(mtl::PixelFormat::R8UNorm.bytes_per_pixel(), Some(1));
+        assert_eq!(mtl::PixelFormat::Rgba32Float.bytes_per_pixel(), Some(16));
+        assert_eq!(mtl::PixelFormat::Gbgr422.bytes_per_pixel(), None);
+        assert_eq!(mtl::PixelFormat::Gbgr422.bytes_per_row(1920), 3840);
+        assert_eq!(mtl::PixelFormat::Bc7RgbaUNorm.bytes_per_image(1, 1), 16);
+        assert_eq!(
+            mtl::PixelFormat::Bc7RgbaUNorm.bytes_per_image(256, 256),
+            65536
+        );
+        assert_eq!(
+            mtl::PixelFormat::Astc12x10Ldr.bytes_per_image(25, 21),
+            3 * 3 * 16
+        );
+        assert_eq!(
+            mtl::PixelFormat::PvrtcRgba2Bpp.bytes_per_image(64, 64),
+            64 * 64 / 4
+        );
+        assert_eq!(mtl::PixelFormat::Etc2Rgb8.bytes_per_image(4, 4), 8);
+    }
+
+    #[test]
+    fn capabilities() {
+        assert!(mtl::PixelFormat::Depth32FloatStencil8.is_depth());
+        assert!(mtl::PixelFormat::Depth32FloatStencil8.is_stencil());
+        assert!(!mtl::PixelFormat::Stencil8.is_depth());
+        assert!(mtl::PixelFormat::X24Stencil8.is_stencil());
+        assert!(!mtl::PixelFormat::R32Float.is_depth_or_stencil());
+
+        assert!(mtl::PixelFormat::Astc4x4Hdr.is_hdr());
+        assert!(!mtl::PixelFormat::Astc4x4Ldr.is_hdr());
+        assert!(mtl::PixelFormat::Astc4x4Srgb.is_srgb());
+        assert_eq!(
+            mtl::PixelFormat::Astc4x4Srgb.linear_variant(),
+            mtl::PixelFormat::Astc4x4Ldr
+        );
+        assert_eq!(mtl::PixelFormat::Astc4x4Hdr.srgb_variant(), None);
+
+        assert_eq!(
+            mtl::PixelFormat::Rgb10A2UInt.component_type(),
+            Some(T::UInt)
+        );
+        assert_eq!(
+            mtl::PixelFormat::Bc5RgSNorm.component_type(),
+            Some(T::SNorm)
+        );
+        assert_eq!(mtl::PixelFormat::Bc5RgSNorm.component_count(), 2);
+        assert_eq!(mtl::PixelFormat::B5G6R5UNorm.component_count(), 3);
+    }
+}
@@ -309,7 +309,7 @@ impl AttachDesc {
     pub fn set_store_action_options(&mut self, val: StoreActionOpts);
 }
 
-#[link(name = "mtl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mtl", kind = "static"))]
 unsafe extern "C" {
     static MTL_RENDER_PASS_DESCRIPTOR: &'static objc::Class<Desc>;
 }
@@ -317,7 +317,7 @@ impl Texture {
     pub fn array_len(&self) -> usize;
 }
 
-#[link(name = "mtl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mtl", kind = "static"))]
 unsafe extern "C" {
     static MTL_TEXTURE_DESCRIPTOR: &'static objc::Class<Desc>;
 }
@@ -387,7 +387,7 @@ impl Desc {
     pub fn attrs_mut(&mut self) -> &mut VertexAttrDescArray;
 }
 
-#[link(name = "mtl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mtl", kind = "static"))]
 unsafe extern "C" {
     static MTL_VERTEX_DESCRIPTOR: &'static objc::Class<Desc>;
     static MTL_VERTEX_BUFFER_LAYOUT_DESCRIPTOR: &'static objc::Class<VertexBufLayoutDesc>;
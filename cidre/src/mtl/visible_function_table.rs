@@ -47,7 +47,7 @@ impl VisibleFnTable {
     );
 }
 
-#[link(name = "mtl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "mtl", kind = "static"))]
 unsafe extern "C" {
     static MTL_VISIBLE_FUNCTION_TABLE_DESCRIPTOR: &'static objc::Class<Desc>;
 }
@@ -240,7 +240,7 @@ impl Embedding {
     ) -> Option<arc::R<ns::Array<ns::String>>>;
 }
 
-#[link(name = "nl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "nl", kind = "static"))]
 unsafe extern "C" {
     static NL_EMBEDDING: &'static objc::Class<Embedding>;
 }
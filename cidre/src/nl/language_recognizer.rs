@@ -35,7 +35,7 @@ impl LangRecognizer {
     pub fn set_lang_constraints(&mut self, val: &ns::Array<nl::Lang>);
 }
 
-#[link(name = "nl", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "nl", kind = "static"))]
 unsafe extern "C" {
     static NL_LANGUAGE_RECOGNIZER: &'static objc::Class<LangRecognizer>;
 }
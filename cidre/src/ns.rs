@@ -257,7 +257,7 @@ macro_rules! log {
 
 pub use log;
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     fn cidre_log(str: &crate::ns::String);
 }
@@ -33,7 +33,7 @@ impl App {
     pub fn key_window(&self) -> Option<arc::R<ns::Window>>;
 }
 
-#[link(name = "app", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "app", kind = "static"))]
 unsafe extern "C" {
     static NS_APPLICATION: &'static objc::Class<App>;
 }
@@ -44,7 +44,7 @@ impl Cell {
     pub fn set_tag(&mut self, val: isize);
 }
 
-#[link(name = "app", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "app", kind = "static"))]
 unsafe extern "C" {
     static NS_CELL: &'static objc::Class<Cell>;
 }
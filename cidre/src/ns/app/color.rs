@@ -56,7 +56,7 @@ impl Color {
 
 unsafe impl Send for Color {}
 
-#[link(name = "app", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "app", kind = "static"))]
 unsafe extern "C" {
     static NS_COLOR: &'static objc::Class<Color>;
 }
@@ -733,7 +733,7 @@ impl Event {
     pub fn remove_monitor(event_monitor: &ns::Id);
 }
 
-#[link(name = "app", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "app", kind = "static"))]
 unsafe extern "C" {
     static NS_EVENT: &'static objc::Class<Event>;
 }
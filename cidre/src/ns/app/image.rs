@@ -51,7 +51,7 @@ impl Image {
     pub fn set_size(&mut self, val: ns::Size);
 }
 
-#[link(name = "app", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "app", kind = "static"))]
 unsafe extern "C" {
     static NS_IMAGE: &'static objc::Class<Image>;
 }
@@ -5,7 +5,7 @@ define_obj_type!(
     NS_RESPONDER
 );
 
-#[link(name = "app", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "app", kind = "static"))]
 unsafe extern "C" {
     static NS_RESPONDER: &'static objc::Class<Responder>;
 }
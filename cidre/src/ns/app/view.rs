@@ -12,7 +12,7 @@ impl View {
     pub fn layer(&self) -> Option<&ca::Layer>;
 }
 
-#[link(name = "app", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "app", kind = "static"))]
 unsafe extern "C" {
     static NS_VIEW: &'static objc::Class<View>;
 }
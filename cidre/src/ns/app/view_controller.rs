@@ -4,7 +4,7 @@ define_obj_type!(
     pub ViewController(ns::Responder), NS_VIEW_CONTROLLER
 );
 
-#[link(name = "app", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "app", kind = "static"))]
 unsafe extern "C" {
     static NS_VIEW_CONTROLLER: &'static objc::Class<ViewController>;
 }
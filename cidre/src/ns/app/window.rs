@@ -116,7 +116,7 @@ impl Window {
     pub fn remove_child_window(&mut self, window: &ns::Window);
 }
 
-#[link(name = "app", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "app", kind = "static"))]
 unsafe extern "C" {
     static NS_WINDOW: &'static objc::Class<Window>;
 }
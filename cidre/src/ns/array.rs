@@ -362,7 +362,7 @@ impl<const N: usize> From<[u32; N]> for arc::R<ns::Array<ns::Number>> {
     }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_ARRAY: &'static objc::Class<ns::Array<ns::Id>>;
     static NS_MUTABLE_ARRAY: &'static objc::Class<ns::ArrayMut<ns::Id>>;
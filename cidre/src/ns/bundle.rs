@@ -21,7 +21,7 @@ impl Bundle {
     }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_BUNDLE: &'static objc::Class<Bundle>;
 }
@@ -26,7 +26,7 @@ define_obj_type!(
 
 impl Coder {}
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_CODER: &'static objc::Class<Coder>;
 }
@@ -222,7 +222,7 @@ impl AsMut<cf::DataMut> for DataMut {
     }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_DATA: &'static objc::Class<Data>;
     static NS_MUTABLE_DATA: &'static objc::Class<DataMut>;
@@ -45,7 +45,7 @@ impl Date {
     pub fn add_time_interval(&self, val: TimeInterval) -> arc::R<Self>;
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_DATE: &'static objc::Class<ns::Date>;
 }
@@ -131,7 +131,7 @@ impl<K: Obj, V: Obj> DictionaryMut<K, V> {
     pub fn set_dictionary(&mut self, other: &Self);
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_DICTIONARY: &'static ns::Class<Dictionary<ns::Id, ns::Id>>;
     static NS_MUTABLE_DICTIONARY: &'static ns::Class<DictionaryMut<ns::Id, ns::Id>>;
@@ -133,7 +133,7 @@ impl Domain {
     }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_ERROR: &'static objc::Class<Error>;
 }
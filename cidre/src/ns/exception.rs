@@ -186,7 +186,7 @@ unsafe extern "C" {
     fn NSSetUncaughtExceptionHandler(handler: *const UncaughtExceptionHandler);
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C-unwind" {
     fn cidre_raise_exception(message: &ns::String) -> !;
     fn cidre_try_catch<'ar>(
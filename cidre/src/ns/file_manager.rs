@@ -331,7 +331,7 @@ impl FileManager {
     }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_FILE_MANAGER: &'static objc::Class<FileManager>;
 }
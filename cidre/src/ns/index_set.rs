@@ -112,7 +112,7 @@ impl IndexSetMut {
     pub fn remove_range(&mut self, val: ns::Range);
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_INDEX_SET: &'static objc::Class<IndexSet>;
     static NS_MUTABLE_INDEX_SET: &'static objc::Class<IndexSetMut>;
@@ -207,7 +207,7 @@ unsafe extern "C" {
     static NSKeyValueChangeNotificationIsPriorKey: &'static KvChangeKey;
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C-unwind" {
     fn cidre_create_observer(
         obj: &ns::Id,
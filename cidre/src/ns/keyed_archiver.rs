@@ -24,7 +24,7 @@ impl KeyedArchiver {
     }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_KEYED_ARCHIVER: &'static objc::Class<KeyedArchiver>;
 }
@@ -24,7 +24,7 @@ impl KeyedUnarchiver {
     }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_KEYED_UNARCHIVER: &'static objc::Class<KeyedUnarchiver>;
 }
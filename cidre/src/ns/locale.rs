@@ -42,7 +42,7 @@ impl Locale {
     pub fn region_code(&self) -> Option<arc::R<ns::String>>;
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_LOCALE: &'static objc::Class<Locale>;
 }
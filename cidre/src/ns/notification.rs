@@ -144,7 +144,7 @@ impl Drop for NotificationGuard {
     }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_NOTIFICATION: &'static objc::Class<Notification>;
     static NS_NOTIFICATION_CENTER: &'static objc::Class<NotificationCenter>;
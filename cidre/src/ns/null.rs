@@ -44,7 +44,7 @@ impl AsRef<cf::Type> for Null {
     }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_NULL: &'static objc::Class<Null>;
 }
@@ -115,7 +115,7 @@ impl ProcessInfo {
     pub fn system_uptime(&self) -> ns::TimeInterval;
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_PROCESS_INFO: &'static objc::Class<ProcessInfo>;
 }
@@ -114,7 +114,7 @@ impl Regex {
     }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_REGULAR_EXPRESSION: &'static objc::Class<Regex>;
 }
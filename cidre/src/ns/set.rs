@@ -88,7 +88,7 @@ impl<T: Obj> Set<T> {
 impl<T> ns::FastEnum<T> for Set<T> where T: Obj {}
 impl<T> ns::FastEnum<T> for SetMut<T> where T: Obj {}
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_SET: &'static Class<ns::Set<ns::Id>>;
 }
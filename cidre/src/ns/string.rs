@@ -223,7 +223,7 @@ impl StringMut {
     }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_STRING: &'static Class<String>;
     static NS_MUTABLE_STRING: &'static Class<StringMut>;
@@ -29,7 +29,7 @@ impl Thread {
     pub fn is_cancelled(&self) -> bool;
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_THREAD: &'static objc::Class<Thread>;
 }
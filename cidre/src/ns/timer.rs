@@ -20,7 +20,7 @@ impl Timer {
     pub fn fire(&self);
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_TIMER: &'static objc::Class<Timer>;
 }
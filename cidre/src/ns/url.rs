@@ -113,7 +113,7 @@ impl Url {
 
 unsafe impl Send for Url {}
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_URL: &'static Class<Url>;
 }
@@ -71,7 +71,7 @@ impl UrlCache {
     pub fn current_disk_usage(&self) -> usize;
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_URL_CACHE: &'static objc::Class<UrlCache>;
 }
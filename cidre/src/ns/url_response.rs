@@ -47,7 +47,7 @@ impl UrlResponse {
     pub fn url(&self) -> Option<arc::R<ns::Url>>;
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_URL_RESPONSE: &'static objc::Class<UrlResponse>;
 }
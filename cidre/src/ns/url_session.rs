@@ -271,7 +271,7 @@ impl WebSocketMessage {
     pub fn type_(&self) -> WebSocketMessageType;
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_URL_SESSION: &'static Class<Session>;
     static NS_URL_SESSION_WEB_SOCKET_MESSAGE: &'static Class<WebSocketMessage>;
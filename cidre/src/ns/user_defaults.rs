@@ -124,7 +124,7 @@ impl ns::KvObserverRegistration for UserDefaults {}
 unsafe impl Send for UserDefaults {}
 unsafe impl Sync for UserDefaults {}
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_USER_DEFAULTS: &'static objc::Class<UserDefaults>;
 }
@@ -12,7 +12,7 @@ impl Uuid {
     pub fn string(&self) -> arc::R<ns::String>;
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C" {
     static NS_UUID: &'static objc::Class<Uuid>;
 }
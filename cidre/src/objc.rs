@@ -118,6 +118,103 @@ impl<T: Obj, I: Sized + Default> ClassInstExtra<T, I> {
     }
 }
 
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Ivar(Type);
+
+/// Receiver and class to start method lookup from for `objc_msgSendSuper`
+#[doc(alias = "objc_super")]
+#[repr(C)]
+pub struct Super {
+    pub receiver: *const c_void,
+    pub super_cls: &'static Class<Id>,
+}
+
+/// Rust state of classes defined with `#[objc::class]`.
+///
+/// Lives in `_rs_ivars` instance variable. Zeroed memory is valid uninitialized state,
+/// so instances created from objc side with `alloc` are safe to deallocate.
+#[repr(C)]
+pub struct Ivars<T> {
+    initialized: bool,
+    val: std::mem::MaybeUninit<T>,
+}
+
+impl<T> Ivars<T> {
+    pub const NAME: &'static std::ffi::CStr = c"_rs_ivars";
+
+    /// Adds `_rs_ivars` instance variable to the class which is not registered yet.
+    ///
+    /// # Safety
+    ///
+    /// `cls` should be allocated with `objc_allocateClassPair` and not registered yet.
+    pub unsafe fn add_to<O: Obj>(cls: &Class<O>) -> bool {
+        unsafe {
+            class_addIvar(
+                &*(cls as *const Class<O>).cast::<Class<Id>>(),
+                Self::NAME.as_ptr(),
+                std::mem::size_of::<Self>(),
+                std::mem::align_of::<Self>().trailing_zeros() as u8,
+                c"?".as_ptr(),
+            )
+        }
+    }
+
+    /// Ivars of the instance of `cls`. `offset` caches ivar offset, it must start with zero.
+    ///
+    /// # Safety
+    ///
+    /// `obj` should be instance of `cls` and `cls` should have `_rs_ivars` of `T` added
+    /// with [`Self::add_to`]. `offset` should be used only with `cls` and returned ivars
+    /// should not be aliased.
+    pub unsafe fn of<'a, O: Obj>(
+        obj: &O,
+        cls: &Class<O>,
+        offset: &std::sync::atomic::AtomicIsize,
+    ) -> &'a mut Self {
+        use std::sync::atomic::Ordering;
+        let mut off = offset.load(Ordering::Relaxed);
+        if off == 0 {
+            unsafe {
+                let cls = &*(cls as *const Class<O>).cast::<Class<Id>>();
+                let ivar = class_getInstanceVariable(cls, Self::NAME.as_ptr());
+                off = ivar_getOffset(ivar.expect("class without _rs_ivars"));
+            }
+            offset.store(off, Ordering::Relaxed);
+        }
+        unsafe { &mut *((obj as *const O as *mut u8).offset(off) as *mut Self) }
+    }
+
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    pub fn get(&self) -> &T {
+        assert!(self.initialized, "ivars are not initialized");
+        unsafe { self.val.assume_init_ref() }
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        assert!(self.initialized, "ivars are not initialized");
+        unsafe { self.val.assume_init_mut() }
+    }
+
+    pub fn set(&mut self, val: T) {
+        self.take();
+        self.val.write(val);
+        self.initialized = true;
+    }
+
+    pub fn take(&mut self) -> Option<T> {
+        if !self.initialized {
+            return None;
+        }
+        self.initialized = false;
+        Some(unsafe { self.val.assume_init_read() })
+    }
+}
+
 impl<T: Obj> Class<T> {
     #[inline]
     pub fn as_type_ref(&self) -> &Type {
@@ -301,6 +398,54 @@ pub unsafe fn sel_reg_name(str: *const i8) -> &'static Sel {
     unsafe { std::mem::transmute(sel_registerName(str)) }
 }
 
+/// Cached `respondsToSelector:` check for selectors above deployment target.
+///
+/// Like [`crate::api::DlSym`], the first answer is kept for the process lifetime.
+/// Used by generated `try_xxx` functions.
+pub struct SelCheck {
+    sel: &'static std::ffi::CStr,
+    state: std::sync::atomic::AtomicU8,
+}
+
+impl SelCheck {
+    const UNKNOWN: u8 = 2;
+
+    pub const fn new(sel: &'static std::ffi::CStr) -> Self {
+        Self {
+            sel,
+            state: std::sync::atomic::AtomicU8::new(Self::UNKNOWN),
+        }
+    }
+
+    #[inline]
+    pub fn sel(&self) -> &'static Sel {
+        unsafe { sel_reg_name(self.sel.as_ptr()) }
+    }
+
+    /// # Safety
+    ///
+    /// `receiver` should be null or point to objc object or class.
+    #[inline]
+    pub unsafe fn responds(&self, receiver: *const c_void) -> bool {
+        match self.state.load(std::sync::atomic::Ordering::Relaxed) {
+            Self::UNKNOWN => unsafe { self.check(receiver) },
+            state => state == 1,
+        }
+    }
+
+    #[cold]
+    unsafe fn check(&self, receiver: *const c_void) -> bool {
+        if receiver.is_null() {
+            return false;
+        }
+        let obj: &Id = unsafe { &*receiver.cast() };
+        let res = obj.responds_to_sel(self.sel());
+        self.state
+            .store(res as u8, std::sync::atomic::Ordering::Relaxed);
+        res
+    }
+}
+
 #[link(name = "objc", kind = "dylib")]
 unsafe extern "C-unwind" {
     #[cfg(any(target_arch = "x86_64", feature = "classic-objc-retain-release"))]
@@ -347,6 +492,19 @@ unsafe extern "C-unwind" {
     pub fn objc_registerClassPair(cls: &Class<Id>);
     pub fn objc_getClass(name: *const u8) -> Option<&'static Class<Id>>;
     pub fn objc_getProtocol(name: *const i8) -> Option<&'static Protocol>;
+    pub fn object_getClass(obj: *const c_void) -> Option<&'static Class<Id>>;
+    fn class_addIvar(
+        cls: &Class<Id>,
+        name: *const i8,
+        size: usize,
+        alignment: u8,
+        types: *const i8,
+    ) -> bool;
+    fn class_getInstanceVariable(cls: &Class<Id>, name: *const i8) -> Option<&Ivar>;
+    fn ivar_getOffset(ivar: &Ivar) -> isize;
+    pub fn objc_msgSendSuper();
+    #[cfg(target_arch = "x86_64")]
+    pub fn objc_msgSendSuper_stret();
     pub static NS_OBJECT: &'static crate::objc::Class<Id>;
     fn objc_exception_throw(exception: &Id) -> !;
 }
@@ -652,7 +810,7 @@ pub fn throw(obj: &Id) -> ! {
     unsafe { objc_exception_throw(obj) }
 }
 
-#[link(name = "ns", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ns", kind = "static"))]
 unsafe extern "C-unwind" {
     fn cidre_try_catch<'ar>(
         during: extern "C" fn(ctx: *mut c_void),
@@ -720,7 +878,9 @@ mod tests {
 }
 pub use cidre_macros::add_methods;
 pub use cidre_macros::api_available as available;
+pub use cidre_macros::class;
 pub use cidre_macros::optional;
+pub use cidre_macros::property;
 pub use cidre_macros::protocol;
 
 /// Docs
@@ -1,4 +1,4 @@
-use std::{mem::MaybeUninit, num::NonZeroI32};
+use std::num::NonZeroI32;
 
 use crate::{four_cc_to_str, mac_types::FourCharCode};
 
@@ -58,10 +58,12 @@ impl std::fmt::Debug for Status {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let val = self.0;
         let mut fcc = val.to_be_bytes();
-        f.debug_struct("os::Status")
-            .field("raw", &val)
-            .field("fcc", &four_cc_to_str(&mut fcc))
-            .field("help", &format!("https://www.osstatus.com?search={}", val))
+        let mut s = f.debug_struct("os::Status");
+        s.field("raw", &val).field("fcc", &four_cc_to_str(&mut fcc));
+        if let Some(info) = self.info() {
+            s.field("name", &info.name);
+        }
+        s.field("help", &format!("https://www.osstatus.com?search={}", val))
             .finish()
     }
 }
@@ -70,17 +72,26 @@ impl std::fmt::Debug for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let val = self.0.get();
         let mut fcc = val.to_be_bytes();
-        f.debug_struct("os::Error")
-            .field("raw", &val)
-            .field("fcc", &four_cc_to_str(&mut fcc))
-            .field("help", &format!("https://www.osstatus.com?search={}", val))
+        let mut s = f.debug_struct("os::Error");
+        s.field("raw", &val).field("fcc", &four_cc_to_str(&mut fcc));
+        if let Some(info) = self.info() {
+            s.field("name", &info.name);
+        }
+        s.field("help", &format!("https://www.osstatus.com?search={}", val))
             .finish()
     }
 }
 
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_info::fmt_display(self.0, f)
+    }
+}
+
 impl std::fmt::Display for Error {
+    /// Renders as `AudioFile: unsupported data format ('fmt?', 1718449215)`
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Debug::fmt(&self, f)
+        error_info::fmt_display(self.0.get(), f)
     }
 }
 
@@ -88,6 +99,7 @@ impl std::error::Error for Error {}
 
 pub type Result<Ok = ()> = std::result::Result<Ok, Error>;
 
+#[cfg(target_vendor = "apple")]
 #[inline]
 pub(crate) unsafe fn result_unchecked<T, R>(op: impl FnOnce(&mut Option<T>) -> R) -> Result<T>
 where
@@ -98,20 +110,25 @@ where
     Ok(unsafe { option.unwrap_unchecked() })
 }
 
+#[cfg(target_vendor = "apple")]
 #[inline]
 pub(crate) fn result_init<T, R>(op: impl FnOnce(*mut T) -> R) -> Result<T>
 where
     R: Into<Result>,
 {
-    let mut val = MaybeUninit::<T>::uninit();
+    let mut val = std::mem::MaybeUninit::<T>::uninit();
     op(val.as_mut_ptr()).into()?;
     Ok(unsafe { val.assume_init() })
 }
 
 pub type Type = FourCharCode;
 
+#[cfg(target_vendor = "apple")]
 pub mod lock;
 
+mod error_info;
+pub use error_info::ErrorInfo;
+
 impl PartialEq<i32> for Status {
     fn eq(&self, other: &i32) -> bool {
         self.0.eq(other)
@@ -137,6 +154,8 @@ impl Status {
         !self.is_ok()
     }
 
+    /// # Safety
+    /// `option` must be `Some` if status is ok
     #[inline]
     pub unsafe fn to_result_unchecked<T>(self, option: Option<T>) -> Result<T> {
         if self.is_ok() {
@@ -0,0 +1,5695 @@
+use super::{Error, Status};
+
+/// Name and description of a well known [`Status`] code.
+///
+/// The registry covers every code exposed by `err` modules of this crate
+/// and does not depend on the host it runs on.
+///
+/// ```
+/// use cidre::os;
+///
+/// let info = os::ErrorInfo::with_name("kAudioFileUnsupportedDataFormatError").unwrap();
+/// assert_eq!(info.value, i32::from_be_bytes(*b"fmt?"));
+/// assert_eq!(info.framework, "AudioFile");
+/// assert_eq!(info.summary, "unsupported data format");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorInfo {
+    pub value: i32,
+
+    /// Name as it appears in SDK headers, e.g. `kAudioFileUnsupportedDataFormatError`.
+    pub name: &'static str,
+
+    /// Framework (or subsystem) the code comes from, e.g. `AudioFile`.
+    pub framework: &'static str,
+
+    /// Short lowercase description derived from the name.
+    pub summary: &'static str,
+
+    /// Description from SDK headers, may be empty.
+    pub desc: &'static str,
+}
+
+impl ErrorInfo {
+    /// All known codes sorted by value.
+    #[inline]
+    pub fn all() -> &'static [Self] {
+        &ALL
+    }
+
+    /// Most likely info for the value.
+    ///
+    /// Some codes are shared between frameworks (-50 is `kAudio_ParamError` and `errSecParam`),
+    /// use [`Self::all_with_value`] to get them all.
+    #[inline]
+    pub fn with_value(val: i32) -> Option<&'static Self> {
+        Self::all_with_value(val).first()
+    }
+
+    pub fn all_with_value(val: i32) -> &'static [Self] {
+        let start = ALL.partition_point(|i| i.value < val);
+        let len = ALL[start..].partition_point(|i| i.value == val);
+        &ALL[start..start + len]
+    }
+
+    pub fn with_name(name: &str) -> Option<&'static Self> {
+        ALL.iter().find(|i| i.name == name)
+    }
+
+    #[inline]
+    pub const fn error(&self) -> Error {
+        Error::new_unchecked(self.value)
+    }
+}
+
+impl Status {
+    #[inline]
+    pub fn info(&self) -> Option<&'static ErrorInfo> {
+        ErrorInfo::with_value(self.0)
+    }
+}
+
+impl Error {
+    #[inline]
+    pub fn info(&self) -> Option<&'static ErrorInfo> {
+        ErrorInfo::with_value(self.0.get())
+    }
+
+    /// Error by its SDK name, e.g. `kAudioFileUnsupportedDataFormatError`.
+    #[inline]
+    pub fn with_name(name: &str) -> Option<Self> {
+        ErrorInfo::with_name(name).map(ErrorInfo::error)
+    }
+}
+
+pub(super) fn fmt_display(val: i32, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if val == 0 {
+        f.write_str("no error (0)")
+    } else {
+        match ErrorInfo::with_value(val) {
+            Some(info) => write!(f, "{}: {} (", info.framework, info.summary)?,
+            None => f.write_str("unknown error (")?,
+        }
+        let fcc = val.to_be_bytes();
+        if fcc.iter().all(|b| (b' '..=b'~').contains(b)) {
+            // SAFETY: All bytes are in the ASCII range
+            let fcc = unsafe { std::str::from_utf8_unchecked(&fcc) };
+            write!(f, "'{fcc}', {val})")
+        } else {
+            write!(f, "{val})")
+        }
+    }
+}
+
+const fn info(
+    value: i32,
+    name: &'static str,
+    framework: &'static str,
+    summary: &'static str,
+    desc: &'static str,
+) -> ErrorInfo {
+    ErrorInfo {
+        value,
+        name,
+        framework,
+        summary,
+        desc,
+    }
+}
+
+const CORE_AUDIO: &str = "CoreAudio";
+const AUDIO_FILE: &str = "AudioFile";
+const AUDIO_CONVERTER: &str = "AudioConverter";
+const AUDIO_QUEUE: &str = "AudioQueue";
+const AUDIO_UNIT: &str = "AudioUnit";
+const CORE_VIDEO: &str = "CoreVideo";
+const CORE_MEDIA: &str = "CoreMedia";
+const CORE_MEDIA_IO: &str = "CoreMediaIO";
+const VIDEO_TOOLBOX: &str = "VideoToolbox";
+const IMAGE_IO: &str = "ImageIO";
+const SECURITY: &str = "Security";
+const MACH: &str = "Mach";
+
+// Collected from `err` modules. Keep sorted by value; for shared values
+// the first entry wins in `ErrorInfo::with_value`.
+static ALL: [ErrorInfo; 787] = [
+    info(
+        -67903,
+        "errSecCertificateDuplicateExtension",
+        SECURITY,
+        "certificate duplicate extension",
+        "The certificate contains multiple extensions with the same extension ID.",
+    ),
+    info(
+        -67902,
+        "errSecCertificateIsCA",
+        SECURITY,
+        "certificate is CA",
+        "The verified certificate is a CA rather than an end-entity",
+    ),
+    info(
+        -67901,
+        "errSecCertificateValidityPeriodTooLong",
+        SECURITY,
+        "certificate validity period too long",
+        "The validity period in the certificate exceeds the maximum allowed.",
+    ),
+    info(
+        -67900,
+        "errSecCertificateNameNotAllowed",
+        SECURITY,
+        "certificate name not allowed",
+        "The requested name is not allowed for this certificate.",
+    ),
+    info(
+        -67899,
+        "errSecCertificatePolicyNotAllowed",
+        SECURITY,
+        "certificate policy not allowed",
+        "The requested policy is not allowed for this certificate.",
+    ),
+    info(
+        -67898,
+        "errSecTimestampRevocationNotification",
+        SECURITY,
+        "timestamp revocation notification",
+        "A timestamp authority revocation notification was issued.",
+    ),
+    info(
+        -67897,
+        "errSecTimestampRevocationWarning",
+        SECURITY,
+        "timestamp revocation warning",
+        "A timestamp authority revocation warning was issued.",
+    ),
+    info(
+        -67896,
+        "errSecTimestampWaiting",
+        SECURITY,
+        "timestamp waiting",
+        "A timestamp transaction is waiting.",
+    ),
+    info(
+        -67895,
+        "errSecTimestampRejection",
+        SECURITY,
+        "timestamp rejection",
+        "A timestamp transaction was rejected.",
+    ),
+    info(
+        -67894,
+        "errSecSigningTimeMissing",
+        SECURITY,
+        "signing time missing",
+        "A signing time was expected but was not found.",
+    ),
+    info(
+        -67893,
+        "errSecTimestampSystemFailure",
+        SECURITY,
+        "timestamp system failure",
+        "The timestamp request cannot be handled due to system failure.",
+    ),
+    info(
+        -67892,
+        "errSecTimestampAddInfoNotAvailable",
+        SECURITY,
+        "timestamp add info not available",
+        "The additional information requested is not available.",
+    ),
+    info(
+        -67891,
+        "errSecTimestampUnacceptedExtension",
+        SECURITY,
+        "timestamp unaccepted extension",
+        "The requested extension is not supported by the Timestamp Authority.",
+    ),
+    info(
+        -67890,
+        "errSecTimestampUnacceptedPolicy",
+        SECURITY,
+        "timestamp unaccepted policy",
+        "The requested policy is not supported by the Timestamp Authority.",
+    ),
+    info(
+        -67889,
+        "errSecTimestampTimeNotAvailable",
+        SECURITY,
+        "timestamp time not available",
+        "The time source for the Timestamp Authority is not available.",
+    ),
+    info(
+        -67888,
+        "errSecTimestampBadDataFormat",
+        SECURITY,
+        "timestamp bad data format",
+        "The timestamp data submitted has the wrong format.",
+    ),
+    info(
+        -67887,
+        "errSecTimestampBadRequest",
+        SECURITY,
+        "timestamp bad request",
+        "The timestamp transaction is not permitted or supported.",
+    ),
+    info(
+        -67886,
+        "errSecTimestampBadAlg",
+        SECURITY,
+        "timestamp bad alg",
+        "An unrecognized or unsupported Algorithm Identifier in timestamp.",
+    ),
+    info(
+        -67885,
+        "errSecTimestampServiceNotAvailable",
+        SECURITY,
+        "timestamp service not available",
+        "The timestamp service is not available.",
+    ),
+    info(
+        -67884,
+        "errSecTimestampNotTrusted",
+        SECURITY,
+        "timestamp not trusted",
+        "The timestamp was not trusted.",
+    ),
+    info(
+        -67883,
+        "errSecTimestampInvalid",
+        SECURITY,
+        "timestamp invalid",
+        "The timestamp was not valid.",
+    ),
+    info(
+        -67882,
+        "errSecTimestampMissing",
+        SECURITY,
+        "timestamp missing",
+        "A timestamp was expected but was not found.",
+    ),
+    info(
+        -67881,
+        "errSecExtendedKeyUsageNotCritical",
+        SECURITY,
+        "extended key usage not critical",
+        "The extended key usage extension was not marked critical.",
+    ),
+    info(
+        -67880,
+        "errSecMissingRequiredExtension",
+        SECURITY,
+        "missing required extension",
+        "A required certificate extension is missing.",
+    ),
+    info(
+        -67879,
+        "errSecInvalidModifyMode",
+        SECURITY,
+        "invalid modify mode",
+        "The modify mode is not valid.",
+    ),
+    info(
+        -67878,
+        "errSecInvalidNewOwner",
+        SECURITY,
+        "invalid new owner",
+        "The new owner is not valid.",
+    ),
+    info(
+        -67877,
+        "errSecInvalidIndexInfo",
+        SECURITY,
+        "invalid index info",
+        "The index information is not valid.",
+    ),
+    info(
+        -67876,
+        "errSecInvalidAccessRequest",
+        SECURITY,
+        "invalid access request",
+        "The access request is not valid.",
+    ),
+    info(
+        -67875,
+        "errSecInvalidDBLocation",
+        SECURITY,
+        "invalid DB location",
+        "The database location is not valid.",
+    ),
+    info(
+        -67874,
+        "errSecUnsupportedOperator",
+        SECURITY,
+        "unsupported operator",
+        "The operator is not supported.",
+    ),
+    info(
+        -67873,
+        "errSecUnsupportedNumSelectionPreds",
+        SECURITY,
+        "unsupported num selection preds",
+        "The number of selection predicates is not supported.",
+    ),
+    info(
+        -67872,
+        "errSecUnsupportedQueryLimits",
+        SECURITY,
+        "unsupported query limits",
+        "The query limits are not supported.",
+    ),
+    info(
+        -67871,
+        "errSecMissingValue",
+        SECURITY,
+        "missing value",
+        "A missing value was detected.",
+    ),
+    info(
+        -67870,
+        "errSecDatastoreIsOpen",
+        SECURITY,
+        "datastore is open",
+        "The data store is open.",
+    ),
+    info(
+        -67869,
+        "errSecDatabaseLocked",
+        SECURITY,
+        "database locked",
+        "The database is locked.",
+    ),
+    info(
+        -67868,
+        "errSecInvalidParsingModule",
+        SECURITY,
+        "invalid parsing module",
+        "The parsing module was not valid.",
+    ),
+    info(
+        -67867,
+        "errSecIncompatibleFieldFormat",
+        SECURITY,
+        "incompatible field format",
+        "The field format was incompatible.",
+    ),
+    info(
+        -67866,
+        "errSecFieldSpecifiedMultiple",
+        SECURITY,
+        "field specified multiple",
+        "Too many fields were specified.",
+    ),
+    info(
+        -67865,
+        "errSecUnsupportedNumRecordTypes",
+        SECURITY,
+        "unsupported num record types",
+        "The number of record types is not supported.",
+    ),
+    info(
+        -67864,
+        "errSecUnsupportedNumIndexes",
+        SECURITY,
+        "unsupported num indexes",
+        "The number of indexes is not supported.",
+    ),
+    info(
+        -67863,
+        "errSecUnsupportedNumAttributes",
+        SECURITY,
+        "unsupported num attributes",
+        "The number of attributes is not supported.",
+    ),
+    info(
+        -67862,
+        "errSecUnsupportedLocality",
+        SECURITY,
+        "unsupported locality",
+        "The locality is not supported.",
+    ),
+    info(
+        -67861,
+        "errSecUnsupportedIndexInfo",
+        SECURITY,
+        "unsupported index info",
+        "The index information is not supported.",
+    ),
+    info(
+        -67860,
+        "errSecUnsupportedFieldFormat",
+        SECURITY,
+        "unsupported field format",
+        "The field format is not supported.",
+    ),
+    info(
+        -67859,
+        "errSecNoFieldValues",
+        SECURITY,
+        "no field values",
+        "No field values were detected.",
+    ),
+    info(
+        -67858,
+        "errSecInvalidCRLIndex",
+        SECURITY,
+        "invalid CRL index",
+        "The CRL index was not valid.",
+    ),
+    info(
+        -67857,
+        "errSecInvalidBundleInfo",
+        SECURITY,
+        "invalid bundle info",
+        "The bundle information was not valid.",
+    ),
+    info(
+        -67856,
+        "errSecRequestDescriptor",
+        SECURITY,
+        "request descriptor",
+        "The request descriptor was not valid.",
+    ),
+    info(
+        -67855,
+        "errSecInvalidRequestor",
+        SECURITY,
+        "invalid requestor",
+        "The requestor was not valid.",
+    ),
+    info(
+        -67854,
+        "errSecInvalidValidityPeriod",
+        SECURITY,
+        "invalid validity period",
+        "The validity period was not valid.",
+    ),
+    info(
+        -67853,
+        "errSecInvalidEncoding",
+        SECURITY,
+        "invalid encoding",
+        "The encoding was not valid.",
+    ),
+    info(
+        -67852,
+        "errSecInvalidTupleCredentials",
+        SECURITY,
+        "invalid tuple credentials",
+        "The tuple credentials are not valid.",
+    ),
+    info(
+        -67851,
+        "errSecInvalidBaseACLs",
+        SECURITY,
+        "invalid base AC ls",
+        "The base ACLs are not valid.",
+    ),
+    info(
+        -67850,
+        "errSecInvalidTupleGroup",
+        SECURITY,
+        "invalid tuple group",
+        "The tuple group was not valid.",
+    ),
+    info(
+        -67849,
+        "errSecUnsupportedService",
+        SECURITY,
+        "unsupported service",
+        "The service is not supported.",
+    ),
+    info(
+        -67848,
+        "errSecUnsupportedAddressType",
+        SECURITY,
+        "unsupported address type",
+        "The address type is not supported.",
+    ),
+    info(
+        -67847,
+        "errSecRequestRejected",
+        SECURITY,
+        "request rejected",
+        "The request was rejected.",
+    ),
+    info(
+        -67846,
+        "errSecRequestLost",
+        SECURITY,
+        "request lost",
+        "The request was lost.",
+    ),
+    info(
+        -67845,
+        "errSecRejectedForm",
+        SECURITY,
+        "rejected form",
+        "The trust policy had a rejected form.",
+    ),
+    info(
+        -67844,
+        "errSecNoDefaultAuthority",
+        SECURITY,
+        "no default authority",
+        "No default authority was detected.",
+    ),
+    info(
+        -67843,
+        "errSecNotTrusted",
+        SECURITY,
+        "not trusted",
+        "The certificate was not trusted.",
+    ),
+    info(
+        -67842,
+        "errSecMultipleValuesUnsupported",
+        SECURITY,
+        "multiple values unsupported",
+        "Multiple values are not supported.",
+    ),
+    info(
+        -67841,
+        "errSecInvalidTuple",
+        SECURITY,
+        "invalid tuple",
+        "The tuple was not valid.",
+    ),
+    info(
+        -67840,
+        "errSecInvalidStopOnPolicy",
+        SECURITY,
+        "invalid stop on policy",
+        "The stop-on policy was not valid.",
+    ),
+    info(
+        -67839,
+        "errSecInvalidResponseVector",
+        SECURITY,
+        "invalid response vector",
+        "The response vector was not valid.",
+    ),
+    info(
+        -67838,
+        "errSecInvalidRequestInputs",
+        SECURITY,
+        "invalid request inputs",
+        "The request inputs are not valid.",
+    ),
+    info(
+        -67837,
+        "errSecInvalidReason",
+        SECURITY,
+        "invalid reason",
+        "The trust policy reason was not valid.",
+    ),
+    info(
+        -67836,
+        "errSecInvalidTimeString",
+        SECURITY,
+        "invalid time string",
+        "The time specified was not valid.",
+    ),
+    info(
+        -67835,
+        "errSecInvalidPolicyIdentifiers",
+        SECURITY,
+        "invalid policy identifiers",
+        "The policy identifiers are not valid.",
+    ),
+    info(
+        -67834,
+        "errSecInvalidIndex",
+        SECURITY,
+        "invalid index",
+        "The index was not valid.",
+    ),
+    info(
+        -67833,
+        "errSecInvalidIdentifier",
+        SECURITY,
+        "invalid identifier",
+        "The identifier was not valid.",
+    ),
+    info(
+        -67832,
+        "errSecInvalidID",
+        SECURITY,
+        "invalid ID",
+        "The ID was not valid.",
+    ),
+    info(
+        -67831,
+        "errSecInvalidFormType",
+        SECURITY,
+        "invalid form type",
+        "The form type was not valid.",
+    ),
+    info(
+        -67830,
+        "errSecInvalidCRL",
+        SECURITY,
+        "invalid CRL",
+        "The CRL was not valid.",
+    ),
+    info(
+        -67829,
+        "errSecInvalidCRLType",
+        SECURITY,
+        "invalid CRL type",
+        "The CRL type was not valid.",
+    ),
+    info(
+        -67828,
+        "errSecInvalidCRLEncoding",
+        SECURITY,
+        "invalid CRL encoding",
+        "The CRL encoding was not valid.",
+    ),
+    info(
+        -67827,
+        "errSecInvalidCRLAuthority",
+        SECURITY,
+        "invalid CRL authority",
+        "The CRL authority was not valid.",
+    ),
+    info(
+        -67826,
+        "errSecInvalidCertAuthority",
+        SECURITY,
+        "invalid cert authority",
+        "The certificate authority was not valid.",
+    ),
+    info(
+        -67825,
+        "errSecVerifyActionFailed",
+        SECURITY,
+        "verify action failed",
+        "A verify action has failed.",
+    ),
+    info(
+        -67824,
+        "errSecInvalidAuthority",
+        SECURITY,
+        "invalid authority",
+        "The authority was not valid.",
+    ),
+    info(
+        -67823,
+        "errSecInvalidAction",
+        SECURITY,
+        "invalid action",
+        "The action was not valid.",
+    ),
+    info(
+        -67822,
+        "errSecInsufficientCredentials",
+        SECURITY,
+        "insufficient credentials",
+        "Insufficient credentials were detected.",
+    ),
+    info(
+        -67821,
+        "errSecCertificateSuspended",
+        SECURITY,
+        "certificate suspended",
+        "The certificate was suspended.",
+    ),
+    info(
+        -67820,
+        "errSecCertificateRevoked",
+        SECURITY,
+        "certificate revoked",
+        "The certificate was revoked.",
+    ),
+    info(
+        -67819,
+        "errSecCertificateNotValidYet",
+        SECURITY,
+        "certificate not valid yet",
+        "The certificate is not yet valid.",
+    ),
+    info(
+        -67818,
+        "errSecCertificateExpired",
+        SECURITY,
+        "certificate expired",
+        "An expired certificate was detected.",
+    ),
+    info(
+        -67817,
+        "errSecCertificateCannotOperate",
+        SECURITY,
+        "certificate cannot operate",
+        "The certificate cannot operate.",
+    ),
+    info(
+        -67816,
+        "errSecInvalidCRLGroup",
+        SECURITY,
+        "invalid CRL group",
+        "An invalid CRL group was detected.",
+    ),
+    info(
+        -67815,
+        "errSecInvalidDigestAlgorithm",
+        SECURITY,
+        "invalid digest algorithm",
+        "An invalid digest algorithm was detected.",
+    ),
+    info(
+        -67814,
+        "errSecAlreadyLoggedIn",
+        SECURITY,
+        "already logged in",
+        "The user is already logged in.",
+    ),
+    info(
+        -67813,
+        "errSecInvalidLoginName",
+        SECURITY,
+        "invalid login name",
+        "An invalid login name was detected.",
+    ),
+    info(
+        -67812,
+        "errSecDeviceVerifyFailed",
+        SECURITY,
+        "device verify failed",
+        "A device verification failure has occurred.",
+    ),
+    info(
+        -67811,
+        "errSecPublicKeyInconsistent",
+        SECURITY,
+        "public key inconsistent",
+        "The public key was inconsistent.",
+    ),
+    info(
+        -67810,
+        "errSecBlockSizeMismatch",
+        SECURITY,
+        "block size mismatch",
+        "A block size mismatch occurred.",
+    ),
+    info(
+        -67809,
+        "errSecQuerySizeUnknown",
+        SECURITY,
+        "query size unknown",
+        "The query size is unknown.",
+    ),
+    info(
+        -67808,
+        "errSecVerifyFailed",
+        SECURITY,
+        "verify failed",
+        "A cryptographic verification failure has occurred.",
+    ),
+    info(
+        -67807,
+        "errSecStagedOperationNotStarted",
+        SECURITY,
+        "staged operation not started",
+        "A staged operation was not started.",
+    ),
+    info(
+        -67806,
+        "errSecStagedOperationInProgress",
+        SECURITY,
+        "staged operation in progress",
+        "A staged operation is in progress.",
+    ),
+    info(
+        -67805,
+        "errSecMissingAttributeWrappedKeyFormat",
+        SECURITY,
+        "missing attribute wrapped key format",
+        "A wrapped key format attribute was missing.",
+    ),
+    info(
+        -67804,
+        "errSecInvalidAttributeWrappedKeyFormat",
+        SECURITY,
+        "invalid attribute wrapped key format",
+        "A wrapped key format attribute was not valid.",
+    ),
+    info(
+        -67803,
+        "errSecMissingAttributeSymmetricKeyFormat",
+        SECURITY,
+        "missing attribute symmetric key format",
+        "A symmetric key format attribute was missing.",
+    ),
+    info(
+        -67802,
+        "errSecInvalidAttributeSymmetricKeyFormat",
+        SECURITY,
+        "invalid attribute symmetric key format",
+        "A symmetric key format attribute was not valid.",
+    ),
+    info(
+        -67801,
+        "errSecMissingAttributePrivateKeyFormat",
+        SECURITY,
+        "missing attribute private key format",
+        "A private key format attribute was missing.",
+    ),
+    info(
+        -67800,
+        "errSecInvalidAttributePrivateKeyFormat",
+        SECURITY,
+        "invalid attribute private key format",
+        "A private key format attribute was not valid.",
+    ),
+    info(
+        -67799,
+        "errSecMissingAttributePublicKeyFormat",
+        SECURITY,
+        "missing attribute public key format",
+        "A public key format attribute was missing.",
+    ),
+    info(
+        -67798,
+        "errSecInvalidAttributePublicKeyFormat",
+        SECURITY,
+        "invalid attribute public key format",
+        "A public key format attribute was not valid.",
+    ),
+    info(
+        -67797,
+        "errSecMissingAttributeAccessCredentials",
+        SECURITY,
+        "missing attribute access credentials",
+        "An access credentials attribute was missing.",
+    ),
+    info(
+        -67796,
+        "errSecInvalidAttributeAccessCredentials",
+        SECURITY,
+        "invalid attribute access credentials",
+        "An access credentials attribute was not valid.",
+    ),
+    info(
+        -67795,
+        "errSecMissingAttributeDLDBHandle",
+        SECURITY,
+        "missing attribute DLDB handle",
+        "A database handle attribute was missing.",
+    ),
+    info(
+        -67794,
+        "errSecInvalidAttributeDLDBHandle",
+        SECURITY,
+        "invalid attribute DLDB handle",
+        "A database handle attribute was not valid.",
+    ),
+    info(
+        -67793,
+        "errSecMissingAttributeIterationCount",
+        SECURITY,
+        "missing attribute iteration count",
+        "An iteration count attribute was missing.",
+    ),
+    info(
+        -67792,
+        "errSecInvalidAttributeIterationCount",
+        SECURITY,
+        "invalid attribute iteration count",
+        "An iteration count attribute was not valid.",
+    ),
+    info(
+        -67791,
+        "errSecMissingAttributeSubprime",
+        SECURITY,
+        "missing attribute subprime",
+        "A subprime attribute was missing.",
+    ),
+    info(
+        -67790,
+        "errSecInvalidAttributeSubprime",
+        SECURITY,
+        "invalid attribute subprime",
+        "A subprime attribute was not valid.",
+    ),
+    info(
+        -67789,
+        "errSecMissingAttributeBase",
+        SECURITY,
+        "missing attribute base",
+        "A base attribute was missing.",
+    ),
+    info(
+        -67788,
+        "errSecInvalidAttributeBase",
+        SECURITY,
+        "invalid attribute base",
+        "A base attribute was not valid.",
+    ),
+    info(
+        -67787,
+        "errSecMissingAttributePrime",
+        SECURITY,
+        "missing attribute prime",
+        "A prime attribute was missing.",
+    ),
+    info(
+        -67786,
+        "errSecInvalidAttributePrime",
+        SECURITY,
+        "invalid attribute prime",
+        "A prime attribute was not valid.",
+    ),
+    info(
+        -67785,
+        "errSecMissingAttributeVersion",
+        SECURITY,
+        "missing attribute version",
+        "A version attribute was missing.",
+    ),
+    info(
+        -67784,
+        "errSecInvalidAttributeVersion",
+        SECURITY,
+        "invalid attribute version",
+        "A version attribute was not valid.",
+    ),
+    info(
+        -67783,
+        "errSecMissingAttributeEndDate",
+        SECURITY,
+        "missing attribute end date",
+        "An end date attribute was missing.",
+    ),
+    info(
+        -67782,
+        "errSecInvalidAttributeEndDate",
+        SECURITY,
+        "invalid attribute end date",
+        "An end date attribute was not valid.",
+    ),
+    info(
+        -67781,
+        "errSecMissingAttributeStartDate",
+        SECURITY,
+        "missing attribute start date",
+        "A start date attribute was missing.",
+    ),
+    info(
+        -67780,
+        "errSecInvalidAttributeStartDate",
+        SECURITY,
+        "invalid attribute start date",
+        "A start date attribute was not valid.",
+    ),
+    info(
+        -67779,
+        "errSecMissingAttributeEffectiveBits",
+        SECURITY,
+        "missing attribute effective bits",
+        "An effective bits attribute was missing.",
+    ),
+    info(
+        -67778,
+        "errSecInvalidAttributeEffectiveBits",
+        SECURITY,
+        "invalid attribute effective bits",
+        "An effective bits attribute was not valid.",
+    ),
+    info(
+        -67777,
+        "errSecMissingAttributeMode",
+        SECURITY,
+        "missing attribute mode",
+        "A mode attribute was missing.",
+    ),
+    info(
+        -67776,
+        "errSecInvalidAttributeMode",
+        SECURITY,
+        "invalid attribute mode",
+        "A mode attribute was not valid.",
+    ),
+    info(
+        -67775,
+        "errSecMissingAttributeKeyType",
+        SECURITY,
+        "missing attribute key type",
+        "A key type attribute was missing.",
+    ),
+    info(
+        -67774,
+        "errSecInvalidAttributeKeyType",
+        SECURITY,
+        "invalid attribute key type",
+        "A key type attribute was not valid.",
+    ),
+    info(
+        -67773,
+        "errSecMissingAttributeLabel",
+        SECURITY,
+        "missing attribute label",
+        "A label attribute was missing.",
+    ),
+    info(
+        -67772,
+        "errSecInvalidAttributeLabel",
+        SECURITY,
+        "invalid attribute label",
+        "A label attribute was not valid.",
+    ),
+    info(
+        -67771,
+        "errSecMissingAlgorithmParms",
+        SECURITY,
+        "missing algorithm parms",
+        "An algorithm parameters attribute was missing.",
+    ),
+    info(
+        -67770,
+        "errSecInvalidAlgorithmParms",
+        SECURITY,
+        "invalid algorithm parms",
+        "An algorithm parameters attribute was not valid.",
+    ),
+    info(
+        -67769,
+        "errSecMissingAttributeRounds",
+        SECURITY,
+        "missing attribute rounds",
+        "The number of rounds attribute was missing.",
+    ),
+    info(
+        -67768,
+        "errSecInvalidAttributeRounds",
+        SECURITY,
+        "invalid attribute rounds",
+        "The number of rounds attribute was not valid.",
+    ),
+    info(
+        -67767,
+        "errSecMissingAttributeOutputSize",
+        SECURITY,
+        "missing attribute output size",
+        "An output size attribute was missing.",
+    ),
+    info(
+        -67766,
+        "errSecInvalidAttributeOutputSize",
+        SECURITY,
+        "invalid attribute output size",
+        "An output size attribute was not valid.",
+    ),
+    info(
+        -67765,
+        "errSecMissingAttributeBlockSize",
+        SECURITY,
+        "missing attribute block size",
+        "A block size attribute was missing.",
+    ),
+    info(
+        -67764,
+        "errSecInvalidAttributeBlockSize",
+        SECURITY,
+        "invalid attribute block size",
+        "A block size attribute was not valid.",
+    ),
+    info(
+        -67763,
+        "errSecMissingAttributeKeyLength",
+        SECURITY,
+        "missing attribute key length",
+        "A key length attribute was missing.",
+    ),
+    info(
+        -67762,
+        "errSecInvalidAttributeKeyLength",
+        SECURITY,
+        "invalid attribute key length",
+        "A key length attribute was not valid.",
+    ),
+    info(
+        -67761,
+        "errSecMissingAttributePassphrase",
+        SECURITY,
+        "missing attribute passphrase",
+        "A passphrase attribute was missing.",
+    ),
+    info(
+        -67760,
+        "errSecInvalidAttributePassphrase",
+        SECURITY,
+        "invalid attribute passphrase",
+        "A passphrase attribute was not valid.",
+    ),
+    info(
+        -67759,
+        "errSecMissingAttributeSeed",
+        SECURITY,
+        "missing attribute seed",
+        "A seed attribute was missing.",
+    ),
+    info(
+        -67758,
+        "errSecInvalidAttributeSeed",
+        SECURITY,
+        "invalid attribute seed",
+        "A seed attribute was not valid.",
+    ),
+    info(
+        -67757,
+        "errSecMissingAttributeRandom",
+        SECURITY,
+        "missing attribute random",
+        "A random number attribute was missing.",
+    ),
+    info(
+        -67756,
+        "errSecInvalidAttributeRandom",
+        SECURITY,
+        "invalid attribute random",
+        "A random number attribute was not valid.",
+    ),
+    info(
+        -67755,
+        "errSecMissingAttributePadding",
+        SECURITY,
+        "missing attribute padding",
+        "A padding attribute was missing.",
+    ),
+    info(
+        -67754,
+        "errSecInvalidAttributePadding",
+        SECURITY,
+        "invalid attribute padding",
+        "A padding attribute was not valid.",
+    ),
+    info(
+        -67753,
+        "errSecMissingAttributeSalt",
+        SECURITY,
+        "missing attribute salt",
+        "A salt attribute was missing.",
+    ),
+    info(
+        -67752,
+        "errSecInvalidAttributeSalt",
+        SECURITY,
+        "invalid attribute salt",
+        "A salt attribute was not valid.",
+    ),
+    info(
+        -67751,
+        "errSecMissingAttributeInitVector",
+        SECURITY,
+        "missing attribute init vector",
+        "An init vector attribute was missing.",
+    ),
+    info(
+        -67750,
+        "errSecInvalidAttributeInitVector",
+        SECURITY,
+        "invalid attribute init vector",
+        "An init vector attribute was not valid.",
+    ),
+    info(
+        -67749,
+        "errSecMissingAttributeKey",
+        SECURITY,
+        "missing attribute key",
+        "A key attribute was missing.",
+    ),
+    info(
+        -67748,
+        "errSecInvalidAttributeKey",
+        SECURITY,
+        "invalid attribute key",
+        "A key attribute was not valid.",
+    ),
+    info(
+        -67747,
+        "errSecInvalidAlgorithm",
+        SECURITY,
+        "invalid algorithm",
+        "An invalid algorithm was encountered.",
+    ),
+    info(
+        -67746,
+        "errSecInvalidContext",
+        SECURITY,
+        "invalid context",
+        "An invalid context was encountered.",
+    ),
+    info(
+        -67745,
+        "errSecInvalidOutputVector",
+        SECURITY,
+        "invalid output vector",
+        "The output vector is not valid.",
+    ),
+    info(
+        -67744,
+        "errSecInvalidInputVector",
+        SECURITY,
+        "invalid input vector",
+        "The input vector is not valid.",
+    ),
+    info(
+        -67743,
+        "errSecUnsupportedVectorOfBuffers",
+        SECURITY,
+        "unsupported vector of buffers",
+        "The vector of buffers is not supported.",
+    ),
+    info(
+        -67742,
+        "errSecInvalidKeyFormat",
+        SECURITY,
+        "invalid key format",
+        "The key format is not valid.",
+    ),
+    info(
+        -67741,
+        "errSecUnsupportedKeyLabel",
+        SECURITY,
+        "unsupported key label",
+        "The key label is not supported.",
+    ),
+    info(
+        -67740,
+        "errSecInvalidKeyLabel",
+        SECURITY,
+        "invalid key label",
+        "The key label is not valid.",
+    ),
+    info(
+        -67739,
+        "errSecUnsupportedKeyAttributeMask",
+        SECURITY,
+        "unsupported key attribute mask",
+        "The key attribute mask is not supported.",
+    ),
+    info(
+        -67738,
+        "errSecInvalidKeyAttributeMask",
+        SECURITY,
+        "invalid key attribute mask",
+        "The key attribute mask is not valid.",
+    ),
+    info(
+        -67737,
+        "errSecUnsupportedKeyUsageMask",
+        SECURITY,
+        "unsupported key usage mask",
+        "The key usage mask is not supported.",
+    ),
+    info(
+        -67736,
+        "errSecInvalidKeyUsageMask",
+        SECURITY,
+        "invalid key usage mask",
+        "The key usage mask is not valid.",
+    ),
+    info(
+        -67735,
+        "errSecUnsupportedKeySize",
+        SECURITY,
+        "unsupported key size",
+        "The key size is not supported.",
+    ),
+    info(
+        -67734,
+        "errSecUnsupportedKeyFormat",
+        SECURITY,
+        "unsupported key format",
+        "The key header format is not supported.",
+    ),
+    info(
+        -67733,
+        "errSecKeyHeaderInconsistent",
+        SECURITY,
+        "key header inconsistent",
+        "The key header is inconsistent.",
+    ),
+    info(
+        -67732,
+        "errSecKeyBlobTypeIncorrect",
+        SECURITY,
+        "key blob type incorrect",
+        "The key blob type is incorrect.",
+    ),
+    info(
+        -67731,
+        "errSecKeyUsageIncorrect",
+        SECURITY,
+        "key usage incorrect",
+        "The key usage is incorrect.",
+    ),
+    info(
+        -67730,
+        "errSecAlgorithmMismatch",
+        SECURITY,
+        "algorithm mismatch",
+        "An algorithm mismatch was encountered.",
+    ),
+    info(
+        -67729,
+        "errSecNotLoggedIn",
+        SECURITY,
+        "not logged in",
+        "You are not logged in.",
+    ),
+    info(
+        -67728,
+        "errSecAttachHandleBusy",
+        SECURITY,
+        "attach handle busy",
+        "The CSP handle was busy.",
+    ),
+    info(
+        -67727,
+        "errSecDeviceError",
+        SECURITY,
+        "device",
+        "A device error was encountered.",
+    ),
+    info(
+        -67726,
+        "errSecPrivilegeNotSupported",
+        SECURITY,
+        "privilege not supported",
+        "The privilege is not supported.",
+    ),
+    info(
+        -67725,
+        "errSecOutputLengthError",
+        SECURITY,
+        "output length",
+        "An output length error was encountered.",
+    ),
+    info(
+        -67724,
+        "errSecInputLengthError",
+        SECURITY,
+        "input length",
+        "An input length error was encountered.",
+    ),
+    info(
+        -67723,
+        "errSecEventNotificationCallbackNotFound",
+        SECURITY,
+        "event notification callback not found",
+        "An event notification callback was not found.",
+    ),
+    info(
+        -67722,
+        "errSecModuleManagerNotFound",
+        SECURITY,
+        "module manager not found",
+        "A module was not found.",
+    ),
+    info(
+        -67721,
+        "errSecModuleManagerInitializeFailed",
+        SECURITY,
+        "module manager initialize failed",
+        "A module failed to initialize.",
+    ),
+    info(
+        -67720,
+        "errSecAttributeNotInContext",
+        SECURITY,
+        "attribute not in context",
+        "An attribute was not in the context.",
+    ),
+    info(
+        -67719,
+        "errSecInvalidSubServiceID",
+        SECURITY,
+        "invalid sub service ID",
+        "An invalid subservice ID was encountered.",
+    ),
+    info(
+        -67718,
+        "errSecModuleNotLoaded",
+        SECURITY,
+        "module not loaded",
+        "A module was not loaded.",
+    ),
+    info(
+        -67717,
+        "errSecInvalidServiceMask",
+        SECURITY,
+        "invalid service mask",
+        "An invalid service mask was encountered.",
+    ),
+    info(
+        -67716,
+        "errSecInvalidAddinFunctionTable",
+        SECURITY,
+        "invalid addin function table",
+        "An invalid add-in function table was encountered.",
+    ),
+    info(
+        -67715,
+        "errSecLibraryReferenceNotFound",
+        SECURITY,
+        "library reference not found",
+        "A library reference was not found.",
+    ),
+    info(
+        -67714,
+        "errSecAddinUnloadFailed",
+        SECURITY,
+        "addin unload failed",
+        "The add-in unload operation has failed.",
+    ),
+    info(
+        -67713,
+        "errSecInvalidKeyHierarchy",
+        SECURITY,
+        "invalid key hierarchy",
+        "An invalid key hierarchy was encountered.",
+    ),
+    info(
+        -67712,
+        "errSecInvalidKeyRef",
+        SECURITY,
+        "invalid key ref",
+        "An invalid key was encountered.",
+    ),
+    info(
+        -67711,
+        "errSecAddinLoadFailed",
+        SECURITY,
+        "addin load failed",
+        "The add-in load operation has failed.",
+    ),
+    info(
+        -67710,
+        "errSecEMMUnloadFailed",
+        SECURITY,
+        "EMM unload failed",
+        "The EMM unload has failed.",
+    ),
+    info(
+        -67709,
+        "errSecEMMLoadFailed",
+        SECURITY,
+        "EMM load failed",
+        "The EMM load has failed.",
+    ),
+    info(
+        -67708,
+        "errSecInvalidPVC",
+        SECURITY,
+        "invalid PVC",
+        "An invalid PVC was encountered.",
+    ),
+    info(
+        -67707,
+        "errSecPVCAlreadyConfigured",
+        SECURITY,
+        "PVC already configured",
+        "The PVC is already configured.",
+    ),
+    info(
+        -67706,
+        "errSecInvalidScope",
+        SECURITY,
+        "invalid scope",
+        "An invalid scope was encountered.",
+    ),
+    info(
+        -67705,
+        "errSecPrivilegeNotGranted",
+        SECURITY,
+        "privilege not granted",
+        "The privilege was not granted.",
+    ),
+    info(
+        -67704,
+        "errSecIncompatibleVersion",
+        SECURITY,
+        "incompatible version",
+        "An incompatible version was encountered.",
+    ),
+    info(
+        -67703,
+        "errSecInvalidSampleValue",
+        SECURITY,
+        "invalid sample value",
+        "An invalid sample value was encountered.",
+    ),
+    info(
+        -67702,
+        "errSecInvalidACL",
+        SECURITY,
+        "invalid ACL",
+        "An invalid ACL was encountered.",
+    ),
+    info(
+        -67701,
+        "errSecInvalidRecord",
+        SECURITY,
+        "invalid record",
+        "An invalid record was encountered.",
+    ),
+    info(
+        -67700,
+        "errSecInvalidAccessCredentials",
+        SECURITY,
+        "invalid access credentials",
+        "",
+    ),
+    info(
+        -67699,
+        "errSecACLChangeFailed",
+        SECURITY,
+        "ACL change failed",
+        "An ACL change operation has failed.",
+    ),
+    info(
+        -67698,
+        "errSecACLAddFailed",
+        SECURITY,
+        "ACL add failed",
+        "An ACL add operation has failed.",
+    ),
+    info(
+        -67697,
+        "errSecACLReplaceFailed",
+        SECURITY,
+        "ACL replace failed",
+        "An ACL replace operation has failed.",
+    ),
+    info(
+        -67696,
+        "errSecACLDeleteFailed",
+        SECURITY,
+        "ACL delete failed",
+        "An ACL delete operation has failed.",
+    ),
+    info(
+        -67695,
+        "errSecCallbackFailed",
+        SECURITY,
+        "callback failed",
+        "A callback has failed.",
+    ),
+    info(
+        -67694,
+        "errSecInvalidValue",
+        SECURITY,
+        "invalid value",
+        "An invalid value was detected.",
+    ),
+    info(
+        -67693,
+        "errSecInvalidQuery",
+        SECURITY,
+        "invalid query",
+        "The specified query was not valid.",
+    ),
+    info(
+        -67692,
+        "errSecTagNotFound",
+        SECURITY,
+        "tag not found",
+        "The specified tag was not found.",
+    ),
+    info(
+        -67691,
+        "errSecInvalidCertificateGroup",
+        SECURITY,
+        "invalid certificate group",
+        "An invalid certificate group was encountered.",
+    ),
+    info(
+        -67690,
+        "errSecInvalidCertificateRef",
+        SECURITY,
+        "invalid certificate ref",
+        "An invalid certificate reference was encountered.",
+    ),
+    info(
+        -67689,
+        "errSecInvalidName",
+        SECURITY,
+        "invalid name",
+        "An invalid name was encountered.",
+    ),
+    info(
+        -67688,
+        "errSecInvalidSignature",
+        SECURITY,
+        "invalid signature",
+        "An invalid signature was encountered.",
+    ),
+    info(
+        -67687,
+        "errSecUnknownTag",
+        SECURITY,
+        "unknown tag",
+        "An unknown tag was encountered.",
+    ),
+    info(
+        -67686,
+        "errSecVerificationFailure",
+        SECURITY,
+        "verification failure",
+        "A verification failure occurred.",
+    ),
+    info(
+        -67685,
+        "errSecInvalidNumberOfFields",
+        SECURITY,
+        "invalid number of fields",
+        "An invalid number of fields were encountered.",
+    ),
+    info(
+        -67684,
+        "errSecCRLAlreadySigned",
+        SECURITY,
+        "CRL already signed",
+        "The certificate revocation list is already signed.",
+    ),
+    info(
+        -67683,
+        "errSecInvalidNetworkAddress",
+        SECURITY,
+        "invalid network address",
+        "An invalid network address was encountered.",
+    ),
+    info(
+        -67682,
+        "errSecInvalidPassthroughID",
+        SECURITY,
+        "invalid passthrough ID",
+        "An invalid passthrough ID was encountered.",
+    ),
+    info(
+        -67681,
+        "errSecInvalidDBList",
+        SECURITY,
+        "invalid DB list",
+        "An invalid DB list was encountered.",
+    ),
+    info(
+        -67680,
+        "errSecInvalidHandle",
+        SECURITY,
+        "invalid handle",
+        "An invalid handle was encountered.",
+    ),
+    info(
+        -67679,
+        "errSecInvalidGUID",
+        SECURITY,
+        "invalid GUID",
+        "An invalid GUID was encountered.",
+    ),
+    info(
+        -67678,
+        "errSecModuleManifestVerifyFailed",
+        SECURITY,
+        "module manifest verify failed",
+        "A module manifest verification failure has occurred.",
+    ),
+    info(
+        -67677,
+        "errSecFunctionFailed",
+        SECURITY,
+        "function failed",
+        "A function has failed.",
+    ),
+    info(
+        -67676,
+        "errSecSelfCheckFailed",
+        SECURITY,
+        "self check failed",
+        "Self-check has failed.",
+    ),
+    info(
+        -67675,
+        "errSecInvalidPointer",
+        SECURITY,
+        "invalid pointer",
+        "An invalid pointer was encountered.",
+    ),
+    info(
+        -67674,
+        "errSecMDSError",
+        SECURITY,
+        "MDS",
+        "A Module Directory Service error has occurred.",
+    ),
+    info(
+        -67673,
+        "errSecInvalidData",
+        SECURITY,
+        "invalid data",
+        "Invalid data was encountered.",
+    ),
+    info(
+        -67672,
+        "errSecMemoryError",
+        SECURITY,
+        "memory",
+        "A memory error has occurred.",
+    ),
+    info(
+        -67671,
+        "errSecInternalError",
+        SECURITY,
+        "internal",
+        "An internal error has occurred.",
+    ),
+    info(
+        -67670,
+        "errSecFunctionIntegrityFail",
+        SECURITY,
+        "function integrity fail",
+        "A function address was not within the verified module.",
+    ),
+    info(
+        -67669,
+        "errSecPVCReferentNotFound",
+        SECURITY,
+        "PVC referent not found",
+        "A reference to the calling module was not found in the list of authorized callers.",
+    ),
+    info(
+        -67668,
+        "errSecInvalidHandleUsage",
+        SECURITY,
+        "invalid handle usage",
+        "The CSSM handle does not match with the service type.",
+    ),
+    info(
+        -67667,
+        "errSecNotInitialized",
+        SECURITY,
+        "not initialized",
+        "A function was called without initializing CSSM.",
+    ),
+    info(
+        -67666,
+        "errSecMobileMeFailedConsistencyCheck",
+        SECURITY,
+        "mobile me failed consistency check",
+        "",
+    ),
+    info(
+        -67665,
+        "errSecMobileMeCSRVerifyFailure",
+        SECURITY,
+        "mobile me CSR verify failure",
+        "",
+    ),
+    info(
+        -67664,
+        "errSecMobileMeNoRequestPending",
+        SECURITY,
+        "mobile me no request pending",
+        "",
+    ),
+    info(
+        -67663,
+        "errSecMobileMeRequestAlreadyPending",
+        SECURITY,
+        "mobile me request already pending",
+        "",
+    ),
+    info(
+        -67662,
+        "errSecMobileMeServerServiceErr",
+        SECURITY,
+        "mobile me server service",
+        "",
+    ),
+    info(
+        -67661,
+        "errSecMobileMeServerAlreadyExists",
+        SECURITY,
+        "mobile me server already exists",
+        "",
+    ),
+    info(
+        -67660,
+        "errSecMobileMeServerNotAvailable",
+        SECURITY,
+        "mobile me server not available",
+        "",
+    ),
+    info(
+        -67659,
+        "errSecMobileMeServerError",
+        SECURITY,
+        "mobile me server",
+        "",
+    ),
+    info(
+        -67658,
+        "errSecMobileMeRequestRedirected",
+        SECURITY,
+        "mobile me request redirected",
+        "",
+    ),
+    info(
+        -67657,
+        "errSecMobileMeRequestQueued",
+        SECURITY,
+        "mobile me request queued",
+        "",
+    ),
+    info(
+        -67656,
+        "errSecUnknownQualifiedCertStatement",
+        SECURITY,
+        "unknown qualified cert statement",
+        "An unknown qualified certificate statement was encountered.",
+    ),
+    info(
+        -67655,
+        "errSecInvalidSubjectName",
+        SECURITY,
+        "invalid subject name",
+        "An invalid certificate subject name was encountered.",
+    ),
+    info(
+        -67654,
+        "errSecTrustSettingDeny",
+        SECURITY,
+        "trust setting deny",
+        "The trust setting for this policy was set to Deny.",
+    ),
+    info(
+        -67653,
+        "errSecResourceSignBadExtKeyUsage",
+        SECURITY,
+        "resource sign bad ext key usage",
+        "Resource signing has encountered an error in the extended key usage.",
+    ),
+    info(
+        -67652,
+        "errSecResourceSignBadCertChainLength",
+        SECURITY,
+        "resource sign bad cert chain length",
+        "Resource signing has encountered an incorrect certificate chain length.",
+    ),
+    info(
+        -67651,
+        "errSecCodeSigningDevelopment",
+        SECURITY,
+        "code signing development",
+        "Code signing indicated use of a development-only certificate.",
+    ),
+    info(
+        -67650,
+        "errSecCodeSigningNoExtendedKeyUsage",
+        SECURITY,
+        "code signing no extended key usage",
+        "Code signing found no extended key usage.",
+    ),
+    info(
+        -67649,
+        "errSecCodeSigningBadPathLengthConstraint",
+        SECURITY,
+        "code signing bad path length constraint",
+        "Code signing encountered an incorrect path length constraint.",
+    ),
+    info(
+        -67648,
+        "errSecCodeSigningNoBasicConstraints",
+        SECURITY,
+        "code signing no basic constraints",
+        "Code signing found no basic constraints.",
+    ),
+    info(
+        -67647,
+        "errSecCodeSigningBadCertChainLength",
+        SECURITY,
+        "code signing bad cert chain length",
+        "Code signing encountered an incorrect certificate chain length.",
+    ),
+    info(
+        -67646,
+        "errSecOCSPResponseNonceMismatch",
+        SECURITY,
+        "OCSP response nonce mismatch",
+        "The OCSP response nonce did not match the request.",
+    ),
+    info(
+        -67645,
+        "errSecOCSPResponderUnauthorized",
+        SECURITY,
+        "OCSP responder unauthorized",
+        "The OCSP responder rejected this request as unauthorized.",
+    ),
+    info(
+        -67644,
+        "errSecOCSPResponderSignatureRequired",
+        SECURITY,
+        "OCSP responder signature required",
+        "The OCSP responder requires a signature.",
+    ),
+    info(
+        -67643,
+        "errSecOCSPResponderTryLater",
+        SECURITY,
+        "OCSP responder try later",
+        "The OCSP responder is busy, try again later.",
+    ),
+    info(
+        -67642,
+        "errSecOCSPResponderInternalError",
+        SECURITY,
+        "OCSP responder internal",
+        "The OCSP responder encountered an internal error.",
+    ),
+    info(
+        -67641,
+        "errSecOCSPResponderMalformedReq",
+        SECURITY,
+        "OCSP responder malformed req",
+        "The OCSP responder was given a malformed request.",
+    ),
+    info(
+        -67640,
+        "errSecOCSPNoSigner",
+        SECURITY,
+        "OCSP no signer",
+        "The OCSP response had no signer.",
+    ),
+    info(
+        -67639,
+        "errSecOCSPSignatureError",
+        SECURITY,
+        "OCSP signature",
+        "The OCSP response had an invalid signature.",
+    ),
+    info(
+        -67638,
+        "errSecRecordModified",
+        SECURITY,
+        "record modified",
+        "The record was modified.",
+    ),
+    info(
+        -67637,
+        "errSecOCSPNotTrustedToAnchor",
+        SECURITY,
+        "OCSP not trusted to anchor",
+        "The OCSP response was not trusted to a root or anchor certificate.",
+    ),
+    info(
+        -67636,
+        "errSecNetworkFailure",
+        SECURITY,
+        "network failure",
+        "A network failure occurred.",
+    ),
+    info(
+        -67635,
+        "errSecIncompleteCertRevocationCheck",
+        SECURITY,
+        "incomplete cert revocation check",
+        "An incomplete certificate revocation check occurred.",
+    ),
+    info(
+        -67634,
+        "errSecEndOfData",
+        SECURITY,
+        "end of data",
+        "An end-of-data was detected.",
+    ),
+    info(
+        -67633,
+        "errSecOCSPStatusUnrecognized",
+        SECURITY,
+        "OCSP status unrecognized",
+        "The OCSP server did not recognize this certificate.",
+    ),
+    info(
+        -67632,
+        "errSecOCSPUnavailable",
+        SECURITY,
+        "OCSP unavailable",
+        "OCSP service is unavailable.",
+    ),
+    info(
+        -67631,
+        "errSecOCSPBadRequest",
+        SECURITY,
+        "OCSP bad request",
+        "The OCSP request was incorrect or could not be parsed.",
+    ),
+    info(
+        -67630,
+        "errSecOCSPBadResponse",
+        SECURITY,
+        "OCSP bad response",
+        "The OCSP response was incorrect or could not be parsed.",
+    ),
+    info(
+        -67629,
+        "errSecSSLBadExtendedKeyUsage",
+        SECURITY,
+        "SSL bad extended key usage",
+        "The appropriate extended key usage for SSL was not found.",
+    ),
+    info(
+        -67628,
+        "errSecSMIMESubjAltNameNotCritical",
+        SECURITY,
+        "SMIME subj alt name not critical",
+        "The subject alternative name extension is not marked as critical.",
+    ),
+    info(
+        -67627,
+        "errSecSMIMENoEmailAddress",
+        SECURITY,
+        "SMIME no email address",
+        "No email address was found in the certificate.",
+    ),
+    info(
+        -67626,
+        "errSecSMIMEKeyUsageNotCritical",
+        SECURITY,
+        "SMIME key usage not critical",
+        "The key usage extension is not marked as critical.",
+    ),
+    info(
+        -67625,
+        "errSecSMIMEBadKeyUsage",
+        SECURITY,
+        "SMIME bad key usage",
+        "The key usage is not compatible with SMIME.",
+    ),
+    info(
+        -67624,
+        "errSecSMIMEBadExtendedKeyUsage",
+        SECURITY,
+        "SMIME bad extended key usage",
+        "The appropriate extended key usage for SMIME was not found.",
+    ),
+    info(
+        -67623,
+        "errSecSMIMEEmailAddressesNotFound",
+        SECURITY,
+        "SMIME email addresses not found",
+        "An email address mismatch was encountered.",
+    ),
+    info(
+        -67622,
+        "errSecIDPFailure",
+        SECURITY,
+        "IDP failure",
+        "The issuing distribution point was not valid.",
+    ),
+    info(
+        -67621,
+        "errSecCRLPolicyFailed",
+        SECURITY,
+        "CRL policy failed",
+        "The CRL policy failed.",
+    ),
+    info(
+        -67620,
+        "errSecCRLNotTrusted",
+        SECURITY,
+        "CRL not trusted",
+        "The CRL is not trusted.",
+    ),
+    info(
+        -67619,
+        "errSecUnknownCRLExtension",
+        SECURITY,
+        "unknown CRL extension",
+        "An unknown CRL extension was encountered.",
+    ),
+    info(
+        -67618,
+        "errSecUnknownCertExtension",
+        SECURITY,
+        "unknown cert extension",
+        "An unknown certificate extension was encountered.",
+    ),
+    info(
+        -67617,
+        "errSecCRLBadURI",
+        SECURITY,
+        "CRL bad URI",
+        "The CRL has a bad Uniform Resource Identifier.",
+    ),
+    info(
+        -67616,
+        "errSecCRLServerDown",
+        SECURITY,
+        "CRL server down",
+        "The CRL server is down.",
+    ),
+    info(
+        -67615,
+        "errSecCRLNotFound",
+        SECURITY,
+        "CRL not found",
+        "The CRL was not found.",
+    ),
+    info(
+        -67614,
+        "errSecCRLNotValidYet",
+        SECURITY,
+        "CRL not valid yet",
+        "The CRL is not yet valid.",
+    ),
+    info(
+        -67613,
+        "errSecCRLExpired",
+        SECURITY,
+        "CRL expired",
+        "The CRL has expired.",
+    ),
+    info(
+        -67612,
+        "errSecInvalidRoot",
+        SECURITY,
+        "invalid root",
+        "The root or anchor certificate is not valid.",
+    ),
+    info(
+        -67611,
+        "errSecPathLengthConstraintExceeded",
+        SECURITY,
+        "path length constraint exceeded",
+        "The path length constraint was exceeded.",
+    ),
+    info(
+        -67610,
+        "errSecInvalidIDLinkage",
+        SECURITY,
+        "invalid ID linkage",
+        "The ID linkage is not valid.",
+    ),
+    info(
+        -67609,
+        "errSecInvalidExtendedKeyUsage",
+        SECURITY,
+        "invalid extended key usage",
+        "The extended key usage is not valid.",
+    ),
+    info(
+        -67608,
+        "errSecInvalidKeyUsageForPolicy",
+        SECURITY,
+        "invalid key usage for policy",
+        "The key usage is not valid for the specified policy.",
+    ),
+    info(
+        -67607,
+        "errSecInvalidSubjectKeyID",
+        SECURITY,
+        "invalid subject key ID",
+        "The subject key ID is not valid.",
+    ),
+    info(
+        -67606,
+        "errSecInvalidAuthorityKeyID",
+        SECURITY,
+        "invalid authority key ID",
+        "The authority key ID is not valid.",
+    ),
+    info(
+        -67605,
+        "errSecNoBasicConstraintsCA",
+        SECURITY,
+        "no basic constraints CA",
+        "No basic CA constraints were found.",
+    ),
+    info(
+        -67604,
+        "errSecNoBasicConstraints",
+        SECURITY,
+        "no basic constraints",
+        "No basic constraints were found.",
+    ),
+    info(
+        -67603,
+        "errSecUnknownCriticalExtensionFlag",
+        SECURITY,
+        "unknown critical extension flag",
+        "There is an unknown critical extension flag.",
+    ),
+    info(
+        -67602,
+        "errSecHostNameMismatch",
+        SECURITY,
+        "host name mismatch",
+        "A host name mismatch has occurred.",
+    ),
+    info(
+        -67601,
+        "errSecIncompatibleKeyBlob",
+        SECURITY,
+        "incompatible key blob",
+        "The specified database has an incompatible key blob.",
+    ),
+    info(
+        -67600,
+        "errSecIncompatibleDatabaseBlob",
+        SECURITY,
+        "incompatible database blob",
+        "The specified database has an incompatible blob.",
+    ),
+    info(
+        -67599,
+        "errSecInvalidKeyBlob",
+        SECURITY,
+        "invalid key blob",
+        "The specified database has an invalid key blob.",
+    ),
+    info(
+        -67598,
+        "errSecInvalidDatabaseBlob",
+        SECURITY,
+        "invalid database blob",
+        "The specified database has an invalid blob.",
+    ),
+    info(
+        -67597,
+        "errSecFileTooBig",
+        SECURITY,
+        "file too big",
+        "The file is too big.",
+    ),
+    info(
+        -67596,
+        "errSecQuotaExceeded",
+        SECURITY,
+        "quota exceeded",
+        "The quota was exceeded.",
+    ),
+    info(
+        -67595,
+        "errSecAppleSSLv2Rollback",
+        SECURITY,
+        "apple SS lv 2 rollback",
+        "A SSLv2 rollback error has occurred.",
+    ),
+    info(
+        -67594,
+        "errSecConversionError",
+        SECURITY,
+        "conversion",
+        "A conversion error has occurred.",
+    ),
+    info(
+        -67593,
+        "errSecAppleInvalidKeyEndDate",
+        SECURITY,
+        "apple invalid key end date",
+        "The specified key has an invalid end date.",
+    ),
+    info(
+        -67592,
+        "errSecAppleInvalidKeyStartDate",
+        SECURITY,
+        "apple invalid key start date",
+        "The specified key has an invalid start date.",
+    ),
+    info(
+        -67591,
+        "errSecAppleSignatureMismatch",
+        SECURITY,
+        "apple signature mismatch",
+        "A signature mismatch has occurred.",
+    ),
+    info(
+        -67590,
+        "errSecApplePublicKeyIncomplete",
+        SECURITY,
+        "apple public key incomplete",
+        "The public key is incomplete.",
+    ),
+    info(
+        -67589,
+        "errSecAppleAddAppACLSubject",
+        SECURITY,
+        "apple add app ACL subject",
+        "Adding an application ACL subject failed.",
+    ),
+    info(
+        -67588,
+        "errSecDeviceFailed",
+        SECURITY,
+        "device failed",
+        "A device failure has occurred.",
+    ),
+    info(
+        -67587,
+        "errSecDeviceReset",
+        SECURITY,
+        "device reset",
+        "A device reset has occurred.",
+    ),
+    info(
+        -67586,
+        "errSecInsufficientClientID",
+        SECURITY,
+        "insufficient client ID",
+        "The client ID is not correct.",
+    ),
+    info(
+        -67585,
+        "errSecServiceNotAvailable",
+        SECURITY,
+        "service not available",
+        "The required service is not available.",
+    ),
+    info(
+        -66754,
+        "kAudioComponentErr_InstanceTimedOut",
+        AUDIO_UNIT,
+        "instance timed out",
+        "",
+    ),
+    info(
+        -66753,
+        "kAudioUnitErr_MIDIOutputBufferFull",
+        AUDIO_UNIT,
+        "MIDI output buffer full",
+        "Returned during the render call, if the audio unit produces more MIDI output, than the default allocated buffer. The audio unit can provide a size hint, in case it needs a larger buffer. See the documentation for AUAudioUnit's MIDIOutputBufferSizeHint property.",
+    ),
+    info(
+        -66752,
+        "kAudioComponentErr_DuplicateDescription",
+        AUDIO_UNIT,
+        "duplicate description",
+        "a non-unique component description was provided to AudioOutputUnitPublish",
+    ),
+    info(
+        -66751,
+        "kAudioComponentErr_UnsupportedType",
+        AUDIO_UNIT,
+        "unsupported type",
+        "an unsupported component type was provided to AudioOutputUnitPublish",
+    ),
+    info(
+        -66750,
+        "kAudioComponentErr_TooManyInstances",
+        AUDIO_UNIT,
+        "too many instances",
+        "components published via AudioOutputUnitPublish may only have one instance",
+    ),
+    info(
+        -66749,
+        "kAudioComponentErr_InstanceInvalidated",
+        AUDIO_UNIT,
+        "instance invalidated",
+        "",
+    ),
+    info(
+        -66748,
+        "kAudioComponentErr_NotPermitted",
+        AUDIO_UNIT,
+        "not permitted",
+        "app needs \"inter-app-audio\" entitlement or host app needs \"audio\" in its UIBackgroundModes. Or app is trying to register a component not declared in its Info.plist.",
+    ),
+    info(
+        -66747,
+        "kAudioComponentErr_InitializationTimedOut",
+        AUDIO_UNIT,
+        "initialization timed out",
+        "host did not render in a timely manner; must uninitialize and reinitialize.",
+    ),
+    info(
+        -66746,
+        "kAudioComponentErr_InvalidFormat",
+        AUDIO_UNIT,
+        "invalid format",
+        "inter-app AU element formats must have sample rates matching the hardware.",
+    ),
+    info(
+        -66745,
+        "kAudioUnitErr_RenderTimeout",
+        AUDIO_UNIT,
+        "render timeout",
+        "The audio unit did not satisfy the render request in time.",
+    ),
+    info(
+        -66744,
+        "kAudioUnitErr_ExtensionNotFound",
+        AUDIO_UNIT,
+        "extension not found",
+        "The specified identifier did not match any Audio Unit Extensions.",
+    ),
+    info(
+        -66743,
+        "kAudioUnitErr_InvalidParameterValue",
+        AUDIO_UNIT,
+        "invalid parameter value",
+        "The parameter value is not supported, e.g. the value specified is NaN or infinite.",
+    ),
+    info(
+        -66742,
+        "kAudioUnitErr_InvalidFilePath",
+        AUDIO_UNIT,
+        "invalid file path",
+        "The file path that was passed is not supported. It is either too long or contains invalid characters.",
+    ),
+    info(
+        -66741,
+        "kAudioUnitErr_MissingKey",
+        AUDIO_UNIT,
+        "missing key",
+        "A required key is missing from a dictionary object.",
+    ),
+    info(
+        -66740,
+        "kAudioUnitErr_ComponentManagerNotSupported",
+        AUDIO_UNIT,
+        "component manager not supported",
+        "The operation can not be performed for a component instance instantiated using the deprecated Component Manager. A host application should use the API functions AudioComponentInstantiate or AudioComponentInstanceNew when rebuilding against the macOS 11 or later SDK.",
+    ),
+    info(
+        -66687,
+        "kAudioQueueErr_InvalidBuffer",
+        AUDIO_QUEUE,
+        "invalid buffer",
+        "",
+    ),
+    info(
+        -66686,
+        "kAudioQueueErr_BufferEmpty",
+        AUDIO_QUEUE,
+        "buffer empty",
+        "",
+    ),
+    info(
+        -66685,
+        "kAudioQueueErr_DisposalPending",
+        AUDIO_QUEUE,
+        "disposal pending",
+        "",
+    ),
+    info(
+        -66684,
+        "kAudioQueueErr_InvalidProperty",
+        AUDIO_QUEUE,
+        "invalid property",
+        "",
+    ),
+    info(
+        -66683,
+        "kAudioQueueErr_InvalidPropertySize",
+        AUDIO_QUEUE,
+        "invalid property size",
+        "",
+    ),
+    info(
+        -66682,
+        "kAudioQueueErr_InvalidParameter",
+        AUDIO_QUEUE,
+        "invalid parameter",
+        "",
+    ),
+    info(
+        -66681,
+        "kAudioQueueErr_CannotStart",
+        AUDIO_QUEUE,
+        "cannot start",
+        "",
+    ),
+    info(
+        -66680,
+        "kAudioQueueErr_InvalidDevice",
+        AUDIO_QUEUE,
+        "invalid device",
+        "",
+    ),
+    info(
+        -66679,
+        "kAudioQueueErr_BufferInQueue",
+        AUDIO_QUEUE,
+        "buffer in queue",
+        "",
+    ),
+    info(
+        -66678,
+        "kAudioQueueErr_InvalidRunState",
+        AUDIO_QUEUE,
+        "invalid run state",
+        "",
+    ),
+    info(
+        -66677,
+        "kAudioQueueErr_InvalidQueueType",
+        AUDIO_QUEUE,
+        "invalid queue type",
+        "",
+    ),
+    info(
+        -66676,
+        "kAudioQueueErr_Permissions",
+        AUDIO_QUEUE,
+        "permissions",
+        "",
+    ),
+    info(
+        -66675,
+        "kAudioQueueErr_InvalidPropertyValue",
+        AUDIO_QUEUE,
+        "invalid property value",
+        "",
+    ),
+    info(
+        -66674,
+        "kAudioQueueErr_PrimeTimedOut",
+        AUDIO_QUEUE,
+        "prime timed out",
+        "",
+    ),
+    info(
+        -66673,
+        "kAudioQueueErr_CodecNotFound",
+        AUDIO_QUEUE,
+        "codec not found",
+        "",
+    ),
+    info(
+        -66672,
+        "kAudioQueueErr_InvalidCodecAccess",
+        AUDIO_QUEUE,
+        "invalid codec access",
+        "",
+    ),
+    info(
+        -66671,
+        "kAudioQueueErr_QueueInvalidated",
+        AUDIO_QUEUE,
+        "queue invalidated",
+        "",
+    ),
+    info(
+        -66670,
+        "kAudioQueueErr_TooManyTaps",
+        AUDIO_QUEUE,
+        "too many taps",
+        "",
+    ),
+    info(
+        -66669,
+        "kAudioQueueErr_InvalidTapContext",
+        AUDIO_QUEUE,
+        "invalid tap context",
+        "",
+    ),
+    info(
+        -66668,
+        "kAudioQueueErr_RecordUnderrun",
+        AUDIO_QUEUE,
+        "record underrun",
+        "",
+    ),
+    info(
+        -66667,
+        "kAudioQueueErr_InvalidTapType",
+        AUDIO_QUEUE,
+        "invalid tap type",
+        "",
+    ),
+    info(
+        -66666,
+        "kAudioQueueErr_BufferEnqueuedTwice",
+        AUDIO_QUEUE,
+        "buffer enqueued twice",
+        "",
+    ),
+    info(
+        -66665,
+        "kAudioQueueErr_CannotStartYet",
+        AUDIO_QUEUE,
+        "cannot start yet",
+        "",
+    ),
+    info(
+        -66635,
+        "kAudioUnitErr_MultipleVoiceProcessors",
+        AUDIO_UNIT,
+        "multiple voice processors",
+        "On some platforms, this error is returned when a client attempts to initialize a voice processor instance while another is initialized",
+    ),
+    info(
+        -66632,
+        "kAudioQueueErr_EnqueueDuringReset",
+        AUDIO_QUEUE,
+        "enqueue during reset",
+        "",
+    ),
+    info(
+        -66626,
+        "kAudioQueueErr_InvalidOfflineMode",
+        AUDIO_QUEUE,
+        "invalid offline mode",
+        "",
+    ),
+    info(
+        -34020,
+        "errSecRestrictedAPI",
+        SECURITY,
+        "restricted API",
+        "Client is restricted and is not permitted to perform this operation.",
+    ),
+    info(
+        -34018,
+        "errSecMissingEntitlement",
+        SECURITY,
+        "missing entitlement",
+        "A required entitlement isn't present.",
+    ),
+    info(
+        -26275,
+        "errSecDecode",
+        SECURITY,
+        "decode",
+        "Unable to decode the provided data.",
+    ),
+    info(
+        -26267,
+        "errSecNotSigner",
+        SECURITY,
+        "not signer",
+        "A certificate was not signed by its proposed parent.",
+    ),
+    info(
+        -25320,
+        "errSecInDarkWake",
+        SECURITY,
+        "in dark wake",
+        "In dark wake, no UI possible",
+    ),
+    info(
+        -25319,
+        "errSecInvalidPrefsDomain",
+        SECURITY,
+        "invalid prefs domain",
+        "The specified preferences domain is not valid.",
+    ),
+    info(
+        -25318,
+        "errSecCreateChainFailed",
+        SECURITY,
+        "create chain failed",
+        "One or more certificates required to validate this certificate cannot be found.",
+    ),
+    info(
+        -25317,
+        "errSecDataNotModifiable",
+        SECURITY,
+        "data not modifiable",
+        "The contents of this item cannot be modified.",
+    ),
+    info(
+        -25316,
+        "errSecDataNotAvailable",
+        SECURITY,
+        "data not available",
+        "The contents of this item cannot be retrieved.",
+    ),
+    info(
+        -25315,
+        "errSecInteractionRequired",
+        SECURITY,
+        "interaction required",
+        "User interaction is required, but is currently not allowed.",
+    ),
+    info(
+        -25314,
+        "errSecNoPolicyModule",
+        SECURITY,
+        "no policy module",
+        "A required component (policy module) could not be loaded. You may need to restart your computer.",
+    ),
+    info(
+        -25313,
+        "errSecNoCertificateModule",
+        SECURITY,
+        "no certificate module",
+        "A required component (certificate module) could not be loaded. You may need to restart your computer.",
+    ),
+    info(
+        -25312,
+        "errSecNoStorageModule",
+        SECURITY,
+        "no storage module",
+        "A required component (data storage module) could not be loaded. You may need to restart your computer.",
+    ),
+    info(
+        -25311,
+        "errSecKeySizeNotAllowed",
+        SECURITY,
+        "key size not allowed",
+        "This item specifies a key size which is too large or too small.",
+    ),
+    info(
+        -25310,
+        "errSecWrongSecVersion",
+        SECURITY,
+        "wrong sec version",
+        "This keychain was created by a different version of the system software and cannot be opened.",
+    ),
+    info(
+        -25309,
+        "errSecReadOnlyAttr",
+        SECURITY,
+        "read only attr",
+        "The specified attribute could not be modified.",
+    ),
+    info(
+        -25308,
+        "errSecInteractionNotAllowed",
+        SECURITY,
+        "interaction not allowed",
+        "User interaction is not allowed.",
+    ),
+    info(
+        -25307,
+        "errSecNoDefaultKeychain",
+        SECURITY,
+        "no default keychain",
+        "A default keychain could not be found.",
+    ),
+    info(
+        -25306,
+        "errSecNoSuchClass",
+        SECURITY,
+        "no such class",
+        "The specified item does not appear to be a valid keychain item.",
+    ),
+    info(
+        -25305,
+        "errSecInvalidSearchRef",
+        SECURITY,
+        "invalid search ref",
+        "Unable to search the current keychain.",
+    ),
+    info(
+        -25304,
+        "errSecInvalidItemRef",
+        SECURITY,
+        "invalid item ref",
+        "The specified item is no longer valid. It may have been deleted from the keychain.",
+    ),
+    info(
+        -25303,
+        "errSecNoSuchAttr",
+        SECURITY,
+        "no such attr",
+        "The specified attribute does not exist.",
+    ),
+    info(
+        -25302,
+        "errSecDataTooLarge",
+        SECURITY,
+        "data too large",
+        "This item contains information which is too large or in a format that cannot be displayed.",
+    ),
+    info(
+        -25301,
+        "errSecBufferTooSmall",
+        SECURITY,
+        "buffer too small",
+        "There is not enough memory available to use the specified item.",
+    ),
+    info(
+        -25300,
+        "errSecItemNotFound",
+        SECURITY,
+        "item not found",
+        "The specified item could not be found in the keychain.",
+    ),
+    info(
+        -25299,
+        "errSecDuplicateItem",
+        SECURITY,
+        "duplicate item",
+        "The specified item already exists in the keychain.",
+    ),
+    info(
+        -25298,
+        "errSecInvalidCallback",
+        SECURITY,
+        "invalid callback",
+        "The specified callback function is not valid.",
+    ),
+    info(
+        -25297,
+        "errSecDuplicateCallback",
+        SECURITY,
+        "duplicate callback",
+        "The specified callback function is already installed.",
+    ),
+    info(
+        -25296,
+        "errSecDuplicateKeychain",
+        SECURITY,
+        "duplicate keychain",
+        "A keychain with the same name already exists.",
+    ),
+    info(
+        -25295,
+        "errSecInvalidKeychain",
+        SECURITY,
+        "invalid keychain",
+        "The specified keychain is not a valid keychain file.",
+    ),
+    info(
+        -25294,
+        "errSecNoSuchKeychain",
+        SECURITY,
+        "no such keychain",
+        "The specified keychain could not be found.",
+    ),
+    info(
+        -25293,
+        "errSecAuthFailed",
+        SECURITY,
+        "auth failed",
+        "The user name or passphrase you entered is not correct.",
+    ),
+    info(
+        -25292,
+        "errSecReadOnly",
+        SECURITY,
+        "read only",
+        "This keychain cannot be modified.",
+    ),
+    info(
+        -25291,
+        "errSecNotAvailable",
+        SECURITY,
+        "not available",
+        "No keychain is available. You may need to restart your computer.",
+    ),
+    info(
+        -25264,
+        "errSecPkcs12VerifyFailure",
+        SECURITY,
+        "pkcs 12 verify failure",
+        "MAC verification failed during PKCS12 import (wrong password?)",
+    ),
+    info(
+        -25263,
+        "errSecNoTrustSettings",
+        SECURITY,
+        "no trust settings",
+        "No Trust Settings were found.",
+    ),
+    info(
+        -25262,
+        "errSecInvalidTrustSettings",
+        SECURITY,
+        "invalid trust settings",
+        "The Trust Settings Record was corrupted.",
+    ),
+    info(
+        -25261,
+        "errSecInvalidPasswordRef",
+        SECURITY,
+        "invalid password ref",
+        "The password reference was invalid.",
+    ),
+    info(
+        -25260,
+        "errSecPassphraseRequired",
+        SECURITY,
+        "passphrase required",
+        "Passphrase is required for import/export.",
+    ),
+    info(
+        -25259,
+        "errSecMultiplePrivKeys",
+        SECURITY,
+        "multiple priv keys",
+        "An attempt was made to import multiple private keys.",
+    ),
+    info(
+        -25258,
+        "errSecKeyIsSensitive",
+        SECURITY,
+        "key is sensitive",
+        "Key material must be wrapped for export.",
+    ),
+    info(
+        -25257,
+        "errSecUnknownFormat",
+        SECURITY,
+        "unknown format",
+        "Unknown format in import.",
+    ),
+    info(
+        -25256,
+        "errSecUnsupportedFormat",
+        SECURITY,
+        "unsupported format",
+        "Import/Export format unsupported.",
+    ),
+    info(
+        -25245,
+        "errSecTrustNotAvailable",
+        SECURITY,
+        "trust not available",
+        "No trust results are available.",
+    ),
+    info(
+        -25244,
+        "errSecInvalidOwnerEdit",
+        SECURITY,
+        "invalid owner edit",
+        "Invalid attempt to change the owner of this item.",
+    ),
+    info(
+        -25243,
+        "errSecNoAccessForItem",
+        SECURITY,
+        "no access for item",
+        "The specified item has no access control.",
+    ),
+    info(
+        -25242,
+        "errSecInvalidTrustSetting",
+        SECURITY,
+        "invalid trust setting",
+        "The specified trust setting is invalid.",
+    ),
+    info(
+        -25241,
+        "errSecPolicyNotFound",
+        SECURITY,
+        "policy not found",
+        "The specified policy cannot be found.",
+    ),
+    info(
+        -25240,
+        "errSecACLNotSimple",
+        SECURITY,
+        "ACL not simple",
+        "The specified access control list is not in standard (simple) form.",
+    ),
+    info(
+        -22144,
+        "kCGImageAnimationStatus_AllocationFailure",
+        IMAGE_IO,
+        "allocation failure",
+        "A required resource could not be created",
+    ),
+    info(
+        -22143,
+        "kCGImageAnimationStatus_IncompleteInputImage",
+        IMAGE_IO,
+        "incomplete input image",
+        "An image can be read from the given source, but it is incomplete",
+    ),
+    info(
+        -22142,
+        "kCGImageAnimationStatus_UnsupportedFormat",
+        IMAGE_IO,
+        "unsupported format",
+        "The image format is not applicable to animation",
+    ),
+    info(
+        -22141,
+        "kCGImageAnimationStatus_CorruptInputImage",
+        IMAGE_IO,
+        "corrupt input image",
+        "An image cannot be read from the given source",
+    ),
+    info(
+        -22140,
+        "kCGImageAnimationStatus_ParameterError",
+        IMAGE_IO,
+        "parameter",
+        "None/null or invalid parameter passed to API",
+    ),
+    info(
+        -17695,
+        "kVTVideoDecoderCallbackMessagingErr",
+        VIDEO_TOOLBOX,
+        "video decoder callback messaging",
+        "",
+    ),
+    info(
+        -17694,
+        "kVTVideoDecoderReferenceMissingErr",
+        VIDEO_TOOLBOX,
+        "video decoder reference missing",
+        "",
+    ),
+    info(
+        -17693,
+        "kVTVideoEncoderNeedsRosettaErr",
+        VIDEO_TOOLBOX,
+        "video encoder needs rosetta",
+        "",
+    ),
+    info(
+        -17692,
+        "kVTVideoDecoderNeedsRosettaErr",
+        VIDEO_TOOLBOX,
+        "video decoder needs rosetta",
+        "",
+    ),
+    info(
+        -17691,
+        "kVTSessionMalfunctionErr",
+        VIDEO_TOOLBOX,
+        "session malfunction",
+        "",
+    ),
+    info(
+        -17690,
+        "kVTVideoDecoderRemovedErr",
+        VIDEO_TOOLBOX,
+        "video decoder removed",
+        "",
+    ),
+    info(
+        -16751,
+        "kCMSampleBufferError_DataCanceled",
+        CORE_MEDIA,
+        "data canceled",
+        "the sample buffer's data loading operation was canceled.",
+    ),
+    info(
+        -16750,
+        "kCMSampleBufferError_DataFailed",
+        CORE_MEDIA,
+        "data failed",
+        "the sample buffer's data loading operation failed (generic error).",
+    ),
+    info(
+        -15782,
+        "kCMTaggedBufferGroupError_InternalError",
+        CORE_MEDIA,
+        "internal",
+        "",
+    ),
+    info(
+        -15781,
+        "kCMTaggedBufferGroupError_AllocationFailed",
+        CORE_MEDIA,
+        "allocation failed",
+        "",
+    ),
+    info(
+        -15780,
+        "kCMTaggedBufferGroupError_ParamErr",
+        CORE_MEDIA,
+        "param",
+        "",
+    ),
+    info(
+        -15749,
+        "kCMTagCollectionError_NotYetImplemented",
+        CORE_MEDIA,
+        "not yet implemented",
+        "Returned if the function is not yet implemented.",
+    ),
+    info(
+        -15748,
+        "kCMTagCollectionError_ExhaustedBufferSize",
+        CORE_MEDIA,
+        "exhausted buffer size",
+        "Returned if the buffer size to retrieve cm::Tags is smaller than necessary.",
+    ),
+    info(
+        -15747,
+        "kCMTagCollectionError_InvalidTagCollectionDataVersion",
+        CORE_MEDIA,
+        "invalid tag collection data version",
+        "Returned if the CFData deserialized to create a cm::TagCollection has an unknown version.",
+    ),
+    info(
+        -15746,
+        "kCMTagCollectionError_TagNotFound",
+        CORE_MEDIA,
+        "tag not found",
+        "Returned if a search for a cm::Tag in the collection failed, including if the collection is empty.",
+    ),
+    info(
+        -15745,
+        "kCMTagCollectionError_InvalidTagCollectionData",
+        CORE_MEDIA,
+        "invalid tag collection data",
+        "Returned if structure of the CFData being deserialized is not valid to create a cm::TagCollection.",
+    ),
+    info(
+        -15744,
+        "kCMTagCollectionError_InvalidTagCollectionDictionary",
+        CORE_MEDIA,
+        "invalid tag collection dictionary",
+        "Returned if the CFDictionary being deserialized is not valid to create a cm::TagCollection.",
+    ),
+    info(
+        -15743,
+        "kCMTagCollectionError_InvalidTag",
+        CORE_MEDIA,
+        "invalid tag",
+        "Returned if the tag is kCMTagInvalid.",
+    ),
+    info(
+        -15742,
+        "kCMTagCollectionError_InternalError",
+        CORE_MEDIA,
+        "internal",
+        "/ Returned if some kind of internal implementation error occurred.",
+    ),
+    info(
+        -15741,
+        "kCMTagCollectionError_AllocationFailed",
+        CORE_MEDIA,
+        "allocation failed",
+        "Returned if a necessary allocation failed.",
+    ),
+    info(
+        -15740,
+        "kCMTagCollectionError_ParamErr",
+        CORE_MEDIA,
+        "param",
+        "When caller passes incorrect input or output parameters.",
+    ),
+    info(
+        -15731,
+        "kCMTagError_AllocationFailed",
+        CORE_MEDIA,
+        "allocation failed",
+        "Returned if a necessary allocation failed.",
+    ),
+    info(
+        -15730,
+        "kCMTagError_ParamErr",
+        CORE_MEDIA,
+        "param",
+        "When caller passes incorrect input or output parameters.",
+    ),
+    info(
+        -12919,
+        "kVTColorSyncTransformConvertFailedErr",
+        VIDEO_TOOLBOX,
+        "color sync transform convert failed",
+        "",
+    ),
+    info(
+        -12918,
+        "kVTCouldNotCreateColorCorrectionDataErr",
+        VIDEO_TOOLBOX,
+        "could not create color correction data",
+        "",
+    ),
+    info(
+        -12917,
+        "kVTInsufficientSourceColorDataErr",
+        VIDEO_TOOLBOX,
+        "insufficient source color data",
+        "",
+    ),
+    info(
+        -12916,
+        "kVTFormatDescriptionChangeNotSupportedErr",
+        VIDEO_TOOLBOX,
+        "format description change not supported",
+        "",
+    ),
+    info(
+        -12915,
+        "kVTVideoEncoderNotAvailableNowErr",
+        VIDEO_TOOLBOX,
+        "video encoder not available now",
+        "",
+    ),
+    info(
+        -12914,
+        "kVTImageRotationNotSupportedErr",
+        VIDEO_TOOLBOX,
+        "image rotation not supported",
+        "",
+    ),
+    info(
+        -12913,
+        "kVTVideoDecoderNotAvailableNowErr",
+        VIDEO_TOOLBOX,
+        "video decoder not available now",
+        "",
+    ),
+    info(
+        -12912,
+        "kVTVideoEncoderMalfunctionErr",
+        VIDEO_TOOLBOX,
+        "video encoder malfunction",
+        "",
+    ),
+    info(
+        -12911,
+        "kVTVideoDecoderMalfunctionErr",
+        VIDEO_TOOLBOX,
+        "video decoder malfunction",
+        "",
+    ),
+    info(
+        -12910,
+        "kVTVideoDecoderUnsupportedDataFormatErr",
+        VIDEO_TOOLBOX,
+        "video decoder unsupported data format",
+        "",
+    ),
+    info(
+        -12909,
+        "kVTVideoDecoderBadDataErr",
+        VIDEO_TOOLBOX,
+        "video decoder bad data",
+        "",
+    ),
+    info(
+        -12908,
+        "kVTCouldNotFindVideoEncoderErr",
+        VIDEO_TOOLBOX,
+        "could not find video encoder",
+        "",
+    ),
+    info(
+        -12907,
+        "kVTCouldNotCreateInstanceErr",
+        VIDEO_TOOLBOX,
+        "could not create instance",
+        "",
+    ),
+    info(
+        -12906,
+        "kVTCouldNotFindVideoDecoderErr",
+        VIDEO_TOOLBOX,
+        "could not find video decoder",
+        "",
+    ),
+    info(
+        -12905,
+        "kVTPixelTransferNotSupportedErr",
+        VIDEO_TOOLBOX,
+        "pixel transfer not supported",
+        "",
+    ),
+    info(
+        -12904,
+        "kVTAllocationFailedErr",
+        VIDEO_TOOLBOX,
+        "allocation failed",
+        "",
+    ),
+    info(
+        -12903,
+        "kVTInvalidSessionErr",
+        VIDEO_TOOLBOX,
+        "invalid session",
+        "",
+    ),
+    info(-12902, "kVTParameterErr", VIDEO_TOOLBOX, "parameter", ""),
+    info(
+        -12901,
+        "kVTPropertyReadOnlyErr",
+        VIDEO_TOOLBOX,
+        "property read only",
+        "",
+    ),
+    info(
+        -12900,
+        "kVTPropertyNotSupportedErr",
+        VIDEO_TOOLBOX,
+        "property not supported",
+        "",
+    ),
+    info(
+        -12773,
+        "kCMSimpleQueueError_QueueIsFull",
+        CORE_MEDIA,
+        "queue is full",
+        "Operation failed because queue was full.",
+    ),
+    info(
+        -12772,
+        "kCMSimpleQueueError_ParameterOutOfRange",
+        CORE_MEDIA,
+        "parameter out of range",
+        "An out-of-range value was passed for a parameter with a restricted valid range.",
+    ),
+    info(
+        -12771,
+        "kCMSimpleQueueError_RequiredParameterMissing",
+        CORE_MEDIA,
+        "required parameter missing",
+        "NULL or 0 was passed for a required parameter.",
+    ),
+    info(
+        -12770,
+        "kCMSimpleQueueError_AllocationFailed",
+        CORE_MEDIA,
+        "allocation failed",
+        "An allocation failed.",
+    ),
+    info(
+        -12757,
+        "kCMTimebaseError_ReadOnly",
+        CORE_MEDIA,
+        "read only",
+        "",
+    ),
+    info(
+        -12756,
+        "kCMClockError_UnsupportedOperation",
+        CORE_MEDIA,
+        "unsupported operation",
+        "",
+    ),
+    info(
+        -12755,
+        "kCMSyncError_RateMustBeNonZero",
+        CORE_MEDIA,
+        "rate must be non zero",
+        "",
+    ),
+    info(
+        -12754,
+        "kCMSyncError_AllocationFailed",
+        CORE_MEDIA,
+        "allocation failed",
+        "",
+    ),
+    info(
+        -12753,
+        "kCMSyncError_InvalidParameter",
+        CORE_MEDIA,
+        "invalid parameter",
+        "",
+    ),
+    info(
+        -12752,
+        "kCMSyncError_MissingRequiredParameter",
+        CORE_MEDIA,
+        "missing required parameter",
+        "",
+    ),
+    info(
+        -12751,
+        "kCMTimebaseError_TimerIntervalTooShort",
+        CORE_MEDIA,
+        "timer interval too short",
+        "",
+    ),
+    info(
+        -12750,
+        "kCMTimebaseError_AllocationFailed",
+        CORE_MEDIA,
+        "allocation failed",
+        "",
+    ),
+    info(
+        -12749,
+        "kCMTimebaseError_InvalidParameter",
+        CORE_MEDIA,
+        "invalid parameter",
+        "",
+    ),
+    info(
+        -12748,
+        "kCMTimebaseError_MissingRequiredParameter",
+        CORE_MEDIA,
+        "missing required parameter",
+        "",
+    ),
+    info(
+        -12747,
+        "kCMClockError_AllocationFailed",
+        CORE_MEDIA,
+        "allocation failed",
+        "",
+    ),
+    info(
+        -12746,
+        "kCMClockError_InvalidParameter",
+        CORE_MEDIA,
+        "invalid parameter",
+        "",
+    ),
+    info(
+        -12745,
+        "kCMClockError_MissingRequiredParameter",
+        CORE_MEDIA,
+        "missing required parameter",
+        "",
+    ),
+    info(
+        -12744,
+        "kCMSampleBufferError_Invalidated",
+        CORE_MEDIA,
+        "invalidated",
+        "the sample buffer was invalidated.",
+    ),
+    info(
+        -12743,
+        "kCMSampleBufferError_InvalidMediaFormat",
+        CORE_MEDIA,
+        "invalid media format",
+        "The format of the given media does not match the given format description (eg. a format description paired with a cv::ImageBuffer that fails cm::VideoFormatDescriptionMatchesImageBuffer).",
+    ),
+    info(
+        -12742,
+        "kCMSampleBufferError_InvalidSampleData",
+        CORE_MEDIA,
+        "invalid sample data",
+        "Buffer contains bad data. Only returned by cm::SampleBuffer functions that inspect its sample data.",
+    ),
+    info(
+        -12741,
+        "kCMSampleBufferError_InvalidMediaTypeForOperation",
+        CORE_MEDIA,
+        "invalid media type for operation",
+        "the media type specified by a format description is not valid for the given operation (eg. a cm::SampleBuffer with a non-audio format description passed to cm::SampleBufferGetAudioStreamPacketDescriptionsPtr).",
+    ),
+    info(
+        -12740,
+        "kCMSampleBufferError_SampleTimingInfoInvalid",
+        CORE_MEDIA,
+        "sample timing info invalid",
+        "buffer unexpectedly contains a non-numeric sample timing info",
+    ),
+    info(
+        -12739,
+        "kCMSampleBufferError_CannotSubdivide",
+        CORE_MEDIA,
+        "cannot subdivide",
+        "Sample buffer does not contain sample sizes.  This can happen when the samples in the buffer are non-contiguous (eg. non-interleaved audio, where the channel values for a single sample are scattered through the buffer).",
+    ),
+    info(
+        -12738,
+        "kCMSampleBufferError_InvalidEntryCount",
+        CORE_MEDIA,
+        "invalid entry count",
+        "Timing info or size array entry count was not 0, 1, or numSamples.",
+    ),
+    info(
+        -12737,
+        "kCMSampleBufferError_ArrayTooSmall",
+        CORE_MEDIA,
+        "array too small",
+        "Output array was not large enough for the array being requested.",
+    ),
+    info(
+        -12736,
+        "kCMSampleBufferError_BufferHasNoSampleTimingInfo",
+        CORE_MEDIA,
+        "buffer has no sample timing info",
+        "Attempt to get sample timing information when there was none.",
+    ),
+    info(
+        -12735,
+        "kCMSampleBufferError_BufferHasNoSampleSizes",
+        CORE_MEDIA,
+        "buffer has no sample sizes",
+        "Attempt to get sample size information when there was none.",
+    ),
+    info(
+        -12734,
+        "kCMSampleBufferError_SampleIndexOutOfRange",
+        CORE_MEDIA,
+        "sample index out of range",
+        "Sample index was not between 0 and numSamples-1, inclusive.",
+    ),
+    info(
+        -12733,
+        "kCMSampleBufferError_BufferNotReady",
+        CORE_MEDIA,
+        "buffer not ready",
+        "Buffer could not be made ready.",
+    ),
+    info(
+        -12732,
+        "kCMSampleBufferError_AlreadyHasDataBuffer",
+        CORE_MEDIA,
+        "already has data buffer",
+        "Attempt was made to set a dataBuffer on a cm::SampleBuffer that already has one.",
+    ),
+    info(
+        -12731,
+        "kCMSampleBufferError_RequiredParameterMissing",
+        CORE_MEDIA,
+        "required parameter missing",
+        "NULL or 0 was passed for a required parameter.",
+    ),
+    info(
+        -12730,
+        "kCMSampleBufferError_AllocationFailed",
+        CORE_MEDIA,
+        "allocation failed",
+        "An allocation failed.",
+    ),
+    info(
+        -12719,
+        "kCMFormatDescriptionBridgeError_InvalidSlice",
+        CORE_MEDIA,
+        "invalid slice",
+        "Returned when the slice has an invalid value.",
+    ),
+    info(
+        -12717,
+        "kCMFormatDescriptionBridgeError_UnsupportedSampleDescriptionFlavor",
+        CORE_MEDIA,
+        "unsupported sample description flavor",
+        "Returned when the sample description is unsupported for the specified format flavor.",
+    ),
+    info(
+        -12716,
+        "kCMFormatDescriptionBridgeError_IncompatibleFormatDescription",
+        CORE_MEDIA,
+        "incompatible format description",
+        "Returned when the format description has an incompatible format (e.g. unknown format / incompatible atom).",
+    ),
+    info(
+        -12715,
+        "kCMFormatDescriptionBridgeError_InvalidFormatDescription",
+        CORE_MEDIA,
+        "invalid format description",
+        "Returned when the format description is invalid (e.g. invalid size).",
+    ),
+    info(
+        -12714,
+        "kCMFormatDescriptionBridgeError_InvalidSerializedSampleDescription",
+        CORE_MEDIA,
+        "invalid serialized sample description",
+        "Returned when the sample description is invalid (e.g. invalid size).",
+    ),
+    info(
+        -12713,
+        "kCMFormatDescriptionBridgeError_AllocationFailed",
+        CORE_MEDIA,
+        "allocation failed",
+        "Returned when an allocation fails.",
+    ),
+    info(
+        -12712,
+        "kCMFormatDescriptionBridgeError_InvalidParameter",
+        CORE_MEDIA,
+        "invalid parameter",
+        "Invalid parameter.",
+    ),
+    info(
+        -12708,
+        "kCMBlockBufferInsufficientSpaceErr",
+        CORE_MEDIA,
+        "insufficient space",
+        "",
+    ),
+    info(
+        -12707,
+        "kCMBlockBufferUnallocatedBlockErr",
+        CORE_MEDIA,
+        "unallocated block",
+        "An unallocated memory block was encountered.",
+    ),
+    info(
+        -12706,
+        "kCMBlockBufferEmptyBBufErr",
+        CORE_MEDIA,
+        "empty block buffer",
+        "Expected a non-empty cm::BlockBuffer.",
+    ),
+    info(
+        -12705,
+        "kCMBlockBufferBadPointerParameterErr",
+        CORE_MEDIA,
+        "bad pointer parameter",
+        "A pointer parameter (e.g. cm::BlockBuffer reference, destination memory) is NULL or otherwise invalid.",
+    ),
+    info(
+        -12704,
+        "kCMBlockBufferBadLengthParameterErr",
+        CORE_MEDIA,
+        "bad length parameter",
+        "The length provided to an API is out of the range of the relevent cm::BlockBuffer, or is not allowed to be zero.",
+    ),
+    info(
+        -12703,
+        "kCMBlockBufferBadOffsetParameterErr",
+        CORE_MEDIA,
+        "bad offset parameter",
+        "The offset provided to an API is out of the range of the relevent cm::BlockBuffer",
+    ),
+    info(
+        -12702,
+        "kCMBlockBufferBadCustomBlockSourceErr",
+        CORE_MEDIA,
+        "bad custom block source",
+        "The custom block source’s Allocate() routine was NULL when an allocation was attempted.",
+    ),
+    info(
+        -12701,
+        "kCMBlockBufferBlockAllocationFailedErr",
+        CORE_MEDIA,
+        "block allocation failed",
+        "Returned when the allocator provided to allocate a memory block (as distinct from cm::BlockBuffer structures) fails.",
+    ),
+    info(
+        -12700,
+        "kCMBlockBufferStructureAllocationFailedErr",
+        CORE_MEDIA,
+        "structure allocation failed",
+        "Returned when a cm::BlockBuffer-creating API gets a failure from the cf::Allocator provided for cm::BlockBuffer construction.",
+    ),
+    info(
+        -12219,
+        "kVTColorCorrectionImageRotationFailedErr",
+        VIDEO_TOOLBOX,
+        "color correction image rotation failed",
+        "",
+    ),
+    info(
+        -12218,
+        "kVTPixelTransferNotPermittedErr",
+        VIDEO_TOOLBOX,
+        "pixel transfer not permitted",
+        "",
+    ),
+    info(
+        -12217,
+        "kVTCouldNotFindTemporalFilterErr",
+        VIDEO_TOOLBOX,
+        "could not find temporal filter",
+        "",
+    ),
+    info(
+        -12216,
+        "kVTFrameSiloInvalidTimeRangeErr",
+        VIDEO_TOOLBOX,
+        "frame silo invalid time range",
+        "",
+    ),
+    info(
+        -12215,
+        "kVTFrameSiloInvalidTimeStampErr",
+        VIDEO_TOOLBOX,
+        "frame silo invalid time stamp",
+        "",
+    ),
+    info(
+        -12214,
+        "kVTMultiPassStorageInvalidErr",
+        VIDEO_TOOLBOX,
+        "multi pass storage invalid",
+        "",
+    ),
+    info(
+        -12213,
+        "kVTMultiPassStorageIdentifierMismatchErr",
+        VIDEO_TOOLBOX,
+        "multi pass storage identifier mismatch",
+        "",
+    ),
+    info(
+        -12212,
+        "kVTColorCorrectionPixelTransferFailedErr",
+        VIDEO_TOOLBOX,
+        "color correction pixel transfer failed",
+        "",
+    ),
+    info(
+        -12211,
+        "kVTVideoEncoderAuthorizationErr",
+        VIDEO_TOOLBOX,
+        "video encoder authorization",
+        "",
+    ),
+    info(
+        -12210,
+        "kVTVideoDecoderAuthorizationErr",
+        VIDEO_TOOLBOX,
+        "video decoder authorization",
+        "",
+    ),
+    info(
+        -10879,
+        "kAudioUnitErr_InvalidProperty",
+        AUDIO_UNIT,
+        "invalid property",
+        "The property is not supported",
+    ),
+    info(
+        -10878,
+        "kAudioUnitErr_InvalidParameter",
+        AUDIO_UNIT,
+        "invalid parameter",
+        "The parameter is not supported",
+    ),
+    info(
+        -10877,
+        "kAudioUnitErr_InvalidElement",
+        AUDIO_UNIT,
+        "invalid element",
+        "The specified element is not valid",
+    ),
+    info(
+        -10876,
+        "kAudioUnitErr_NoConnection",
+        AUDIO_UNIT,
+        "no connection",
+        "There is no connection (generally an audio unit is asked to render but it has not input from which to gather data)",
+    ),
+    info(
+        -10875,
+        "kAudioUnitErr_FailedInitialization",
+        AUDIO_UNIT,
+        "failed initialization",
+        "The audio unit is unable to be initialized",
+    ),
+    info(
+        -10874,
+        "kAudioUnitErr_TooManyFramesToProcess",
+        AUDIO_UNIT,
+        "too many frames to process",
+        "When an audio unit is initialized it has a value which specifies the max number of frames it will be asked to render at any given time. If an audio unit is asked to render more than this, this error is returned.",
+    ),
+    info(
+        -10871,
+        "kAudioUnitErr_InvalidFile",
+        AUDIO_UNIT,
+        "invalid file",
+        "If an audio unit uses external files as a data source, this error is returned if a file is invalid (Apple's DLS synth returns this error)",
+    ),
+    info(
+        -10870,
+        "kAudioUnitErr_UnknownFileType",
+        AUDIO_UNIT,
+        "unknown file type",
+        "If an audio unit uses external files as a data source, this error is returned if a file is invalid (Apple's DLS synth returns this error)",
+    ),
+    info(
+        -10869,
+        "kAudioUnitErr_FileNotSpecified",
+        AUDIO_UNIT,
+        "file not specified",
+        "If an audio unit uses external files as a data source, this error is returned if a file hasn't been set on it (Apple's DLS synth returns this error)",
+    ),
+    info(
+        -10868,
+        "kAudioUnitErr_FormatNotSupported",
+        AUDIO_UNIT,
+        "format not supported",
+        "Returned if an input or output format is not supported",
+    ),
+    info(
+        -10867,
+        "kAudioUnitErr_Uninitialized",
+        AUDIO_UNIT,
+        "uninitialized",
+        "Returned if an operation requires an audio unit to be initialized and it is not.",
+    ),
+    info(
+        -10866,
+        "kAudioUnitErr_InvalidScope",
+        AUDIO_UNIT,
+        "invalid scope",
+        "The specified scope is invalid",
+    ),
+    info(
+        -10865,
+        "kAudioUnitErr_PropertyNotWritable",
+        AUDIO_UNIT,
+        "property not writable",
+        "The property cannot be written",
+    ),
+    info(
+        -10863,
+        "kAudioUnitErr_CannotDoInCurrentContext",
+        AUDIO_UNIT,
+        "cannot do in current context",
+        "Returned when an audio unit is in a state where it can't perform the requested action now - but it could later. Its usually used to guard a render operation when a reconfiguration of its internal state is being performed.",
+    ),
+    info(
+        -10851,
+        "kAudioUnitErr_InvalidPropertyValue",
+        AUDIO_UNIT,
+        "invalid property value",
+        "The property is valid, but the value of the property being provided is not",
+    ),
+    info(
+        -10850,
+        "kAudioUnitErr_PropertyNotInUse",
+        AUDIO_UNIT,
+        "property not in use",
+        "Returned when a property is valid, but it hasn't been set to a valid value at this time.",
+    ),
+    info(
+        -10849,
+        "kAudioUnitErr_Initialized",
+        AUDIO_UNIT,
+        "initialized",
+        "Indicates the operation cannot be performed because the audio unit is initialized.",
+    ),
+    info(
+        -10848,
+        "kAudioUnitErr_InvalidOfflineRender",
+        AUDIO_UNIT,
+        "invalid offline render",
+        "Used to indicate that the offline render operation is invalid. For instance, when the audio unit needs to be pre-flighted, but it hasn't been.",
+    ),
+    info(
+        -10847,
+        "kAudioUnitErr_Unauthorized",
+        AUDIO_UNIT,
+        "unauthorized",
+        "Returned by either Open or Initialize, this error is used to indicate that the audio unit is not authorised, that it cannot be used. A host can then present a UI to notify the user the audio unit is not able to be used in its current state.",
+    ),
+    info(
+        -9890,
+        "errSSLEarlyDataRejected",
+        SECURITY,
+        "SSL early data rejected",
+        "Early application data rejected by peer",
+    ),
+    info(
+        -9886,
+        "errSSLATSCertificateTrustViolation",
+        SECURITY,
+        "SSL ATS certificate trust violation",
+        "ATS violation: peer certificate is not issued by trusted peer",
+    ),
+    info(
+        -9885,
+        "errSSLATSCertificateHashAlgorithmViolation",
+        SECURITY,
+        "SSL ATS certificate hash algorithm violation",
+        "ATS violation: peer certificate hash algorithm is not ATS compliant",
+    ),
+    info(
+        -9884,
+        "errSSLATSLeafCertificateHashAlgorithmViolation",
+        SECURITY,
+        "SSL ATS leaf certificate hash algorithm violation",
+        "ATS violation: peer leaf certificate hash algorithm is not ATS compliant",
+    ),
+    info(
+        -9883,
+        "errSSLATSMinimumKeySizeViolation",
+        SECURITY,
+        "SSL ATS minimum key size violation",
+        "ATS violation: peer key size is not ATS compliant",
+    ),
+    info(
+        -9882,
+        "errSSLATSCiphersuiteViolation",
+        SECURITY,
+        "SSL ATS ciphersuite violation",
+        "ATS violation: selected ciphersuite is not ATS compliant",
+    ),
+    info(
+        -9881,
+        "errSSLATSMinimumVersionViolation",
+        SECURITY,
+        "SSL ATS minimum version violation",
+        "ATS violation: minimum protocol version is not ATS compliant",
+    ),
+    info(
+        -9880,
+        "errSSLATSViolation",
+        SECURITY,
+        "SSL ATS violation",
+        "ATS violation",
+    ),
+    info(
+        -9865,
+        "errSSLUnrecognizedName",
+        SECURITY,
+        "SSL unrecognized name",
+        "Unknown or unrecognized name",
+    ),
+    info(
+        -9864,
+        "errSSLUnknownPSKIdentity",
+        SECURITY,
+        "SSL unknown PSK identity",
+        "Unknown PSK identity",
+    ),
+    info(
+        -9863,
+        "errSSLCertificateRequired",
+        SECURITY,
+        "SSL certificate required",
+        "Certificate required",
+    ),
+    info(
+        -9862,
+        "errSSLBadCertificateStatusResponse",
+        SECURITY,
+        "SSL bad certificate status response",
+        "Bad OCSP response",
+    ),
+    info(
+        -9861,
+        "errSSLMissingExtension",
+        SECURITY,
+        "SSL missing extension",
+        "Missing extension",
+    ),
+    info(
+        -9860,
+        "errSSLInappropriateFallback",
+        SECURITY,
+        "SSL inappropriate fallback",
+        "Inappropriate fallback",
+    ),
+    info(
+        -9859,
+        "errSSLDecodeError",
+        SECURITY,
+        "SSL decode",
+        "Decode failed",
+    ),
+    info(
+        -9858,
+        "errSSLHandshakeFail",
+        SECURITY,
+        "SSL handshake fail",
+        "Handshake failed",
+    ),
+    info(
+        -9857,
+        "errSSLDecompressFail",
+        SECURITY,
+        "SSL decompress fail",
+        "Decompression failed",
+    ),
+    info(
+        -9856,
+        "errSSLUnexpectedMessage",
+        SECURITY,
+        "SSL unexpected message",
+        "Peer rejected unexpected message",
+    ),
+    info(
+        -9855,
+        "errSSLUnsupportedExtension",
+        SECURITY,
+        "SSL unsupported extension",
+        "Unsupported TLS extension",
+    ),
+    info(
+        -9854,
+        "errSSLConfigurationFailed",
+        SECURITY,
+        "SSL configuration failed",
+        "TLS configuration failed",
+    ),
+    info(
+        -9853,
+        "errSSLNetworkTimeout",
+        SECURITY,
+        "SSL network timeout",
+        "Network timeout triggered",
+    ),
+    info(
+        -9852,
+        "errSSLTransportReset",
+        SECURITY,
+        "SSL transport reset",
+        "Transport (socket) shutdown, e.g., TCP RST or FIN.",
+    ),
+    info(
+        -9851,
+        "errSSLClientHelloReceived",
+        SECURITY,
+        "SSL client hello received",
+        "SNI",
+    ),
+    info(
+        -9850,
+        "errSSLWeakPeerEphemeralDHKey",
+        SECURITY,
+        "SSL weak peer ephemeral DH key",
+        "Weak ephemeral dh key",
+    ),
+    info(
+        -9849,
+        "errSSLUnexpectedRecord",
+        SECURITY,
+        "SSL unexpected record",
+        "Unexpected (skipped) record in DTLS",
+    ),
+    info(
+        -9848,
+        "errSSLBadConfiguration",
+        SECURITY,
+        "SSL bad configuration",
+        "Configuration error",
+    ),
+    info(
+        -9847,
+        "errSSLRecordOverflow",
+        SECURITY,
+        "SSL record overflow",
+        "Record overflow",
+    ),
+    info(
+        -9846,
+        "errSSLBadRecordMac",
+        SECURITY,
+        "SSL bad record mac",
+        "Bad MAC",
+    ),
+    info(
+        -9845,
+        "errSSLDecryptionFail",
+        SECURITY,
+        "SSL decryption fail",
+        "Decryption failure",
+    ),
+    info(
+        -9844,
+        "errSSLConnectionRefused",
+        SECURITY,
+        "SSL connection refused",
+        "Peer dropped connection before responding",
+    ),
+    info(
+        -9843,
+        "errSSLHostNameMismatch",
+        SECURITY,
+        "SSL host name mismatch",
+        "Peer host name mismatch",
+    ),
+    info(
+        -9842,
+        "errSSLClientCertRequested",
+        SECURITY,
+        "SSL client cert requested",
+        "Server has requested a client cert",
+    ),
+    info(
+        -9841,
+        "errSSLPeerAuthCompleted",
+        SECURITY,
+        "SSL peer auth completed",
+        "Peer cert is valid, or was ignored if verification disabled",
+    ),
+    info(
+        -9840,
+        "errSSLPeerNoRenegotiation",
+        SECURITY,
+        "SSL peer no renegotiation",
+        "No renegotiation allowed",
+    ),
+    info(
+        -9839,
+        "errSSLPeerUserCancelled",
+        SECURITY,
+        "SSL peer user cancelled",
+        "User canceled",
+    ),
+    info(
+        -9838,
+        "errSSLPeerInternalError",
+        SECURITY,
+        "SSL peer internal",
+        "Internal error",
+    ),
+    info(
+        -9837,
+        "errSSLPeerInsufficientSecurity",
+        SECURITY,
+        "SSL peer insufficient security",
+        "Insufficient security",
+    ),
+    info(
+        -9836,
+        "errSSLPeerProtocolVersion",
+        SECURITY,
+        "SSL peer protocol version",
+        "Bad protocol version",
+    ),
+    info(
+        -9835,
+        "errSSLPeerExportRestriction",
+        SECURITY,
+        "SSL peer export restriction",
+        "Export restriction",
+    ),
+    info(
+        -9834,
+        "errSSLPeerDecryptError",
+        SECURITY,
+        "SSL peer decrypt",
+        "Decryption error",
+    ),
+    info(
+        -9833,
+        "errSSLPeerDecodeError",
+        SECURITY,
+        "SSL peer decode",
+        "Decoding error",
+    ),
+    info(
+        -9832,
+        "errSSLPeerAccessDenied",
+        SECURITY,
+        "SSL peer access denied",
+        "Access denied",
+    ),
+    info(
+        -9831,
+        "errSSLPeerUnknownCA",
+        SECURITY,
+        "SSL peer unknown CA",
+        "Unknown Cert Authority",
+    ),
+    info(
+        -9830,
+        "errSSLIllegalParam",
+        SECURITY,
+        "SSL illegal param",
+        "Illegal parameter",
+    ),
+    info(
+        -9829,
+        "errSSLPeerCertUnknown",
+        SECURITY,
+        "SSL peer cert unknown",
+        "Unknown certificate",
+    ),
+    info(
+        -9828,
+        "errSSLPeerCertExpired",
+        SECURITY,
+        "SSL peer cert expired",
+        "Certificate expired",
+    ),
+    info(
+        -9827,
+        "errSSLPeerCertRevoked",
+        SECURITY,
+        "SSL peer cert revoked",
+        "Certificate revoked",
+    ),
+    info(
+        -9826,
+        "errSSLPeerUnsupportedCert",
+        SECURITY,
+        "SSL peer unsupported cert",
+        "Bad unsupported cert format",
+    ),
+    info(
+        -9825,
+        "errSSLPeerBadCert",
+        SECURITY,
+        "SSL peer bad cert",
+        "Misc. bad certificate",
+    ),
+    info(
+        -9824,
+        "errSSLPeerHandshakeFail",
+        SECURITY,
+        "SSL peer handshake fail",
+        "Handshake failure",
+    ),
+    info(
+        -9823,
+        "errSSLPeerDecompressFail",
+        SECURITY,
+        "SSL peer decompress fail",
+        "Decompression failure",
+    ),
+    info(
+        -9822,
+        "errSSLPeerRecordOverflow",
+        SECURITY,
+        "SSL peer record overflow",
+        "Record overflow",
+    ),
+    info(
+        -9821,
+        "errSSLPeerDecryptionFail",
+        SECURITY,
+        "SSL peer decryption fail",
+        "Decryption failed",
+    ),
+    info(
+        -9820,
+        "errSSLPeerBadRecordMac",
+        SECURITY,
+        "SSL peer bad record mac",
+        "Bad MAC",
+    ),
+    info(
+        -9819,
+        "errSSLPeerUnexpectedMsg",
+        SECURITY,
+        "SSL peer unexpected msg",
+        "Unexpected message received",
+    ),
+    info(
+        -9818,
+        "errSSLBadCipherSuite",
+        SECURITY,
+        "SSL bad cipher suite",
+        "bad SSLCipherSuite",
+    ),
+    info(
+        -9817,
+        "errSSLBufferOverflow",
+        SECURITY,
+        "SSL buffer overflow",
+        "Insufficient buffer provided",
+    ),
+    info(
+        -9816,
+        "errSSLClosedNoNotify",
+        SECURITY,
+        "SSL closed no notify",
+        "Server closed session with no notification",
+    ),
+    info(
+        -9815,
+        "errSSLCertNotYetValid",
+        SECURITY,
+        "SSL cert not yet valid",
+        "Chain had a cert not yet valid",
+    ),
+    info(
+        -9814,
+        "errSSLCertExpired",
+        SECURITY,
+        "SSL cert expired",
+        "Chain had an expired cert",
+    ),
+    info(
+        -9813,
+        "errSSLNoRootCert",
+        SECURITY,
+        "SSL no root cert",
+        "Cert chain not verified by root",
+    ),
+    info(
+        -9812,
+        "errSSLUnknownRootCert",
+        SECURITY,
+        "SSL unknown root cert",
+        "Valid cert chain, untrusted root",
+    ),
+    info(
+        -9811,
+        "errSSLModuleAttach",
+        SECURITY,
+        "SSL module attach",
+        "Module attach failure",
+    ),
+    info(
+        -9810,
+        "errSSLInternal",
+        SECURITY,
+        "SSL internal",
+        "Internal error",
+    ),
+    info(
+        -9809,
+        "errSSLCrypto",
+        SECURITY,
+        "SSL crypto",
+        "Underlying cryptographic error",
+    ),
+    info(
+        -9808,
+        "errSSLBadCert",
+        SECURITY,
+        "SSL bad cert",
+        "Bad certificate format",
+    ),
+    info(
+        -9807,
+        "errSSLXCertChainInvalid",
+        SECURITY,
+        "SSLX cert chain invalid",
+        "Invalid certificate chain",
+    ),
+    info(
+        -9806,
+        "errSSLClosedAbort",
+        SECURITY,
+        "SSL closed abort",
+        "Connection closed via error",
+    ),
+    info(
+        -9805,
+        "errSSLClosedGraceful",
+        SECURITY,
+        "SSL closed graceful",
+        "Connection closed gracefully",
+    ),
+    info(
+        -9804,
+        "errSSLSessionNotFound",
+        SECURITY,
+        "SSL session not found",
+        "Attempt to restore an unknown session",
+    ),
+    info(
+        -9803,
+        "errSSLWouldBlock",
+        SECURITY,
+        "SSL would block",
+        "I/O would block (not fatal)",
+    ),
+    info(
+        -9802,
+        "errSSLFatalAlert",
+        SECURITY,
+        "SSL fatal alert",
+        "Fatal alert",
+    ),
+    info(
+        -9801,
+        "errSSLNegotiation",
+        SECURITY,
+        "SSL negotiation",
+        "Cipher Suite negotiation failure",
+    ),
+    info(
+        -9800,
+        "errSSLProtocol",
+        SECURITY,
+        "SSL protocol",
+        "SSL protocol error",
+    ),
+    info(
+        -6692,
+        "kCVReturnRetry",
+        CORE_VIDEO,
+        "retry",
+        "A scan hasn't completely traversed the CVBufferPool due to a concurrent operation. The client can retry the scan.",
+    ),
+    info(
+        -6691,
+        "kCVReturnInvalidPoolAttributes",
+        CORE_VIDEO,
+        "invalid pool attributes",
+        "A cv::BufPool cannot be created with the given attributes.",
+    ),
+    info(
+        -6690,
+        "kCVReturnPoolAllocationFailed",
+        CORE_VIDEO,
+        "pool allocation failed",
+        "The allocation for the buffer pool failed. Most likely because of lack of resources. Check if your parameters are in range.",
+    ),
+    info(
+        -6689,
+        "kCVReturnWouldExceedAllocationThreshold",
+        CORE_VIDEO,
+        "would exceed allocation threshold",
+        "The allocation request failed because it would have exceeded a specified allocation threshold (see kCVPixelBufferPoolAllocationThresholdKey).",
+    ),
+    info(
+        -6684,
+        "kCVReturnPixelBufferNotMetalCompatible",
+        CORE_VIDEO,
+        "pixel buffer not metal compatible",
+        "The Buffer cannot be used with Metal as either its size, pixelformat or attributes are not supported by Metal.",
+    ),
+    info(
+        -6683,
+        "kCVReturnPixelBufferNotOpenGLCompatible",
+        CORE_VIDEO,
+        "pixel buffer not OpenGL compatible",
+        "The Buffer cannot be used with OpenGL as either its size, pixelformat or attributes are not supported by OpenGL.",
+    ),
+    info(
+        -6682,
+        "kCVReturnInvalidPixelBufferAttributes",
+        CORE_VIDEO,
+        "invalid pixel buffer attributes",
+        "A cv::Buffer cannot be created with the given attributes.",
+    ),
+    info(
+        -6681,
+        "kCVReturnInvalidSize",
+        CORE_VIDEO,
+        "invalid size",
+        "The requested size (most likely too big) is not supported for the cv::Buf type.",
+    ),
+    info(
+        -6680,
+        "kCVReturnInvalidPixelFormat",
+        CORE_VIDEO,
+        "invalid pixel format",
+        "The requested pixelformat is not supported for the cv::Buf type.",
+    ),
+    info(
+        -6673,
+        "kCVReturnDisplayLinkCallbacksNotSet",
+        CORE_VIDEO,
+        "display link callbacks not set",
+        "The output callback is not set.",
+    ),
+    info(
+        -6672,
+        "kCVReturnDisplayLinkNotRunning",
+        CORE_VIDEO,
+        "display link not running",
+        "The cv::DisplayLink has not been started.",
+    ),
+    info(
+        -6671,
+        "kCVReturnDisplayLinkAlreadyRunning",
+        CORE_VIDEO,
+        "display link already running",
+        "The cv::DisplayLink is already started and running.",
+    ),
+    info(
+        -6670,
+        "kCVReturnInvalidDisplay",
+        CORE_VIDEO,
+        "invalid display",
+        "A cv::DisplayLink cannot be created for the given DisplayRef.",
+    ),
+    info(-6663, "kCVReturnUnsupported", CORE_VIDEO, "unsupported", ""),
+    info(
+        -6662,
+        "kCVReturnAllocationFailed",
+        CORE_VIDEO,
+        "allocation failed",
+        "The allocation for a buffer or buffer pool failed. Most likely because of lack of resources.",
+    ),
+    info(
+        -6661,
+        "kCVReturnInvalidArgument",
+        CORE_VIDEO,
+        "invalid argument",
+        "At least one of the arguments passed in is not valid. Either out of range or the wrong type.",
+    ),
+    info(-6660, "kCVReturnError", CORE_VIDEO, "error", ""),
+    info(
+        -4960,
+        "errSecCoreFoundationUnknown",
+        SECURITY,
+        "core foundation unknown",
+        "",
+    ),
+    info(
+        -2070,
+        "errSecInternalComponent",
+        SECURITY,
+        "internal component",
+        "",
+    ),
+    info(
+        -909,
+        "errSecBadReq",
+        SECURITY,
+        "bad req",
+        "Bad parameter or invalid state for operation.",
+    ),
+    info(
+        -128,
+        "errSecUserCanceled",
+        SECURITY,
+        "user canceled",
+        "User canceled the operation.",
+    ),
+    info(
+        -108,
+        "kAudio_MemFullError",
+        CORE_AUDIO,
+        "mem full",
+        "Not enough room in heap zone.",
+    ),
+    info(
+        -108,
+        "errSecAllocate",
+        SECURITY,
+        "allocate",
+        "Failed to allocate memory.",
+    ),
+    info(
+        -61,
+        "errSecWrPerm",
+        SECURITY,
+        "wr perm",
+        "Write permissions error.",
+    ),
+    info(
+        -54,
+        "kAudio_FilePermissionError",
+        CORE_AUDIO,
+        "file permission",
+        "File cannot be opened due to either file, directory, or sandbox permissions.",
+    ),
+    info(
+        -50,
+        "kAudio_ParamError",
+        CORE_AUDIO,
+        "param",
+        "Error in user parameter list.",
+    ),
+    info(
+        -50,
+        "errSecParam",
+        SECURITY,
+        "param",
+        "One or more parameters passed to a function were not valid.",
+    ),
+    info(
+        -49,
+        "errSecOpWr",
+        SECURITY,
+        "op wr",
+        "File already open with write permission.",
+    ),
+    info(
+        -43,
+        "kAudio_FileNotFoundError",
+        CORE_AUDIO,
+        "file not found",
+        "File not found.",
+    ),
+    info(
+        -43,
+        "kAudioFileFileNotFoundError",
+        AUDIO_FILE,
+        "file not found",
+        "File not found.",
+    ),
+    info(
+        -42,
+        "kAudio_TooManyFilesOpenError",
+        CORE_AUDIO,
+        "too many files open",
+        "File cannot be opened because too many files are already open.",
+    ),
+    info(
+        -40,
+        "kAudioFilePositionError",
+        AUDIO_FILE,
+        "position",
+        "Invalid file position.",
+    ),
+    info(
+        -39,
+        "kAudioFileEndOfFileError",
+        AUDIO_FILE,
+        "end of file",
+        "End of file.",
+    ),
+    info(
+        -38,
+        "kAudioFileNotOpenError",
+        AUDIO_FILE,
+        "not open",
+        "The file is closed.",
+    ),
+    info(-36, "errSecIO", SECURITY, "IO", "I/O error."),
+    info(
+        -34,
+        "errSecDiskFull",
+        SECURITY,
+        "disk full",
+        "Disk Full error.",
+    ),
+    info(
+        -4,
+        "kAudio_UnimplementedError",
+        CORE_AUDIO,
+        "unimplemented",
+        "Unimplemented core routine.",
+    ),
+    info(
+        -4,
+        "errSecUnimplemented",
+        SECURITY,
+        "unimplemented",
+        "Function or operation not implemented.",
+    ),
+    info(
+        1,
+        "KERN_INVALID_ADDRESS",
+        MACH,
+        "invalid address",
+        "Specified address is not currently valid.",
+    ),
+    info(
+        2,
+        "KERN_PROTECTION_FAILURE",
+        MACH,
+        "protection failure",
+        "Specified memory is valid, but does not permit the required forms of access.",
+    ),
+    info(
+        3,
+        "KERN_NO_SPACE",
+        MACH,
+        "no space",
+        "The address range specified is already in use, or no address range of the size specified could be found.",
+    ),
+    info(
+        4,
+        "KERN_INVALID_ARGUMENT",
+        MACH,
+        "invalid argument",
+        "The function requested was not applicable to this type of argument, or an argument is invalid",
+    ),
+    info(
+        5,
+        "KERN_FAILURE",
+        MACH,
+        "failure",
+        "The function could not be performed.  A catch-all.",
+    ),
+    info(
+        6,
+        "KERN_RESOURCE_SHORTAGE",
+        MACH,
+        "resource shortage",
+        "A system resource could not be allocated to fulfill this request. This failure may not be permanent.",
+    ),
+    info(
+        7,
+        "KERN_NOT_RECEIVER",
+        MACH,
+        "not receiver",
+        "The task in question does not hold receive rights for the port argument.",
+    ),
+    info(
+        8,
+        "KERN_NO_ACCESS",
+        MACH,
+        "no access",
+        "Bogus access restriction.",
+    ),
+    info(
+        9,
+        "KERN_MEMORY_FAILURE",
+        MACH,
+        "memory failure",
+        "During a page fault, the target address refers to a memory object that has been destroyed.  This failure is permanent.",
+    ),
+    info(
+        10,
+        "KERN_MEMORY_ERROR",
+        MACH,
+        "memory error",
+        "During a page fault, the memory object indicated that the data could not be returned.  This failure may be temporary; future attempts to access this same data may succeed, as defined by the memory object.",
+    ),
+    info(
+        11,
+        "KERN_ALREADY_IN_SET",
+        MACH,
+        "already in set",
+        "The receive right is already a member of the portset.",
+    ),
+    info(
+        12,
+        "KERN_NOT_IN_SET",
+        MACH,
+        "not in set",
+        "The receive right is not a member of a port set.",
+    ),
+    info(
+        13,
+        "KERN_NAME_EXISTS",
+        MACH,
+        "name exists",
+        "The name already denotes a right in the task.",
+    ),
+    info(
+        14,
+        "KERN_ABORTED",
+        MACH,
+        "aborted",
+        "The operation was aborted.  Ipc code will catch this and reflect it as a message error.",
+    ),
+    info(
+        15,
+        "KERN_INVALID_NAME",
+        MACH,
+        "invalid name",
+        "The name doesn't denote a right in the task.",
+    ),
+    info(
+        16,
+        "KERN_INVALID_TASK",
+        MACH,
+        "invalid task",
+        "Target task isn't an active task.",
+    ),
+    info(
+        17,
+        "KERN_INVALID_RIGHT",
+        MACH,
+        "invalid right",
+        "The name denotes a right, but not an appropriate right.",
+    ),
+    info(
+        18,
+        "KERN_INVALID_VALUE",
+        MACH,
+        "invalid value",
+        "A blatant range error.",
+    ),
+    info(
+        19,
+        "KERN_UREFS_OVERFLOW",
+        MACH,
+        "urefs overflow",
+        "Operation would overflow limit on user-references.",
+    ),
+    info(
+        20,
+        "KERN_INVALID_CAPABILITY",
+        MACH,
+        "invalid capability",
+        "The supplied (port) capability is improper.",
+    ),
+    info(
+        21,
+        "KERN_RIGHT_EXISTS",
+        MACH,
+        "right exists",
+        "The task already has send or receive rights for the port under another name.",
+    ),
+    info(
+        22,
+        "KERN_INVALID_HOST",
+        MACH,
+        "invalid host",
+        "Target host isn't actually a host.",
+    ),
+    info(
+        23,
+        "KERN_MEMORY_PRESENT",
+        MACH,
+        "memory present",
+        "An attempt was made to supply \"precious\" data for memory that is already present in a memory object.",
+    ),
+    info(
+        24,
+        "KERN_MEMORY_DATA_MOVED",
+        MACH,
+        "memory data moved",
+        "A page was requested of a memory manager via memory_object_data_request for an object using a MEMORY_OBJECT_COPY_CALL strategy, with the VM_PROT_WANTS_COPY flag being used to specify that the page desired is for a copy of the object, and the memory manager has detected the page was pushed into a copy of the object while the kernel was walking the shadow chain from the copy to the object. This error code is delivered via memory_object_data_error and is handled by the kernel (it forces the kernel to restart the fault). It will not be seen by users.",
+    ),
+    info(
+        25,
+        "KERN_MEMORY_RESTART_COPY",
+        MACH,
+        "memory restart copy",
+        "A strategic copy was attempted of an object upon which a quicker copy is now possible. The caller should retry the copy using vm_object_copy_quickly. This error code is seen only by the kernel.",
+    ),
+    info(
+        26,
+        "KERN_INVALID_PROCESSOR_SET",
+        MACH,
+        "invalid processor set",
+        "An argument applied to assert processor set privilege was not a processor set control port.",
+    ),
+    info(
+        27,
+        "KERN_POLICY_LIMIT",
+        MACH,
+        "policy limit",
+        "The specified scheduling attributes exceed the thread's limits.",
+    ),
+    info(
+        28,
+        "KERN_INVALID_POLICY",
+        MACH,
+        "invalid policy",
+        "The specified scheduling policy is not currently enabled for the processor set.",
+    ),
+    info(
+        29,
+        "KERN_INVALID_OBJECT",
+        MACH,
+        "invalid object",
+        "The external memory manager failed to initialize the memory object.",
+    ),
+    info(
+        30,
+        "KERN_ALREADY_WAITING",
+        MACH,
+        "already waiting",
+        "A thread is attempting to wait for an event for which there is already a waiting thread.",
+    ),
+    info(
+        31,
+        "KERN_DEFAULT_SET",
+        MACH,
+        "default set",
+        "An attempt was made to destroy the default processor set.",
+    ),
+    info(
+        32,
+        "KERN_EXCEPTION_PROTECTED",
+        MACH,
+        "exception protected",
+        "An attempt was made to fetch an exception port that is protected, or to abort a thread while processing a protected exception.",
+    ),
+    info(
+        33,
+        "KERN_INVALID_LEDGER",
+        MACH,
+        "invalid ledger",
+        "A ledger was required but not supplied.",
+    ),
+    info(
+        34,
+        "KERN_INVALID_MEMORY_CONTROL",
+        MACH,
+        "invalid memory control",
+        "The port was not a memory cache control port.",
+    ),
+    info(
+        35,
+        "KERN_INVALID_SECURITY",
+        MACH,
+        "invalid security",
+        "An argument supplied to assert security privilege was not a host security port.",
+    ),
+    info(
+        36,
+        "KERN_NOT_DEPRESSED",
+        MACH,
+        "not depressed",
+        "thread_depress_abort was called on a thread which was not currently depressed.",
+    ),
+    info(
+        37,
+        "KERN_TERMINATED",
+        MACH,
+        "terminated",
+        "Object has been terminated and is no longer available",
+    ),
+    info(
+        38,
+        "KERN_LOCK_SET_DESTROYED",
+        MACH,
+        "lock set destroyed",
+        "Lock set has been destroyed and is no longer available.",
+    ),
+    info(
+        39,
+        "KERN_LOCK_UNSTABLE",
+        MACH,
+        "lock unstable",
+        "The thread holding the lock terminated before releasing the lock",
+    ),
+    info(
+        40,
+        "KERN_LOCK_OWNED",
+        MACH,
+        "lock owned",
+        "The lock is already owned by another thread",
+    ),
+    info(
+        41,
+        "KERN_LOCK_OWNED_SELF",
+        MACH,
+        "lock owned self",
+        "The lock is already owned by the calling thread",
+    ),
+    info(
+        42,
+        "KERN_SEMAPHORE_DESTROYED",
+        MACH,
+        "semaphore destroyed",
+        "Semaphore has been destroyed and is no longer available.",
+    ),
+    info(
+        43,
+        "KERN_RPC_SERVER_TERMINATED",
+        MACH,
+        "rpc server terminated",
+        "Return from RPC indicating the target server was terminated before it successfully replied",
+    ),
+    info(
+        44,
+        "KERN_RPC_TERMINATE_ORPHAN",
+        MACH,
+        "rpc terminate orphan",
+        "Terminate an orphaned activation.",
+    ),
+    info(
+        45,
+        "KERN_RPC_CONTINUE_ORPHAN",
+        MACH,
+        "rpc continue orphan",
+        "Allow an orphaned activation to continue executing.",
+    ),
+    info(
+        46,
+        "KERN_NOT_SUPPORTED",
+        MACH,
+        "not supported",
+        "Empty thread activation (No thread linked to it)",
+    ),
+    info(
+        47,
+        "KERN_NODE_DOWN",
+        MACH,
+        "node down",
+        "Remote node down or inaccessible.",
+    ),
+    info(
+        48,
+        "KERN_NOT_WAITING",
+        MACH,
+        "not waiting",
+        "A signalled thread was not actually waiting.",
+    ),
+    info(
+        49,
+        "KERN_OPERATION_TIMED_OUT",
+        MACH,
+        "operation timed out",
+        "Some thread-oriented operation (semaphore_wait) timed out",
+    ),
+    info(
+        50,
+        "KERN_CODESIGN_ERROR",
+        MACH,
+        "codesign error",
+        "During a page fault, indicates that the page was rejected as a result of a signature check.",
+    ),
+    info(
+        51,
+        "KERN_POLICY_STATIC",
+        MACH,
+        "policy static",
+        "The requested property cannot be changed at this time.",
+    ),
+    info(
+        52,
+        "KERN_INSUFFICIENT_BUFFER_SIZE",
+        MACH,
+        "insufficient buffer size",
+        "The provided buffer is of insufficient size for the requested data.",
+    ),
+    info(
+        53,
+        "KERN_DENIED",
+        MACH,
+        "denied",
+        "Denied by security policy",
+    ),
+    info(
+        54,
+        "KERN_MISSING_KC",
+        MACH,
+        "missing kc",
+        "The KC on which the function is operating is missing",
+    ),
+    info(
+        55,
+        "KERN_INVALID_KC",
+        MACH,
+        "invalid kc",
+        "The KC on which the function is operating is invalid",
+    ),
+    info(
+        56,
+        "KERN_NOT_FOUND",
+        MACH,
+        "not found",
+        "A search or query operation did not return a result",
+    ),
+    info(
+        256,
+        "KERN_RETURN_MAX",
+        MACH,
+        "return max",
+        "Maximum return value allowable",
+    ),
+    info(
+        1024,
+        "MACH_MSG_VM_KERNEL",
+        MACH,
+        "msg vm kernel",
+        "Kernel resource shortage handling out-of-line memory.",
+    ),
+    info(
+        2048,
+        "MACH_MSG_IPC_KERNEL",
+        MACH,
+        "msg ipc kernel",
+        "Kernel resource shortage handling an IPC capability.",
+    ),
+    info(
+        4096,
+        "MACH_MSG_VM_SPACE",
+        MACH,
+        "msg vm space",
+        "No room in VM address space for out-of-line memory.",
+    ),
+    info(
+        8192,
+        "MACH_MSG_IPC_SPACE",
+        MACH,
+        "msg ipc space",
+        "No room in IPC name space for another capability name.",
+    ),
+    info(
+        268435457,
+        "MACH_SEND_IN_PROGRESS",
+        MACH,
+        "send in progress",
+        "Thread is waiting to send.  (Internal use only.)",
+    ),
+    info(
+        268435458,
+        "MACH_SEND_INVALID_DATA",
+        MACH,
+        "send invalid data",
+        "Bogus in-line data.",
+    ),
+    info(
+        268435459,
+        "MACH_SEND_INVALID_DEST",
+        MACH,
+        "send invalid dest",
+        "Bogus destination port.",
+    ),
+    info(
+        268435460,
+        "MACH_SEND_TIMED_OUT",
+        MACH,
+        "send timed out",
+        "Message not sent before timeout expired.",
+    ),
+    info(
+        268435461,
+        "MACH_SEND_INVALID_VOUCHER",
+        MACH,
+        "send invalid voucher",
+        "Bogus voucher port.",
+    ),
+    info(
+        268435463,
+        "MACH_SEND_INTERRUPTED",
+        MACH,
+        "send interrupted",
+        "Software interrupt.",
+    ),
+    info(
+        268435464,
+        "MACH_SEND_MSG_TOO_SMALL",
+        MACH,
+        "send msg too small",
+        "Data doesn't contain a complete message.",
+    ),
+    info(
+        268435465,
+        "MACH_SEND_INVALID_REPLY",
+        MACH,
+        "send invalid reply",
+        "Bogus reply port.",
+    ),
+    info(
+        268435466,
+        "MACH_SEND_INVALID_RIGHT",
+        MACH,
+        "send invalid right",
+        "Bogus port rights in the message body.",
+    ),
+    info(
+        268435467,
+        "MACH_SEND_INVALID_NOTIFY",
+        MACH,
+        "send invalid notify",
+        "Bogus notify port argument.",
+    ),
+    info(
+        268435468,
+        "MACH_SEND_INVALID_MEMORY",
+        MACH,
+        "send invalid memory",
+        "Invalid out-of-line memory pointer.",
+    ),
+    info(
+        268435469,
+        "MACH_SEND_NO_BUFFER",
+        MACH,
+        "send no buffer",
+        "No message buffer is available.",
+    ),
+    info(
+        268435470,
+        "MACH_SEND_TOO_LARGE",
+        MACH,
+        "send too large",
+        "Send is too large for port",
+    ),
+    info(
+        268435471,
+        "MACH_SEND_INVALID_TYPE",
+        MACH,
+        "send invalid type",
+        "Invalid msg-type specification.",
+    ),
+    info(
+        268435472,
+        "MACH_SEND_INVALID_HEADER",
+        MACH,
+        "send invalid header",
+        "A field in the header had a bad value.",
+    ),
+    info(
+        268435473,
+        "MACH_SEND_INVALID_TRAILER",
+        MACH,
+        "send invalid trailer",
+        "The trailer to be sent does not match kernel format.",
+    ),
+    info(
+        268435474,
+        "MACH_SEND_INVALID_CONTEXT",
+        MACH,
+        "send invalid context",
+        "The sending thread context did not match the context on the dest port",
+    ),
+    info(
+        268435475,
+        "MACH_SEND_INVALID_OPTIONS",
+        MACH,
+        "send invalid options",
+        "Send options are invalid.",
+    ),
+    info(
+        268435477,
+        "MACH_SEND_INVALID_RT_OOL_SIZE",
+        MACH,
+        "send invalid rt ool size",
+        "Compatibility: no longer a returned error",
+    ),
+    info(
+        268435478,
+        "MACH_SEND_NO_GRANT_DEST",
+        MACH,
+        "send no grant dest",
+        "The destination port doesn't accept ports in body",
+    ),
+    info(
+        268435479,
+        "MACH_SEND_MSG_FILTERED",
+        MACH,
+        "send msg filtered",
+        "Message send was rejected by message filter",
+    ),
+    info(
+        268435480,
+        "MACH_SEND_AUX_TOO_SMALL",
+        MACH,
+        "send aux too small",
+        "Message auxiliary data is too small",
+    ),
+    info(
+        268435481,
+        "MACH_SEND_AUX_TOO_LARGE",
+        MACH,
+        "send aux too large",
+        "Message auxiliary data is too large",
+    ),
+    info(
+        268451841,
+        "MACH_RCV_IN_PROGRESS",
+        MACH,
+        "rcv in progress",
+        "Thread is waiting for receive. (Internal use only.)",
+    ),
+    info(
+        268451842,
+        "MACH_RCV_INVALID_NAME",
+        MACH,
+        "rcv invalid name",
+        "Bogus name for receive port/port-set.",
+    ),
+    info(
+        268451843,
+        "MACH_RCV_TIMED_OUT",
+        MACH,
+        "rcv timed out",
+        "Didn't get a message within the timeout value.",
+    ),
+    info(
+        268451844,
+        "MACH_RCV_TOO_LARGE",
+        MACH,
+        "rcv too large",
+        "Message buffer is not large enough for inline data.",
+    ),
+    info(
+        268451845,
+        "MACH_RCV_INTERRUPTED",
+        MACH,
+        "rcv interrupted",
+        "Software interrupt.",
+    ),
+    info(
+        268451846,
+        "MACH_RCV_PORT_CHANGED",
+        MACH,
+        "rcv port changed",
+        "compatibility: no longer a returned error",
+    ),
+    info(
+        268451847,
+        "MACH_RCV_INVALID_NOTIFY",
+        MACH,
+        "rcv invalid notify",
+        "Bogus notify port argument.",
+    ),
+    info(
+        268451848,
+        "MACH_RCV_INVALID_DATA",
+        MACH,
+        "rcv invalid data",
+        "Bogus message buffer for inline data.",
+    ),
+    info(
+        268451849,
+        "MACH_RCV_PORT_DIED",
+        MACH,
+        "rcv port died",
+        "Port/set was sent away/died during receive.",
+    ),
+    info(
+        268451850,
+        "MACH_RCV_IN_SET",
+        MACH,
+        "rcv in set",
+        "compatibility: no longer a returned error",
+    ),
+    info(
+        268451851,
+        "MACH_RCV_HEADER_ERROR",
+        MACH,
+        "rcv header error",
+        "Error receiving message header.  See special bits.",
+    ),
+    info(
+        268451852,
+        "MACH_RCV_BODY_ERROR",
+        MACH,
+        "rcv body error",
+        "Error receiving message body.  See special bits.",
+    ),
+    info(
+        268451853,
+        "MACH_RCV_INVALID_TYPE",
+        MACH,
+        "rcv invalid type",
+        "Invalid msg-type specification in scatter list.",
+    ),
+    info(
+        268451854,
+        "MACH_RCV_SCATTER_SMALL",
+        MACH,
+        "rcv scatter small",
+        "Out-of-line overwrite region is not large enough",
+    ),
+    info(
+        268451855,
+        "MACH_RCV_INVALID_TRAILER",
+        MACH,
+        "rcv invalid trailer",
+        "trailer type or number of trailer elements not supported",
+    ),
+    info(
+        268451857,
+        "MACH_RCV_IN_PROGRESS_TIMED",
+        MACH,
+        "rcv in progress timed",
+        "Waiting for receive with timeout. (Internal use only.)",
+    ),
+    info(
+        268451858,
+        "MACH_RCV_INVALID_REPLY",
+        MACH,
+        "rcv invalid reply",
+        "invalid reply port used in a STRICT_REPLY message",
+    ),
+    info(
+        268451859,
+        "MACH_RCV_INVALID_ARGUMENTS",
+        MACH,
+        "rcv invalid arguments",
+        "invalid receive arguments, receive has not started",
+    ),
+    info(
+        560226676,
+        "kCMIODeviceUnsupportedFormatError",
+        CORE_MEDIA_IO,
+        "device unsupported format",
+        "The 'cm::io::Stream' doesn't support the requested format.",
+    ),
+    info(
+        560227702,
+        "kCMIOHardwareBadDeviceError",
+        CORE_MEDIA_IO,
+        "bad device",
+        "The 'cm::io::ObjectId' passed to the function doesn't map to a valid cm::io::Device.",
+    ),
+    info(
+        560492391,
+        "kCMIODevicePermissionsError",
+        CORE_MEDIA_IO,
+        "device permissions",
+        "The requested operation can't be completed because the process doesn't have permission.",
+    ),
+    info(
+        560558962,
+        "kAudioConverterErr_InputSampleRateOutOfRange",
+        AUDIO_CONVERTER,
+        "input sample rate out of range",
+        "",
+    ),
+    info(
+        560947818,
+        "kCMIOHardwareBadObjectError",
+        CORE_MEDIA_IO,
+        "bad object",
+        "The 'cm::io::ObjectId' passed to the function doesn't map to a valid 'cm::io::Object'.",
+    ),
+    info(
+        560952178,
+        "kAudioConverterErr_OutputSampleRateOutOfRange",
+        AUDIO_CONVERTER,
+        "output sample rate out of range",
+        "",
+    ),
+    info(
+        561015652,
+        "kAudioConverterErr_RequiresPacketDescriptionsError",
+        AUDIO_CONVERTER,
+        "requires packet descriptions",
+        "",
+    ),
+    info(
+        561017453,
+        "kAudioFilePermissionsError",
+        AUDIO_FILE,
+        "permissions",
+        "The operation violated the file permissions. For example, an attempt was made to write to a file opened with the kAudioFileReadPermission constant.",
+    ),
+    info(
+        561017960,
+        "kAudio_BadFilePathError",
+        CORE_AUDIO,
+        "bad file path",
+        "File cannot be opened because the specified path is malformed.",
+    ),
+    info(
+        561211770,
+        "kAudioFileBadPropertySizeError",
+        AUDIO_FILE,
+        "bad property size",
+        "0x2173697A,  561211770 The size of the property data was not correct.",
+    ),
+    info(
+        561211770,
+        "kAudioConverterErr_BadPropertySizeError",
+        AUDIO_CONVERTER,
+        "bad property size",
+        "",
+    ),
+    info(
+        561211770,
+        "kCMIOHardwareBadPropertySizeError",
+        CORE_MEDIA_IO,
+        "bad property size",
+        "An improperly sized buffer was provided when accessing the data of a property.",
+    ),
+    info(
+        561214578,
+        "kCMIOHardwareBadStreamError",
+        CORE_MEDIA_IO,
+        "bad stream",
+        "The 'cm::io::StreamId' passed to the function doesn't map to a valid 'cm::io::Stream'.",
+    ),
+    info(
+        1667787583,
+        "kAudioFileInvalidChunkError",
+        AUDIO_FILE,
+        "invalid chunk",
+        "Either the chunk does not exist in the file or it is not supported by the file.",
+    ),
+    info(
+        1684369017,
+        "kCMIOHardwareSuspendedBySystemError",
+        CORE_MEDIA_IO,
+        "suspended by system",
+        "The function call failed because because access been suspended by the system.",
+    ),
+    info(
+        1684369471,
+        "kAudioFileInvalidPacketDependencyError",
+        AUDIO_FILE,
+        "invalid packet dependency",
+        "The file offset was too large for the file type. The AIFF and WAVE file format types have 32-bit file size limits.",
+    ),
+    info(
+        1685348671,
+        "kAudioFileInvalidFileError",
+        AUDIO_FILE,
+        "invalid file",
+        "The file is malformed, or otherwise not a valid instance of an audio file of its type.",
+    ),
+    info(
+        1718449215,
+        "kAudioFileUnsupportedDataFormatError",
+        AUDIO_FILE,
+        "unsupported data format",
+        "The data format is not supported by this file type.",
+    ),
+    info(
+        1718449215,
+        "kAudioConverterErr_FormatNotSupported",
+        AUDIO_CONVERTER,
+        "format not supported",
+        "",
+    ),
+    info(
+        1752656245,
+        "kAudioConverterErr_HardwareInUse",
+        AUDIO_CONVERTER,
+        "hardware in use",
+        "Returned from AudioConverterFillComplexBuffer if the underlying hardware codec has become unavailable, probably due to an interruption. In this case, your application must stop calling AudioConverterFillComplexBuffer. If the converter can resume from an interruption (see kAudioConverterPropertyCanResumeFromInterruption), you must wait for an EndInterruption notification from AudioSession, and call AudioSessionSetActive(true) before resuming.",
+    ),
+    info(
+        1768846202,
+        "kAudioConverterErr_InvalidInputSize",
+        AUDIO_CONVERTER,
+        "invalid input size",
+        "",
+    ),
+    info(
+        1852797029,
+        "kCMIOHardwareIllegalOperationError",
+        CORE_MEDIA_IO,
+        "illegal operation",
+        "The requested operation couldn't be completed.",
+    ),
+    info(
+        1868981823,
+        "kAudioFileDoesNotAllow64BitDataSizeError",
+        AUDIO_FILE,
+        "does not allow 64 bit data size",
+        "The file offset was too large for the file type. The AIFF and WAVE file format types have 32-bit file size limits.",
+    ),
+    info(
+        1869627199,
+        "kAudioFileOperationNotSupportedError",
+        AUDIO_FILE,
+        "operation not supported",
+        "0x6F703F3F The file is malformed, or otherwise not a valid instance of an audio file of its type.",
+    ),
+    info(
+        1869627199,
+        "kAudioConverterErr_OperationNotSupported",
+        AUDIO_CONVERTER,
+        "operation not supported",
+        "",
+    ),
+    info(
+        1869640813,
+        "kAudioFileNotOptimizedError",
+        AUDIO_FILE,
+        "not optimized",
+        "The chunks following the audio data chunk are preventing the extension of the audio data chunk. To write more data, you must optimize the file.",
+    ),
+    info(
+        1869902714,
+        "kAudioConverterErr_InvalidOutputSize",
+        AUDIO_CONVERTER,
+        "invalid output size",
+        "",
+    ),
+    info(
+        1885563711,
+        "kAudioFileInvalidPacketOffsetError",
+        AUDIO_FILE,
+        "invalid packet offset",
+        "The file offset was too large for the file type. The AIFF and WAVE file format types have 32-bit file size limits.",
+    ),
+    info(
+        1885696621,
+        "kAudioConverterErr_NoHardwarePermission",
+        AUDIO_CONVERTER,
+        "no hardware permission",
+        "Returned from AudioConverterNew if the new converter would use a hardware codec which the application does not have permission to use.",
+    ),
+    info(
+        1886547824,
+        "kAudioConverterErr_PropertyNotSupported",
+        AUDIO_CONVERTER,
+        "property not supported",
+        "",
+    ),
+    info(
+        1886681407,
+        "kAudioFileUnsupportedPropertyError",
+        AUDIO_FILE,
+        "unsupported property",
+        "The property is not supported.",
+    ),
+    info(
+        1920298528,
+        "kCMIOHardwareNotStoppedError",
+        CORE_MEDIA_IO,
+        "not stopped",
+        "The function call requires that the hardware be stopped but it isn't.",
+    ),
+    info(
+        1937010544,
+        "kCMIOHardwareNotRunningError",
+        CORE_MEDIA_IO,
+        "not running",
+        "The function call requires that the hardware be running but it isn't.",
+    ),
+    info(
+        1954115647,
+        "kAudioFileUnsupportedFileTypeError",
+        AUDIO_FILE,
+        "unsupported file type",
+        "The file type is not supported.",
+    ),
+    info(
+        1970171760,
+        "kCMIOHardwareUnsupportedOperationError",
+        CORE_MEDIA_IO,
+        "unsupported operation",
+        "The 'cm::io::Object' doesn't support the requested operation.",
+    ),
+    info(
+        2003329396,
+        "kAudioConverterErr_UnspecifiedError",
+        AUDIO_CONVERTER,
+        "unspecified",
+        "e.g. byte size is not a multiple of the frame size",
+    ),
+    info(
+        2003329396,
+        "kCMIOHardwareUnspecifiedError",
+        CORE_MEDIA_IO,
+        "unspecified",
+        "The function call failed while doing something that doesn't provide any error messages.",
+    ),
+    info(
+        2003332927,
+        "kCMIOHardwareUnknownPropertyError",
+        CORE_MEDIA_IO,
+        "unknown property",
+        "The CMIOObject doesn't know about the property at the given address.",
+    ),
+    info(
+        2003334207,
+        "kAudioFileUnspecifiedError",
+        AUDIO_FILE,
+        "unspecified",
+        "An unspecified error has occurred.",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::os;
+
+    #[test]
+    fn lookup() {
+        let info = os::ErrorInfo::with_value(1718449215).unwrap();
+        assert_eq!(info.name, "kAudioFileUnsupportedDataFormatError");
+        assert_eq!(os::ErrorInfo::all_with_value(1718449215).len(), 2);
+        assert_eq!(
+            os::ErrorInfo::all_with_value(1718449215)[1].name,
+            "kAudioConverterErr_FormatNotSupported"
+        );
+
+        let info = os::ErrorInfo::with_name("kVTVideoDecoderBadDataErr").unwrap();
+        assert_eq!(info.value, -12909);
+        assert_eq!(info.framework, "VideoToolbox");
+        assert_eq!(info.summary, "video decoder bad data");
+
+        let info = os::ErrorInfo::with_name("KERN_INVALID_ADDRESS").unwrap();
+        assert_eq!(info.value, 1);
+        assert_eq!(info.summary, "invalid address");
+
+        let info = os::ErrorInfo::with_name("errSecItemNotFound").unwrap();
+        assert_eq!(info.value, -25300);
+        assert_eq!(info.summary, "item not found");
+        assert!(!info.desc.is_empty());
+
+        assert!(os::ErrorInfo::with_value(-1_234_567).is_none());
+        assert!(os::ErrorInfo::all_with_value(-1_234_567).is_empty());
+        assert!(os::ErrorInfo::with_name("kAudioFileUnknownError").is_none());
+
+        let err = os::Error::with_name("kCMSampleBufferError_Invalidated").unwrap();
+        assert_eq!(err, os::Status(-12744));
+        assert_eq!(err.info().unwrap().framework, "CoreMedia");
+    }
+
+    #[test]
+    fn registry() {
+        let all = os::ErrorInfo::all();
+        assert!(all.windows(2).all(|w| w[0].value <= w[1].value));
+        assert!(all.iter().all(|i| i.value != 0));
+        for info in all {
+            assert_eq!(os::ErrorInfo::with_name(info.name), Some(info));
+            assert!(os::ErrorInfo::all_with_value(info.value).contains(info));
+        }
+    }
+
+    #[test]
+    fn display() {
+        let err = os::Error::from_be_bytes(*b"fmt?");
+        assert_eq!(
+            err.to_string(),
+            "AudioFile: unsupported data format ('fmt?', 1718449215)"
+        );
+        assert_eq!(
+            os::Status(-12909).to_string(),
+            "VideoToolbox: video decoder bad data (-12909)"
+        );
+        assert_eq!(os::Status(-50).to_string(), "CoreAudio: param (-50)");
+        assert_eq!(os::Status(0).to_string(), "no error (0)");
+        assert_eq!(
+            os::Error::new_unchecked(-1_234_567).to_string(),
+            "unknown error (-1234567)"
+        );
+        assert_eq!(
+            os::Error::from_be_bytes(*b"zzzz").to_string(),
+            "unknown error ('zzzz', 2054847098)"
+        );
+        let dbg = format!("{:?}", os::Error::from_be_bytes(*b"fmt?"));
+        assert!(dbg.contains("kAudioFileUnsupportedDataFormatError"));
+    }
+}
@@ -76,7 +76,7 @@ impl ScreenshotManager {
     }
 }
 
-#[link(name = "sc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "sc", kind = "static"))]
 unsafe extern "C" {
     static SC_SCREENSHOT_MANAGER: &'static objc::Class<ScreenshotManager>;
 }
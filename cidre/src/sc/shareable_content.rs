@@ -99,7 +99,7 @@ impl std::fmt::Display for Window {
     }
 }
 
-#[link(name = "sc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "sc", kind = "static"))]
 unsafe extern "C" {
     static SC_SHAREABLE_CONTENT: &'static objc::Class<ShareableContent>;
 }
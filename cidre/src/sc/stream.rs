@@ -443,7 +443,7 @@ impl Cfg {
     pub fn with_preset(preset: CfgPreset) -> arc::R<Self>;
 }
 
-#[link(name = "sc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "sc", kind = "static"))]
 unsafe extern "C" {
     static SC_STREAM_CONFIGURATION: &'static objc::Class<Cfg>;
     static SC_CONTENT_FILTER: &'static objc::Class<ContentFilter>;
@@ -163,6 +163,12 @@ pub mod oids {
         unsafe { kSecOIDX509V1IssuerName }
     }
 
+    /// Value is absolute time of expiration as `cf::Number`.
+    #[doc(alias = "kSecOIDX509V1ValidityNotAfter")]
+    pub const fn x509_v1_validity_not_after() -> &'static cf::String {
+        unsafe { kSecOIDX509V1ValidityNotAfter }
+    }
+
     // 2.5.4.10
     #[doc(alias = "kSecOIDOrganizationName")]
     pub const fn organization_name() -> &'static cf::String {
@@ -179,6 +185,7 @@ pub mod oids {
     unsafe extern "C" {
         static kSecOIDX509V1SubjectName: &'static cf::String;
         static kSecOIDX509V1IssuerName: &'static cf::String;
+        static kSecOIDX509V1ValidityNotAfter: &'static cf::String;
 
         static kSecOIDOrganizationName: &'static cf::String;
         static kSecOIDOrganizationalUnitName: &'static cf::String;
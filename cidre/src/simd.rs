@@ -2,6 +2,27 @@ pub mod vector_types;
 
 pub use vector_types::Simd;
 
+mod common;
+mod geometry;
+
+pub mod matrix;
+pub use matrix::f32x2x2;
+pub use matrix::f32x2x3;
+pub use matrix::f32x2x4;
+pub use matrix::f32x3x2;
+pub use matrix::f32x3x3;
+pub use matrix::f32x3x4;
+pub use matrix::f32x4x2;
+pub use matrix::f32x4x3;
+pub use matrix::f32x4x4;
+pub use matrix::f64x2x2;
+pub use matrix::f64x3x3;
+pub use matrix::f64x4x4;
+
+pub mod quaternion;
+pub use quaternion::quatd;
+pub use quaternion::quatf;
+
 #[allow(non_camel_case_types)]
 pub type i8x2 = Simd<i8, 2, 2>;
 #[allow(non_camel_case_types)]
@@ -37,185 +58,69 @@ pub type f32x3 = Simd<f32, 4, 3>;
 #[allow(non_camel_case_types)]
 pub type f32x4 = Simd<f32, 4, 4>;
 
-#[derive(Debug, Copy, Clone)]
 #[allow(non_camel_case_types)]
-#[repr(transparent)]
-pub struct f32x2x2(pub [f32x2; 2]);
-
-#[derive(Debug, Copy, Clone)]
+pub type i32x2 = Simd<i32, 2, 2>;
 #[allow(non_camel_case_types)]
-#[repr(transparent)]
-pub struct f32x3x2(pub [f32x2; 3]);
-
-#[derive(Debug, Copy, Clone)]
+pub type i32x3 = Simd<i32, 4, 3>;
 #[allow(non_camel_case_types)]
-#[repr(transparent)]
-pub struct f32x4x2(pub [f32x2; 4]);
+pub type i32x4 = Simd<i32, 4, 4>;
 
-#[derive(Debug, Copy, Clone)]
 #[allow(non_camel_case_types)]
-#[repr(transparent)]
-pub struct f32x2x3(pub [f32x3; 2]);
-
-#[derive(Debug, Copy, Clone)]
+pub type u32x2 = Simd<u32, 2, 2>;
 #[allow(non_camel_case_types)]
-#[repr(transparent)]
-pub struct f32x3x3(pub [f32x3; 3]);
-
-impl f32x3x3 {
-    pub fn diagonal(v: f32x3) -> Self {
-        Self([
-            f32x3::with_xyz_f32(v.x(), 0.0, 0.0),
-            f32x3::with_xyz_f32(0.0, v.y(), 0.0),
-            f32x3::with_xyz_f32(0.0, 0.0, v.z()),
-        ])
-    }
-
-    pub const fn identity() -> Self {
-        Self([
-            f32x3::with_xyz_f32(1.0, 0.0, 0.0),
-            f32x3::with_xyz_f32(0.0, 1.0, 0.0),
-            f32x3::with_xyz_f32(0.0, 0.0, 1.0),
-        ])
-    }
-
-    pub const fn translate(tx: f32, ty: f32) -> Self {
-        Self([
-            f32x3::with_xyz_f32(1.0, 0.0, tx),
-            f32x3::with_xyz_f32(0.0, 1.0, ty),
-            f32x3::with_xyz_f32(0.0, 0.0, 1.0),
-        ])
-    }
-}
-#[derive(Debug, Copy, Clone)]
+pub type u32x3 = Simd<u32, 4, 3>;
 #[allow(non_camel_case_types)]
-#[repr(transparent)]
-pub struct f32x4x4(pub [f32x4; 4]);
-
-impl f32x4x4 {
-    pub fn diagonal(v: f32x4) -> Self {
-        Self([
-            f32x4::with_xyzw_f32(v.x(), 0.0, 0.0, 0.0),
-            f32x4::with_xyzw_f32(0.0, v.y(), 0.0, 0.0),
-            f32x4::with_xyzw_f32(0.0, 0.0, v.z(), 0.0),
-            f32x4::with_xyzw_f32(0.0, 0.0, 0.0, v.w()),
-        ])
-    }
-
-    pub const fn identity() -> Self {
-        Self([
-            f32x4::with_xyzw_f32(1.0, 0.0, 0.0, 0.0),
-            f32x4::with_xyzw_f32(0.0, 1.0, 0.0, 0.0),
-            f32x4::with_xyzw_f32(0.0, 0.0, 1.0, 0.0),
-            f32x4::with_xyzw_f32(0.0, 0.0, 0.0, 1.0),
-        ])
-    }
-
-    pub const fn translate(tx: f32, ty: f32, tz: f32) -> Self {
-        Self([
-            f32x4::with_xyzw_f32(1.0, 0.0, 0.0, tx),
-            f32x4::with_xyzw_f32(0.0, 1.0, 0.0, ty),
-            f32x4::with_xyzw_f32(0.0, 0.0, 1.0, tz),
-            f32x4::with_xyzw_f32(0.0, 0.0, 0.0, 1.0),
-        ])
-    }
-
-    #[inline]
-    pub fn tx(&self) -> f32 {
-        self[0].w()
-    }
-
-    #[inline]
-    pub fn set_tx(&mut self, value: f32) {
-        self[0].set_w(value)
-    }
-
-    #[inline]
-    pub fn ty(&self) -> f32 {
-        self[1].w()
-    }
-
-    #[inline]
-    pub fn set_ty(&mut self, value: f32) {
-        self[1].set_w(value)
-    }
-
-    #[inline]
-    pub fn tz(&self) -> f32 {
-        self[2].w()
-    }
-
-    #[inline]
-    pub fn set_tz(&mut self, value: f32) {
-        self[2].set_w(value)
-    }
-
-    #[inline]
-    pub fn sx(&self) -> f32 {
-        self[0].x()
-    }
-
-    #[inline]
-    pub fn set_sx(&mut self, value: f32) {
-        self[0].set_x(value)
-    }
-
-    #[inline]
-    pub fn sy(&self) -> f32 {
-        self[0].y()
-    }
+pub type u32x4 = Simd<u32, 4, 4>;
 
-    #[inline]
-    pub fn set_sy(&mut self, value: f32) {
-        self[0].set_y(value)
-    }
-
-    #[inline]
-    pub fn sz(&self) -> f32 {
-        self[0].z()
-    }
+#[allow(non_camel_case_types)]
+pub type f64x2 = Simd<f64, 2, 2>;
+#[allow(non_camel_case_types)]
+pub type f64x3 = Simd<f64, 4, 3>;
+#[allow(non_camel_case_types)]
+pub type f64x4 = Simd<f64, 4, 4>;
 
-    #[inline]
-    pub fn set_sz(&mut self, value: f32) {
-        self[0].set_z(value)
-    }
-}
+pub mod packed {
+    use super::{Simd, vector_types::Lane};
 
-impl std::ops::Index<usize> for f32x4x4 {
-    type Output = f32x4;
+    /// Vector with alignment of its element, so it can be loaded from or stored
+    /// to an array of elements. Convert it to [`Simd`] for arithmetic.
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    #[repr(transparent)]
+    pub struct Packed<T, const N: usize>(pub [T; N]);
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.0[index]
+    impl<T: Lane<N>, const N: usize> From<Simd<T, N, N>> for Packed<T, N> {
+        #[inline]
+        fn from(val: Simd<T, N, N>) -> Self {
+            Self(val.0)
+        }
     }
-}
 
-impl std::ops::IndexMut<usize> for f32x4x4 {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.0[index]
+    impl<T: Lane<N>, const N: usize> From<Packed<T, N>> for Simd<T, N, N> {
+        #[inline]
+        fn from(val: Packed<T, N>) -> Self {
+            Simd::from_array(val.0)
+        }
     }
-}
 
-pub mod packed {
-    use super::Simd;
     #[allow(non_camel_case_types)]
-    pub type i8x2 = Simd<i8, 2, 2>;
+    pub type i8x2 = Packed<i8, 2>;
     #[allow(non_camel_case_types)]
-    pub type i8x4 = Simd<i8, 4, 4>;
+    pub type i8x4 = Packed<i8, 4>;
 
     #[allow(non_camel_case_types)]
-    pub type u8x2 = Simd<u8, 2, 2>;
+    pub type u8x2 = Packed<u8, 2>;
     #[allow(non_camel_case_types)]
-    pub type u8x4 = Simd<u8, 4, 4>;
+    pub type u8x4 = Packed<u8, 4>;
 
     #[allow(non_camel_case_types)]
-    pub type i16x2 = Simd<i16, 2, 2>;
+    pub type i16x2 = Packed<i16, 2>;
     #[allow(non_camel_case_types)]
-    pub type i16x4 = Simd<i16, 4, 4>;
+    pub type i16x4 = Packed<i16, 4>;
 
     #[allow(non_camel_case_types)]
-    pub type u16x2 = Simd<u16, 2, 2>;
+    pub type u16x2 = Packed<u16, 2>;
     #[allow(non_camel_case_types)]
-    pub type u16x4 = Simd<u16, 4, 4>;
+    pub type u16x4 = Packed<u16, 4>;
 
     /// A vector of two 32-bit signed (twos-complement) integers with
     /// relaxed alignment.
@@ -224,34 +129,34 @@ pub mod packed {
     /// scalar element type, so you can use it to load or store from an array of
     /// that type.                                                                */
     #[allow(non_camel_case_types)]
-    pub type i32x2 = Simd<i32, 2, 2>;
+    pub type i32x2 = Packed<i32, 2>;
 
     #[allow(non_camel_case_types)]
-    pub type i32x4 = Simd<i32, 4, 4>;
+    pub type i32x4 = Packed<i32, 4>;
 
     #[allow(non_camel_case_types)]
-    pub type u32x2 = Simd<u32, 2, 2>;
+    pub type u32x2 = Packed<u32, 2>;
 
     #[allow(non_camel_case_types)]
-    pub type u32x4 = Simd<u32, 4, 4>;
+    pub type u32x4 = Packed<u32, 4>;
 
     #[allow(non_camel_case_types)]
-    pub type i64x2 = Simd<i64, 2, 2>;
+    pub type i64x2 = Packed<i64, 2>;
 
     #[allow(non_camel_case_types)]
-    pub type i64x4 = Simd<i64, 4, 4>;
+    pub type i64x4 = Packed<i64, 4>;
 
     #[allow(non_camel_case_types)]
-    pub type u64x2 = Simd<u64, 2, 2>;
+    pub type u64x2 = Packed<u64, 2>;
 
     #[allow(non_camel_case_types)]
-    pub type u64x4 = Simd<u64, 4, 4>;
+    pub type u64x4 = Packed<u64, 4>;
 
     #[allow(non_camel_case_types)]
-    pub type f32x2 = Simd<f32, 2, 2>;
+    pub type f32x2 = Packed<f32, 2>;
 
     #[allow(non_camel_case_types)]
-    pub type f32x4 = Simd<f32, 4, 4>;
+    pub type f32x4 = Packed<f32, 4>;
 }
 
 #[cfg(test)]
@@ -261,7 +166,59 @@ mod tests {
 
     #[test]
     fn mul() {
-        let _x = f32x2x2([f32x2::with_xy(1.0, 0.0), f32x2::with_xy(1.0, 0.0)]);
-        let _y = f32x2x2([f32x2::with_xy(1.0, 0.0), f32x2::with_xy(1.0, 0.0)]);
+        let x = f32x2x2([f32x2::with_xy(1.0, 0.0), f32x2::with_xy(1.0, 0.0)]);
+        let y = f32x2x2([f32x2::with_xy(1.0, 0.0), f32x2::with_xy(1.0, 0.0)]);
+        assert_eq!(x * y, x);
+        assert_eq!(x * f32x2::with_xy(1.0, 2.0), f32x2::with_xy(3.0, 0.0));
+    }
+
+    #[test]
+    fn layout() {
+        use std::mem::{align_of, offset_of, size_of};
+
+        use super::{f32x2, f32x3, f32x4, f32x4x4, f64x3, f64x4, i8x3, packed, quatf};
+
+        // sizes and alignments of simd_float3, simd_float4, simd_double3 and simd_quatf
+        assert_eq!(size_of::<f32x3>(), 16);
+        assert_eq!(size_of::<f32x4>(), 16);
+        assert_eq!(size_of::<f64x3>(), 32);
+        assert_eq!(size_of::<quatf>(), 16);
+        assert_eq!(size_of::<f32x4x4>(), 64);
+        assert_eq!(align_of::<f32x2>(), 8);
+        assert_eq!(align_of::<f32x3>(), 16);
+        assert_eq!(align_of::<f32x4>(), 16);
+        assert_eq!(align_of::<f32x4x4>(), 16);
+        assert_eq!(align_of::<quatf>(), 16);
+        assert_eq!(align_of::<f64x3>(), 32);
+        assert_eq!(align_of::<f64x4>(), 32);
+        assert_eq!(align_of::<i8x3>(), 4);
+
+        // packed types keep alignment of element
+        assert_eq!(size_of::<packed::f32x4>(), 16);
+        assert_eq!(align_of::<packed::f32x4>(), 4);
+
+        #[repr(C)]
+        struct Uniforms {
+            a: f32x4,
+            b: f32x3,
+            c: f32,
+        }
+        assert_eq!(offset_of!(Uniforms, b), 16);
+        assert_eq!(offset_of!(Uniforms, c), 32);
+
+        #[repr(C)]
+        struct Light {
+            a: f32,
+            b: f32x4,
+            c: f32,
+            d: f64x3,
+            e: f32,
+            f: f32x4x4,
+        }
+        assert_eq!(offset_of!(Light, b), 16);
+        assert_eq!(offset_of!(Light, d), 64);
+        assert_eq!(offset_of!(Light, f), 112);
+        assert_eq!(size_of::<Light>(), 192);
+        assert_eq!(align_of::<Light>(), 32);
     }
 }
@@ -0,0 +1,90 @@
+use super::{Simd, vector_types::Lane};
+
+macro_rules! common {
+    ($t:ty) => {
+        impl<const LANES: usize, const N: usize> Simd<$t, LANES, N>
+        where
+            $t: Lane<LANES>,
+        {
+            #[doc(alias = "simd_abs")]
+            #[inline]
+            pub fn abs(self) -> Self {
+                self.map(<$t>::abs)
+            }
+
+            #[doc(alias = "simd_min")]
+            #[inline]
+            pub fn min(self, other: Self) -> Self {
+                self.zip_map(other, <$t>::min)
+            }
+
+            #[doc(alias = "simd_max")]
+            #[inline]
+            pub fn max(self, other: Self) -> Self {
+                self.zip_map(other, <$t>::max)
+            }
+
+            #[doc(alias = "simd_clamp")]
+            #[inline]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                self.max(min).min(max)
+            }
+
+            /// Linear interpolation, `self` at `t == 0` and `to` at `t == 1`.
+            #[doc(alias = "simd_mix")]
+            #[inline]
+            pub fn lerp(self, to: Self, t: $t) -> Self {
+                self + (to - self) * t
+            }
+
+            #[doc(alias = "simd_reduce_add")]
+            #[inline]
+            pub fn reduce_add(&self) -> $t {
+                self.fold(0.0, |a, b| a + b)
+            }
+
+            #[doc(alias = "simd_reduce_min")]
+            #[inline]
+            pub fn reduce_min(&self) -> $t {
+                self.fold(<$t>::INFINITY, <$t>::min)
+            }
+
+            #[doc(alias = "simd_reduce_max")]
+            #[inline]
+            pub fn reduce_max(&self) -> $t {
+                self.fold(<$t>::NEG_INFINITY, <$t>::max)
+            }
+        }
+    };
+}
+
+common!(f32);
+common!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::simd::{f32x3, f32x4, f64x2};
+
+    #[test]
+    fn basics() {
+        let a = f32x4::with_xyzw(-1.0, 2.0, -3.0, 4.0);
+        assert_eq!(a.abs(), f32x4::with_xyzw(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(
+            a.clamp(f32x4::splat(-2.0), f32x4::splat(2.0)),
+            f32x4::with_xyzw(-1.0, 2.0, -2.0, 2.0)
+        );
+        assert_eq!(a.reduce_add(), 2.0);
+        assert_eq!(a.reduce_min(), -3.0);
+        assert_eq!(a.reduce_max(), 4.0);
+
+        // padding lane doesn't take part
+        let v = f32x3::with_xyz(-1.0, -2.0, -3.0);
+        assert_eq!(v.reduce_max(), -1.0);
+
+        let from = f64x2::with_xy(0.0, 10.0);
+        let to = f64x2::with_xy(10.0, 20.0);
+        assert_eq!(from.lerp(to, 0.0), from);
+        assert_eq!(from.lerp(to, 1.0), to);
+        assert_eq!(from.lerp(to, 0.25), f64x2::with_xy(2.5, 12.5));
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Simd, vector_types::Lane};
+
+macro_rules! geometry {
+    ($t:ty) => {
+        impl<const LANES: usize, const N: usize> Simd<$t, LANES, N>
+        where
+            $t: Lane<LANES>,
+        {
+            #[doc(alias = "simd_dot")]
+            #[inline]
+            pub fn dot(self, other: Self) -> $t {
+                (self * other).reduce_add()
+            }
+
+            #[doc(alias = "simd_length_squared")]
+            #[inline]
+            pub fn length_squared(self) -> $t {
+                self.dot(self)
+            }
+
+            #[doc(alias = "simd_length")]
+            #[inline]
+            pub fn length(self) -> $t {
+                self.length_squared().sqrt()
+            }
+
+            #[doc(alias = "simd_distance_squared")]
+            #[inline]
+            pub fn distance_squared(self, other: Self) -> $t {
+                (self - other).length_squared()
+            }
+
+            #[doc(alias = "simd_distance")]
+            #[inline]
+            pub fn distance(self, other: Self) -> $t {
+                (self - other).length()
+            }
+
+            /// Unit vector in the direction of `self`, NaN for zero vector.
+            #[doc(alias = "simd_normalize")]
+            #[inline]
+            pub fn normalize(self) -> Self {
+                self / self.length()
+            }
+
+            /// Reflects `self` through the plane perpendicular to unit `normal`.
+            #[doc(alias = "simd_reflect")]
+            #[inline]
+            pub fn reflect(self, normal: Self) -> Self {
+                self - normal * (2.0 * self.dot(normal))
+            }
+        }
+
+        impl Simd<$t, 4, 3> {
+            #[doc(alias = "simd_cross")]
+            #[inline]
+            pub fn cross(self, other: Self) -> Self {
+                let [ax, ay, az, _] = self.0;
+                let [bx, by, bz, _] = other.0;
+                Self::from_array([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx, 0.0])
+            }
+        }
+    };
+}
+
+geometry!(f32);
+geometry!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::simd::{f32x2, f32x3, f64x3};
+
+    #[test]
+    fn basics() {
+        let x = f32x3::with_xyz(1.0, 0.0, 0.0);
+        let y = f32x3::with_xyz(0.0, 1.0, 0.0);
+        assert_eq!(x.cross(y), f32x3::with_xyz(0.0, 0.0, 1.0));
+        assert_eq!(y.cross(x), f32x3::with_xyz(0.0, 0.0, -1.0));
+        assert_eq!(x.dot(y), 0.0);
+
+        let v = f32x2::with_xy(3.0, 4.0);
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(v.normalize(), f32x2::with_xy(0.6, 0.8));
+        assert_eq!(v.distance(f32x2::with_xy(0.0, 0.0)), 5.0);
+
+        let d = f64x3::with_xyz(1.0, -1.0, 0.0);
+        let n = f64x3::with_xyz(0.0, 1.0, 0.0);
+        assert_eq!(d.reflect(n), f64x3::with_xyz(1.0, 1.0, 0.0));
+    }
+}
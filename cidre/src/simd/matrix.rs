@@ -0,0 +1,680 @@
+//! Column-major matrices, `f32x3x2` is 3 columns of `f32x2` like `simd_float3x2`.
+//!
+//! `m[c][r]` is element at column `c` and row `r`, matrices multiply column vectors
+//! (`m * v`) as in Metal shaders.
+
+use std::ops;
+
+use super::{Simd, f32x2, f32x3, f32x4, f64x2, f64x3, f64x4, quatd, quatf};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x2x2(pub [f32x2; 2]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x3x2(pub [f32x2; 3]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x4x2(pub [f32x2; 4]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x2x3(pub [f32x3; 2]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x3x3(pub [f32x3; 3]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x4x3(pub [f32x3; 4]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x2x4(pub [f32x4; 2]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x3x4(pub [f32x4; 3]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f32x4x4(pub [f32x4; 4]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f64x2x2(pub [f64x2; 2]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f64x3x3(pub [f64x3; 3]);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f64x4x4(pub [f64x4; 4]);
+
+/// Operations of any matrix `$name` with `$cols` columns of `$col`,
+/// `$row` is vector with `$cols` elements.
+macro_rules! matrix {
+    ($name:ident, $t:ty, $cols:literal, $col:ty, $row:ty, $transposed:ident) => {
+        impl $name {
+            #[doc(alias = "simd_transpose")]
+            pub fn transpose(&self) -> $transposed {
+                let mut res = $transposed::default();
+                for (r, col) in res.0.iter_mut().enumerate() {
+                    for c in 0..$cols {
+                        col[c] = self.0[c][r];
+                    }
+                }
+                res
+            }
+        }
+
+        impl ops::Index<usize> for $name {
+            type Output = $col;
+
+            #[inline]
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.0[index]
+            }
+        }
+
+        impl ops::IndexMut<usize> for $name {
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                &mut self.0[index]
+            }
+        }
+
+        impl ops::Add for $name {
+            type Output = Self;
+
+            #[doc(alias = "simd_add")]
+            #[inline]
+            fn add(mut self, rhs: Self) -> Self {
+                for c in 0..$cols {
+                    self.0[c] += rhs.0[c];
+                }
+                self
+            }
+        }
+
+        impl ops::Sub for $name {
+            type Output = Self;
+
+            #[doc(alias = "simd_sub")]
+            #[inline]
+            fn sub(mut self, rhs: Self) -> Self {
+                for c in 0..$cols {
+                    self.0[c] -= rhs.0[c];
+                }
+                self
+            }
+        }
+
+        impl ops::Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(mut self) -> Self {
+                for c in 0..$cols {
+                    self.0[c] = -self.0[c];
+                }
+                self
+            }
+        }
+
+        impl ops::Mul<$t> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(mut self, rhs: $t) -> Self {
+                for c in 0..$cols {
+                    self.0[c] *= rhs;
+                }
+                self
+            }
+        }
+
+        impl ops::Mul<$name> for $t {
+            type Output = $name;
+
+            #[inline]
+            fn mul(self, rhs: $name) -> $name {
+                rhs * self
+            }
+        }
+
+        /// Transforms column vector.
+        impl ops::Mul<$row> for $name {
+            type Output = $col;
+
+            #[doc(alias = "simd_mul")]
+            #[inline]
+            fn mul(self, rhs: $row) -> $col {
+                let mut res = <$col>::default();
+                for c in 0..$cols {
+                    res += self.0[c] * rhs[c];
+                }
+                res
+            }
+        }
+
+        /// Transforms row vector.
+        impl ops::Mul<$name> for $col {
+            type Output = $row;
+
+            #[doc(alias = "simd_mul")]
+            #[inline]
+            fn mul(self, rhs: $name) -> $row {
+                let mut res = <$row>::default();
+                for c in 0..$cols {
+                    res[c] = self.dot(rhs.0[c]);
+                }
+                res
+            }
+        }
+    };
+}
+
+matrix!(f32x2x2, f32, 2, f32x2, f32x2, f32x2x2);
+matrix!(f32x3x2, f32, 3, f32x2, f32x3, f32x2x3);
+matrix!(f32x4x2, f32, 4, f32x2, f32x4, f32x2x4);
+matrix!(f32x2x3, f32, 2, f32x3, f32x2, f32x3x2);
+matrix!(f32x3x3, f32, 3, f32x3, f32x3, f32x3x3);
+matrix!(f32x4x3, f32, 4, f32x3, f32x4, f32x3x4);
+matrix!(f32x2x4, f32, 2, f32x4, f32x2, f32x4x2);
+matrix!(f32x3x4, f32, 3, f32x4, f32x3, f32x4x3);
+matrix!(f32x4x4, f32, 4, f32x4, f32x4, f32x4x4);
+matrix!(f64x2x2, f64, 2, f64x2, f64x2, f64x2x2);
+matrix!(f64x3x3, f64, 3, f64x3, f64x3, f64x3x3);
+matrix!(f64x4x4, f64, 4, f64x4, f64x4, f64x4x4);
+
+/// Operations of square matrices.
+macro_rules! square {
+    ($name:ident, $t:ty, $n:literal, $v:ty) => {
+        impl $name {
+            pub fn diagonal(v: $v) -> Self {
+                let mut res = Self::default();
+                for i in 0..$n {
+                    res.0[i][i] = v[i];
+                }
+                res
+            }
+        }
+
+        impl ops::Mul for $name {
+            type Output = Self;
+
+            #[doc(alias = "simd_mul")]
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                let mut res = rhs;
+                for c in 0..$n {
+                    res.0[c] = self * rhs.0[c];
+                }
+                res
+            }
+        }
+
+        impl ops::MulAssign for $name {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+square!(f32x2x2, f32, 2, f32x2);
+square!(f32x3x3, f32, 3, f32x3);
+square!(f32x4x4, f32, 4, f32x4);
+square!(f64x2x2, f64, 2, f64x2);
+square!(f64x3x3, f64, 3, f64x3);
+square!(f64x4x4, f64, 4, f64x4);
+
+macro_rules! square2 {
+    ($name:ident, $t:ty) => {
+        impl $name {
+            pub const fn identity() -> Self {
+                Self([Simd::from_array([1.0, 0.0]), Simd::from_array([0.0, 1.0])])
+            }
+
+            #[doc(alias = "simd_determinant")]
+            pub fn determinant(&self) -> $t {
+                let [a, b] = self.0;
+                a[0] * b[1] - b[0] * a[1]
+            }
+
+            /// Inverse matrix, elements are not finite if matrix is singular.
+            #[doc(alias = "simd_inverse")]
+            pub fn inverse(&self) -> Self {
+                let [a, b] = self.0;
+                let det = self.determinant();
+                Self([
+                    Simd::from_array([b[1], -a[1]]),
+                    Simd::from_array([-b[0], a[0]]),
+                ]) * (1.0 / det)
+            }
+        }
+    };
+}
+
+square2!(f32x2x2, f32);
+square2!(f64x2x2, f64);
+
+macro_rules! square3 {
+    ($name:ident, $t:ty) => {
+        impl $name {
+            pub const fn identity() -> Self {
+                Self([
+                    Simd::from_array([1.0, 0.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, 1.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, 0.0, 1.0, 0.0]),
+                ])
+            }
+
+            /// 2D homogeneous translation.
+            pub const fn translate(tx: $t, ty: $t) -> Self {
+                Self([
+                    Simd::from_array([1.0, 0.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, 1.0, 0.0, 0.0]),
+                    Simd::from_array([tx, ty, 1.0, 0.0]),
+                ])
+            }
+
+            #[doc(alias = "simd_determinant")]
+            pub fn determinant(&self) -> $t {
+                let [a, b, c] = self.0;
+                a.dot(b.cross(c))
+            }
+
+            /// Inverse matrix, elements are not finite if matrix is singular.
+            #[doc(alias = "simd_inverse")]
+            pub fn inverse(&self) -> Self {
+                let [a, b, c] = self.0;
+                let r0 = b.cross(c);
+                let det = a.dot(r0);
+                Self([r0, c.cross(a), a.cross(b)]).transpose() * (1.0 / det)
+            }
+        }
+    };
+}
+
+square3!(f32x3x3, f32);
+square3!(f64x3x3, f64);
+
+macro_rules! square4 {
+    ($name:ident, $t:ty, $v3:ty, $v4:ty, $q:ident) => {
+        impl $name {
+            pub const fn identity() -> Self {
+                Self([
+                    Simd::from_array([1.0, 0.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, 1.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, 0.0, 1.0, 0.0]),
+                    Simd::from_array([0.0, 0.0, 0.0, 1.0]),
+                ])
+            }
+
+            pub const fn translate(tx: $t, ty: $t, tz: $t) -> Self {
+                Self([
+                    Simd::from_array([1.0, 0.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, 1.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, 0.0, 1.0, 0.0]),
+                    Simd::from_array([tx, ty, tz, 1.0]),
+                ])
+            }
+
+            pub const fn scale(sx: $t, sy: $t, sz: $t) -> Self {
+                Self([
+                    Simd::from_array([sx, 0.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, sy, 0.0, 0.0]),
+                    Simd::from_array([0.0, 0.0, sz, 0.0]),
+                    Simd::from_array([0.0, 0.0, 0.0, 1.0]),
+                ])
+            }
+
+            #[inline]
+            pub fn tx(&self) -> $t {
+                self[3].x()
+            }
+
+            #[inline]
+            pub fn set_tx(&mut self, value: $t) {
+                self[3].set_x(value)
+            }
+
+            #[inline]
+            pub fn ty(&self) -> $t {
+                self[3].y()
+            }
+
+            #[inline]
+            pub fn set_ty(&mut self, value: $t) {
+                self[3].set_y(value)
+            }
+
+            #[inline]
+            pub fn tz(&self) -> $t {
+                self[3].z()
+            }
+
+            #[inline]
+            pub fn set_tz(&mut self, value: $t) {
+                self[3].set_z(value)
+            }
+
+            #[inline]
+            pub fn sx(&self) -> $t {
+                self[0].x()
+            }
+
+            #[inline]
+            pub fn set_sx(&mut self, value: $t) {
+                self[0].set_x(value)
+            }
+
+            #[inline]
+            pub fn sy(&self) -> $t {
+                self[1].y()
+            }
+
+            #[inline]
+            pub fn set_sy(&mut self, value: $t) {
+                self[1].set_y(value)
+            }
+
+            #[inline]
+            pub fn sz(&self) -> $t {
+                self[2].z()
+            }
+
+            #[inline]
+            pub fn set_sz(&mut self, value: $t) {
+                self[2].set_z(value)
+            }
+
+            /// Cross products of column pairs, shared by determinant and inverse.
+            ///
+            /// Eric Lengyel, Foundations of Game Engine Development, Vol. 1.
+            fn split(&self) -> ([$v3; 4], [$t; 4], [$v3; 4], $t) {
+                let [a, b, c, d] = self.0;
+                let (x, y, z, w) = (a.w(), b.w(), c.w(), d.w());
+                let (a, b, c, d) = (a.xyz(), b.xyz(), c.xyz(), d.xyz());
+                let s = a.cross(b);
+                let t = c.cross(d);
+                let u = a * y - b * x;
+                let v = c * w - d * z;
+                let det = s.dot(v) + t.dot(u);
+                ([a, b, c, d], [x, y, z, w], [s, t, u, v], det)
+            }
+
+            #[doc(alias = "simd_determinant")]
+            pub fn determinant(&self) -> $t {
+                self.split().3
+            }
+
+            /// Inverse matrix, elements are not finite if matrix is singular.
+            #[doc(alias = "simd_inverse")]
+            pub fn inverse(&self) -> Self {
+                let ([a, b, c, d], [x, y, z, w], [s, t, u, v], det) = self.split();
+                let inv_det = 1.0 / det;
+                let (s, t, u, v) = (s * inv_det, t * inv_det, u * inv_det, v * inv_det);
+                let row = |r: $v3, w: $t| <$v4>::with_xyzw(r.x(), r.y(), r.z(), w);
+                Self([
+                    row(b.cross(v) + t * y, -b.dot(t)),
+                    row(v.cross(a) - t * x, a.dot(t)),
+                    row(d.cross(u) + s * w, -d.dot(s)),
+                    row(u.cross(c) - s * z, c.dot(s)),
+                ])
+                .transpose()
+            }
+
+            /// Rotation around unit `axis` by `radians` counterclockwise.
+            pub fn rotate(radians: $t, axis: $v3) -> Self {
+                Self::with_quat($q::with_angle_axis(radians, axis))
+            }
+
+            /// Right-handed perspective projection to Metal clip space (z in `0..=1`).
+            pub fn perspective_rh(fovy_radians: $t, aspect: $t, near: $t, far: $t) -> Self {
+                let ys = 1.0 / (fovy_radians * 0.5).tan();
+                let xs = ys / aspect;
+                let zs = far / (near - far);
+                Self([
+                    Simd::from_array([xs, 0.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, ys, 0.0, 0.0]),
+                    Simd::from_array([0.0, 0.0, zs, -1.0]),
+                    Simd::from_array([0.0, 0.0, near * zs, 0.0]),
+                ])
+            }
+
+            /// Left-handed perspective projection to Metal clip space (z in `0..=1`).
+            pub fn perspective_lh(fovy_radians: $t, aspect: $t, near: $t, far: $t) -> Self {
+                let ys = 1.0 / (fovy_radians * 0.5).tan();
+                let xs = ys / aspect;
+                let zs = far / (far - near);
+                Self([
+                    Simd::from_array([xs, 0.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, ys, 0.0, 0.0]),
+                    Simd::from_array([0.0, 0.0, zs, 1.0]),
+                    Simd::from_array([0.0, 0.0, -near * zs, 0.0]),
+                ])
+            }
+
+            /// Right-handed orthographic projection to Metal clip space (z in `0..=1`).
+            pub fn ortho_rh(left: $t, right: $t, bottom: $t, top: $t, near: $t, far: $t) -> Self {
+                Self([
+                    Simd::from_array([2.0 / (right - left), 0.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, 2.0 / (top - bottom), 0.0, 0.0]),
+                    Simd::from_array([0.0, 0.0, -1.0 / (far - near), 0.0]),
+                    Simd::from_array([
+                        (left + right) / (left - right),
+                        (top + bottom) / (bottom - top),
+                        near / (near - far),
+                        1.0,
+                    ]),
+                ])
+            }
+
+            /// Left-handed orthographic projection to Metal clip space (z in `0..=1`).
+            pub fn ortho_lh(left: $t, right: $t, bottom: $t, top: $t, near: $t, far: $t) -> Self {
+                Self([
+                    Simd::from_array([2.0 / (right - left), 0.0, 0.0, 0.0]),
+                    Simd::from_array([0.0, 2.0 / (top - bottom), 0.0, 0.0]),
+                    Simd::from_array([0.0, 0.0, 1.0 / (far - near), 0.0]),
+                    Simd::from_array([
+                        (left + right) / (left - right),
+                        (top + bottom) / (bottom - top),
+                        near / (near - far),
+                        1.0,
+                    ]),
+                ])
+            }
+
+            /// Right-handed view matrix, camera at `eye` looks at `target` along -z.
+            pub fn look_at_rh(eye: $v3, target: $v3, up: $v3) -> Self {
+                Self::look_at(eye, (eye - target).normalize(), up)
+            }
+
+            /// Left-handed view matrix, camera at `eye` looks at `target` along +z.
+            pub fn look_at_lh(eye: $v3, target: $v3, up: $v3) -> Self {
+                Self::look_at(eye, (target - eye).normalize(), up)
+            }
+
+            fn look_at(eye: $v3, z: $v3, up: $v3) -> Self {
+                let x = up.cross(z).normalize();
+                let y = z.cross(x);
+                Self([
+                    Simd::from_array([x.x(), y.x(), z.x(), 0.0]),
+                    Simd::from_array([x.y(), y.y(), z.y(), 0.0]),
+                    Simd::from_array([x.z(), y.z(), z.z(), 0.0]),
+                    Simd::from_array([-x.dot(eye), -y.dot(eye), -z.dot(eye), 1.0]),
+                ])
+            }
+        }
+    };
+}
+
+square4!(f32x4x4, f32, f32x3, f32x4, quatf);
+square4!(f64x4x4, f64, f64x3, f64x4, quatd);
+
+#[cfg(test)]
+mod tests {
+    use crate::simd::{
+        f32x2, f32x2x2, f32x3, f32x3x2, f32x3x3, f32x4, f32x4x4, f64x2, f64x2x2, f64x4, f64x4x4,
+    };
+
+    fn assert_near(a: f32x4x4, b: f32x4x4) {
+        for c in 0..4 {
+            assert!((a[c] - b[c]).abs().reduce_max() < 1e-5, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn transform() {
+        let t = f32x4x4::translate(1.0, 2.0, 3.0);
+        let p = f32x4::with_xyzw(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(t * p, f32x4::with_xyzw(2.0, 3.0, 4.0, 1.0));
+        assert_eq!((t.tx(), t.ty(), t.tz()), (1.0, 2.0, 3.0));
+
+        let s = f32x4x4::scale(2.0, 3.0, 4.0);
+        assert_eq!((s.sx(), s.sy(), s.sz()), (2.0, 3.0, 4.0));
+        // scale first, then translate
+        assert_eq!(t * s * p, f32x4::with_xyzw(3.0, 5.0, 7.0, 1.0));
+
+        let t2 = f32x3x3::translate(10.0, 20.0);
+        assert_eq!(
+            t2 * f32x3::with_xyz(1.0, 2.0, 1.0),
+            f32x3::with_xyz(11.0, 22.0, 1.0)
+        );
+
+        // row vector multiplies transposed
+        assert_eq!(p * t, t.transpose() * p);
+    }
+
+    #[test]
+    fn non_square() {
+        let m = f32x3x2([
+            f32x2::with_xy(1.0, 2.0),
+            f32x2::with_xy(3.0, 4.0),
+            f32x2::with_xy(5.0, 6.0),
+        ]);
+        let v = f32x3::with_xyz(1.0, 1.0, 1.0);
+        assert_eq!(m * v, f32x2::with_xy(9.0, 12.0));
+        let t = m.transpose();
+        assert_eq!(t[1], f32x3::with_xyz(2.0, 4.0, 6.0));
+        assert_eq!(t.transpose(), m);
+        assert_eq!(
+            f32x2::with_xy(1.0, 1.0) * m,
+            f32x3::with_xyz(3.0, 7.0, 11.0)
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let m = f32x2x2([f32x2::with_xy(3.0, 1.0), f32x2::with_xy(5.0, 2.0)]);
+        assert_eq!(m.determinant(), 1.0);
+        assert_eq!(m * m.inverse(), f32x2x2::identity());
+        let m = f64x2x2::diagonal(f64x2::with_xy(2.0, 4.0));
+        assert_eq!(m.inverse(), f64x2x2::diagonal(f64x2::with_xy(0.5, 0.25)));
+
+        let m = f32x3x3([
+            f32x3::with_xyz(1.0, 0.0, 0.0),
+            f32x3::with_xyz(2.0, 1.0, 0.0),
+            f32x3::with_xyz(3.0, 4.0, 1.0),
+        ]);
+        assert_eq!(m.determinant(), 1.0);
+        assert_eq!(m * m.inverse(), f32x3x3::identity());
+        assert_eq!(m.inverse() * m, f32x3x3::identity());
+
+        let m = f32x4x4::translate(1.0, -2.0, 3.0)
+            * f32x4x4::rotate(0.7, f32x3::with_xyz(0.0, 0.6, 0.8))
+            * f32x4x4::scale(2.0, 3.0, 4.0);
+        assert!((m.determinant() - 24.0).abs() < 1e-4);
+        assert_near(m * m.inverse(), f32x4x4::identity());
+        assert_near(m.inverse() * m, f32x4x4::identity());
+
+        let m = f64x4x4([
+            f64x4::with_xyzw(1.0, 0.0, 2.0, 0.0),
+            f64x4::with_xyzw(0.0, 3.0, 0.0, 1.0),
+            f64x4::with_xyzw(4.0, 0.0, 5.0, 0.0),
+            f64x4::with_xyzw(0.0, 6.0, 0.0, 7.0),
+        ]);
+        // block diagonal: (1*5 - 4*2) * (3*7 - 6*1)
+        assert_eq!(m.determinant(), -45.0);
+        let e = m * m.inverse() - f64x4x4::identity();
+        assert!(e.0.iter().all(|c| c.abs().reduce_max() < 1e-12));
+    }
+
+    #[test]
+    fn projections() {
+        let near = 0.1;
+        let far = 100.0;
+        let p = f32x4x4::perspective_rh(std::f32::consts::FRAC_PI_2, 2.0, near, far);
+        let clip = |p: f32x4x4, z: f32| {
+            let v = p * f32x4::with_xyzw(0.0, 0.0, z, 1.0);
+            v.z() / v.w()
+        };
+        // right-handed camera looks along -z
+        assert!(clip(p, -near).abs() < 1e-6);
+        assert!((clip(p, -far) - 1.0).abs() < 1e-6);
+        assert_eq!(p[0].x(), 0.5);
+        assert!((p[1].y() - 1.0).abs() < 1e-6);
+
+        let p = f32x4x4::perspective_lh(std::f32::consts::FRAC_PI_2, 1.0, near, far);
+        assert!(clip(p, near).abs() < 1e-6);
+        assert!((clip(p, far) - 1.0).abs() < 1e-6);
+
+        let o = f32x4x4::ortho_rh(-2.0, 2.0, -1.0, 1.0, 1.0, 11.0);
+        assert_eq!(
+            o * f32x4::with_xyzw(2.0, 1.0, -1.0, 1.0),
+            f32x4::with_xyzw(1.0, 1.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            o * f32x4::with_xyzw(-2.0, -1.0, -11.0, 1.0),
+            f32x4::with_xyzw(-1.0, -1.0, 1.0, 1.0)
+        );
+        let o = f32x4x4::ortho_lh(0.0, 4.0, 0.0, 2.0, 0.0, 10.0);
+        assert_eq!(
+            o * f32x4::with_xyzw(4.0, 2.0, 10.0, 1.0),
+            f32x4::with_xyzw(1.0, 1.0, 1.0, 1.0)
+        );
+
+        let eye = f32x3::with_xyz(0.0, 0.0, 5.0);
+        let up = f32x3::with_xyz(0.0, 1.0, 0.0);
+        let v = f32x4x4::look_at_rh(eye, f32x3::with_xyz(0.0, 0.0, 0.0), up);
+        assert_eq!(v, f32x4x4::translate(0.0, 0.0, -5.0));
+        let v = f32x4x4::look_at_lh(eye, f32x3::with_xyz(0.0, 0.0, 0.0), up);
+        let origin = v * f32x4::with_xyzw(0.0, 0.0, 0.0, 1.0);
+        assert_eq!(origin, f32x4::with_xyzw(0.0, 0.0, 5.0, 1.0));
+    }
+
+    #[test]
+    fn layout() {
+        use std::mem::size_of;
+        assert_eq!(size_of::<f32x2x2>(), 16);
+        assert_eq!(size_of::<f32x3x2>(), 24);
+        assert_eq!(size_of::<f32x3x3>(), 48);
+        assert_eq!(size_of::<f32x4x4>(), 64);
+        assert_eq!(size_of::<f64x4x4>(), 128);
+
+        let m = f32x4x4::translate(1.0, 2.0, 3.0);
+        let floats: &[f32; 16] = unsafe { std::mem::transmute(&m) };
+        // columns[3] holds translation as in simd_float4x4
+        assert_eq!(&floats[12..], &[1.0, 2.0, 3.0, 1.0]);
+    }
+}
@@ -0,0 +1,369 @@
+//! Quaternions as in `<simd/quaternion.h>`, vector is `(ix, iy, iz, r)`.
+
+use std::ops;
+
+use super::{Simd, f32x3, f32x3x3, f32x4, f32x4x4, f64x3, f64x3x3, f64x4, f64x4x4};
+
+#[doc(alias = "simd_quatf")]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct quatf(pub f32x4);
+
+#[doc(alias = "simd_quatd")]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct quatd(pub f64x4);
+
+macro_rules! quat {
+    ($name:ident, $t:ty, $v3:ty, $v4:ty, $m3:ident, $m4:ident) => {
+        impl $name {
+            pub const fn identity() -> Self {
+                Self(Simd::from_array([0.0, 0.0, 0.0, 1.0]))
+            }
+
+            #[doc(alias = "simd_quaternion")]
+            pub const fn with_ix_iy_iz_r(ix: $t, iy: $t, iz: $t, r: $t) -> Self {
+                Self(Simd::from_array([ix, iy, iz, r]))
+            }
+
+            #[doc(alias = "simd_quaternion")]
+            pub fn with_imag_real(imag: $v3, real: $t) -> Self {
+                Self::with_ix_iy_iz_r(imag.x(), imag.y(), imag.z(), real)
+            }
+
+            /// Rotation around unit `axis` by `radians` counterclockwise.
+            #[doc(alias = "simd_quaternion")]
+            pub fn with_angle_axis(radians: $t, axis: $v3) -> Self {
+                let (sin, cos) = (radians * 0.5).sin_cos();
+                Self::with_imag_real(axis * sin, cos)
+            }
+
+            /// Shortest rotation taking unit vector `from` to unit vector `to`.
+            #[doc(alias = "simd_quaternion")]
+            pub fn with_from_to(from: $v3, to: $v3) -> Self {
+                // accurate while angle is not too big
+                if from.dot(to) >= 0.0 {
+                    return Self::with_from_to_reduced(from, to);
+                }
+                // otherwise go through the middle, any orthogonal one for opposite vectors
+                let sum = from + to;
+                let half = if sum.length_squared() == 0.0 {
+                    let a = from.abs();
+                    let axis = if a.x() <= a.y() && a.x() <= a.z() {
+                        <$v3>::with_xyz(1.0, 0.0, 0.0)
+                    } else if a.y() <= a.z() {
+                        <$v3>::with_xyz(0.0, 1.0, 0.0)
+                    } else {
+                        <$v3>::with_xyz(0.0, 0.0, 1.0)
+                    };
+                    from.cross(axis).normalize()
+                } else {
+                    sum.normalize()
+                };
+                Self::with_from_to_reduced(half, to) * Self::with_from_to_reduced(from, half)
+            }
+
+            fn with_from_to_reduced(from: $v3, to: $v3) -> Self {
+                let half = (from + to).normalize();
+                Self::with_imag_real(from.cross(half), from.dot(half))
+            }
+
+            /// Quaternion of rotation matrix.
+            #[doc(alias = "simd_quaternion")]
+            pub fn with_matrix(m: &$m3) -> Self {
+                let m = |c: usize, r: usize| m.0[c][r];
+                let trace = m(0, 0) + m(1, 1) + m(2, 2);
+                if trace >= 0.0 {
+                    let r = 2.0 * (1.0 + trace).sqrt();
+                    let i = 1.0 / r;
+                    Self::with_ix_iy_iz_r(
+                        (m(1, 2) - m(2, 1)) * i,
+                        (m(2, 0) - m(0, 2)) * i,
+                        (m(0, 1) - m(1, 0)) * i,
+                        r * 0.25,
+                    )
+                } else if m(0, 0) >= m(1, 1) && m(0, 0) >= m(2, 2) {
+                    let r = 2.0 * (1.0 + m(0, 0) - m(1, 1) - m(2, 2)).sqrt();
+                    let i = 1.0 / r;
+                    Self::with_ix_iy_iz_r(
+                        r * 0.25,
+                        (m(1, 0) + m(0, 1)) * i,
+                        (m(2, 0) + m(0, 2)) * i,
+                        (m(1, 2) - m(2, 1)) * i,
+                    )
+                } else if m(1, 1) >= m(2, 2) {
+                    let r = 2.0 * (1.0 - m(0, 0) + m(1, 1) - m(2, 2)).sqrt();
+                    let i = 1.0 / r;
+                    Self::with_ix_iy_iz_r(
+                        (m(1, 0) + m(0, 1)) * i,
+                        r * 0.25,
+                        (m(2, 1) + m(1, 2)) * i,
+                        (m(2, 0) - m(0, 2)) * i,
+                    )
+                } else {
+                    let r = 2.0 * (1.0 - m(0, 0) - m(1, 1) + m(2, 2)).sqrt();
+                    let i = 1.0 / r;
+                    Self::with_ix_iy_iz_r(
+                        (m(2, 0) + m(0, 2)) * i,
+                        (m(2, 1) + m(1, 2)) * i,
+                        r * 0.25,
+                        (m(0, 1) - m(1, 0)) * i,
+                    )
+                }
+            }
+
+            #[doc(alias = "simd_real")]
+            #[inline]
+            pub fn real(&self) -> $t {
+                self.0.w()
+            }
+
+            #[doc(alias = "simd_imag")]
+            #[inline]
+            pub fn imag(&self) -> $v3 {
+                self.0.xyz()
+            }
+
+            /// Rotation angle in radians.
+            #[doc(alias = "simd_angle")]
+            pub fn angle(&self) -> $t {
+                2.0 * self.imag().length().atan2(self.real())
+            }
+
+            /// Unit rotation axis.
+            #[doc(alias = "simd_axis")]
+            pub fn axis(&self) -> $v3 {
+                self.imag().normalize()
+            }
+
+            #[doc(alias = "simd_length")]
+            #[inline]
+            pub fn length(&self) -> $t {
+                self.0.length()
+            }
+
+            #[doc(alias = "simd_dot")]
+            #[inline]
+            pub fn dot(&self, other: Self) -> $t {
+                self.0.dot(other.0)
+            }
+
+            #[doc(alias = "simd_normalize")]
+            #[inline]
+            pub fn normalize(&self) -> Self {
+                Self(self.0.normalize())
+            }
+
+            #[doc(alias = "simd_conjugate")]
+            #[inline]
+            pub fn conjugate(&self) -> Self {
+                Self::with_imag_real(-self.imag(), self.real())
+            }
+
+            #[doc(alias = "simd_inverse")]
+            #[inline]
+            pub fn inverse(&self) -> Self {
+                Self(self.conjugate().0 / self.0.length_squared())
+            }
+
+            /// Rotates vector.
+            #[doc(alias = "simd_act")]
+            pub fn act(&self, v: $v3) -> $v3 {
+                (*self * Self::with_imag_real(v, 0.0) * self.inverse()).imag()
+            }
+
+            /// Spherical interpolation along the shortest arc.
+            #[doc(alias = "simd_slerp")]
+            pub fn slerp(&self, to: Self, t: $t) -> Self {
+                if self.dot(to) >= 0.0 {
+                    self.slerp_internal(to, t)
+                } else {
+                    self.slerp_internal(-to, t)
+                }
+            }
+
+            /// Spherical interpolation along the longest arc.
+            #[doc(alias = "simd_slerp_longest")]
+            pub fn slerp_longest(&self, to: Self, t: $t) -> Self {
+                if self.dot(to) >= 0.0 {
+                    self.slerp_internal(-to, t)
+                } else {
+                    self.slerp_internal(to, t)
+                }
+            }
+
+            fn slerp_internal(&self, to: Self, t: $t) -> Self {
+                fn sinc(x: $t) -> $t {
+                    if x == 0.0 { 1.0 } else { x.sin() / x }
+                }
+                let s = 1.0 - t;
+                let a = 2.0 * (self.0 - to.0).length().atan2((self.0 + to.0).length());
+                let r = 1.0 / sinc(a);
+                Self(self.0 * (sinc(s * a) * r * s) + to.0 * (sinc(t * a) * r * t)).normalize()
+            }
+        }
+
+        impl ops::Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl ops::Sub for $name {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl ops::Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl ops::Mul<$t> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: $t) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        /// Hamilton product, rotation by `rhs` then by `self`.
+        impl ops::Mul for $name {
+            type Output = Self;
+
+            #[doc(alias = "simd_mul")]
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                let (v1, r1) = (self.imag(), self.real());
+                let (v2, r2) = (rhs.imag(), rhs.real());
+                Self::with_imag_real(v2 * r1 + v1 * r2 + v1.cross(v2), r1 * r2 - v1.dot(v2))
+            }
+        }
+
+        impl $m3 {
+            /// Rotation matrix of unit quaternion.
+            #[doc(alias = "simd_matrix3x3")]
+            pub fn with_quat(q: $name) -> Self {
+                let [x, y, z, w] = q.0.0;
+                let (xx, yy, zz) = (x * x, y * y, z * z);
+                let (xy, xz, yz) = (x * y, x * z, y * z);
+                let (xw, yw, zw) = (x * w, y * w, z * w);
+                Self([
+                    <$v3>::with_xyz(1.0 - 2.0 * (yy + zz), 2.0 * (xy + zw), 2.0 * (xz - yw)),
+                    <$v3>::with_xyz(2.0 * (xy - zw), 1.0 - 2.0 * (xx + zz), 2.0 * (yz + xw)),
+                    <$v3>::with_xyz(2.0 * (xz + yw), 2.0 * (yz - xw), 1.0 - 2.0 * (xx + yy)),
+                ])
+            }
+        }
+
+        impl $m4 {
+            /// Rotation matrix of unit quaternion.
+            #[doc(alias = "simd_matrix4x4")]
+            pub fn with_quat(q: $name) -> Self {
+                let [a, b, c] = $m3::with_quat(q).0;
+                Self([
+                    <$v4>::with_xyzw(a.x(), a.y(), a.z(), 0.0),
+                    <$v4>::with_xyzw(b.x(), b.y(), b.z(), 0.0),
+                    <$v4>::with_xyzw(c.x(), c.y(), c.z(), 0.0),
+                    <$v4>::with_xyzw(0.0, 0.0, 0.0, 1.0),
+                ])
+            }
+        }
+    };
+}
+
+quat!(quatf, f32, f32x3, f32x4, f32x3x3, f32x4x4);
+quat!(quatd, f64, f64x3, f64x4, f64x3x3, f64x4x4);
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use crate::simd::{f32x3, f32x3x3, f32x4x4, quatd, quatf};
+
+    fn assert_near(a: f32x3, b: f32x3) {
+        assert!((a - b).abs().reduce_max() < 1e-6, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn rotation() {
+        let z = f32x3::with_xyz(0.0, 0.0, 1.0);
+        let x = f32x3::with_xyz(1.0, 0.0, 0.0);
+        let y = f32x3::with_xyz(0.0, 1.0, 0.0);
+        let q = quatf::with_angle_axis(FRAC_PI_2, z);
+        assert_near(q.act(x), y);
+        assert!((q.angle() - FRAC_PI_2).abs() < 1e-6);
+        assert_near(q.axis(), z);
+        assert_near((q * q).act(x), -x);
+        assert_near(q.inverse().act(y), x);
+        assert!(
+            ((q * q.conjugate()).0 - quatf::identity().0)
+                .abs()
+                .reduce_max()
+                < 1e-6
+        );
+
+        let m = f32x3x3::with_quat(q);
+        assert_near(m * x, y);
+        // image of y
+        assert_near(f32x4x4::rotate(FRAC_PI_2, z)[1].xyz(), -x);
+
+        // matrix round trip for each branch
+        for q in [
+            q,
+            quatf::with_angle_axis(PI * 0.9, x),
+            quatf::with_angle_axis(PI * 0.9, y),
+            quatf::with_angle_axis(PI * 0.9, z),
+        ] {
+            let r = quatf::with_matrix(&f32x3x3::with_quat(q));
+            assert!((r.0 - q.0).abs().reduce_max() < 1e-6, "{r:?} != {q:?}");
+        }
+    }
+
+    #[test]
+    fn from_to() {
+        let x = f32x3::with_xyz(1.0, 0.0, 0.0);
+        let y = f32x3::with_xyz(0.0, 1.0, 0.0);
+        let v = f32x3::with_xyz(-1.0, 1.0, 0.0).normalize();
+        assert_near(quatf::with_from_to(x, y).act(x), y);
+        assert_near(quatf::with_from_to(x, v).act(x), v);
+        assert_near(quatf::with_from_to(x, -x).act(x), -x);
+        assert!((quatf::with_from_to(x, -x).length() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn slerp() {
+        let z = f32x3::with_xyz(0.0, 0.0, 1.0);
+        let a = quatf::identity();
+        let b = quatf::with_angle_axis(FRAC_PI_2, z);
+        assert_eq!(a.slerp(b, 0.0), a);
+        assert!((a.slerp(b, 1.0).0 - b.0).abs().reduce_max() < 1e-6);
+
+        let mid = a.slerp(b, 0.5);
+        assert!((mid.angle() - FRAC_PI_2 * 0.5).abs() < 1e-6);
+        // -b is the same rotation, shortest arc doesn't care
+        assert!((a.slerp(-b, 0.5).0 - mid.0).abs().reduce_max() < 1e-6);
+
+        let long = a.slerp_longest(b, 0.5);
+        let x = f32x3::with_xyz(1.0, 0.0, 0.0);
+        let expected = f32x3::with_xyz(-1.0, -1.0, 0.0).normalize();
+        assert_near(long.act(x), expected);
+
+        let d = quatd::identity().slerp(quatd::with_ix_iy_iz_r(0.0, 1.0, 0.0, 0.0), 0.5);
+        assert!((d.angle() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    }
+}
@@ -1,4 +1,8 @@
-use std::hash::{Hash, Hasher};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops,
+};
 
 macro_rules! accessors {
     (x) => {
@@ -138,9 +142,10 @@ macro_rules! accessors {
         accessors!(x, y);
         accessors!(z);
 
+        /// Three element vector with padding lane, like `float4.xyz` in simd.
         #[inline]
-        pub fn xyz(&self) -> Simd<T, 3, 3> {
-            Simd([self.x(), self.y(), self.z()])
+        pub fn xyz(&self) -> Simd<T, 4, 3> {
+            Simd::from_array(self.0)
         }
     };
     (x, y, z, w) => {
@@ -149,17 +154,100 @@ macro_rules! accessors {
     };
 }
 
-#[derive(Debug, Copy, Clone)]
+/// Vector of `N` elements stored in `LANES` lanes.
+///
+/// Size, alignment and lane offsets match `<simd/simd.h>` vectors, 3 element
+/// vectors have padding lane, so `f32x3` is 16 bytes aligned to 16 and can be
+/// embedded in `#[repr(C)]` structs shared with Metal.
+///
+/// Arithmetic operates on the first `N` elements, padding is kept as is.
+#[derive(Copy, Clone)]
 #[repr(C)]
-pub struct Simd<T, const LANES: usize, const N: usize>([T; LANES]);
+pub struct Simd<T: Lane<LANES>, const LANES: usize, const N: usize>(
+    pub(crate) [T; LANES],
+    T::Align,
+);
 
-impl<T: Default + Copy, const LANES: usize, const N: usize> Default for Simd<T, LANES, N> {
+/// Element of vector with `LANES` lanes, selects alignment of the vector.
+pub trait Lane<const LANES: usize>: Sized {
+    /// Zero sized type with alignment of `<simd/simd.h>` vector.
+    type Align: Copy;
+
+    const ALIGN: Self::Align;
+}
+
+macro_rules! align {
+    ($($Align:ident($n:literal)),*) => {
+        $(
+            #[doc(hidden)]
+            #[derive(Copy, Clone)]
+            #[repr(align($n))]
+            pub struct $Align;
+        )*
+    };
+}
+
+align!(
+    Align1(1),
+    Align2(2),
+    Align4(4),
+    Align8(8),
+    Align16(16),
+    Align32(32)
+);
+
+macro_rules! lanes {
+    ($($t:ty: $($lanes:literal => $Align:ident),*;)*) => {
+        $($(
+            impl Lane<$lanes> for $t {
+                type Align = $Align;
+
+                const ALIGN: $Align = $Align;
+            }
+        )*)*
+    };
+}
+
+lanes! {
+    i8: 1 => Align1, 2 => Align2, 4 => Align4;
+    u8: 1 => Align1, 2 => Align2, 4 => Align4;
+    i16: 1 => Align2, 2 => Align4, 4 => Align8;
+    u16: 1 => Align2, 2 => Align4, 4 => Align8;
+    i32: 1 => Align4, 2 => Align8, 4 => Align16;
+    u32: 1 => Align4, 2 => Align8, 4 => Align16;
+    f32: 1 => Align4, 2 => Align8, 4 => Align16;
+    i64: 1 => Align8, 2 => Align16, 4 => Align32;
+    u64: 1 => Align8, 2 => Align16, 4 => Align32;
+    f64: 1 => Align8, 2 => Align16, 4 => Align32;
+}
+
+impl<T: Lane<LANES>, const LANES: usize, const N: usize> Simd<T, LANES, N> {
+    /// Vector with `lanes`, including padding one.
+    #[inline]
+    pub const fn from_array(lanes: [T; LANES]) -> Self {
+        Self(lanes, T::ALIGN)
+    }
+}
+
+impl<T: Lane<LANES> + fmt::Debug, const LANES: usize, const N: usize> fmt::Debug
+    for Simd<T, LANES, N>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Simd").field(&self.0).finish()
+    }
+}
+
+impl<T: Lane<LANES> + Default + Copy, const LANES: usize, const N: usize> Default
+    for Simd<T, LANES, N>
+{
     fn default() -> Self {
-        Self([T::default(); LANES])
+        Self::from_array([T::default(); LANES])
     }
 }
 
-impl<T: PartialEq, const LANES: usize, const N: usize> PartialEq for Simd<T, LANES, N> {
+impl<T: Lane<LANES> + PartialEq, const LANES: usize, const N: usize> PartialEq
+    for Simd<T, LANES, N>
+{
     fn eq(&self, other: &Self) -> bool {
         for i in 0..N {
             if self.0[i] != other.0[i] {
@@ -171,7 +259,9 @@ impl<T: PartialEq, const LANES: usize, const N: usize> PartialEq for Simd<T, LAN
     }
 }
 
-impl<T, const LANES: usize, const N: usize> std::ops::Index<usize> for Simd<T, LANES, N> {
+impl<T: Lane<LANES>, const LANES: usize, const N: usize> std::ops::Index<usize>
+    for Simd<T, LANES, N>
+{
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
@@ -179,53 +269,183 @@ impl<T, const LANES: usize, const N: usize> std::ops::Index<usize> for Simd<T, L
     }
 }
 
-impl<T, const LANES: usize, const N: usize> std::ops::IndexMut<usize> for Simd<T, LANES, N> {
+impl<T: Lane<LANES>, const LANES: usize, const N: usize> std::ops::IndexMut<usize>
+    for Simd<T, LANES, N>
+{
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const LANES: usize, const N: usize> Eq for Simd<f32, LANES, N> {}
+impl<T: Lane<LANES> + Copy, const LANES: usize, const N: usize> Simd<T, LANES, N> {
+    /// Vector with all lanes set to `val`.
+    #[inline]
+    pub const fn splat(val: T) -> Self {
+        Self::from_array([val; LANES])
+    }
+
+    #[inline]
+    pub(crate) fn map(mut self, f: impl Fn(T) -> T) -> Self {
+        for i in 0..N {
+            self.0[i] = f(self.0[i]);
+        }
+        self
+    }
+
+    #[inline]
+    pub(crate) fn zip_map(mut self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+        for i in 0..N {
+            self.0[i] = f(self.0[i], other.0[i]);
+        }
+        self
+    }
+
+    #[inline]
+    pub(crate) fn fold<A>(&self, init: A, f: impl Fn(A, T) -> A) -> A {
+        let mut res = init;
+        for i in 0..N {
+            res = f(res, self.0[i]);
+        }
+        res
+    }
+}
+
+macro_rules! bin_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl<T: Lane<LANES> + Copy + ops::$Op<Output = T>, const LANES: usize, const N: usize>
+            ops::$Op for Simd<T, LANES, N>
+        {
+            type Output = Self;
 
-impl<const LANES: usize, const N: usize> Hash for Simd<f32, LANES, N> {
+            #[inline]
+            fn $op(self, rhs: Self) -> Self {
+                self.zip_map(rhs, |a, b| a.$op(b))
+            }
+        }
+
+        impl<T: Lane<LANES> + Copy + ops::$Op<Output = T>, const LANES: usize, const N: usize>
+            ops::$Op<T> for Simd<T, LANES, N>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $op(self, rhs: T) -> Self {
+                self.map(|a| a.$op(rhs))
+            }
+        }
+
+        impl<T: Lane<LANES> + Copy + ops::$Op<Output = T>, const LANES: usize, const N: usize>
+            ops::$OpAssign for Simd<T, LANES, N>
+        {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = ops::$Op::$op(*self, rhs);
+            }
+        }
+
+        impl<T: Lane<LANES> + Copy + ops::$Op<Output = T>, const LANES: usize, const N: usize>
+            ops::$OpAssign<T> for Simd<T, LANES, N>
+        {
+            #[inline]
+            fn $op_assign(&mut self, rhs: T) {
+                *self = ops::$Op::$op(*self, rhs);
+            }
+        }
+    };
+}
+
+bin_op!(Add, add, AddAssign, add_assign);
+bin_op!(Sub, sub, SubAssign, sub_assign);
+bin_op!(Mul, mul, MulAssign, mul_assign);
+bin_op!(Div, div, DivAssign, div_assign);
+
+impl<T: Lane<LANES> + Copy + ops::Neg<Output = T>, const LANES: usize, const N: usize> ops::Neg
+    for Simd<T, LANES, N>
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        self.map(|a| -a)
+    }
+}
+
+macro_rules! scalar_lhs {
+    ($($t:ty),*) => {
+        $(
+            impl<const LANES: usize, const N: usize> ops::Mul<Simd<$t, LANES, N>> for $t
+            where
+                $t: Lane<LANES>,
+            {
+                type Output = Simd<$t, LANES, N>;
+
+                #[inline]
+                fn mul(self, rhs: Simd<$t, LANES, N>) -> Self::Output {
+                    rhs * self
+                }
+            }
+
+            impl<const LANES: usize, const N: usize> ops::Add<Simd<$t, LANES, N>> for $t
+            where
+                $t: Lane<LANES>,
+            {
+                type Output = Simd<$t, LANES, N>;
+
+                #[inline]
+                fn add(self, rhs: Simd<$t, LANES, N>) -> Self::Output {
+                    rhs + self
+                }
+            }
+        )*
+    };
+}
+
+scalar_lhs!(i8, u8, i16, u16, i32, u32, i64, u64, f32, f64);
+
+impl<const LANES: usize, const N: usize> Eq for Simd<f32, LANES, N> where f32: Lane<LANES> {}
+
+impl<const LANES: usize, const N: usize> Hash for Simd<f32, LANES, N>
+where
+    f32: Lane<LANES>,
+{
     fn hash<H: Hasher>(&self, state: &mut H) {
         let s = format!("{self:?}");
         s.hash(state);
     }
 }
 
-impl<T: Copy> Simd<T, 1, 1> {
+impl<T: Lane<1> + Copy> Simd<T, 1, 1> {
     #[inline]
     pub fn with_x(x: T) -> Self {
-        Self([x])
+        Self::from_array([x])
     }
 
     accessors!(x);
 }
 
-impl<T: Copy> Simd<T, 2, 2> {
+impl<T: Lane<2> + Copy> Simd<T, 2, 2> {
     #[inline]
     pub const fn with_xy(x: T, y: T) -> Self {
-        Self([x, y])
+        Self::from_array([x, y])
     }
 
     #[inline]
     pub fn with_rg(r: T, g: T) -> Self {
-        Self([r, g])
+        Self::from_array([r, g])
     }
 
     accessors!(x, y);
 }
 
-impl<T: Default + Copy> Simd<T, 4, 3> {
+impl<T: Lane<2> + Lane<4> + Default + Copy> Simd<T, 4, 3> {
     #[inline]
     pub fn with_xyz(x: T, y: T, z: T) -> Self {
-        Self([x, y, z, Default::default()])
+        Self::from_array([x, y, z, Default::default()])
     }
 
     #[inline]
     pub fn with_rgb(r: T, g: T, b: T) -> Self {
-        Self([r, g, b, Default::default()])
+        Self::from_array([r, g, b, Default::default()])
     }
 
     accessors!(x, y, z);
@@ -234,44 +454,58 @@ impl<T: Default + Copy> Simd<T, 4, 3> {
 impl Simd<f32, 4, 3> {
     #[inline]
     pub const fn with_xyz_f32(x: f32, y: f32, z: f32) -> Self {
-        Self([x, y, z, 0.0])
+        Self::from_array([x, y, z, 0.0])
     }
 
     #[inline]
     pub const fn with_rgb_f32(r: f32, g: f32, b: f32) -> Self {
-        Self([r, g, b, 0.0])
+        Self::from_array([r, g, b, 0.0])
     }
 }
 
-impl<T: Copy> Simd<T, 4, 4> {
+impl<T: Lane<2> + Lane<4> + Copy> Simd<T, 4, 4> {
     #[inline]
     pub const fn with_xyzw(x: T, y: T, z: T, w: T) -> Self {
-        Self([x, y, z, w])
+        Self::from_array([x, y, z, w])
     }
 
     #[inline]
     pub const fn with_rgba(r: T, g: T, b: T, a: T) -> Self {
-        Self([r, g, b, a])
+        Self::from_array([r, g, b, a])
     }
 
     accessors!(x, y, z, w);
 }
 
+impl Simd<f64, 4, 3> {
+    #[inline]
+    pub const fn with_xyz_f64(x: f64, y: f64, z: f64) -> Self {
+        Self::from_array([x, y, z, 0.0])
+    }
+}
+
+impl Simd<f64, 4, 4> {
+    #[inline]
+    pub const fn with_xyzw_f64(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self::from_array([x, y, z, w])
+    }
+}
+
 impl Simd<f32, 4, 4> {
     #[inline]
     pub const fn with_xyzw_f32(x: f32, y: f32, z: f32, w: f32) -> Self {
-        Self([x, y, z, w])
+        Self::from_array([x, y, z, w])
     }
 
     #[inline]
     pub const fn with_rgba_f32(r: f32, g: f32, b: f32, a: f32) -> Self {
-        Self([r, g, b, a])
+        Self::from_array([r, g, b, a])
     }
 }
 
 #[cfg(test)]
 pub mod tests {
-    use crate::simd::{f32x2, f32x4x4};
+    use crate::simd::{f32x2, f32x3, f32x4, f32x4x4, i32x4};
 
     #[test]
     fn basics() {
@@ -285,4 +519,27 @@ pub mod tests {
         f[0][1] = 10.0;
         assert_eq!(f[0][1], 10.0);
     }
+
+    #[test]
+    fn arithmetic() {
+        let a = f32x4::with_xyzw(1.0, 2.0, 3.0, 4.0);
+        let b = f32x4::splat(2.0);
+        assert_eq!(a + b, f32x4::with_xyzw(3.0, 4.0, 5.0, 6.0));
+        assert_eq!(a - b, f32x4::with_xyzw(-1.0, 0.0, 1.0, 2.0));
+        assert_eq!(a * b, 2.0 * a);
+        assert_eq!(a / 2.0, f32x4::with_xyzw(0.5, 1.0, 1.5, 2.0));
+        assert_eq!(-a, f32x4::with_xyzw(-1.0, -2.0, -3.0, -4.0));
+
+        let mut c = a;
+        c += b;
+        c *= 2.0;
+        assert_eq!(c, f32x4::with_xyzw(6.0, 8.0, 10.0, 12.0));
+
+        // padding lane is not touched
+        let v = f32x3::with_xyz(1.0, 2.0, 3.0) / f32x3::with_xyz(1.0, 1.0, 1.0);
+        assert_eq!(v.0[3], 0.0);
+
+        let i = i32x4::with_xyzw(1, 2, 3, 4) * 3 - 1;
+        assert_eq!(i, i32x4::with_xyzw(2, 5, 8, 11));
+    }
 }
@@ -44,7 +44,7 @@ impl ClassifySoundRequest {
     pub fn known_classifications(&self) -> arc::R<ns::Array<ns::String>>;
 }
 
-#[link(name = "sn", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "sn", kind = "static"))]
 unsafe extern "C" {
     static SN_CLASSIFY_SOUND_REQUEST: &'static objc::Class<ClassifySoundRequest>;
 }
@@ -30,7 +30,7 @@ impl Color {
     }
 }
 
-#[link(name = "ui", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ui", kind = "static"))]
 unsafe extern "C" {
     static UI_COLOR: &'static objc::Class<Color>;
 }
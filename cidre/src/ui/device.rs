@@ -168,7 +168,7 @@ impl Device {
     pub fn orientation(&self) -> Orientation;
 }
 
-#[link(name = "ui", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ui", kind = "static"))]
 unsafe extern "C" {
     static UI_DEVICE: &'static objc::Class<Device>;
 }
@@ -34,7 +34,7 @@ impl Image {
     }
 }
 
-#[link(name = "ui", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ui", kind = "static"))]
 unsafe extern "C" {
     static UI_IMAGE: &'static objc::Class<Image>;
 }
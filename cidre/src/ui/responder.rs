@@ -6,7 +6,7 @@ define_obj_type!(
     UI_RESPONDER
 );
 
-#[link(name = "ui", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ui", kind = "static"))]
 unsafe extern "C" {
     static UI_RESPONDER: &'static objc::Class<Responder>;
 }
@@ -33,7 +33,7 @@ define_obj_type!(
 
 impl SceneDelegate for AnySceneDelegate {}
 
-#[link(name = "ui", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ui", kind = "static"))]
 unsafe extern "C" {
     static UI_SCENE: &'static objc::Class<Scene>;
 }
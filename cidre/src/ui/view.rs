@@ -25,7 +25,7 @@ impl View {
     pub fn set_hidden(&self, val: bool);
 }
 
-#[link(name = "ui", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ui", kind = "static"))]
 unsafe extern "C" {
     static UI_VIEW: &'static objc::Class<View>;
 }
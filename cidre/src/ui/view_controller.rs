@@ -17,7 +17,7 @@ impl ViewController {
     pub fn set_preferred_content_size(&mut self, val: cg::Size);
 }
 
-#[link(name = "ui", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ui", kind = "static"))]
 unsafe extern "C" {
     static UI_VIEW_CONTROLLER: &'static objc::Class<ViewController>;
 }
@@ -43,7 +43,7 @@ impl UserNotificationCenter {
     pub fn current() -> &'static mut Self;
 }
 
-#[link(name = "un", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "un", kind = "static"))]
 unsafe extern "C" {
     static UN_USER_NOTIFICATION_CENTER: &'static objc::Class<UserNotificationCenter>;
 }
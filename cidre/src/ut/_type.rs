@@ -59,7 +59,7 @@ impl Type {
     pub fn supertypes(&self) -> arc::R<ns::Set<Self>>;
 }
 
-#[link(name = "ut", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "ut", kind = "static"))]
 unsafe extern "C" {
     static UT_TYPE: &'static objc::Class<Type>;
 }
@@ -88,26 +88,26 @@ pub struct FftSetup<T>(std::ffi::c_void, std::marker::PhantomData<T>);
 
 struct FftVt<T> {
     transform_io: unsafe extern "C-unwind" fn(
-        __Setup: *mut FftSetup<T>,
-        __C: *mut SplitComplex<T>,
-        __IC: Stride,
-        __Log2N: Len,
-        __Direction: FftDirection,
+        setup: *mut FftSetup<T>,
+        c: *mut SplitComplex<T>,
+        ic: Stride,
+        log2n: Len,
+        direction: FftDirection,
     ),
     transform: unsafe extern "C-unwind" fn(
-        __Setup: *mut FftSetup<T>,
-        __C: *const SplitComplex<T>,
-        __IC: Stride,
-        __Buffer: *mut SplitComplex<T>,
-        __Log2N: Len,
-        __Direction: FftDirection,
+        setup: *mut FftSetup<T>,
+        c: *const SplitComplex<T>,
+        ic: Stride,
+        buffer: *mut SplitComplex<T>,
+        log2n: Len,
+        direction: FftDirection,
     ),
     zrip: unsafe extern "C-unwind" fn(
-        __Setup: *mut FftSetup<T>,
-        __C: *const SplitComplex<T>,
-        __IC: Stride,
-        __Log2N: Len,
-        __Direction: FftDirection,
+        setup: *mut FftSetup<T>,
+        c: *const SplitComplex<T>,
+        ic: Stride,
+        log2n: Len,
+        direction: FftDirection,
     ),
 
     destroy: unsafe extern "C-unwind" fn(*mut FftSetup<T>),
@@ -140,7 +140,8 @@ pub struct Fft<T>(NonNull<FftSetup<T>>, FftVt<T>);
 impl<T> Fft<T> {
     #[inline]
     pub fn zr_io(&mut self, re_io: &mut [T], im_io: &mut [T], direction: FftDirection) {
-        let log2n = (re_io.len() as f64).log2().ceil();
+        // real signal of 2 * len values
+        let log2n = (re_io.len() as f64).log2().ceil() + 1.0;
         let mut split = SplitComplex::new_mut(re_io, im_io);
         unsafe { (self.1.zrip)(self.0.as_mut(), &mut split, 1, log2n as _, direction) }
     }
@@ -1269,6 +1270,13 @@ pub fn u16_f32(a: &[u16], c: &mut [f32]) {
     unsafe { _u16_f32(a.as_ptr(), 1, c.as_mut_ptr(), 1, n) };
 }
 
+/// Portable Rust implementation of Accelerate functions below.
+#[cfg(feature = "vdsp_soft")]
+mod soft;
+#[cfg(feature = "vdsp_soft")]
+pub use soft::*;
+
+#[cfg(not(feature = "vdsp_soft"))]
 #[link(name = "Accelerate", kind = "framework")]
 unsafe extern "C-unwind" {
     #[link_name = "vDSP_vadd"]
@@ -2129,9 +2137,9 @@ mod tests {
     fn synth_signal(freq_amp_pairs: &[(f32, f32)], len: usize) -> Vec<f32> {
         let mut res = vec![0.0f32; len];
 
-        for i in 0..len {
+        for (i, v) in res.iter_mut().enumerate() {
             let n_index = (i as f32) / ((len - 1) as f32);
-            res[i] = freq_amp_pairs.iter().fold(0.0f32, |acc, pair| {
+            *v = freq_amp_pairs.iter().fold(0.0f32, |acc, pair| {
                 println!("{pair:?}");
                 let r = acc + (n_index + pair.0 * TAU).sin() * pair.1;
                 println!("{r:?}");
@@ -2189,4 +2197,272 @@ mod tests {
         assert_eq!(c[2].re, 3.0);
         assert_eq!(c[2].im, 6.0);
     }
+
+    // conformance of both backends, known vectors and naive DFT
+
+    fn signal(n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| ((i * 37 + 11) % 101) as f64 / 50.0 - 1.0)
+            .collect()
+    }
+
+    fn dft(re: &[f64], im: &[f64], sign: f64) -> (Vec<f64>, Vec<f64>) {
+        let n = re.len();
+        let mut out = (vec![0.0; n], vec![0.0; n]);
+        for k in 0..n {
+            for j in 0..n {
+                let (s, c) =
+                    (sign * std::f64::consts::TAU * ((j * k) % n) as f64 / n as f64).sin_cos();
+                out.0[k] += re[j] * c - im[j] * s;
+                out.1[k] += re[j] * s + im[j] * c;
+            }
+        }
+        out
+    }
+
+    #[track_caller]
+    fn assert_close(a: &[f64], b: &[f64], tol: f64) {
+        assert_eq!(a.len(), b.len());
+        let scale = b.iter().fold(1.0f64, |m, v| m.max(v.abs()));
+        for (i, (a, b)) in a.iter().zip(b).enumerate() {
+            assert!((a - b).abs() <= tol * scale, "{i}: {a} vs {b}");
+        }
+    }
+
+    fn f64s(a: &[f32]) -> Vec<f64> {
+        a.iter().map(|&v| v as f64).collect()
+    }
+
+    #[test]
+    fn conformance_vectors() {
+        let a = [1.0f32, -2.0, 3.0, -4.0];
+        let b = [0.5f32, 4.0, -2.0, 8.0];
+        let mut c = [0.0f32; 4];
+        let mut d = [0.0f32; 4];
+
+        vdsp::sub_f32(&a, &b, &mut c);
+        assert_eq!(c, [0.5, -6.0, 5.0, -12.0]);
+        vdsp::div_f32(&a, &b, &mut c);
+        assert_eq!(c, [2.0, -0.5, -1.5, -0.5]);
+        vdsp::ssq_f32(&a, &mut c);
+        assert_eq!(c, [1.0, -4.0, 9.0, -16.0]);
+        vdsp::neg_f32(&a, &mut c);
+        assert_eq!(c, [-1.0, 2.0, -3.0, 4.0]);
+        vdsp::ma_f32(&a, &b, &a, &mut d);
+        assert_eq!(d, [1.5, -10.0, -3.0, -36.0]);
+        vdsp::tmerg_f32(&a, &b, &mut d);
+        assert_close(&f64s(&d), &[1.0, 0.0, -1.0 / 3.0, 8.0], 1e-6);
+        vdsp::ramp_f32(&1.0, &-0.5, &mut d);
+        assert_eq!(d, [1.0, 0.5, 0.0, -0.5]);
+
+        let mut start = 2.0f32;
+        vdsp::rampmul_f32(&a, &mut start, &0.5, &mut d);
+        assert_eq!(d, [2.0, -5.0, 9.0, -14.0]);
+        assert_eq!(start, 4.0);
+
+        let mut i = [0i32; 4];
+        vdsp::sub_i32(&[5, 6, 7, 8], &[1, 2, 3, 4], &mut i);
+        assert_eq!(i, [4, 4, 4, 4]);
+        vdsp::div_i32(&[5, -6, 7, 8], &[2, 2, 3, 4], &mut i);
+        assert_eq!(i, [2, -3, 2, 2]);
+        vdsp::abs_i32(&[-1, 2, -3, 0], &mut i);
+        assert_eq!(i, [1, 2, 3, 0]);
+        vdsp::eqv_i32(&[0, -1, 0b1010, 7], &[0, 0, 0b0110, 7], &mut i);
+        assert_eq!(i, [-1, 0, !0b1100, -1]);
+
+        vdsp::i16_f32(&[-32768, 0, 1, 32767], &mut c);
+        assert_eq!(c, [-32768.0, 0.0, 1.0, 32767.0]);
+        vdsp::u16_f32(&[0, 1, 65535, 2], &mut c);
+        assert_eq!(c, [0.0, 1.0, 65535.0, 2.0]);
+
+        let mut e = [0.0f64; 4];
+        vdsp::f32_f64(&a, &mut e);
+        vdsp::f64_f32(&e, &mut c);
+        assert_eq!(c, a);
+
+        let x = [1.0f32, 10.0, 100.0, 1000.0];
+        unsafe {
+            vdsp::_dbcon_f32(x.as_ptr(), 1, &10.0, c.as_mut_ptr(), 1, 4, 1);
+            assert_eq!(c, [-20.0, 0.0, 20.0, 40.0]);
+            vdsp::_dbcon_f32(x.as_ptr(), 1, &1.0, c.as_mut_ptr(), 1, 4, 0);
+            assert_eq!(c, [0.0, 10.0, 20.0, 30.0]);
+            vdsp::_clip_f32(a.as_ptr(), 1, &-2.5, &2.0, c.as_mut_ptr(), 1, 4);
+            assert_eq!(c, [1.0, -2.0, 2.0, -2.5]);
+        }
+
+        // strides
+        let a = [1.0f32, -1.0, 2.0, -1.0, 3.0, -1.0];
+        let b = [10.0f32, 20.0, 30.0];
+        let mut c = [0.0f32; 6];
+        unsafe {
+            vdsp::_add_f32(a.as_ptr(), 2, b.as_ptr(), 1, c.as_mut_ptr(), 2, 3);
+            assert_eq!(c, [11.0, 0.0, 22.0, 0.0, 33.0, 0.0]);
+            vdsp::_sub_f32(b.as_ptr(), 1, a.as_ptr(), 2, c.as_mut_ptr(), 1, 3);
+            assert_eq!(c[..3], [-9.0, -18.0, -27.0]);
+            vdsp::_smul_f32(a.as_ptr().add(1), 2, &3.0, c.as_mut_ptr(), 2, 3);
+            assert_eq!(c, [-3.0, -18.0, -3.0, 0.0, -3.0, 0.0]);
+            vdsp::_clr_f32(c.as_mut_ptr().add(1), 2, 3);
+            assert_eq!(c, [-3.0, 0.0, -3.0, 0.0, -3.0, 0.0]);
+        }
+        assert_eq!(vdsp::se_stride_f32(&a, 2), 6.0);
+        assert_eq!(vdsp::max_stride_f32(&a[1..], 2), -1.0);
+        let mut dot = 0.0f32;
+        unsafe { vdsp::_dotpr_f32(a.as_ptr(), 2, b.as_ptr(), 1, &mut dot, 3) };
+        assert_eq!(dot, 140.0);
+
+        // split complex
+        let c = [
+            vdsp::Complex { re: 1.0, im: 2.0 },
+            vdsp::Complex { re: 3.0, im: 4.0 },
+        ];
+        let (mut re, mut im) = ([0.0f64; 2], [0.0f64; 2]);
+        vdsp::ctoz_f64(&c, &mut re, &mut im);
+        assert_eq!((re, im), ([1.0, 3.0], [2.0, 4.0]));
+        let mut spec = [1.0f64, 0.0];
+        vdsp::zaspec_f64(&SplitComplex::new(&re, &im), &mut spec);
+        assert_eq!(spec, [6.0, 25.0]);
+        let (mut re, mut im) = ([0.0f32; 2], [0.0f32; 2]);
+        vdsp::actoz_f32(&[1.0, 2.0, 3.0, 4.0], &mut re, &mut im);
+        assert_eq!((re, im), ([1.0, 3.0], [2.0, 4.0]));
+    }
+
+    #[test]
+    fn conformance_reductions() {
+        // lanes and remainder, exact in f32
+        let a: Vec<f32> = (0..1003).map(|i| (i % 7) as f32 - 3.0).collect();
+        let b: Vec<f32> = (0..1003).map(|i| (i % 5) as f32 - 1.0).collect();
+        let sum = |f: &dyn Fn(f32, f32) -> f32| a.iter().zip(&b).map(|(&a, &b)| f(a, b)).sum();
+
+        assert_eq!(vdsp::se_f32(&a), sum(&|a, _| a));
+        assert_eq!(vdsp::semg_f32(&a), sum(&|a, _| a.abs()));
+        assert_eq!(vdsp::sesq_f32(&a), sum(&|a, _| a * a));
+        assert_eq!(vdsp::svs_f32(&a), sum(&|a, _| a * a.abs()));
+        assert_eq!(vdsp::dotpr_f32(&a, &b), sum(&|a, b| a * b));
+        assert_eq!(
+            vdsp::distance_sq_f32(&a, &b),
+            sum(&|a, b| (a - b) * (a - b))
+        );
+        assert_eq!(vdsp::mean_f32(&a), sum(&|a, _| a) / 1003.0);
+        assert_eq!(vdsp::mean_sq_f32(&a), sum(&|a, _| a * a) / 1003.0);
+
+        assert_eq!(vdsp::max_f32(&a), 3.0);
+        assert_eq!(vdsp::min_f32(&a), -3.0);
+        assert_eq!(vdsp::maxmg_f32(&b), 3.0);
+        assert_eq!(vdsp::minmg_f32(&b), 0.0);
+        assert_eq!(vdsp::max_f32(&[]), f32::NEG_INFINITY);
+
+        let x = signal(1000);
+        let se: f64 = x.iter().sum();
+        assert!((vdsp::se_f64(&x) - se).abs() < 1e-12);
+        assert!((vdsp::mean_f64(&x) - se / 1000.0).abs() < 1e-15);
+        let x32: Vec<f32> = x.iter().map(|&v| v as f32).collect();
+        assert!((vdsp::se_f32(&x32) as f64 - se).abs() < 1e-3);
+    }
+
+    #[test]
+    fn conformance_fft() {
+        let mut fft32 = vdsp::Fft::new_f32(10, vdsp::FftRadix::_2).unwrap();
+        let mut fft64 = vdsp::Fft::new_f64(10, vdsp::FftRadix::_2).unwrap();
+        for log2n in 0..=10 {
+            let n = 1usize << log2n;
+            let (re, im) = (signal(n), signal(2 * n)[n..].to_vec());
+            let (x_re, x_im) = dft(&re, &im, -1.0);
+
+            // unscaled forward, inverse scaled by n
+            let (mut r, mut i) = (re.clone(), im.clone());
+            fft64.forward_io(&mut r, &mut i);
+            assert_close(&r, &x_re, 1e-12);
+            assert_close(&i, &x_im, 1e-12);
+            fft64.inverse_io(&mut r, &mut i);
+            let scale = n as f64;
+            let (r, i): (Vec<_>, Vec<_>) = r
+                .iter()
+                .zip(&i)
+                .map(|(r, i)| (r / scale, i / scale))
+                .unzip();
+            assert_close(&r, &re, 1e-12);
+            assert_close(&i, &im, 1e-12);
+
+            let mut r: Vec<f32> = re.iter().map(|&v| v as f32).collect();
+            let mut i: Vec<f32> = im.iter().map(|&v| v as f32).collect();
+            let (mut tmp_re, mut tmp_im) = (vec![0.0f32; n], vec![0.0f32; n]);
+            fft32.forward(&mut r, &mut i, &mut tmp_re, &mut tmp_im);
+            assert_close(&f64s(&r), &x_re, 1e-5);
+            assert_close(&f64s(&i), &x_im, 1e-5);
+        }
+
+        // every other value
+        let n = 64;
+        let (re, im) = (signal(n), signal(2 * n)[n..].to_vec());
+        let (x_re, x_im) = dft(&re, &im, -1.0);
+        let mut r: Vec<f64> = re.iter().flat_map(|&v| [v, 0.0]).collect();
+        let mut i: Vec<f64> = im.iter().flat_map(|&v| [v, 0.0]).collect();
+        unsafe {
+            let setup = vdsp::_create_fftsetup_f64(6, vdsp::FftRadix::_2).unwrap();
+            let mut split = SplitComplex::new_mut(&mut r, &mut i);
+            vdsp::_fft_zip_f64(
+                setup.as_ptr(),
+                &mut split,
+                2,
+                6,
+                vdsp::FftDirection::Forward,
+            );
+            vdsp::_destroy_fftsetup_f64(setup.as_ptr());
+        }
+        let odd = |v: &[f64]| v.iter().skip(1).step_by(2).all(|&v| v == 0.0);
+        assert!(odd(&r) && odd(&i));
+        let even = |v: &[f64]| v.iter().step_by(2).copied().collect::<Vec<_>>();
+        assert_close(&even(&r), &x_re, 1e-12);
+        assert_close(&even(&i), &x_im, 1e-12);
+    }
+
+    #[test]
+    fn conformance_zrip() {
+        // doubled spectrum, DC in re[0], Nyquist in im[0]
+        let x = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let (mut re, mut im) = ([0.0f32; 4], [0.0f32; 4]);
+        vdsp::actoz_f32(&x, &mut re, &mut im);
+        let mut fft = vdsp::Fft::new_f32(3, vdsp::FftRadix::_2).unwrap();
+        fft.zr_io(&mut re, &mut im, vdsp::FftDirection::Forward);
+        let k = 8.0 * (1.0 + 2.0f64.sqrt());
+        assert_close(&f64s(&re), &[72.0, -8.0, -8.0, -8.0], 1e-6);
+        assert_close(&f64s(&im), &[-8.0, k, 8.0, k - 16.0], 1e-6);
+
+        // inverse of that is scaled by 2 * n
+        fft.zr_io(&mut re, &mut im, vdsp::FftDirection::Inverse);
+        let mut c = [vdsp::Complex::default(); 4];
+        vdsp::ztoc_f32(&re, &im, &mut c);
+        let y: Vec<f64> = c
+            .iter()
+            .flat_map(|c| [c.re, c.im])
+            .map(|v| v as f64 / 16.0)
+            .collect();
+        assert_close(&y, &f64s(&x), 1e-6);
+
+        let mut fft = vdsp::Fft::new_f64(10, vdsp::FftRadix::_2).unwrap();
+        for log2n in 1..=10 {
+            let n = 1usize << log2n;
+            let x = signal(n);
+            let (x_re, x_im) = dft(&x, &vec![0.0; n], -1.0);
+            let mut re: Vec<f64> = x.iter().step_by(2).copied().collect();
+            let mut im: Vec<f64> = x.iter().skip(1).step_by(2).copied().collect();
+            fft.zr_io(&mut re, &mut im, vdsp::FftDirection::Forward);
+
+            let mut expected_re: Vec<f64> = x_re[..n / 2].iter().map(|v| 2.0 * v).collect();
+            let mut expected_im: Vec<f64> = x_im[..n / 2].iter().map(|v| 2.0 * v).collect();
+            expected_im[0] = 2.0 * x_re[n / 2];
+            expected_re[0] = 2.0 * x_re[0];
+            assert_close(&re, &expected_re, 1e-12);
+            assert_close(&im, &expected_im, 1e-12);
+
+            fft.zr_io(&mut re, &mut im, vdsp::FftDirection::Inverse);
+            let scale = 2.0 * n as f64;
+            let y: Vec<f64> = re
+                .iter()
+                .zip(&im)
+                .flat_map(|(r, i)| [r / scale, i / scale])
+                .collect();
+            assert_close(&y, &x, 1e-12);
+        }
+    }
 }
@@ -0,0 +1,1026 @@
+// Same contracts as Accelerate counterparts, argument order follows vDSP.
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+use std::{
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub},
+    ptr::NonNull,
+};
+
+use super::{Complex, FftDirection, FftRadix, FftSetup, Len, SplitComplex, Stride};
+
+trait Real:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+{
+    const ZERO: Self;
+
+    fn log10(self) -> Self;
+    fn from_f64(val: f64) -> Self;
+    fn from_usize(val: usize) -> Self;
+}
+
+macro_rules! real {
+    ($($t:ty),*) => {$(
+        impl Real for $t {
+            const ZERO: Self = 0.0;
+
+            #[inline]
+            fn log10(self) -> Self {
+                <$t>::log10(self)
+            }
+
+            #[inline]
+            fn from_f64(val: f64) -> Self {
+                val as _
+            }
+
+            #[inline]
+            fn from_usize(val: usize) -> Self {
+                val as _
+            }
+        }
+    )*};
+}
+
+real!(f32, f64);
+
+/// Accumulators of contiguous sums, independent lanes let reductions vectorize.
+const LANES: usize = 8;
+
+#[inline(always)]
+unsafe fn at<T>(ptr: *const T, stride: Stride, i: usize) -> *const T {
+    unsafe { ptr.offset(i as isize * stride) }
+}
+
+#[inline(always)]
+unsafe fn at_mut<T>(ptr: *mut T, stride: Stride, i: usize) -> *mut T {
+    unsafe { ptr.offset(i as isize * stride) }
+}
+
+/// `C[n] = f(A[n])`, `A` and `C` may be the same vector.
+#[inline(always)]
+unsafe fn map1<A: Copy, C: Copy>(
+    a: *const A,
+    ia: Stride,
+    c: *mut C,
+    ic: Stride,
+    n: Len,
+    f: impl Fn(A) -> C,
+) {
+    unsafe {
+        if ia == 1 && ic == 1 {
+            for i in 0..n {
+                c.add(i).write(f(a.add(i).read()));
+            }
+        } else {
+            for i in 0..n {
+                at_mut(c, ic, i).write(f(at(a, ia, i).read()));
+            }
+        }
+    }
+}
+
+/// `C[n] = f(A[n], B[n])`.
+#[inline(always)]
+unsafe fn map2<T: Copy>(
+    a: *const T,
+    ia: Stride,
+    b: *const T,
+    ib: Stride,
+    c: *mut T,
+    ic: Stride,
+    n: Len,
+    f: impl Fn(T, T) -> T,
+) {
+    unsafe {
+        if ia == 1 && ib == 1 && ic == 1 {
+            for i in 0..n {
+                c.add(i).write(f(a.add(i).read(), b.add(i).read()));
+            }
+        } else {
+            for i in 0..n {
+                at_mut(c, ic, i).write(f(at(a, ia, i).read(), at(b, ib, i).read()));
+            }
+        }
+    }
+}
+
+/// `D[n] = f(A[n], B[n], C[n])`.
+#[inline(always)]
+unsafe fn map3<T: Copy>(
+    a: *const T,
+    ia: Stride,
+    b: *const T,
+    ib: Stride,
+    c: *const T,
+    ic: Stride,
+    d: *mut T,
+    id: Stride,
+    n: Len,
+    f: impl Fn(T, T, T) -> T,
+) {
+    unsafe {
+        if ia == 1 && ib == 1 && ic == 1 && id == 1 {
+            for i in 0..n {
+                d.add(i)
+                    .write(f(a.add(i).read(), b.add(i).read(), c.add(i).read()));
+            }
+        } else {
+            for i in 0..n {
+                let (a, b, c) = (at(a, ia, i), at(b, ib, i), at(c, ic, i));
+                at_mut(d, id, i).write(f(a.read(), b.read(), c.read()));
+            }
+        }
+    }
+}
+
+/// Sum of `f(A[n], B[n])`.
+#[inline(always)]
+unsafe fn sum2<T: Real>(
+    a: *const T,
+    ia: Stride,
+    b: *const T,
+    ib: Stride,
+    n: Len,
+    f: impl Fn(T, T) -> T,
+) -> T {
+    let mut sum = T::ZERO;
+    unsafe {
+        if ia == 1 && ib == 1 {
+            let a = std::slice::from_raw_parts(a, n);
+            let b = std::slice::from_raw_parts(b, n);
+            let (a, b) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+            for (&a, &b) in a.remainder().iter().zip(b.remainder()) {
+                sum += f(a, b);
+            }
+            let mut lanes = [T::ZERO; LANES];
+            for (a, b) in a.zip(b) {
+                for ((lane, &a), &b) in lanes.iter_mut().zip(a).zip(b) {
+                    *lane += f(a, b);
+                }
+            }
+            for lane in lanes {
+                sum += lane;
+            }
+        } else {
+            for i in 0..n {
+                sum += f(at(a, ia, i).read(), at(b, ib, i).read());
+            }
+        }
+    }
+    sum
+}
+
+/// Sum of `f(A[n])`.
+#[inline(always)]
+unsafe fn sum1<T: Real>(a: *const T, ia: Stride, n: Len, f: impl Fn(T) -> T) -> T {
+    unsafe { sum2(a, ia, a, ia, n, |a, _| f(a)) }
+}
+
+/// `f(A[n])` replacing `init` when `replace(current, value)`.
+#[inline(always)]
+unsafe fn extremum<T: Real>(
+    a: *const T,
+    ia: Stride,
+    n: Len,
+    init: T,
+    f: impl Fn(T) -> T,
+    replace: impl Fn(T, T) -> bool,
+) -> T {
+    let mut res = init;
+    for i in 0..n {
+        let v = f(unsafe { at(a, ia, i).read() });
+        if replace(res, v) {
+            res = v;
+        }
+    }
+    res
+}
+
+/// `n / (N - 1)`.
+#[inline(always)]
+fn taper<T: Real>(i: usize, n: Len) -> T {
+    if n > 1 {
+        T::from_usize(i) / T::from_usize(n - 1)
+    } else {
+        T::ZERO
+    }
+}
+
+macro_rules! vv {
+    ($($alias:literal $name:ident: $t:ty, |$a:ident, $b:ident| $e:expr;)*) => {$(
+        #[doc(alias = $alias)]
+        pub unsafe extern "C-unwind" fn $name(
+            a: *const $t,
+            ia: Stride,
+            b: *const $t,
+            ib: Stride,
+            c: *mut $t,
+            ic: Stride,
+            n: Len,
+        ) {
+            unsafe { map2(a, ia, b, ib, c, ic, n, |$a: $t, $b: $t| $e) }
+        }
+    )*};
+}
+
+// vsub and vdiv take B first: C = A - B
+vv! {
+    "vDSP_vadd" _add_f32: f32, |a, b| a + b;
+    "vDSP_vaddD" _add_f64: f64, |a, b| a + b;
+    "vDSP_vaddi" _add_i32: i32, |a, b| a.wrapping_add(b);
+    "vDSP_vsub" _sub_f32: f32, |b, a| a - b;
+    "vDSP_vsubD" _sub_f64: f64, |b, a| a - b;
+    "vDSP_vsubi" _sub_i32: i32, |b, a| a.wrapping_sub(b);
+    "vDSP_vmul" _mul_f32: f32, |a, b| a * b;
+    "vDSP_vmulD" _mul_f64: f64, |a, b| a * b;
+    "vDSP_vdiv" _div_f32: f32, |b, a| a / b;
+    "vDSP_vdivD" _div_f64: f64, |b, a| a / b;
+    "vDSP_vdivi" _div_i32: i32, |b, a| a.wrapping_div(b);
+    "vDSP_veqvi" _eqv_i32: i32, |a, b| !(a ^ b);
+}
+
+macro_rules! vs {
+    ($($alias:literal $name:ident: $t:ty, |$a:ident, $b:ident| $e:expr;)*) => {$(
+        #[doc(alias = $alias)]
+        pub unsafe extern "C-unwind" fn $name(
+            a: *const $t,
+            ia: Stride,
+            b: &$t,
+            c: *mut $t,
+            ic: Stride,
+            n: Len,
+        ) {
+            let $b = *b;
+            unsafe { map1(a, ia, c, ic, n, |$a: $t| $e) }
+        }
+    )*};
+}
+
+vs! {
+    "vDSP_vsmul" _smul_f32: f32, |a, b| a * b;
+    "vDSP_vsmulD" _smul_f64: f64, |a, b| a * b;
+    "vDSP_vsadd" _sadd_f32: f32, |a, b| a + b;
+    "vDSP_vsaddD" _sadd_f64: f64, |a, b| a + b;
+    "vDSP_vsaddi" _sadd_i32: i32, |a, b| a.wrapping_add(b);
+}
+
+macro_rules! v {
+    ($($alias:literal $name:ident: $a_t:ty => $c_t:ty, |$a:ident| $e:expr;)*) => {$(
+        #[doc(alias = $alias)]
+        pub unsafe extern "C-unwind" fn $name(
+            a: *const $a_t,
+            ia: Stride,
+            c: *mut $c_t,
+            ic: Stride,
+            n: Len,
+        ) {
+            unsafe { map1(a, ia, c, ic, n, |$a: $a_t| $e) }
+        }
+    )*};
+}
+
+v! {
+    "vDSP_vsq" _sq_f32: f32 => f32, |a| a * a;
+    "vDSP_vsqD" _sq_f64: f64 => f64, |a| a * a;
+    "vDSP_vssq" _ssq_f32: f32 => f32, |a| a * a.abs();
+    "vDSP_vssqD" _ssq_f64: f64 => f64, |a| a * a.abs();
+    "vDSP_vabs" _abs_f32: f32 => f32, |a| a.abs();
+    "vDSP_vabsD" _abs_f64: f64 => f64, |a| a.abs();
+    "vDSP_vabsi" _abs_i32: i32 => i32, |a| a.wrapping_abs();
+    "vDSP_vneg" _neg_f32: f32 => f32, |a| -a;
+    "vDSP_vnegD" _neg_f64: f64 => f64, |a| -a;
+    "vDSP_vdpsp" _f64_f32: f64 => f32, |a| a as f32;
+    "vDSP_vspdp" _f32_f64: f32 => f64, |a| a as f64;
+    "vDSP_vflt16" _i16_f32: i16 => f32, |a| a as f32;
+    "vDSP_vfltu16" _u16_f32: u16 => f32, |a| a as f32;
+}
+
+macro_rules! vvv {
+    ($($alias:literal $name:ident: $t:ty, |$a:ident, $b:ident, $c:ident| $e:expr;)*) => {$(
+        #[doc(alias = $alias)]
+        pub unsafe extern "C-unwind" fn $name(
+            a: *const $t,
+            ia: Stride,
+            b: *const $t,
+            ib: Stride,
+            c: *const $t,
+            ic: Stride,
+            d: *mut $t,
+            id: Stride,
+            n: Len,
+        ) {
+            unsafe { map3(a, ia, b, ib, c, ic, d, id, n, |$a: $t, $b: $t, $c: $t| $e) }
+        }
+    )*};
+}
+
+vvv! {
+    "vDSP_vam" _am_f32: f32, |a, b, c| (a + b) * c;
+    "vDSP_vamD" _am_f64: f64, |a, b, c| (a + b) * c;
+    "vDSP_vma" _ma_f32: f32, |a, b, c| a * b + c;
+    "vDSP_vmaD" _ma_f64: f64, |a, b, c| a * b + c;
+}
+
+macro_rules! reduce {
+    ($($alias:literal $name:ident: $t:ty, |$a:ident, $ia:ident, $n:ident| $e:expr;)*) => {$(
+        #[doc(alias = $alias)]
+        pub unsafe extern "C-unwind" fn $name($a: *const $t, $ia: Stride, c: *mut $t, $n: Len) {
+            unsafe { c.write($e) }
+        }
+    )*};
+}
+
+reduce! {
+    "vDSP_meanv" _mean_f32: f32, |a, ia, n| sum1(a, ia, n, |a| a) / n as f32;
+    "vDSP_meanvD" _mean_f64: f64, |a, ia, n| sum1(a, ia, n, |a| a) / n as f64;
+    "vDSP_measqv" _meansq_f32: f32, |a, ia, n| sum1(a, ia, n, |a| a * a) / n as f32;
+    "vDSP_measqvD" _meansq_f64: f64, |a, ia, n| sum1(a, ia, n, |a| a * a) / n as f64;
+    "vDSP_sve" _se_f32: f32, |a, ia, n| sum1(a, ia, n, |a| a);
+    "vDSP_sveD" _se_f64: f64, |a, ia, n| sum1(a, ia, n, |a| a);
+    "vDSP_svemg" _semg_f32: f32, |a, ia, n| sum1(a, ia, n, f32::abs);
+    "vDSP_svemgD" _semg_f64: f64, |a, ia, n| sum1(a, ia, n, f64::abs);
+    "vDSP_svesq" _sesq_f32: f32, |a, ia, n| sum1(a, ia, n, |a| a * a);
+    "vDSP_svesqD" _sesq_f64: f64, |a, ia, n| sum1(a, ia, n, |a| a * a);
+    "vDSP_svs" _svs_f32: f32, |a, ia, n| sum1(a, ia, n, |a| a * a.abs());
+    "vDSP_svsD" _svs_f64: f64, |a, ia, n| sum1(a, ia, n, |a| a * a.abs());
+    "vDSP_maxmgv" _maxmg_f32: f32, |a, ia, n| extremum(a, ia, n, 0.0, f32::abs, |c, v| c < v);
+    "vDSP_maxmgvD" _maxmg_f64: f64, |a, ia, n| extremum(a, ia, n, 0.0, f64::abs, |c, v| c < v);
+    "vDSP_minmgv" _minmg_f32: f32, |a, ia, n| extremum(a, ia, n, f32::INFINITY, f32::abs, |c, v| v < c);
+    "vDSP_minmgvD" _minmg_f64: f64, |a, ia, n| extremum(a, ia, n, f64::INFINITY, f64::abs, |c, v| v < c);
+    "vDSP_maxv" _max_f32: f32, |a, ia, n| extremum(a, ia, n, -f32::INFINITY, |a| a, |c, v| c < v);
+    "vDSP_maxvD" _max_f64: f64, |a, ia, n| extremum(a, ia, n, -f64::INFINITY, |a| a, |c, v| c < v);
+    "vDSP_minv" _min_f32: f32, |a, ia, n| extremum(a, ia, n, f32::INFINITY, |a| a, |c, v| v < c);
+    "vDSP_minvD" _min_f64: f64, |a, ia, n| extremum(a, ia, n, f64::INFINITY, |a| a, |c, v| v < c);
+}
+
+macro_rules! reduce2 {
+    ($($alias:literal $name:ident: $t:ty, |$a:ident, $b:ident| $e:expr;)*) => {$(
+        #[doc(alias = $alias)]
+        pub unsafe extern "C-unwind" fn $name(
+            a: *const $t,
+            ia: Stride,
+            b: *const $t,
+            ib: Stride,
+            c: *mut $t,
+            n: Len,
+        ) {
+            unsafe { c.write(sum2(a, ia, b, ib, n, |$a: $t, $b: $t| $e)) }
+        }
+    )*};
+}
+
+reduce2! {
+    "vDSP_distancesq" _distance_sq_f32: f32, |a, b| (a - b) * (a - b);
+    "vDSP_distancesqD" _distance_sq_f64: f64, |a, b| (a - b) * (a - b);
+    "vDSP_dotpr" _dotpr_f32: f32, |a, b| a * b;
+    "vDSP_dotprD" _dotpr_f64: f64, |a, b| a * b;
+}
+
+#[inline(always)]
+unsafe fn fill<T: Copy>(a: T, c: *mut T, ic: Stride, n: Len) {
+    unsafe { map1(c, ic, c, ic, n, |_| a) }
+}
+
+#[doc(alias = "vDSP_vfill")]
+pub unsafe extern "C-unwind" fn _fill_f32(a: &f32, c: *mut f32, ic: Stride, n: Len) {
+    unsafe { fill(*a, c, ic, n) }
+}
+
+#[doc(alias = "vDSP_vfillD")]
+pub unsafe extern "C-unwind" fn _fill_f64(a: &f64, c: *mut f64, ic: Stride, n: Len) {
+    unsafe { fill(*a, c, ic, n) }
+}
+
+#[doc(alias = "vDSP_vfilli")]
+pub unsafe extern "C-unwind" fn _fill_i32(a: &i32, c: *mut i32, ic: Stride, n: Len) {
+    unsafe { fill(*a, c, ic, n) }
+}
+
+#[doc(alias = "vDSP_vclr")]
+pub unsafe extern "C-unwind" fn _clr_f32(c: *mut f32, ic: Stride, n: Len) {
+    unsafe { fill(0.0, c, ic, n) }
+}
+
+#[doc(alias = "vDSP_vclrD")]
+pub unsafe extern "C-unwind" fn _clr_f64(c: *mut f64, ic: Stride, n: Len) {
+    unsafe { fill(0.0, c, ic, n) }
+}
+
+#[inline(always)]
+unsafe fn generate<T: Real>(a: T, b: T, c: *mut T, ic: Stride, n: Len) {
+    for i in 0..n {
+        unsafe { at_mut(c, ic, i).write(a + (b - a) * taper(i, n)) }
+    }
+}
+
+#[doc(alias = "vDSP_vgen")]
+pub unsafe extern "C-unwind" fn _gen_f32(a: &f32, b: &f32, c: *mut f32, ic: Stride, n: Len) {
+    unsafe { generate(*a, *b, c, ic, n) }
+}
+
+#[doc(alias = "vDSP_vgenD")]
+pub unsafe extern "C-unwind" fn _gen_f64(a: &f64, b: &f64, c: *mut f64, ic: Stride, n: Len) {
+    unsafe { generate(*a, *b, c, ic, n) }
+}
+
+#[inline(always)]
+unsafe fn ramp<T: Real>(a: T, b: T, c: *mut T, ic: Stride, n: Len) {
+    for i in 0..n {
+        unsafe { at_mut(c, ic, i).write(a + T::from_usize(i) * b) }
+    }
+}
+
+#[doc(alias = "vDSP_vramp")]
+pub unsafe extern "C-unwind" fn _ramp_f32(a: &f32, b: &f32, c: *mut f32, ic: Stride, n: Len) {
+    unsafe { ramp(*a, *b, c, ic, n) }
+}
+
+#[doc(alias = "vDSP_vrampD")]
+pub unsafe extern "C-unwind" fn _ramp_f64(a: &f64, b: &f64, c: *mut f64, ic: Stride, n: Len) {
+    unsafe { ramp(*a, *b, c, ic, n) }
+}
+
+#[inline(always)]
+unsafe fn rampmul<T: Real>(
+    i: [*const T; 2],
+    is: Stride,
+    start: &mut T,
+    step: T,
+    o: [*mut T; 2],
+    os: Stride,
+    channels: usize,
+    n: Len,
+) {
+    for k in 0..n {
+        let s = *start + T::from_usize(k) * step;
+        for c in 0..channels {
+            unsafe { at_mut(o[c], os, k).write(s * at(i[c], is, k).read()) }
+        }
+    }
+    *start += T::from_usize(n) * step;
+}
+
+#[doc(alias = "vDSP_vrampmul")]
+pub unsafe extern "C-unwind" fn _rampmul_f32(
+    i: *const f32,
+    is: Stride,
+    start: &mut f32,
+    step: &f32,
+    o: *mut f32,
+    os: Stride,
+    n: Len,
+) {
+    unsafe { rampmul([i, i], is, start, *step, [o, o], os, 1, n) }
+}
+
+#[doc(alias = "vDSP_vrampmulD")]
+pub unsafe extern "C-unwind" fn _rampmul_f64(
+    i: *const f64,
+    is: Stride,
+    start: &mut f64,
+    step: &f64,
+    o: *mut f64,
+    os: Stride,
+    n: Len,
+) {
+    unsafe { rampmul([i, i], is, start, *step, [o, o], os, 1, n) }
+}
+
+#[doc(alias = "vDSP_vrampmul2")]
+pub unsafe extern "C-unwind" fn _rampmul2_f32(
+    i0: *const f32,
+    i1: *const f32,
+    is: Stride,
+    start: &mut f32,
+    step: &f32,
+    o0: *mut f32,
+    o1: *mut f32,
+    os: Stride,
+    n: Len,
+) {
+    unsafe { rampmul([i0, i1], is, start, *step, [o0, o1], os, 2, n) }
+}
+
+#[doc(alias = "vDSP_vrampmul2D")]
+pub unsafe extern "C-unwind" fn _rampmul2_f64(
+    i0: *const f64,
+    i1: *const f64,
+    is: Stride,
+    start: &mut f64,
+    step: &f64,
+    o0: *mut f64,
+    o1: *mut f64,
+    os: Stride,
+    n: Len,
+) {
+    unsafe { rampmul([i0, i1], is, start, *step, [o0, o1], os, 2, n) }
+}
+
+#[inline(always)]
+unsafe fn tmerg<T: Real>(
+    a: *const T,
+    ia: Stride,
+    b: *const T,
+    ib: Stride,
+    c: *mut T,
+    ic: Stride,
+    n: Len,
+) {
+    for i in 0..n {
+        unsafe {
+            let (a, b) = (at(a, ia, i).read(), at(b, ib, i).read());
+            at_mut(c, ic, i).write(a + (b - a) * taper(i, n));
+        }
+    }
+}
+
+#[doc(alias = "vDSP_vtmerg")]
+pub unsafe extern "C-unwind" fn _tmerg_f32(
+    a: *const f32,
+    ia: Stride,
+    b: *const f32,
+    ib: Stride,
+    c: *mut f32,
+    ic: Stride,
+    n: Len,
+) {
+    unsafe { tmerg(a, ia, b, ib, c, ic, n) }
+}
+
+#[doc(alias = "vDSP_vtmergD")]
+pub unsafe extern "C-unwind" fn _tmerg_f64(
+    a: *const f64,
+    ia: Stride,
+    b: *const f64,
+    ib: Stride,
+    c: *mut f64,
+    ic: Stride,
+    n: Len,
+) {
+    unsafe { tmerg(a, ia, b, ib, c, ic, n) }
+}
+
+/// `IC` counts scalars, 2 for adjacent complex values.
+#[inline(always)]
+unsafe fn ctoz<T: Copy>(
+    c: *const Complex<T>,
+    ic: Stride,
+    z: *const SplitComplex<T>,
+    iz: Stride,
+    n: Len,
+) {
+    unsafe {
+        let (c, z) = (c.cast::<T>(), &*z);
+        map1(c, ic, z.re, iz, n, |re| re);
+        map1(c.add(1), ic, z.im, iz, n, |im| im);
+    }
+}
+
+#[doc(alias = "vDSP_ctoz")]
+pub unsafe extern "C-unwind" fn _ctoz_f32(
+    c: *const Complex<f32>,
+    ic: Stride,
+    z: *const SplitComplex<f32>,
+    iz: Stride,
+    n: Len,
+) {
+    unsafe { ctoz(c, ic, z, iz, n) }
+}
+
+#[doc(alias = "vDSP_ctozD")]
+pub unsafe extern "C-unwind" fn _ctoz_f64(
+    c: *const Complex<f64>,
+    ic: Stride,
+    z: *const SplitComplex<f64>,
+    iz: Stride,
+    n: Len,
+) {
+    unsafe { ctoz(c, ic, z, iz, n) }
+}
+
+#[inline(always)]
+unsafe fn ztoc<T: Copy>(
+    z: *const SplitComplex<T>,
+    iz: Stride,
+    c: *mut Complex<T>,
+    ic: Stride,
+    n: Len,
+) {
+    unsafe {
+        let (z, c) = (&*z, c.cast::<T>());
+        map1(z.re, iz, c, ic, n, |re| re);
+        map1(z.im, iz, c.add(1), ic, n, |im| im);
+    }
+}
+
+#[doc(alias = "vDSP_ztoc")]
+pub unsafe extern "C-unwind" fn _ztoc_f32(
+    z: *const SplitComplex<f32>,
+    iz: Stride,
+    c: *mut Complex<f32>,
+    ic: Stride,
+    n: Len,
+) {
+    unsafe { ztoc(z, iz, c, ic, n) }
+}
+
+#[doc(alias = "vDSP_ztocD")]
+pub unsafe extern "C-unwind" fn _ztoc_f64(
+    z: *const SplitComplex<f64>,
+    iz: Stride,
+    c: *mut Complex<f64>,
+    ic: Stride,
+    n: Len,
+) {
+    unsafe { ztoc(z, iz, c, ic, n) }
+}
+
+#[inline(always)]
+unsafe fn zaspec<T: Real>(a: *const SplitComplex<T>, c: *mut T, n: Len) {
+    unsafe {
+        let a = &*a;
+        for i in 0..n {
+            let (re, im) = (a.re.add(i).read(), a.im.add(i).read());
+            *c.add(i) += re * re + im * im;
+        }
+    }
+}
+
+#[doc(alias = "vDSP_zaspec")]
+pub unsafe extern "C-unwind" fn _zaspec_f32(a: *const SplitComplex<f32>, c: *mut f32, n: Len) {
+    unsafe { zaspec(a, c, n) }
+}
+
+#[doc(alias = "vDSP_zaspecD")]
+pub unsafe extern "C-unwind" fn _zaspec_f64(a: *const SplitComplex<f64>, c: *mut f64, n: Len) {
+    unsafe { zaspec(a, c, n) }
+}
+
+/// `C[n] = α * log10(A[n] / B)`, α is 20 for amplitude (`F == 1`), 10 for power.
+#[inline(always)]
+unsafe fn dbcon<T: Real>(a: *const T, ia: Stride, b: T, c: *mut T, ic: Stride, n: Len, f: u32) {
+    let alpha = T::from_f64(if f == 1 { 20.0 } else { 10.0 });
+    unsafe { map1(a, ia, c, ic, n, |a| alpha * (a / b).log10()) }
+}
+
+#[doc(alias = "vDSP_vdbcon")]
+pub unsafe extern "C-unwind" fn _dbcon_f32(
+    a: *const f32,
+    ia: Stride,
+    b: *const f32,
+    c: *mut f32,
+    ic: Stride,
+    n: Len,
+    f: u32,
+) {
+    unsafe { dbcon(a, ia, *b, c, ic, n, f) }
+}
+
+#[doc(alias = "vDSP_vdbconD")]
+pub unsafe extern "C-unwind" fn _dbcon_f64(
+    a: *const f64,
+    ia: Stride,
+    b: *const f64,
+    c: *mut f64,
+    ic: Stride,
+    n: Len,
+    f: u32,
+) {
+    unsafe { dbcon(a, ia, *b, c, ic, n, f) }
+}
+
+#[inline(always)]
+unsafe fn clip<T: Real>(a: *const T, ia: Stride, low: T, high: T, d: *mut T, id: Stride, n: Len) {
+    let clip = |a| {
+        if a < low {
+            low
+        } else if a > high {
+            high
+        } else {
+            a
+        }
+    };
+    unsafe { map1(a, ia, d, id, n, clip) }
+}
+
+#[doc(alias = "vDSP_vclip")]
+pub unsafe extern "C-unwind" fn _clip_f32(
+    a: *const f32,
+    ia: Stride,
+    b: *const f32,
+    c: *const f32,
+    d: *mut f32,
+    id: Stride,
+    n: Len,
+) {
+    unsafe { clip(a, ia, *b, *c, d, id, n) }
+}
+
+#[doc(alias = "vDSP_vclipD")]
+pub unsafe extern "C-unwind" fn _clip_f64(
+    a: *const f64,
+    ia: Stride,
+    b: *const f64,
+    c: *const f64,
+    d: *mut f64,
+    id: Stride,
+    n: Len,
+) {
+    unsafe { clip(a, ia, *b, *c, d, id, n) }
+}
+
+/// Twiddles of radix-2 FFT up to `len()` points.
+///
+/// Butterflies of half size `h` read `e^(iπj/h)` for `j < h` at `h + j`,
+/// so every stage is a contiguous slice.
+struct Setup<T> {
+    cos: Vec<T>,
+    sin: Vec<T>,
+}
+
+impl<T: Real> Setup<T> {
+    fn new(log2n: Len) -> Option<Self> {
+        let n = 1usize.checked_shl(log2n.try_into().ok()?)?;
+        let mut cos = vec![T::ZERO; n];
+        let mut sin = vec![T::ZERO; n];
+        let mut h = 1;
+        while h < n {
+            for j in 0..h {
+                let (s, c) = (std::f64::consts::PI * j as f64 / h as f64).sin_cos();
+                cos[h + j] = T::from_f64(c);
+                sin[h + j] = T::from_f64(s);
+            }
+            h *= 2;
+        }
+        Some(Self { cos, sin })
+    }
+
+    fn len(&self) -> usize {
+        self.cos.len()
+    }
+
+    /// Twiddles of stage with half size `h`, `sin` is negated for forward direction.
+    fn stage(&self, h: usize) -> (&[T], &[T]) {
+        (&self.cos[h..2 * h], &self.sin[h..2 * h])
+    }
+
+    /// Unscaled in-place complex FFT, forward uses `e^(-iθ)`.
+    fn fft(&self, re: &mut [T], im: &mut [T], direction: FftDirection) {
+        let n = re.len();
+        assert!(n <= self.len(), "log2n is larger than FFT setup");
+        if n < 2 {
+            return;
+        }
+
+        let shift = usize::BITS - n.trailing_zeros();
+        for i in 0..n {
+            let j = i.reverse_bits() >> shift;
+            if i < j {
+                re.swap(i, j);
+                im.swap(i, j);
+            }
+        }
+
+        let sign = T::from_f64(match direction {
+            FftDirection::Forward => -1.0,
+            FftDirection::Inverse => 1.0,
+        });
+        let mut h = 1;
+        while h < n {
+            let (cos, sin) = self.stage(h);
+            for (re, im) in re.chunks_exact_mut(2 * h).zip(im.chunks_exact_mut(2 * h)) {
+                let (re0, re1) = re.split_at_mut(h);
+                let (im0, im1) = im.split_at_mut(h);
+                for j in 0..h {
+                    let (wr, wi) = (cos[j], sign * sin[j]);
+                    let tr = wr * re1[j] - wi * im1[j];
+                    let ti = wr * im1[j] + wi * re1[j];
+                    re1[j] = re0[j] - tr;
+                    im1[j] = im0[j] - ti;
+                    re0[j] += tr;
+                    im0[j] += ti;
+                }
+            }
+            h *= 2;
+        }
+    }
+
+    /// Real FFT of `2 * re.len()` points packed as even (`re`) and odd (`im`) values.
+    ///
+    /// Forward result is doubled spectrum with DC in `re[0]` and Nyquist in `im[0]`,
+    /// inverse of that is input scaled by `2 * N`.
+    fn real_fft(&self, re: &mut [T], im: &mut [T], direction: FftDirection) {
+        let m = re.len();
+        if m == 0 {
+            return;
+        }
+        assert!(2 * m <= self.len(), "log2n is larger than FFT setup");
+        // e^(iπk/m), twiddles of 2m points
+        let (cos, sin) = self.stage(m);
+        match direction {
+            FftDirection::Forward => {
+                self.fft(re, im, direction);
+                let (r, i) = (re[0], im[0]);
+                re[0] = (r + i) + (r + i);
+                im[0] = (r - i) + (r - i);
+                for k in 1..=m / 2 {
+                    let j = m - k;
+                    let (ar, ai, br, bi) = (re[k], im[k], re[j], im[j]);
+                    // even a + conj(b), odd a - conj(b) times e^(-iπk/m)
+                    let (er, ei) = (ar + br, ai - bi);
+                    let (or, oi) = (ar - br, ai + bi);
+                    let (pr, pi) = (cos[k] * or + sin[k] * oi, cos[k] * oi - sin[k] * or);
+                    re[k] = er + pi;
+                    im[k] = ei - pr;
+                    re[j] = er - pi;
+                    im[j] = -ei - pr;
+                }
+            }
+            FftDirection::Inverse => {
+                let (dc, nyquist) = (re[0], im[0]);
+                re[0] = dc + nyquist;
+                im[0] = dc - nyquist;
+                for k in 1..=m / 2 {
+                    let j = m - k;
+                    let (ar, ai, br, bi) = (re[k], im[k], re[j], im[j]);
+                    // even a + conj(b), odd a - conj(b) times e^(iπk/m)
+                    let (er, ei) = (ar + br, ai - bi);
+                    let (dr, di) = (ar - br, ai + bi);
+                    let (qr, qi) = (cos[k] * dr - sin[k] * di, cos[k] * di + sin[k] * dr);
+                    re[k] = er - qi;
+                    im[k] = ei + qr;
+                    re[j] = er + qi;
+                    im[j] = qr - ei;
+                }
+                self.fft(re, im, direction);
+            }
+        }
+    }
+}
+
+fn create_fftsetup<T: Real>(log2n: Len) -> Option<NonNull<FftSetup<T>>> {
+    let setup = Box::new(Setup::<T>::new(log2n)?);
+    NonNull::new(Box::into_raw(setup).cast())
+}
+
+unsafe fn destroy_fftsetup<T>(setup: *mut FftSetup<T>) {
+    if !setup.is_null() {
+        drop(unsafe { Box::from_raw(setup.cast::<Setup<T>>()) });
+    }
+}
+
+/// Runs `f` on contiguous `n` values of split complex `c`.
+unsafe fn with_split<T: Copy>(
+    c: *const SplitComplex<T>,
+    ic: Stride,
+    n: usize,
+    f: impl FnOnce(&mut [T], &mut [T]),
+) {
+    unsafe {
+        let c = &*c;
+        if ic == 1 {
+            let re = std::slice::from_raw_parts_mut(c.re, n);
+            let im = std::slice::from_raw_parts_mut(c.im, n);
+            f(re, im);
+        } else {
+            let mut re: Vec<_> = (0..n).map(|i| at(c.re, ic, i).read()).collect();
+            let mut im: Vec<_> = (0..n).map(|i| at(c.im, ic, i).read()).collect();
+            f(&mut re, &mut im);
+            map1(re.as_ptr(), 1, c.re, ic, n, |re| re);
+            map1(im.as_ptr(), 1, c.im, ic, n, |im| im);
+        }
+    }
+}
+
+unsafe fn fft_zip<T: Real>(
+    setup: *mut FftSetup<T>,
+    c: *const SplitComplex<T>,
+    ic: Stride,
+    log2n: Len,
+    direction: FftDirection,
+) {
+    unsafe {
+        let setup = &*setup.cast::<Setup<T>>();
+        with_split(c, ic, 1 << log2n, |re, im| setup.fft(re, im, direction))
+    }
+}
+
+unsafe fn fft_zrip<T: Real>(
+    setup: *mut FftSetup<T>,
+    c: *const SplitComplex<T>,
+    ic: Stride,
+    log2n: Len,
+    direction: FftDirection,
+) {
+    unsafe {
+        let setup = &*setup.cast::<Setup<T>>();
+        with_split(c, ic, (1 << log2n) / 2, |re, im| {
+            setup.real_fft(re, im, direction)
+        })
+    }
+}
+
+/// Any radix, only power of 2 transforms are provided.
+#[doc(alias = "vDSP_create_fftsetup")]
+pub unsafe extern "C-unwind" fn _create_fftsetup_f32(
+    log2n: Len,
+    _radix: FftRadix,
+) -> Option<NonNull<FftSetup<f32>>> {
+    create_fftsetup(log2n)
+}
+
+/// Any radix, only power of 2 transforms are provided.
+#[doc(alias = "vDSP_create_fftsetupD")]
+pub unsafe extern "C-unwind" fn _create_fftsetup_f64(
+    log2n: Len,
+    _radix: FftRadix,
+) -> Option<NonNull<FftSetup<f64>>> {
+    create_fftsetup(log2n)
+}
+
+#[doc(alias = "vDSP_destroy_fftsetup")]
+pub unsafe extern "C-unwind" fn _destroy_fftsetup_f32(setup: *mut FftSetup<f32>) {
+    unsafe { destroy_fftsetup(setup) }
+}
+
+#[doc(alias = "vDSP_destroy_fftsetupD")]
+pub unsafe extern "C-unwind" fn _destroy_fftsetup_f64(setup: *mut FftSetup<f64>) {
+    unsafe { destroy_fftsetup(setup) }
+}
+
+#[doc(alias = "vDSP_fft_zip")]
+pub unsafe extern "C-unwind" fn _fft_zip_f32(
+    setup: *mut FftSetup<f32>,
+    c: *mut SplitComplex<f32>,
+    ic: Stride,
+    log2n: Len,
+    direction: FftDirection,
+) {
+    unsafe { fft_zip(setup, c, ic, log2n, direction) }
+}
+
+#[doc(alias = "vDSP_fft_zipD")]
+pub unsafe extern "C-unwind" fn _fft_zip_f64(
+    setup: *mut FftSetup<f64>,
+    c: *mut SplitComplex<f64>,
+    ic: Stride,
+    log2n: Len,
+    direction: FftDirection,
+) {
+    unsafe { fft_zip(setup, c, ic, log2n, direction) }
+}
+
+/// `buffer` is not used.
+#[doc(alias = "vDSP_fft_zipt")]
+pub unsafe extern "C-unwind" fn _fft_zipt_f32(
+    setup: *mut FftSetup<f32>,
+    c: *const SplitComplex<f32>,
+    ic: Stride,
+    _buffer: *mut SplitComplex<f32>,
+    log2n: Len,
+    direction: FftDirection,
+) {
+    unsafe { fft_zip(setup, c, ic, log2n, direction) }
+}
+
+/// `buffer` is not used.
+#[doc(alias = "vDSP_fft_ziptD")]
+pub unsafe extern "C-unwind" fn _fft_zipt_f64(
+    setup: *mut FftSetup<f64>,
+    c: *const SplitComplex<f64>,
+    ic: Stride,
+    _buffer: *mut SplitComplex<f64>,
+    log2n: Len,
+    direction: FftDirection,
+) {
+    unsafe { fft_zip(setup, c, ic, log2n, direction) }
+}
+
+#[doc(alias = "vDSP_fft_zrip")]
+pub unsafe extern "C-unwind" fn _fft_zrip_f32(
+    setup: *mut FftSetup<f32>,
+    c: *const SplitComplex<f32>,
+    ic: Stride,
+    log2n: Len,
+    direction: FftDirection,
+) {
+    unsafe { fft_zrip(setup, c, ic, log2n, direction) }
+}
+
+#[doc(alias = "vDSP_fft_zripD")]
+pub unsafe extern "C-unwind" fn _fft_zrip_f64(
+    setup: *mut FftSetup<f64>,
+    c: *const SplitComplex<f64>,
+    ic: Stride,
+    log2n: Len,
+    direction: FftDirection,
+) {
+    unsafe { fft_zrip(setup, c, ic, log2n, direction) }
+}
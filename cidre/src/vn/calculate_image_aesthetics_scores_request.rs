@@ -37,7 +37,7 @@ impl CalcImageAestheticsScoresRequest {
     pub fn results(&self) -> Option<arc::R<ns::Array<vn::ImageAestheticsScoresObservation>>>;
 }
 
-#[link(name = "vn", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "vn", kind = "static"))]
 unsafe extern "C" {
     static VN_CALCULATE_IMAGE_AESTHETICS_SCORES_REQUEST:
         &'static objc::Class<CalcImageAestheticsScoresRequest>;
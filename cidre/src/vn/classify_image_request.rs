@@ -37,7 +37,7 @@ impl ClassifyImageRequest {
     }
 }
 
-#[link(name = "vn", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "vn", kind = "static"))]
 unsafe extern "C" {
     static VN_CLASSIFY_IMAGE_REQUEST: &'static objc::Class<ClassifyImageRequest>;
 }
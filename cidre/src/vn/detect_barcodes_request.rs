@@ -32,7 +32,7 @@ impl DetectBarcodesRequest {
     }
 }
 
-#[link(name = "vn", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "vn", kind = "static"))]
 unsafe extern "C" {
     static VN_DETECT_BARCODES_REQUEST: &'static objc::Class<DetectBarcodesRequest>;
 }
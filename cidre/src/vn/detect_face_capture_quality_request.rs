@@ -13,7 +13,7 @@ impl DetectFaceCaptureQualityRequest {
     pub fn results(&self) -> Option<arc::R<ns::Array<vn::FaceObservation>>>;
 }
 
-#[link(name = "vn", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "vn", kind = "static"))]
 unsafe extern "C" {
     static VN_DETECT_FACE_CAPTURE_QUALITY_REQUEST:
         &'static objc::Class<DetectFaceCaptureQualityRequest>;
@@ -20,7 +20,7 @@ impl DetectFaceRectanglesRequest {
     pub fn results(&self) -> Option<arc::R<ns::Array<vn::FaceObservation>>>;
 }
 
-#[link(name = "vn", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "vn", kind = "static"))]
 unsafe extern "C" {
     static VN_DETECT_FACE_RECTANGLES_REQUEST: &'static objc::Class<DetectFaceRectanglesRequest>;
 }
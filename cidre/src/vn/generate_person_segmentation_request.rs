@@ -59,7 +59,7 @@ impl GenPersonSegmentationRequest {
     }
 }
 
-#[link(name = "vn", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "vn", kind = "static"))]
 unsafe extern "C" {
     static VN_GENERATE_PERSON_SEGMENTAION_REQUEST:
         &'static objc::Class<GenPersonSegmentationRequest>;
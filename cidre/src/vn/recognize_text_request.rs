@@ -112,7 +112,7 @@ impl RecognizeTextRequest {
     pub fn set_min_text_height(&mut self, val: f32);
 }
 
-#[link(name = "vn", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "vn", kind = "static"))]
 unsafe extern "C" {
     static VN_RECOGNIZE_TEXT_REQUEST: &'static objc::Class<RecognizeTextRequest>;
 }
@@ -90,7 +90,7 @@ pub trait RequestProgressProviding: objc::Obj {
     fn indeterminate(&self) -> bool;
 }
 
-#[link(name = "vn", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "vn", kind = "static"))]
 unsafe extern "C" {
     static VN_DETECT_HORIZON_REQUEST: &'static objc::Class<DetectHorizonRequest>;
 }
@@ -160,7 +160,7 @@ impl SequenceRequestHandler {
     }
 }
 
-#[link(name = "vn", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "vn", kind = "static"))]
 unsafe extern "C" {
     static VN_IMAGE_REQUEST_HANDLER: &'static objc::Class<ImageRequestHandler>;
     static VN_SEQUENCE_REQUEST_HANDLER: &'static objc::Class<SequenceRequestHandler>;
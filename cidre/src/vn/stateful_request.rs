@@ -50,7 +50,7 @@ impl StatefulRequest {
     pub fn frame_analysis_spacing(&self) -> cm::Time;
 }
 
-#[link(name = "vn", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "vn", kind = "static"))]
 unsafe extern "C" {
     static VN_STATEFUL_REQUEST: &'static objc::Class<StatefulRequest>;
 }
@@ -127,7 +127,7 @@ define_obj_type!(
 
 impl Delegate for AnyDelegate {}
 
-#[link(name = "wc", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "wc", kind = "static"))]
 unsafe extern "C" {
     static WC_SESSION: &'static objc::Class<Session>;
 }
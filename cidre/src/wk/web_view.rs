@@ -52,7 +52,7 @@ impl WebView {
     }
 }
 
-#[link(name = "wk", kind = "static")]
+#[cfg_attr(not(cidre_skip_pomace), link(name = "wk", kind = "static"))]
 unsafe extern "C" {
     static WK_WEB_VIEW: &'static objc::Class<WebView>;
 }